@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_acl`]
@@ -104,282 +103,123 @@ pub enum UpdateAclError {
 
 
 /// Create a new ACL attached to the specified service version. A new, empty ACL must be attached to a draft version of a service. The version associated with the ACL must be activated to be used.
-pub async fn create_acl(configuration: &mut configuration::Configuration, params: CreateAclParams) -> Result<crate::models::AclResponse, Error<CreateAclError>> {
-    let local_var_configuration = configuration;
+pub async fn create_acl(configuration: &configuration::Configuration, params: CreateAclParams) -> Result<crate::models::AclResponse, Error<CreateAclError>> {
+    create_acl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_acl`], but also returns the status and headers of the response.
+pub async fn create_acl_with_info(configuration: &configuration::Configuration, params: CreateAclParams) -> Result<crate::apis::Response<crate::models::AclResponse>, Error<CreateAclError>> {
+    crate::apis::execute(configuration, create_acl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_acl`] sends.
+pub(crate) fn create_acl_request(params: CreateAclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let name = params.name;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/acl", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_acl", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/acl", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateAclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete an ACL from the specified service version. To remove an ACL from use, the ACL must be deleted from a draft version and the version without the ACL must be activated.
-pub async fn delete_acl(configuration: &mut configuration::Configuration, params: DeleteAclParams) -> Result<crate::models::InlineResponse200, Error<DeleteAclError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_acl(configuration: &configuration::Configuration, params: DeleteAclParams) -> Result<crate::models::InlineResponse200, Error<DeleteAclError>> {
+    delete_acl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_acl`], but also returns the status and headers of the response.
+pub async fn delete_acl_with_info(configuration: &configuration::Configuration, params: DeleteAclParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteAclError>> {
+    crate::apis::execute(configuration, delete_acl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_acl`] sends.
+pub(crate) fn delete_acl_request(params: DeleteAclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let acl_name = params.acl_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/acl/{acl_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, acl_name=crate::apis::urlencode(acl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteAclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/acl/{acl_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, acl_name=crate::apis::urlencode(acl_name));
+    crate::apis::Request::new("delete_acl", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Retrieve a single ACL by name for the version and service.
-pub async fn get_acl(configuration: &mut configuration::Configuration, params: GetAclParams) -> Result<crate::models::AclResponse, Error<GetAclError>> {
-    let local_var_configuration = configuration;
+pub async fn get_acl(configuration: &configuration::Configuration, params: GetAclParams) -> Result<crate::models::AclResponse, Error<GetAclError>> {
+    get_acl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_acl`], but also returns the status and headers of the response.
+pub async fn get_acl_with_info(configuration: &configuration::Configuration, params: GetAclParams) -> Result<crate::apis::Response<crate::models::AclResponse>, Error<GetAclError>> {
+    crate::apis::execute(configuration, get_acl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_acl`] sends.
+pub(crate) fn get_acl_request(params: GetAclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let acl_name = params.acl_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/acl/{acl_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, acl_name=crate::apis::urlencode(acl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetAclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/acl/{acl_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, acl_name=crate::apis::urlencode(acl_name));
+    crate::apis::Request::new("get_acl", reqwest::Method::GET, local_var_path)
 }
 
 /// List ACLs.
-pub async fn list_acls(configuration: &mut configuration::Configuration, params: ListAclsParams) -> Result<Vec<crate::models::AclResponse>, Error<ListAclsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_acls(configuration: &configuration::Configuration, params: ListAclsParams) -> Result<Vec<crate::models::AclResponse>, Error<ListAclsError>> {
+    list_acls_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_acls`], but also returns the status and headers of the response.
+pub async fn list_acls_with_info(configuration: &configuration::Configuration, params: ListAclsParams) -> Result<crate::apis::Response<Vec<crate::models::AclResponse>>, Error<ListAclsError>> {
+    crate::apis::execute(configuration, list_acls_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_acls`] sends.
+pub(crate) fn list_acls_request(params: ListAclsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/acl", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListAclsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/acl", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_acls", reqwest::Method::GET, local_var_path)
 }
 
 /// Update an ACL for a particular service and version.
-pub async fn update_acl(configuration: &mut configuration::Configuration, params: UpdateAclParams) -> Result<crate::models::AclResponse, Error<UpdateAclError>> {
-    let local_var_configuration = configuration;
+pub async fn update_acl(configuration: &configuration::Configuration, params: UpdateAclParams) -> Result<crate::models::AclResponse, Error<UpdateAclError>> {
+    update_acl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_acl`], but also returns the status and headers of the response.
+pub async fn update_acl_with_info(configuration: &configuration::Configuration, params: UpdateAclParams) -> Result<crate::apis::Response<crate::models::AclResponse>, Error<UpdateAclError>> {
+    crate::apis::execute(configuration, update_acl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_acl`] sends.
+pub(crate) fn update_acl_request(params: UpdateAclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let acl_name = params.acl_name;
     let name = params.name;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/acl/{acl_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, acl_name=crate::apis::urlencode(acl_name));
+    let mut local_var_req = crate::apis::Request::new("update_acl", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/acl/{acl_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, acl_name=crate::apis::urlencode(acl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateAclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
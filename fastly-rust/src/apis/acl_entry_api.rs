@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`bulk_update_acl_entries`]
@@ -127,227 +126,109 @@ pub enum UpdateAclEntryError {
 
 
 /// Update multiple ACL entries on the same ACL. For faster updates to your service, group your changes into large batches. The maximum batch size is 1000 entries. [Contact support](https://support.fastly.com/) to discuss raising this limit.
-pub async fn bulk_update_acl_entries(configuration: &mut configuration::Configuration, params: BulkUpdateAclEntriesParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateAclEntriesError>> {
-    let local_var_configuration = configuration;
+pub async fn bulk_update_acl_entries(configuration: &configuration::Configuration, params: BulkUpdateAclEntriesParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateAclEntriesError>> {
+    bulk_update_acl_entries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`bulk_update_acl_entries`], but also returns the status and headers of the response.
+pub async fn bulk_update_acl_entries_with_info(configuration: &configuration::Configuration, params: BulkUpdateAclEntriesParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<BulkUpdateAclEntriesError>> {
+    crate::apis::execute(configuration, bulk_update_acl_entries_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`bulk_update_acl_entries`] sends.
+pub(crate) fn bulk_update_acl_entries_request(params: BulkUpdateAclEntriesParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let acl_id = params.acl_id;
     let bulk_update_acl_entries_request = params.bulk_update_acl_entries_request;
 
+    let local_var_path = format!("/service/{service_id}/acl/{acl_id}/entries", service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id));
+    let mut local_var_req = crate::apis::Request::new("bulk_update_acl_entries", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/acl/{acl_id}/entries", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&bulk_update_acl_entries_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&bulk_update_acl_entries_request)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<BulkUpdateAclEntriesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Add an ACL entry to an ACL.
-pub async fn create_acl_entry(configuration: &mut configuration::Configuration, params: CreateAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<CreateAclEntryError>> {
-    let local_var_configuration = configuration;
+pub async fn create_acl_entry(configuration: &configuration::Configuration, params: CreateAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<CreateAclEntryError>> {
+    create_acl_entry_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_acl_entry`], but also returns the status and headers of the response.
+pub async fn create_acl_entry_with_info(configuration: &configuration::Configuration, params: CreateAclEntryParams) -> Result<crate::apis::Response<crate::models::AclEntryResponse>, Error<CreateAclEntryError>> {
+    crate::apis::execute(configuration, create_acl_entry_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_acl_entry`] sends.
+pub(crate) fn create_acl_entry_request(params: CreateAclEntryParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let acl_id = params.acl_id;
     let acl_entry = params.acl_entry;
 
+    let local_var_path = format!("/service/{service_id}/acl/{acl_id}/entry", service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id));
+    let mut local_var_req = crate::apis::Request::new("create_acl_entry", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&acl_entry)?);
 
-    let local_var_uri_str = format!("{}/service/{service_id}/acl/{acl_id}/entry", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&acl_entry);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateAclEntryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete an ACL entry from a specified ACL.
-pub async fn delete_acl_entry(configuration: &mut configuration::Configuration, params: DeleteAclEntryParams) -> Result<crate::models::InlineResponse200, Error<DeleteAclEntryError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_acl_entry(configuration: &configuration::Configuration, params: DeleteAclEntryParams) -> Result<crate::models::InlineResponse200, Error<DeleteAclEntryError>> {
+    delete_acl_entry_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_acl_entry`], but also returns the status and headers of the response.
+pub async fn delete_acl_entry_with_info(configuration: &configuration::Configuration, params: DeleteAclEntryParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteAclEntryError>> {
+    crate::apis::execute(configuration, delete_acl_entry_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_acl_entry`] sends.
+pub(crate) fn delete_acl_entry_request(params: DeleteAclEntryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let acl_id = params.acl_id;
     let acl_entry_id = params.acl_entry_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/acl/{acl_id}/entry/{acl_entry_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id), acl_entry_id=crate::apis::urlencode(acl_entry_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteAclEntryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/acl/{acl_id}/entry/{acl_entry_id}", service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id), acl_entry_id=crate::apis::urlencode(acl_entry_id));
+    crate::apis::Request::new("delete_acl_entry", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Retrieve a single ACL entry.
-pub async fn get_acl_entry(configuration: &mut configuration::Configuration, params: GetAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<GetAclEntryError>> {
-    let local_var_configuration = configuration;
+pub async fn get_acl_entry(configuration: &configuration::Configuration, params: GetAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<GetAclEntryError>> {
+    get_acl_entry_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_acl_entry`], but also returns the status and headers of the response.
+pub async fn get_acl_entry_with_info(configuration: &configuration::Configuration, params: GetAclEntryParams) -> Result<crate::apis::Response<crate::models::AclEntryResponse>, Error<GetAclEntryError>> {
+    crate::apis::execute(configuration, get_acl_entry_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_acl_entry`] sends.
+pub(crate) fn get_acl_entry_request(params: GetAclEntryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let acl_id = params.acl_id;
     let acl_entry_id = params.acl_entry_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/acl/{acl_id}/entry/{acl_entry_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id), acl_entry_id=crate::apis::urlencode(acl_entry_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetAclEntryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/acl/{acl_id}/entry/{acl_entry_id}", service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id), acl_entry_id=crate::apis::urlencode(acl_entry_id));
+    crate::apis::Request::new("get_acl_entry", reqwest::Method::GET, local_var_path)
 }
 
 /// List ACL entries for a specified ACL.
-pub async fn list_acl_entries(configuration: &mut configuration::Configuration, params: ListAclEntriesParams) -> Result<Vec<crate::models::AclEntryResponse>, Error<ListAclEntriesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_acl_entries(configuration: &configuration::Configuration, params: ListAclEntriesParams) -> Result<Vec<crate::models::AclEntryResponse>, Error<ListAclEntriesError>> {
+    list_acl_entries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_acl_entries`], but also returns the status and headers of the response.
+pub async fn list_acl_entries_with_info(configuration: &configuration::Configuration, params: ListAclEntriesParams) -> Result<crate::apis::Response<Vec<crate::models::AclEntryResponse>>, Error<ListAclEntriesError>> {
+    crate::apis::execute(configuration, list_acl_entries_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_acl_entries`] sends.
+pub(crate) fn list_acl_entries_request(params: ListAclEntriesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let acl_id = params.acl_id;
@@ -356,116 +237,48 @@ pub async fn list_acl_entries(configuration: &mut configuration::Configuration,
     let sort = params.sort;
     let direction = params.direction;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/acl/{acl_id}/entries", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/acl/{acl_id}/entries", service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id));
+    let mut local_var_req = crate::apis::Request::new("list_acl_entries", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = sort {
-        local_var_req_builder = local_var_req_builder.query(&[("sort", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("sort", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = direction {
-        local_var_req_builder = local_var_req_builder.query(&[("direction", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("direction", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListAclEntriesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update an ACL entry for a specified ACL.
-pub async fn update_acl_entry(configuration: &mut configuration::Configuration, params: UpdateAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<UpdateAclEntryError>> {
-    let local_var_configuration = configuration;
+pub async fn update_acl_entry(configuration: &configuration::Configuration, params: UpdateAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<UpdateAclEntryError>> {
+    update_acl_entry_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_acl_entry`], but also returns the status and headers of the response.
+pub async fn update_acl_entry_with_info(configuration: &configuration::Configuration, params: UpdateAclEntryParams) -> Result<crate::apis::Response<crate::models::AclEntryResponse>, Error<UpdateAclEntryError>> {
+    crate::apis::execute(configuration, update_acl_entry_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_acl_entry`] sends.
+pub(crate) fn update_acl_entry_request(params: UpdateAclEntryParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let acl_id = params.acl_id;
     let acl_entry_id = params.acl_entry_id;
     let acl_entry = params.acl_entry;
 
+    let local_var_path = format!("/service/{service_id}/acl/{acl_id}/entry/{acl_entry_id}", service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id), acl_entry_id=crate::apis::urlencode(acl_entry_id));
+    let mut local_var_req = crate::apis::Request::new("update_acl_entry", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/acl/{acl_id}/entry/{acl_entry_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), acl_id=crate::apis::urlencode(acl_id), acl_entry_id=crate::apis::urlencode(acl_entry_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&acl_entry)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&acl_entry);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateAclEntryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_apex_redirect`]
@@ -113,9 +112,17 @@ pub enum UpdateApexRedirectError {
 
 
 /// Create an apex redirect for a particular service and version.
-pub async fn create_apex_redirect(configuration: &mut configuration::Configuration, params: CreateApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<CreateApexRedirectError>> {
-    let local_var_configuration = configuration;
+pub async fn create_apex_redirect(configuration: &configuration::Configuration, params: CreateApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<CreateApexRedirectError>> {
+    create_apex_redirect_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_apex_redirect`], but also returns the status and headers of the response.
+pub async fn create_apex_redirect_with_info(configuration: &configuration::Configuration, params: CreateApexRedirectParams) -> Result<crate::apis::Response<crate::models::ApexRedirect>, Error<CreateApexRedirectError>> {
+    crate::apis::execute(configuration, create_apex_redirect_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_apex_redirect`] sends.
+pub(crate) fn create_apex_redirect_request(params: CreateApexRedirectParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -128,24 +135,10 @@ pub async fn create_apex_redirect(configuration: &mut configuration::Configurati
     let domains = params.domains;
     let feature_revision = params.feature_revision;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/apex-redirects", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_apex_redirect", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/apex-redirects", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = service_id2 {
         local_var_form_params.insert("service_id", local_var_param_value.to_string());
     }
@@ -165,201 +158,86 @@ pub async fn create_apex_redirect(configuration: &mut configuration::Configurati
         local_var_form_params.insert("status_code", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = domains {
-        local_var_form_params.insert("domains", local_var_param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string());
+        local_var_form_params.insert_array("domains", local_var_param_value);
     }
     if let Some(local_var_param_value) = feature_revision {
         local_var_form_params.insert("feature_revision", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateApexRedirectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete an apex redirect by its ID.
-pub async fn delete_apex_redirect(configuration: &mut configuration::Configuration, params: DeleteApexRedirectParams) -> Result<crate::models::InlineResponse200, Error<DeleteApexRedirectError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_apex_redirect(configuration: &configuration::Configuration, params: DeleteApexRedirectParams) -> Result<crate::models::InlineResponse200, Error<DeleteApexRedirectError>> {
+    delete_apex_redirect_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_apex_redirect`], but also returns the status and headers of the response.
+pub async fn delete_apex_redirect_with_info(configuration: &configuration::Configuration, params: DeleteApexRedirectParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteApexRedirectError>> {
+    crate::apis::execute(configuration, delete_apex_redirect_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_apex_redirect`] sends.
+pub(crate) fn delete_apex_redirect_request(params: DeleteApexRedirectParams) -> crate::apis::Request {
     // unbox the parameters
     let apex_redirect_id = params.apex_redirect_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/apex-redirects/{apex_redirect_id}", local_var_configuration.base_path, apex_redirect_id=crate::apis::urlencode(apex_redirect_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteApexRedirectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/apex-redirects/{apex_redirect_id}", apex_redirect_id=crate::apis::urlencode(apex_redirect_id));
+    crate::apis::Request::new("delete_apex_redirect", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get an apex redirect by its ID.
-pub async fn get_apex_redirect(configuration: &mut configuration::Configuration, params: GetApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<GetApexRedirectError>> {
-    let local_var_configuration = configuration;
+pub async fn get_apex_redirect(configuration: &configuration::Configuration, params: GetApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<GetApexRedirectError>> {
+    get_apex_redirect_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_apex_redirect`], but also returns the status and headers of the response.
+pub async fn get_apex_redirect_with_info(configuration: &configuration::Configuration, params: GetApexRedirectParams) -> Result<crate::apis::Response<crate::models::ApexRedirect>, Error<GetApexRedirectError>> {
+    crate::apis::execute(configuration, get_apex_redirect_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_apex_redirect`] sends.
+pub(crate) fn get_apex_redirect_request(params: GetApexRedirectParams) -> crate::apis::Request {
     // unbox the parameters
     let apex_redirect_id = params.apex_redirect_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/apex-redirects/{apex_redirect_id}", local_var_configuration.base_path, apex_redirect_id=crate::apis::urlencode(apex_redirect_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetApexRedirectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/apex-redirects/{apex_redirect_id}", apex_redirect_id=crate::apis::urlencode(apex_redirect_id));
+    crate::apis::Request::new("get_apex_redirect", reqwest::Method::GET, local_var_path)
 }
 
 /// List all apex redirects for a particular service and version.
-pub async fn list_apex_redirects(configuration: &mut configuration::Configuration, params: ListApexRedirectsParams) -> Result<Vec<crate::models::ApexRedirect>, Error<ListApexRedirectsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_apex_redirects(configuration: &configuration::Configuration, params: ListApexRedirectsParams) -> Result<Vec<crate::models::ApexRedirect>, Error<ListApexRedirectsError>> {
+    list_apex_redirects_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_apex_redirects`], but also returns the status and headers of the response.
+pub async fn list_apex_redirects_with_info(configuration: &configuration::Configuration, params: ListApexRedirectsParams) -> Result<crate::apis::Response<Vec<crate::models::ApexRedirect>>, Error<ListApexRedirectsError>> {
+    crate::apis::execute(configuration, list_apex_redirects_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_apex_redirects`] sends.
+pub(crate) fn list_apex_redirects_request(params: ListApexRedirectsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/apex-redirects", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListApexRedirectsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/apex-redirects", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_apex_redirects", reqwest::Method::GET, local_var_path)
 }
 
 /// Update an apex redirect by its ID.
-pub async fn update_apex_redirect(configuration: &mut configuration::Configuration, params: UpdateApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<UpdateApexRedirectError>> {
-    let local_var_configuration = configuration;
+pub async fn update_apex_redirect(configuration: &configuration::Configuration, params: UpdateApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<UpdateApexRedirectError>> {
+    update_apex_redirect_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_apex_redirect`], but also returns the status and headers of the response.
+pub async fn update_apex_redirect_with_info(configuration: &configuration::Configuration, params: UpdateApexRedirectParams) -> Result<crate::apis::Response<crate::models::ApexRedirect>, Error<UpdateApexRedirectError>> {
+    crate::apis::execute(configuration, update_apex_redirect_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_apex_redirect`] sends.
+pub(crate) fn update_apex_redirect_request(params: UpdateApexRedirectParams) -> crate::apis::Request {
     // unbox the parameters
     let apex_redirect_id = params.apex_redirect_id;
     let service_id = params.service_id;
@@ -371,24 +249,10 @@ pub async fn update_apex_redirect(configuration: &mut configuration::Configurati
     let domains = params.domains;
     let feature_revision = params.feature_revision;
 
+    let local_var_path = format!("/apex-redirects/{apex_redirect_id}", apex_redirect_id=crate::apis::urlencode(apex_redirect_id));
+    let mut local_var_req = crate::apis::Request::new("update_apex_redirect", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/apex-redirects/{apex_redirect_id}", local_var_configuration.base_path, apex_redirect_id=crate::apis::urlencode(apex_redirect_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = service_id {
         local_var_form_params.insert("service_id", local_var_param_value.to_string());
     }
@@ -408,37 +272,13 @@ pub async fn update_apex_redirect(configuration: &mut configuration::Configurati
         local_var_form_params.insert("status_code", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = domains {
-        local_var_form_params.insert("domains", local_var_param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string());
+        local_var_form_params.insert_array("domains", local_var_param_value);
     }
     if let Some(local_var_param_value) = feature_revision {
         local_var_form_params.insert("feature_revision", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateApexRedirectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
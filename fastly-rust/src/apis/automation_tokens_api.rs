@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_automation_token`]
@@ -94,278 +93,122 @@ pub enum RevokeAutomationTokenIdError {
 
 
 /// Creates a new automation token.
-pub async fn create_automation_token(configuration: &mut configuration::Configuration, params: CreateAutomationTokenParams) -> Result<crate::models::AutomationTokenCreateResponse, Error<CreateAutomationTokenError>> {
-    let local_var_configuration = configuration;
+pub async fn create_automation_token(configuration: &configuration::Configuration, params: CreateAutomationTokenParams) -> Result<crate::models::AutomationTokenCreateResponse, Error<CreateAutomationTokenError>> {
+    create_automation_token_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_automation_token`], but also returns the status and headers of the response.
+pub async fn create_automation_token_with_info(configuration: &configuration::Configuration, params: CreateAutomationTokenParams) -> Result<crate::apis::Response<crate::models::AutomationTokenCreateResponse>, Error<CreateAutomationTokenError>> {
+    crate::apis::execute(configuration, create_automation_token_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_automation_token`] sends.
+pub(crate) fn create_automation_token_request(params: CreateAutomationTokenParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let automation_token_create_request = params.automation_token_create_request;
 
+    let local_var_path = String::from("/automation-tokens");
+    let mut local_var_req = crate::apis::Request::new("create_automation_token", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/automation-tokens", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&automation_token_create_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.json(serde_json::to_value(&automation_token_create_request)?);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateAutomationTokenError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Retrieves an automation token by ID.
-pub async fn get_automation_token_id(configuration: &mut configuration::Configuration, params: GetAutomationTokenIdParams) -> Result<crate::models::AutomationTokenResponse, Error<GetAutomationTokenIdError>> {
-    let local_var_configuration = configuration;
+pub async fn get_automation_token_id(configuration: &configuration::Configuration, params: GetAutomationTokenIdParams) -> Result<crate::models::AutomationTokenResponse, Error<GetAutomationTokenIdError>> {
+    get_automation_token_id_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_automation_token_id`], but also returns the status and headers of the response.
+pub async fn get_automation_token_id_with_info(configuration: &configuration::Configuration, params: GetAutomationTokenIdParams) -> Result<crate::apis::Response<crate::models::AutomationTokenResponse>, Error<GetAutomationTokenIdError>> {
+    crate::apis::execute(configuration, get_automation_token_id_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_automation_token_id`] sends.
+pub(crate) fn get_automation_token_id_request(params: GetAutomationTokenIdParams) -> crate::apis::Request {
     // unbox the parameters
     let id = params.id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/automation-tokens/{id}", local_var_configuration.base_path, id=crate::apis::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetAutomationTokenIdError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/automation-tokens/{id}", id=crate::apis::urlencode(id));
+    crate::apis::Request::new("get_automation_token_id", reqwest::Method::GET, local_var_path)
 }
 
 /// List of services associated with the automation token.
-pub async fn get_automation_tokens_id_services(configuration: &mut configuration::Configuration, params: GetAutomationTokensIdServicesParams) -> Result<crate::models::InlineResponse2001, Error<GetAutomationTokensIdServicesError>> {
-    let local_var_configuration = configuration;
+pub async fn get_automation_tokens_id_services(configuration: &configuration::Configuration, params: GetAutomationTokensIdServicesParams) -> Result<crate::models::InlineResponse2001, Error<GetAutomationTokensIdServicesError>> {
+    get_automation_tokens_id_services_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_automation_tokens_id_services`], but also returns the status and headers of the response.
+pub async fn get_automation_tokens_id_services_with_info(configuration: &configuration::Configuration, params: GetAutomationTokensIdServicesParams) -> Result<crate::apis::Response<crate::models::InlineResponse2001>, Error<GetAutomationTokensIdServicesError>> {
+    crate::apis::execute(configuration, get_automation_tokens_id_services_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_automation_tokens_id_services`] sends.
+pub(crate) fn get_automation_tokens_id_services_request(params: GetAutomationTokensIdServicesParams) -> crate::apis::Request {
     // unbox the parameters
     let id = params.id;
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/automation-tokens/{id}/services", local_var_configuration.base_path, id=crate::apis::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/automation-tokens/{id}/services", id=crate::apis::urlencode(id));
+    let mut local_var_req = crate::apis::Request::new("get_automation_tokens_id_services", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetAutomationTokensIdServicesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Lists all automation tokens for a customer.
-pub async fn list_automation_tokens(configuration: &mut configuration::Configuration, params: ListAutomationTokensParams) -> Result<Vec<crate::models::AutomationTokenResponse>, Error<ListAutomationTokensError>> {
-    let local_var_configuration = configuration;
+pub async fn list_automation_tokens(configuration: &configuration::Configuration, params: ListAutomationTokensParams) -> Result<Vec<crate::models::AutomationTokenResponse>, Error<ListAutomationTokensError>> {
+    list_automation_tokens_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_automation_tokens`], but also returns the status and headers of the response.
+pub async fn list_automation_tokens_with_info(configuration: &configuration::Configuration, params: ListAutomationTokensParams) -> Result<crate::apis::Response<Vec<crate::models::AutomationTokenResponse>>, Error<ListAutomationTokensError>> {
+    crate::apis::execute(configuration, list_automation_tokens_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_automation_tokens`] sends.
+pub(crate) fn list_automation_tokens_request(params: ListAutomationTokensParams) -> crate::apis::Request {
     // unbox the parameters
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/automation-tokens", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/automation-tokens");
+    let mut local_var_req = crate::apis::Request::new("list_automation_tokens", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListAutomationTokensError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Revoke an automation token by ID.
-pub async fn revoke_automation_token_id(configuration: &mut configuration::Configuration, params: RevokeAutomationTokenIdParams) -> Result<crate::models::AutomationTokenErrorResponse, Error<RevokeAutomationTokenIdError>> {
-    let local_var_configuration = configuration;
+pub async fn revoke_automation_token_id(configuration: &configuration::Configuration, params: RevokeAutomationTokenIdParams) -> Result<crate::models::AutomationTokenErrorResponse, Error<RevokeAutomationTokenIdError>> {
+    revoke_automation_token_id_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`revoke_automation_token_id`], but also returns the status and headers of the response.
+pub async fn revoke_automation_token_id_with_info(configuration: &configuration::Configuration, params: RevokeAutomationTokenIdParams) -> Result<crate::apis::Response<crate::models::AutomationTokenErrorResponse>, Error<RevokeAutomationTokenIdError>> {
+    crate::apis::execute(configuration, revoke_automation_token_id_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`revoke_automation_token_id`] sends.
+pub(crate) fn revoke_automation_token_id_request(params: RevokeAutomationTokenIdParams) -> crate::apis::Request {
     // unbox the parameters
     let id = params.id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/automation-tokens/{id}", local_var_configuration.base_path, id=crate::apis::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<RevokeAutomationTokenIdError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/automation-tokens/{id}", id=crate::apis::urlencode(id));
+    crate::apis::Request::new("revoke_automation_token_id", reqwest::Method::DELETE, local_var_path)
 }
 
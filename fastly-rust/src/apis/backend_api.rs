@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_backend`]
@@ -71,7 +70,7 @@ pub struct CreateBackendParams {
     /// Client certificate attached to origin.
     pub ssl_client_cert: Option<String>,
     /// Client key attached to origin.
-    pub ssl_client_key: Option<String>,
+    pub ssl_client_key: Option<crate::apis::redact::Secret>,
     /// Use `ssl_cert_hostname` and `ssl_sni_hostname` to configure certificate validation.
     pub ssl_hostname: Option<String>,
     /// Overrides `ssl_hostname`, but only for SNI in the handshake. Does not affect cert validation at all.
@@ -183,7 +182,7 @@ pub struct UpdateBackendParams {
     /// Client certificate attached to origin.
     pub ssl_client_cert: Option<String>,
     /// Client key attached to origin.
-    pub ssl_client_key: Option<String>,
+    pub ssl_client_key: Option<crate::apis::redact::Secret>,
     /// Use `ssl_cert_hostname` and `ssl_sni_hostname` to configure certificate validation.
     pub ssl_hostname: Option<String>,
     /// Overrides `ssl_hostname`, but only for SNI in the handshake. Does not affect cert validation at all.
@@ -240,9 +239,17 @@ pub enum UpdateBackendError {
 
 
 /// Create a backend for a particular service and version.
-pub async fn create_backend(configuration: &mut configuration::Configuration, params: CreateBackendParams) -> Result<crate::models::BackendResponse, Error<CreateBackendError>> {
-    let local_var_configuration = configuration;
+pub async fn create_backend(configuration: &configuration::Configuration, params: CreateBackendParams) -> Result<crate::models::BackendResponse, Error<CreateBackendError>> {
+    create_backend_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_backend`], but also returns the status and headers of the response.
+pub async fn create_backend_with_info(configuration: &configuration::Configuration, params: CreateBackendParams) -> Result<crate::apis::Response<crate::models::BackendResponse>, Error<CreateBackendError>> {
+    crate::apis::execute(configuration, create_backend_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_backend`] sends.
+pub(crate) fn create_backend_request(params: CreateBackendParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -282,24 +289,10 @@ pub async fn create_backend(configuration: &mut configuration::Configuration, pa
     let use_ssl = params.use_ssl;
     let weight = params.weight;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/backend", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_backend", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/backend", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = address {
         local_var_form_params.insert("address", local_var_param_value.to_string());
     }
@@ -379,7 +372,7 @@ pub async fn create_backend(configuration: &mut configuration::Configuration, pa
         local_var_form_params.insert("ssl_client_cert", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = ssl_client_key {
-        local_var_form_params.insert("ssl_client_key", local_var_param_value.to_string());
+        local_var_form_params.insert("ssl_client_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = ssl_hostname {
         local_var_form_params.insert("ssl_hostname", local_var_param_value.to_string());
@@ -405,200 +398,85 @@ pub async fn create_backend(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = weight {
         local_var_form_params.insert("weight", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateBackendError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the backend for a particular service and version.
-pub async fn delete_backend(configuration: &mut configuration::Configuration, params: DeleteBackendParams) -> Result<crate::models::InlineResponse200, Error<DeleteBackendError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_backend(configuration: &configuration::Configuration, params: DeleteBackendParams) -> Result<crate::models::InlineResponse200, Error<DeleteBackendError>> {
+    delete_backend_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_backend`], but also returns the status and headers of the response.
+pub async fn delete_backend_with_info(configuration: &configuration::Configuration, params: DeleteBackendParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteBackendError>> {
+    crate::apis::execute(configuration, delete_backend_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_backend`] sends.
+pub(crate) fn delete_backend_request(params: DeleteBackendParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let backend_name = params.backend_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/backend/{backend_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteBackendError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/backend/{backend_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
+    crate::apis::Request::new("delete_backend", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the backend for a particular service and version.
-pub async fn get_backend(configuration: &mut configuration::Configuration, params: GetBackendParams) -> Result<crate::models::BackendResponse, Error<GetBackendError>> {
-    let local_var_configuration = configuration;
+pub async fn get_backend(configuration: &configuration::Configuration, params: GetBackendParams) -> Result<crate::models::BackendResponse, Error<GetBackendError>> {
+    get_backend_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_backend`], but also returns the status and headers of the response.
+pub async fn get_backend_with_info(configuration: &configuration::Configuration, params: GetBackendParams) -> Result<crate::apis::Response<crate::models::BackendResponse>, Error<GetBackendError>> {
+    crate::apis::execute(configuration, get_backend_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_backend`] sends.
+pub(crate) fn get_backend_request(params: GetBackendParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let backend_name = params.backend_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/backend/{backend_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetBackendError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/backend/{backend_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
+    crate::apis::Request::new("get_backend", reqwest::Method::GET, local_var_path)
 }
 
 /// List all backends for a particular service and version.
-pub async fn list_backends(configuration: &mut configuration::Configuration, params: ListBackendsParams) -> Result<Vec<crate::models::BackendResponse>, Error<ListBackendsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_backends(configuration: &configuration::Configuration, params: ListBackendsParams) -> Result<Vec<crate::models::BackendResponse>, Error<ListBackendsError>> {
+    list_backends_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_backends`], but also returns the status and headers of the response.
+pub async fn list_backends_with_info(configuration: &configuration::Configuration, params: ListBackendsParams) -> Result<crate::apis::Response<Vec<crate::models::BackendResponse>>, Error<ListBackendsError>> {
+    crate::apis::execute(configuration, list_backends_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_backends`] sends.
+pub(crate) fn list_backends_request(params: ListBackendsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/backend", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListBackendsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/backend", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_backends", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the backend for a particular service and version.
-pub async fn update_backend(configuration: &mut configuration::Configuration, params: UpdateBackendParams) -> Result<crate::models::BackendResponse, Error<UpdateBackendError>> {
-    let local_var_configuration = configuration;
+pub async fn update_backend(configuration: &configuration::Configuration, params: UpdateBackendParams) -> Result<crate::models::BackendResponse, Error<UpdateBackendError>> {
+    update_backend_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_backend`], but also returns the status and headers of the response.
+pub async fn update_backend_with_info(configuration: &configuration::Configuration, params: UpdateBackendParams) -> Result<crate::apis::Response<crate::models::BackendResponse>, Error<UpdateBackendError>> {
+    crate::apis::execute(configuration, update_backend_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_backend`] sends.
+pub(crate) fn update_backend_request(params: UpdateBackendParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -639,24 +517,10 @@ pub async fn update_backend(configuration: &mut configuration::Configuration, pa
     let use_ssl = params.use_ssl;
     let weight = params.weight;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/backend/{backend_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
+    let mut local_var_req = crate::apis::Request::new("update_backend", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/backend/{backend_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = address {
         local_var_form_params.insert("address", local_var_param_value.to_string());
     }
@@ -736,7 +600,7 @@ pub async fn update_backend(configuration: &mut configuration::Configuration, pa
         local_var_form_params.insert("ssl_client_cert", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = ssl_client_key {
-        local_var_form_params.insert("ssl_client_key", local_var_param_value.to_string());
+        local_var_form_params.insert("ssl_client_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = ssl_hostname {
         local_var_form_params.insert("ssl_hostname", local_var_param_value.to_string());
@@ -762,32 +626,8 @@ pub async fn update_backend(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = weight {
         local_var_form_params.insert("weight", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateBackendError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -0,0 +1,163 @@
+//! Applying one endpoint function to many params with bounded concurrency.
+//!
+//! [`Batch::run`] calls an endpoint function once for every params value,
+//! with at most [`Batch::concurrency`] calls in flight, and collects every
+//! outcome into a [`Report`] instead of stopping at the first failure:
+//!
+//! ```no_run
+//! # use fastly_api::apis::batch::Batch;
+//! # use fastly_api::apis::configuration::{Configuration, RetryPolicy};
+//! # use fastly_api::apis::version_api::{activate_service_version, ActivateServiceVersionParams};
+//! # async fn run(cfg: &Configuration, service_ids: Vec<String>) {
+//! let batch = Batch::new(cfg).concurrency(8);
+//! let params = service_ids.into_iter().map(|service_id| ActivateServiceVersionParams { service_id, version_id: 2 });
+//! let report = batch.run(params, activate_service_version).await;
+//! println!("{report}");
+//! # }
+//! ```
+//!
+//! Every call goes through one clone of the configuration, so they share its
+//! rate-limit state and a single [`Throttle`]: the configuration's own, or a
+//! new one with no reserve if it has none, starting from the budget the
+//! configuration last saw. Mutating calls therefore wait for the rate-limit
+//! window to reset once the budget is spent, however many are in flight, even
+//! if earlier calls spent most of it before the batch started. Transient
+//! failures are retried per call as [`Batch::retry_policy`] allows.
+//!
+//! Under a [dry run](super::dry_run) the mutating calls are planned rather
+//! than sent; the report counts them as planned, not succeeded.
+
+use std::fmt;
+use std::future::Future;
+
+use futures::stream::{self, StreamExt};
+
+use super::configuration::{Configuration, RetryPolicy, Throttle};
+use super::Error;
+
+/// Calls in flight at once unless [`Batch::concurrency`] says otherwise.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Runs endpoint calls for many params. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct Batch {
+    configuration: Configuration,
+    concurrency: usize,
+}
+
+impl Batch {
+    /// A batch sending its calls with a clone of `configuration`.
+    pub fn new(configuration: &Configuration) -> Batch {
+        let mut configuration = configuration.clone();
+        if configuration.throttle.is_none() {
+            configuration.throttle = Some(Throttle::seeded(0, &configuration.rate_limit_state()));
+        }
+        Batch { configuration, concurrency: DEFAULT_CONCURRENCY }
+    }
+
+    /// Keep at most `concurrency` calls in flight; `0` is treated as `1`.
+    pub fn concurrency(mut self, concurrency: usize) -> Batch {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Retry the batch's calls with `policy` instead of the configuration's.
+    ///
+    /// `POST` and `PATCH` calls are only retried if the policy sets
+    /// [`RetryPolicy::retry_non_idempotent`].
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Batch {
+        self.configuration.retry_policy = policy;
+        self
+    }
+
+    /// Draw from `throttle` instead of the configuration's, e.g. to share it
+    /// with other batches running against a different configuration.
+    pub fn throttle(mut self, throttle: Throttle) -> Batch {
+        self.configuration.throttle = Some(throttle);
+        self
+    }
+
+    /// The configuration the calls are sent with.
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Call `operation` once for each of `params` and report every outcome,
+    /// in the order of `params`.
+    ///
+    /// `operation` is usually an endpoint function such as
+    /// `healthcheck_api::update_healthcheck`; a closure works as well, e.g.
+    /// to call a different endpoint depending on the params.
+    pub async fn run<'a, P, T, E, F, Fut>(&'a self, params: impl IntoIterator<Item = P>, operation: F) -> Report<T, E>
+    where
+        F: Fn(&'a Configuration, P) -> Fut,
+        Fut: Future<Output = Result<T, Error<E>>> + 'a,
+    {
+        let planned_before = self.planned_so_far();
+        let calls = params.into_iter().enumerate().map(|(index, params)| {
+            let call = operation(&self.configuration, params);
+            async move { (index, call.await) }
+        });
+        let mut results: Vec<_> = stream::iter(calls).buffer_unordered(self.concurrency).collect().await;
+        results.sort_by_key(|(index, _)| *index);
+        let (_, results): (Vec<usize>, _) = results.into_iter().unzip();
+        let planned = self.planned_so_far() - planned_before;
+        Report { results, planned }
+    }
+
+    fn planned_so_far(&self) -> usize {
+        self.configuration.dry_run.as_ref().map_or(0, |plan| plan.len())
+    }
+}
+
+/// The outcome of every call of a [`Batch::run`].
+///
+/// Calls planned by a [dry run](super::dry_run) return `Ok` but count as
+/// planned rather than succeeded. Its `Display` output is a summary line followed by one line
+/// per failure.
+#[derive(Debug)]
+pub struct Report<T, E> {
+    /// One result per params value, in the order they were given.
+    pub results: Vec<Result<T, Error<E>>>,
+    planned: usize,
+}
+
+impl<T, E> Report<T, E> {
+    /// Whether every call succeeded or was planned.
+    pub fn is_success(&self) -> bool {
+        self.failed() == 0
+    }
+
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|result| result.is_ok()).count().saturating_sub(self.planned)
+    }
+
+    /// Requests that a dry run planned instead of sending: as many as the
+    /// batch added to the [`Plan`](super::dry_run::Plan).
+    pub fn planned(&self) -> usize {
+        self.planned
+    }
+
+    pub fn failed(&self) -> usize {
+        self.failures().count()
+    }
+
+    /// The failed calls, as the position of their params and the error.
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &Error<E>)> {
+        self.results.iter().enumerate().filter_map(|(index, result)| result.as_ref().err().map(|error| (index, error)))
+    }
+}
+
+impl<T, E> fmt::Display for Report<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} of {} calls succeeded", self.succeeded(), self.results.len())?;
+        let planned = self.planned();
+        if planned > 0 {
+            write!(f, ", {} planned", planned)?;
+        }
+        for (index, error) in self.failures() {
+            write!(f, "\n  #{}: {}", index, error)?;
+        }
+        Ok(())
+    }
+}
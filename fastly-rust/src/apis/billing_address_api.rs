@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`add_billing_addr`]
@@ -76,214 +75,88 @@ pub enum UpdateBillingAddrError {
 
 
 /// Add a billing address to a customer.
-pub async fn add_billing_addr(configuration: &mut configuration::Configuration, params: AddBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<AddBillingAddrError>> {
-    let local_var_configuration = configuration;
+pub async fn add_billing_addr(configuration: &configuration::Configuration, params: AddBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<AddBillingAddrError>> {
+    add_billing_addr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`add_billing_addr`], but also returns the status and headers of the response.
+pub async fn add_billing_addr_with_info(configuration: &configuration::Configuration, params: AddBillingAddrParams) -> Result<crate::apis::Response<crate::models::BillingAddressResponse>, Error<AddBillingAddrError>> {
+    crate::apis::execute(configuration, add_billing_addr_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`add_billing_addr`] sends.
+pub(crate) fn add_billing_addr_request(params: AddBillingAddrParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let customer_id = params.customer_id;
     let billing_address_request = params.billing_address_request;
 
+    let local_var_path = format!("/customer/{customer_id}/billing_address", customer_id=crate::apis::urlencode(customer_id));
+    let mut local_var_req = crate::apis::Request::new("add_billing_addr", reqwest::Method::POST, local_var_path);
+
+    local_var_req = local_var_req.json(serde_json::to_value(&billing_address_request)?);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/billing_address", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&billing_address_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<AddBillingAddrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete a customer's billing address.
-pub async fn delete_billing_addr(configuration: &mut configuration::Configuration, params: DeleteBillingAddrParams) -> Result<(), Error<DeleteBillingAddrError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_billing_addr(configuration: &configuration::Configuration, params: DeleteBillingAddrParams) -> Result<(), Error<DeleteBillingAddrError>> {
+    delete_billing_addr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_billing_addr`], but also returns the status and headers of the response.
+pub async fn delete_billing_addr_with_info(configuration: &configuration::Configuration, params: DeleteBillingAddrParams) -> Result<crate::apis::Response<()>, Error<DeleteBillingAddrError>> {
+    crate::apis::execute(configuration, delete_billing_addr_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_billing_addr`] sends.
+pub(crate) fn delete_billing_addr_request(params: DeleteBillingAddrParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/billing_address", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteBillingAddrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/customer/{customer_id}/billing_address", customer_id=crate::apis::urlencode(customer_id));
+    crate::apis::Request::new("delete_billing_addr", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a customer's billing address.
-pub async fn get_billing_addr(configuration: &mut configuration::Configuration, params: GetBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<GetBillingAddrError>> {
-    let local_var_configuration = configuration;
+pub async fn get_billing_addr(configuration: &configuration::Configuration, params: GetBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<GetBillingAddrError>> {
+    get_billing_addr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_billing_addr`], but also returns the status and headers of the response.
+pub async fn get_billing_addr_with_info(configuration: &configuration::Configuration, params: GetBillingAddrParams) -> Result<crate::apis::Response<crate::models::BillingAddressResponse>, Error<GetBillingAddrError>> {
+    crate::apis::execute(configuration, get_billing_addr_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_billing_addr`] sends.
+pub(crate) fn get_billing_addr_request(params: GetBillingAddrParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/billing_address", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetBillingAddrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/customer/{customer_id}/billing_address", customer_id=crate::apis::urlencode(customer_id));
+    crate::apis::Request::new("get_billing_addr", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a customer's billing address. You may update only part of the customer's billing address.
-pub async fn update_billing_addr(configuration: &mut configuration::Configuration, params: UpdateBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<UpdateBillingAddrError>> {
-    let local_var_configuration = configuration;
+pub async fn update_billing_addr(configuration: &configuration::Configuration, params: UpdateBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<UpdateBillingAddrError>> {
+    update_billing_addr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_billing_addr`], but also returns the status and headers of the response.
+pub async fn update_billing_addr_with_info(configuration: &configuration::Configuration, params: UpdateBillingAddrParams) -> Result<crate::apis::Response<crate::models::BillingAddressResponse>, Error<UpdateBillingAddrError>> {
+    crate::apis::execute(configuration, update_billing_addr_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_billing_addr`] sends.
+pub(crate) fn update_billing_addr_request(params: UpdateBillingAddrParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let customer_id = params.customer_id;
     let update_billing_address_request = params.update_billing_address_request;
 
+    let local_var_path = format!("/customer/{customer_id}/billing_address", customer_id=crate::apis::urlencode(customer_id));
+    let mut local_var_req = crate::apis::Request::new("update_billing_addr", reqwest::Method::PATCH, local_var_path);
+
+    local_var_req = local_var_req.json(serde_json::to_value(&update_billing_address_request)?);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/billing_address", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&update_billing_address_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateBillingAddrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_invoice`]
@@ -63,168 +62,72 @@ pub enum GetInvoiceMtdError {
 
 
 /// Get the invoice for a given year and month. Can be any month from when the Customer was created to the current month.
-pub async fn get_invoice(configuration: &mut configuration::Configuration, params: GetInvoiceParams) -> Result<crate::models::BillingResponse, Error<GetInvoiceError>> {
-    let local_var_configuration = configuration;
+pub async fn get_invoice(configuration: &configuration::Configuration, params: GetInvoiceParams) -> Result<crate::models::BillingResponse, Error<GetInvoiceError>> {
+    get_invoice_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_invoice`], but also returns the status and headers of the response.
+pub async fn get_invoice_with_info(configuration: &configuration::Configuration, params: GetInvoiceParams) -> Result<crate::apis::Response<crate::models::BillingResponse>, Error<GetInvoiceError>> {
+    crate::apis::execute(configuration, get_invoice_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_invoice`] sends.
+pub(crate) fn get_invoice_request(params: GetInvoiceParams) -> crate::apis::Request {
     // unbox the parameters
     let month = params.month;
     let year = params.year;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/billing/v2/year/{year}/month/{month}", local_var_configuration.base_path, month=crate::apis::urlencode(month), year=crate::apis::urlencode(year));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetInvoiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/billing/v2/year/{year}/month/{month}", month=crate::apis::urlencode(month), year=crate::apis::urlencode(year));
+    crate::apis::Request::new("get_invoice", reqwest::Method::GET, local_var_path)
 }
 
 /// Get the invoice for the given invoice_id.
-pub async fn get_invoice_by_id(configuration: &mut configuration::Configuration, params: GetInvoiceByIdParams) -> Result<crate::models::BillingResponse, Error<GetInvoiceByIdError>> {
-    let local_var_configuration = configuration;
+pub async fn get_invoice_by_id(configuration: &configuration::Configuration, params: GetInvoiceByIdParams) -> Result<crate::models::BillingResponse, Error<GetInvoiceByIdError>> {
+    get_invoice_by_id_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_invoice_by_id`], but also returns the status and headers of the response.
+pub async fn get_invoice_by_id_with_info(configuration: &configuration::Configuration, params: GetInvoiceByIdParams) -> Result<crate::apis::Response<crate::models::BillingResponse>, Error<GetInvoiceByIdError>> {
+    crate::apis::execute(configuration, get_invoice_by_id_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_invoice_by_id`] sends.
+pub(crate) fn get_invoice_by_id_request(params: GetInvoiceByIdParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
     let invoice_id = params.invoice_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/billing/v2/account_customers/{customer_id}/invoices/{invoice_id}", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id), invoice_id=invoice_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetInvoiceByIdError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/billing/v2/account_customers/{customer_id}/invoices/{invoice_id}", customer_id=crate::apis::urlencode(customer_id), invoice_id=invoice_id);
+    crate::apis::Request::new("get_invoice_by_id", reqwest::Method::GET, local_var_path)
 }
 
 /// Get the current month-to-date estimate. This endpoint has two different responses. Under normal circumstances, it generally takes less than 5 seconds to generate but in certain cases can take up to 60 seconds. Once generated the month-to-date estimate is cached for 4 hours, and is available the next request will return the JSON representation of the month-to-date estimate. While a report is being generated in the background, this endpoint will return a `202 Accepted` response. The full format of which can be found in detail in our [billing calculation guide](https://docs.fastly.com/en/guides/how-we-calculate-your-bill). There are certain accounts for which we are unable to generate a month-to-date estimate. For example, accounts who have parent-pay are unable to generate an MTD estimate. The parent accounts are able to generate a month-to-date estimate but that estimate will not include the child accounts amounts at this time.
-pub async fn get_invoice_mtd(configuration: &mut configuration::Configuration, params: GetInvoiceMtdParams) -> Result<crate::models::BillingEstimateResponse, Error<GetInvoiceMtdError>> {
-    let local_var_configuration = configuration;
+pub async fn get_invoice_mtd(configuration: &configuration::Configuration, params: GetInvoiceMtdParams) -> Result<crate::models::BillingEstimateResponse, Error<GetInvoiceMtdError>> {
+    get_invoice_mtd_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_invoice_mtd`], but also returns the status and headers of the response.
+pub async fn get_invoice_mtd_with_info(configuration: &configuration::Configuration, params: GetInvoiceMtdParams) -> Result<crate::apis::Response<crate::models::BillingEstimateResponse>, Error<GetInvoiceMtdError>> {
+    crate::apis::execute(configuration, get_invoice_mtd_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_invoice_mtd`] sends.
+pub(crate) fn get_invoice_mtd_request(params: GetInvoiceMtdParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
     let month = params.month;
     let year = params.year;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/billing/v2/account_customers/{customer_id}/mtd_invoice", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/billing/v2/account_customers/{customer_id}/mtd_invoice", customer_id=crate::apis::urlencode(customer_id));
+    let mut local_var_req = crate::apis::Request::new("get_invoice_mtd", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = month {
-        local_var_req_builder = local_var_req_builder.query(&[("month", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("month", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = year {
-        local_var_req_builder = local_var_req_builder.query(&[("year", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("year", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetInvoiceMtdError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
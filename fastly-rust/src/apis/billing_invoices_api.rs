@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_invoice_by_invoice_id`]
@@ -62,172 +61,74 @@ pub enum ListInvoicesError {
 
 
 /// Returns invoice associated with the invoice id.
-pub async fn get_invoice_by_invoice_id(configuration: &mut configuration::Configuration, params: GetInvoiceByInvoiceIdParams) -> Result<crate::models::EomInvoiceResponse, Error<GetInvoiceByInvoiceIdError>> {
-    let local_var_configuration = configuration;
+pub async fn get_invoice_by_invoice_id(configuration: &configuration::Configuration, params: GetInvoiceByInvoiceIdParams) -> Result<crate::models::EomInvoiceResponse, Error<GetInvoiceByInvoiceIdError>> {
+    get_invoice_by_invoice_id_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_invoice_by_invoice_id`], but also returns the status and headers of the response.
+pub async fn get_invoice_by_invoice_id_with_info(configuration: &configuration::Configuration, params: GetInvoiceByInvoiceIdParams) -> Result<crate::apis::Response<crate::models::EomInvoiceResponse>, Error<GetInvoiceByInvoiceIdError>> {
+    crate::apis::execute(configuration, get_invoice_by_invoice_id_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_invoice_by_invoice_id`] sends.
+pub(crate) fn get_invoice_by_invoice_id_request(params: GetInvoiceByInvoiceIdParams) -> crate::apis::Request {
     // unbox the parameters
     let invoice_id = params.invoice_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/billing/v3/invoices/{invoice_id}", local_var_configuration.base_path, invoice_id=invoice_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetInvoiceByInvoiceIdError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/billing/v3/invoices/{invoice_id}", invoice_id=invoice_id);
+    crate::apis::Request::new("get_invoice_by_invoice_id", reqwest::Method::GET, local_var_path)
 }
 
 /// Returns month-to-date invoice for the current month.
-pub async fn get_month_to_date_invoice(configuration: &mut configuration::Configuration) -> Result<crate::models::MtdInvoiceResponse, Error<GetMonthToDateInvoiceError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
-
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/billing/v3/invoices/month-to-date", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+pub async fn get_month_to_date_invoice(configuration: &configuration::Configuration) -> Result<crate::models::MtdInvoiceResponse, Error<GetMonthToDateInvoiceError>> {
+    get_month_to_date_invoice_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+/// Like [`get_month_to_date_invoice`], but also returns the status and headers of the response.
+pub async fn get_month_to_date_invoice_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::MtdInvoiceResponse>, Error<GetMonthToDateInvoiceError>> {
+    crate::apis::execute(configuration, get_month_to_date_invoice_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetMonthToDateInvoiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`get_month_to_date_invoice`] sends.
+pub(crate) fn get_month_to_date_invoice_request() -> crate::apis::Request {
+    let local_var_path = String::from("/billing/v3/invoices/month-to-date");
+    crate::apis::Request::new("get_month_to_date_invoice", reqwest::Method::GET, local_var_path)
 }
 
 /// Returns the list of invoices, sorted by billing start date (newest to oldest).
-pub async fn list_invoices(configuration: &mut configuration::Configuration, params: ListInvoicesParams) -> Result<crate::models::ListEomInvoicesResponse, Error<ListInvoicesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_invoices(configuration: &configuration::Configuration, params: ListInvoicesParams) -> Result<crate::models::ListEomInvoicesResponse, Error<ListInvoicesError>> {
+    list_invoices_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_invoices`], but also returns the status and headers of the response.
+pub async fn list_invoices_with_info(configuration: &configuration::Configuration, params: ListInvoicesParams) -> Result<crate::apis::Response<crate::models::ListEomInvoicesResponse>, Error<ListInvoicesError>> {
+    crate::apis::execute(configuration, list_invoices_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_invoices`] sends.
+pub(crate) fn list_invoices_request(params: ListInvoicesParams) -> crate::apis::Request {
     // unbox the parameters
     let billing_start_date = params.billing_start_date;
     let billing_end_date = params.billing_end_date;
     let limit = params.limit;
     let cursor = params.cursor;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/billing/v3/invoices", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/billing/v3/invoices");
+    let mut local_var_req = crate::apis::Request::new("list_invoices", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = billing_start_date {
-        local_var_req_builder = local_var_req_builder.query(&[("billing_start_date", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("billing_start_date", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = billing_end_date {
-        local_var_req_builder = local_var_req_builder.query(&[("billing_end_date", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("billing_end_date", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = limit {
-        local_var_req_builder = local_var_req_builder.query(&[("limit", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("limit", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = cursor {
-        local_var_req_builder = local_var_req_builder.query(&[("cursor", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("cursor", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListInvoicesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
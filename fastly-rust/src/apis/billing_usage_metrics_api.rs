@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_service_level_usage`]
@@ -61,9 +60,17 @@ pub enum GetServiceLevelUsageTypesError {
 
 
 /// Returns product usage, broken down by service.
-pub async fn get_service_level_usage(configuration: &mut configuration::Configuration, params: GetServiceLevelUsageParams) -> Result<crate::models::Serviceusagemetrics, Error<GetServiceLevelUsageError>> {
-    let local_var_configuration = configuration;
+pub async fn get_service_level_usage(configuration: &configuration::Configuration, params: GetServiceLevelUsageParams) -> Result<crate::models::Serviceusagemetrics, Error<GetServiceLevelUsageError>> {
+    get_service_level_usage_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_service_level_usage`], but also returns the status and headers of the response.
+pub async fn get_service_level_usage_with_info(configuration: &configuration::Configuration, params: GetServiceLevelUsageParams) -> Result<crate::apis::Response<crate::models::Serviceusagemetrics>, Error<GetServiceLevelUsageError>> {
+    crate::apis::execute(configuration, get_service_level_usage_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_service_level_usage`] sends.
+pub(crate) fn get_service_level_usage_request(params: GetServiceLevelUsageParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
     let product_id = params.product_id;
@@ -76,121 +83,50 @@ pub async fn get_service_level_usage(configuration: &mut configuration::Configur
     let limit = params.limit;
     let cursor = params.cursor;
 
+    let local_var_path = format!("/billing/v2/account_customers/{customer_id}/service-usage-metrics", customer_id=crate::apis::urlencode(customer_id));
+    let mut local_var_req = crate::apis::Request::new("get_service_level_usage", reqwest::Method::GET, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/billing/v2/account_customers/{customer_id}/service-usage-metrics", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    local_var_req_builder = local_var_req_builder.query(&[("product_id", &product_id.to_string())]);
-    local_var_req_builder = local_var_req_builder.query(&[("usage_type_name", &usage_type_name.to_string())]);
-    local_var_req_builder = local_var_req_builder.query(&[("time_granularity", &time_granularity.to_string())]);
+    local_var_req = local_var_req.query("product_id", product_id.to_string());
+    local_var_req = local_var_req.query("usage_type_name", usage_type_name.to_string());
+    local_var_req = local_var_req.query("time_granularity", time_granularity.to_string());
     if let Some(ref local_var_str) = start_date {
-        local_var_req_builder = local_var_req_builder.query(&[("start_date", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("start_date", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = end_date {
-        local_var_req_builder = local_var_req_builder.query(&[("end_date", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("end_date", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = start_month {
-        local_var_req_builder = local_var_req_builder.query(&[("start_month", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("start_month", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = end_month {
-        local_var_req_builder = local_var_req_builder.query(&[("end_month", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("end_month", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = limit {
-        local_var_req_builder = local_var_req_builder.query(&[("limit", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("limit", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = cursor {
-        local_var_req_builder = local_var_req_builder.query(&[("cursor", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("cursor", local_var_str.to_string());
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServiceLevelUsageError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Returns product usage types reported by the customer's services.
-pub async fn get_service_level_usage_types(configuration: &mut configuration::Configuration, params: GetServiceLevelUsageTypesParams) -> Result<crate::models::Serviceusagetypes, Error<GetServiceLevelUsageTypesError>> {
-    let local_var_configuration = configuration;
+pub async fn get_service_level_usage_types(configuration: &configuration::Configuration, params: GetServiceLevelUsageTypesParams) -> Result<crate::models::Serviceusagetypes, Error<GetServiceLevelUsageTypesError>> {
+    get_service_level_usage_types_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_service_level_usage_types`], but also returns the status and headers of the response.
+pub async fn get_service_level_usage_types_with_info(configuration: &configuration::Configuration, params: GetServiceLevelUsageTypesParams) -> Result<crate::apis::Response<crate::models::Serviceusagetypes>, Error<GetServiceLevelUsageTypesError>> {
+    crate::apis::execute(configuration, get_service_level_usage_types_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_service_level_usage_types`] sends.
+pub(crate) fn get_service_level_usage_types_request(params: GetServiceLevelUsageTypesParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/billing/v2/account_customers/{customer_id}/service-usage-types", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServiceLevelUsageTypesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/billing/v2/account_customers/{customer_id}/service-usage-types", customer_id=crate::apis::urlencode(customer_id));
+    crate::apis::Request::new("get_service_level_usage_types", reqwest::Method::GET, local_var_path)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_cache_settings`]
@@ -120,9 +119,17 @@ pub enum UpdateCacheSettingsError {
 
 
 /// Create a cache settings object.
-pub async fn create_cache_settings(configuration: &mut configuration::Configuration, params: CreateCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<CreateCacheSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn create_cache_settings(configuration: &configuration::Configuration, params: CreateCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<CreateCacheSettingsError>> {
+    create_cache_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_cache_settings`], but also returns the status and headers of the response.
+pub async fn create_cache_settings_with_info(configuration: &configuration::Configuration, params: CreateCacheSettingsParams) -> Result<crate::apis::Response<crate::models::CacheSettingResponse>, Error<CreateCacheSettingsError>> {
+    crate::apis::execute(configuration, create_cache_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_cache_settings`] sends.
+pub(crate) fn create_cache_settings_request(params: CreateCacheSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -132,24 +139,10 @@ pub async fn create_cache_settings(configuration: &mut configuration::Configurat
     let stale_ttl = params.stale_ttl;
     let ttl = params.ttl;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/cache_settings", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_cache_settings", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/cache_settings", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = action {
         local_var_form_params.insert("action", local_var_param_value.to_string());
     }
@@ -165,200 +158,85 @@ pub async fn create_cache_settings(configuration: &mut configuration::Configurat
     if let Some(local_var_param_value) = ttl {
         local_var_form_params.insert("ttl", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateCacheSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a specific cache settings object.
-pub async fn delete_cache_settings(configuration: &mut configuration::Configuration, params: DeleteCacheSettingsParams) -> Result<crate::models::InlineResponse200, Error<DeleteCacheSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_cache_settings(configuration: &configuration::Configuration, params: DeleteCacheSettingsParams) -> Result<crate::models::InlineResponse200, Error<DeleteCacheSettingsError>> {
+    delete_cache_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_cache_settings`], but also returns the status and headers of the response.
+pub async fn delete_cache_settings_with_info(configuration: &configuration::Configuration, params: DeleteCacheSettingsParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteCacheSettingsError>> {
+    crate::apis::execute(configuration, delete_cache_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_cache_settings`] sends.
+pub(crate) fn delete_cache_settings_request(params: DeleteCacheSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let cache_settings_name = params.cache_settings_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/cache_settings/{cache_settings_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, cache_settings_name=crate::apis::urlencode(cache_settings_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteCacheSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/cache_settings/{cache_settings_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, cache_settings_name=crate::apis::urlencode(cache_settings_name));
+    crate::apis::Request::new("delete_cache_settings", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a specific cache settings object.
-pub async fn get_cache_settings(configuration: &mut configuration::Configuration, params: GetCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<GetCacheSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_cache_settings(configuration: &configuration::Configuration, params: GetCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<GetCacheSettingsError>> {
+    get_cache_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_cache_settings`], but also returns the status and headers of the response.
+pub async fn get_cache_settings_with_info(configuration: &configuration::Configuration, params: GetCacheSettingsParams) -> Result<crate::apis::Response<crate::models::CacheSettingResponse>, Error<GetCacheSettingsError>> {
+    crate::apis::execute(configuration, get_cache_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_cache_settings`] sends.
+pub(crate) fn get_cache_settings_request(params: GetCacheSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let cache_settings_name = params.cache_settings_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/cache_settings/{cache_settings_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, cache_settings_name=crate::apis::urlencode(cache_settings_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCacheSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/cache_settings/{cache_settings_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, cache_settings_name=crate::apis::urlencode(cache_settings_name));
+    crate::apis::Request::new("get_cache_settings", reqwest::Method::GET, local_var_path)
 }
 
 /// Get a list of all cache settings for a particular service and version.
-pub async fn list_cache_settings(configuration: &mut configuration::Configuration, params: ListCacheSettingsParams) -> Result<Vec<crate::models::CacheSettingResponse>, Error<ListCacheSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_cache_settings(configuration: &configuration::Configuration, params: ListCacheSettingsParams) -> Result<Vec<crate::models::CacheSettingResponse>, Error<ListCacheSettingsError>> {
+    list_cache_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_cache_settings`], but also returns the status and headers of the response.
+pub async fn list_cache_settings_with_info(configuration: &configuration::Configuration, params: ListCacheSettingsParams) -> Result<crate::apis::Response<Vec<crate::models::CacheSettingResponse>>, Error<ListCacheSettingsError>> {
+    crate::apis::execute(configuration, list_cache_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_cache_settings`] sends.
+pub(crate) fn list_cache_settings_request(params: ListCacheSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/cache_settings", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListCacheSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/cache_settings", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_cache_settings", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a specific cache settings object.
-pub async fn update_cache_settings(configuration: &mut configuration::Configuration, params: UpdateCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<UpdateCacheSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn update_cache_settings(configuration: &configuration::Configuration, params: UpdateCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<UpdateCacheSettingsError>> {
+    update_cache_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_cache_settings`], but also returns the status and headers of the response.
+pub async fn update_cache_settings_with_info(configuration: &configuration::Configuration, params: UpdateCacheSettingsParams) -> Result<crate::apis::Response<crate::models::CacheSettingResponse>, Error<UpdateCacheSettingsError>> {
+    crate::apis::execute(configuration, update_cache_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_cache_settings`] sends.
+pub(crate) fn update_cache_settings_request(params: UpdateCacheSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -369,24 +247,10 @@ pub async fn update_cache_settings(configuration: &mut configuration::Configurat
     let stale_ttl = params.stale_ttl;
     let ttl = params.ttl;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/cache_settings/{cache_settings_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, cache_settings_name=crate::apis::urlencode(cache_settings_name));
+    let mut local_var_req = crate::apis::Request::new("update_cache_settings", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/cache_settings/{cache_settings_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, cache_settings_name=crate::apis::urlencode(cache_settings_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = action {
         local_var_form_params.insert("action", local_var_param_value.to_string());
     }
@@ -402,32 +266,8 @@ pub async fn update_cache_settings(configuration: &mut configuration::Configurat
     if let Some(local_var_param_value) = ttl {
         local_var_form_params.insert("ttl", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateCacheSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -0,0 +1,256 @@
+//! Recording and replaying HTTP exchanges, for offline regression tests.
+//!
+//! A [`Cassette`] in [`Configuration::cassette`](super::configuration::Configuration::cassette)
+//! sits between the API functions and `reqwest::Client`. In record mode every
+//! exchange with the real API is appended to a JSON file; in replay mode
+//! responses are served from that file and nothing goes over the network:
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use fastly_api::apis::cassette::Cassette;
+//! # use fastly_api::apis::configuration::Configuration;
+//! # fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! // Once, against a real account:
+//! let recording = Configuration::builder().cassette(Arc::new(Cassette::record("tests/fixtures/healthcheck.json"))).build()?;
+//! // From then on, in CI:
+//! let replaying = Configuration::builder().cassette(Arc::new(Cassette::replay("tests/fixtures/healthcheck.json")?)).build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Credentials never reach the file: headers, query parameters, form fields
+//! and JSON fields that [`redact`] considers secret are stored as
+//! [`redact::REDACTED`], in requests and responses alike. Requests are
+//! matched on method, path, query and body after the same scrubbing, so a
+//! cassette recorded with one token replays with any other. Bodies are stored
+//! as text; multipart request bodies are not stored and not matched.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::redact;
+
+/// Whether a [`Cassette`] talks to the API or answers from its file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Record,
+    Replay,
+}
+
+/// One request and the response it got.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    /// Percent-encoded, without the leading `?`.
+    pub query: String,
+    pub headers: Vec<(String, String)>,
+    /// `None` for multipart and other streamed bodies.
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// A JSON file of recorded [`Interaction`]s. See the [module docs](self).
+///
+/// Shared by every clone of the [`Configuration`](super::configuration::Configuration)
+/// it is set on, so concurrent calls record to and replay from the same file.
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: Mode,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    interactions: Vec<Interaction>,
+    /// Replay serves each interaction once.
+    used: Vec<bool>,
+}
+
+impl Cassette {
+    /// Record to `path`, replacing whatever is there. The file is rewritten
+    /// after every exchange, so it is complete even if the test panics.
+    pub fn record(path: impl Into<PathBuf>) -> Cassette {
+        Cassette {
+            path: path.into(),
+            mode: Mode::Record,
+            state: Mutex::new(State { interactions: Vec::new(), used: Vec::new() }),
+        }
+    }
+
+    /// Replay the interactions recorded in `path`.
+    ///
+    /// Each request is answered by the first interaction that matches it and
+    /// has not been served yet, so repeated identical calls replay in order.
+    /// A request with no such interaction fails with an
+    /// [`io::ErrorKind::NotFound`] error.
+    pub fn replay(path: impl Into<PathBuf>) -> io::Result<Cassette> {
+        let path = path.into();
+        let file: CassetteFile = serde_json::from_slice(&fs::read(&path)?)?;
+        let used = vec![false; file.interactions.len()];
+        Ok(Cassette {
+            path,
+            mode: Mode::Replay,
+            state: Mutex::new(State { interactions: file.interactions, used }),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// The interactions recorded so far, or loaded for replay.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state.lock().unwrap().interactions.clone()
+    }
+
+    /// Send `request` with `client`, or answer it from the cassette.
+    ///
+    /// The outer error is the cassette's own: a replay miss or a failure to
+    /// write the file. The inner one is the client's, passed through so the
+    /// retry policy sees it as usual.
+    pub(crate) async fn execute(&self, client: &reqwest::Client, request: reqwest::Request) -> io::Result<Result<reqwest::Response, reqwest::Error>> {
+        let recorded = RecordedRequest::scrubbed(&request);
+        let path = recorded.path.clone();
+        if self.mode == Mode::Replay {
+            return self.take(&recorded).map(Ok);
+        }
+
+        let response = match client.execute(request).await {
+            Ok(response) => response,
+            Err(e) => return Ok(Err(e)),
+        };
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = match response.bytes().await {
+            Ok(body) => body,
+            Err(e) => return Ok(Err(e)),
+        };
+        self.push(Interaction { request: recorded, response: RecordedResponse::scrubbed(&path, status, &headers, &body) })?;
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+        Ok(Ok(response.into()))
+    }
+
+    fn take(&self, request: &RecordedRequest) -> io::Result<reqwest::Response> {
+        let mut state = self.state.lock().unwrap();
+        let State { interactions, used } = &mut *state;
+        let found = interactions.iter().zip(used.iter_mut()).find(|(i, used)| !**used && i.request.matches(request));
+        let Some((interaction, used)) = found else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{}: no recorded interaction left for {} {}", self.path.display(), request.method, request.path),
+            ));
+        };
+        *used = true;
+
+        let mut response = http::Response::builder().status(interaction.response.status);
+        for (name, value) in &interaction.response.headers {
+            response = response.header(name.as_str(), value.as_str());
+        }
+        let response = response
+            .body(interaction.response.body.clone())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(response.into())
+    }
+
+    fn push(&self, interaction: Interaction) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.interactions.push(interaction);
+        state.used.push(false);
+        let file = CassetteFile { interactions: state.interactions.clone() };
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_vec_pretty(&file)?)
+    }
+}
+
+impl RecordedRequest {
+    fn scrubbed(request: &reqwest::Request) -> RecordedRequest {
+        let content_type = request.headers().get(reqwest::header::CONTENT_TYPE).and_then(|v| v.to_str().ok()).unwrap_or_default();
+        let path = request.url().path();
+        let body = request.body().and_then(reqwest::Body::as_bytes).map(|body| scrub_body(path, content_type, body));
+        RecordedRequest {
+            method: request.method().to_string(),
+            path: path.to_owned(),
+            query: request.url().query().map(|query| scrub_form(path, query)).unwrap_or_default(),
+            headers: scrub_headers(path, request.headers()),
+            body,
+        }
+    }
+
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method && self.path == other.path && self.query == other.query && self.body == other.body
+    }
+}
+
+impl RecordedResponse {
+    fn scrubbed(path: &str, status: reqwest::StatusCode, headers: &reqwest::header::HeaderMap, body: &[u8]) -> RecordedResponse {
+        let content_type = headers.get(reqwest::header::CONTENT_TYPE).and_then(|v| v.to_str().ok()).unwrap_or_default();
+        RecordedResponse {
+            status: status.as_u16(),
+            headers: scrub_headers(path, headers),
+            body: scrub_body(path, content_type, body),
+        }
+    }
+}
+
+/// Headers describing the framing of the original message; the replayed
+/// body is in memory and may have been rewritten by scrubbing.
+const FRAMING_HEADERS: &[&str] = &["connection", "content-length", "transfer-encoding"];
+
+fn scrub_headers(path: &str, headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| !FRAMING_HEADERS.contains(&name.as_str()))
+        .map(|(name, value)| {
+            let value = String::from_utf8_lossy(value.as_bytes());
+            (name.to_string(), redact::value(path, name.as_str(), &value).to_owned())
+        })
+        .collect()
+}
+
+fn scrub_body(path: &str, content_type: &str, body: &[u8]) -> String {
+    if content_type.starts_with("application/x-www-form-urlencoded") {
+        return scrub_form(path, &String::from_utf8_lossy(body));
+    }
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(json) if content_type.contains("json") => redact::json(path, &json).to_string(),
+        _ => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+fn scrub_form(path: &str, form: &str) -> String {
+    let mut scrubbed = url::form_urlencoded::Serializer::new(String::new());
+    for (name, value) in url::form_urlencoded::parse(form.as_bytes()) {
+        scrubbed.append_pair(&name, redact::value(path, &name, &value));
+    }
+    scrubbed.finish()
+}
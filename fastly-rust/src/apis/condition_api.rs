@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_condition`]
@@ -126,9 +125,17 @@ pub enum UpdateConditionError {
 
 
 /// Creates a new condition.
-pub async fn create_condition(configuration: &mut configuration::Configuration, params: CreateConditionParams) -> Result<crate::models::ConditionResponse, Error<CreateConditionError>> {
-    let local_var_configuration = configuration;
+pub async fn create_condition(configuration: &configuration::Configuration, params: CreateConditionParams) -> Result<crate::models::ConditionResponse, Error<CreateConditionError>> {
+    create_condition_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_condition`], but also returns the status and headers of the response.
+pub async fn create_condition_with_info(configuration: &configuration::Configuration, params: CreateConditionParams) -> Result<crate::apis::Response<crate::models::ConditionResponse>, Error<CreateConditionError>> {
+    crate::apis::execute(configuration, create_condition_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_condition`] sends.
+pub(crate) fn create_condition_request(params: CreateConditionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -140,24 +147,10 @@ pub async fn create_condition(configuration: &mut configuration::Configuration,
     let version = params.version;
     let _type = params._type;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/condition", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_condition", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/condition", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = comment {
         local_var_form_params.insert("comment", local_var_param_value.to_string());
     }
@@ -179,200 +172,85 @@ pub async fn create_condition(configuration: &mut configuration::Configuration,
     if let Some(local_var_param_value) = _type {
         local_var_form_params.insert("type", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateConditionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Deletes the specified condition.
-pub async fn delete_condition(configuration: &mut configuration::Configuration, params: DeleteConditionParams) -> Result<crate::models::InlineResponse200, Error<DeleteConditionError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_condition(configuration: &configuration::Configuration, params: DeleteConditionParams) -> Result<crate::models::InlineResponse200, Error<DeleteConditionError>> {
+    delete_condition_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_condition`], but also returns the status and headers of the response.
+pub async fn delete_condition_with_info(configuration: &configuration::Configuration, params: DeleteConditionParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteConditionError>> {
+    crate::apis::execute(configuration, delete_condition_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_condition`] sends.
+pub(crate) fn delete_condition_request(params: DeleteConditionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let condition_name = params.condition_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/condition/{condition_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, condition_name=crate::apis::urlencode(condition_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteConditionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/condition/{condition_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, condition_name=crate::apis::urlencode(condition_name));
+    crate::apis::Request::new("delete_condition", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Gets the specified condition.
-pub async fn get_condition(configuration: &mut configuration::Configuration, params: GetConditionParams) -> Result<crate::models::ConditionResponse, Error<GetConditionError>> {
-    let local_var_configuration = configuration;
+pub async fn get_condition(configuration: &configuration::Configuration, params: GetConditionParams) -> Result<crate::models::ConditionResponse, Error<GetConditionError>> {
+    get_condition_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_condition`], but also returns the status and headers of the response.
+pub async fn get_condition_with_info(configuration: &configuration::Configuration, params: GetConditionParams) -> Result<crate::apis::Response<crate::models::ConditionResponse>, Error<GetConditionError>> {
+    crate::apis::execute(configuration, get_condition_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_condition`] sends.
+pub(crate) fn get_condition_request(params: GetConditionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let condition_name = params.condition_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/condition/{condition_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, condition_name=crate::apis::urlencode(condition_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetConditionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/condition/{condition_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, condition_name=crate::apis::urlencode(condition_name));
+    crate::apis::Request::new("get_condition", reqwest::Method::GET, local_var_path)
 }
 
 /// Gets all conditions for a particular service and version.
-pub async fn list_conditions(configuration: &mut configuration::Configuration, params: ListConditionsParams) -> Result<Vec<crate::models::ConditionResponse>, Error<ListConditionsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_conditions(configuration: &configuration::Configuration, params: ListConditionsParams) -> Result<Vec<crate::models::ConditionResponse>, Error<ListConditionsError>> {
+    list_conditions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_conditions`], but also returns the status and headers of the response.
+pub async fn list_conditions_with_info(configuration: &configuration::Configuration, params: ListConditionsParams) -> Result<crate::apis::Response<Vec<crate::models::ConditionResponse>>, Error<ListConditionsError>> {
+    crate::apis::execute(configuration, list_conditions_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_conditions`] sends.
+pub(crate) fn list_conditions_request(params: ListConditionsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/condition", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListConditionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/condition", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_conditions", reqwest::Method::GET, local_var_path)
 }
 
 /// Updates the specified condition.
-pub async fn update_condition(configuration: &mut configuration::Configuration, params: UpdateConditionParams) -> Result<crate::models::ConditionResponse, Error<UpdateConditionError>> {
-    let local_var_configuration = configuration;
+pub async fn update_condition(configuration: &configuration::Configuration, params: UpdateConditionParams) -> Result<crate::models::ConditionResponse, Error<UpdateConditionError>> {
+    update_condition_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_condition`], but also returns the status and headers of the response.
+pub async fn update_condition_with_info(configuration: &configuration::Configuration, params: UpdateConditionParams) -> Result<crate::apis::Response<crate::models::ConditionResponse>, Error<UpdateConditionError>> {
+    crate::apis::execute(configuration, update_condition_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_condition`] sends.
+pub(crate) fn update_condition_request(params: UpdateConditionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -385,24 +263,10 @@ pub async fn update_condition(configuration: &mut configuration::Configuration,
     let version = params.version;
     let _type = params._type;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/condition/{condition_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, condition_name=crate::apis::urlencode(condition_name));
+    let mut local_var_req = crate::apis::Request::new("update_condition", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/condition/{condition_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, condition_name=crate::apis::urlencode(condition_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = comment {
         local_var_form_params.insert("comment", local_var_param_value.to_string());
     }
@@ -424,32 +288,8 @@ pub async fn update_condition(configuration: &mut configuration::Configuration,
     if let Some(local_var_param_value) = _type {
         local_var_form_params.insert("type", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateConditionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_config_store`]
@@ -114,380 +113,158 @@ pub enum UpdateConfigStoreError {
 
 
 /// Create a config store.
-pub async fn create_config_store(configuration: &mut configuration::Configuration, params: CreateConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<CreateConfigStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn create_config_store(configuration: &configuration::Configuration, params: CreateConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<CreateConfigStoreError>> {
+    create_config_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_config_store`], but also returns the status and headers of the response.
+pub async fn create_config_store_with_info(configuration: &configuration::Configuration, params: CreateConfigStoreParams) -> Result<crate::apis::Response<crate::models::ConfigStoreResponse>, Error<CreateConfigStoreError>> {
+    crate::apis::execute(configuration, create_config_store_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_config_store`] sends.
+pub(crate) fn create_config_store_request(params: CreateConfigStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let name = params.name;
 
+    let local_var_path = String::from("/resources/stores/config");
+    let mut local_var_req = crate::apis::Request::new("create_config_store", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateConfigStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a config store.
-pub async fn delete_config_store(configuration: &mut configuration::Configuration, params: DeleteConfigStoreParams) -> Result<crate::models::InlineResponse200, Error<DeleteConfigStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_config_store(configuration: &configuration::Configuration, params: DeleteConfigStoreParams) -> Result<crate::models::InlineResponse200, Error<DeleteConfigStoreError>> {
+    delete_config_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_config_store`], but also returns the status and headers of the response.
+pub async fn delete_config_store_with_info(configuration: &configuration::Configuration, params: DeleteConfigStoreParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteConfigStoreError>> {
+    crate::apis::execute(configuration, delete_config_store_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_config_store`] sends.
+pub(crate) fn delete_config_store_request(params: DeleteConfigStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteConfigStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/config/{config_store_id}", config_store_id=crate::apis::urlencode(config_store_id));
+    crate::apis::Request::new("delete_config_store", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Describe a config store by its identifier.
-pub async fn get_config_store(configuration: &mut configuration::Configuration, params: GetConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<GetConfigStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn get_config_store(configuration: &configuration::Configuration, params: GetConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<GetConfigStoreError>> {
+    get_config_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_config_store`], but also returns the status and headers of the response.
+pub async fn get_config_store_with_info(configuration: &configuration::Configuration, params: GetConfigStoreParams) -> Result<crate::apis::Response<crate::models::ConfigStoreResponse>, Error<GetConfigStoreError>> {
+    crate::apis::execute(configuration, get_config_store_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_config_store`] sends.
+pub(crate) fn get_config_store_request(params: GetConfigStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetConfigStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/config/{config_store_id}", config_store_id=crate::apis::urlencode(config_store_id));
+    crate::apis::Request::new("get_config_store", reqwest::Method::GET, local_var_path)
 }
 
 /// Retrieve metadata for a single config store.
-pub async fn get_config_store_info(configuration: &mut configuration::Configuration, params: GetConfigStoreInfoParams) -> Result<crate::models::ConfigStoreInfoResponse, Error<GetConfigStoreInfoError>> {
-    let local_var_configuration = configuration;
+pub async fn get_config_store_info(configuration: &configuration::Configuration, params: GetConfigStoreInfoParams) -> Result<crate::models::ConfigStoreInfoResponse, Error<GetConfigStoreInfoError>> {
+    get_config_store_info_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_config_store_info`], but also returns the status and headers of the response.
+pub async fn get_config_store_info_with_info(configuration: &configuration::Configuration, params: GetConfigStoreInfoParams) -> Result<crate::apis::Response<crate::models::ConfigStoreInfoResponse>, Error<GetConfigStoreInfoError>> {
+    crate::apis::execute(configuration, get_config_store_info_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_config_store_info`] sends.
+pub(crate) fn get_config_store_info_request(params: GetConfigStoreInfoParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/info", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetConfigStoreInfoError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/info", config_store_id=crate::apis::urlencode(config_store_id));
+    crate::apis::Request::new("get_config_store_info", reqwest::Method::GET, local_var_path)
 }
 
 /// List services linked to a config store
-pub async fn list_config_store_services(configuration: &mut configuration::Configuration, params: ListConfigStoreServicesParams) -> Result<serde_json::Value, Error<ListConfigStoreServicesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_config_store_services(configuration: &configuration::Configuration, params: ListConfigStoreServicesParams) -> Result<serde_json::Value, Error<ListConfigStoreServicesError>> {
+    list_config_store_services_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_config_store_services`], but also returns the status and headers of the response.
+pub async fn list_config_store_services_with_info(configuration: &configuration::Configuration, params: ListConfigStoreServicesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListConfigStoreServicesError>> {
+    crate::apis::execute(configuration, list_config_store_services_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_config_store_services`] sends.
+pub(crate) fn list_config_store_services_request(params: ListConfigStoreServicesParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/services", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListConfigStoreServicesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/services", config_store_id=crate::apis::urlencode(config_store_id));
+    crate::apis::Request::new("list_config_store_services", reqwest::Method::GET, local_var_path)
 }
 
 /// List config stores.
-pub async fn list_config_stores(configuration: &mut configuration::Configuration, params: ListConfigStoresParams) -> Result<Vec<crate::models::ConfigStoreResponse>, Error<ListConfigStoresError>> {
-    let local_var_configuration = configuration;
+pub async fn list_config_stores(configuration: &configuration::Configuration, params: ListConfigStoresParams) -> Result<Vec<crate::models::ConfigStoreResponse>, Error<ListConfigStoresError>> {
+    list_config_stores_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_config_stores`], but also returns the status and headers of the response.
+pub async fn list_config_stores_with_info(configuration: &configuration::Configuration, params: ListConfigStoresParams) -> Result<crate::apis::Response<Vec<crate::models::ConfigStoreResponse>>, Error<ListConfigStoresError>> {
+    crate::apis::execute(configuration, list_config_stores_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_config_stores`] sends.
+pub(crate) fn list_config_stores_request(params: ListConfigStoresParams) -> crate::apis::Request {
     // unbox the parameters
     let name = params.name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/resources/stores/config");
+    let mut local_var_req = crate::apis::Request::new("list_config_stores", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = name {
-        local_var_req_builder = local_var_req_builder.query(&[("name", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("name", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListConfigStoresError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a config store.
-pub async fn update_config_store(configuration: &mut configuration::Configuration, params: UpdateConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<UpdateConfigStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn update_config_store(configuration: &configuration::Configuration, params: UpdateConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<UpdateConfigStoreError>> {
+    update_config_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_config_store`], but also returns the status and headers of the response.
+pub async fn update_config_store_with_info(configuration: &configuration::Configuration, params: UpdateConfigStoreParams) -> Result<crate::apis::Response<crate::models::ConfigStoreResponse>, Error<UpdateConfigStoreError>> {
+    crate::apis::execute(configuration, update_config_store_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_config_store`] sends.
+pub(crate) fn update_config_store_request(params: UpdateConfigStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
     let name = params.name;
 
+    let local_var_path = format!("/resources/stores/config/{config_store_id}", config_store_id=crate::apis::urlencode(config_store_id));
+    let mut local_var_req = crate::apis::Request::new("update_config_store", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateConfigStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
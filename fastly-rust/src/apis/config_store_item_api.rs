@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`bulk_update_config_store_item`]
@@ -133,402 +132,183 @@ pub enum UpsertConfigStoreItemError {
 
 
 /// Add multiple key-value pairs to an individual config store, specified by ID.
-pub async fn bulk_update_config_store_item(configuration: &mut configuration::Configuration, params: BulkUpdateConfigStoreItemParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateConfigStoreItemError>> {
-    let local_var_configuration = configuration;
+pub async fn bulk_update_config_store_item(configuration: &configuration::Configuration, params: BulkUpdateConfigStoreItemParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateConfigStoreItemError>> {
+    bulk_update_config_store_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`bulk_update_config_store_item`], but also returns the status and headers of the response.
+pub async fn bulk_update_config_store_item_with_info(configuration: &configuration::Configuration, params: BulkUpdateConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<BulkUpdateConfigStoreItemError>> {
+    crate::apis::execute(configuration, bulk_update_config_store_item_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`bulk_update_config_store_item`] sends.
+pub(crate) fn bulk_update_config_store_item_request(params: BulkUpdateConfigStoreItemParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let config_store_id = params.config_store_id;
     let bulk_update_config_store_list_request = params.bulk_update_config_store_list_request;
 
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/items", config_store_id=crate::apis::urlencode(config_store_id));
+    let mut local_var_req = crate::apis::Request::new("bulk_update_config_store_item", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&bulk_update_config_store_list_request)?);
 
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/items", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&bulk_update_config_store_list_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<BulkUpdateConfigStoreItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Add a single key-value pair to an individual config store, specified by ID.
-pub async fn create_config_store_item(configuration: &mut configuration::Configuration, params: CreateConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<CreateConfigStoreItemError>> {
-    let local_var_configuration = configuration;
+pub async fn create_config_store_item(configuration: &configuration::Configuration, params: CreateConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<CreateConfigStoreItemError>> {
+    create_config_store_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_config_store_item`], but also returns the status and headers of the response.
+pub async fn create_config_store_item_with_info(configuration: &configuration::Configuration, params: CreateConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::ConfigStoreItemResponse>, Error<CreateConfigStoreItemError>> {
+    crate::apis::execute(configuration, create_config_store_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_config_store_item`] sends.
+pub(crate) fn create_config_store_item_request(params: CreateConfigStoreItemParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
     let item_key = params.item_key;
     let item_value = params.item_value;
 
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/item", config_store_id=crate::apis::urlencode(config_store_id));
+    let mut local_var_req = crate::apis::Request::new("create_config_store_item", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/item", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = item_key {
         local_var_form_params.insert("item_key", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = item_value {
         local_var_form_params.insert("item_value", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateConfigStoreItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete an entry in a config store given a config store ID, and item key.
-pub async fn delete_config_store_item(configuration: &mut configuration::Configuration, params: DeleteConfigStoreItemParams) -> Result<crate::models::InlineResponse200, Error<DeleteConfigStoreItemError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_config_store_item(configuration: &configuration::Configuration, params: DeleteConfigStoreItemParams) -> Result<crate::models::InlineResponse200, Error<DeleteConfigStoreItemError>> {
+    delete_config_store_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_config_store_item`], but also returns the status and headers of the response.
+pub async fn delete_config_store_item_with_info(configuration: &configuration::Configuration, params: DeleteConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteConfigStoreItemError>> {
+    crate::apis::execute(configuration, delete_config_store_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_config_store_item`] sends.
+pub(crate) fn delete_config_store_item_request(params: DeleteConfigStoreItemParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
     let config_store_item_key = params.config_store_item_key;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/item/{config_store_item_key}", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id), config_store_item_key=crate::apis::urlencode(config_store_item_key));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteConfigStoreItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/item/{config_store_item_key}", config_store_id=crate::apis::urlencode(config_store_id), config_store_item_key=crate::apis::urlencode(config_store_item_key));
+    crate::apis::Request::new("delete_config_store_item", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Retrieve a config store entry given a config store ID and item key.
-pub async fn get_config_store_item(configuration: &mut configuration::Configuration, params: GetConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<GetConfigStoreItemError>> {
-    let local_var_configuration = configuration;
+pub async fn get_config_store_item(configuration: &configuration::Configuration, params: GetConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<GetConfigStoreItemError>> {
+    get_config_store_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_config_store_item`], but also returns the status and headers of the response.
+pub async fn get_config_store_item_with_info(configuration: &configuration::Configuration, params: GetConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::ConfigStoreItemResponse>, Error<GetConfigStoreItemError>> {
+    crate::apis::execute(configuration, get_config_store_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_config_store_item`] sends.
+pub(crate) fn get_config_store_item_request(params: GetConfigStoreItemParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
     let config_store_item_key = params.config_store_item_key;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/item/{config_store_item_key}", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id), config_store_item_key=crate::apis::urlencode(config_store_item_key));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetConfigStoreItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/item/{config_store_item_key}", config_store_id=crate::apis::urlencode(config_store_id), config_store_item_key=crate::apis::urlencode(config_store_item_key));
+    crate::apis::Request::new("get_config_store_item", reqwest::Method::GET, local_var_path)
 }
 
 /// List the key-value pairs associated with a given config store ID.
-pub async fn list_config_store_items(configuration: &mut configuration::Configuration, params: ListConfigStoreItemsParams) -> Result<Vec<crate::models::ConfigStoreItemResponse>, Error<ListConfigStoreItemsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_config_store_items(configuration: &configuration::Configuration, params: ListConfigStoreItemsParams) -> Result<Vec<crate::models::ConfigStoreItemResponse>, Error<ListConfigStoreItemsError>> {
+    list_config_store_items_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_config_store_items`], but also returns the status and headers of the response.
+pub async fn list_config_store_items_with_info(configuration: &configuration::Configuration, params: ListConfigStoreItemsParams) -> Result<crate::apis::Response<Vec<crate::models::ConfigStoreItemResponse>>, Error<ListConfigStoreItemsError>> {
+    crate::apis::execute(configuration, list_config_store_items_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_config_store_items`] sends.
+pub(crate) fn list_config_store_items_request(params: ListConfigStoreItemsParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/items", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListConfigStoreItemsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/items", config_store_id=crate::apis::urlencode(config_store_id));
+    crate::apis::Request::new("list_config_store_items", reqwest::Method::GET, local_var_path)
 }
 
 /// Update an entry in a config store given a config store ID, item key, and item value.
-pub async fn update_config_store_item(configuration: &mut configuration::Configuration, params: UpdateConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<UpdateConfigStoreItemError>> {
-    let local_var_configuration = configuration;
+pub async fn update_config_store_item(configuration: &configuration::Configuration, params: UpdateConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<UpdateConfigStoreItemError>> {
+    update_config_store_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_config_store_item`], but also returns the status and headers of the response.
+pub async fn update_config_store_item_with_info(configuration: &configuration::Configuration, params: UpdateConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::ConfigStoreItemResponse>, Error<UpdateConfigStoreItemError>> {
+    crate::apis::execute(configuration, update_config_store_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_config_store_item`] sends.
+pub(crate) fn update_config_store_item_request(params: UpdateConfigStoreItemParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
     let config_store_item_key = params.config_store_item_key;
     let item_key = params.item_key;
     let item_value = params.item_value;
 
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/item/{config_store_item_key}", config_store_id=crate::apis::urlencode(config_store_id), config_store_item_key=crate::apis::urlencode(config_store_item_key));
+    let mut local_var_req = crate::apis::Request::new("update_config_store_item", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/item/{config_store_item_key}", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id), config_store_item_key=crate::apis::urlencode(config_store_item_key));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = item_key {
         local_var_form_params.insert("item_key", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = item_value {
         local_var_form_params.insert("item_value", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateConfigStoreItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Insert or update an entry in a config store given a config store ID, item key, and item value.
-pub async fn upsert_config_store_item(configuration: &mut configuration::Configuration, params: UpsertConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<UpsertConfigStoreItemError>> {
-    let local_var_configuration = configuration;
+pub async fn upsert_config_store_item(configuration: &configuration::Configuration, params: UpsertConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<UpsertConfigStoreItemError>> {
+    upsert_config_store_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`upsert_config_store_item`], but also returns the status and headers of the response.
+pub async fn upsert_config_store_item_with_info(configuration: &configuration::Configuration, params: UpsertConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::ConfigStoreItemResponse>, Error<UpsertConfigStoreItemError>> {
+    crate::apis::execute(configuration, upsert_config_store_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`upsert_config_store_item`] sends.
+pub(crate) fn upsert_config_store_item_request(params: UpsertConfigStoreItemParams) -> crate::apis::Request {
     // unbox the parameters
     let config_store_id = params.config_store_id;
     let config_store_item_key = params.config_store_item_key;
     let item_key = params.item_key;
     let item_value = params.item_value;
 
+    let local_var_path = format!("/resources/stores/config/{config_store_id}/item/{config_store_item_key}", config_store_id=crate::apis::urlencode(config_store_id), config_store_item_key=crate::apis::urlencode(config_store_item_key));
+    let mut local_var_req = crate::apis::Request::new("upsert_config_store_item", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/config/{config_store_id}/item/{config_store_item_key}", local_var_configuration.base_path, config_store_id=crate::apis::urlencode(config_store_id), config_store_item_key=crate::apis::urlencode(config_store_item_key));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = item_key {
         local_var_form_params.insert("item_key", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = item_value {
         local_var_form_params.insert("item_value", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpsertConfigStoreItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -7,7 +7,18 @@
 
 
 use reqwest;
+use std::collections::HashMap;
 use std::env;
+use std::error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::cassette::Cassette;
+use super::credential_chain::{CredentialChain, CredentialChainError};
+use super::dry_run::Plan;
+use super::redact::Secret;
+use super::token_source::TokenSource;
 
 // https://www.fastly.com/documentation/reference/api/#rate-limiting
 pub const DEFAULT_RATELIMIT: u64 = 1000;
@@ -15,51 +26,634 @@ pub const DEFAULT_RATELIMIT: u64 = 1000;
 #[derive(Debug, Clone)]
 pub struct Configuration {
     pub base_path: String,
+    /// Overrides for endpoint groups that are not served from `base_path`,
+    /// e.g. to point realtime stats at a local mock.
+    pub endpoint_base_paths: HashMap<EndpointGroup, String>,
     pub user_agent: Option<String>,
     pub client: reqwest::Client,
     pub basic_auth: Option<BasicAuth>,
-    pub oauth_access_token: Option<String>,
-    pub bearer_access_token: Option<String>,
+    pub oauth_access_token: Option<Secret>,
+    pub bearer_access_token: Option<Secret>,
     pub api_key: Option<ApiKey>,
-    pub rate_limit_remaining: u64,
-    pub rate_limit_reset: u64,
-    // TODO: take an oauth2 token source, similar to the go one
+    /// Updated from every response; shared with clones of this configuration.
+    pub rate_limit: Arc<Mutex<RateLimitState>>,
+    pub retry_policy: RetryPolicy,
+    pub throttle: Option<Throttle>,
+    /// Asked for a token before every request; takes precedence over `api_key`.
+    pub token_source: Option<Arc<dyn TokenSource>>,
+    /// Records exchanges with the API to, or replays them from, a file.
+    pub cassette: Option<Arc<Cassette>>,
+    /// When set, `POST`, `PUT`, `PATCH` and `DELETE` requests are added to
+    /// this plan instead of being sent; other requests run as usual.
+    ///
+    /// A planned call succeeds with the `Default` value of its return type
+    /// and status [`dry_run::PLANNED`](super::dry_run::PLANNED).
+    pub dry_run: Option<Plan>,
 }
 
-pub type BasicAuth = (String, Option<String>);
+pub type BasicAuth = (String, Option<Secret>);
+
+/// Endpoints served from a host other than `base_path`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointGroup {
+    /// `realtime_api`, under `/v1/channel`.
+    Realtime,
+    /// `origin_inspector_realtime_api`, under `/v1/origins`.
+    OriginInspectorRealtime,
+    /// `domain_inspector_realtime_api`, under `/v1/domains`.
+    DomainInspectorRealtime,
+}
+
+impl EndpointGroup {
+    /// Where the group is served from unless overridden.
+    pub fn default_base_path(self) -> &'static str {
+        match self {
+            EndpointGroup::Realtime | EndpointGroup::OriginInspectorRealtime | EndpointGroup::DomainInspectorRealtime => "https://rt.fastly.com",
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ApiKey {
     pub prefix: Option<String>,
-    pub key: String,
+    pub key: Secret,
 }
 
 impl Configuration {
     pub fn new() -> Configuration {
         Configuration::default()
     }
+
+    /// A default configuration whose API token comes from `FASTLY_API_TOKEN`
+    /// or, failing that, a Fastly CLI profile.
+    ///
+    /// Use [`CredentialChain`] directly to pass an explicit token, pick a
+    /// profile or read a different CLI config file.
+    pub fn from_env_chain() -> Result<Configuration, CredentialChainError> {
+        CredentialChain::new().configuration()
+    }
+
+    /// The base path for endpoints in `group`, or `base_path` for the rest.
+    pub fn base_path_for(&self, group: Option<EndpointGroup>) -> &str {
+        match group {
+            Some(group) => self.endpoint_base_paths.get(&group).map_or(group.default_base_path(), String::as_str),
+            None => &self.base_path,
+        }
+    }
+
+    /// Start building a configuration with its own HTTP client.
+    pub fn builder() -> ConfigurationBuilder {
+        ConfigurationBuilder::new()
+    }
+
+    /// The rate-limit budget reported by the most recent response that carried one.
+    pub fn rate_limit_state(&self) -> RateLimitState {
+        self.rate_limit.lock().unwrap().clone()
+    }
 }
 
-impl Default for Configuration {
+/// Builds a [`Configuration`] together with the HTTP client it uses.
+///
+/// Settings left unset take their [`Configuration::default`] values. `build`
+/// creates the only `reqwest::Client`, with the requested timeouts, proxies
+/// and TLS settings.
+#[derive(Debug)]
+pub struct ConfigurationBuilder {
+    base_path: Option<String>,
+    endpoint_base_paths: HashMap<EndpointGroup, String>,
+    user_agent: Option<String>,
+    api_key: Option<ApiKey>,
+    token_source: Option<Arc<dyn TokenSource>>,
+    retry_policy: Option<RetryPolicy>,
+    throttle: Option<Throttle>,
+    cassette: Option<Arc<Cassette>>,
+    dry_run: Option<Plan>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    no_proxy: bool,
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    built_in_root_certs: bool,
+    tls_backend: TlsBackend,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TlsBackend {
+    Default,
+    #[cfg(feature = "native-tls")]
+    NativeTls,
+    #[cfg(feature = "rustls")]
+    Rustls,
+}
+
+impl ConfigurationBuilder {
+    pub fn new() -> ConfigurationBuilder {
+        ConfigurationBuilder {
+            base_path: None,
+            endpoint_base_paths: HashMap::new(),
+            user_agent: None,
+            api_key: None,
+            token_source: None,
+            retry_policy: None,
+            throttle: None,
+            cassette: None,
+            dry_run: None,
+            connect_timeout: None,
+            timeout: None,
+            proxies: Vec::new(),
+            no_proxy: false,
+            #[cfg(any(feature = "native-tls", feature = "rustls"))]
+            root_certificates: Vec::new(),
+            #[cfg(any(feature = "native-tls", feature = "rustls"))]
+            built_in_root_certs: true,
+            tls_backend: TlsBackend::Default,
+        }
+    }
+
+    /// Send requests to `base_path` instead of `https://api.fastly.com`.
+    pub fn base_path(mut self, base_path: impl Into<String>) -> ConfigurationBuilder {
+        self.base_path = Some(base_path.into());
+        self
+    }
+
+    /// Send requests for `group` to `base_path` instead of its default host.
+    pub fn endpoint_base_path(mut self, group: EndpointGroup, base_path: impl Into<String>) -> ConfigurationBuilder {
+        self.endpoint_base_paths.insert(group, base_path.into());
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> ConfigurationBuilder {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Authenticate with this API token instead of `FASTLY_API_TOKEN`.
+    pub fn api_key(mut self, key: impl Into<Secret>) -> ConfigurationBuilder {
+        self.api_key = Some(ApiKey { prefix: None, key: key.into() });
+        self
+    }
+
+    pub fn token_source(mut self, token_source: Arc<dyn TokenSource>) -> ConfigurationBuilder {
+        self.token_source = Some(token_source);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> ConfigurationBuilder {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn throttle(mut self, throttle: Throttle) -> ConfigurationBuilder {
+        self.throttle = Some(throttle);
+        self
+    }
+
+    /// Record to or replay from `cassette`; see [`super::cassette`].
+    pub fn cassette(mut self, cassette: Arc<Cassette>) -> ConfigurationBuilder {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Collect mutating requests in `plan` instead of sending them; see
+    /// [`super::dry_run`] for what planned calls return.
+    pub fn dry_run(mut self, plan: Plan) -> ConfigurationBuilder {
+        self.dry_run = Some(plan);
+        self
+    }
+
+    /// Give up on establishing a connection after `timeout`.
+    pub fn connect_timeout(mut self, timeout: Duration) -> ConfigurationBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Give up on each attempt after `timeout`, from connecting until the
+    /// response body has been read. Retries get a fresh timeout.
+    pub fn timeout(mut self, timeout: Duration) -> ConfigurationBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Route requests through `proxy`, e.g. `reqwest::Proxy::https("http://proxy:3128")`.
+    ///
+    /// Without this, proxies are taken from the `HTTPS_PROXY` family of
+    /// environment variables.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> ConfigurationBuilder {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Ignore proxy environment variables and connect directly.
+    pub fn no_proxy(mut self) -> ConfigurationBuilder {
+        self.no_proxy = true;
+        self
+    }
+
+    /// Trust `certificate`, e.g. a corporate root CA, in addition to the
+    /// built-in roots. Load it with `reqwest::Certificate::from_pem`.
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> ConfigurationBuilder {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Whether to trust the backend's built-in root certificates. Turn this
+    /// off to trust only the certificates added with `add_root_certificate`.
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    pub fn tls_built_in_root_certs(mut self, enabled: bool) -> ConfigurationBuilder {
+        self.built_in_root_certs = enabled;
+        self
+    }
+
+    /// Use the platform's TLS library (OpenSSL, Secure Transport or SChannel).
+    #[cfg(feature = "native-tls")]
+    pub fn use_native_tls(mut self) -> ConfigurationBuilder {
+        self.tls_backend = TlsBackend::NativeTls;
+        self
+    }
+
+    /// Use rustls.
+    #[cfg(feature = "rustls")]
+    pub fn use_rustls_tls(mut self) -> ConfigurationBuilder {
+        self.tls_backend = TlsBackend::Rustls;
+        self
+    }
+
+    pub fn build(self) -> Result<Configuration, reqwest::Error> {
+        let mut client = reqwest::Client::builder();
+        if let Some(timeout) = self.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        if self.no_proxy {
+            client = client.no_proxy();
+        }
+        for proxy in self.proxies {
+            client = client.proxy(proxy);
+        }
+        #[cfg(any(feature = "native-tls", feature = "rustls"))]
+        {
+            for certificate in self.root_certificates {
+                client = client.add_root_certificate(certificate);
+            }
+            client = client.tls_built_in_root_certs(self.built_in_root_certs);
+        }
+        client = match self.tls_backend {
+            TlsBackend::Default => client,
+            #[cfg(feature = "native-tls")]
+            TlsBackend::NativeTls => client.use_native_tls(),
+            #[cfg(feature = "rustls")]
+            TlsBackend::Rustls => client.use_rustls_tls(),
+        };
+
+        let defaults = Configuration::with_client(client.build()?);
+        Ok(Configuration {
+            base_path: self.base_path.unwrap_or(defaults.base_path),
+            endpoint_base_paths: self.endpoint_base_paths,
+            user_agent: self.user_agent.or(defaults.user_agent),
+            api_key: self.api_key.or(defaults.api_key),
+            token_source: self.token_source,
+            retry_policy: self.retry_policy.unwrap_or(defaults.retry_policy),
+            throttle: self.throttle,
+            cassette: self.cassette,
+            dry_run: self.dry_run,
+            ..defaults
+        })
+    }
+}
+
+impl Default for ConfigurationBuilder {
+    fn default() -> Self {
+        ConfigurationBuilder::new()
+    }
+}
+
+/// Rate-limit budget as reported by the `Fastly-RateLimit-*` response headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitState {
+    /// Requests left in the current window.
+    pub remaining: u64,
+    /// When the current window ends and the budget is replenished.
+    pub reset: Option<SystemTime>,
+    /// When a response last carried rate-limit headers.
+    pub last_updated: Option<SystemTime>,
+    /// Set when the latest response carried a header that could not be parsed.
+    /// The other fields keep their previous values in that case.
+    pub last_error: Option<RateLimitHeaderError>,
+}
+
+impl Default for RateLimitState {
+    fn default() -> Self {
+        RateLimitState {
+            remaining: DEFAULT_RATELIMIT,
+            reset: None,
+            last_updated: None,
+            last_error: None,
+        }
+    }
+}
+
+impl RateLimitState {
+    /// Record the rate-limit headers of a response received at `now`, and
+    /// return whether the budget was refreshed.
+    ///
+    /// Responses without rate-limit headers (such as most `GET`s) leave the
+    /// state untouched.
+    pub fn update(&mut self, headers: &reqwest::header::HeaderMap, now: SystemTime) -> bool {
+        let remaining = parse_header(headers, "Fastly-RateLimit-Remaining");
+        let reset = parse_header(headers, "Fastly-RateLimit-Reset");
+
+        match (remaining, reset) {
+            (Ok(None), Ok(None)) => false,
+            (Ok(remaining), Ok(reset)) => {
+                if let Some(remaining) = remaining {
+                    self.remaining = remaining;
+                }
+                if let Some(reset) = reset {
+                    self.reset = Some(UNIX_EPOCH + Duration::from_secs(reset));
+                }
+                self.last_updated = Some(now);
+                self.last_error = None;
+                true
+            }
+            (Err(e), _) | (_, Err(e)) => {
+                self.last_error = Some(e);
+                false
+            }
+        }
+    }
+
+    /// Time left until the budget is replenished, or `None` if the reset time
+    /// is unknown or already past.
+    pub fn until_reset(&self, now: SystemTime) -> Option<Duration> {
+        self.reset.and_then(|reset| reset.duration_since(now).ok())
+    }
+}
+
+/// Client-side throttle for mutating requests.
+///
+/// The throttle keeps a token bucket seeded from the server-reported budget and
+/// spends one token per `POST`, `PUT`, `PATCH` or `DELETE` before it is sent.
+/// Once the bucket is down to `reserve` tokens, further mutating calls wait
+/// until the rate-limit window resets. Clones share one bucket, so every clone
+/// of a [`Configuration`] draws from the same budget.
+///
+/// Mutating requests that have not completed yet are kept out of the bucket
+/// when it is resynchronized, since the reported budget does not include them.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    reserve: u64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: u64,
+    reset: Option<SystemTime>,
+    /// Requests let through that have not completed.
+    in_flight: u64,
+}
+
+/// A token taken from a [`Throttle`]; the request counts as in flight until
+/// this is dropped.
+#[derive(Debug)]
+pub struct ThrottlePermit {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+impl Drop for ThrottlePermit {
+    fn drop(&mut self) {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.in_flight = bucket.in_flight.saturating_sub(1);
+    }
+}
+
+impl Throttle {
+    /// Throttle once no more than `reserve` requests are left in the budget.
+    ///
+    /// The bucket starts full, at [`DEFAULT_RATELIMIT`]; use [`Throttle::seeded`]
+    /// when the budget is already known.
+    pub fn new(reserve: u64) -> Throttle {
+        Throttle::seeded(reserve, &RateLimitState::default())
+    }
+
+    /// Like [`Throttle::new`], with the bucket starting from the budget in
+    /// `state`, e.g. [`Configuration::rate_limit_state`].
+    pub fn seeded(reserve: u64, state: &RateLimitState) -> Throttle {
+        Throttle {
+            reserve,
+            bucket: Arc::new(Mutex::new(Bucket { tokens: state.remaining, reset: state.reset, in_flight: 0 })),
+        }
+    }
+
+    pub fn reserve(&self) -> u64 {
+        self.reserve
+    }
+
+    /// Tokens currently left in the shared bucket.
+    pub fn available(&self) -> u64 {
+        self.bucket.lock().unwrap().tokens
+    }
+
+    /// Wait until a token is available and take it.
+    pub async fn acquire(&self) -> ThrottlePermit {
+        while let Some(wait) = self.try_acquire(SystemTime::now()) {
+            tokio::time::sleep(wait).await;
+        }
+        ThrottlePermit { bucket: self.bucket.clone() }
+    }
+
+    /// Take a token, or return how long to wait before trying again.
+    fn try_acquire(&self, now: SystemTime) -> Option<Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        if bucket.reset.is_some_and(|reset| reset <= now) {
+            bucket.tokens = DEFAULT_RATELIMIT;
+            bucket.reset = None;
+        }
+        if bucket.tokens > self.reserve {
+            bucket.tokens -= 1;
+        } else if let Some(reset) = bucket.reset {
+            return Some(reset.duration_since(now).unwrap_or_default());
+        }
+        // Without a known reset time there is nothing to wait for, so the
+        // request goes ahead and still counts as in flight.
+        bucket.in_flight += 1;
+        None
+    }
+
+    /// Resynchronize the bucket with the budget the server reported, less
+    /// the tokens of requests still in flight.
+    pub fn observe(&self, state: &RateLimitState) {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.tokens = state.remaining.saturating_sub(bucket.in_flight);
+        bucket.reset = state.reset;
+    }
+}
+
+/// When and how failed requests are retried.
+///
+/// A request is retried after a connection failure, a timeout, or a `429`,
+/// `502`, `503` or `504` response. A `429` waits for `Retry-After` or, failing
+/// that, the rate-limit reset time; other failures back off exponentially with
+/// jitter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts per call, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every further attempt.
+    pub initial_backoff: Duration,
+    /// Upper bound for a single backoff delay.
+    pub max_backoff: Duration,
+    /// Longest server-requested wait (`Retry-After` or rate-limit reset) that
+    /// is honored, one minute by default. Calls that would have to wait longer
+    /// fail right away.
+    /// `None` waits however long the server asks, which for a spent hourly
+    /// budget can be most of an hour, on every attempt.
+    pub max_wait: Option<Duration>,
+    /// Also retry `POST` and `PATCH` requests. These are not idempotent, so a
+    /// retry may repeat a change the server already applied.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
     fn default() -> Self {
-        let api_key = match env::var("FASTLY_API_TOKEN") {
-          Ok(key) => key,
-          Err(_) => "".to_string(),
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_wait: Some(Duration::from_secs(60)),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn never() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Whether requests with `method` may be retried at all.
+    pub fn allows_method(&self, method: &reqwest::Method) -> bool {
+        self.retry_non_idempotent || (method != reqwest::Method::POST && method != reqwest::Method::PATCH)
+    }
+
+    /// Jittered exponential backoff before retry number `attempt` (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.initial_backoff.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+        let ceiling = exp.min(self.max_backoff);
+        // Equal jitter: somewhere between half and all of the ceiling.
+        let half = ceiling / 2;
+        half + half.mul_f64(jitter())
+    }
+
+    pub(crate) fn delay_for_response(&self, response: &reqwest::Response, rate_limit: &RateLimitState, attempt: u32) -> Option<Duration> {
+        let status = response.status();
+        let requested = match status.as_u16() {
+            429 => retry_after(response.headers()).or_else(|| rate_limit.until_reset(SystemTime::now())),
+            502..=504 => retry_after(response.headers()),
+            _ => return None,
         };
+        match requested {
+            Some(wait) if self.max_wait.is_some_and(|max_wait| wait > max_wait) => None,
+            Some(wait) => Some(wait),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+
+    pub(crate) fn delay_for_error(&self, error: &reqwest::Error, attempt: u32) -> Option<Duration> {
+        if error.is_connect() || error.is_timeout() {
+            Some(self.backoff(attempt))
+        } else {
+            None
+        }
+    }
+}
+
+/// The `Retry-After` header in its delay-seconds form.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+/// A random fraction in `[0, 1)`, seeded from the standard library's per-hasher random keys.
+fn jitter() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn parse_header(headers: &reqwest::header::HeaderMap, name: &'static str) -> Result<Option<u64>, RateLimitHeaderError> {
+    match headers.get(name) {
+        None => Ok(None),
+        Some(v) => v
+            .to_str()
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .map(Some)
+            .ok_or_else(|| RateLimitHeaderError {
+                header: name,
+                value: String::from_utf8_lossy(v.as_bytes()).into_owned(),
+            }),
+    }
+}
+
+/// A rate-limit response header that was present but not a valid integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitHeaderError {
+    pub header: &'static str,
+    pub value: String,
+}
+
+impl fmt::Display for RateLimitHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} header: {:?}", self.header, self.value)
+    }
+}
+
+impl error::Error for RateLimitHeaderError {}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration::with_client(reqwest::Client::new())
+    }
+}
+
+impl Configuration {
+    /// The default settings around an already built `client`.
+    fn with_client(client: reqwest::Client) -> Configuration {
+        let api_key = env::var("FASTLY_API_TOKEN").ok().filter(|key| !key.is_empty());
 
         Configuration {
             base_path: "https://api.fastly.com".to_owned(),
+            endpoint_base_paths: HashMap::new(),
             user_agent: Some("fastly-rust/4.10.0/rust".to_owned()),
-            client: reqwest::Client::new(),
+            client,
             basic_auth: None,
             oauth_access_token: None,
             bearer_access_token: None,
-            api_key: Some(ApiKey {
+            api_key: api_key.map(|key| ApiKey {
               prefix: None,
-              key: api_key,
+              key: key.into(),
             }),
-            rate_limit_remaining: DEFAULT_RATELIMIT,
-            rate_limit_reset: 0,
+            rate_limit: Arc::new(Mutex::new(RateLimitState::default())),
+            retry_policy: RetryPolicy::default(),
+            throttle: None,
+            token_source: None,
+            cassette: None,
+            dry_run: None,
         }
     }
 }
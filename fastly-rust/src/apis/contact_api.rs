@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_contacts`]
@@ -70,9 +69,17 @@ pub enum ListContactsError {
 
 
 /// Create a contact.
-pub async fn create_contacts(configuration: &mut configuration::Configuration, params: CreateContactsParams) -> Result<crate::models::ContactResponse, Error<CreateContactsError>> {
-    let local_var_configuration = configuration;
+pub async fn create_contacts(configuration: &configuration::Configuration, params: CreateContactsParams) -> Result<crate::models::ContactResponse, Error<CreateContactsError>> {
+    create_contacts_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_contacts`], but also returns the status and headers of the response.
+pub async fn create_contacts_with_info(configuration: &configuration::Configuration, params: CreateContactsParams) -> Result<crate::apis::Response<crate::models::ContactResponse>, Error<CreateContactsError>> {
+    crate::apis::execute(configuration, create_contacts_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_contacts`] sends.
+pub(crate) fn create_contacts_request(params: CreateContactsParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
     let user_id = params.user_id;
@@ -82,24 +89,10 @@ pub async fn create_contacts(configuration: &mut configuration::Configuration, p
     let phone = params.phone;
     let customer_id2 = params.customer_id2;
 
+    let local_var_path = format!("/customer/{customer_id}/contacts", customer_id=crate::apis::urlencode(customer_id));
+    let mut local_var_req = crate::apis::Request::new("create_contacts", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/contacts", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = user_id {
         local_var_form_params.insert("user_id", local_var_param_value.to_string());
     }
@@ -118,137 +111,47 @@ pub async fn create_contacts(configuration: &mut configuration::Configuration, p
     if let Some(local_var_param_value) = customer_id2 {
         local_var_form_params.insert("customer_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateContactsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a contact.
-pub async fn delete_contact(configuration: &mut configuration::Configuration, params: DeleteContactParams) -> Result<crate::models::InlineResponse200, Error<DeleteContactError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_contact(configuration: &configuration::Configuration, params: DeleteContactParams) -> Result<crate::models::InlineResponse200, Error<DeleteContactError>> {
+    delete_contact_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_contact`], but also returns the status and headers of the response.
+pub async fn delete_contact_with_info(configuration: &configuration::Configuration, params: DeleteContactParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteContactError>> {
+    crate::apis::execute(configuration, delete_contact_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_contact`] sends.
+pub(crate) fn delete_contact_request(params: DeleteContactParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
     let contact_id = params.contact_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/contact/{contact_id}", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id), contact_id=crate::apis::urlencode(contact_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteContactError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/customer/{customer_id}/contact/{contact_id}", customer_id=crate::apis::urlencode(customer_id), contact_id=crate::apis::urlencode(contact_id));
+    crate::apis::Request::new("delete_contact", reqwest::Method::DELETE, local_var_path)
 }
 
 /// List all contacts from a specified customer ID.
-pub async fn list_contacts(configuration: &mut configuration::Configuration, params: ListContactsParams) -> Result<Vec<crate::models::SchemasContactResponse>, Error<ListContactsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_contacts(configuration: &configuration::Configuration, params: ListContactsParams) -> Result<Vec<crate::models::SchemasContactResponse>, Error<ListContactsError>> {
+    list_contacts_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_contacts`], but also returns the status and headers of the response.
+pub async fn list_contacts_with_info(configuration: &configuration::Configuration, params: ListContactsParams) -> Result<crate::apis::Response<Vec<crate::models::SchemasContactResponse>>, Error<ListContactsError>> {
+    crate::apis::execute(configuration, list_contacts_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_contacts`] sends.
+pub(crate) fn list_contacts_request(params: ListContactsParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/contacts", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListContactsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/customer/{customer_id}/contacts", customer_id=crate::apis::urlencode(customer_id));
+    crate::apis::Request::new("list_contacts", reqwest::Method::GET, local_var_path)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`content_check`]
@@ -28,57 +27,27 @@ pub enum ContentCheckError {
 
 
 /// Retrieve headers and MD5 hash of the content for a particular URL from each Fastly edge server. This API is limited to 200 requests per hour. If the content takes too long to download, the hash will be set to `error-timeout-$pop`. If the response is too large, it will be set to `warning-too-large-$pop`.
-pub async fn content_check(configuration: &mut configuration::Configuration, params: ContentCheckParams) -> Result<Vec<crate::models::Content>, Error<ContentCheckError>> {
-    let local_var_configuration = configuration;
+pub async fn content_check(configuration: &configuration::Configuration, params: ContentCheckParams) -> Result<Vec<crate::models::Content>, Error<ContentCheckError>> {
+    content_check_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`content_check`], but also returns the status and headers of the response.
+pub async fn content_check_with_info(configuration: &configuration::Configuration, params: ContentCheckParams) -> Result<crate::apis::Response<Vec<crate::models::Content>>, Error<ContentCheckError>> {
+    crate::apis::execute(configuration, content_check_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`content_check`] sends.
+pub(crate) fn content_check_request(params: ContentCheckParams) -> crate::apis::Request {
     // unbox the parameters
     let url = params.url;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/content/edge_check", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/content/edge_check");
+    let mut local_var_req = crate::apis::Request::new("content_check", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = url {
-        local_var_req_builder = local_var_req_builder.query(&[("url", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("url", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ContentCheckError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
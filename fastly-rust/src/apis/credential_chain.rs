@@ -0,0 +1,182 @@
+//! Resolving the API token from explicit values, the environment and Fastly CLI profiles.
+//!
+//! Sources are checked in order, and the first one that yields a non-empty
+//! token wins:
+//!
+//! 1. a token passed to [`CredentialChain::token`], or else the Fastly CLI
+//!    profile passed to [`CredentialChain::profile`]. Either is an explicit
+//!    choice, so the chain stops here if it has no token;
+//! 2. the `FASTLY_API_TOKEN` environment variable;
+//! 3. the Fastly CLI profile named by `FASTLY_PROFILE`, else the one marked
+//!    `default = true`.
+//!
+//! Profiles are read from `$XDG_CONFIG_HOME/fastly/config.toml` or
+//! `~/.config/fastly/config.toml` unless [`CredentialChain::config_path`] says
+//! otherwise.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use super::configuration::{ApiKey, Configuration};
+use super::redact::Secret;
+
+pub const TOKEN_ENV: &str = "FASTLY_API_TOKEN";
+pub const PROFILE_ENV: &str = "FASTLY_PROFILE";
+
+#[derive(Debug, Clone, Default)]
+pub struct CredentialChain {
+    token: Option<Secret>,
+    profile: Option<String>,
+    config_path: Option<PathBuf>,
+}
+
+/// A source the chain looked at, and why it did not yield a token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckedSource {
+    pub source: String,
+    pub reason: String,
+}
+
+/// None of the sources in a [`CredentialChain`] yielded a token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CredentialChainError {
+    pub checked: Vec<CheckedSource>,
+}
+
+impl fmt::Display for CredentialChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no Fastly API token found; checked")?;
+        for (i, checked) in self.checked.iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            write!(f, "{}{} ({})", sep, checked.source, checked.reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for CredentialChainError {}
+
+#[derive(Deserialize)]
+struct CliConfig {
+    #[serde(default)]
+    profile: BTreeMap<String, CliProfile>,
+}
+
+#[derive(Deserialize)]
+struct CliProfile {
+    #[serde(default)]
+    default: bool,
+    token: Option<Secret>,
+}
+
+impl CredentialChain {
+    pub fn new() -> CredentialChain {
+        CredentialChain::default()
+    }
+
+    /// Use `token`, ignoring the other sources.
+    pub fn token(mut self, token: impl Into<Secret>) -> CredentialChain {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Use the token of this Fastly CLI profile, ignoring `FASTLY_API_TOKEN`,
+    /// `FASTLY_PROFILE` and the default profile.
+    pub fn profile(mut self, profile: impl Into<String>) -> CredentialChain {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Read Fastly CLI profiles from `path` instead of the default location.
+    pub fn config_path(mut self, path: impl Into<PathBuf>) -> CredentialChain {
+        self.config_path = Some(path.into());
+        self
+    }
+
+    /// The token from the first source that has one.
+    pub fn resolve(&self) -> Result<Secret, CredentialChainError> {
+        let mut checked = Vec::new();
+
+        match self.token {
+            Some(ref token) if !token.expose().is_empty() => return Ok(token.clone()),
+            Some(_) => checked.push(CheckedSource::new("explicit token", "empty")),
+            None => checked.push(CheckedSource::new("explicit token", "not set")),
+        }
+
+        if let Some(ref profile) = self.profile {
+            return self.profile_token(Some(profile.clone())).map_err(|source| {
+                checked.push(source);
+                CredentialChainError { checked }
+            });
+        }
+
+        match env::var(TOKEN_ENV) {
+            Ok(token) if !token.is_empty() => return Ok(token.into()),
+            Ok(_) => checked.push(CheckedSource::new(TOKEN_ENV, "empty")),
+            Err(_) => checked.push(CheckedSource::new(TOKEN_ENV, "not set")),
+        }
+
+        match self.profile_token(env::var(PROFILE_ENV).ok().filter(|p| !p.is_empty())) {
+            Ok(token) => return Ok(token),
+            Err(source) => checked.push(source),
+        }
+
+        Err(CredentialChainError { checked })
+    }
+
+    /// A default [`Configuration`] using the resolved token as its `api_key`.
+    pub fn configuration(&self) -> Result<Configuration, CredentialChainError> {
+        let key = self.resolve()?;
+        Ok(Configuration {
+            api_key: Some(ApiKey { prefix: None, key }),
+            ..Default::default()
+        })
+    }
+
+    /// The token of the `requested` profile, or of the default one.
+    fn profile_token(&self, requested: Option<String>) -> Result<Secret, CheckedSource> {
+        let path = match self.config_path.clone().or_else(default_config_path) {
+            Some(path) => path,
+            None => return Err(CheckedSource::new("Fastly CLI profile", "no home directory to find config.toml in")),
+        };
+        let source = match requested {
+            Some(ref name) => format!("Fastly CLI profile {:?} in {}", name, path.display()),
+            None => format!("default Fastly CLI profile in {}", path.display()),
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Err(CheckedSource::new(source, "file not found")),
+            Err(e) => return Err(CheckedSource::new(source, e.to_string())),
+        };
+        let config: CliConfig = toml::from_str(&contents).map_err(|e| CheckedSource::new(&source, format!("invalid TOML: {}", e.message())))?;
+
+        let profile = match requested {
+            Some(ref name) => config.profile.get(name).ok_or_else(|| CheckedSource::new(&source, "no such profile"))?,
+            None => config.profile.values().find(|p| p.default).ok_or_else(|| CheckedSource::new(&source, "no profile is marked default"))?,
+        };
+        match profile.token {
+            Some(ref token) if !token.expose().is_empty() => Ok(token.clone()),
+            _ => Err(CheckedSource::new(source, "profile has no token")),
+        }
+    }
+}
+
+impl CheckedSource {
+    fn new(source: impl Into<String>, reason: impl Into<String>) -> CheckedSource {
+        CheckedSource { source: source.into(), reason: reason.into() }
+    }
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").filter(|dir| !dir.is_empty()).map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("fastly").join("config.toml"))
+}
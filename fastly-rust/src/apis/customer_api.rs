@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`delete_customer`]
@@ -125,216 +124,90 @@ pub enum UpdateCustomerError {
 
 
 /// Delete a customer.
-pub async fn delete_customer(configuration: &mut configuration::Configuration, params: DeleteCustomerParams) -> Result<crate::models::InlineResponse200, Error<DeleteCustomerError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_customer(configuration: &configuration::Configuration, params: DeleteCustomerParams) -> Result<crate::models::InlineResponse200, Error<DeleteCustomerError>> {
+    delete_customer_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_customer`], but also returns the status and headers of the response.
+pub async fn delete_customer_with_info(configuration: &configuration::Configuration, params: DeleteCustomerParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteCustomerError>> {
+    crate::apis::execute(configuration, delete_customer_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_customer`] sends.
+pub(crate) fn delete_customer_request(params: DeleteCustomerParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteCustomerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/customer/{customer_id}", customer_id=crate::apis::urlencode(customer_id));
+    crate::apis::Request::new("delete_customer", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a specific customer.
-pub async fn get_customer(configuration: &mut configuration::Configuration, params: GetCustomerParams) -> Result<crate::models::CustomerResponse, Error<GetCustomerError>> {
-    let local_var_configuration = configuration;
+pub async fn get_customer(configuration: &configuration::Configuration, params: GetCustomerParams) -> Result<crate::models::CustomerResponse, Error<GetCustomerError>> {
+    get_customer_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_customer`], but also returns the status and headers of the response.
+pub async fn get_customer_with_info(configuration: &configuration::Configuration, params: GetCustomerParams) -> Result<crate::apis::Response<crate::models::CustomerResponse>, Error<GetCustomerError>> {
+    crate::apis::execute(configuration, get_customer_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_customer`] sends.
+pub(crate) fn get_customer_request(params: GetCustomerParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCustomerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/customer/{customer_id}", customer_id=crate::apis::urlencode(customer_id));
+    crate::apis::Request::new("get_customer", reqwest::Method::GET, local_var_path)
 }
 
 /// Get the logged in customer.
-pub async fn get_logged_in_customer(configuration: &mut configuration::Configuration) -> Result<crate::models::CustomerResponse, Error<GetLoggedInCustomerError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
-
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/current_customer", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+pub async fn get_logged_in_customer(configuration: &configuration::Configuration) -> Result<crate::models::CustomerResponse, Error<GetLoggedInCustomerError>> {
+    get_logged_in_customer_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+/// Like [`get_logged_in_customer`], but also returns the status and headers of the response.
+pub async fn get_logged_in_customer_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::CustomerResponse>, Error<GetLoggedInCustomerError>> {
+    crate::apis::execute(configuration, get_logged_in_customer_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLoggedInCustomerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`get_logged_in_customer`] sends.
+pub(crate) fn get_logged_in_customer_request() -> crate::apis::Request {
+    let local_var_path = String::from("/current_customer");
+    crate::apis::Request::new("get_logged_in_customer", reqwest::Method::GET, local_var_path)
 }
 
 /// List all users from a specified customer id.
-pub async fn list_users(configuration: &mut configuration::Configuration, params: ListUsersParams) -> Result<Vec<crate::models::SchemasUserResponse>, Error<ListUsersError>> {
-    let local_var_configuration = configuration;
+pub async fn list_users(configuration: &configuration::Configuration, params: ListUsersParams) -> Result<Vec<crate::models::SchemasUserResponse>, Error<ListUsersError>> {
+    list_users_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_users`], but also returns the status and headers of the response.
+pub async fn list_users_with_info(configuration: &configuration::Configuration, params: ListUsersParams) -> Result<crate::apis::Response<Vec<crate::models::SchemasUserResponse>>, Error<ListUsersError>> {
+    crate::apis::execute(configuration, list_users_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_users`] sends.
+pub(crate) fn list_users_request(params: ListUsersParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/users", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListUsersError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/customer/{customer_id}/users", customer_id=crate::apis::urlencode(customer_id));
+    crate::apis::Request::new("list_users", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a customer.
-pub async fn update_customer(configuration: &mut configuration::Configuration, params: UpdateCustomerParams) -> Result<crate::models::CustomerResponse, Error<UpdateCustomerError>> {
-    let local_var_configuration = configuration;
+pub async fn update_customer(configuration: &configuration::Configuration, params: UpdateCustomerParams) -> Result<crate::models::CustomerResponse, Error<UpdateCustomerError>> {
+    update_customer_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_customer`], but also returns the status and headers of the response.
+pub async fn update_customer_with_info(configuration: &configuration::Configuration, params: UpdateCustomerParams) -> Result<crate::apis::Response<crate::models::CustomerResponse>, Error<UpdateCustomerError>> {
+    crate::apis::execute(configuration, update_customer_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_customer`] sends.
+pub(crate) fn update_customer_request(params: UpdateCustomerParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
     let billing_contact_id = params.billing_contact_id;
@@ -362,24 +235,10 @@ pub async fn update_customer(configuration: &mut configuration::Configuration, p
     let security_contact_id = params.security_contact_id;
     let technical_contact_id = params.technical_contact_id;
 
+    let local_var_path = format!("/customer/{customer_id}", customer_id=crate::apis::urlencode(customer_id));
+    let mut local_var_req = crate::apis::Request::new("update_customer", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = billing_contact_id {
         local_var_form_params.insert("billing_contact_id", local_var_param_value.to_string());
     }
@@ -452,32 +311,8 @@ pub async fn update_customer(configuration: &mut configuration::Configuration, p
     if let Some(local_var_param_value) = technical_contact_id {
         local_var_form_params.insert("technical_contact_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateCustomerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
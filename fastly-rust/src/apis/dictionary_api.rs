@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_dictionary`]
@@ -108,233 +107,112 @@ pub enum UpdateDictionaryError {
 
 
 /// Create named dictionary for a particular service and version.
-pub async fn create_dictionary(configuration: &mut configuration::Configuration, params: CreateDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<CreateDictionaryError>> {
-    let local_var_configuration = configuration;
+pub async fn create_dictionary(configuration: &configuration::Configuration, params: CreateDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<CreateDictionaryError>> {
+    create_dictionary_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_dictionary`], but also returns the status and headers of the response.
+pub async fn create_dictionary_with_info(configuration: &configuration::Configuration, params: CreateDictionaryParams) -> Result<crate::apis::Response<crate::models::DictionaryResponse>, Error<CreateDictionaryError>> {
+    crate::apis::execute(configuration, create_dictionary_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_dictionary`] sends.
+pub(crate) fn create_dictionary_request(params: CreateDictionaryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let name = params.name;
     let write_only = params.write_only;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/dictionary", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_dictionary", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/dictionary", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = write_only {
         local_var_form_params.insert("write_only", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateDictionaryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete named dictionary for a particular service and version.
-pub async fn delete_dictionary(configuration: &mut configuration::Configuration, params: DeleteDictionaryParams) -> Result<crate::models::InlineResponse200, Error<DeleteDictionaryError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_dictionary(configuration: &configuration::Configuration, params: DeleteDictionaryParams) -> Result<crate::models::InlineResponse200, Error<DeleteDictionaryError>> {
+    delete_dictionary_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_dictionary`], but also returns the status and headers of the response.
+pub async fn delete_dictionary_with_info(configuration: &configuration::Configuration, params: DeleteDictionaryParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDictionaryError>> {
+    crate::apis::execute(configuration, delete_dictionary_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_dictionary`] sends.
+pub(crate) fn delete_dictionary_request(params: DeleteDictionaryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let dictionary_name = params.dictionary_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/dictionary/{dictionary_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, dictionary_name=crate::apis::urlencode(dictionary_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteDictionaryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/dictionary/{dictionary_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, dictionary_name=crate::apis::urlencode(dictionary_name));
+    crate::apis::Request::new("delete_dictionary", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Retrieve a single dictionary by name for the version and service.
-pub async fn get_dictionary(configuration: &mut configuration::Configuration, params: GetDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<GetDictionaryError>> {
-    let local_var_configuration = configuration;
+pub async fn get_dictionary(configuration: &configuration::Configuration, params: GetDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<GetDictionaryError>> {
+    get_dictionary_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_dictionary`], but also returns the status and headers of the response.
+pub async fn get_dictionary_with_info(configuration: &configuration::Configuration, params: GetDictionaryParams) -> Result<crate::apis::Response<crate::models::DictionaryResponse>, Error<GetDictionaryError>> {
+    crate::apis::execute(configuration, get_dictionary_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_dictionary`] sends.
+pub(crate) fn get_dictionary_request(params: GetDictionaryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let dictionary_name = params.dictionary_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/dictionary/{dictionary_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, dictionary_name=crate::apis::urlencode(dictionary_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetDictionaryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/dictionary/{dictionary_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, dictionary_name=crate::apis::urlencode(dictionary_name));
+    crate::apis::Request::new("get_dictionary", reqwest::Method::GET, local_var_path)
 }
 
 /// List all dictionaries for the version of the service.
-pub async fn list_dictionaries(configuration: &mut configuration::Configuration, params: ListDictionariesParams) -> Result<Vec<crate::models::DictionaryResponse>, Error<ListDictionariesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_dictionaries(configuration: &configuration::Configuration, params: ListDictionariesParams) -> Result<Vec<crate::models::DictionaryResponse>, Error<ListDictionariesError>> {
+    list_dictionaries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_dictionaries`], but also returns the status and headers of the response.
+pub async fn list_dictionaries_with_info(configuration: &configuration::Configuration, params: ListDictionariesParams) -> Result<crate::apis::Response<Vec<crate::models::DictionaryResponse>>, Error<ListDictionariesError>> {
+    crate::apis::execute(configuration, list_dictionaries_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_dictionaries`] sends.
+pub(crate) fn list_dictionaries_request(params: ListDictionariesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/dictionary", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListDictionariesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/dictionary", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_dictionaries", reqwest::Method::GET, local_var_path)
 }
 
 /// Update named dictionary for a particular service and version.
-pub async fn update_dictionary(configuration: &mut configuration::Configuration, params: UpdateDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<UpdateDictionaryError>> {
-    let local_var_configuration = configuration;
+pub async fn update_dictionary(configuration: &configuration::Configuration, params: UpdateDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<UpdateDictionaryError>> {
+    update_dictionary_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_dictionary`], but also returns the status and headers of the response.
+pub async fn update_dictionary_with_info(configuration: &configuration::Configuration, params: UpdateDictionaryParams) -> Result<crate::apis::Response<crate::models::DictionaryResponse>, Error<UpdateDictionaryError>> {
+    crate::apis::execute(configuration, update_dictionary_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_dictionary`] sends.
+pub(crate) fn update_dictionary_request(params: UpdateDictionaryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -342,56 +220,18 @@ pub async fn update_dictionary(configuration: &mut configuration::Configuration,
     let name = params.name;
     let write_only = params.write_only;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/dictionary/{dictionary_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, dictionary_name=crate::apis::urlencode(dictionary_name));
+    let mut local_var_req = crate::apis::Request::new("update_dictionary", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/dictionary/{dictionary_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, dictionary_name=crate::apis::urlencode(dictionary_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = write_only {
         local_var_form_params.insert("write_only", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateDictionaryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
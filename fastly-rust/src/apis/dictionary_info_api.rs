@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_dictionary_info`]
@@ -32,56 +31,23 @@ pub enum GetDictionaryInfoError {
 
 
 /// Retrieve metadata for a single dictionary by ID for a version and service.
-pub async fn get_dictionary_info(configuration: &mut configuration::Configuration, params: GetDictionaryInfoParams) -> Result<crate::models::DictionaryInfoResponse, Error<GetDictionaryInfoError>> {
-    let local_var_configuration = configuration;
+pub async fn get_dictionary_info(configuration: &configuration::Configuration, params: GetDictionaryInfoParams) -> Result<crate::models::DictionaryInfoResponse, Error<GetDictionaryInfoError>> {
+    get_dictionary_info_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_dictionary_info`], but also returns the status and headers of the response.
+pub async fn get_dictionary_info_with_info(configuration: &configuration::Configuration, params: GetDictionaryInfoParams) -> Result<crate::apis::Response<crate::models::DictionaryInfoResponse>, Error<GetDictionaryInfoError>> {
+    crate::apis::execute(configuration, get_dictionary_info_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_dictionary_info`] sends.
+pub(crate) fn get_dictionary_info_request(params: GetDictionaryInfoParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let dictionary_id = params.dictionary_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/dictionary/{dictionary_id}/info", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, dictionary_id=crate::apis::urlencode(dictionary_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetDictionaryInfoError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/dictionary/{dictionary_id}/info", service_id=crate::apis::urlencode(service_id), version_id=version_id, dictionary_id=crate::apis::urlencode(dictionary_id));
+    crate::apis::Request::new("get_dictionary_info", reqwest::Method::GET, local_var_path)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`bulk_update_dictionary_item`]
@@ -155,235 +154,117 @@ pub enum UpsertDictionaryItemError {
 
 
 /// Update multiple items in the same dictionary. For faster updates to your service, group your changes into large batches. The maximum batch size is 1000 items. [Contact support](https://support.fastly.com/) to discuss raising this limit.
-pub async fn bulk_update_dictionary_item(configuration: &mut configuration::Configuration, params: BulkUpdateDictionaryItemParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateDictionaryItemError>> {
-    let local_var_configuration = configuration;
+pub async fn bulk_update_dictionary_item(configuration: &configuration::Configuration, params: BulkUpdateDictionaryItemParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateDictionaryItemError>> {
+    bulk_update_dictionary_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`bulk_update_dictionary_item`], but also returns the status and headers of the response.
+pub async fn bulk_update_dictionary_item_with_info(configuration: &configuration::Configuration, params: BulkUpdateDictionaryItemParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<BulkUpdateDictionaryItemError>> {
+    crate::apis::execute(configuration, bulk_update_dictionary_item_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`bulk_update_dictionary_item`] sends.
+pub(crate) fn bulk_update_dictionary_item_request(params: BulkUpdateDictionaryItemParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let dictionary_id = params.dictionary_id;
     let bulk_update_dictionary_list_request = params.bulk_update_dictionary_list_request;
 
+    let local_var_path = format!("/service/{service_id}/dictionary/{dictionary_id}/items", service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id));
+    let mut local_var_req = crate::apis::Request::new("bulk_update_dictionary_item", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&bulk_update_dictionary_list_request)?);
 
-    let local_var_uri_str = format!("{}/service/{service_id}/dictionary/{dictionary_id}/items", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&bulk_update_dictionary_list_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<BulkUpdateDictionaryItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create DictionaryItem given service, dictionary ID, item key, and item value.
-pub async fn create_dictionary_item(configuration: &mut configuration::Configuration, params: CreateDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<CreateDictionaryItemError>> {
-    let local_var_configuration = configuration;
+pub async fn create_dictionary_item(configuration: &configuration::Configuration, params: CreateDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<CreateDictionaryItemError>> {
+    create_dictionary_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_dictionary_item`], but also returns the status and headers of the response.
+pub async fn create_dictionary_item_with_info(configuration: &configuration::Configuration, params: CreateDictionaryItemParams) -> Result<crate::apis::Response<crate::models::DictionaryItemResponse>, Error<CreateDictionaryItemError>> {
+    crate::apis::execute(configuration, create_dictionary_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_dictionary_item`] sends.
+pub(crate) fn create_dictionary_item_request(params: CreateDictionaryItemParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let dictionary_id = params.dictionary_id;
     let item_key = params.item_key;
     let item_value = params.item_value;
 
+    let local_var_path = format!("/service/{service_id}/dictionary/{dictionary_id}/item", service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id));
+    let mut local_var_req = crate::apis::Request::new("create_dictionary_item", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/dictionary/{dictionary_id}/item", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = item_key {
         local_var_form_params.insert("item_key", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = item_value {
         local_var_form_params.insert("item_value", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateDictionaryItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete DictionaryItem given service, dictionary ID, and item key.
-pub async fn delete_dictionary_item(configuration: &mut configuration::Configuration, params: DeleteDictionaryItemParams) -> Result<crate::models::InlineResponse200, Error<DeleteDictionaryItemError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_dictionary_item(configuration: &configuration::Configuration, params: DeleteDictionaryItemParams) -> Result<crate::models::InlineResponse200, Error<DeleteDictionaryItemError>> {
+    delete_dictionary_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_dictionary_item`], but also returns the status and headers of the response.
+pub async fn delete_dictionary_item_with_info(configuration: &configuration::Configuration, params: DeleteDictionaryItemParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDictionaryItemError>> {
+    crate::apis::execute(configuration, delete_dictionary_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_dictionary_item`] sends.
+pub(crate) fn delete_dictionary_item_request(params: DeleteDictionaryItemParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let dictionary_id = params.dictionary_id;
     let dictionary_item_key = params.dictionary_item_key;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/dictionary/{dictionary_id}/item/{dictionary_item_key}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id), dictionary_item_key=crate::apis::urlencode(dictionary_item_key));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteDictionaryItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/dictionary/{dictionary_id}/item/{dictionary_item_key}", service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id), dictionary_item_key=crate::apis::urlencode(dictionary_item_key));
+    crate::apis::Request::new("delete_dictionary_item", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Retrieve a single DictionaryItem given service, dictionary ID and item key.
-pub async fn get_dictionary_item(configuration: &mut configuration::Configuration, params: GetDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<GetDictionaryItemError>> {
-    let local_var_configuration = configuration;
+pub async fn get_dictionary_item(configuration: &configuration::Configuration, params: GetDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<GetDictionaryItemError>> {
+    get_dictionary_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_dictionary_item`], but also returns the status and headers of the response.
+pub async fn get_dictionary_item_with_info(configuration: &configuration::Configuration, params: GetDictionaryItemParams) -> Result<crate::apis::Response<crate::models::DictionaryItemResponse>, Error<GetDictionaryItemError>> {
+    crate::apis::execute(configuration, get_dictionary_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_dictionary_item`] sends.
+pub(crate) fn get_dictionary_item_request(params: GetDictionaryItemParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let dictionary_id = params.dictionary_id;
     let dictionary_item_key = params.dictionary_item_key;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/dictionary/{dictionary_id}/item/{dictionary_item_key}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id), dictionary_item_key=crate::apis::urlencode(dictionary_item_key));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetDictionaryItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/dictionary/{dictionary_id}/item/{dictionary_item_key}", service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id), dictionary_item_key=crate::apis::urlencode(dictionary_item_key));
+    crate::apis::Request::new("get_dictionary_item", reqwest::Method::GET, local_var_path)
 }
 
 /// List of DictionaryItems given service and dictionary ID.
-pub async fn list_dictionary_items(configuration: &mut configuration::Configuration, params: ListDictionaryItemsParams) -> Result<Vec<crate::models::DictionaryItemResponse>, Error<ListDictionaryItemsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_dictionary_items(configuration: &configuration::Configuration, params: ListDictionaryItemsParams) -> Result<Vec<crate::models::DictionaryItemResponse>, Error<ListDictionaryItemsError>> {
+    list_dictionary_items_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_dictionary_items`], but also returns the status and headers of the response.
+pub async fn list_dictionary_items_with_info(configuration: &configuration::Configuration, params: ListDictionaryItemsParams) -> Result<crate::apis::Response<Vec<crate::models::DictionaryItemResponse>>, Error<ListDictionaryItemsError>> {
+    crate::apis::execute(configuration, list_dictionary_items_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_dictionary_items`] sends.
+pub(crate) fn list_dictionary_items_request(params: ListDictionaryItemsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let dictionary_id = params.dictionary_id;
@@ -392,67 +273,37 @@ pub async fn list_dictionary_items(configuration: &mut configuration::Configurat
     let sort = params.sort;
     let direction = params.direction;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/dictionary/{dictionary_id}/items", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/dictionary/{dictionary_id}/items", service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id));
+    let mut local_var_req = crate::apis::Request::new("list_dictionary_items", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = sort {
-        local_var_req_builder = local_var_req_builder.query(&[("sort", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("sort", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = direction {
-        local_var_req_builder = local_var_req_builder.query(&[("direction", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("direction", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListDictionaryItemsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update DictionaryItem given service, dictionary ID, item key, and item value.
-pub async fn update_dictionary_item(configuration: &mut configuration::Configuration, params: UpdateDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<UpdateDictionaryItemError>> {
-    let local_var_configuration = configuration;
+pub async fn update_dictionary_item(configuration: &configuration::Configuration, params: UpdateDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<UpdateDictionaryItemError>> {
+    update_dictionary_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_dictionary_item`], but also returns the status and headers of the response.
+pub async fn update_dictionary_item_with_info(configuration: &configuration::Configuration, params: UpdateDictionaryItemParams) -> Result<crate::apis::Response<crate::models::DictionaryItemResponse>, Error<UpdateDictionaryItemError>> {
+    crate::apis::execute(configuration, update_dictionary_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_dictionary_item`] sends.
+pub(crate) fn update_dictionary_item_request(params: UpdateDictionaryItemParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let dictionary_id = params.dictionary_id;
@@ -460,63 +311,33 @@ pub async fn update_dictionary_item(configuration: &mut configuration::Configura
     let item_key = params.item_key;
     let item_value = params.item_value;
 
+    let local_var_path = format!("/service/{service_id}/dictionary/{dictionary_id}/item/{dictionary_item_key}", service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id), dictionary_item_key=crate::apis::urlencode(dictionary_item_key));
+    let mut local_var_req = crate::apis::Request::new("update_dictionary_item", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/dictionary/{dictionary_id}/item/{dictionary_item_key}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id), dictionary_item_key=crate::apis::urlencode(dictionary_item_key));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = item_key {
         local_var_form_params.insert("item_key", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = item_value {
         local_var_form_params.insert("item_value", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateDictionaryItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Upsert DictionaryItem given service, dictionary ID, item key, and item value.
-pub async fn upsert_dictionary_item(configuration: &mut configuration::Configuration, params: UpsertDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<UpsertDictionaryItemError>> {
-    let local_var_configuration = configuration;
+pub async fn upsert_dictionary_item(configuration: &configuration::Configuration, params: UpsertDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<UpsertDictionaryItemError>> {
+    upsert_dictionary_item_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`upsert_dictionary_item`], but also returns the status and headers of the response.
+pub async fn upsert_dictionary_item_with_info(configuration: &configuration::Configuration, params: UpsertDictionaryItemParams) -> Result<crate::apis::Response<crate::models::DictionaryItemResponse>, Error<UpsertDictionaryItemError>> {
+    crate::apis::execute(configuration, upsert_dictionary_item_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`upsert_dictionary_item`] sends.
+pub(crate) fn upsert_dictionary_item_request(params: UpsertDictionaryItemParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let dictionary_id = params.dictionary_id;
@@ -524,56 +345,18 @@ pub async fn upsert_dictionary_item(configuration: &mut configuration::Configura
     let item_key = params.item_key;
     let item_value = params.item_value;
 
+    let local_var_path = format!("/service/{service_id}/dictionary/{dictionary_id}/item/{dictionary_item_key}", service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id), dictionary_item_key=crate::apis::urlencode(dictionary_item_key));
+    let mut local_var_req = crate::apis::Request::new("upsert_dictionary_item", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/dictionary/{dictionary_id}/item/{dictionary_item_key}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), dictionary_id=crate::apis::urlencode(dictionary_id), dictionary_item_key=crate::apis::urlencode(dictionary_item_key));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = item_key {
         local_var_form_params.insert("item_key", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = item_value {
         local_var_form_params.insert("item_value", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpsertDictionaryItemError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`diff_service_versions`]
@@ -34,60 +33,30 @@ pub enum DiffServiceVersionsError {
 
 
 /// Get diff between two versions.
-pub async fn diff_service_versions(configuration: &mut configuration::Configuration, params: DiffServiceVersionsParams) -> Result<crate::models::DiffResponse, Error<DiffServiceVersionsError>> {
-    let local_var_configuration = configuration;
+pub async fn diff_service_versions(configuration: &configuration::Configuration, params: DiffServiceVersionsParams) -> Result<crate::models::DiffResponse, Error<DiffServiceVersionsError>> {
+    diff_service_versions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`diff_service_versions`], but also returns the status and headers of the response.
+pub async fn diff_service_versions_with_info(configuration: &configuration::Configuration, params: DiffServiceVersionsParams) -> Result<crate::apis::Response<crate::models::DiffResponse>, Error<DiffServiceVersionsError>> {
+    crate::apis::execute(configuration, diff_service_versions_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`diff_service_versions`] sends.
+pub(crate) fn diff_service_versions_request(params: DiffServiceVersionsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let from_version_id = params.from_version_id;
     let to_version_id = params.to_version_id;
     let format = params.format;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/diff/from/{from_version_id}/to/{to_version_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), from_version_id=from_version_id, to_version_id=to_version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/diff/from/{from_version_id}/to/{to_version_id}", service_id=crate::apis::urlencode(service_id), from_version_id=from_version_id, to_version_id=to_version_id);
+    let mut local_var_req = crate::apis::Request::new("diff_service_versions", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = format {
-        local_var_req_builder = local_var_req_builder.query(&[("format", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("format", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DiffServiceVersionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`delete_director`]
@@ -84,217 +83,85 @@ pub enum UpdateDirectorError {
 
 
 /// Delete the director for a particular service and version.
-pub async fn delete_director(configuration: &mut configuration::Configuration, params: DeleteDirectorParams) -> Result<crate::models::InlineResponse200, Error<DeleteDirectorError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_director(configuration: &configuration::Configuration, params: DeleteDirectorParams) -> Result<crate::models::InlineResponse200, Error<DeleteDirectorError>> {
+    delete_director_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_director`], but also returns the status and headers of the response.
+pub async fn delete_director_with_info(configuration: &configuration::Configuration, params: DeleteDirectorParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDirectorError>> {
+    crate::apis::execute(configuration, delete_director_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_director`] sends.
+pub(crate) fn delete_director_request(params: DeleteDirectorParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let director_name = params.director_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/director/{director_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, director_name=crate::apis::urlencode(director_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteDirectorError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/director/{director_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, director_name=crate::apis::urlencode(director_name));
+    crate::apis::Request::new("delete_director", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the director for a particular service and version.
-pub async fn get_director(configuration: &mut configuration::Configuration, params: GetDirectorParams) -> Result<crate::models::DirectorResponse, Error<GetDirectorError>> {
-    let local_var_configuration = configuration;
+pub async fn get_director(configuration: &configuration::Configuration, params: GetDirectorParams) -> Result<crate::models::DirectorResponse, Error<GetDirectorError>> {
+    get_director_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_director`], but also returns the status and headers of the response.
+pub async fn get_director_with_info(configuration: &configuration::Configuration, params: GetDirectorParams) -> Result<crate::apis::Response<crate::models::DirectorResponse>, Error<GetDirectorError>> {
+    crate::apis::execute(configuration, get_director_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_director`] sends.
+pub(crate) fn get_director_request(params: GetDirectorParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let director_name = params.director_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/director/{director_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, director_name=crate::apis::urlencode(director_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetDirectorError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/director/{director_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, director_name=crate::apis::urlencode(director_name));
+    crate::apis::Request::new("get_director", reqwest::Method::GET, local_var_path)
 }
 
 /// List the directors for a particular service and version.
-pub async fn list_directors(configuration: &mut configuration::Configuration, params: ListDirectorsParams) -> Result<Vec<crate::models::DirectorResponse>, Error<ListDirectorsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_directors(configuration: &configuration::Configuration, params: ListDirectorsParams) -> Result<Vec<crate::models::DirectorResponse>, Error<ListDirectorsError>> {
+    list_directors_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_directors`], but also returns the status and headers of the response.
+pub async fn list_directors_with_info(configuration: &configuration::Configuration, params: ListDirectorsParams) -> Result<crate::apis::Response<Vec<crate::models::DirectorResponse>>, Error<ListDirectorsError>> {
+    crate::apis::execute(configuration, list_directors_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_directors`] sends.
+pub(crate) fn list_directors_request(params: ListDirectorsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/director", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListDirectorsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/director", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_directors", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the director for a particular service and version.
-pub async fn update_director(configuration: &mut configuration::Configuration, params: UpdateDirectorParams) -> Result<crate::models::DirectorResponse, Error<UpdateDirectorError>> {
-    let local_var_configuration = configuration;
+pub async fn update_director(configuration: &configuration::Configuration, params: UpdateDirectorParams) -> Result<crate::models::DirectorResponse, Error<UpdateDirectorError>> {
+    update_director_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_director`], but also returns the status and headers of the response.
+pub async fn update_director_with_info(configuration: &configuration::Configuration, params: UpdateDirectorParams) -> Result<crate::apis::Response<crate::models::DirectorResponse>, Error<UpdateDirectorError>> {
+    crate::apis::execute(configuration, update_director_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_director`] sends.
+pub(crate) fn update_director_request(params: UpdateDirectorParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let director_name = params.director_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/director/{director_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, director_name=crate::apis::urlencode(director_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateDirectorError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/director/{director_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, director_name=crate::apis::urlencode(director_name));
+    crate::apis::Request::new("update_director", reqwest::Method::PUT, local_var_path)
 }
 
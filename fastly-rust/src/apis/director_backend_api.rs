@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_director_backend`]
@@ -75,167 +74,68 @@ pub enum GetDirectorBackendError {
 
 
 /// Establishes a relationship between a Backend and a Director. The Backend is then considered a member of the Director and can be used to balance traffic onto.
-pub async fn create_director_backend(configuration: &mut configuration::Configuration, params: CreateDirectorBackendParams) -> Result<crate::models::DirectorBackend, Error<CreateDirectorBackendError>> {
-    let local_var_configuration = configuration;
+pub async fn create_director_backend(configuration: &configuration::Configuration, params: CreateDirectorBackendParams) -> Result<crate::models::DirectorBackend, Error<CreateDirectorBackendError>> {
+    create_director_backend_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_director_backend`], but also returns the status and headers of the response.
+pub async fn create_director_backend_with_info(configuration: &configuration::Configuration, params: CreateDirectorBackendParams) -> Result<crate::apis::Response<crate::models::DirectorBackend>, Error<CreateDirectorBackendError>> {
+    crate::apis::execute(configuration, create_director_backend_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_director_backend`] sends.
+pub(crate) fn create_director_backend_request(params: CreateDirectorBackendParams) -> crate::apis::Request {
     // unbox the parameters
     let director_name = params.director_name;
     let service_id = params.service_id;
     let version_id = params.version_id;
     let backend_name = params.backend_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/director/{director_name}/backend/{backend_name}", local_var_configuration.base_path, director_name=crate::apis::urlencode(director_name), service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateDirectorBackendError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/director/{director_name}/backend/{backend_name}", director_name=crate::apis::urlencode(director_name), service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
+    crate::apis::Request::new("create_director_backend", reqwest::Method::POST, local_var_path)
 }
 
 /// Deletes the relationship between a Backend and a Director. The Backend is no longer considered a member of the Director and thus will not have traffic balanced onto it from this Director.
-pub async fn delete_director_backend(configuration: &mut configuration::Configuration, params: DeleteDirectorBackendParams) -> Result<crate::models::InlineResponse200, Error<DeleteDirectorBackendError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_director_backend(configuration: &configuration::Configuration, params: DeleteDirectorBackendParams) -> Result<crate::models::InlineResponse200, Error<DeleteDirectorBackendError>> {
+    delete_director_backend_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_director_backend`], but also returns the status and headers of the response.
+pub async fn delete_director_backend_with_info(configuration: &configuration::Configuration, params: DeleteDirectorBackendParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDirectorBackendError>> {
+    crate::apis::execute(configuration, delete_director_backend_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_director_backend`] sends.
+pub(crate) fn delete_director_backend_request(params: DeleteDirectorBackendParams) -> crate::apis::Request {
     // unbox the parameters
     let director_name = params.director_name;
     let service_id = params.service_id;
     let version_id = params.version_id;
     let backend_name = params.backend_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/director/{director_name}/backend/{backend_name}", local_var_configuration.base_path, director_name=crate::apis::urlencode(director_name), service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteDirectorBackendError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/director/{director_name}/backend/{backend_name}", director_name=crate::apis::urlencode(director_name), service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
+    crate::apis::Request::new("delete_director_backend", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Returns the relationship between a Backend and a Director. If the Backend has been associated with the Director, it returns a simple record indicating this. Otherwise, returns a 404.
-pub async fn get_director_backend(configuration: &mut configuration::Configuration, params: GetDirectorBackendParams) -> Result<crate::models::DirectorBackend, Error<GetDirectorBackendError>> {
-    let local_var_configuration = configuration;
+pub async fn get_director_backend(configuration: &configuration::Configuration, params: GetDirectorBackendParams) -> Result<crate::models::DirectorBackend, Error<GetDirectorBackendError>> {
+    get_director_backend_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_director_backend`], but also returns the status and headers of the response.
+pub async fn get_director_backend_with_info(configuration: &configuration::Configuration, params: GetDirectorBackendParams) -> Result<crate::apis::Response<crate::models::DirectorBackend>, Error<GetDirectorBackendError>> {
+    crate::apis::execute(configuration, get_director_backend_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_director_backend`] sends.
+pub(crate) fn get_director_backend_request(params: GetDirectorBackendParams) -> crate::apis::Request {
     // unbox the parameters
     let director_name = params.director_name;
     let service_id = params.service_id;
     let version_id = params.version_id;
     let backend_name = params.backend_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/director/{director_name}/backend/{backend_name}", local_var_configuration.base_path, director_name=crate::apis::urlencode(director_name), service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetDirectorBackendError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/director/{director_name}/backend/{backend_name}", director_name=crate::apis::urlencode(director_name), service_id=crate::apis::urlencode(service_id), version_id=version_id, backend_name=crate::apis::urlencode(backend_name));
+    crate::apis::Request::new("get_director_backend", reqwest::Method::GET, local_var_path)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`check_domain`]
@@ -126,287 +125,133 @@ pub enum UpdateDomainError {
 
 
 /// Checks the status of a specific domain's DNS record for a Service Version. Returns an array in the same format as domain/check_all.
-pub async fn check_domain(configuration: &mut configuration::Configuration, params: CheckDomainParams) -> Result<Vec<serde_json::Value>, Error<CheckDomainError>> {
-    let local_var_configuration = configuration;
+pub async fn check_domain(configuration: &configuration::Configuration, params: CheckDomainParams) -> Result<Vec<serde_json::Value>, Error<CheckDomainError>> {
+    check_domain_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`check_domain`], but also returns the status and headers of the response.
+pub async fn check_domain_with_info(configuration: &configuration::Configuration, params: CheckDomainParams) -> Result<crate::apis::Response<Vec<serde_json::Value>>, Error<CheckDomainError>> {
+    crate::apis::execute(configuration, check_domain_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`check_domain`] sends.
+pub(crate) fn check_domain_request(params: CheckDomainParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let domain_name = params.domain_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/domain/{domain_name}/check", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, domain_name=crate::apis::urlencode(domain_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CheckDomainError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/domain/{domain_name}/check", service_id=crate::apis::urlencode(service_id), version_id=version_id, domain_name=crate::apis::urlencode(domain_name));
+    crate::apis::Request::new("check_domain", reqwest::Method::GET, local_var_path)
 }
 
 /// Create a domain for a particular service and version.
-pub async fn create_domain(configuration: &mut configuration::Configuration, params: CreateDomainParams) -> Result<crate::models::DomainResponse, Error<CreateDomainError>> {
-    let local_var_configuration = configuration;
+pub async fn create_domain(configuration: &configuration::Configuration, params: CreateDomainParams) -> Result<crate::models::DomainResponse, Error<CreateDomainError>> {
+    create_domain_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`create_domain`], but also returns the status and headers of the response.
+pub async fn create_domain_with_info(configuration: &configuration::Configuration, params: CreateDomainParams) -> Result<crate::apis::Response<crate::models::DomainResponse>, Error<CreateDomainError>> {
+    crate::apis::execute(configuration, create_domain_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`create_domain`] sends.
+pub(crate) fn create_domain_request(params: CreateDomainParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let comment = params.comment;
     let name = params.name;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/domain", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_domain", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/domain", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = comment {
         local_var_form_params.insert("comment", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateDomainError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the domain for a particular service and versions.
-pub async fn delete_domain(configuration: &mut configuration::Configuration, params: DeleteDomainParams) -> Result<crate::models::InlineResponse200, Error<DeleteDomainError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_domain(configuration: &configuration::Configuration, params: DeleteDomainParams) -> Result<crate::models::InlineResponse200, Error<DeleteDomainError>> {
+    delete_domain_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_domain`], but also returns the status and headers of the response.
+pub async fn delete_domain_with_info(configuration: &configuration::Configuration, params: DeleteDomainParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDomainError>> {
+    crate::apis::execute(configuration, delete_domain_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_domain`] sends.
+pub(crate) fn delete_domain_request(params: DeleteDomainParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let domain_name = params.domain_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/domain/{domain_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, domain_name=crate::apis::urlencode(domain_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteDomainError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/domain/{domain_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, domain_name=crate::apis::urlencode(domain_name));
+    crate::apis::Request::new("delete_domain", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the domain for a particular service and version.
-pub async fn get_domain(configuration: &mut configuration::Configuration, params: GetDomainParams) -> Result<crate::models::DomainResponse, Error<GetDomainError>> {
-    let local_var_configuration = configuration;
+pub async fn get_domain(configuration: &configuration::Configuration, params: GetDomainParams) -> Result<crate::models::DomainResponse, Error<GetDomainError>> {
+    get_domain_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_domain`], but also returns the status and headers of the response.
+pub async fn get_domain_with_info(configuration: &configuration::Configuration, params: GetDomainParams) -> Result<crate::apis::Response<crate::models::DomainResponse>, Error<GetDomainError>> {
+    crate::apis::execute(configuration, get_domain_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_domain`] sends.
+pub(crate) fn get_domain_request(params: GetDomainParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let domain_name = params.domain_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/domain/{domain_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, domain_name=crate::apis::urlencode(domain_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetDomainError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/domain/{domain_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, domain_name=crate::apis::urlencode(domain_name));
+    crate::apis::Request::new("get_domain", reqwest::Method::GET, local_var_path)
 }
 
 /// List all the domains for a particular service and version.
-pub async fn list_domains(configuration: &mut configuration::Configuration, params: ListDomainsParams) -> Result<Vec<crate::models::DomainResponse>, Error<ListDomainsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_domains(configuration: &configuration::Configuration, params: ListDomainsParams) -> Result<Vec<crate::models::DomainResponse>, Error<ListDomainsError>> {
+    list_domains_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_domains`], but also returns the status and headers of the response.
+pub async fn list_domains_with_info(configuration: &configuration::Configuration, params: ListDomainsParams) -> Result<crate::apis::Response<Vec<crate::models::DomainResponse>>, Error<ListDomainsError>> {
+    crate::apis::execute(configuration, list_domains_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_domains`] sends.
+pub(crate) fn list_domains_request(params: ListDomainsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/domain", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListDomainsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/domain", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_domains", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the domain for a particular service and version.
-pub async fn update_domain(configuration: &mut configuration::Configuration, params: UpdateDomainParams) -> Result<crate::models::DomainResponse, Error<UpdateDomainError>> {
-    let local_var_configuration = configuration;
+pub async fn update_domain(configuration: &configuration::Configuration, params: UpdateDomainParams) -> Result<crate::models::DomainResponse, Error<UpdateDomainError>> {
+    update_domain_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_domain`], but also returns the status and headers of the response.
+pub async fn update_domain_with_info(configuration: &configuration::Configuration, params: UpdateDomainParams) -> Result<crate::apis::Response<crate::models::DomainResponse>, Error<UpdateDomainError>> {
+    crate::apis::execute(configuration, update_domain_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_domain`] sends.
+pub(crate) fn update_domain_request(params: UpdateDomainParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -414,56 +259,18 @@ pub async fn update_domain(configuration: &mut configuration::Configuration, par
     let comment = params.comment;
     let name = params.name;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/domain/{domain_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, domain_name=crate::apis::urlencode(domain_name));
+    let mut local_var_req = crate::apis::Request::new("update_domain", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/domain/{domain_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, domain_name=crate::apis::urlencode(domain_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = comment {
         local_var_form_params.insert("comment", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateDomainError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 
@@ -21,53 +20,18 @@ pub enum ListDomainOwnershipsError {
 
 
 /// List all domain-ownerships.
-pub async fn list_domain_ownerships(configuration: &mut configuration::Configuration) -> Result<crate::models::InlineResponse2002, Error<ListDomainOwnershipsError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
-
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/domain-ownerships", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+pub async fn list_domain_ownerships(configuration: &configuration::Configuration) -> Result<crate::models::InlineResponse2002, Error<ListDomainOwnershipsError>> {
+    list_domain_ownerships_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+/// Like [`list_domain_ownerships`], but also returns the status and headers of the response.
+pub async fn list_domain_ownerships_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::InlineResponse2002>, Error<ListDomainOwnershipsError>> {
+    crate::apis::execute(configuration, list_domain_ownerships_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListDomainOwnershipsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`list_domain_ownerships`] sends.
+pub(crate) fn list_domain_ownerships_request() -> crate::apis::Request {
+    let local_var_path = String::from("/domain-ownerships");
+    crate::apis::Request::new("list_domain_ownerships", reqwest::Method::GET, local_var_path)
 }
 
@@ -0,0 +1,167 @@
+//! Planning mutating requests instead of sending them.
+//!
+//! With a [`Plan`] in [`Configuration::dry_run`](super::configuration::Configuration::dry_run),
+//! every `POST`, `PUT`, `PATCH` and `DELETE` is recorded in the plan as a
+//! [`PlannedRequest`] and nothing is sent. `GET` requests still run, so code
+//! that looks services up before changing them works unchanged.
+//!
+//! A planned call succeeds without a response from the API: it returns the
+//! `Default` value of the endpoint's return type, e.g. a model with every
+//! field `None`, and its `_with_info` variant reports status [`PLANNED`]
+//! with no headers. `?` therefore carries a script on through every change,
+//! and the plan holds them all afterwards:
+//!
+//! ```no_run
+//! # use fastly_api::apis::configuration::Configuration;
+//! # use fastly_api::apis::dry_run::Plan;
+//! # use fastly_api::apis::version_api::{activate_service_version, clone_service_version, ActivateServiceVersionParams, CloneServiceVersionParams};
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let plan = Plan::new();
+//! let cfg = Configuration::builder().dry_run(plan.clone()).build()?;
+//! let service_id = "SU1Z0isxPaozGVKXdv0eY".to_owned();
+//! let cloned = clone_service_version(&cfg, CloneServiceVersionParams { service_id: service_id.clone(), version_id: 2 }).await?;
+//! let version_id = cloned.number.unwrap_or(3);
+//! activate_service_version(&cfg, ActivateServiceVersionParams { service_id, version_id }).await?;
+//! print!("{plan}");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Responses to planned requests never exist, so later calls that depend on
+//! them need a stand-in value, like the version number above.
+//!
+//! Secrets are redacted as the request is planned: query parameters, headers,
+//! form fields and JSON fields that [`redact`] considers secret hold
+//! [`redact::REDACTED`]. Credentials are never part of a planned request.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use super::configuration::Configuration;
+use super::raw::Body;
+use super::{redact, FormParams, Request, RequestBody};
+
+/// The status of the response to a planned request, `202 Accepted`.
+///
+/// Under a dry run no mutating request reaches the API, so a mutating call
+/// reporting this status was planned rather than sent.
+pub const PLANNED: reqwest::StatusCode = reqwest::StatusCode::ACCEPTED;
+
+/// A request that would have been sent.
+#[derive(Debug, Clone)]
+pub struct PlannedRequest {
+    /// Name of the endpoint function, e.g. `update_healthcheck`.
+    pub operation: &'static str,
+    pub method: reqwest::Method,
+    /// Absolute URL including the query string.
+    pub url: String,
+    /// Headers set by the endpoint; credentials and the user agent are left out.
+    pub headers: Vec<(String, String)>,
+    pub body: Body,
+    /// The service ID named in the path, if any.
+    pub service_id: Option<String>,
+    /// The service version named in the path, if any.
+    pub version: Option<i32>,
+}
+
+impl PlannedRequest {
+    pub(crate) fn new(configuration: &Configuration, request: &Request) -> PlannedRequest {
+        let mut url = format!("{}{}", configuration.base_path_for(request.group), request.path);
+        if !request.query.is_empty() {
+            url.push('?');
+            url.push_str(&redacted_form(&request.path, request.query.iter().map(|(k, v)| (k.as_str(), v.as_str()))).encode());
+        }
+        let (service_id, version) = request.service_version();
+        PlannedRequest {
+            operation: request.operation,
+            method: request.method.clone(),
+            url,
+            headers: request.headers.iter().map(|(k, v)| (k.clone(), redact::value(&request.path, k, v).to_owned())).collect(),
+            body: match request.body {
+                RequestBody::Empty => Body::Empty,
+                RequestBody::Form(ref form) => Body::Form(redacted_form(&request.path, form.iter())),
+                RequestBody::Json(ref json) => Body::Json(redact::json(&request.path, json)),
+                RequestBody::Multipart(ref form) => Body::Multipart(form.clone()),
+            },
+            service_id: service_id.map(str::to_owned),
+            version: version.and_then(|version| version.parse().ok()),
+        }
+    }
+}
+
+/// The method and URL, then one indented line each for the target service,
+/// the headers and the body.
+impl fmt::Display for PlannedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)?;
+        match (&self.service_id, self.version) {
+            (Some(service_id), Some(version)) => write!(f, "\n    service {} version {}", service_id, version)?,
+            (Some(service_id), None) => write!(f, "\n    service {}", service_id)?,
+            _ => {}
+        }
+        for (name, value) in &self.headers {
+            write!(f, "\n    {}: {}", name, value)?;
+        }
+        match self.body {
+            Body::Empty => Ok(()),
+            Body::Form(ref form) => write!(f, "\n    {}", form.encode()),
+            Body::Json(ref json) => write!(f, "\n    {}", json),
+            Body::Multipart(ref form) => write!(f, "\n    multipart {:?}", form),
+        }
+    }
+}
+
+fn redacted_form<'a>(path: &str, pairs: impl Iterator<Item = (&'a str, &'a str)>) -> FormParams {
+    let mut form = FormParams::new();
+    for (name, value) in pairs {
+        form.insert(name, redact::value(path, name, value).to_owned());
+    }
+    form
+}
+
+/// The requests planned by a dry run, in the order they were made.
+///
+/// Clones share the list, so keep a clone of the plan put into a
+/// [`Configuration`] to read it afterwards.
+#[derive(Debug, Clone, Default)]
+pub struct Plan {
+    requests: Arc<Mutex<Vec<PlannedRequest>>>,
+}
+
+impl Plan {
+    pub fn new() -> Plan {
+        Plan::default()
+    }
+
+    /// The requests planned so far.
+    pub fn requests(&self) -> Vec<PlannedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Remove and return the requests planned so far.
+    pub fn take(&self) -> Vec<PlannedRequest> {
+        std::mem::take(&mut *self.requests.lock().unwrap())
+    }
+
+    pub fn len(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn push(&self, request: PlannedRequest) {
+        self.requests.lock().unwrap().push(request);
+    }
+}
+
+/// Each planned request, numbered from 1.
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, request) in self.requests.lock().unwrap().iter().enumerate() {
+            writeln!(f, "{}. {}", index + 1, request)?;
+        }
+        Ok(())
+    }
+}
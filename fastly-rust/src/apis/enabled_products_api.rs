@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`disable_product`]
@@ -59,161 +58,62 @@ pub enum GetEnabledProductError {
 
 
 /// Disable a product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
-pub async fn disable_product(configuration: &mut configuration::Configuration, params: DisableProductParams) -> Result<(), Error<DisableProductError>> {
-    let local_var_configuration = configuration;
+pub async fn disable_product(configuration: &configuration::Configuration, params: DisableProductParams) -> Result<(), Error<DisableProductError>> {
+    disable_product_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`disable_product`], but also returns the status and headers of the response.
+pub async fn disable_product_with_info(configuration: &configuration::Configuration, params: DisableProductParams) -> Result<crate::apis::Response<()>, Error<DisableProductError>> {
+    crate::apis::execute(configuration, disable_product_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`disable_product`] sends.
+pub(crate) fn disable_product_request(params: DisableProductParams) -> crate::apis::Request {
     // unbox the parameters
     let product_id = params.product_id;
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/enabled-products/{product_id}/services/{service_id}", local_var_configuration.base_path, product_id=crate::apis::urlencode(product_id), service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DisableProductError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/enabled-products/{product_id}/services/{service_id}", product_id=crate::apis::urlencode(product_id), service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("disable_product", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Enable a product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
-pub async fn enable_product(configuration: &mut configuration::Configuration, params: EnableProductParams) -> Result<crate::models::EnabledProductResponse, Error<EnableProductError>> {
-    let local_var_configuration = configuration;
+pub async fn enable_product(configuration: &configuration::Configuration, params: EnableProductParams) -> Result<crate::models::EnabledProductResponse, Error<EnableProductError>> {
+    enable_product_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`enable_product`], but also returns the status and headers of the response.
+pub async fn enable_product_with_info(configuration: &configuration::Configuration, params: EnableProductParams) -> Result<crate::apis::Response<crate::models::EnabledProductResponse>, Error<EnableProductError>> {
+    crate::apis::execute(configuration, enable_product_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`enable_product`] sends.
+pub(crate) fn enable_product_request(params: EnableProductParams) -> crate::apis::Request {
     // unbox the parameters
     let product_id = params.product_id;
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/enabled-products/{product_id}/services/{service_id}", local_var_configuration.base_path, product_id=crate::apis::urlencode(product_id), service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<EnableProductError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/enabled-products/{product_id}/services/{service_id}", product_id=crate::apis::urlencode(product_id), service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("enable_product", reqwest::Method::PUT, local_var_path)
 }
 
 /// Get enabled product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
-pub async fn get_enabled_product(configuration: &mut configuration::Configuration, params: GetEnabledProductParams) -> Result<crate::models::EnabledProductResponse, Error<GetEnabledProductError>> {
-    let local_var_configuration = configuration;
+pub async fn get_enabled_product(configuration: &configuration::Configuration, params: GetEnabledProductParams) -> Result<crate::models::EnabledProductResponse, Error<GetEnabledProductError>> {
+    get_enabled_product_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_enabled_product`], but also returns the status and headers of the response.
+pub async fn get_enabled_product_with_info(configuration: &configuration::Configuration, params: GetEnabledProductParams) -> Result<crate::apis::Response<crate::models::EnabledProductResponse>, Error<GetEnabledProductError>> {
+    crate::apis::execute(configuration, get_enabled_product_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_enabled_product`] sends.
+pub(crate) fn get_enabled_product_request(params: GetEnabledProductParams) -> crate::apis::Request {
     // unbox the parameters
     let product_id = params.product_id;
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/enabled-products/{product_id}/services/{service_id}", local_var_configuration.base_path, product_id=crate::apis::urlencode(product_id), service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetEnabledProductError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/enabled-products/{product_id}/services/{service_id}", product_id=crate::apis::urlencode(product_id), service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("get_enabled_product", reqwest::Method::GET, local_var_path)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_event`]
@@ -66,61 +65,36 @@ pub enum ListEventsError {
 
 
 /// Get a specific event.
-pub async fn get_event(configuration: &mut configuration::Configuration, params: GetEventParams) -> Result<crate::models::EventResponse, Error<GetEventError>> {
-    let local_var_configuration = configuration;
+pub async fn get_event(configuration: &configuration::Configuration, params: GetEventParams) -> Result<crate::models::EventResponse, Error<GetEventError>> {
+    get_event_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_event`], but also returns the status and headers of the response.
+pub async fn get_event_with_info(configuration: &configuration::Configuration, params: GetEventParams) -> Result<crate::apis::Response<crate::models::EventResponse>, Error<GetEventError>> {
+    crate::apis::execute(configuration, get_event_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_event`] sends.
+pub(crate) fn get_event_request(params: GetEventParams) -> crate::apis::Request {
     // unbox the parameters
     let event_id = params.event_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/events/{event_id}", local_var_configuration.base_path, event_id=crate::apis::urlencode(event_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetEventError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/events/{event_id}", event_id=crate::apis::urlencode(event_id));
+    crate::apis::Request::new("get_event", reqwest::Method::GET, local_var_path)
 }
 
 /// List all events for a particular customer. Events can be filtered by user, customer and event type. Events can be sorted by date.
-pub async fn list_events(configuration: &mut configuration::Configuration, params: ListEventsParams) -> Result<crate::models::EventsResponse, Error<ListEventsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_events(configuration: &configuration::Configuration, params: ListEventsParams) -> Result<crate::models::EventsResponse, Error<ListEventsError>> {
+    list_events_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_events`], but also returns the status and headers of the response.
+pub async fn list_events_with_info(configuration: &configuration::Configuration, params: ListEventsParams) -> Result<crate::apis::Response<crate::models::EventsResponse>, Error<ListEventsError>> {
+    crate::apis::execute(configuration, list_events_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_events`] sends.
+pub(crate) fn list_events_request(params: ListEventsParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_customer_id = params.filter_customer_id;
     let filter_event_type = params.filter_event_type;
@@ -136,87 +110,49 @@ pub async fn list_events(configuration: &mut configuration::Configuration, param
     let page_size = params.page_size;
     let sort = params.sort;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/events", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/events");
+    let mut local_var_req = crate::apis::Request::new("list_events", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_customer_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[customer_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[customer_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_event_type {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[event_type]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[event_type]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_service_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[service_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[service_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_user_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[user_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[user_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_token_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[token_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[token_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_created_at {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[created_at]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[created_at]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_created_at_lte {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[created_at][lte]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[created_at][lte]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_created_at_lt {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[created_at][lt]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[created_at][lt]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_created_at_gte {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[created_at][gte]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[created_at][gte]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_created_at_gt {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[created_at][gt]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[created_at][gt]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = sort {
-        local_var_req_builder = local_var_req_builder.query(&[("sort", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("sort", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListEventsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_gzip_config`]
@@ -116,9 +115,17 @@ pub enum UpdateGzipConfigError {
 
 
 /// Create a named gzip configuration on a particular service and version.
-pub async fn create_gzip_config(configuration: &mut configuration::Configuration, params: CreateGzipConfigParams) -> Result<crate::models::GzipResponse, Error<CreateGzipConfigError>> {
-    let local_var_configuration = configuration;
+pub async fn create_gzip_config(configuration: &configuration::Configuration, params: CreateGzipConfigParams) -> Result<crate::models::GzipResponse, Error<CreateGzipConfigError>> {
+    create_gzip_config_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_gzip_config`], but also returns the status and headers of the response.
+pub async fn create_gzip_config_with_info(configuration: &configuration::Configuration, params: CreateGzipConfigParams) -> Result<crate::apis::Response<crate::models::GzipResponse>, Error<CreateGzipConfigError>> {
+    crate::apis::execute(configuration, create_gzip_config_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_gzip_config`] sends.
+pub(crate) fn create_gzip_config_request(params: CreateGzipConfigParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -127,24 +134,10 @@ pub async fn create_gzip_config(configuration: &mut configuration::Configuration
     let extensions = params.extensions;
     let name = params.name;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/gzip", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_gzip_config", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/gzip", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = cache_condition {
         local_var_form_params.insert("cache_condition", local_var_param_value.to_string());
     }
@@ -157,200 +150,85 @@ pub async fn create_gzip_config(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateGzipConfigError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a named gzip configuration on a particular service and version.
-pub async fn delete_gzip_config(configuration: &mut configuration::Configuration, params: DeleteGzipConfigParams) -> Result<crate::models::InlineResponse200, Error<DeleteGzipConfigError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_gzip_config(configuration: &configuration::Configuration, params: DeleteGzipConfigParams) -> Result<crate::models::InlineResponse200, Error<DeleteGzipConfigError>> {
+    delete_gzip_config_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_gzip_config`], but also returns the status and headers of the response.
+pub async fn delete_gzip_config_with_info(configuration: &configuration::Configuration, params: DeleteGzipConfigParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteGzipConfigError>> {
+    crate::apis::execute(configuration, delete_gzip_config_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_gzip_config`] sends.
+pub(crate) fn delete_gzip_config_request(params: DeleteGzipConfigParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let gzip_name = params.gzip_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/gzip/{gzip_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, gzip_name=crate::apis::urlencode(gzip_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteGzipConfigError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/gzip/{gzip_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, gzip_name=crate::apis::urlencode(gzip_name));
+    crate::apis::Request::new("delete_gzip_config", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the gzip configuration for a particular service, version, and name.
-pub async fn get_gzip_configs(configuration: &mut configuration::Configuration, params: GetGzipConfigsParams) -> Result<crate::models::GzipResponse, Error<GetGzipConfigsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_gzip_configs(configuration: &configuration::Configuration, params: GetGzipConfigsParams) -> Result<crate::models::GzipResponse, Error<GetGzipConfigsError>> {
+    get_gzip_configs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_gzip_configs`], but also returns the status and headers of the response.
+pub async fn get_gzip_configs_with_info(configuration: &configuration::Configuration, params: GetGzipConfigsParams) -> Result<crate::apis::Response<crate::models::GzipResponse>, Error<GetGzipConfigsError>> {
+    crate::apis::execute(configuration, get_gzip_configs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_gzip_configs`] sends.
+pub(crate) fn get_gzip_configs_request(params: GetGzipConfigsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let gzip_name = params.gzip_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/gzip/{gzip_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, gzip_name=crate::apis::urlencode(gzip_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetGzipConfigsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/gzip/{gzip_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, gzip_name=crate::apis::urlencode(gzip_name));
+    crate::apis::Request::new("get_gzip_configs", reqwest::Method::GET, local_var_path)
 }
 
 /// List all gzip configurations for a particular service and version.
-pub async fn list_gzip_configs(configuration: &mut configuration::Configuration, params: ListGzipConfigsParams) -> Result<Vec<crate::models::GzipResponse>, Error<ListGzipConfigsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_gzip_configs(configuration: &configuration::Configuration, params: ListGzipConfigsParams) -> Result<Vec<crate::models::GzipResponse>, Error<ListGzipConfigsError>> {
+    list_gzip_configs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_gzip_configs`], but also returns the status and headers of the response.
+pub async fn list_gzip_configs_with_info(configuration: &configuration::Configuration, params: ListGzipConfigsParams) -> Result<crate::apis::Response<Vec<crate::models::GzipResponse>>, Error<ListGzipConfigsError>> {
+    crate::apis::execute(configuration, list_gzip_configs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_gzip_configs`] sends.
+pub(crate) fn list_gzip_configs_request(params: ListGzipConfigsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/gzip", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListGzipConfigsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/gzip", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_gzip_configs", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a named gzip configuration on a particular service and version.
-pub async fn update_gzip_config(configuration: &mut configuration::Configuration, params: UpdateGzipConfigParams) -> Result<crate::models::GzipResponse, Error<UpdateGzipConfigError>> {
-    let local_var_configuration = configuration;
+pub async fn update_gzip_config(configuration: &configuration::Configuration, params: UpdateGzipConfigParams) -> Result<crate::models::GzipResponse, Error<UpdateGzipConfigError>> {
+    update_gzip_config_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_gzip_config`], but also returns the status and headers of the response.
+pub async fn update_gzip_config_with_info(configuration: &configuration::Configuration, params: UpdateGzipConfigParams) -> Result<crate::apis::Response<crate::models::GzipResponse>, Error<UpdateGzipConfigError>> {
+    crate::apis::execute(configuration, update_gzip_config_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_gzip_config`] sends.
+pub(crate) fn update_gzip_config_request(params: UpdateGzipConfigParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -360,24 +238,10 @@ pub async fn update_gzip_config(configuration: &mut configuration::Configuration
     let extensions = params.extensions;
     let name = params.name;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/gzip/{gzip_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, gzip_name=crate::apis::urlencode(gzip_name));
+    let mut local_var_req = crate::apis::Request::new("update_gzip_config", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/gzip/{gzip_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, gzip_name=crate::apis::urlencode(gzip_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = cache_condition {
         local_var_form_params.insert("cache_condition", local_var_param_value.to_string());
     }
@@ -390,32 +254,8 @@ pub async fn update_gzip_config(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateGzipConfigError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_header_object`]
@@ -148,9 +147,17 @@ pub enum UpdateHeaderObjectError {
 
 
 /// Creates a new Header object.
-pub async fn create_header_object(configuration: &mut configuration::Configuration, params: CreateHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<CreateHeaderObjectError>> {
-    let local_var_configuration = configuration;
+pub async fn create_header_object(configuration: &configuration::Configuration, params: CreateHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<CreateHeaderObjectError>> {
+    create_header_object_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_header_object`], but also returns the status and headers of the response.
+pub async fn create_header_object_with_info(configuration: &configuration::Configuration, params: CreateHeaderObjectParams) -> Result<crate::apis::Response<crate::models::HeaderResponse>, Error<CreateHeaderObjectError>> {
+    crate::apis::execute(configuration, create_header_object_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_header_object`] sends.
+pub(crate) fn create_header_object_request(params: CreateHeaderObjectParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -167,24 +174,10 @@ pub async fn create_header_object(configuration: &mut configuration::Configurati
     let ignore_if_set = params.ignore_if_set;
     let priority = params.priority;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/header", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_header_object", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/header", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = action {
         local_var_form_params.insert("action", local_var_param_value.to_string());
     }
@@ -221,200 +214,85 @@ pub async fn create_header_object(configuration: &mut configuration::Configurati
     if let Some(local_var_param_value) = priority {
         local_var_form_params.insert("priority", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateHeaderObjectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Deletes a Header object by name.
-pub async fn delete_header_object(configuration: &mut configuration::Configuration, params: DeleteHeaderObjectParams) -> Result<crate::models::InlineResponse200, Error<DeleteHeaderObjectError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_header_object(configuration: &configuration::Configuration, params: DeleteHeaderObjectParams) -> Result<crate::models::InlineResponse200, Error<DeleteHeaderObjectError>> {
+    delete_header_object_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_header_object`], but also returns the status and headers of the response.
+pub async fn delete_header_object_with_info(configuration: &configuration::Configuration, params: DeleteHeaderObjectParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteHeaderObjectError>> {
+    crate::apis::execute(configuration, delete_header_object_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_header_object`] sends.
+pub(crate) fn delete_header_object_request(params: DeleteHeaderObjectParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let header_name = params.header_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/header/{header_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, header_name=crate::apis::urlencode(header_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteHeaderObjectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/header/{header_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, header_name=crate::apis::urlencode(header_name));
+    crate::apis::Request::new("delete_header_object", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Retrieves a Header object by name.
-pub async fn get_header_object(configuration: &mut configuration::Configuration, params: GetHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<GetHeaderObjectError>> {
-    let local_var_configuration = configuration;
+pub async fn get_header_object(configuration: &configuration::Configuration, params: GetHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<GetHeaderObjectError>> {
+    get_header_object_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_header_object`], but also returns the status and headers of the response.
+pub async fn get_header_object_with_info(configuration: &configuration::Configuration, params: GetHeaderObjectParams) -> Result<crate::apis::Response<crate::models::HeaderResponse>, Error<GetHeaderObjectError>> {
+    crate::apis::execute(configuration, get_header_object_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_header_object`] sends.
+pub(crate) fn get_header_object_request(params: GetHeaderObjectParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let header_name = params.header_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/header/{header_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, header_name=crate::apis::urlencode(header_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetHeaderObjectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/header/{header_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, header_name=crate::apis::urlencode(header_name));
+    crate::apis::Request::new("get_header_object", reqwest::Method::GET, local_var_path)
 }
 
 /// Retrieves all Header objects for a particular Version of a Service.
-pub async fn list_header_objects(configuration: &mut configuration::Configuration, params: ListHeaderObjectsParams) -> Result<Vec<crate::models::HeaderResponse>, Error<ListHeaderObjectsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_header_objects(configuration: &configuration::Configuration, params: ListHeaderObjectsParams) -> Result<Vec<crate::models::HeaderResponse>, Error<ListHeaderObjectsError>> {
+    list_header_objects_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_header_objects`], but also returns the status and headers of the response.
+pub async fn list_header_objects_with_info(configuration: &configuration::Configuration, params: ListHeaderObjectsParams) -> Result<crate::apis::Response<Vec<crate::models::HeaderResponse>>, Error<ListHeaderObjectsError>> {
+    crate::apis::execute(configuration, list_header_objects_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_header_objects`] sends.
+pub(crate) fn list_header_objects_request(params: ListHeaderObjectsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/header", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListHeaderObjectsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/header", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_header_objects", reqwest::Method::GET, local_var_path)
 }
 
 /// Modifies an existing Header object by name.
-pub async fn update_header_object(configuration: &mut configuration::Configuration, params: UpdateHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<UpdateHeaderObjectError>> {
-    let local_var_configuration = configuration;
+pub async fn update_header_object(configuration: &configuration::Configuration, params: UpdateHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<UpdateHeaderObjectError>> {
+    update_header_object_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_header_object`], but also returns the status and headers of the response.
+pub async fn update_header_object_with_info(configuration: &configuration::Configuration, params: UpdateHeaderObjectParams) -> Result<crate::apis::Response<crate::models::HeaderResponse>, Error<UpdateHeaderObjectError>> {
+    crate::apis::execute(configuration, update_header_object_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_header_object`] sends.
+pub(crate) fn update_header_object_request(params: UpdateHeaderObjectParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -432,24 +310,10 @@ pub async fn update_header_object(configuration: &mut configuration::Configurati
     let ignore_if_set = params.ignore_if_set;
     let priority = params.priority;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/header/{header_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, header_name=crate::apis::urlencode(header_name));
+    let mut local_var_req = crate::apis::Request::new("update_header_object", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/header/{header_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, header_name=crate::apis::urlencode(header_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = action {
         local_var_form_params.insert("action", local_var_param_value.to_string());
     }
@@ -486,32 +350,8 @@ pub async fn update_header_object(configuration: &mut configuration::Configurati
     if let Some(local_var_param_value) = priority {
         local_var_form_params.insert("priority", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateHeaderObjectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_healthcheck`]
@@ -152,9 +151,17 @@ pub enum UpdateHealthcheckError {
 
 
 /// Create a health check for a particular service and version.
-pub async fn create_healthcheck(configuration: &mut configuration::Configuration, params: CreateHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<CreateHealthcheckError>> {
-    let local_var_configuration = configuration;
+pub async fn create_healthcheck(configuration: &configuration::Configuration, params: CreateHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<CreateHealthcheckError>> {
+    create_healthcheck_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_healthcheck`], but also returns the status and headers of the response.
+pub async fn create_healthcheck_with_info(configuration: &configuration::Configuration, params: CreateHealthcheckParams) -> Result<crate::apis::Response<crate::models::HealthcheckResponse>, Error<CreateHealthcheckError>> {
+    crate::apis::execute(configuration, create_healthcheck_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_healthcheck`] sends.
+pub(crate) fn create_healthcheck_request(params: CreateHealthcheckParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -172,24 +179,10 @@ pub async fn create_healthcheck(configuration: &mut configuration::Configuration
     let timeout = params.timeout;
     let window = params.window;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/healthcheck", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_healthcheck", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/healthcheck", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = check_interval {
         local_var_form_params.insert("check_interval", local_var_param_value.to_string());
     }
@@ -199,11 +192,8 @@ pub async fn create_healthcheck(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = expected_response {
         local_var_form_params.insert("expected_response", local_var_param_value.to_string());
     }
-    if let Some(ref local_var_param_value) = headers {
-        let x = local_var_param_value.into_iter().map(|p| {
-           ("headers[]".to_string(), p.to_string())
-        }).collect::<Vec<(String, String)>>();
-        local_var_req_builder = local_var_req_builder.query(&x);
+    if let Some(local_var_param_value) = headers {
+        local_var_form_params.insert_array("headers", local_var_param_value);
     }
     if let Some(local_var_param_value) = host {
         local_var_form_params.insert("host", local_var_param_value.to_string());
@@ -232,203 +222,85 @@ pub async fn create_healthcheck(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = window {
         local_var_form_params.insert("window", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-
-    println!("Request:\n{local_var_req:?}\n");
-
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateHealthcheckError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the health check for a particular service and version.
-pub async fn delete_healthcheck(configuration: &mut configuration::Configuration, params: DeleteHealthcheckParams) -> Result<crate::models::InlineResponse200, Error<DeleteHealthcheckError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_healthcheck(configuration: &configuration::Configuration, params: DeleteHealthcheckParams) -> Result<crate::models::InlineResponse200, Error<DeleteHealthcheckError>> {
+    delete_healthcheck_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_healthcheck`], but also returns the status and headers of the response.
+pub async fn delete_healthcheck_with_info(configuration: &configuration::Configuration, params: DeleteHealthcheckParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteHealthcheckError>> {
+    crate::apis::execute(configuration, delete_healthcheck_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_healthcheck`] sends.
+pub(crate) fn delete_healthcheck_request(params: DeleteHealthcheckParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let healthcheck_name = params.healthcheck_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/healthcheck/{healthcheck_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, healthcheck_name=crate::apis::urlencode(healthcheck_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteHealthcheckError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/healthcheck/{healthcheck_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, healthcheck_name=crate::apis::urlencode(healthcheck_name));
+    crate::apis::Request::new("delete_healthcheck", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the health check for a particular service and version.
-pub async fn get_healthcheck(configuration: &mut configuration::Configuration, params: GetHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<GetHealthcheckError>> {
-    let local_var_configuration = configuration;
+pub async fn get_healthcheck(configuration: &configuration::Configuration, params: GetHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<GetHealthcheckError>> {
+    get_healthcheck_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_healthcheck`], but also returns the status and headers of the response.
+pub async fn get_healthcheck_with_info(configuration: &configuration::Configuration, params: GetHealthcheckParams) -> Result<crate::apis::Response<crate::models::HealthcheckResponse>, Error<GetHealthcheckError>> {
+    crate::apis::execute(configuration, get_healthcheck_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_healthcheck`] sends.
+pub(crate) fn get_healthcheck_request(params: GetHealthcheckParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let healthcheck_name = params.healthcheck_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/healthcheck/{healthcheck_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, healthcheck_name=crate::apis::urlencode(healthcheck_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetHealthcheckError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/healthcheck/{healthcheck_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, healthcheck_name=crate::apis::urlencode(healthcheck_name));
+    crate::apis::Request::new("get_healthcheck", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the health checks for a particular service and version.
-pub async fn list_healthchecks(configuration: &mut configuration::Configuration, params: ListHealthchecksParams) -> Result<Vec<crate::models::HealthcheckResponse>, Error<ListHealthchecksError>> {
-    let local_var_configuration = configuration;
+pub async fn list_healthchecks(configuration: &configuration::Configuration, params: ListHealthchecksParams) -> Result<Vec<crate::models::HealthcheckResponse>, Error<ListHealthchecksError>> {
+    list_healthchecks_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_healthchecks`], but also returns the status and headers of the response.
+pub async fn list_healthchecks_with_info(configuration: &configuration::Configuration, params: ListHealthchecksParams) -> Result<crate::apis::Response<Vec<crate::models::HealthcheckResponse>>, Error<ListHealthchecksError>> {
+    crate::apis::execute(configuration, list_healthchecks_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_healthchecks`] sends.
+pub(crate) fn list_healthchecks_request(params: ListHealthchecksParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/healthcheck", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListHealthchecksError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/healthcheck", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_healthchecks", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the health check for a particular service and version.
-pub async fn update_healthcheck(configuration: &mut configuration::Configuration, params: UpdateHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<UpdateHealthcheckError>> {
-    let local_var_configuration = configuration;
+pub async fn update_healthcheck(configuration: &configuration::Configuration, params: UpdateHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<UpdateHealthcheckError>> {
+    update_healthcheck_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_healthcheck`], but also returns the status and headers of the response.
+pub async fn update_healthcheck_with_info(configuration: &configuration::Configuration, params: UpdateHealthcheckParams) -> Result<crate::apis::Response<crate::models::HealthcheckResponse>, Error<UpdateHealthcheckError>> {
+    crate::apis::execute(configuration, update_healthcheck_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_healthcheck`] sends.
+pub(crate) fn update_healthcheck_request(params: UpdateHealthcheckParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -447,24 +319,10 @@ pub async fn update_healthcheck(configuration: &mut configuration::Configuration
     let timeout = params.timeout;
     let window = params.window;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/healthcheck/{healthcheck_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, healthcheck_name=crate::apis::urlencode(healthcheck_name));
+    let mut local_var_req = crate::apis::Request::new("update_healthcheck", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/healthcheck/{healthcheck_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, healthcheck_name=crate::apis::urlencode(healthcheck_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = check_interval {
         local_var_form_params.insert("check_interval", local_var_param_value.to_string());
     }
@@ -475,7 +333,7 @@ pub async fn update_healthcheck(configuration: &mut configuration::Configuration
         local_var_form_params.insert("expected_response", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = headers {
-        local_var_form_params.insert("headers", local_var_param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string());
+        local_var_form_params.insert_array("headers", local_var_param_value);
     }
     if let Some(local_var_param_value) = host {
         local_var_form_params.insert("host", local_var_param_value.to_string());
@@ -504,32 +362,8 @@ pub async fn update_healthcheck(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = window {
         local_var_form_params.insert("window", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateHealthcheckError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -1,14 +1,13 @@
 /*
  * Fastly API
  *
- * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://developer.fastly.com/reference/api/) 
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
  *
  */
 
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_hist_stats`]
@@ -179,143 +178,91 @@ pub enum GetUsageServiceError {
 
 
 /// Fetches historical stats for each of your Fastly services and groups the results by service ID.
-pub async fn get_hist_stats(configuration: &mut configuration::Configuration, params: GetHistStatsParams) -> Result<crate::models::HistoricalResponse, Error<GetHistStatsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_hist_stats(configuration: &configuration::Configuration, params: GetHistStatsParams) -> Result<crate::models::HistoricalResponse, Error<GetHistStatsError>> {
+    get_hist_stats_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_hist_stats`], but also returns the status and headers of the response.
+pub async fn get_hist_stats_with_info(configuration: &configuration::Configuration, params: GetHistStatsParams) -> Result<crate::apis::Response<crate::models::HistoricalResponse>, Error<GetHistStatsError>> {
+    crate::apis::execute(configuration, get_hist_stats_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_hist_stats`] sends.
+pub(crate) fn get_hist_stats_request(params: GetHistStatsParams) -> crate::apis::Request {
     // unbox the parameters
     let from = params.from;
     let to = params.to;
     let by = params.by;
     let region = params.region;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/stats");
+    let mut local_var_req = crate::apis::Request::new("get_hist_stats", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = from {
-        local_var_req_builder = local_var_req_builder.query(&[("from", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("from", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = to {
-        local_var_req_builder = local_var_req_builder.query(&[("to", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("to", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = by {
-        local_var_req_builder = local_var_req_builder.query(&[("by", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("by", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = region {
-        local_var_req_builder = local_var_req_builder.query(&[("region", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("region", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetHistStatsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Fetches historical stats information aggregated across all of your Fastly services.
-pub async fn get_hist_stats_aggregated(configuration: &mut configuration::Configuration, params: GetHistStatsAggregatedParams) -> Result<crate::models::HistoricalAggregateResponse, Error<GetHistStatsAggregatedError>> {
-    let local_var_configuration = configuration;
+pub async fn get_hist_stats_aggregated(configuration: &configuration::Configuration, params: GetHistStatsAggregatedParams) -> Result<crate::models::HistoricalAggregateResponse, Error<GetHistStatsAggregatedError>> {
+    get_hist_stats_aggregated_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_hist_stats_aggregated`], but also returns the status and headers of the response.
+pub async fn get_hist_stats_aggregated_with_info(configuration: &configuration::Configuration, params: GetHistStatsAggregatedParams) -> Result<crate::apis::Response<crate::models::HistoricalAggregateResponse>, Error<GetHistStatsAggregatedError>> {
+    crate::apis::execute(configuration, get_hist_stats_aggregated_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_hist_stats_aggregated`] sends.
+pub(crate) fn get_hist_stats_aggregated_request(params: GetHistStatsAggregatedParams) -> crate::apis::Request {
     // unbox the parameters
     let from = params.from;
     let to = params.to;
     let by = params.by;
     let region = params.region;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats/aggregate", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/stats/aggregate");
+    let mut local_var_req = crate::apis::Request::new("get_hist_stats_aggregated", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = from {
-        local_var_req_builder = local_var_req_builder.query(&[("from", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("from", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = to {
-        local_var_req_builder = local_var_req_builder.query(&[("to", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("to", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = by {
-        local_var_req_builder = local_var_req_builder.query(&[("by", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("by", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = region {
-        local_var_req_builder = local_var_req_builder.query(&[("region", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("region", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetHistStatsAggregatedError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Fetches the specified field from the historical stats for each of your services and groups the results by service ID.
-pub async fn get_hist_stats_field(configuration: &mut configuration::Configuration, params: GetHistStatsFieldParams) -> Result<crate::models::HistoricalFieldResponse, Error<GetHistStatsFieldError>> {
-    let local_var_configuration = configuration;
+pub async fn get_hist_stats_field(configuration: &configuration::Configuration, params: GetHistStatsFieldParams) -> Result<crate::models::HistoricalFieldResponse, Error<GetHistStatsFieldError>> {
+    get_hist_stats_field_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_hist_stats_field`], but also returns the status and headers of the response.
+pub async fn get_hist_stats_field_with_info(configuration: &configuration::Configuration, params: GetHistStatsFieldParams) -> Result<crate::apis::Response<crate::models::HistoricalFieldResponse>, Error<GetHistStatsFieldError>> {
+    crate::apis::execute(configuration, get_hist_stats_field_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_hist_stats_field`] sends.
+pub(crate) fn get_hist_stats_field_request(params: GetHistStatsFieldParams) -> crate::apis::Request {
     // unbox the parameters
     let field = params.field;
     let from = params.from;
@@ -323,67 +270,37 @@ pub async fn get_hist_stats_field(configuration: &mut configuration::Configurati
     let by = params.by;
     let region = params.region;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats/field/{field}", local_var_configuration.base_path, field=crate::apis::urlencode(field));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/stats/field/{field}", field=crate::apis::urlencode(field));
+    let mut local_var_req = crate::apis::Request::new("get_hist_stats_field", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = from {
-        local_var_req_builder = local_var_req_builder.query(&[("from", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("from", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = to {
-        local_var_req_builder = local_var_req_builder.query(&[("to", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("to", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = by {
-        local_var_req_builder = local_var_req_builder.query(&[("by", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("by", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = region {
-        local_var_req_builder = local_var_req_builder.query(&[("region", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("region", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetHistStatsFieldError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Fetches historical stats for a given service.
-pub async fn get_hist_stats_service(configuration: &mut configuration::Configuration, params: GetHistStatsServiceParams) -> Result<crate::models::HistoricalAggregateResponse, Error<GetHistStatsServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn get_hist_stats_service(configuration: &configuration::Configuration, params: GetHistStatsServiceParams) -> Result<crate::models::HistoricalAggregateResponse, Error<GetHistStatsServiceError>> {
+    get_hist_stats_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_hist_stats_service`], but also returns the status and headers of the response.
+pub async fn get_hist_stats_service_with_info(configuration: &configuration::Configuration, params: GetHistStatsServiceParams) -> Result<crate::apis::Response<crate::models::HistoricalAggregateResponse>, Error<GetHistStatsServiceError>> {
+    crate::apis::execute(configuration, get_hist_stats_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_hist_stats_service`] sends.
+pub(crate) fn get_hist_stats_service_request(params: GetHistStatsServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let from = params.from;
@@ -391,67 +308,37 @@ pub async fn get_hist_stats_service(configuration: &mut configuration::Configura
     let by = params.by;
     let region = params.region;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats/service/{service_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/stats/service/{service_id}", service_id=crate::apis::urlencode(service_id));
+    let mut local_var_req = crate::apis::Request::new("get_hist_stats_service", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = from {
-        local_var_req_builder = local_var_req_builder.query(&[("from", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("from", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = to {
-        local_var_req_builder = local_var_req_builder.query(&[("to", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("to", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = by {
-        local_var_req_builder = local_var_req_builder.query(&[("by", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("by", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = region {
-        local_var_req_builder = local_var_req_builder.query(&[("region", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("region", local_var_str.to_string());
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetHistStatsServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Fetches the specified field from the historical stats for a given service.
-pub async fn get_hist_stats_service_field(configuration: &mut configuration::Configuration, params: GetHistStatsServiceFieldParams) -> Result<crate::models::HistoricalFieldAggregateResponse, Error<GetHistStatsServiceFieldError>> {
-    let local_var_configuration = configuration;
+pub async fn get_hist_stats_service_field(configuration: &configuration::Configuration, params: GetHistStatsServiceFieldParams) -> Result<crate::models::HistoricalFieldAggregateResponse, Error<GetHistStatsServiceFieldError>> {
+    get_hist_stats_service_field_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_hist_stats_service_field`], but also returns the status and headers of the response.
+pub async fn get_hist_stats_service_field_with_info(configuration: &configuration::Configuration, params: GetHistStatsServiceFieldParams) -> Result<crate::apis::Response<crate::models::HistoricalFieldAggregateResponse>, Error<GetHistStatsServiceFieldError>> {
+    crate::apis::execute(configuration, get_hist_stats_service_field_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_hist_stats_service_field`] sends.
+pub(crate) fn get_hist_stats_service_field_request(params: GetHistStatsServiceFieldParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let field = params.field;
@@ -460,292 +347,129 @@ pub async fn get_hist_stats_service_field(configuration: &mut configuration::Con
     let by = params.by;
     let region = params.region;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats/service/{service_id}/field/{field}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), field=crate::apis::urlencode(field));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/stats/service/{service_id}/field/{field}", service_id=crate::apis::urlencode(service_id), field=crate::apis::urlencode(field));
+    let mut local_var_req = crate::apis::Request::new("get_hist_stats_service_field", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = from {
-        local_var_req_builder = local_var_req_builder.query(&[("from", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("from", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = to {
-        local_var_req_builder = local_var_req_builder.query(&[("to", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("to", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = by {
-        local_var_req_builder = local_var_req_builder.query(&[("by", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("by", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = region {
-        local_var_req_builder = local_var_req_builder.query(&[("region", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("region", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetHistStatsServiceFieldError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Fetches the list of codes for regions that are covered by the Fastly CDN service.
-pub async fn get_regions(configuration: &mut configuration::Configuration) -> Result<crate::models::HistoricalRegionsResponse, Error<GetRegionsError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
-
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats/regions", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+pub async fn get_regions(configuration: &configuration::Configuration) -> Result<crate::models::HistoricalRegionsResponse, Error<GetRegionsError>> {
+    get_regions_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+/// Like [`get_regions`], but also returns the status and headers of the response.
+pub async fn get_regions_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::HistoricalRegionsResponse>, Error<GetRegionsError>> {
+    crate::apis::execute(configuration, get_regions_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetRegionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`get_regions`] sends.
+pub(crate) fn get_regions_request() -> crate::apis::Request {
+    let local_var_path = String::from("/stats/regions");
+    crate::apis::Request::new("get_regions", reqwest::Method::GET, local_var_path)
 }
 
 /// Returns usage information aggregated across all Fastly services and grouped by region. To aggregate across all Fastly services by time period, see [`/stats/aggregate`](#get-hist-stats-aggregated).
-pub async fn get_usage(configuration: &mut configuration::Configuration, params: GetUsageParams) -> Result<crate::models::HistoricalUsageAggregateResponse, Error<GetUsageError>> {
-    let local_var_configuration = configuration;
+pub async fn get_usage(configuration: &configuration::Configuration, params: GetUsageParams) -> Result<crate::models::HistoricalUsageAggregateResponse, Error<GetUsageError>> {
+    get_usage_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_usage`], but also returns the status and headers of the response.
+pub async fn get_usage_with_info(configuration: &configuration::Configuration, params: GetUsageParams) -> Result<crate::apis::Response<crate::models::HistoricalUsageAggregateResponse>, Error<GetUsageError>> {
+    crate::apis::execute(configuration, get_usage_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_usage`] sends.
+pub(crate) fn get_usage_request(params: GetUsageParams) -> crate::apis::Request {
     // unbox the parameters
     let from = params.from;
     let to = params.to;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats/usage", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/stats/usage");
+    let mut local_var_req = crate::apis::Request::new("get_usage", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = from {
-        local_var_req_builder = local_var_req_builder.query(&[("from", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("from", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = to {
-        local_var_req_builder = local_var_req_builder.query(&[("to", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("to", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetUsageError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Returns month-to-date usage details for a given month and year. Usage details are aggregated by service and across all Fastly services, and then grouped by region. This endpoint does not use the `from` or `to` fields for selecting the date for which data is requested. Instead, it uses `month` and `year` integer fields. Both fields are optional and default to the current month and year respectively. When set, an optional `billable_units` field will convert bandwidth to GB and divide requests by 10,000.
-pub async fn get_usage_month(configuration: &mut configuration::Configuration, params: GetUsageMonthParams) -> Result<crate::models::HistoricalUsageMonthResponse, Error<GetUsageMonthError>> {
-    let local_var_configuration = configuration;
+pub async fn get_usage_month(configuration: &configuration::Configuration, params: GetUsageMonthParams) -> Result<crate::models::HistoricalUsageMonthResponse, Error<GetUsageMonthError>> {
+    get_usage_month_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_usage_month`], but also returns the status and headers of the response.
+pub async fn get_usage_month_with_info(configuration: &configuration::Configuration, params: GetUsageMonthParams) -> Result<crate::apis::Response<crate::models::HistoricalUsageMonthResponse>, Error<GetUsageMonthError>> {
+    crate::apis::execute(configuration, get_usage_month_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_usage_month`] sends.
+pub(crate) fn get_usage_month_request(params: GetUsageMonthParams) -> crate::apis::Request {
     // unbox the parameters
     let year = params.year;
     let month = params.month;
     let billable_units = params.billable_units;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats/usage_by_month", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/stats/usage_by_month");
+    let mut local_var_req = crate::apis::Request::new("get_usage_month", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = year {
-        local_var_req_builder = local_var_req_builder.query(&[("year", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("year", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = month {
-        local_var_req_builder = local_var_req_builder.query(&[("month", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("month", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = billable_units {
-        local_var_req_builder = local_var_req_builder.query(&[("billable_units", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("billable_units", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetUsageMonthError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Returns usage information aggregated by service and grouped by service and region. For service stats by time period, see [`/stats`](#get-hist-stats) and [`/stats/field/:field`](#get-hist-stats-field).
-pub async fn get_usage_service(configuration: &mut configuration::Configuration, params: GetUsageServiceParams) -> Result<crate::models::HistoricalUsageServiceResponse, Error<GetUsageServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn get_usage_service(configuration: &configuration::Configuration, params: GetUsageServiceParams) -> Result<crate::models::HistoricalUsageServiceResponse, Error<GetUsageServiceError>> {
+    get_usage_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_usage_service`], but also returns the status and headers of the response.
+pub async fn get_usage_service_with_info(configuration: &configuration::Configuration, params: GetUsageServiceParams) -> Result<crate::apis::Response<crate::models::HistoricalUsageServiceResponse>, Error<GetUsageServiceError>> {
+    crate::apis::execute(configuration, get_usage_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_usage_service`] sends.
+pub(crate) fn get_usage_service_request(params: GetUsageServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let from = params.from;
     let to = params.to;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stats/usage_by_service", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/stats/usage_by_service");
+    let mut local_var_req = crate::apis::Request::new("get_usage_service", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = from {
-        local_var_req_builder = local_var_req_builder.query(&[("from", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("from", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = to {
-        local_var_req_builder = local_var_req_builder.query(&[("to", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("to", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetUsageServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_http3`]
@@ -72,9 +71,17 @@ pub enum GetHttp3Error {
 
 
 /// Enable HTTP/3 (QUIC) support for a particular service and version.
-pub async fn create_http3(configuration: &mut configuration::Configuration, params: CreateHttp3Params) -> Result<crate::models::Http3, Error<CreateHttp3Error>> {
-    let local_var_configuration = configuration;
+pub async fn create_http3(configuration: &configuration::Configuration, params: CreateHttp3Params) -> Result<crate::models::Http3, Error<CreateHttp3Error>> {
+    create_http3_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_http3`], but also returns the status and headers of the response.
+pub async fn create_http3_with_info(configuration: &configuration::Configuration, params: CreateHttp3Params) -> Result<crate::apis::Response<crate::models::Http3>, Error<CreateHttp3Error>> {
+    crate::apis::execute(configuration, create_http3_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_http3`] sends.
+pub(crate) fn create_http3_request(params: CreateHttp3Params) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -85,24 +92,10 @@ pub async fn create_http3(configuration: &mut configuration::Configuration, para
     let updated_at = params.updated_at;
     let feature_revision = params.feature_revision;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/http3", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_http3", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/http3", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = service_id2 {
         local_var_form_params.insert("service_id", local_var_param_value.to_string());
     }
@@ -121,138 +114,48 @@ pub async fn create_http3(configuration: &mut configuration::Configuration, para
     if let Some(local_var_param_value) = feature_revision {
         local_var_form_params.insert("feature_revision", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateHttp3Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Disable HTTP/3 (QUIC) support for a particular service and version.
-pub async fn delete_http3(configuration: &mut configuration::Configuration, params: DeleteHttp3Params) -> Result<crate::models::InlineResponse200, Error<DeleteHttp3Error>> {
-    let local_var_configuration = configuration;
+pub async fn delete_http3(configuration: &configuration::Configuration, params: DeleteHttp3Params) -> Result<crate::models::InlineResponse200, Error<DeleteHttp3Error>> {
+    delete_http3_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_http3`], but also returns the status and headers of the response.
+pub async fn delete_http3_with_info(configuration: &configuration::Configuration, params: DeleteHttp3Params) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteHttp3Error>> {
+    crate::apis::execute(configuration, delete_http3_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_http3`] sends.
+pub(crate) fn delete_http3_request(params: DeleteHttp3Params) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/http3", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteHttp3Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/http3", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("delete_http3", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the status of HTTP/3 (QUIC) support for a particular service and version.
-pub async fn get_http3(configuration: &mut configuration::Configuration, params: GetHttp3Params) -> Result<crate::models::Http3, Error<GetHttp3Error>> {
-    let local_var_configuration = configuration;
+pub async fn get_http3(configuration: &configuration::Configuration, params: GetHttp3Params) -> Result<crate::models::Http3, Error<GetHttp3Error>> {
+    get_http3_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_http3`], but also returns the status and headers of the response.
+pub async fn get_http3_with_info(configuration: &configuration::Configuration, params: GetHttp3Params) -> Result<crate::apis::Response<crate::models::Http3>, Error<GetHttp3Error>> {
+    crate::apis::execute(configuration, get_http3_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_http3`] sends.
+pub(crate) fn get_http3_request(params: GetHttp3Params) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/http3", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetHttp3Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/http3", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("get_http3", reqwest::Method::GET, local_var_path)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`add_role_permissions`]
@@ -130,432 +129,183 @@ pub enum UpdateARoleError {
 
 
 /// Add permissions to a role.
-pub async fn add_role_permissions(configuration: &mut configuration::Configuration, params: AddRolePermissionsParams) -> Result<serde_json::Value, Error<AddRolePermissionsError>> {
-    let local_var_configuration = configuration;
+pub async fn add_role_permissions(configuration: &configuration::Configuration, params: AddRolePermissionsParams) -> Result<serde_json::Value, Error<AddRolePermissionsError>> {
+    add_role_permissions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`add_role_permissions`], but also returns the status and headers of the response.
+pub async fn add_role_permissions_with_info(configuration: &configuration::Configuration, params: AddRolePermissionsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddRolePermissionsError>> {
+    crate::apis::execute(configuration, add_role_permissions_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`add_role_permissions`] sends.
+pub(crate) fn add_role_permissions_request(params: AddRolePermissionsParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let role_id = params.role_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/roles/{role_id}/permissions", role_id=crate::apis::urlencode(role_id));
+    let mut local_var_req = crate::apis::Request::new("add_role_permissions", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/roles/{role_id}/permissions", local_var_configuration.base_path, role_id=crate::apis::urlencode(role_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<AddRolePermissionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create a role.
-pub async fn create_a_role(configuration: &mut configuration::Configuration, params: CreateARoleParams) -> Result<serde_json::Value, Error<CreateARoleError>> {
-    let local_var_configuration = configuration;
+pub async fn create_a_role(configuration: &configuration::Configuration, params: CreateARoleParams) -> Result<serde_json::Value, Error<CreateARoleError>> {
+    create_a_role_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`create_a_role`], but also returns the status and headers of the response.
+pub async fn create_a_role_with_info(configuration: &configuration::Configuration, params: CreateARoleParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateARoleError>> {
+    crate::apis::execute(configuration, create_a_role_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`create_a_role`] sends.
+pub(crate) fn create_a_role_request(params: CreateARoleParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let request_body = params.request_body;
 
+    let local_var_path = String::from("/roles");
+    let mut local_var_req = crate::apis::Request::new("create_a_role", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/roles", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateARoleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete a role.
-pub async fn delete_a_role(configuration: &mut configuration::Configuration, params: DeleteARoleParams) -> Result<(), Error<DeleteARoleError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_a_role(configuration: &configuration::Configuration, params: DeleteARoleParams) -> Result<(), Error<DeleteARoleError>> {
+    delete_a_role_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_a_role`], but also returns the status and headers of the response.
+pub async fn delete_a_role_with_info(configuration: &configuration::Configuration, params: DeleteARoleParams) -> Result<crate::apis::Response<()>, Error<DeleteARoleError>> {
+    crate::apis::execute(configuration, delete_a_role_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_a_role`] sends.
+pub(crate) fn delete_a_role_request(params: DeleteARoleParams) -> crate::apis::Request {
     // unbox the parameters
     let role_id = params.role_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/roles/{role_id}", local_var_configuration.base_path, role_id=crate::apis::urlencode(role_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteARoleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/roles/{role_id}", role_id=crate::apis::urlencode(role_id));
+    crate::apis::Request::new("delete_a_role", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a role.
-pub async fn get_a_role(configuration: &mut configuration::Configuration, params: GetARoleParams) -> Result<serde_json::Value, Error<GetARoleError>> {
-    let local_var_configuration = configuration;
+pub async fn get_a_role(configuration: &configuration::Configuration, params: GetARoleParams) -> Result<serde_json::Value, Error<GetARoleError>> {
+    get_a_role_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_a_role`], but also returns the status and headers of the response.
+pub async fn get_a_role_with_info(configuration: &configuration::Configuration, params: GetARoleParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetARoleError>> {
+    crate::apis::execute(configuration, get_a_role_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_a_role`] sends.
+pub(crate) fn get_a_role_request(params: GetARoleParams) -> crate::apis::Request {
     // unbox the parameters
     let role_id = params.role_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/roles/{role_id}", local_var_configuration.base_path, role_id=crate::apis::urlencode(role_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetARoleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/roles/{role_id}", role_id=crate::apis::urlencode(role_id));
+    crate::apis::Request::new("get_a_role", reqwest::Method::GET, local_var_path)
 }
 
 /// List all permissions in a role.
-pub async fn list_role_permissions(configuration: &mut configuration::Configuration, params: ListRolePermissionsParams) -> Result<serde_json::Value, Error<ListRolePermissionsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_role_permissions(configuration: &configuration::Configuration, params: ListRolePermissionsParams) -> Result<serde_json::Value, Error<ListRolePermissionsError>> {
+    list_role_permissions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_role_permissions`], but also returns the status and headers of the response.
+pub async fn list_role_permissions_with_info(configuration: &configuration::Configuration, params: ListRolePermissionsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListRolePermissionsError>> {
+    crate::apis::execute(configuration, list_role_permissions_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_role_permissions`] sends.
+pub(crate) fn list_role_permissions_request(params: ListRolePermissionsParams) -> crate::apis::Request {
     // unbox the parameters
     let role_id = params.role_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/roles/{role_id}/permissions", local_var_configuration.base_path, role_id=crate::apis::urlencode(role_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListRolePermissionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/roles/{role_id}/permissions", role_id=crate::apis::urlencode(role_id));
+    crate::apis::Request::new("list_role_permissions", reqwest::Method::GET, local_var_path)
 }
 
 /// List all roles.
-pub async fn list_roles(configuration: &mut configuration::Configuration, params: ListRolesParams) -> Result<serde_json::Value, Error<ListRolesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_roles(configuration: &configuration::Configuration, params: ListRolesParams) -> Result<serde_json::Value, Error<ListRolesError>> {
+    list_roles_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_roles`], but also returns the status and headers of the response.
+pub async fn list_roles_with_info(configuration: &configuration::Configuration, params: ListRolesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListRolesError>> {
+    crate::apis::execute(configuration, list_roles_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_roles`] sends.
+pub(crate) fn list_roles_request(params: ListRolesParams) -> crate::apis::Request {
     // unbox the parameters
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/roles", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/roles");
+    let mut local_var_req = crate::apis::Request::new("list_roles", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListRolesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Remove permissions from a role.
-pub async fn remove_role_permissions(configuration: &mut configuration::Configuration, params: RemoveRolePermissionsParams) -> Result<(), Error<RemoveRolePermissionsError>> {
-    let local_var_configuration = configuration;
+pub async fn remove_role_permissions(configuration: &configuration::Configuration, params: RemoveRolePermissionsParams) -> Result<(), Error<RemoveRolePermissionsError>> {
+    remove_role_permissions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`remove_role_permissions`], but also returns the status and headers of the response.
+pub async fn remove_role_permissions_with_info(configuration: &configuration::Configuration, params: RemoveRolePermissionsParams) -> Result<crate::apis::Response<()>, Error<RemoveRolePermissionsError>> {
+    crate::apis::execute(configuration, remove_role_permissions_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`remove_role_permissions`] sends.
+pub(crate) fn remove_role_permissions_request(params: RemoveRolePermissionsParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let role_id = params.role_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/roles/{role_id}/permissions", role_id=crate::apis::urlencode(role_id));
+    let mut local_var_req = crate::apis::Request::new("remove_role_permissions", reqwest::Method::DELETE, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/roles/{role_id}/permissions", local_var_configuration.base_path, role_id=crate::apis::urlencode(role_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<RemoveRolePermissionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Update a role.
-pub async fn update_a_role(configuration: &mut configuration::Configuration, params: UpdateARoleParams) -> Result<serde_json::Value, Error<UpdateARoleError>> {
-    let local_var_configuration = configuration;
+pub async fn update_a_role(configuration: &configuration::Configuration, params: UpdateARoleParams) -> Result<serde_json::Value, Error<UpdateARoleError>> {
+    update_a_role_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_a_role`], but also returns the status and headers of the response.
+pub async fn update_a_role_with_info(configuration: &configuration::Configuration, params: UpdateARoleParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateARoleError>> {
+    crate::apis::execute(configuration, update_a_role_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_a_role`] sends.
+pub(crate) fn update_a_role_request(params: UpdateARoleParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let role_id = params.role_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/roles/{role_id}", role_id=crate::apis::urlencode(role_id));
+    let mut local_var_req = crate::apis::Request::new("update_a_role", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_uri_str = format!("{}/roles/{role_id}", local_var_configuration.base_path, role_id=crate::apis::urlencode(role_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateARoleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`add_service_group_services`]
@@ -134,440 +133,194 @@ pub enum UpdateAServiceGroupError {
 
 
 /// Add services in a service group.
-pub async fn add_service_group_services(configuration: &mut configuration::Configuration, params: AddServiceGroupServicesParams) -> Result<serde_json::Value, Error<AddServiceGroupServicesError>> {
-    let local_var_configuration = configuration;
+pub async fn add_service_group_services(configuration: &configuration::Configuration, params: AddServiceGroupServicesParams) -> Result<serde_json::Value, Error<AddServiceGroupServicesError>> {
+    add_service_group_services_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`add_service_group_services`], but also returns the status and headers of the response.
+pub async fn add_service_group_services_with_info(configuration: &configuration::Configuration, params: AddServiceGroupServicesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddServiceGroupServicesError>> {
+    crate::apis::execute(configuration, add_service_group_services_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`add_service_group_services`] sends.
+pub(crate) fn add_service_group_services_request(params: AddServiceGroupServicesParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_group_id = params.service_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service-groups/{service_group_id}/services", service_group_id=crate::apis::urlencode(service_group_id));
+    let mut local_var_req = crate::apis::Request::new("add_service_group_services", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-groups/{service_group_id}/services", local_var_configuration.base_path, service_group_id=crate::apis::urlencode(service_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<AddServiceGroupServicesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create a service group.
-pub async fn create_a_service_group(configuration: &mut configuration::Configuration, params: CreateAServiceGroupParams) -> Result<serde_json::Value, Error<CreateAServiceGroupError>> {
-    let local_var_configuration = configuration;
+pub async fn create_a_service_group(configuration: &configuration::Configuration, params: CreateAServiceGroupParams) -> Result<serde_json::Value, Error<CreateAServiceGroupError>> {
+    create_a_service_group_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`create_a_service_group`], but also returns the status and headers of the response.
+pub async fn create_a_service_group_with_info(configuration: &configuration::Configuration, params: CreateAServiceGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateAServiceGroupError>> {
+    crate::apis::execute(configuration, create_a_service_group_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`create_a_service_group`] sends.
+pub(crate) fn create_a_service_group_request(params: CreateAServiceGroupParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let request_body = params.request_body;
 
+    let local_var_path = String::from("/service-groups");
+    let mut local_var_req = crate::apis::Request::new("create_a_service_group", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-groups", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateAServiceGroupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete a service group.
-pub async fn delete_a_service_group(configuration: &mut configuration::Configuration, params: DeleteAServiceGroupParams) -> Result<(), Error<DeleteAServiceGroupError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_a_service_group(configuration: &configuration::Configuration, params: DeleteAServiceGroupParams) -> Result<(), Error<DeleteAServiceGroupError>> {
+    delete_a_service_group_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_a_service_group`], but also returns the status and headers of the response.
+pub async fn delete_a_service_group_with_info(configuration: &configuration::Configuration, params: DeleteAServiceGroupParams) -> Result<crate::apis::Response<()>, Error<DeleteAServiceGroupError>> {
+    crate::apis::execute(configuration, delete_a_service_group_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_a_service_group`] sends.
+pub(crate) fn delete_a_service_group_request(params: DeleteAServiceGroupParams) -> crate::apis::Request {
     // unbox the parameters
     let service_group_id = params.service_group_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-groups/{service_group_id}", local_var_configuration.base_path, service_group_id=crate::apis::urlencode(service_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteAServiceGroupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service-groups/{service_group_id}", service_group_id=crate::apis::urlencode(service_group_id));
+    crate::apis::Request::new("delete_a_service_group", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a service group.
-pub async fn get_a_service_group(configuration: &mut configuration::Configuration, params: GetAServiceGroupParams) -> Result<serde_json::Value, Error<GetAServiceGroupError>> {
-    let local_var_configuration = configuration;
+pub async fn get_a_service_group(configuration: &configuration::Configuration, params: GetAServiceGroupParams) -> Result<serde_json::Value, Error<GetAServiceGroupError>> {
+    get_a_service_group_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_a_service_group`], but also returns the status and headers of the response.
+pub async fn get_a_service_group_with_info(configuration: &configuration::Configuration, params: GetAServiceGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetAServiceGroupError>> {
+    crate::apis::execute(configuration, get_a_service_group_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_a_service_group`] sends.
+pub(crate) fn get_a_service_group_request(params: GetAServiceGroupParams) -> crate::apis::Request {
     // unbox the parameters
     let service_group_id = params.service_group_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-groups/{service_group_id}", local_var_configuration.base_path, service_group_id=crate::apis::urlencode(service_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetAServiceGroupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service-groups/{service_group_id}", service_group_id=crate::apis::urlencode(service_group_id));
+    crate::apis::Request::new("get_a_service_group", reqwest::Method::GET, local_var_path)
 }
 
 /// List services to a service group.
-pub async fn list_service_group_services(configuration: &mut configuration::Configuration, params: ListServiceGroupServicesParams) -> Result<serde_json::Value, Error<ListServiceGroupServicesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_service_group_services(configuration: &configuration::Configuration, params: ListServiceGroupServicesParams) -> Result<serde_json::Value, Error<ListServiceGroupServicesError>> {
+    list_service_group_services_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_service_group_services`], but also returns the status and headers of the response.
+pub async fn list_service_group_services_with_info(configuration: &configuration::Configuration, params: ListServiceGroupServicesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListServiceGroupServicesError>> {
+    crate::apis::execute(configuration, list_service_group_services_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_service_group_services`] sends.
+pub(crate) fn list_service_group_services_request(params: ListServiceGroupServicesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_group_id = params.service_group_id;
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-groups/{service_group_id}/services", local_var_configuration.base_path, service_group_id=crate::apis::urlencode(service_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service-groups/{service_group_id}/services", service_group_id=crate::apis::urlencode(service_group_id));
+    let mut local_var_req = crate::apis::Request::new("list_service_group_services", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListServiceGroupServicesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all service groups.
-pub async fn list_service_groups(configuration: &mut configuration::Configuration, params: ListServiceGroupsParams) -> Result<serde_json::Value, Error<ListServiceGroupsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_service_groups(configuration: &configuration::Configuration, params: ListServiceGroupsParams) -> Result<serde_json::Value, Error<ListServiceGroupsError>> {
+    list_service_groups_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_service_groups`], but also returns the status and headers of the response.
+pub async fn list_service_groups_with_info(configuration: &configuration::Configuration, params: ListServiceGroupsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListServiceGroupsError>> {
+    crate::apis::execute(configuration, list_service_groups_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_service_groups`] sends.
+pub(crate) fn list_service_groups_request(params: ListServiceGroupsParams) -> crate::apis::Request {
     // unbox the parameters
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-groups", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/service-groups");
+    let mut local_var_req = crate::apis::Request::new("list_service_groups", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListServiceGroupsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Remove services from a service group.
-pub async fn remove_service_group_services(configuration: &mut configuration::Configuration, params: RemoveServiceGroupServicesParams) -> Result<(), Error<RemoveServiceGroupServicesError>> {
-    let local_var_configuration = configuration;
+pub async fn remove_service_group_services(configuration: &configuration::Configuration, params: RemoveServiceGroupServicesParams) -> Result<(), Error<RemoveServiceGroupServicesError>> {
+    remove_service_group_services_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`remove_service_group_services`], but also returns the status and headers of the response.
+pub async fn remove_service_group_services_with_info(configuration: &configuration::Configuration, params: RemoveServiceGroupServicesParams) -> Result<crate::apis::Response<()>, Error<RemoveServiceGroupServicesError>> {
+    crate::apis::execute(configuration, remove_service_group_services_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`remove_service_group_services`] sends.
+pub(crate) fn remove_service_group_services_request(params: RemoveServiceGroupServicesParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_group_id = params.service_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service-groups/{service_group_id}/services", service_group_id=crate::apis::urlencode(service_group_id));
+    let mut local_var_req = crate::apis::Request::new("remove_service_group_services", reqwest::Method::DELETE, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-groups/{service_group_id}/services", local_var_configuration.base_path, service_group_id=crate::apis::urlencode(service_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<RemoveServiceGroupServicesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Update a service group.
-pub async fn update_a_service_group(configuration: &mut configuration::Configuration, params: UpdateAServiceGroupParams) -> Result<serde_json::Value, Error<UpdateAServiceGroupError>> {
-    let local_var_configuration = configuration;
+pub async fn update_a_service_group(configuration: &configuration::Configuration, params: UpdateAServiceGroupParams) -> Result<serde_json::Value, Error<UpdateAServiceGroupError>> {
+    update_a_service_group_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_a_service_group`], but also returns the status and headers of the response.
+pub async fn update_a_service_group_with_info(configuration: &configuration::Configuration, params: UpdateAServiceGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateAServiceGroupError>> {
+    crate::apis::execute(configuration, update_a_service_group_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_a_service_group`] sends.
+pub(crate) fn update_a_service_group_request(params: UpdateAServiceGroupParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_group_id = params.service_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service-groups/{service_group_id}", service_group_id=crate::apis::urlencode(service_group_id));
+    let mut local_var_req = crate::apis::Request::new("update_a_service_group", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_uri_str = format!("{}/service-groups/{service_group_id}", local_var_configuration.base_path, service_group_id=crate::apis::urlencode(service_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateAServiceGroupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
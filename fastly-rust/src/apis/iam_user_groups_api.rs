@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`add_user_group_members`]
@@ -230,776 +229,350 @@ pub enum UpdateAUserGroupError {
 
 
 /// Add members to a user group.
-pub async fn add_user_group_members(configuration: &mut configuration::Configuration, params: AddUserGroupMembersParams) -> Result<serde_json::Value, Error<AddUserGroupMembersError>> {
-    let local_var_configuration = configuration;
+pub async fn add_user_group_members(configuration: &configuration::Configuration, params: AddUserGroupMembersParams) -> Result<serde_json::Value, Error<AddUserGroupMembersError>> {
+    add_user_group_members_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`add_user_group_members`], but also returns the status and headers of the response.
+pub async fn add_user_group_members_with_info(configuration: &configuration::Configuration, params: AddUserGroupMembersParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddUserGroupMembersError>> {
+    crate::apis::execute(configuration, add_user_group_members_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`add_user_group_members`] sends.
+pub(crate) fn add_user_group_members_request(params: AddUserGroupMembersParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/user-groups/{user_group_id}/members", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("add_user_group_members", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/members", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<AddUserGroupMembersError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Add roles to a user group.
-pub async fn add_user_group_roles(configuration: &mut configuration::Configuration, params: AddUserGroupRolesParams) -> Result<serde_json::Value, Error<AddUserGroupRolesError>> {
-    let local_var_configuration = configuration;
+pub async fn add_user_group_roles(configuration: &configuration::Configuration, params: AddUserGroupRolesParams) -> Result<serde_json::Value, Error<AddUserGroupRolesError>> {
+    add_user_group_roles_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`add_user_group_roles`], but also returns the status and headers of the response.
+pub async fn add_user_group_roles_with_info(configuration: &configuration::Configuration, params: AddUserGroupRolesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddUserGroupRolesError>> {
+    crate::apis::execute(configuration, add_user_group_roles_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`add_user_group_roles`] sends.
+pub(crate) fn add_user_group_roles_request(params: AddUserGroupRolesParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/user-groups/{user_group_id}/roles", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("add_user_group_roles", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/roles", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<AddUserGroupRolesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Add service groups to a user group.
-pub async fn add_user_group_service_groups(configuration: &mut configuration::Configuration, params: AddUserGroupServiceGroupsParams) -> Result<serde_json::Value, Error<AddUserGroupServiceGroupsError>> {
-    let local_var_configuration = configuration;
+pub async fn add_user_group_service_groups(configuration: &configuration::Configuration, params: AddUserGroupServiceGroupsParams) -> Result<serde_json::Value, Error<AddUserGroupServiceGroupsError>> {
+    add_user_group_service_groups_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`add_user_group_service_groups`], but also returns the status and headers of the response.
+pub async fn add_user_group_service_groups_with_info(configuration: &configuration::Configuration, params: AddUserGroupServiceGroupsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddUserGroupServiceGroupsError>> {
+    crate::apis::execute(configuration, add_user_group_service_groups_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`add_user_group_service_groups`] sends.
+pub(crate) fn add_user_group_service_groups_request(params: AddUserGroupServiceGroupsParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/user-groups/{user_group_id}/service-groups", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("add_user_group_service_groups", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/service-groups", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<AddUserGroupServiceGroupsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create a user group.
-pub async fn create_a_user_group(configuration: &mut configuration::Configuration, params: CreateAUserGroupParams) -> Result<serde_json::Value, Error<CreateAUserGroupError>> {
-    let local_var_configuration = configuration;
+pub async fn create_a_user_group(configuration: &configuration::Configuration, params: CreateAUserGroupParams) -> Result<serde_json::Value, Error<CreateAUserGroupError>> {
+    create_a_user_group_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_a_user_group`], but also returns the status and headers of the response.
+pub async fn create_a_user_group_with_info(configuration: &configuration::Configuration, params: CreateAUserGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateAUserGroupError>> {
+    crate::apis::execute(configuration, create_a_user_group_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_a_user_group`] sends.
+pub(crate) fn create_a_user_group_request(params: CreateAUserGroupParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let request_body = params.request_body;
 
+    let local_var_path = String::from("/user-groups");
+    let mut local_var_req = crate::apis::Request::new("create_a_user_group", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateAUserGroupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete a user group.
-pub async fn delete_a_user_group(configuration: &mut configuration::Configuration, params: DeleteAUserGroupParams) -> Result<(), Error<DeleteAUserGroupError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_a_user_group(configuration: &configuration::Configuration, params: DeleteAUserGroupParams) -> Result<(), Error<DeleteAUserGroupError>> {
+    delete_a_user_group_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_a_user_group`], but also returns the status and headers of the response.
+pub async fn delete_a_user_group_with_info(configuration: &configuration::Configuration, params: DeleteAUserGroupParams) -> Result<crate::apis::Response<()>, Error<DeleteAUserGroupError>> {
+    crate::apis::execute(configuration, delete_a_user_group_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_a_user_group`] sends.
+pub(crate) fn delete_a_user_group_request(params: DeleteAUserGroupParams) -> crate::apis::Request {
     // unbox the parameters
     let user_group_id = params.user_group_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteAUserGroupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/user-groups/{user_group_id}", user_group_id=crate::apis::urlencode(user_group_id));
+    crate::apis::Request::new("delete_a_user_group", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a user group.
-pub async fn get_a_user_group(configuration: &mut configuration::Configuration, params: GetAUserGroupParams) -> Result<serde_json::Value, Error<GetAUserGroupError>> {
-    let local_var_configuration = configuration;
+pub async fn get_a_user_group(configuration: &configuration::Configuration, params: GetAUserGroupParams) -> Result<serde_json::Value, Error<GetAUserGroupError>> {
+    get_a_user_group_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_a_user_group`], but also returns the status and headers of the response.
+pub async fn get_a_user_group_with_info(configuration: &configuration::Configuration, params: GetAUserGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetAUserGroupError>> {
+    crate::apis::execute(configuration, get_a_user_group_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_a_user_group`] sends.
+pub(crate) fn get_a_user_group_request(params: GetAUserGroupParams) -> crate::apis::Request {
     // unbox the parameters
     let user_group_id = params.user_group_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetAUserGroupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/user-groups/{user_group_id}", user_group_id=crate::apis::urlencode(user_group_id));
+    crate::apis::Request::new("get_a_user_group", reqwest::Method::GET, local_var_path)
 }
 
 /// List members of a user group.
-pub async fn list_user_group_members(configuration: &mut configuration::Configuration, params: ListUserGroupMembersParams) -> Result<serde_json::Value, Error<ListUserGroupMembersError>> {
-    let local_var_configuration = configuration;
+pub async fn list_user_group_members(configuration: &configuration::Configuration, params: ListUserGroupMembersParams) -> Result<serde_json::Value, Error<ListUserGroupMembersError>> {
+    list_user_group_members_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_user_group_members`], but also returns the status and headers of the response.
+pub async fn list_user_group_members_with_info(configuration: &configuration::Configuration, params: ListUserGroupMembersParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListUserGroupMembersError>> {
+    crate::apis::execute(configuration, list_user_group_members_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_user_group_members`] sends.
+pub(crate) fn list_user_group_members_request(params: ListUserGroupMembersParams) -> crate::apis::Request {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/members", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/user-groups/{user_group_id}/members", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("list_user_group_members", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListUserGroupMembersError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List roles in a user group.
-pub async fn list_user_group_roles(configuration: &mut configuration::Configuration, params: ListUserGroupRolesParams) -> Result<serde_json::Value, Error<ListUserGroupRolesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_user_group_roles(configuration: &configuration::Configuration, params: ListUserGroupRolesParams) -> Result<serde_json::Value, Error<ListUserGroupRolesError>> {
+    list_user_group_roles_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_user_group_roles`], but also returns the status and headers of the response.
+pub async fn list_user_group_roles_with_info(configuration: &configuration::Configuration, params: ListUserGroupRolesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListUserGroupRolesError>> {
+    crate::apis::execute(configuration, list_user_group_roles_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_user_group_roles`] sends.
+pub(crate) fn list_user_group_roles_request(params: ListUserGroupRolesParams) -> crate::apis::Request {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/roles", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/user-groups/{user_group_id}/roles", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("list_user_group_roles", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListUserGroupRolesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List service groups in a user group.
-pub async fn list_user_group_service_groups(configuration: &mut configuration::Configuration, params: ListUserGroupServiceGroupsParams) -> Result<serde_json::Value, Error<ListUserGroupServiceGroupsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_user_group_service_groups(configuration: &configuration::Configuration, params: ListUserGroupServiceGroupsParams) -> Result<serde_json::Value, Error<ListUserGroupServiceGroupsError>> {
+    list_user_group_service_groups_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_user_group_service_groups`], but also returns the status and headers of the response.
+pub async fn list_user_group_service_groups_with_info(configuration: &configuration::Configuration, params: ListUserGroupServiceGroupsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListUserGroupServiceGroupsError>> {
+    crate::apis::execute(configuration, list_user_group_service_groups_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_user_group_service_groups`] sends.
+pub(crate) fn list_user_group_service_groups_request(params: ListUserGroupServiceGroupsParams) -> crate::apis::Request {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/service-groups", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/user-groups/{user_group_id}/service-groups", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("list_user_group_service_groups", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListUserGroupServiceGroupsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all user groups.
-pub async fn list_user_groups(configuration: &mut configuration::Configuration, params: ListUserGroupsParams) -> Result<serde_json::Value, Error<ListUserGroupsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_user_groups(configuration: &configuration::Configuration, params: ListUserGroupsParams) -> Result<serde_json::Value, Error<ListUserGroupsError>> {
+    list_user_groups_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_user_groups`], but also returns the status and headers of the response.
+pub async fn list_user_groups_with_info(configuration: &configuration::Configuration, params: ListUserGroupsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListUserGroupsError>> {
+    crate::apis::execute(configuration, list_user_groups_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_user_groups`] sends.
+pub(crate) fn list_user_groups_request(params: ListUserGroupsParams) -> crate::apis::Request {
     // unbox the parameters
     let per_page = params.per_page;
     let page = params.page;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/user-groups");
+    let mut local_var_req = crate::apis::Request::new("list_user_groups", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListUserGroupsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Remove members of a user group
-pub async fn remove_user_group_members(configuration: &mut configuration::Configuration, params: RemoveUserGroupMembersParams) -> Result<(), Error<RemoveUserGroupMembersError>> {
-    let local_var_configuration = configuration;
+pub async fn remove_user_group_members(configuration: &configuration::Configuration, params: RemoveUserGroupMembersParams) -> Result<(), Error<RemoveUserGroupMembersError>> {
+    remove_user_group_members_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`remove_user_group_members`], but also returns the status and headers of the response.
+pub async fn remove_user_group_members_with_info(configuration: &configuration::Configuration, params: RemoveUserGroupMembersParams) -> Result<crate::apis::Response<()>, Error<RemoveUserGroupMembersError>> {
+    crate::apis::execute(configuration, remove_user_group_members_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`remove_user_group_members`] sends.
+pub(crate) fn remove_user_group_members_request(params: RemoveUserGroupMembersParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/user-groups/{user_group_id}/members", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("remove_user_group_members", reqwest::Method::DELETE, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/members", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<RemoveUserGroupMembersError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Remove roles from a user group.
-pub async fn remove_user_group_roles(configuration: &mut configuration::Configuration, params: RemoveUserGroupRolesParams) -> Result<(), Error<RemoveUserGroupRolesError>> {
-    let local_var_configuration = configuration;
+pub async fn remove_user_group_roles(configuration: &configuration::Configuration, params: RemoveUserGroupRolesParams) -> Result<(), Error<RemoveUserGroupRolesError>> {
+    remove_user_group_roles_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`remove_user_group_roles`], but also returns the status and headers of the response.
+pub async fn remove_user_group_roles_with_info(configuration: &configuration::Configuration, params: RemoveUserGroupRolesParams) -> Result<crate::apis::Response<()>, Error<RemoveUserGroupRolesError>> {
+    crate::apis::execute(configuration, remove_user_group_roles_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`remove_user_group_roles`] sends.
+pub(crate) fn remove_user_group_roles_request(params: RemoveUserGroupRolesParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/user-groups/{user_group_id}/roles", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("remove_user_group_roles", reqwest::Method::DELETE, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/roles", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<RemoveUserGroupRolesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Remove service groups from a user group.
-pub async fn remove_user_group_service_groups(configuration: &mut configuration::Configuration, params: RemoveUserGroupServiceGroupsParams) -> Result<(), Error<RemoveUserGroupServiceGroupsError>> {
-    let local_var_configuration = configuration;
+pub async fn remove_user_group_service_groups(configuration: &configuration::Configuration, params: RemoveUserGroupServiceGroupsParams) -> Result<(), Error<RemoveUserGroupServiceGroupsError>> {
+    remove_user_group_service_groups_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`remove_user_group_service_groups`], but also returns the status and headers of the response.
+pub async fn remove_user_group_service_groups_with_info(configuration: &configuration::Configuration, params: RemoveUserGroupServiceGroupsParams) -> Result<crate::apis::Response<()>, Error<RemoveUserGroupServiceGroupsError>> {
+    crate::apis::execute(configuration, remove_user_group_service_groups_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`remove_user_group_service_groups`] sends.
+pub(crate) fn remove_user_group_service_groups_request(params: RemoveUserGroupServiceGroupsParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/user-groups/{user_group_id}/service-groups", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("remove_user_group_service_groups", reqwest::Method::DELETE, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}/service-groups", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<RemoveUserGroupServiceGroupsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Update a user group.
-pub async fn update_a_user_group(configuration: &mut configuration::Configuration, params: UpdateAUserGroupParams) -> Result<serde_json::Value, Error<UpdateAUserGroupError>> {
-    let local_var_configuration = configuration;
+pub async fn update_a_user_group(configuration: &configuration::Configuration, params: UpdateAUserGroupParams) -> Result<serde_json::Value, Error<UpdateAUserGroupError>> {
+    update_a_user_group_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_a_user_group`], but also returns the status and headers of the response.
+pub async fn update_a_user_group_with_info(configuration: &configuration::Configuration, params: UpdateAUserGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateAUserGroupError>> {
+    crate::apis::execute(configuration, update_a_user_group_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_a_user_group`] sends.
+pub(crate) fn update_a_user_group_request(params: UpdateAUserGroupParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let user_group_id = params.user_group_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/user-groups/{user_group_id}", user_group_id=crate::apis::urlencode(user_group_id));
+    let mut local_var_req = crate::apis::Request::new("update_a_user_group", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user-groups/{user_group_id}", local_var_configuration.base_path, user_group_id=crate::apis::urlencode(user_group_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateAUserGroupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -0,0 +1,72 @@
+//! Tracing instrumentation for API calls.
+//!
+//! With the `tracing` feature, every call runs inside a `fastly_api.request`
+//! span carrying the endpoint, method, service ID and version, and records the
+//! final status, latency and retry count on it. Each attempt is logged at
+//! `DEBUG` with the redacted request. Without the feature these hooks are
+//! no-ops.
+
+use std::future::Future;
+use std::time::Duration;
+
+use super::Request;
+
+#[cfg(feature = "tracing")]
+pub(crate) type Span = tracing::Span;
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct Span;
+
+#[cfg(feature = "tracing")]
+pub(crate) fn request_span(request: &Request) -> Span {
+    let (service_id, version) = request.service_version();
+    tracing::info_span!(
+        "fastly_api.request",
+        endpoint = request.operation,
+        method = %request.method,
+        service_id,
+        version,
+        status = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+        retries = tracing::field::Empty,
+    )
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn request_span(_request: &Request) -> Span {
+    Span
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn in_span<F: Future>(span: Span, future: F) -> impl Future<Output = F::Output> {
+    tracing::Instrument::instrument(future, span)
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn in_span<F: Future>(_span: Span, future: F) -> F {
+    future
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn attempt(request: &Request, attempt: u32) {
+    tracing::debug!(attempt, ?request, "sending request");
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn attempt(_request: &Request, _attempt: u32) {}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn outcome(result: &Result<reqwest::Response, reqwest::Error>, latency: Duration, retries: u32) {
+    let span = tracing::Span::current();
+    span.record("latency_ms", latency.as_millis() as u64);
+    span.record("retries", retries);
+    match result {
+        Ok(response) => {
+            span.record("status", response.status().as_u16());
+            tracing::debug!(status = response.status().as_u16(), "received response");
+        }
+        Err(error) => tracing::debug!(%error, "request failed"),
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn outcome(_result: &Result<reqwest::Response, reqwest::Error>, _latency: Duration, _retries: u32) {}
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_invitation`]
@@ -57,166 +56,73 @@ pub enum ListInvitationsError {
 
 
 /// Create an invitation.
-pub async fn create_invitation(configuration: &mut configuration::Configuration, params: CreateInvitationParams) -> Result<crate::models::InvitationResponse, Error<CreateInvitationError>> {
-    let local_var_configuration = configuration;
+pub async fn create_invitation(configuration: &configuration::Configuration, params: CreateInvitationParams) -> Result<crate::models::InvitationResponse, Error<CreateInvitationError>> {
+    create_invitation_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_invitation`], but also returns the status and headers of the response.
+pub async fn create_invitation_with_info(configuration: &configuration::Configuration, params: CreateInvitationParams) -> Result<crate::apis::Response<crate::models::InvitationResponse>, Error<CreateInvitationError>> {
+    crate::apis::execute(configuration, create_invitation_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_invitation`] sends.
+pub(crate) fn create_invitation_request(params: CreateInvitationParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let invitation = params.invitation;
 
+    let local_var_path = String::from("/invitations");
+    let mut local_var_req = crate::apis::Request::new("create_invitation", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/invitations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&invitation);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&invitation)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateInvitationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete an invitation.
-pub async fn delete_invitation(configuration: &mut configuration::Configuration, params: DeleteInvitationParams) -> Result<(), Error<DeleteInvitationError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_invitation(configuration: &configuration::Configuration, params: DeleteInvitationParams) -> Result<(), Error<DeleteInvitationError>> {
+    delete_invitation_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_invitation`], but also returns the status and headers of the response.
+pub async fn delete_invitation_with_info(configuration: &configuration::Configuration, params: DeleteInvitationParams) -> Result<crate::apis::Response<()>, Error<DeleteInvitationError>> {
+    crate::apis::execute(configuration, delete_invitation_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_invitation`] sends.
+pub(crate) fn delete_invitation_request(params: DeleteInvitationParams) -> crate::apis::Request {
     // unbox the parameters
     let invitation_id = params.invitation_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/invitations/{invitation_id}", local_var_configuration.base_path, invitation_id=crate::apis::urlencode(invitation_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteInvitationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/invitations/{invitation_id}", invitation_id=crate::apis::urlencode(invitation_id));
+    crate::apis::Request::new("delete_invitation", reqwest::Method::DELETE, local_var_path)
 }
 
 /// List all invitations.
-pub async fn list_invitations(configuration: &mut configuration::Configuration, params: ListInvitationsParams) -> Result<crate::models::InvitationsResponse, Error<ListInvitationsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_invitations(configuration: &configuration::Configuration, params: ListInvitationsParams) -> Result<crate::models::InvitationsResponse, Error<ListInvitationsError>> {
+    list_invitations_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_invitations`], but also returns the status and headers of the response.
+pub async fn list_invitations_with_info(configuration: &configuration::Configuration, params: ListInvitationsParams) -> Result<crate::apis::Response<crate::models::InvitationsResponse>, Error<ListInvitationsError>> {
+    crate::apis::execute(configuration, list_invitations_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_invitations`] sends.
+pub(crate) fn list_invitations_request(params: ListInvitationsParams) -> crate::apis::Request {
     // unbox the parameters
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/invitations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/invitations");
+    let mut local_var_req = crate::apis::Request::new("list_invitations", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListInvitationsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
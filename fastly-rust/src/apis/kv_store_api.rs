@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_store`]
@@ -68,222 +67,96 @@ pub enum GetStoresError {
 
 
 /// Create a new KV store.
-pub async fn create_store(configuration: &mut configuration::Configuration, params: CreateStoreParams) -> Result<crate::models::StoreResponse, Error<CreateStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn create_store(configuration: &configuration::Configuration, params: CreateStoreParams) -> Result<crate::models::StoreResponse, Error<CreateStoreError>> {
+    create_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_store`], but also returns the status and headers of the response.
+pub async fn create_store_with_info(configuration: &configuration::Configuration, params: CreateStoreParams) -> Result<crate::apis::Response<crate::models::StoreResponse>, Error<CreateStoreError>> {
+    crate::apis::execute(configuration, create_store_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_store`] sends.
+pub(crate) fn create_store_request(params: CreateStoreParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let location = params.location;
     let store = params.store;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/kv", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+    let local_var_path = String::from("/resources/stores/kv");
+    let mut local_var_req = crate::apis::Request::new("create_store", reqwest::Method::POST, local_var_path);
 
     if let Some(ref local_var_str) = location {
-        local_var_req_builder = local_var_req_builder.query(&[("location", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("location", local_var_str.to_string());
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&store);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&store)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// A KV store must be empty before it can be deleted.  Deleting a KV store that still contains keys will result in a `409` (Conflict).
-pub async fn delete_store(configuration: &mut configuration::Configuration, params: DeleteStoreParams) -> Result<(), Error<DeleteStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_store(configuration: &configuration::Configuration, params: DeleteStoreParams) -> Result<(), Error<DeleteStoreError>> {
+    delete_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_store`], but also returns the status and headers of the response.
+pub async fn delete_store_with_info(configuration: &configuration::Configuration, params: DeleteStoreParams) -> Result<crate::apis::Response<()>, Error<DeleteStoreError>> {
+    crate::apis::execute(configuration, delete_store_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_store`] sends.
+pub(crate) fn delete_store_request(params: DeleteStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/kv/{store_id}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/kv/{store_id}", store_id=crate::apis::urlencode(store_id));
+    crate::apis::Request::new("delete_store", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a KV store by ID.
-pub async fn get_store(configuration: &mut configuration::Configuration, params: GetStoreParams) -> Result<crate::models::StoreResponse, Error<GetStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn get_store(configuration: &configuration::Configuration, params: GetStoreParams) -> Result<crate::models::StoreResponse, Error<GetStoreError>> {
+    get_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_store`], but also returns the status and headers of the response.
+pub async fn get_store_with_info(configuration: &configuration::Configuration, params: GetStoreParams) -> Result<crate::apis::Response<crate::models::StoreResponse>, Error<GetStoreError>> {
+    crate::apis::execute(configuration, get_store_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_store`] sends.
+pub(crate) fn get_store_request(params: GetStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/kv/{store_id}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/kv/{store_id}", store_id=crate::apis::urlencode(store_id));
+    crate::apis::Request::new("get_store", reqwest::Method::GET, local_var_path)
 }
 
 /// Get all stores for a given customer.
-pub async fn get_stores(configuration: &mut configuration::Configuration, params: GetStoresParams) -> Result<crate::models::InlineResponse2003, Error<GetStoresError>> {
-    let local_var_configuration = configuration;
+pub async fn get_stores(configuration: &configuration::Configuration, params: GetStoresParams) -> Result<crate::models::InlineResponse2003, Error<GetStoresError>> {
+    get_stores_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_stores`], but also returns the status and headers of the response.
+pub async fn get_stores_with_info(configuration: &configuration::Configuration, params: GetStoresParams) -> Result<crate::apis::Response<crate::models::InlineResponse2003>, Error<GetStoresError>> {
+    crate::apis::execute(configuration, get_stores_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_stores`] sends.
+pub(crate) fn get_stores_request(params: GetStoresParams) -> crate::apis::Request {
     // unbox the parameters
     let cursor = params.cursor;
     let limit = params.limit;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/kv", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/resources/stores/kv");
+    let mut local_var_req = crate::apis::Request::new("get_stores", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = cursor {
-        local_var_req_builder = local_var_req_builder.query(&[("cursor", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("cursor", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = limit {
-        local_var_req_builder = local_var_req_builder.query(&[("limit", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("limit", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetStoresError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`delete_key_from_store`]
@@ -80,179 +79,91 @@ pub enum SetValueForKeyError {
 
 
 /// Delete an item from an kv store
-pub async fn delete_key_from_store(configuration: &mut configuration::Configuration, params: DeleteKeyFromStoreParams) -> Result<(), Error<DeleteKeyFromStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_key_from_store(configuration: &configuration::Configuration, params: DeleteKeyFromStoreParams) -> Result<(), Error<DeleteKeyFromStoreError>> {
+    delete_key_from_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_key_from_store`], but also returns the status and headers of the response.
+pub async fn delete_key_from_store_with_info(configuration: &configuration::Configuration, params: DeleteKeyFromStoreParams) -> Result<crate::apis::Response<()>, Error<DeleteKeyFromStoreError>> {
+    crate::apis::execute(configuration, delete_key_from_store_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_key_from_store`] sends.
+pub(crate) fn delete_key_from_store_request(params: DeleteKeyFromStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
     let key_name = params.key_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/kv/{store_id}/keys/{key_name}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id), key_name=crate::apis::urlencode(key_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteKeyFromStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/kv/{store_id}/keys/{key_name}", store_id=crate::apis::urlencode(store_id), key_name=crate::apis::urlencode(key_name));
+    crate::apis::Request::new("delete_key_from_store", reqwest::Method::DELETE, local_var_path)
 }
 
 /// List the keys of all items within an kv store.
-pub async fn get_keys(configuration: &mut configuration::Configuration, params: GetKeysParams) -> Result<crate::models::InlineResponse2004, Error<GetKeysError>> {
-    let local_var_configuration = configuration;
+pub async fn get_keys(configuration: &configuration::Configuration, params: GetKeysParams) -> Result<crate::models::InlineResponse2004, Error<GetKeysError>> {
+    get_keys_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_keys`], but also returns the status and headers of the response.
+pub async fn get_keys_with_info(configuration: &configuration::Configuration, params: GetKeysParams) -> Result<crate::apis::Response<crate::models::InlineResponse2004>, Error<GetKeysError>> {
+    crate::apis::execute(configuration, get_keys_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_keys`] sends.
+pub(crate) fn get_keys_request(params: GetKeysParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
     let cursor = params.cursor;
     let limit = params.limit;
     let prefix = params.prefix;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/kv/{store_id}/keys", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/resources/stores/kv/{store_id}/keys", store_id=crate::apis::urlencode(store_id));
+    let mut local_var_req = crate::apis::Request::new("get_keys", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = cursor {
-        local_var_req_builder = local_var_req_builder.query(&[("cursor", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("cursor", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = limit {
-        local_var_req_builder = local_var_req_builder.query(&[("limit", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("limit", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = prefix {
-        local_var_req_builder = local_var_req_builder.query(&[("prefix", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("prefix", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetKeysError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Get the value associated with a key.
-pub async fn get_value_for_key(configuration: &mut configuration::Configuration, params: GetValueForKeyParams) -> Result<String, Error<GetValueForKeyError>> {
-    let local_var_configuration = configuration;
+pub async fn get_value_for_key(configuration: &configuration::Configuration, params: GetValueForKeyParams) -> Result<String, Error<GetValueForKeyError>> {
+    get_value_for_key_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_value_for_key`], but also returns the status and headers of the response.
+pub async fn get_value_for_key_with_info(configuration: &configuration::Configuration, params: GetValueForKeyParams) -> Result<crate::apis::Response<String>, Error<GetValueForKeyError>> {
+    crate::apis::execute(configuration, get_value_for_key_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_value_for_key`] sends.
+pub(crate) fn get_value_for_key_request(params: GetValueForKeyParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
     let key_name = params.key_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/kv/{store_id}/keys/{key_name}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id), key_name=crate::apis::urlencode(key_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetValueForKeyError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/kv/{store_id}/keys/{key_name}", store_id=crate::apis::urlencode(store_id), key_name=crate::apis::urlencode(key_name));
+    crate::apis::Request::new("get_value_for_key", reqwest::Method::GET, local_var_path)
 }
 
 /// Set a new value for a new or existing key in an kv store.
-pub async fn set_value_for_key(configuration: &mut configuration::Configuration, params: SetValueForKeyParams) -> Result<String, Error<SetValueForKeyError>> {
-    let local_var_configuration = configuration;
+pub async fn set_value_for_key(configuration: &configuration::Configuration, params: SetValueForKeyParams) -> Result<String, Error<SetValueForKeyError>> {
+    set_value_for_key_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`set_value_for_key`], but also returns the status and headers of the response.
+pub async fn set_value_for_key_with_info(configuration: &configuration::Configuration, params: SetValueForKeyParams) -> Result<crate::apis::Response<String>, Error<SetValueForKeyError>> {
+    crate::apis::execute(configuration, set_value_for_key_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`set_value_for_key`] sends.
+pub(crate) fn set_value_for_key_request(params: SetValueForKeyParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let store_id = params.store_id;
     let key_name = params.key_name;
@@ -265,70 +176,32 @@ pub async fn set_value_for_key(configuration: &mut configuration::Configuration,
     let background_fetch = params.background_fetch;
     let body = params.body;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/kv/{store_id}/keys/{key_name}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id), key_name=crate::apis::urlencode(key_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
+    let local_var_path = format!("/resources/stores/kv/{store_id}/keys/{key_name}", store_id=crate::apis::urlencode(store_id), key_name=crate::apis::urlencode(key_name));
+    let mut local_var_req = crate::apis::Request::new("set_value_for_key", reqwest::Method::PUT, local_var_path);
 
     if let Some(ref local_var_str) = add {
-        local_var_req_builder = local_var_req_builder.query(&[("add", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("add", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = append {
-        local_var_req_builder = local_var_req_builder.query(&[("append", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("append", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = prepend {
-        local_var_req_builder = local_var_req_builder.query(&[("prepend", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("prepend", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = background_fetch {
-        local_var_req_builder = local_var_req_builder.query(&[("background_fetch", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("background_fetch", local_var_str.to_string());
     }
     if let Some(local_var_param_value) = if_generation_match {
-        local_var_req_builder = local_var_req_builder.header("if-generation-match", local_var_param_value.to_string());
+        local_var_req = local_var_req.header("if-generation-match", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = time_to_live_sec {
-        local_var_req_builder = local_var_req_builder.header("time_to_live_sec", local_var_param_value.to_string());
+        local_var_req = local_var_req.header("time_to_live_sec", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = metadata {
-        local_var_req_builder = local_var_req_builder.header("metadata", local_var_param_value.to_string());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.header("metadata", local_var_param_value.to_string());
     }
+    local_var_req = local_var_req.json(serde_json::to_value(&body)?);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<SetValueForKeyError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`list_wafs_config_set`]
@@ -50,159 +49,61 @@ pub enum UseWafConfigSetError {
 
 
 /// List all Configuration sets.
-pub async fn list_waf_config_sets(configuration: &mut configuration::Configuration) -> Result<serde_json::Value, Error<ListWafConfigSetsError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
+pub async fn list_waf_config_sets(configuration: &configuration::Configuration) -> Result<serde_json::Value, Error<ListWafConfigSetsError>> {
+    list_waf_config_sets_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_waf_config_sets`], but also returns the status and headers of the response.
+pub async fn list_waf_config_sets_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<serde_json::Value>, Error<ListWafConfigSetsError>> {
+    crate::apis::execute(configuration, list_waf_config_sets_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/configuration_sets", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafConfigSetsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`list_waf_config_sets`] sends.
+pub(crate) fn list_waf_config_sets_request() -> crate::apis::Request {
+    let local_var_path = String::from("/wafs/configuration_sets");
+    crate::apis::Request::new("list_waf_config_sets", reqwest::Method::GET, local_var_path)
 }
 
 /// List the WAF objects currently using the specified configuration set.
-pub async fn list_wafs_config_set(configuration: &mut configuration::Configuration, params: ListWafsConfigSetParams) -> Result<serde_json::Value, Error<ListWafsConfigSetError>> {
-    let local_var_configuration = configuration;
+pub async fn list_wafs_config_set(configuration: &configuration::Configuration, params: ListWafsConfigSetParams) -> Result<serde_json::Value, Error<ListWafsConfigSetError>> {
+    list_wafs_config_set_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_wafs_config_set`], but also returns the status and headers of the response.
+pub async fn list_wafs_config_set_with_info(configuration: &configuration::Configuration, params: ListWafsConfigSetParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListWafsConfigSetError>> {
+    crate::apis::execute(configuration, list_wafs_config_set_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_wafs_config_set`] sends.
+pub(crate) fn list_wafs_config_set_request(params: ListWafsConfigSetParams) -> crate::apis::Request {
     // unbox the parameters
     let configuration_set_id = params.configuration_set_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/configuration_sets/{configuration_set_id}/relationships/wafs", local_var_configuration.base_path, configuration_set_id=crate::apis::urlencode(configuration_set_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafsConfigSetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/wafs/configuration_sets/{configuration_set_id}/relationships/wafs", configuration_set_id=crate::apis::urlencode(configuration_set_id));
+    crate::apis::Request::new("list_wafs_config_set", reqwest::Method::GET, local_var_path)
 }
 
 /// Update one or more WAF objects to use the specified configuration set.
-pub async fn use_waf_config_set(configuration: &mut configuration::Configuration, params: UseWafConfigSetParams) -> Result<serde_json::Value, Error<UseWafConfigSetError>> {
-    let local_var_configuration = configuration;
+pub async fn use_waf_config_set(configuration: &configuration::Configuration, params: UseWafConfigSetParams) -> Result<serde_json::Value, Error<UseWafConfigSetError>> {
+    use_waf_config_set_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`use_waf_config_set`], but also returns the status and headers of the response.
+pub async fn use_waf_config_set_with_info(configuration: &configuration::Configuration, params: UseWafConfigSetParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UseWafConfigSetError>> {
+    crate::apis::execute(configuration, use_waf_config_set_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`use_waf_config_set`] sends.
+pub(crate) fn use_waf_config_set_request(params: UseWafConfigSetParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let configuration_set_id = params.configuration_set_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/wafs/configuration_sets/{configuration_set_id}/relationships/wafs", configuration_set_id=crate::apis::urlencode(configuration_set_id));
+    let mut local_var_req = crate::apis::Request::new("use_waf_config_set", reqwest::Method::PATCH, local_var_path);
+
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/configuration_sets/{configuration_set_id}/relationships/wafs", local_var_configuration.base_path, configuration_set_id=crate::apis::urlencode(configuration_set_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UseWafConfigSetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
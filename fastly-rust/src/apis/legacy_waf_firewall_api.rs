@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_legacy_waf_firewall_service`]
@@ -156,349 +155,174 @@ pub enum UpdateLegacyWafFirewallServiceError {
 
 
 /// Create a firewall object for a particular service and version.
-pub async fn create_legacy_waf_firewall_service(configuration: &mut configuration::Configuration, params: CreateLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<CreateLegacyWafFirewallServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn create_legacy_waf_firewall_service(configuration: &configuration::Configuration, params: CreateLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<CreateLegacyWafFirewallServiceError>> {
+    create_legacy_waf_firewall_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_legacy_waf_firewall_service`], but also returns the status and headers of the response.
+pub async fn create_legacy_waf_firewall_service_with_info(configuration: &configuration::Configuration, params: CreateLegacyWafFirewallServiceParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateLegacyWafFirewallServiceError>> {
+    crate::apis::execute(configuration, create_legacy_waf_firewall_service_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_legacy_waf_firewall_service`] sends.
+pub(crate) fn create_legacy_waf_firewall_service_request(params: CreateLegacyWafFirewallServiceParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/wafs", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_legacy_waf_firewall_service", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/wafs", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLegacyWafFirewallServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Disable a firewall for a particular service and version. This endpoint is intended to be used in an emergency. Disabling a firewall object for a specific service and version replaces your existing WAF ruleset with an empty ruleset. While disabled, your WAF ruleset will not be applied to your origin traffic. This endpoint is only available to users assigned the role of superuser or above. This is an asynchronous action. To check on the completion of this action, use the related link returned in the response to check on the Update Status of the action.
-pub async fn disable_legacy_waf_firewall(configuration: &mut configuration::Configuration, params: DisableLegacyWafFirewallParams) -> Result<serde_json::Value, Error<DisableLegacyWafFirewallError>> {
-    let local_var_configuration = configuration;
+pub async fn disable_legacy_waf_firewall(configuration: &configuration::Configuration, params: DisableLegacyWafFirewallParams) -> Result<serde_json::Value, Error<DisableLegacyWafFirewallError>> {
+    disable_legacy_waf_firewall_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`disable_legacy_waf_firewall`], but also returns the status and headers of the response.
+pub async fn disable_legacy_waf_firewall_with_info(configuration: &configuration::Configuration, params: DisableLegacyWafFirewallParams) -> Result<crate::apis::Response<serde_json::Value>, Error<DisableLegacyWafFirewallError>> {
+    crate::apis::execute(configuration, disable_legacy_waf_firewall_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`disable_legacy_waf_firewall`] sends.
+pub(crate) fn disable_legacy_waf_firewall_request(params: DisableLegacyWafFirewallParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/wafs/{firewall_id}/disable", firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("disable_legacy_waf_firewall", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/{firewall_id}/disable", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DisableLegacyWafFirewallError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Re-enable a firewall object for a particular service and version after it has been disabled. This endpoint is intended to be used in an emergency. When a firewall object is re-enabled, a newly generated WAF ruleset VCL based on the current WAF configuration is used to replace the empty ruleset. This endpoint is only available to users assigned the role of superuser or above. This is an asynchronous action. To check on the completion of this action, use the related link returned in the response to check on the Update Status of the action.
-pub async fn enable_legacy_waf_firewall(configuration: &mut configuration::Configuration, params: EnableLegacyWafFirewallParams) -> Result<serde_json::Value, Error<EnableLegacyWafFirewallError>> {
-    let local_var_configuration = configuration;
+pub async fn enable_legacy_waf_firewall(configuration: &configuration::Configuration, params: EnableLegacyWafFirewallParams) -> Result<serde_json::Value, Error<EnableLegacyWafFirewallError>> {
+    enable_legacy_waf_firewall_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`enable_legacy_waf_firewall`], but also returns the status and headers of the response.
+pub async fn enable_legacy_waf_firewall_with_info(configuration: &configuration::Configuration, params: EnableLegacyWafFirewallParams) -> Result<crate::apis::Response<serde_json::Value>, Error<EnableLegacyWafFirewallError>> {
+    crate::apis::execute(configuration, enable_legacy_waf_firewall_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`enable_legacy_waf_firewall`] sends.
+pub(crate) fn enable_legacy_waf_firewall_request(params: EnableLegacyWafFirewallParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/wafs/{firewall_id}/enable", firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("enable_legacy_waf_firewall", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/{firewall_id}/enable", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<EnableLegacyWafFirewallError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Get a specific firewall object.
-pub async fn get_legacy_waf_firewall(configuration: &mut configuration::Configuration, params: GetLegacyWafFirewallParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallError>> {
-    let local_var_configuration = configuration;
+pub async fn get_legacy_waf_firewall(configuration: &configuration::Configuration, params: GetLegacyWafFirewallParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallError>> {
+    get_legacy_waf_firewall_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_legacy_waf_firewall`], but also returns the status and headers of the response.
+pub async fn get_legacy_waf_firewall_with_info(configuration: &configuration::Configuration, params: GetLegacyWafFirewallParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafFirewallError>> {
+    crate::apis::execute(configuration, get_legacy_waf_firewall_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_legacy_waf_firewall`] sends.
+pub(crate) fn get_legacy_waf_firewall_request(params: GetLegacyWafFirewallParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/{firewall_id}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/wafs/{firewall_id}", firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("get_legacy_waf_firewall", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLegacyWafFirewallError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Get a specific firewall object.
-pub async fn get_legacy_waf_firewall_service(configuration: &mut configuration::Configuration, params: GetLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn get_legacy_waf_firewall_service(configuration: &configuration::Configuration, params: GetLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallServiceError>> {
+    get_legacy_waf_firewall_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_legacy_waf_firewall_service`], but also returns the status and headers of the response.
+pub async fn get_legacy_waf_firewall_service_with_info(configuration: &configuration::Configuration, params: GetLegacyWafFirewallServiceParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafFirewallServiceError>> {
+    crate::apis::execute(configuration, get_legacy_waf_firewall_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_legacy_waf_firewall_service`] sends.
+pub(crate) fn get_legacy_waf_firewall_service_request(params: GetLegacyWafFirewallServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let firewall_id = params.firewall_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/wafs/{firewall_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLegacyWafFirewallServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/wafs/{firewall_id}", service_id=crate::apis::urlencode(service_id), version_id=version_id, firewall_id=crate::apis::urlencode(firewall_id));
+    crate::apis::Request::new("get_legacy_waf_firewall_service", reqwest::Method::GET, local_var_path)
 }
 
 /// List all active firewall objects.
-pub async fn list_legacy_waf_firewalls(configuration: &mut configuration::Configuration, params: ListLegacyWafFirewallsParams) -> Result<serde_json::Value, Error<ListLegacyWafFirewallsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_legacy_waf_firewalls(configuration: &configuration::Configuration, params: ListLegacyWafFirewallsParams) -> Result<serde_json::Value, Error<ListLegacyWafFirewallsError>> {
+    list_legacy_waf_firewalls_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_legacy_waf_firewalls`], but also returns the status and headers of the response.
+pub async fn list_legacy_waf_firewalls_with_info(configuration: &configuration::Configuration, params: ListLegacyWafFirewallsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListLegacyWafFirewallsError>> {
+    crate::apis::execute(configuration, list_legacy_waf_firewalls_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_legacy_waf_firewalls`] sends.
+pub(crate) fn list_legacy_waf_firewalls_request(params: ListLegacyWafFirewallsParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_rules_rule_id = params.filter_rules_rule_id;
     let page_number = params.page_number;
     let page_size = params.page_size;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/wafs");
+    let mut local_var_req = crate::apis::Request::new("list_legacy_waf_firewalls", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_rules_rule_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[rules][rule_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[rules][rule_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLegacyWafFirewallsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all firewall objects for a particular service and version.
-pub async fn list_legacy_waf_firewalls_service(configuration: &mut configuration::Configuration, params: ListLegacyWafFirewallsServiceParams) -> Result<serde_json::Value, Error<ListLegacyWafFirewallsServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn list_legacy_waf_firewalls_service(configuration: &configuration::Configuration, params: ListLegacyWafFirewallsServiceParams) -> Result<serde_json::Value, Error<ListLegacyWafFirewallsServiceError>> {
+    list_legacy_waf_firewalls_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_legacy_waf_firewalls_service`], but also returns the status and headers of the response.
+pub async fn list_legacy_waf_firewalls_service_with_info(configuration: &configuration::Configuration, params: ListLegacyWafFirewallsServiceParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListLegacyWafFirewallsServiceError>> {
+    crate::apis::execute(configuration, list_legacy_waf_firewalls_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_legacy_waf_firewalls_service`] sends.
+pub(crate) fn list_legacy_waf_firewalls_service_request(params: ListLegacyWafFirewallsServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -506,113 +330,45 @@ pub async fn list_legacy_waf_firewalls_service(configuration: &mut configuration
     let page_size = params.page_size;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/wafs", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/wafs", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("list_legacy_waf_firewalls_service", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLegacyWafFirewallsServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a firewall object for a particular service and version. 
-pub async fn update_legacy_waf_firewall_service(configuration: &mut configuration::Configuration, params: UpdateLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<UpdateLegacyWafFirewallServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn update_legacy_waf_firewall_service(configuration: &configuration::Configuration, params: UpdateLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<UpdateLegacyWafFirewallServiceError>> {
+    update_legacy_waf_firewall_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_legacy_waf_firewall_service`], but also returns the status and headers of the response.
+pub async fn update_legacy_waf_firewall_service_with_info(configuration: &configuration::Configuration, params: UpdateLegacyWafFirewallServiceParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateLegacyWafFirewallServiceError>> {
+    crate::apis::execute(configuration, update_legacy_waf_firewall_service_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_legacy_waf_firewall_service`] sends.
+pub(crate) fn update_legacy_waf_firewall_service_request(params: UpdateLegacyWafFirewallServiceParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let firewall_id = params.firewall_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/wafs/{firewall_id}", service_id=crate::apis::urlencode(service_id), version_id=version_id, firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("update_legacy_waf_firewall_service", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/wafs/{firewall_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLegacyWafFirewallServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_owasp_settings`]
@@ -64,165 +63,72 @@ pub enum UpdateOwaspSettingsError {
 
 
 /// Create an OWASP settings object for a particular service and firewall.
-pub async fn create_owasp_settings(configuration: &mut configuration::Configuration, params: CreateOwaspSettingsParams) -> Result<serde_json::Value, Error<CreateOwaspSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn create_owasp_settings(configuration: &configuration::Configuration, params: CreateOwaspSettingsParams) -> Result<serde_json::Value, Error<CreateOwaspSettingsError>> {
+    create_owasp_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_owasp_settings`], but also returns the status and headers of the response.
+pub async fn create_owasp_settings_with_info(configuration: &configuration::Configuration, params: CreateOwaspSettingsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateOwaspSettingsError>> {
+    crate::apis::execute(configuration, create_owasp_settings_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_owasp_settings`] sends.
+pub(crate) fn create_owasp_settings_request(params: CreateOwaspSettingsParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/owasp", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("create_owasp_settings", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/owasp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateOwaspSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
+
+    Ok(local_var_req)
 }
 
 /// Get the OWASP settings object for a particular service and firewall.
-pub async fn get_owasp_settings(configuration: &mut configuration::Configuration, params: GetOwaspSettingsParams) -> Result<serde_json::Value, Error<GetOwaspSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_owasp_settings(configuration: &configuration::Configuration, params: GetOwaspSettingsParams) -> Result<serde_json::Value, Error<GetOwaspSettingsError>> {
+    get_owasp_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_owasp_settings`], but also returns the status and headers of the response.
+pub async fn get_owasp_settings_with_info(configuration: &configuration::Configuration, params: GetOwaspSettingsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetOwaspSettingsError>> {
+    crate::apis::execute(configuration, get_owasp_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_owasp_settings`] sends.
+pub(crate) fn get_owasp_settings_request(params: GetOwaspSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/owasp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetOwaspSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/owasp", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    crate::apis::Request::new("get_owasp_settings", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the OWASP settings object for a particular service and firewall.
-pub async fn update_owasp_settings(configuration: &mut configuration::Configuration, params: UpdateOwaspSettingsParams) -> Result<serde_json::Value, Error<UpdateOwaspSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn update_owasp_settings(configuration: &configuration::Configuration, params: UpdateOwaspSettingsParams) -> Result<serde_json::Value, Error<UpdateOwaspSettingsError>> {
+    update_owasp_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_owasp_settings`], but also returns the status and headers of the response.
+pub async fn update_owasp_settings_with_info(configuration: &configuration::Configuration, params: UpdateOwaspSettingsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateOwaspSettingsError>> {
+    crate::apis::execute(configuration, update_owasp_settings_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_owasp_settings`] sends.
+pub(crate) fn update_owasp_settings_request(params: UpdateOwaspSettingsParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/owasp", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("update_owasp_settings", reqwest::Method::PATCH, local_var_path);
+
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/owasp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateOwaspSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_legacy_waf_firewall_rule_vcl`]
@@ -88,174 +87,86 @@ pub enum ListLegacyWafRulesError {
 
 
 /// Get associated VCL for a specific rule associated with a specific firewall.
-pub async fn get_legacy_waf_firewall_rule_vcl(configuration: &mut configuration::Configuration, params: GetLegacyWafFirewallRuleVclParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallRuleVclError>> {
-    let local_var_configuration = configuration;
+pub async fn get_legacy_waf_firewall_rule_vcl(configuration: &configuration::Configuration, params: GetLegacyWafFirewallRuleVclParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallRuleVclError>> {
+    get_legacy_waf_firewall_rule_vcl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_legacy_waf_firewall_rule_vcl`], but also returns the status and headers of the response.
+pub async fn get_legacy_waf_firewall_rule_vcl_with_info(configuration: &configuration::Configuration, params: GetLegacyWafFirewallRuleVclParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafFirewallRuleVclError>> {
+    crate::apis::execute(configuration, get_legacy_waf_firewall_rule_vcl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_legacy_waf_firewall_rule_vcl`] sends.
+pub(crate) fn get_legacy_waf_firewall_rule_vcl_request(params: GetLegacyWafFirewallRuleVclParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let waf_rule_id = params.waf_rule_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/{firewall_id}/rules/{waf_rule_id}/vcl", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLegacyWafFirewallRuleVclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/wafs/{firewall_id}/rules/{waf_rule_id}/vcl", firewall_id=crate::apis::urlencode(firewall_id), waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    crate::apis::Request::new("get_legacy_waf_firewall_rule_vcl", reqwest::Method::GET, local_var_path)
 }
 
 /// Get a specific rule.
-pub async fn get_legacy_waf_rule(configuration: &mut configuration::Configuration, params: GetLegacyWafRuleParams) -> Result<serde_json::Value, Error<GetLegacyWafRuleError>> {
-    let local_var_configuration = configuration;
+pub async fn get_legacy_waf_rule(configuration: &configuration::Configuration, params: GetLegacyWafRuleParams) -> Result<serde_json::Value, Error<GetLegacyWafRuleError>> {
+    get_legacy_waf_rule_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_legacy_waf_rule`], but also returns the status and headers of the response.
+pub async fn get_legacy_waf_rule_with_info(configuration: &configuration::Configuration, params: GetLegacyWafRuleParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafRuleError>> {
+    crate::apis::execute(configuration, get_legacy_waf_rule_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_legacy_waf_rule`] sends.
+pub(crate) fn get_legacy_waf_rule_request(params: GetLegacyWafRuleParams) -> crate::apis::Request {
     // unbox the parameters
     let waf_rule_id = params.waf_rule_id;
     let filter_configuration_set_id = params.filter_configuration_set_id;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/rules/{waf_rule_id}", local_var_configuration.base_path, waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/wafs/rules/{waf_rule_id}", waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    let mut local_var_req = crate::apis::Request::new("get_legacy_waf_rule", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_configuration_set_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[configuration_set_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[configuration_set_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLegacyWafRuleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Get associated VCL for a specific rule.
-pub async fn get_legacy_waf_rule_vcl(configuration: &mut configuration::Configuration, params: GetLegacyWafRuleVclParams) -> Result<serde_json::Value, Error<GetLegacyWafRuleVclError>> {
-    let local_var_configuration = configuration;
+pub async fn get_legacy_waf_rule_vcl(configuration: &configuration::Configuration, params: GetLegacyWafRuleVclParams) -> Result<serde_json::Value, Error<GetLegacyWafRuleVclError>> {
+    get_legacy_waf_rule_vcl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_legacy_waf_rule_vcl`], but also returns the status and headers of the response.
+pub async fn get_legacy_waf_rule_vcl_with_info(configuration: &configuration::Configuration, params: GetLegacyWafRuleVclParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafRuleVclError>> {
+    crate::apis::execute(configuration, get_legacy_waf_rule_vcl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_legacy_waf_rule_vcl`] sends.
+pub(crate) fn get_legacy_waf_rule_vcl_request(params: GetLegacyWafRuleVclParams) -> crate::apis::Request {
     // unbox the parameters
     let waf_rule_id = params.waf_rule_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/rules/{waf_rule_id}/vcl", local_var_configuration.base_path, waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLegacyWafRuleVclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/wafs/rules/{waf_rule_id}/vcl", waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    crate::apis::Request::new("get_legacy_waf_rule_vcl", reqwest::Method::GET, local_var_path)
 }
 
 /// List all rules in the latest configuration set.
-pub async fn list_legacy_waf_rules(configuration: &mut configuration::Configuration, params: ListLegacyWafRulesParams) -> Result<Vec<serde_json::Value>, Error<ListLegacyWafRulesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_legacy_waf_rules(configuration: &configuration::Configuration, params: ListLegacyWafRulesParams) -> Result<Vec<serde_json::Value>, Error<ListLegacyWafRulesError>> {
+    list_legacy_waf_rules_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_legacy_waf_rules`], but also returns the status and headers of the response.
+pub async fn list_legacy_waf_rules_with_info(configuration: &configuration::Configuration, params: ListLegacyWafRulesParams) -> Result<crate::apis::Response<Vec<serde_json::Value>>, Error<ListLegacyWafRulesError>> {
+    crate::apis::execute(configuration, list_legacy_waf_rules_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_legacy_waf_rules`] sends.
+pub(crate) fn list_legacy_waf_rules_request(params: ListLegacyWafRulesParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_rule_id = params.filter_rule_id;
     let filter_severity = params.filter_severity;
@@ -265,69 +176,31 @@ pub async fn list_legacy_waf_rules(configuration: &mut configuration::Configurat
     let page_size = params.page_size;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/rules", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/wafs/rules");
+    let mut local_var_req = crate::apis::Request::new("list_legacy_waf_rules", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_rule_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[rule_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[rule_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_severity {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[severity]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[severity]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_tags_name {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[tags][name]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[tags][name]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_configuration_set_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[configuration_set_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[configuration_set_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLegacyWafRulesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
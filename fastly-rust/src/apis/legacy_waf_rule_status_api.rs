@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_waf_firewall_rule_status`]
@@ -104,63 +103,38 @@ pub enum UpdateWafFirewallRuleStatusesTagError {
 
 
 /// Get a specific rule status object for a particular service, firewall, and rule.
-pub async fn get_waf_firewall_rule_status(configuration: &mut configuration::Configuration, params: GetWafFirewallRuleStatusParams) -> Result<serde_json::Value, Error<GetWafFirewallRuleStatusError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_firewall_rule_status(configuration: &configuration::Configuration, params: GetWafFirewallRuleStatusParams) -> Result<serde_json::Value, Error<GetWafFirewallRuleStatusError>> {
+    get_waf_firewall_rule_status_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_firewall_rule_status`], but also returns the status and headers of the response.
+pub async fn get_waf_firewall_rule_status_with_info(configuration: &configuration::Configuration, params: GetWafFirewallRuleStatusParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetWafFirewallRuleStatusError>> {
+    crate::apis::execute(configuration, get_waf_firewall_rule_status_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_firewall_rule_status`] sends.
+pub(crate) fn get_waf_firewall_rule_status_request(params: GetWafFirewallRuleStatusParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
     let waf_rule_id = params.waf_rule_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/rules/{waf_rule_id}/rule_status", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id), waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafFirewallRuleStatusError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/rules/{waf_rule_id}/rule_status", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id), waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    crate::apis::Request::new("get_waf_firewall_rule_status", reqwest::Method::GET, local_var_path)
 }
 
 /// List all rule statuses for a particular service and firewall.
-pub async fn list_waf_firewall_rule_statuses(configuration: &mut configuration::Configuration, params: ListWafFirewallRuleStatusesParams) -> Result<serde_json::Value, Error<ListWafFirewallRuleStatusesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_waf_firewall_rule_statuses(configuration: &configuration::Configuration, params: ListWafFirewallRuleStatusesParams) -> Result<serde_json::Value, Error<ListWafFirewallRuleStatusesError>> {
+    list_waf_firewall_rule_statuses_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_waf_firewall_rule_statuses`], but also returns the status and headers of the response.
+pub async fn list_waf_firewall_rule_statuses_with_info(configuration: &configuration::Configuration, params: ListWafFirewallRuleStatusesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListWafFirewallRuleStatusesError>> {
+    crate::apis::execute(configuration, list_waf_firewall_rule_statuses_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_waf_firewall_rule_statuses`] sends.
+pub(crate) fn list_waf_firewall_rule_statuses_request(params: ListWafFirewallRuleStatusesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
@@ -173,135 +147,75 @@ pub async fn list_waf_firewall_rule_statuses(configuration: &mut configuration::
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/rule_statuses", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/rule_statuses", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("list_waf_firewall_rule_statuses", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_status {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[status]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[status]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_rule_message {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[rule][message]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[rule][message]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_rule_rule_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[rule][rule_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[rule][rule_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_rule_tags {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[rule][tags]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[rule][tags]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_rule_tags_name {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[rule][tags][name]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[rule][tags][name]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafFirewallRuleStatusesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a rule status for a particular service, firewall, and rule.
-pub async fn update_waf_firewall_rule_status(configuration: &mut configuration::Configuration, params: UpdateWafFirewallRuleStatusParams) -> Result<serde_json::Value, Error<UpdateWafFirewallRuleStatusError>> {
-    let local_var_configuration = configuration;
+pub async fn update_waf_firewall_rule_status(configuration: &configuration::Configuration, params: UpdateWafFirewallRuleStatusParams) -> Result<serde_json::Value, Error<UpdateWafFirewallRuleStatusError>> {
+    update_waf_firewall_rule_status_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_waf_firewall_rule_status`], but also returns the status and headers of the response.
+pub async fn update_waf_firewall_rule_status_with_info(configuration: &configuration::Configuration, params: UpdateWafFirewallRuleStatusParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateWafFirewallRuleStatusError>> {
+    crate::apis::execute(configuration, update_waf_firewall_rule_status_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_waf_firewall_rule_status`] sends.
+pub(crate) fn update_waf_firewall_rule_status_request(params: UpdateWafFirewallRuleStatusParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
     let waf_rule_id = params.waf_rule_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/rules/{waf_rule_id}/rule_status", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id), waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    let mut local_var_req = crate::apis::Request::new("update_waf_firewall_rule_status", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/rules/{waf_rule_id}/rule_status", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id), waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateWafFirewallRuleStatusError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create or update all rule statuses for a particular service and firewall, based on tag name. By default, only rule status for enabled rules (with status log or block) will be updated. To update rule statuses for disabled rules under the specified tag, use the force attribute.
-pub async fn update_waf_firewall_rule_statuses_tag(configuration: &mut configuration::Configuration, params: UpdateWafFirewallRuleStatusesTagParams) -> Result<serde_json::Value, Error<UpdateWafFirewallRuleStatusesTagError>> {
-    let local_var_configuration = configuration;
+pub async fn update_waf_firewall_rule_statuses_tag(configuration: &configuration::Configuration, params: UpdateWafFirewallRuleStatusesTagParams) -> Result<serde_json::Value, Error<UpdateWafFirewallRuleStatusesTagError>> {
+    update_waf_firewall_rule_statuses_tag_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_waf_firewall_rule_statuses_tag`], but also returns the status and headers of the response.
+pub async fn update_waf_firewall_rule_statuses_tag_with_info(configuration: &configuration::Configuration, params: UpdateWafFirewallRuleStatusesTagParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateWafFirewallRuleStatusesTagError>> {
+    crate::apis::execute(configuration, update_waf_firewall_rule_statuses_tag_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_waf_firewall_rule_statuses_tag`] sends.
+pub(crate) fn update_waf_firewall_rule_statuses_tag_request(params: UpdateWafFirewallRuleStatusesTagParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
@@ -309,55 +223,17 @@ pub async fn update_waf_firewall_rule_statuses_tag(configuration: &mut configura
     let force = params.force;
     let request_body = params.request_body;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/rule_statuses", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/rule_statuses", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("update_waf_firewall_rule_statuses_tag", reqwest::Method::POST, local_var_path);
 
     if let Some(ref local_var_str) = name {
-        local_var_req_builder = local_var_req_builder.query(&[("name", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("name", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = force {
-        local_var_req_builder = local_var_req_builder.query(&[("force", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("force", local_var_str.to_string());
     }
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateWafFirewallRuleStatusesTagError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
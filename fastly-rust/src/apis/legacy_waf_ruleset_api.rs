@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_waf_ruleset`]
@@ -63,163 +62,67 @@ pub enum UpdateWafRulesetError {
 
 
 /// Get a WAF ruleset for a particular service and firewall object.
-pub async fn get_waf_ruleset(configuration: &mut configuration::Configuration, params: GetWafRulesetParams) -> Result<serde_json::Value, Error<GetWafRulesetError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_ruleset(configuration: &configuration::Configuration, params: GetWafRulesetParams) -> Result<serde_json::Value, Error<GetWafRulesetError>> {
+    get_waf_ruleset_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_ruleset`], but also returns the status and headers of the response.
+pub async fn get_waf_ruleset_with_info(configuration: &configuration::Configuration, params: GetWafRulesetParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetWafRulesetError>> {
+    crate::apis::execute(configuration, get_waf_ruleset_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_ruleset`] sends.
+pub(crate) fn get_waf_ruleset_request(params: GetWafRulesetParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/ruleset", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafRulesetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/ruleset", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    crate::apis::Request::new("get_waf_ruleset", reqwest::Method::GET, local_var_path)
 }
 
 /// Get a preview of the WAF ruleset VCL for a particular service and firewall object based on changes to WAF configuration before deploying the ruleset. The response will include a link to status of the background VCL generation job. Once the background job is completed, the preview WAF ruleset VCL can be retrieved from the status response.
-pub async fn get_waf_ruleset_vcl(configuration: &mut configuration::Configuration, params: GetWafRulesetVclParams) -> Result<serde_json::Value, Error<GetWafRulesetVclError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_ruleset_vcl(configuration: &configuration::Configuration, params: GetWafRulesetVclParams) -> Result<serde_json::Value, Error<GetWafRulesetVclError>> {
+    get_waf_ruleset_vcl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_ruleset_vcl`], but also returns the status and headers of the response.
+pub async fn get_waf_ruleset_vcl_with_info(configuration: &configuration::Configuration, params: GetWafRulesetVclParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetWafRulesetVclError>> {
+    crate::apis::execute(configuration, get_waf_ruleset_vcl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_ruleset_vcl`] sends.
+pub(crate) fn get_waf_ruleset_vcl_request(params: GetWafRulesetVclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/ruleset/preview", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafRulesetVclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/ruleset/preview", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    crate::apis::Request::new("get_waf_ruleset_vcl", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the WAF ruleset for a particular service and firewall object. Use the URL in the response to view the WAF ruleset deploy status.
-pub async fn update_waf_ruleset(configuration: &mut configuration::Configuration, params: UpdateWafRulesetParams) -> Result<serde_json::Value, Error<UpdateWafRulesetError>> {
-    let local_var_configuration = configuration;
+pub async fn update_waf_ruleset(configuration: &configuration::Configuration, params: UpdateWafRulesetParams) -> Result<serde_json::Value, Error<UpdateWafRulesetError>> {
+    update_waf_ruleset_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_waf_ruleset`], but also returns the status and headers of the response.
+pub async fn update_waf_ruleset_with_info(configuration: &configuration::Configuration, params: UpdateWafRulesetParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateWafRulesetError>> {
+    crate::apis::execute(configuration, update_waf_ruleset_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_waf_ruleset`] sends.
+pub(crate) fn update_waf_ruleset_request(params: UpdateWafRulesetParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/ruleset", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("update_waf_ruleset", reqwest::Method::PATCH, local_var_path);
+
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/ruleset", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateWafRulesetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
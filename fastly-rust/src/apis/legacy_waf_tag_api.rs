@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`list_legacy_waf_tags`]
@@ -34,69 +33,39 @@ pub enum ListLegacyWafTagsError {
 
 
 /// List all tags.
-pub async fn list_legacy_waf_tags(configuration: &mut configuration::Configuration, params: ListLegacyWafTagsParams) -> Result<serde_json::Value, Error<ListLegacyWafTagsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_legacy_waf_tags(configuration: &configuration::Configuration, params: ListLegacyWafTagsParams) -> Result<serde_json::Value, Error<ListLegacyWafTagsError>> {
+    list_legacy_waf_tags_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_legacy_waf_tags`], but also returns the status and headers of the response.
+pub async fn list_legacy_waf_tags_with_info(configuration: &configuration::Configuration, params: ListLegacyWafTagsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListLegacyWafTagsError>> {
+    crate::apis::execute(configuration, list_legacy_waf_tags_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_legacy_waf_tags`] sends.
+pub(crate) fn list_legacy_waf_tags_request(params: ListLegacyWafTagsParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_name = params.filter_name;
     let page_number = params.page_number;
     let page_size = params.page_size;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/wafs/tags", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/wafs/tags");
+    let mut local_var_req = crate::apis::Request::new("list_legacy_waf_tags", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_name {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[name]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[name]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLegacyWafTagsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_waf_update_status`]
@@ -54,63 +53,38 @@ pub enum ListWafUpdateStatusesError {
 
 
 /// Get a specific update status object for a particular service and firewall object.
-pub async fn get_waf_update_status(configuration: &mut configuration::Configuration, params: GetWafUpdateStatusParams) -> Result<serde_json::Value, Error<GetWafUpdateStatusError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_update_status(configuration: &configuration::Configuration, params: GetWafUpdateStatusParams) -> Result<serde_json::Value, Error<GetWafUpdateStatusError>> {
+    get_waf_update_status_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_update_status`], but also returns the status and headers of the response.
+pub async fn get_waf_update_status_with_info(configuration: &configuration::Configuration, params: GetWafUpdateStatusParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetWafUpdateStatusError>> {
+    crate::apis::execute(configuration, get_waf_update_status_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_update_status`] sends.
+pub(crate) fn get_waf_update_status_request(params: GetWafUpdateStatusParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
     let update_status_id = params.update_status_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/update_statuses/{update_status_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id), update_status_id=crate::apis::urlencode(update_status_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafUpdateStatusError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/update_statuses/{update_status_id}", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id), update_status_id=crate::apis::urlencode(update_status_id));
+    crate::apis::Request::new("get_waf_update_status", reqwest::Method::GET, local_var_path)
 }
 
 /// List all update statuses for a particular service and firewall object.
-pub async fn list_waf_update_statuses(configuration: &mut configuration::Configuration, params: ListWafUpdateStatusesParams) -> Result<serde_json::Value, Error<ListWafUpdateStatusesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_waf_update_statuses(configuration: &configuration::Configuration, params: ListWafUpdateStatusesParams) -> Result<serde_json::Value, Error<ListWafUpdateStatusesError>> {
+    list_waf_update_statuses_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_waf_update_statuses`], but also returns the status and headers of the response.
+pub async fn list_waf_update_statuses_with_info(configuration: &configuration::Configuration, params: ListWafUpdateStatusesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListWafUpdateStatusesError>> {
+    crate::apis::execute(configuration, list_waf_update_statuses_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_waf_update_statuses`] sends.
+pub(crate) fn list_waf_update_statuses_request(params: ListWafUpdateStatusesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let firewall_id = params.firewall_id;
@@ -118,57 +92,19 @@ pub async fn list_waf_update_statuses(configuration: &mut configuration::Configu
     let page_size = params.page_size;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/wafs/{firewall_id}/update_statuses", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/wafs/{firewall_id}/update_statuses", service_id=crate::apis::urlencode(service_id), firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("list_waf_update_statuses", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafUpdateStatusesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_azure`]
@@ -45,7 +44,7 @@ pub struct CreateLogAzureParams {
     /// The name of the Azure Blob Storage container in which to store logs. Required.
     pub container: Option<String>,
     /// The Azure shared access signature providing write access to the blob service objects. Be sure to update your token before it expires or the logging functionality will not work. Required.
-    pub sas_token: Option<String>,
+    pub sas_token: Option<crate::apis::redact::Secret>,
     /// A PGP public key that Fastly will use to encrypt your log files before writing them to disk.
     pub public_key: Option<String>,
     /// The maximum number of bytes for each uploaded file. A value of 0 can be used to indicate there is no limit on the size of uploaded files, otherwise the minimum value is 1048576 bytes (1 MiB.)
@@ -119,7 +118,7 @@ pub struct UpdateLogAzureParams {
     /// The name of the Azure Blob Storage container in which to store logs. Required.
     pub container: Option<String>,
     /// The Azure shared access signature providing write access to the blob service objects. Be sure to update your token before it expires or the logging functionality will not work. Required.
-    pub sas_token: Option<String>,
+    pub sas_token: Option<crate::apis::redact::Secret>,
     /// A PGP public key that Fastly will use to encrypt your log files before writing them to disk.
     pub public_key: Option<String>,
     /// The maximum number of bytes for each uploaded file. A value of 0 can be used to indicate there is no limit on the size of uploaded files, otherwise the minimum value is 1048576 bytes (1 MiB.)
@@ -164,9 +163,17 @@ pub enum UpdateLogAzureError {
 
 
 /// Create an Azure Blob Storage logging endpoint for a particular service and version.
-pub async fn create_log_azure(configuration: &mut configuration::Configuration, params: CreateLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<CreateLogAzureError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_azure(configuration: &configuration::Configuration, params: CreateLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<CreateLogAzureError>> {
+    create_log_azure_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_azure`], but also returns the status and headers of the response.
+pub async fn create_log_azure_with_info(configuration: &configuration::Configuration, params: CreateLogAzureParams) -> Result<crate::apis::Response<crate::models::LoggingAzureblobResponse>, Error<CreateLogAzureError>> {
+    crate::apis::execute(configuration, create_log_azure_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_azure`] sends.
+pub(crate) fn create_log_azure_request(params: CreateLogAzureParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -187,24 +194,10 @@ pub async fn create_log_azure(configuration: &mut configuration::Configuration,
     let public_key = params.public_key;
     let file_max_bytes = params.file_max_bytes;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/azureblob", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_azure", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/azureblob", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -245,7 +238,7 @@ pub async fn create_log_azure(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("container", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = sas_token {
-        local_var_form_params.insert("sas_token", local_var_param_value.to_string());
+        local_var_form_params.insert("sas_token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = public_key {
         local_var_form_params.insert("public_key", local_var_param_value.to_string());
@@ -253,200 +246,85 @@ pub async fn create_log_azure(configuration: &mut configuration::Configuration,
     if let Some(local_var_param_value) = file_max_bytes {
         local_var_form_params.insert("file_max_bytes", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogAzureError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the Azure Blob Storage logging endpoint for a particular service and version.
-pub async fn delete_log_azure(configuration: &mut configuration::Configuration, params: DeleteLogAzureParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogAzureError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_azure(configuration: &configuration::Configuration, params: DeleteLogAzureParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogAzureError>> {
+    delete_log_azure_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_azure`], but also returns the status and headers of the response.
+pub async fn delete_log_azure_with_info(configuration: &configuration::Configuration, params: DeleteLogAzureParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogAzureError>> {
+    crate::apis::execute(configuration, delete_log_azure_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_azure`] sends.
+pub(crate) fn delete_log_azure_request(params: DeleteLogAzureParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_azureblob_name = params.logging_azureblob_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/azureblob/{logging_azureblob_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_azureblob_name=crate::apis::urlencode(logging_azureblob_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogAzureError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/azureblob/{logging_azureblob_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_azureblob_name=crate::apis::urlencode(logging_azureblob_name));
+    crate::apis::Request::new("delete_log_azure", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the Azure Blob Storage logging endpoint for a particular service and version.
-pub async fn get_log_azure(configuration: &mut configuration::Configuration, params: GetLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<GetLogAzureError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_azure(configuration: &configuration::Configuration, params: GetLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<GetLogAzureError>> {
+    get_log_azure_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_azure`], but also returns the status and headers of the response.
+pub async fn get_log_azure_with_info(configuration: &configuration::Configuration, params: GetLogAzureParams) -> Result<crate::apis::Response<crate::models::LoggingAzureblobResponse>, Error<GetLogAzureError>> {
+    crate::apis::execute(configuration, get_log_azure_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_azure`] sends.
+pub(crate) fn get_log_azure_request(params: GetLogAzureParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_azureblob_name = params.logging_azureblob_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/azureblob/{logging_azureblob_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_azureblob_name=crate::apis::urlencode(logging_azureblob_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogAzureError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/azureblob/{logging_azureblob_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_azureblob_name=crate::apis::urlencode(logging_azureblob_name));
+    crate::apis::Request::new("get_log_azure", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Azure Blob Storage logging endpoints for a particular service and version.
-pub async fn list_log_azure(configuration: &mut configuration::Configuration, params: ListLogAzureParams) -> Result<Vec<crate::models::LoggingAzureblobResponse>, Error<ListLogAzureError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_azure(configuration: &configuration::Configuration, params: ListLogAzureParams) -> Result<Vec<crate::models::LoggingAzureblobResponse>, Error<ListLogAzureError>> {
+    list_log_azure_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_azure`], but also returns the status and headers of the response.
+pub async fn list_log_azure_with_info(configuration: &configuration::Configuration, params: ListLogAzureParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingAzureblobResponse>>, Error<ListLogAzureError>> {
+    crate::apis::execute(configuration, list_log_azure_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_azure`] sends.
+pub(crate) fn list_log_azure_request(params: ListLogAzureParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/azureblob", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogAzureError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/azureblob", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_azure", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the Azure Blob Storage logging endpoint for a particular service and version.
-pub async fn update_log_azure(configuration: &mut configuration::Configuration, params: UpdateLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<UpdateLogAzureError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_azure(configuration: &configuration::Configuration, params: UpdateLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<UpdateLogAzureError>> {
+    update_log_azure_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_azure`], but also returns the status and headers of the response.
+pub async fn update_log_azure_with_info(configuration: &configuration::Configuration, params: UpdateLogAzureParams) -> Result<crate::apis::Response<crate::models::LoggingAzureblobResponse>, Error<UpdateLogAzureError>> {
+    crate::apis::execute(configuration, update_log_azure_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_azure`] sends.
+pub(crate) fn update_log_azure_request(params: UpdateLogAzureParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -468,24 +346,10 @@ pub async fn update_log_azure(configuration: &mut configuration::Configuration,
     let public_key = params.public_key;
     let file_max_bytes = params.file_max_bytes;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/azureblob/{logging_azureblob_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_azureblob_name=crate::apis::urlencode(logging_azureblob_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_azure", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/azureblob/{logging_azureblob_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_azureblob_name=crate::apis::urlencode(logging_azureblob_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -526,7 +390,7 @@ pub async fn update_log_azure(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("container", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = sas_token {
-        local_var_form_params.insert("sas_token", local_var_param_value.to_string());
+        local_var_form_params.insert("sas_token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = public_key {
         local_var_form_params.insert("public_key", local_var_param_value.to_string());
@@ -534,32 +398,8 @@ pub async fn update_log_azure(configuration: &mut configuration::Configuration,
     if let Some(local_var_param_value) = file_max_bytes {
         local_var_form_params.insert("file_max_bytes", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogAzureError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
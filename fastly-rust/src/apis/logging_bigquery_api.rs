@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_bigquery`]
@@ -31,7 +30,7 @@ pub struct CreateLogBigqueryParams {
     /// Your Google Cloud Platform service account email address. The `client_email` field in your service account authentication JSON. Not required if `account_name` is specified.
     pub user: Option<String>,
     /// Your Google Cloud Platform account secret key. The `private_key` field in your service account authentication JSON. Not required if `account_name` is specified.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The name of the Google Cloud Platform service account associated with the target log collection service. Not required if `user` and `secret_key` are provided.
     pub account_name: Option<String>,
     /// Your BigQuery dataset.
@@ -97,7 +96,7 @@ pub struct UpdateLogBigqueryParams {
     /// Your Google Cloud Platform service account email address. The `client_email` field in your service account authentication JSON. Not required if `account_name` is specified.
     pub user: Option<String>,
     /// Your Google Cloud Platform account secret key. The `private_key` field in your service account authentication JSON. Not required if `account_name` is specified.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The name of the Google Cloud Platform service account associated with the target log collection service. Not required if `user` and `secret_key` are provided.
     pub account_name: Option<String>,
     /// Your BigQuery dataset.
@@ -148,9 +147,17 @@ pub enum UpdateLogBigqueryError {
 
 
 /// Create a BigQuery logging object for a particular service and version.
-pub async fn create_log_bigquery(configuration: &mut configuration::Configuration, params: CreateLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<CreateLogBigqueryError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_bigquery(configuration: &configuration::Configuration, params: CreateLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<CreateLogBigqueryError>> {
+    create_log_bigquery_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_bigquery`], but also returns the status and headers of the response.
+pub async fn create_log_bigquery_with_info(configuration: &configuration::Configuration, params: CreateLogBigqueryParams) -> Result<crate::apis::Response<crate::models::LoggingBigqueryResponse>, Error<CreateLogBigqueryError>> {
+    crate::apis::execute(configuration, create_log_bigquery_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_bigquery`] sends.
+pub(crate) fn create_log_bigquery_request(params: CreateLogBigqueryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -167,24 +174,10 @@ pub async fn create_log_bigquery(configuration: &mut configuration::Configuratio
     let template_suffix = params.template_suffix;
     let project_id = params.project_id;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/bigquery", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_bigquery", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/bigquery", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -204,7 +197,7 @@ pub async fn create_log_bigquery(configuration: &mut configuration::Configuratio
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = account_name {
         local_var_form_params.insert("account_name", local_var_param_value.to_string());
@@ -221,200 +214,85 @@ pub async fn create_log_bigquery(configuration: &mut configuration::Configuratio
     if let Some(local_var_param_value) = project_id {
         local_var_form_params.insert("project_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogBigqueryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a BigQuery logging object for a particular service and version.
-pub async fn delete_log_bigquery(configuration: &mut configuration::Configuration, params: DeleteLogBigqueryParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogBigqueryError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_bigquery(configuration: &configuration::Configuration, params: DeleteLogBigqueryParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogBigqueryError>> {
+    delete_log_bigquery_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_bigquery`], but also returns the status and headers of the response.
+pub async fn delete_log_bigquery_with_info(configuration: &configuration::Configuration, params: DeleteLogBigqueryParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogBigqueryError>> {
+    crate::apis::execute(configuration, delete_log_bigquery_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_bigquery`] sends.
+pub(crate) fn delete_log_bigquery_request(params: DeleteLogBigqueryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_bigquery_name = params.logging_bigquery_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/bigquery/{logging_bigquery_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_bigquery_name=crate::apis::urlencode(logging_bigquery_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogBigqueryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/bigquery/{logging_bigquery_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_bigquery_name=crate::apis::urlencode(logging_bigquery_name));
+    crate::apis::Request::new("delete_log_bigquery", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the details for a BigQuery logging object for a particular service and version.
-pub async fn get_log_bigquery(configuration: &mut configuration::Configuration, params: GetLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<GetLogBigqueryError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_bigquery(configuration: &configuration::Configuration, params: GetLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<GetLogBigqueryError>> {
+    get_log_bigquery_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_bigquery`], but also returns the status and headers of the response.
+pub async fn get_log_bigquery_with_info(configuration: &configuration::Configuration, params: GetLogBigqueryParams) -> Result<crate::apis::Response<crate::models::LoggingBigqueryResponse>, Error<GetLogBigqueryError>> {
+    crate::apis::execute(configuration, get_log_bigquery_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_bigquery`] sends.
+pub(crate) fn get_log_bigquery_request(params: GetLogBigqueryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_bigquery_name = params.logging_bigquery_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/bigquery/{logging_bigquery_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_bigquery_name=crate::apis::urlencode(logging_bigquery_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogBigqueryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/bigquery/{logging_bigquery_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_bigquery_name=crate::apis::urlencode(logging_bigquery_name));
+    crate::apis::Request::new("get_log_bigquery", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the BigQuery logging objects for a particular service and version.
-pub async fn list_log_bigquery(configuration: &mut configuration::Configuration, params: ListLogBigqueryParams) -> Result<Vec<crate::models::LoggingBigqueryResponse>, Error<ListLogBigqueryError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_bigquery(configuration: &configuration::Configuration, params: ListLogBigqueryParams) -> Result<Vec<crate::models::LoggingBigqueryResponse>, Error<ListLogBigqueryError>> {
+    list_log_bigquery_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_bigquery`], but also returns the status and headers of the response.
+pub async fn list_log_bigquery_with_info(configuration: &configuration::Configuration, params: ListLogBigqueryParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingBigqueryResponse>>, Error<ListLogBigqueryError>> {
+    crate::apis::execute(configuration, list_log_bigquery_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_bigquery`] sends.
+pub(crate) fn list_log_bigquery_request(params: ListLogBigqueryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/bigquery", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogBigqueryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/bigquery", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_bigquery", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a BigQuery logging object for a particular service and version.
-pub async fn update_log_bigquery(configuration: &mut configuration::Configuration, params: UpdateLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<UpdateLogBigqueryError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_bigquery(configuration: &configuration::Configuration, params: UpdateLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<UpdateLogBigqueryError>> {
+    update_log_bigquery_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_bigquery`], but also returns the status and headers of the response.
+pub async fn update_log_bigquery_with_info(configuration: &configuration::Configuration, params: UpdateLogBigqueryParams) -> Result<crate::apis::Response<crate::models::LoggingBigqueryResponse>, Error<UpdateLogBigqueryError>> {
+    crate::apis::execute(configuration, update_log_bigquery_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_bigquery`] sends.
+pub(crate) fn update_log_bigquery_request(params: UpdateLogBigqueryParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -432,24 +310,10 @@ pub async fn update_log_bigquery(configuration: &mut configuration::Configuratio
     let template_suffix = params.template_suffix;
     let project_id = params.project_id;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/bigquery/{logging_bigquery_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_bigquery_name=crate::apis::urlencode(logging_bigquery_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_bigquery", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/bigquery/{logging_bigquery_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_bigquery_name=crate::apis::urlencode(logging_bigquery_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -469,7 +333,7 @@ pub async fn update_log_bigquery(configuration: &mut configuration::Configuratio
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = account_name {
         local_var_form_params.insert("account_name", local_var_param_value.to_string());
@@ -486,32 +350,8 @@ pub async fn update_log_bigquery(configuration: &mut configuration::Configuratio
     if let Some(local_var_param_value) = project_id {
         local_var_form_params.insert("project_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogBigqueryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_datadog`]
@@ -31,7 +30,7 @@ pub struct CreateLogDatadogParams {
     /// The region that log data will be sent to.
     pub region: Option<String>,
     /// The API key from your Datadog account. Required.
-    pub token: Option<String>
+    pub token: Option<crate::apis::redact::Secret>
 }
 
 /// struct for passing parameters to the method [`delete_log_datadog`]
@@ -87,7 +86,7 @@ pub struct UpdateLogDatadogParams {
     /// The region that log data will be sent to.
     pub region: Option<String>,
     /// The API key from your Datadog account. Required.
-    pub token: Option<String>
+    pub token: Option<crate::apis::redact::Secret>
 }
 
 
@@ -128,9 +127,17 @@ pub enum UpdateLogDatadogError {
 
 
 /// Create a Datadog logging object for a particular service and version.
-pub async fn create_log_datadog(configuration: &mut configuration::Configuration, params: CreateLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<CreateLogDatadogError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_datadog(configuration: &configuration::Configuration, params: CreateLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<CreateLogDatadogError>> {
+    create_log_datadog_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_datadog`], but also returns the status and headers of the response.
+pub async fn create_log_datadog_with_info(configuration: &configuration::Configuration, params: CreateLogDatadogParams) -> Result<crate::apis::Response<crate::models::LoggingDatadogResponse>, Error<CreateLogDatadogError>> {
+    crate::apis::execute(configuration, create_log_datadog_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_datadog`] sends.
+pub(crate) fn create_log_datadog_request(params: CreateLogDatadogParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -142,24 +149,10 @@ pub async fn create_log_datadog(configuration: &mut configuration::Configuration
     let region = params.region;
     let token = params.token;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/datadog", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_datadog", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/datadog", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -179,202 +172,87 @@ pub async fn create_log_datadog(configuration: &mut configuration::Configuration
         local_var_form_params.insert("region", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
-    }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogDatadogError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the Datadog logging object for a particular service and version.
-pub async fn delete_log_datadog(configuration: &mut configuration::Configuration, params: DeleteLogDatadogParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogDatadogError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_datadog(configuration: &configuration::Configuration, params: DeleteLogDatadogParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogDatadogError>> {
+    delete_log_datadog_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_datadog`], but also returns the status and headers of the response.
+pub async fn delete_log_datadog_with_info(configuration: &configuration::Configuration, params: DeleteLogDatadogParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogDatadogError>> {
+    crate::apis::execute(configuration, delete_log_datadog_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_datadog`] sends.
+pub(crate) fn delete_log_datadog_request(params: DeleteLogDatadogParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_datadog_name = params.logging_datadog_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/datadog/{logging_datadog_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_datadog_name=crate::apis::urlencode(logging_datadog_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogDatadogError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/datadog/{logging_datadog_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_datadog_name=crate::apis::urlencode(logging_datadog_name));
+    crate::apis::Request::new("delete_log_datadog", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the details for a Datadog logging object for a particular service and version.
-pub async fn get_log_datadog(configuration: &mut configuration::Configuration, params: GetLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<GetLogDatadogError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_datadog(configuration: &configuration::Configuration, params: GetLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<GetLogDatadogError>> {
+    get_log_datadog_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_datadog`], but also returns the status and headers of the response.
+pub async fn get_log_datadog_with_info(configuration: &configuration::Configuration, params: GetLogDatadogParams) -> Result<crate::apis::Response<crate::models::LoggingDatadogResponse>, Error<GetLogDatadogError>> {
+    crate::apis::execute(configuration, get_log_datadog_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_datadog`] sends.
+pub(crate) fn get_log_datadog_request(params: GetLogDatadogParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_datadog_name = params.logging_datadog_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/datadog/{logging_datadog_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_datadog_name=crate::apis::urlencode(logging_datadog_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogDatadogError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/datadog/{logging_datadog_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_datadog_name=crate::apis::urlencode(logging_datadog_name));
+    crate::apis::Request::new("get_log_datadog", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Datadog logging objects for a particular service and version.
-pub async fn list_log_datadog(configuration: &mut configuration::Configuration, params: ListLogDatadogParams) -> Result<Vec<crate::models::LoggingDatadogResponse>, Error<ListLogDatadogError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_datadog(configuration: &configuration::Configuration, params: ListLogDatadogParams) -> Result<Vec<crate::models::LoggingDatadogResponse>, Error<ListLogDatadogError>> {
+    list_log_datadog_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_datadog`], but also returns the status and headers of the response.
+pub async fn list_log_datadog_with_info(configuration: &configuration::Configuration, params: ListLogDatadogParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingDatadogResponse>>, Error<ListLogDatadogError>> {
+    crate::apis::execute(configuration, list_log_datadog_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_datadog`] sends.
+pub(crate) fn list_log_datadog_request(params: ListLogDatadogParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/datadog", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogDatadogError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/datadog", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_datadog", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the Datadog logging object for a particular service and version.
-pub async fn update_log_datadog(configuration: &mut configuration::Configuration, params: UpdateLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<UpdateLogDatadogError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_datadog(configuration: &configuration::Configuration, params: UpdateLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<UpdateLogDatadogError>> {
+    update_log_datadog_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_datadog`], but also returns the status and headers of the response.
+pub async fn update_log_datadog_with_info(configuration: &configuration::Configuration, params: UpdateLogDatadogParams) -> Result<crate::apis::Response<crate::models::LoggingDatadogResponse>, Error<UpdateLogDatadogError>> {
+    crate::apis::execute(configuration, update_log_datadog_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_datadog`] sends.
+pub(crate) fn update_log_datadog_request(params: UpdateLogDatadogParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -387,24 +265,10 @@ pub async fn update_log_datadog(configuration: &mut configuration::Configuration
     let region = params.region;
     let token = params.token;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/datadog/{logging_datadog_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_datadog_name=crate::apis::urlencode(logging_datadog_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_datadog", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/datadog/{logging_datadog_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_datadog_name=crate::apis::urlencode(logging_datadog_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -424,34 +288,10 @@ pub async fn update_log_datadog(configuration: &mut configuration::Configuration
         local_var_form_params.insert("region", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogDatadogError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_digocean`]
@@ -41,9 +40,9 @@ pub struct CreateLogDigoceanParams {
     /// The name of the DigitalOcean Space.
     pub bucket_name: Option<String>,
     /// Your DigitalOcean Spaces account access key.
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// Your DigitalOcean Spaces account secret key.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The domain of the DigitalOcean Spaces endpoint.
     pub domain: Option<String>,
     /// The path to upload logs to.
@@ -115,9 +114,9 @@ pub struct UpdateLogDigoceanParams {
     /// The name of the DigitalOcean Space.
     pub bucket_name: Option<String>,
     /// Your DigitalOcean Spaces account access key.
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// Your DigitalOcean Spaces account secret key.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The domain of the DigitalOcean Spaces endpoint.
     pub domain: Option<String>,
     /// The path to upload logs to.
@@ -164,9 +163,17 @@ pub enum UpdateLogDigoceanError {
 
 
 /// Create a DigitalOcean Space for a particular service and version.
-pub async fn create_log_digocean(configuration: &mut configuration::Configuration, params: CreateLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<CreateLogDigoceanError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_digocean(configuration: &configuration::Configuration, params: CreateLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<CreateLogDigoceanError>> {
+    create_log_digocean_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_digocean`], but also returns the status and headers of the response.
+pub async fn create_log_digocean_with_info(configuration: &configuration::Configuration, params: CreateLogDigoceanParams) -> Result<crate::apis::Response<crate::models::LoggingDigitaloceanResponse>, Error<CreateLogDigoceanError>> {
+    crate::apis::execute(configuration, create_log_digocean_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_digocean`] sends.
+pub(crate) fn create_log_digocean_request(params: CreateLogDigoceanParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -187,24 +194,10 @@ pub async fn create_log_digocean(configuration: &mut configuration::Configuratio
     let path = params.path;
     let public_key = params.public_key;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/digitalocean", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_digocean", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/digitalocean", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -239,10 +232,10 @@ pub async fn create_log_digocean(configuration: &mut configuration::Configuratio
         local_var_form_params.insert("bucket_name", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = access_key {
-        local_var_form_params.insert("access_key", local_var_param_value.to_string());
+        local_var_form_params.insert("access_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = domain {
         local_var_form_params.insert("domain", local_var_param_value.to_string());
@@ -253,200 +246,85 @@ pub async fn create_log_digocean(configuration: &mut configuration::Configuratio
     if let Some(local_var_param_value) = public_key {
         local_var_form_params.insert("public_key", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogDigoceanError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the DigitalOcean Space for a particular service and version.
-pub async fn delete_log_digocean(configuration: &mut configuration::Configuration, params: DeleteLogDigoceanParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogDigoceanError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_digocean(configuration: &configuration::Configuration, params: DeleteLogDigoceanParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogDigoceanError>> {
+    delete_log_digocean_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_digocean`], but also returns the status and headers of the response.
+pub async fn delete_log_digocean_with_info(configuration: &configuration::Configuration, params: DeleteLogDigoceanParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogDigoceanError>> {
+    crate::apis::execute(configuration, delete_log_digocean_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_digocean`] sends.
+pub(crate) fn delete_log_digocean_request(params: DeleteLogDigoceanParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_digitalocean_name = params.logging_digitalocean_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/digitalocean/{logging_digitalocean_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_digitalocean_name=crate::apis::urlencode(logging_digitalocean_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogDigoceanError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/digitalocean/{logging_digitalocean_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_digitalocean_name=crate::apis::urlencode(logging_digitalocean_name));
+    crate::apis::Request::new("delete_log_digocean", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the DigitalOcean Space for a particular service and version.
-pub async fn get_log_digocean(configuration: &mut configuration::Configuration, params: GetLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<GetLogDigoceanError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_digocean(configuration: &configuration::Configuration, params: GetLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<GetLogDigoceanError>> {
+    get_log_digocean_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_digocean`], but also returns the status and headers of the response.
+pub async fn get_log_digocean_with_info(configuration: &configuration::Configuration, params: GetLogDigoceanParams) -> Result<crate::apis::Response<crate::models::LoggingDigitaloceanResponse>, Error<GetLogDigoceanError>> {
+    crate::apis::execute(configuration, get_log_digocean_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_digocean`] sends.
+pub(crate) fn get_log_digocean_request(params: GetLogDigoceanParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_digitalocean_name = params.logging_digitalocean_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/digitalocean/{logging_digitalocean_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_digitalocean_name=crate::apis::urlencode(logging_digitalocean_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogDigoceanError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/digitalocean/{logging_digitalocean_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_digitalocean_name=crate::apis::urlencode(logging_digitalocean_name));
+    crate::apis::Request::new("get_log_digocean", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the DigitalOcean Spaces for a particular service and version.
-pub async fn list_log_digocean(configuration: &mut configuration::Configuration, params: ListLogDigoceanParams) -> Result<Vec<crate::models::LoggingDigitaloceanResponse>, Error<ListLogDigoceanError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_digocean(configuration: &configuration::Configuration, params: ListLogDigoceanParams) -> Result<Vec<crate::models::LoggingDigitaloceanResponse>, Error<ListLogDigoceanError>> {
+    list_log_digocean_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_digocean`], but also returns the status and headers of the response.
+pub async fn list_log_digocean_with_info(configuration: &configuration::Configuration, params: ListLogDigoceanParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingDigitaloceanResponse>>, Error<ListLogDigoceanError>> {
+    crate::apis::execute(configuration, list_log_digocean_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_digocean`] sends.
+pub(crate) fn list_log_digocean_request(params: ListLogDigoceanParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/digitalocean", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogDigoceanError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/digitalocean", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_digocean", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the DigitalOcean Space for a particular service and version.
-pub async fn update_log_digocean(configuration: &mut configuration::Configuration, params: UpdateLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<UpdateLogDigoceanError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_digocean(configuration: &configuration::Configuration, params: UpdateLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<UpdateLogDigoceanError>> {
+    update_log_digocean_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_digocean`], but also returns the status and headers of the response.
+pub async fn update_log_digocean_with_info(configuration: &configuration::Configuration, params: UpdateLogDigoceanParams) -> Result<crate::apis::Response<crate::models::LoggingDigitaloceanResponse>, Error<UpdateLogDigoceanError>> {
+    crate::apis::execute(configuration, update_log_digocean_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_digocean`] sends.
+pub(crate) fn update_log_digocean_request(params: UpdateLogDigoceanParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -468,24 +346,10 @@ pub async fn update_log_digocean(configuration: &mut configuration::Configuratio
     let path = params.path;
     let public_key = params.public_key;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/digitalocean/{logging_digitalocean_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_digitalocean_name=crate::apis::urlencode(logging_digitalocean_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_digocean", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/digitalocean/{logging_digitalocean_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_digitalocean_name=crate::apis::urlencode(logging_digitalocean_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -520,10 +384,10 @@ pub async fn update_log_digocean(configuration: &mut configuration::Configuratio
         local_var_form_params.insert("bucket_name", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = access_key {
-        local_var_form_params.insert("access_key", local_var_param_value.to_string());
+        local_var_form_params.insert("access_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = domain {
         local_var_form_params.insert("domain", local_var_param_value.to_string());
@@ -534,32 +398,8 @@ pub async fn update_log_digocean(configuration: &mut configuration::Configuratio
     if let Some(local_var_param_value) = public_key {
         local_var_form_params.insert("public_key", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogDigoceanError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_elasticsearch`]
@@ -33,7 +32,7 @@ pub struct CreateLogElasticsearchParams {
     /// The client certificate used to make authenticated requests. Must be in PEM format.
     pub tls_client_cert: Option<String>,
     /// The client private key used to make authenticated requests. Must be in PEM format.
-    pub tls_client_key: Option<String>,
+    pub tls_client_key: Option<crate::apis::redact::Secret>,
     /// The hostname to verify the server's certificate. This should be one of the Subject Alternative Name (SAN) fields for the certificate. Common Names (CN) are not supported.
     pub tls_hostname: Option<String>,
     /// The maximum number of logs sent in one request. Defaults `0` for unbounded.
@@ -49,7 +48,7 @@ pub struct CreateLogElasticsearchParams {
     /// Basic Auth username.
     pub user: Option<String>,
     /// Basic Auth password.
-    pub password: Option<String>
+    pub password: Option<crate::apis::redact::Secret>
 }
 
 /// struct for passing parameters to the method [`delete_log_elasticsearch`]
@@ -107,7 +106,7 @@ pub struct UpdateLogElasticsearchParams {
     /// The client certificate used to make authenticated requests. Must be in PEM format.
     pub tls_client_cert: Option<String>,
     /// The client private key used to make authenticated requests. Must be in PEM format.
-    pub tls_client_key: Option<String>,
+    pub tls_client_key: Option<crate::apis::redact::Secret>,
     /// The hostname to verify the server's certificate. This should be one of the Subject Alternative Name (SAN) fields for the certificate. Common Names (CN) are not supported.
     pub tls_hostname: Option<String>,
     /// The maximum number of logs sent in one request. Defaults `0` for unbounded.
@@ -123,7 +122,7 @@ pub struct UpdateLogElasticsearchParams {
     /// Basic Auth username.
     pub user: Option<String>,
     /// Basic Auth password.
-    pub password: Option<String>
+    pub password: Option<crate::apis::redact::Secret>
 }
 
 
@@ -164,9 +163,17 @@ pub enum UpdateLogElasticsearchError {
 
 
 /// Create a Elasticsearch logging endpoint for a particular service and version.
-pub async fn create_log_elasticsearch(configuration: &mut configuration::Configuration, params: CreateLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<CreateLogElasticsearchError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_elasticsearch(configuration: &configuration::Configuration, params: CreateLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<CreateLogElasticsearchError>> {
+    create_log_elasticsearch_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_elasticsearch`], but also returns the status and headers of the response.
+pub async fn create_log_elasticsearch_with_info(configuration: &configuration::Configuration, params: CreateLogElasticsearchParams) -> Result<crate::apis::Response<crate::models::LoggingElasticsearchResponse>, Error<CreateLogElasticsearchError>> {
+    crate::apis::execute(configuration, create_log_elasticsearch_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_elasticsearch`] sends.
+pub(crate) fn create_log_elasticsearch_request(params: CreateLogElasticsearchParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -187,24 +194,10 @@ pub async fn create_log_elasticsearch(configuration: &mut configuration::Configu
     let user = params.user;
     let password = params.password;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/elasticsearch", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_elasticsearch", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/elasticsearch", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -227,7 +220,7 @@ pub async fn create_log_elasticsearch(configuration: &mut configuration::Configu
         local_var_form_params.insert("tls_client_cert", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = tls_client_key {
-        local_var_form_params.insert("tls_client_key", local_var_param_value.to_string());
+        local_var_form_params.insert("tls_client_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = tls_hostname {
         local_var_form_params.insert("tls_hostname", local_var_param_value.to_string());
@@ -251,202 +244,87 @@ pub async fn create_log_elasticsearch(configuration: &mut configuration::Configu
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = password {
-        local_var_form_params.insert("password", local_var_param_value.to_string());
-    }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_form_params.insert("password", local_var_param_value.expose().to_string());
     }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogElasticsearchError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the Elasticsearch logging endpoint for a particular service and version.
-pub async fn delete_log_elasticsearch(configuration: &mut configuration::Configuration, params: DeleteLogElasticsearchParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogElasticsearchError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_elasticsearch(configuration: &configuration::Configuration, params: DeleteLogElasticsearchParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogElasticsearchError>> {
+    delete_log_elasticsearch_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_elasticsearch`], but also returns the status and headers of the response.
+pub async fn delete_log_elasticsearch_with_info(configuration: &configuration::Configuration, params: DeleteLogElasticsearchParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogElasticsearchError>> {
+    crate::apis::execute(configuration, delete_log_elasticsearch_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_elasticsearch`] sends.
+pub(crate) fn delete_log_elasticsearch_request(params: DeleteLogElasticsearchParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_elasticsearch_name = params.logging_elasticsearch_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/elasticsearch/{logging_elasticsearch_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_elasticsearch_name=crate::apis::urlencode(logging_elasticsearch_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogElasticsearchError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/elasticsearch/{logging_elasticsearch_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_elasticsearch_name=crate::apis::urlencode(logging_elasticsearch_name));
+    crate::apis::Request::new("delete_log_elasticsearch", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the Elasticsearch logging endpoint for a particular service and version.
-pub async fn get_log_elasticsearch(configuration: &mut configuration::Configuration, params: GetLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<GetLogElasticsearchError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_elasticsearch(configuration: &configuration::Configuration, params: GetLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<GetLogElasticsearchError>> {
+    get_log_elasticsearch_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_elasticsearch`], but also returns the status and headers of the response.
+pub async fn get_log_elasticsearch_with_info(configuration: &configuration::Configuration, params: GetLogElasticsearchParams) -> Result<crate::apis::Response<crate::models::LoggingElasticsearchResponse>, Error<GetLogElasticsearchError>> {
+    crate::apis::execute(configuration, get_log_elasticsearch_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_elasticsearch`] sends.
+pub(crate) fn get_log_elasticsearch_request(params: GetLogElasticsearchParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_elasticsearch_name = params.logging_elasticsearch_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/elasticsearch/{logging_elasticsearch_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_elasticsearch_name=crate::apis::urlencode(logging_elasticsearch_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogElasticsearchError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/elasticsearch/{logging_elasticsearch_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_elasticsearch_name=crate::apis::urlencode(logging_elasticsearch_name));
+    crate::apis::Request::new("get_log_elasticsearch", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Elasticsearch logging endpoints for a particular service and version.
-pub async fn list_log_elasticsearch(configuration: &mut configuration::Configuration, params: ListLogElasticsearchParams) -> Result<Vec<crate::models::LoggingElasticsearchResponse>, Error<ListLogElasticsearchError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_elasticsearch(configuration: &configuration::Configuration, params: ListLogElasticsearchParams) -> Result<Vec<crate::models::LoggingElasticsearchResponse>, Error<ListLogElasticsearchError>> {
+    list_log_elasticsearch_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_elasticsearch`], but also returns the status and headers of the response.
+pub async fn list_log_elasticsearch_with_info(configuration: &configuration::Configuration, params: ListLogElasticsearchParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingElasticsearchResponse>>, Error<ListLogElasticsearchError>> {
+    crate::apis::execute(configuration, list_log_elasticsearch_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_elasticsearch`] sends.
+pub(crate) fn list_log_elasticsearch_request(params: ListLogElasticsearchParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/elasticsearch", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogElasticsearchError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/elasticsearch", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_elasticsearch", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the Elasticsearch logging endpoint for a particular service and version.
-pub async fn update_log_elasticsearch(configuration: &mut configuration::Configuration, params: UpdateLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<UpdateLogElasticsearchError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_elasticsearch(configuration: &configuration::Configuration, params: UpdateLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<UpdateLogElasticsearchError>> {
+    update_log_elasticsearch_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_elasticsearch`], but also returns the status and headers of the response.
+pub async fn update_log_elasticsearch_with_info(configuration: &configuration::Configuration, params: UpdateLogElasticsearchParams) -> Result<crate::apis::Response<crate::models::LoggingElasticsearchResponse>, Error<UpdateLogElasticsearchError>> {
+    crate::apis::execute(configuration, update_log_elasticsearch_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_elasticsearch`] sends.
+pub(crate) fn update_log_elasticsearch_request(params: UpdateLogElasticsearchParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -468,24 +346,10 @@ pub async fn update_log_elasticsearch(configuration: &mut configuration::Configu
     let user = params.user;
     let password = params.password;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/elasticsearch/{logging_elasticsearch_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_elasticsearch_name=crate::apis::urlencode(logging_elasticsearch_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_elasticsearch", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/elasticsearch/{logging_elasticsearch_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_elasticsearch_name=crate::apis::urlencode(logging_elasticsearch_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -508,7 +372,7 @@ pub async fn update_log_elasticsearch(configuration: &mut configuration::Configu
         local_var_form_params.insert("tls_client_cert", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = tls_client_key {
-        local_var_form_params.insert("tls_client_key", local_var_param_value.to_string());
+        local_var_form_params.insert("tls_client_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = tls_hostname {
         local_var_form_params.insert("tls_hostname", local_var_param_value.to_string());
@@ -532,34 +396,10 @@ pub async fn update_log_elasticsearch(configuration: &mut configuration::Configu
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = password {
-        local_var_form_params.insert("password", local_var_param_value.to_string());
+        local_var_form_params.insert("password", local_var_param_value.expose().to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogElasticsearchError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
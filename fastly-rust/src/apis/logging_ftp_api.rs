@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_ftp`]
@@ -45,7 +44,7 @@ pub struct CreateLogFtpParams {
     /// IPv4 address of the host.
     pub ipv4: Option<String>,
     /// The password for the server. For anonymous use an email address.
-    pub password: Option<String>,
+    pub password: Option<crate::apis::redact::Secret>,
     /// The path to upload log files to. If the path ends in `/` then it is treated as a directory.
     pub path: Option<String>,
     /// A PGP public key that Fastly will use to encrypt your log files before writing them to disk.
@@ -123,7 +122,7 @@ pub struct UpdateLogFtpParams {
     /// IPv4 address of the host.
     pub ipv4: Option<String>,
     /// The password for the server. For anonymous use an email address.
-    pub password: Option<String>,
+    pub password: Option<crate::apis::redact::Secret>,
     /// The path to upload log files to. If the path ends in `/` then it is treated as a directory.
     pub path: Option<String>,
     /// A PGP public key that Fastly will use to encrypt your log files before writing them to disk.
@@ -172,9 +171,17 @@ pub enum UpdateLogFtpError {
 
 
 /// Create a FTP for a particular service and version.
-pub async fn create_log_ftp(configuration: &mut configuration::Configuration, params: CreateLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<CreateLogFtpError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_ftp(configuration: &configuration::Configuration, params: CreateLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<CreateLogFtpError>> {
+    create_log_ftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_ftp`], but also returns the status and headers of the response.
+pub async fn create_log_ftp_with_info(configuration: &configuration::Configuration, params: CreateLogFtpParams) -> Result<crate::apis::Response<crate::models::LoggingFtpResponse>, Error<CreateLogFtpError>> {
+    crate::apis::execute(configuration, create_log_ftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_ftp`] sends.
+pub(crate) fn create_log_ftp_request(params: CreateLogFtpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -197,24 +204,10 @@ pub async fn create_log_ftp(configuration: &mut configuration::Configuration, pa
     let user = params.user;
     let port = params.port;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/ftp", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_ftp", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/ftp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -255,7 +248,7 @@ pub async fn create_log_ftp(configuration: &mut configuration::Configuration, pa
         local_var_form_params.insert("ipv4", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = password {
-        local_var_form_params.insert("password", local_var_param_value.to_string());
+        local_var_form_params.insert("password", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = path {
         local_var_form_params.insert("path", local_var_param_value.to_string());
@@ -269,200 +262,85 @@ pub async fn create_log_ftp(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = port {
         local_var_form_params.insert("port", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogFtpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the FTP for a particular service and version.
-pub async fn delete_log_ftp(configuration: &mut configuration::Configuration, params: DeleteLogFtpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogFtpError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_ftp(configuration: &configuration::Configuration, params: DeleteLogFtpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogFtpError>> {
+    delete_log_ftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_ftp`], but also returns the status and headers of the response.
+pub async fn delete_log_ftp_with_info(configuration: &configuration::Configuration, params: DeleteLogFtpParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogFtpError>> {
+    crate::apis::execute(configuration, delete_log_ftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_ftp`] sends.
+pub(crate) fn delete_log_ftp_request(params: DeleteLogFtpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_ftp_name = params.logging_ftp_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/ftp/{logging_ftp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_ftp_name=crate::apis::urlencode(logging_ftp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogFtpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/ftp/{logging_ftp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_ftp_name=crate::apis::urlencode(logging_ftp_name));
+    crate::apis::Request::new("delete_log_ftp", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the FTP for a particular service and version.
-pub async fn get_log_ftp(configuration: &mut configuration::Configuration, params: GetLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<GetLogFtpError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_ftp(configuration: &configuration::Configuration, params: GetLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<GetLogFtpError>> {
+    get_log_ftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_ftp`], but also returns the status and headers of the response.
+pub async fn get_log_ftp_with_info(configuration: &configuration::Configuration, params: GetLogFtpParams) -> Result<crate::apis::Response<crate::models::LoggingFtpResponse>, Error<GetLogFtpError>> {
+    crate::apis::execute(configuration, get_log_ftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_ftp`] sends.
+pub(crate) fn get_log_ftp_request(params: GetLogFtpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_ftp_name = params.logging_ftp_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/ftp/{logging_ftp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_ftp_name=crate::apis::urlencode(logging_ftp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogFtpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/ftp/{logging_ftp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_ftp_name=crate::apis::urlencode(logging_ftp_name));
+    crate::apis::Request::new("get_log_ftp", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the FTPs for a particular service and version.
-pub async fn list_log_ftp(configuration: &mut configuration::Configuration, params: ListLogFtpParams) -> Result<Vec<crate::models::LoggingFtpResponse>, Error<ListLogFtpError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_ftp(configuration: &configuration::Configuration, params: ListLogFtpParams) -> Result<Vec<crate::models::LoggingFtpResponse>, Error<ListLogFtpError>> {
+    list_log_ftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_ftp`], but also returns the status and headers of the response.
+pub async fn list_log_ftp_with_info(configuration: &configuration::Configuration, params: ListLogFtpParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingFtpResponse>>, Error<ListLogFtpError>> {
+    crate::apis::execute(configuration, list_log_ftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_ftp`] sends.
+pub(crate) fn list_log_ftp_request(params: ListLogFtpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/ftp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogFtpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/ftp", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_ftp", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the FTP for a particular service and version.
-pub async fn update_log_ftp(configuration: &mut configuration::Configuration, params: UpdateLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<UpdateLogFtpError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_ftp(configuration: &configuration::Configuration, params: UpdateLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<UpdateLogFtpError>> {
+    update_log_ftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_ftp`], but also returns the status and headers of the response.
+pub async fn update_log_ftp_with_info(configuration: &configuration::Configuration, params: UpdateLogFtpParams) -> Result<crate::apis::Response<crate::models::LoggingFtpResponse>, Error<UpdateLogFtpError>> {
+    crate::apis::execute(configuration, update_log_ftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_ftp`] sends.
+pub(crate) fn update_log_ftp_request(params: UpdateLogFtpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -486,24 +364,10 @@ pub async fn update_log_ftp(configuration: &mut configuration::Configuration, pa
     let user = params.user;
     let port = params.port;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/ftp/{logging_ftp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_ftp_name=crate::apis::urlencode(logging_ftp_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_ftp", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/ftp/{logging_ftp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_ftp_name=crate::apis::urlencode(logging_ftp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -544,7 +408,7 @@ pub async fn update_log_ftp(configuration: &mut configuration::Configuration, pa
         local_var_form_params.insert("ipv4", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = password {
-        local_var_form_params.insert("password", local_var_param_value.to_string());
+        local_var_form_params.insert("password", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = path {
         local_var_form_params.insert("path", local_var_param_value.to_string());
@@ -558,32 +422,8 @@ pub async fn update_log_ftp(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = port {
         local_var_form_params.insert("port", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogFtpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
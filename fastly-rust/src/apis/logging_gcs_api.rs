@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_gcs`]
@@ -41,7 +40,7 @@ pub struct CreateLogGcsParams {
     /// Your Google Cloud Platform service account email address. The `client_email` field in your service account authentication JSON. Not required if `account_name` is specified.
     pub user: Option<String>,
     /// Your Google Cloud Platform account secret key. The `private_key` field in your service account authentication JSON. Not required if `account_name` is specified.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The name of the Google Cloud Platform service account associated with the target log collection service. Not required if `user` and `secret_key` are provided.
     pub account_name: Option<String>,
     /// The name of the GCS bucket.
@@ -116,7 +115,7 @@ pub struct UpdateLogGcsParams {
     /// Your Google Cloud Platform service account email address. The `client_email` field in your service account authentication JSON. Not required if `account_name` is specified.
     pub user: Option<String>,
     /// Your Google Cloud Platform account secret key. The `private_key` field in your service account authentication JSON. Not required if `account_name` is specified.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The name of the Google Cloud Platform service account associated with the target log collection service. Not required if `user` and `secret_key` are provided.
     pub account_name: Option<String>,
     /// The name of the GCS bucket.
@@ -166,9 +165,17 @@ pub enum UpdateLogGcsError {
 
 
 /// Create GCS logging for a particular service and version.
-pub async fn create_log_gcs(configuration: &mut configuration::Configuration, params: CreateLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<CreateLogGcsError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_gcs(configuration: &configuration::Configuration, params: CreateLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<CreateLogGcsError>> {
+    create_log_gcs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_gcs`], but also returns the status and headers of the response.
+pub async fn create_log_gcs_with_info(configuration: &configuration::Configuration, params: CreateLogGcsParams) -> Result<crate::apis::Response<crate::models::LoggingGcsResponse>, Error<CreateLogGcsError>> {
+    crate::apis::execute(configuration, create_log_gcs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_gcs`] sends.
+pub(crate) fn create_log_gcs_request(params: CreateLogGcsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -190,24 +197,10 @@ pub async fn create_log_gcs(configuration: &mut configuration::Configuration, pa
     let public_key = params.public_key;
     let project_id = params.project_id;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/gcs", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_gcs", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/gcs", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -242,7 +235,7 @@ pub async fn create_log_gcs(configuration: &mut configuration::Configuration, pa
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = account_name {
         local_var_form_params.insert("account_name", local_var_param_value.to_string());
@@ -259,200 +252,85 @@ pub async fn create_log_gcs(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = project_id {
         local_var_form_params.insert("project_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogGcsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the GCS Logging for a particular service and version.
-pub async fn delete_log_gcs(configuration: &mut configuration::Configuration, params: DeleteLogGcsParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogGcsError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_gcs(configuration: &configuration::Configuration, params: DeleteLogGcsParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogGcsError>> {
+    delete_log_gcs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_gcs`], but also returns the status and headers of the response.
+pub async fn delete_log_gcs_with_info(configuration: &configuration::Configuration, params: DeleteLogGcsParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogGcsError>> {
+    crate::apis::execute(configuration, delete_log_gcs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_gcs`] sends.
+pub(crate) fn delete_log_gcs_request(params: DeleteLogGcsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_gcs_name = params.logging_gcs_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/gcs/{logging_gcs_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_gcs_name=crate::apis::urlencode(logging_gcs_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogGcsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/gcs/{logging_gcs_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_gcs_name=crate::apis::urlencode(logging_gcs_name));
+    crate::apis::Request::new("delete_log_gcs", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the GCS Logging for a particular service and version.
-pub async fn get_log_gcs(configuration: &mut configuration::Configuration, params: GetLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<GetLogGcsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_gcs(configuration: &configuration::Configuration, params: GetLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<GetLogGcsError>> {
+    get_log_gcs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_gcs`], but also returns the status and headers of the response.
+pub async fn get_log_gcs_with_info(configuration: &configuration::Configuration, params: GetLogGcsParams) -> Result<crate::apis::Response<crate::models::LoggingGcsResponse>, Error<GetLogGcsError>> {
+    crate::apis::execute(configuration, get_log_gcs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_gcs`] sends.
+pub(crate) fn get_log_gcs_request(params: GetLogGcsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_gcs_name = params.logging_gcs_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/gcs/{logging_gcs_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_gcs_name=crate::apis::urlencode(logging_gcs_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogGcsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/gcs/{logging_gcs_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_gcs_name=crate::apis::urlencode(logging_gcs_name));
+    crate::apis::Request::new("get_log_gcs", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the GCS log endpoints for a particular service and version.
-pub async fn list_log_gcs(configuration: &mut configuration::Configuration, params: ListLogGcsParams) -> Result<Vec<crate::models::LoggingGcsResponse>, Error<ListLogGcsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_gcs(configuration: &configuration::Configuration, params: ListLogGcsParams) -> Result<Vec<crate::models::LoggingGcsResponse>, Error<ListLogGcsError>> {
+    list_log_gcs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_gcs`], but also returns the status and headers of the response.
+pub async fn list_log_gcs_with_info(configuration: &configuration::Configuration, params: ListLogGcsParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingGcsResponse>>, Error<ListLogGcsError>> {
+    crate::apis::execute(configuration, list_log_gcs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_gcs`] sends.
+pub(crate) fn list_log_gcs_request(params: ListLogGcsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/gcs", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogGcsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/gcs", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_gcs", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the GCS for a particular service and version.
-pub async fn update_log_gcs(configuration: &mut configuration::Configuration, params: UpdateLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<UpdateLogGcsError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_gcs(configuration: &configuration::Configuration, params: UpdateLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<UpdateLogGcsError>> {
+    update_log_gcs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_gcs`], but also returns the status and headers of the response.
+pub async fn update_log_gcs_with_info(configuration: &configuration::Configuration, params: UpdateLogGcsParams) -> Result<crate::apis::Response<crate::models::LoggingGcsResponse>, Error<UpdateLogGcsError>> {
+    crate::apis::execute(configuration, update_log_gcs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_gcs`] sends.
+pub(crate) fn update_log_gcs_request(params: UpdateLogGcsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -475,24 +353,10 @@ pub async fn update_log_gcs(configuration: &mut configuration::Configuration, pa
     let public_key = params.public_key;
     let project_id = params.project_id;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/gcs/{logging_gcs_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_gcs_name=crate::apis::urlencode(logging_gcs_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_gcs", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/gcs/{logging_gcs_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_gcs_name=crate::apis::urlencode(logging_gcs_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -527,7 +391,7 @@ pub async fn update_log_gcs(configuration: &mut configuration::Configuration, pa
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = account_name {
         local_var_form_params.insert("account_name", local_var_param_value.to_string());
@@ -544,32 +408,8 @@ pub async fn update_log_gcs(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = project_id {
         local_var_form_params.insert("project_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogGcsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
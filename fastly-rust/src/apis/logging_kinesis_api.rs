@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_kinesis`]
@@ -27,9 +26,9 @@ pub struct CreateLogKinesisParams {
     pub topic: Option<String>,
     pub region: Option<crate::models::AwsRegion>,
     /// The secret key associated with the target Amazon Kinesis stream. Not required if `iam_role` is specified.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The access key associated with the target Amazon Kinesis stream. Not required if `iam_role` is specified.
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The ARN for an IAM role granting Fastly access to the target Amazon Kinesis stream. Not required if `access_key` and `secret_key` are provided.
     pub iam_role: Option<String>,
     /// The version of the custom logging format used for the configured endpoint. The logging call gets placed by default in `vcl_log` if `format_version` is set to `2` and in `vcl_deliver` if `format_version` is set to `1`. 
@@ -116,9 +115,17 @@ pub enum UpdateLogKinesisError {
 
 
 /// Create an Amazon Kinesis Data Streams logging object for a particular service and version.
-pub async fn create_log_kinesis(configuration: &mut configuration::Configuration, params: CreateLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<CreateLogKinesisError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_kinesis(configuration: &configuration::Configuration, params: CreateLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<CreateLogKinesisError>> {
+    create_log_kinesis_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_kinesis`], but also returns the status and headers of the response.
+pub async fn create_log_kinesis_with_info(configuration: &configuration::Configuration, params: CreateLogKinesisParams) -> Result<crate::apis::Response<crate::models::LoggingKinesisResponse>, Error<CreateLogKinesisError>> {
+    crate::apis::execute(configuration, create_log_kinesis_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_kinesis`] sends.
+pub(crate) fn create_log_kinesis_request(params: CreateLogKinesisParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -132,24 +139,10 @@ pub async fn create_log_kinesis(configuration: &mut configuration::Configuration
     let iam_role = params.iam_role;
     let format_version = params.format_version;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/kinesis", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_kinesis", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/kinesis", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -166,10 +159,10 @@ pub async fn create_log_kinesis(configuration: &mut configuration::Configuration
         local_var_form_params.insert("region", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = access_key {
-        local_var_form_params.insert("access_key", local_var_param_value.to_string());
+        local_var_form_params.insert("access_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = iam_role {
         local_var_form_params.insert("iam_role", local_var_param_value.to_string());
@@ -177,247 +170,91 @@ pub async fn create_log_kinesis(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = format_version {
         local_var_form_params.insert("format_version", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogKinesisError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete an Amazon Kinesis Data Streams logging object for a particular service and version.
-pub async fn delete_log_kinesis(configuration: &mut configuration::Configuration, params: DeleteLogKinesisParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogKinesisError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_kinesis(configuration: &configuration::Configuration, params: DeleteLogKinesisParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogKinesisError>> {
+    delete_log_kinesis_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_kinesis`], but also returns the status and headers of the response.
+pub async fn delete_log_kinesis_with_info(configuration: &configuration::Configuration, params: DeleteLogKinesisParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogKinesisError>> {
+    crate::apis::execute(configuration, delete_log_kinesis_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_kinesis`] sends.
+pub(crate) fn delete_log_kinesis_request(params: DeleteLogKinesisParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_kinesis_name = params.logging_kinesis_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/kinesis/{logging_kinesis_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_kinesis_name=crate::apis::urlencode(logging_kinesis_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogKinesisError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/kinesis/{logging_kinesis_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_kinesis_name=crate::apis::urlencode(logging_kinesis_name));
+    crate::apis::Request::new("delete_log_kinesis", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the details for an Amazon Kinesis Data Streams logging object for a particular service and version.
-pub async fn get_log_kinesis(configuration: &mut configuration::Configuration, params: GetLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<GetLogKinesisError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_kinesis(configuration: &configuration::Configuration, params: GetLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<GetLogKinesisError>> {
+    get_log_kinesis_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_log_kinesis`], but also returns the status and headers of the response.
+pub async fn get_log_kinesis_with_info(configuration: &configuration::Configuration, params: GetLogKinesisParams) -> Result<crate::apis::Response<crate::models::LoggingKinesisResponse>, Error<GetLogKinesisError>> {
+    crate::apis::execute(configuration, get_log_kinesis_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_log_kinesis`] sends.
+pub(crate) fn get_log_kinesis_request(params: GetLogKinesisParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_kinesis_name = params.logging_kinesis_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/kinesis/{logging_kinesis_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_kinesis_name=crate::apis::urlencode(logging_kinesis_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogKinesisError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/kinesis/{logging_kinesis_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_kinesis_name=crate::apis::urlencode(logging_kinesis_name));
+    crate::apis::Request::new("get_log_kinesis", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Amazon Kinesis Data Streams logging objects for a particular service and version.
-pub async fn list_log_kinesis(configuration: &mut configuration::Configuration, params: ListLogKinesisParams) -> Result<Vec<crate::models::LoggingKinesisResponse>, Error<ListLogKinesisError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_kinesis(configuration: &configuration::Configuration, params: ListLogKinesisParams) -> Result<Vec<crate::models::LoggingKinesisResponse>, Error<ListLogKinesisError>> {
+    list_log_kinesis_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_log_kinesis`], but also returns the status and headers of the response.
+pub async fn list_log_kinesis_with_info(configuration: &configuration::Configuration, params: ListLogKinesisParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingKinesisResponse>>, Error<ListLogKinesisError>> {
+    crate::apis::execute(configuration, list_log_kinesis_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_log_kinesis`] sends.
+pub(crate) fn list_log_kinesis_request(params: ListLogKinesisParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/kinesis", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogKinesisError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/kinesis", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_kinesis", reqwest::Method::GET, local_var_path)
 }
 
 /// Update an Amazon Kinesis Data Streams logging object for a particular service and version.
-pub async fn update_log_kinesis(configuration: &mut configuration::Configuration, params: UpdateLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<UpdateLogKinesisError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_kinesis(configuration: &configuration::Configuration, params: UpdateLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<UpdateLogKinesisError>> {
+    update_log_kinesis_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_log_kinesis`], but also returns the status and headers of the response.
+pub async fn update_log_kinesis_with_info(configuration: &configuration::Configuration, params: UpdateLogKinesisParams) -> Result<crate::apis::Response<crate::models::LoggingKinesisResponse>, Error<UpdateLogKinesisError>> {
+    crate::apis::execute(configuration, update_log_kinesis_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_log_kinesis`] sends.
+pub(crate) fn update_log_kinesis_request(params: UpdateLogKinesisParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_kinesis_name = params.logging_kinesis_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/kinesis/{logging_kinesis_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_kinesis_name=crate::apis::urlencode(logging_kinesis_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogKinesisError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/kinesis/{logging_kinesis_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_kinesis_name=crate::apis::urlencode(logging_kinesis_name));
+    crate::apis::Request::new("update_log_kinesis", reqwest::Method::PUT, local_var_path)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_logentries`]
@@ -31,7 +30,7 @@ pub struct CreateLogLogentriesParams {
     /// The port number.
     pub port: Option<i32>,
     /// Use token based authentication.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     pub use_tls: Option<crate::models::LoggingUseTlsString>,
     /// The region to which to stream logs.
     pub region: Option<String>
@@ -90,7 +89,7 @@ pub struct UpdateLogLogentriesParams {
     /// The port number.
     pub port: Option<i32>,
     /// Use token based authentication.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     pub use_tls: Option<crate::models::LoggingUseTlsString>,
     /// The region to which to stream logs.
     pub region: Option<String>
@@ -134,9 +133,17 @@ pub enum UpdateLogLogentriesError {
 
 
 /// Create a Logentry for a particular service and version.
-pub async fn create_log_logentries(configuration: &mut configuration::Configuration, params: CreateLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<CreateLogLogentriesError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_logentries(configuration: &configuration::Configuration, params: CreateLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<CreateLogLogentriesError>> {
+    create_log_logentries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_logentries`], but also returns the status and headers of the response.
+pub async fn create_log_logentries_with_info(configuration: &configuration::Configuration, params: CreateLogLogentriesParams) -> Result<crate::apis::Response<crate::models::LoggingLogentriesResponse>, Error<CreateLogLogentriesError>> {
+    crate::apis::execute(configuration, create_log_logentries_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_logentries`] sends.
+pub(crate) fn create_log_logentries_request(params: CreateLogLogentriesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -150,24 +157,10 @@ pub async fn create_log_logentries(configuration: &mut configuration::Configurat
     let use_tls = params.use_tls;
     let region = params.region;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logentries", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_logentries", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logentries", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -187,7 +180,7 @@ pub async fn create_log_logentries(configuration: &mut configuration::Configurat
         local_var_form_params.insert("port", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = use_tls {
         local_var_form_params.insert("use_tls", local_var_param_value.to_string());
@@ -195,200 +188,85 @@ pub async fn create_log_logentries(configuration: &mut configuration::Configurat
     if let Some(local_var_param_value) = region {
         local_var_form_params.insert("region", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogLogentriesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the Logentry for a particular service and version.
-pub async fn delete_log_logentries(configuration: &mut configuration::Configuration, params: DeleteLogLogentriesParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogLogentriesError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_logentries(configuration: &configuration::Configuration, params: DeleteLogLogentriesParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogLogentriesError>> {
+    delete_log_logentries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_logentries`], but also returns the status and headers of the response.
+pub async fn delete_log_logentries_with_info(configuration: &configuration::Configuration, params: DeleteLogLogentriesParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogLogentriesError>> {
+    crate::apis::execute(configuration, delete_log_logentries_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_logentries`] sends.
+pub(crate) fn delete_log_logentries_request(params: DeleteLogLogentriesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_logentries_name = params.logging_logentries_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logentries/{logging_logentries_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logentries_name=crate::apis::urlencode(logging_logentries_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogLogentriesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logentries/{logging_logentries_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logentries_name=crate::apis::urlencode(logging_logentries_name));
+    crate::apis::Request::new("delete_log_logentries", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the Logentry for a particular service and version.
-pub async fn get_log_logentries(configuration: &mut configuration::Configuration, params: GetLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<GetLogLogentriesError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_logentries(configuration: &configuration::Configuration, params: GetLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<GetLogLogentriesError>> {
+    get_log_logentries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_logentries`], but also returns the status and headers of the response.
+pub async fn get_log_logentries_with_info(configuration: &configuration::Configuration, params: GetLogLogentriesParams) -> Result<crate::apis::Response<crate::models::LoggingLogentriesResponse>, Error<GetLogLogentriesError>> {
+    crate::apis::execute(configuration, get_log_logentries_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_logentries`] sends.
+pub(crate) fn get_log_logentries_request(params: GetLogLogentriesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_logentries_name = params.logging_logentries_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logentries/{logging_logentries_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logentries_name=crate::apis::urlencode(logging_logentries_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogLogentriesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logentries/{logging_logentries_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logentries_name=crate::apis::urlencode(logging_logentries_name));
+    crate::apis::Request::new("get_log_logentries", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Logentries for a particular service and version.
-pub async fn list_log_logentries(configuration: &mut configuration::Configuration, params: ListLogLogentriesParams) -> Result<Vec<crate::models::LoggingLogentriesResponse>, Error<ListLogLogentriesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_logentries(configuration: &configuration::Configuration, params: ListLogLogentriesParams) -> Result<Vec<crate::models::LoggingLogentriesResponse>, Error<ListLogLogentriesError>> {
+    list_log_logentries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_logentries`], but also returns the status and headers of the response.
+pub async fn list_log_logentries_with_info(configuration: &configuration::Configuration, params: ListLogLogentriesParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingLogentriesResponse>>, Error<ListLogLogentriesError>> {
+    crate::apis::execute(configuration, list_log_logentries_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_logentries`] sends.
+pub(crate) fn list_log_logentries_request(params: ListLogLogentriesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logentries", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogLogentriesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logentries", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_logentries", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the Logentry for a particular service and version.
-pub async fn update_log_logentries(configuration: &mut configuration::Configuration, params: UpdateLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<UpdateLogLogentriesError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_logentries(configuration: &configuration::Configuration, params: UpdateLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<UpdateLogLogentriesError>> {
+    update_log_logentries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_logentries`], but also returns the status and headers of the response.
+pub async fn update_log_logentries_with_info(configuration: &configuration::Configuration, params: UpdateLogLogentriesParams) -> Result<crate::apis::Response<crate::models::LoggingLogentriesResponse>, Error<UpdateLogLogentriesError>> {
+    crate::apis::execute(configuration, update_log_logentries_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_logentries`] sends.
+pub(crate) fn update_log_logentries_request(params: UpdateLogLogentriesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -403,24 +281,10 @@ pub async fn update_log_logentries(configuration: &mut configuration::Configurat
     let use_tls = params.use_tls;
     let region = params.region;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logentries/{logging_logentries_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logentries_name=crate::apis::urlencode(logging_logentries_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_logentries", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logentries/{logging_logentries_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logentries_name=crate::apis::urlencode(logging_logentries_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -440,7 +304,7 @@ pub async fn update_log_logentries(configuration: &mut configuration::Configurat
         local_var_form_params.insert("port", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = use_tls {
         local_var_form_params.insert("use_tls", local_var_param_value.to_string());
@@ -448,32 +312,8 @@ pub async fn update_log_logentries(configuration: &mut configuration::Configurat
     if let Some(local_var_param_value) = region {
         local_var_form_params.insert("region", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogLogentriesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_logshuttle`]
@@ -29,7 +28,7 @@ pub struct CreateLogLogshuttleParams {
     /// The version of the custom logging format used for the configured endpoint. The logging call gets placed by default in `vcl_log` if `format_version` is set to `2` and in `vcl_deliver` if `format_version` is set to `1`. 
     pub format_version: Option<i32>,
     /// The data authentication token associated with this endpoint.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The URL to stream logs to.
     pub url: Option<String>
 }
@@ -85,7 +84,7 @@ pub struct UpdateLogLogshuttleParams {
     /// The version of the custom logging format used for the configured endpoint. The logging call gets placed by default in `vcl_log` if `format_version` is set to `2` and in `vcl_deliver` if `format_version` is set to `1`. 
     pub format_version: Option<i32>,
     /// The data authentication token associated with this endpoint.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The URL to stream logs to.
     pub url: Option<String>
 }
@@ -128,9 +127,17 @@ pub enum UpdateLogLogshuttleError {
 
 
 /// Create a Log Shuttle logging endpoint for a particular service and version.
-pub async fn create_log_logshuttle(configuration: &mut configuration::Configuration, params: CreateLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<CreateLogLogshuttleError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_logshuttle(configuration: &configuration::Configuration, params: CreateLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<CreateLogLogshuttleError>> {
+    create_log_logshuttle_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_logshuttle`], but also returns the status and headers of the response.
+pub async fn create_log_logshuttle_with_info(configuration: &configuration::Configuration, params: CreateLogLogshuttleParams) -> Result<crate::apis::Response<crate::models::LoggingLogshuttleResponse>, Error<CreateLogLogshuttleError>> {
+    crate::apis::execute(configuration, create_log_logshuttle_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_logshuttle`] sends.
+pub(crate) fn create_log_logshuttle_request(params: CreateLogLogshuttleParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -142,24 +149,10 @@ pub async fn create_log_logshuttle(configuration: &mut configuration::Configurat
     let token = params.token;
     let url = params.url;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logshuttle", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_logshuttle", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logshuttle", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -176,205 +169,90 @@ pub async fn create_log_logshuttle(configuration: &mut configuration::Configurat
         local_var_form_params.insert("format_version", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = url {
         local_var_form_params.insert("url", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogLogshuttleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the Log Shuttle logging endpoint for a particular service and version.
-pub async fn delete_log_logshuttle(configuration: &mut configuration::Configuration, params: DeleteLogLogshuttleParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogLogshuttleError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_logshuttle(configuration: &configuration::Configuration, params: DeleteLogLogshuttleParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogLogshuttleError>> {
+    delete_log_logshuttle_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_logshuttle`], but also returns the status and headers of the response.
+pub async fn delete_log_logshuttle_with_info(configuration: &configuration::Configuration, params: DeleteLogLogshuttleParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogLogshuttleError>> {
+    crate::apis::execute(configuration, delete_log_logshuttle_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_logshuttle`] sends.
+pub(crate) fn delete_log_logshuttle_request(params: DeleteLogLogshuttleParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_logshuttle_name = params.logging_logshuttle_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logshuttle/{logging_logshuttle_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logshuttle_name=crate::apis::urlencode(logging_logshuttle_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogLogshuttleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logshuttle/{logging_logshuttle_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logshuttle_name=crate::apis::urlencode(logging_logshuttle_name));
+    crate::apis::Request::new("delete_log_logshuttle", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the Log Shuttle logging endpoint for a particular service and version.
-pub async fn get_log_logshuttle(configuration: &mut configuration::Configuration, params: GetLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<GetLogLogshuttleError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_logshuttle(configuration: &configuration::Configuration, params: GetLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<GetLogLogshuttleError>> {
+    get_log_logshuttle_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_logshuttle`], but also returns the status and headers of the response.
+pub async fn get_log_logshuttle_with_info(configuration: &configuration::Configuration, params: GetLogLogshuttleParams) -> Result<crate::apis::Response<crate::models::LoggingLogshuttleResponse>, Error<GetLogLogshuttleError>> {
+    crate::apis::execute(configuration, get_log_logshuttle_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_logshuttle`] sends.
+pub(crate) fn get_log_logshuttle_request(params: GetLogLogshuttleParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_logshuttle_name = params.logging_logshuttle_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logshuttle/{logging_logshuttle_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logshuttle_name=crate::apis::urlencode(logging_logshuttle_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogLogshuttleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logshuttle/{logging_logshuttle_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logshuttle_name=crate::apis::urlencode(logging_logshuttle_name));
+    crate::apis::Request::new("get_log_logshuttle", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Log Shuttle logging endpoints for a particular service and version.
-pub async fn list_log_logshuttle(configuration: &mut configuration::Configuration, params: ListLogLogshuttleParams) -> Result<Vec<crate::models::LoggingLogshuttleResponse>, Error<ListLogLogshuttleError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_logshuttle(configuration: &configuration::Configuration, params: ListLogLogshuttleParams) -> Result<Vec<crate::models::LoggingLogshuttleResponse>, Error<ListLogLogshuttleError>> {
+    list_log_logshuttle_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_logshuttle`], but also returns the status and headers of the response.
+pub async fn list_log_logshuttle_with_info(configuration: &configuration::Configuration, params: ListLogLogshuttleParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingLogshuttleResponse>>, Error<ListLogLogshuttleError>> {
+    crate::apis::execute(configuration, list_log_logshuttle_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_logshuttle`] sends.
+pub(crate) fn list_log_logshuttle_request(params: ListLogLogshuttleParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logshuttle", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogLogshuttleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logshuttle", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_logshuttle", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the Log Shuttle logging endpoint for a particular service and version.
-pub async fn update_log_logshuttle(configuration: &mut configuration::Configuration, params: UpdateLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<UpdateLogLogshuttleError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_logshuttle(configuration: &configuration::Configuration, params: UpdateLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<UpdateLogLogshuttleError>> {
+    update_log_logshuttle_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_logshuttle`], but also returns the status and headers of the response.
+pub async fn update_log_logshuttle_with_info(configuration: &configuration::Configuration, params: UpdateLogLogshuttleParams) -> Result<crate::apis::Response<crate::models::LoggingLogshuttleResponse>, Error<UpdateLogLogshuttleError>> {
+    crate::apis::execute(configuration, update_log_logshuttle_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_logshuttle`] sends.
+pub(crate) fn update_log_logshuttle_request(params: UpdateLogLogshuttleParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -387,24 +265,10 @@ pub async fn update_log_logshuttle(configuration: &mut configuration::Configurat
     let token = params.token;
     let url = params.url;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/logshuttle/{logging_logshuttle_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logshuttle_name=crate::apis::urlencode(logging_logshuttle_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_logshuttle", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/logshuttle/{logging_logshuttle_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_logshuttle_name=crate::apis::urlencode(logging_logshuttle_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -421,37 +285,13 @@ pub async fn update_log_logshuttle(configuration: &mut configuration::Configurat
         local_var_form_params.insert("format_version", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = url {
         local_var_form_params.insert("url", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogLogshuttleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
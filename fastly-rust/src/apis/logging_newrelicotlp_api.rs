@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_newrelicotlp`]
@@ -29,7 +28,7 @@ pub struct CreateLogNewrelicotlpParams {
     /// The version of the custom logging format used for the configured endpoint. The logging call gets placed by default in `vcl_log` if `format_version` is set to `2` and in `vcl_deliver` if `format_version` is set to `1`. 
     pub format_version: Option<i32>,
     /// The Insert API key from the Account page of your New Relic account. Required.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The region to which to stream logs.
     pub region: Option<String>,
     /// (Optional) URL of the New Relic Trace Observer, if you are using New Relic Infinite Tracing.
@@ -87,7 +86,7 @@ pub struct UpdateLogNewrelicotlpParams {
     /// The version of the custom logging format used for the configured endpoint. The logging call gets placed by default in `vcl_log` if `format_version` is set to `2` and in `vcl_deliver` if `format_version` is set to `1`. 
     pub format_version: Option<i32>,
     /// The Insert API key from the Account page of your New Relic account. Required.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The region to which to stream logs.
     pub region: Option<String>,
     /// (Optional) URL of the New Relic Trace Observer, if you are using New Relic Infinite Tracing.
@@ -132,9 +131,17 @@ pub enum UpdateLogNewrelicotlpError {
 
 
 /// Create a New Relic OTLP logging object for a particular service and version.
-pub async fn create_log_newrelicotlp(configuration: &mut configuration::Configuration, params: CreateLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<CreateLogNewrelicotlpError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_newrelicotlp(configuration: &configuration::Configuration, params: CreateLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<CreateLogNewrelicotlpError>> {
+    create_log_newrelicotlp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_newrelicotlp`], but also returns the status and headers of the response.
+pub async fn create_log_newrelicotlp_with_info(configuration: &configuration::Configuration, params: CreateLogNewrelicotlpParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicotlpResponse>, Error<CreateLogNewrelicotlpError>> {
+    crate::apis::execute(configuration, create_log_newrelicotlp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_newrelicotlp`] sends.
+pub(crate) fn create_log_newrelicotlp_request(params: CreateLogNewrelicotlpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -147,24 +154,10 @@ pub async fn create_log_newrelicotlp(configuration: &mut configuration::Configur
     let region = params.region;
     let url = params.url;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/newrelicotlp", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_newrelicotlp", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/newrelicotlp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -181,7 +174,7 @@ pub async fn create_log_newrelicotlp(configuration: &mut configuration::Configur
         local_var_form_params.insert("format_version", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = region {
         local_var_form_params.insert("region", local_var_param_value.to_string());
@@ -189,200 +182,85 @@ pub async fn create_log_newrelicotlp(configuration: &mut configuration::Configur
     if let Some(local_var_param_value) = url {
         local_var_form_params.insert("url", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogNewrelicotlpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the New Relic OTLP logging object for a particular service and version.
-pub async fn delete_log_newrelicotlp(configuration: &mut configuration::Configuration, params: DeleteLogNewrelicotlpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogNewrelicotlpError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_newrelicotlp(configuration: &configuration::Configuration, params: DeleteLogNewrelicotlpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogNewrelicotlpError>> {
+    delete_log_newrelicotlp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_newrelicotlp`], but also returns the status and headers of the response.
+pub async fn delete_log_newrelicotlp_with_info(configuration: &configuration::Configuration, params: DeleteLogNewrelicotlpParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogNewrelicotlpError>> {
+    crate::apis::execute(configuration, delete_log_newrelicotlp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_newrelicotlp`] sends.
+pub(crate) fn delete_log_newrelicotlp_request(params: DeleteLogNewrelicotlpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_newrelicotlp_name = params.logging_newrelicotlp_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/newrelicotlp/{logging_newrelicotlp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_newrelicotlp_name=crate::apis::urlencode(logging_newrelicotlp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogNewrelicotlpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/newrelicotlp/{logging_newrelicotlp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_newrelicotlp_name=crate::apis::urlencode(logging_newrelicotlp_name));
+    crate::apis::Request::new("delete_log_newrelicotlp", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the details of a New Relic OTLP logging object for a particular service and version.
-pub async fn get_log_newrelicotlp(configuration: &mut configuration::Configuration, params: GetLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<GetLogNewrelicotlpError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_newrelicotlp(configuration: &configuration::Configuration, params: GetLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<GetLogNewrelicotlpError>> {
+    get_log_newrelicotlp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_newrelicotlp`], but also returns the status and headers of the response.
+pub async fn get_log_newrelicotlp_with_info(configuration: &configuration::Configuration, params: GetLogNewrelicotlpParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicotlpResponse>, Error<GetLogNewrelicotlpError>> {
+    crate::apis::execute(configuration, get_log_newrelicotlp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_newrelicotlp`] sends.
+pub(crate) fn get_log_newrelicotlp_request(params: GetLogNewrelicotlpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_newrelicotlp_name = params.logging_newrelicotlp_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/newrelicotlp/{logging_newrelicotlp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_newrelicotlp_name=crate::apis::urlencode(logging_newrelicotlp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogNewrelicotlpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/newrelicotlp/{logging_newrelicotlp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_newrelicotlp_name=crate::apis::urlencode(logging_newrelicotlp_name));
+    crate::apis::Request::new("get_log_newrelicotlp", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the New Relic OTLP logging objects for a particular service and version.
-pub async fn list_log_newrelicotlp(configuration: &mut configuration::Configuration, params: ListLogNewrelicotlpParams) -> Result<Vec<crate::models::LoggingNewrelicotlpResponse>, Error<ListLogNewrelicotlpError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_newrelicotlp(configuration: &configuration::Configuration, params: ListLogNewrelicotlpParams) -> Result<Vec<crate::models::LoggingNewrelicotlpResponse>, Error<ListLogNewrelicotlpError>> {
+    list_log_newrelicotlp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_newrelicotlp`], but also returns the status and headers of the response.
+pub async fn list_log_newrelicotlp_with_info(configuration: &configuration::Configuration, params: ListLogNewrelicotlpParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingNewrelicotlpResponse>>, Error<ListLogNewrelicotlpError>> {
+    crate::apis::execute(configuration, list_log_newrelicotlp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_newrelicotlp`] sends.
+pub(crate) fn list_log_newrelicotlp_request(params: ListLogNewrelicotlpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/newrelicotlp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogNewrelicotlpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/newrelicotlp", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_newrelicotlp", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a New Relic OTLP logging object for a particular service and version.
-pub async fn update_log_newrelicotlp(configuration: &mut configuration::Configuration, params: UpdateLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<UpdateLogNewrelicotlpError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_newrelicotlp(configuration: &configuration::Configuration, params: UpdateLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<UpdateLogNewrelicotlpError>> {
+    update_log_newrelicotlp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_newrelicotlp`], but also returns the status and headers of the response.
+pub async fn update_log_newrelicotlp_with_info(configuration: &configuration::Configuration, params: UpdateLogNewrelicotlpParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicotlpResponse>, Error<UpdateLogNewrelicotlpError>> {
+    crate::apis::execute(configuration, update_log_newrelicotlp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_newrelicotlp`] sends.
+pub(crate) fn update_log_newrelicotlp_request(params: UpdateLogNewrelicotlpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -396,24 +274,10 @@ pub async fn update_log_newrelicotlp(configuration: &mut configuration::Configur
     let region = params.region;
     let url = params.url;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/newrelicotlp/{logging_newrelicotlp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_newrelicotlp_name=crate::apis::urlencode(logging_newrelicotlp_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_newrelicotlp", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/newrelicotlp/{logging_newrelicotlp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_newrelicotlp_name=crate::apis::urlencode(logging_newrelicotlp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -430,7 +294,7 @@ pub async fn update_log_newrelicotlp(configuration: &mut configuration::Configur
         local_var_form_params.insert("format_version", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = region {
         local_var_form_params.insert("region", local_var_param_value.to_string());
@@ -438,32 +302,8 @@ pub async fn update_log_newrelicotlp(configuration: &mut configuration::Configur
     if let Some(local_var_param_value) = url {
         local_var_form_params.insert("url", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogNewrelicotlpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_openstack`]
@@ -39,7 +38,7 @@ pub struct CreateLogOpenstackParams {
     /// The level of gzip encoding when sending logs (default `0`, no compression). Specifying both `compression_codec` and `gzip_level` in the same API request will result in an error.
     pub gzip_level: Option<i32>,
     /// Your OpenStack account access key.
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The name of your OpenStack container.
     pub bucket_name: Option<String>,
     /// The path to upload logs to.
@@ -113,7 +112,7 @@ pub struct UpdateLogOpenstackParams {
     /// The level of gzip encoding when sending logs (default `0`, no compression). Specifying both `compression_codec` and `gzip_level` in the same API request will result in an error.
     pub gzip_level: Option<i32>,
     /// Your OpenStack account access key.
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The name of your OpenStack container.
     pub bucket_name: Option<String>,
     /// The path to upload logs to.
@@ -164,9 +163,17 @@ pub enum UpdateLogOpenstackError {
 
 
 /// Create a openstack for a particular service and version.
-pub async fn create_log_openstack(configuration: &mut configuration::Configuration, params: CreateLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<CreateLogOpenstackError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_openstack(configuration: &configuration::Configuration, params: CreateLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<CreateLogOpenstackError>> {
+    create_log_openstack_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_openstack`], but also returns the status and headers of the response.
+pub async fn create_log_openstack_with_info(configuration: &configuration::Configuration, params: CreateLogOpenstackParams) -> Result<crate::apis::Response<crate::models::LoggingOpenstackResponse>, Error<CreateLogOpenstackError>> {
+    crate::apis::execute(configuration, create_log_openstack_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_openstack`] sends.
+pub(crate) fn create_log_openstack_request(params: CreateLogOpenstackParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -187,24 +194,10 @@ pub async fn create_log_openstack(configuration: &mut configuration::Configurati
     let url = params.url;
     let user = params.user;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/openstack", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_openstack", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/openstack", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -236,7 +229,7 @@ pub async fn create_log_openstack(configuration: &mut configuration::Configurati
         local_var_form_params.insert("gzip_level", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = access_key {
-        local_var_form_params.insert("access_key", local_var_param_value.to_string());
+        local_var_form_params.insert("access_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = bucket_name {
         local_var_form_params.insert("bucket_name", local_var_param_value.to_string());
@@ -253,200 +246,85 @@ pub async fn create_log_openstack(configuration: &mut configuration::Configurati
     if let Some(local_var_param_value) = user {
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogOpenstackError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the openstack for a particular service and version.
-pub async fn delete_log_openstack(configuration: &mut configuration::Configuration, params: DeleteLogOpenstackParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogOpenstackError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_openstack(configuration: &configuration::Configuration, params: DeleteLogOpenstackParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogOpenstackError>> {
+    delete_log_openstack_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_openstack`], but also returns the status and headers of the response.
+pub async fn delete_log_openstack_with_info(configuration: &configuration::Configuration, params: DeleteLogOpenstackParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogOpenstackError>> {
+    crate::apis::execute(configuration, delete_log_openstack_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_openstack`] sends.
+pub(crate) fn delete_log_openstack_request(params: DeleteLogOpenstackParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_openstack_name = params.logging_openstack_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/openstack/{logging_openstack_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_openstack_name=crate::apis::urlencode(logging_openstack_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogOpenstackError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/openstack/{logging_openstack_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_openstack_name=crate::apis::urlencode(logging_openstack_name));
+    crate::apis::Request::new("delete_log_openstack", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the openstack for a particular service and version.
-pub async fn get_log_openstack(configuration: &mut configuration::Configuration, params: GetLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<GetLogOpenstackError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_openstack(configuration: &configuration::Configuration, params: GetLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<GetLogOpenstackError>> {
+    get_log_openstack_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_openstack`], but also returns the status and headers of the response.
+pub async fn get_log_openstack_with_info(configuration: &configuration::Configuration, params: GetLogOpenstackParams) -> Result<crate::apis::Response<crate::models::LoggingOpenstackResponse>, Error<GetLogOpenstackError>> {
+    crate::apis::execute(configuration, get_log_openstack_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_openstack`] sends.
+pub(crate) fn get_log_openstack_request(params: GetLogOpenstackParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_openstack_name = params.logging_openstack_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/openstack/{logging_openstack_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_openstack_name=crate::apis::urlencode(logging_openstack_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogOpenstackError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/openstack/{logging_openstack_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_openstack_name=crate::apis::urlencode(logging_openstack_name));
+    crate::apis::Request::new("get_log_openstack", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the openstacks for a particular service and version.
-pub async fn list_log_openstack(configuration: &mut configuration::Configuration, params: ListLogOpenstackParams) -> Result<Vec<crate::models::LoggingOpenstackResponse>, Error<ListLogOpenstackError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_openstack(configuration: &configuration::Configuration, params: ListLogOpenstackParams) -> Result<Vec<crate::models::LoggingOpenstackResponse>, Error<ListLogOpenstackError>> {
+    list_log_openstack_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_openstack`], but also returns the status and headers of the response.
+pub async fn list_log_openstack_with_info(configuration: &configuration::Configuration, params: ListLogOpenstackParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingOpenstackResponse>>, Error<ListLogOpenstackError>> {
+    crate::apis::execute(configuration, list_log_openstack_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_openstack`] sends.
+pub(crate) fn list_log_openstack_request(params: ListLogOpenstackParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/openstack", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogOpenstackError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/openstack", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_openstack", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the openstack for a particular service and version.
-pub async fn update_log_openstack(configuration: &mut configuration::Configuration, params: UpdateLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<UpdateLogOpenstackError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_openstack(configuration: &configuration::Configuration, params: UpdateLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<UpdateLogOpenstackError>> {
+    update_log_openstack_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_openstack`], but also returns the status and headers of the response.
+pub async fn update_log_openstack_with_info(configuration: &configuration::Configuration, params: UpdateLogOpenstackParams) -> Result<crate::apis::Response<crate::models::LoggingOpenstackResponse>, Error<UpdateLogOpenstackError>> {
+    crate::apis::execute(configuration, update_log_openstack_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_openstack`] sends.
+pub(crate) fn update_log_openstack_request(params: UpdateLogOpenstackParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -468,24 +346,10 @@ pub async fn update_log_openstack(configuration: &mut configuration::Configurati
     let url = params.url;
     let user = params.user;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/openstack/{logging_openstack_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_openstack_name=crate::apis::urlencode(logging_openstack_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_openstack", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/openstack/{logging_openstack_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_openstack_name=crate::apis::urlencode(logging_openstack_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -517,7 +381,7 @@ pub async fn update_log_openstack(configuration: &mut configuration::Configurati
         local_var_form_params.insert("gzip_level", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = access_key {
-        local_var_form_params.insert("access_key", local_var_param_value.to_string());
+        local_var_form_params.insert("access_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = bucket_name {
         local_var_form_params.insert("bucket_name", local_var_param_value.to_string());
@@ -534,32 +398,8 @@ pub async fn update_log_openstack(configuration: &mut configuration::Configurati
     if let Some(local_var_param_value) = user {
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogOpenstackError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
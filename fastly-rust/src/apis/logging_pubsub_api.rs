@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_gcp_pubsub`]
@@ -31,7 +30,7 @@ pub struct CreateLogGcpPubsubParams {
     /// Your Google Cloud Platform service account email address. The `client_email` field in your service account authentication JSON. Not required if `account_name` is specified.
     pub user: Option<String>,
     /// Your Google Cloud Platform account secret key. The `private_key` field in your service account authentication JSON. Not required if `account_name` is specified.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The name of the Google Cloud Platform service account associated with the target log collection service. Not required if `user` and `secret_key` are provided.
     pub account_name: Option<String>,
     /// The Google Cloud Pub/Sub topic to which logs will be published. Required.
@@ -93,7 +92,7 @@ pub struct UpdateLogGcpPubsubParams {
     /// Your Google Cloud Platform service account email address. The `client_email` field in your service account authentication JSON. Not required if `account_name` is specified.
     pub user: Option<String>,
     /// Your Google Cloud Platform account secret key. The `private_key` field in your service account authentication JSON. Not required if `account_name` is specified.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The name of the Google Cloud Platform service account associated with the target log collection service. Not required if `user` and `secret_key` are provided.
     pub account_name: Option<String>,
     /// The Google Cloud Pub/Sub topic to which logs will be published. Required.
@@ -140,9 +139,17 @@ pub enum UpdateLogGcpPubsubError {
 
 
 /// Create a Pub/Sub logging object for a particular service and version.
-pub async fn create_log_gcp_pubsub(configuration: &mut configuration::Configuration, params: CreateLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<CreateLogGcpPubsubError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_gcp_pubsub(configuration: &configuration::Configuration, params: CreateLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<CreateLogGcpPubsubError>> {
+    create_log_gcp_pubsub_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub async fn create_log_gcp_pubsub_with_info(configuration: &configuration::Configuration, params: CreateLogGcpPubsubParams) -> Result<crate::apis::Response<crate::models::LoggingGooglePubsubResponse>, Error<CreateLogGcpPubsubError>> {
+    crate::apis::execute(configuration, create_log_gcp_pubsub_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_gcp_pubsub`] sends.
+pub(crate) fn create_log_gcp_pubsub_request(params: CreateLogGcpPubsubParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -157,24 +164,10 @@ pub async fn create_log_gcp_pubsub(configuration: &mut configuration::Configurat
     let topic = params.topic;
     let project_id = params.project_id;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/pubsub", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_gcp_pubsub", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/pubsub", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -194,7 +187,7 @@ pub async fn create_log_gcp_pubsub(configuration: &mut configuration::Configurat
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = account_name {
         local_var_form_params.insert("account_name", local_var_param_value.to_string());
@@ -205,200 +198,85 @@ pub async fn create_log_gcp_pubsub(configuration: &mut configuration::Configurat
     if let Some(local_var_param_value) = project_id {
         local_var_form_params.insert("project_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogGcpPubsubError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a Pub/Sub logging object for a particular service and version.
-pub async fn delete_log_gcp_pubsub(configuration: &mut configuration::Configuration, params: DeleteLogGcpPubsubParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogGcpPubsubError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_gcp_pubsub(configuration: &configuration::Configuration, params: DeleteLogGcpPubsubParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogGcpPubsubError>> {
+    delete_log_gcp_pubsub_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub async fn delete_log_gcp_pubsub_with_info(configuration: &configuration::Configuration, params: DeleteLogGcpPubsubParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogGcpPubsubError>> {
+    crate::apis::execute(configuration, delete_log_gcp_pubsub_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_gcp_pubsub`] sends.
+pub(crate) fn delete_log_gcp_pubsub_request(params: DeleteLogGcpPubsubParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_google_pubsub_name = params.logging_google_pubsub_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/pubsub/{logging_google_pubsub_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_google_pubsub_name=crate::apis::urlencode(logging_google_pubsub_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogGcpPubsubError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/pubsub/{logging_google_pubsub_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_google_pubsub_name=crate::apis::urlencode(logging_google_pubsub_name));
+    crate::apis::Request::new("delete_log_gcp_pubsub", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the details for a Pub/Sub logging object for a particular service and version.
-pub async fn get_log_gcp_pubsub(configuration: &mut configuration::Configuration, params: GetLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<GetLogGcpPubsubError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_gcp_pubsub(configuration: &configuration::Configuration, params: GetLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<GetLogGcpPubsubError>> {
+    get_log_gcp_pubsub_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub async fn get_log_gcp_pubsub_with_info(configuration: &configuration::Configuration, params: GetLogGcpPubsubParams) -> Result<crate::apis::Response<crate::models::LoggingGooglePubsubResponse>, Error<GetLogGcpPubsubError>> {
+    crate::apis::execute(configuration, get_log_gcp_pubsub_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_gcp_pubsub`] sends.
+pub(crate) fn get_log_gcp_pubsub_request(params: GetLogGcpPubsubParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_google_pubsub_name = params.logging_google_pubsub_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/pubsub/{logging_google_pubsub_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_google_pubsub_name=crate::apis::urlencode(logging_google_pubsub_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogGcpPubsubError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/pubsub/{logging_google_pubsub_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_google_pubsub_name=crate::apis::urlencode(logging_google_pubsub_name));
+    crate::apis::Request::new("get_log_gcp_pubsub", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Pub/Sub logging objects for a particular service and version.
-pub async fn list_log_gcp_pubsub(configuration: &mut configuration::Configuration, params: ListLogGcpPubsubParams) -> Result<Vec<crate::models::LoggingGooglePubsubResponse>, Error<ListLogGcpPubsubError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_gcp_pubsub(configuration: &configuration::Configuration, params: ListLogGcpPubsubParams) -> Result<Vec<crate::models::LoggingGooglePubsubResponse>, Error<ListLogGcpPubsubError>> {
+    list_log_gcp_pubsub_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub async fn list_log_gcp_pubsub_with_info(configuration: &configuration::Configuration, params: ListLogGcpPubsubParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingGooglePubsubResponse>>, Error<ListLogGcpPubsubError>> {
+    crate::apis::execute(configuration, list_log_gcp_pubsub_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_gcp_pubsub`] sends.
+pub(crate) fn list_log_gcp_pubsub_request(params: ListLogGcpPubsubParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/pubsub", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogGcpPubsubError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/pubsub", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_gcp_pubsub", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a Pub/Sub logging object for a particular service and version.
-pub async fn update_log_gcp_pubsub(configuration: &mut configuration::Configuration, params: UpdateLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<UpdateLogGcpPubsubError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_gcp_pubsub(configuration: &configuration::Configuration, params: UpdateLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<UpdateLogGcpPubsubError>> {
+    update_log_gcp_pubsub_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub async fn update_log_gcp_pubsub_with_info(configuration: &configuration::Configuration, params: UpdateLogGcpPubsubParams) -> Result<crate::apis::Response<crate::models::LoggingGooglePubsubResponse>, Error<UpdateLogGcpPubsubError>> {
+    crate::apis::execute(configuration, update_log_gcp_pubsub_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_gcp_pubsub`] sends.
+pub(crate) fn update_log_gcp_pubsub_request(params: UpdateLogGcpPubsubParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -414,24 +292,10 @@ pub async fn update_log_gcp_pubsub(configuration: &mut configuration::Configurat
     let topic = params.topic;
     let project_id = params.project_id;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/pubsub/{logging_google_pubsub_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_google_pubsub_name=crate::apis::urlencode(logging_google_pubsub_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_gcp_pubsub", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/pubsub/{logging_google_pubsub_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_google_pubsub_name=crate::apis::urlencode(logging_google_pubsub_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -451,7 +315,7 @@ pub async fn update_log_gcp_pubsub(configuration: &mut configuration::Configurat
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = account_name {
         local_var_form_params.insert("account_name", local_var_param_value.to_string());
@@ -462,32 +326,8 @@ pub async fn update_log_gcp_pubsub(configuration: &mut configuration::Configurat
     if let Some(local_var_param_value) = project_id {
         local_var_form_params.insert("project_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogGcpPubsubError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
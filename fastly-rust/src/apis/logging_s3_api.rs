@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_aws_s3`]
@@ -39,7 +38,7 @@ pub struct CreateLogAwsS3Params {
     /// The level of gzip encoding when sending logs (default `0`, no compression). Specifying both `compression_codec` and `gzip_level` in the same API request will result in an error.
     pub gzip_level: Option<i32>,
     /// The access key for your S3 account. Not required if `iam_role` is provided.
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The access control list (ACL) specific request header. See the AWS documentation for [Access Control List (ACL) Specific Request Headers](https://docs.aws.amazon.com/AmazonS3/latest/API/mpUploadInitiate.html#initiate-mpu-acl-specific-request-headers) for more information.
     pub acl: Option<String>,
     /// The bucket name for S3 account.
@@ -55,7 +54,7 @@ pub struct CreateLogAwsS3Params {
     /// The S3 redundancy level.
     pub redundancy: Option<String>,
     /// The secret key for your S3 account. Not required if `iam_role` is provided.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// Optional server-side KMS Key Id. Must be set if `server_side_encryption` is set to `aws:kms` or `AES256`.
     pub server_side_encryption_kms_key_id: Option<String>,
     /// Set this to `AES256` or `aws:kms` to enable S3 Server Side Encryption.
@@ -125,7 +124,7 @@ pub struct UpdateLogAwsS3Params {
     /// The level of gzip encoding when sending logs (default `0`, no compression). Specifying both `compression_codec` and `gzip_level` in the same API request will result in an error.
     pub gzip_level: Option<i32>,
     /// The access key for your S3 account. Not required if `iam_role` is provided.
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The access control list (ACL) specific request header. See the AWS documentation for [Access Control List (ACL) Specific Request Headers](https://docs.aws.amazon.com/AmazonS3/latest/API/mpUploadInitiate.html#initiate-mpu-acl-specific-request-headers) for more information.
     pub acl: Option<String>,
     /// The bucket name for S3 account.
@@ -141,7 +140,7 @@ pub struct UpdateLogAwsS3Params {
     /// The S3 redundancy level.
     pub redundancy: Option<String>,
     /// The secret key for your S3 account. Not required if `iam_role` is provided.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// Optional server-side KMS Key Id. Must be set if `server_side_encryption` is set to `aws:kms` or `AES256`.
     pub server_side_encryption_kms_key_id: Option<String>,
     /// Set this to `AES256` or `aws:kms` to enable S3 Server Side Encryption.
@@ -188,9 +187,17 @@ pub enum UpdateLogAwsS3Error {
 
 
 /// Create a S3 for a particular service and version.
-pub async fn create_log_aws_s3(configuration: &mut configuration::Configuration, params: CreateLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<CreateLogAwsS3Error>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_aws_s3(configuration: &configuration::Configuration, params: CreateLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<CreateLogAwsS3Error>> {
+    create_log_aws_s3_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_aws_s3`], but also returns the status and headers of the response.
+pub async fn create_log_aws_s3_with_info(configuration: &configuration::Configuration, params: CreateLogAwsS3Params) -> Result<crate::apis::Response<crate::models::LoggingS3Response>, Error<CreateLogAwsS3Error>> {
+    crate::apis::execute(configuration, create_log_aws_s3_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_aws_s3`] sends.
+pub(crate) fn create_log_aws_s3_request(params: CreateLogAwsS3Params) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -217,24 +224,10 @@ pub async fn create_log_aws_s3(configuration: &mut configuration::Configuration,
     let server_side_encryption = params.server_side_encryption;
     let file_max_bytes = params.file_max_bytes;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/s3", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_aws_s3", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/s3", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -266,7 +259,7 @@ pub async fn create_log_aws_s3(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("gzip_level", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = access_key {
-        local_var_form_params.insert("access_key", local_var_param_value.to_string());
+        local_var_form_params.insert("access_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = acl {
         local_var_form_params.insert("acl", local_var_param_value.to_string());
@@ -290,7 +283,7 @@ pub async fn create_log_aws_s3(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("redundancy", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = server_side_encryption_kms_key_id {
         local_var_form_params.insert("server_side_encryption_kms_key_id", local_var_param_value.to_string());
@@ -301,200 +294,85 @@ pub async fn create_log_aws_s3(configuration: &mut configuration::Configuration,
     if let Some(local_var_param_value) = file_max_bytes {
         local_var_form_params.insert("file_max_bytes", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogAwsS3Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the S3 for a particular service and version.
-pub async fn delete_log_aws_s3(configuration: &mut configuration::Configuration, params: DeleteLogAwsS3Params) -> Result<crate::models::InlineResponse200, Error<DeleteLogAwsS3Error>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_aws_s3(configuration: &configuration::Configuration, params: DeleteLogAwsS3Params) -> Result<crate::models::InlineResponse200, Error<DeleteLogAwsS3Error>> {
+    delete_log_aws_s3_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_aws_s3`], but also returns the status and headers of the response.
+pub async fn delete_log_aws_s3_with_info(configuration: &configuration::Configuration, params: DeleteLogAwsS3Params) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogAwsS3Error>> {
+    crate::apis::execute(configuration, delete_log_aws_s3_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_aws_s3`] sends.
+pub(crate) fn delete_log_aws_s3_request(params: DeleteLogAwsS3Params) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_s3_name = params.logging_s3_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/s3/{logging_s3_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_s3_name=crate::apis::urlencode(logging_s3_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogAwsS3Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/s3/{logging_s3_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_s3_name=crate::apis::urlencode(logging_s3_name));
+    crate::apis::Request::new("delete_log_aws_s3", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the S3 for a particular service and version.
-pub async fn get_log_aws_s3(configuration: &mut configuration::Configuration, params: GetLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<GetLogAwsS3Error>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_aws_s3(configuration: &configuration::Configuration, params: GetLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<GetLogAwsS3Error>> {
+    get_log_aws_s3_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_aws_s3`], but also returns the status and headers of the response.
+pub async fn get_log_aws_s3_with_info(configuration: &configuration::Configuration, params: GetLogAwsS3Params) -> Result<crate::apis::Response<crate::models::LoggingS3Response>, Error<GetLogAwsS3Error>> {
+    crate::apis::execute(configuration, get_log_aws_s3_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_aws_s3`] sends.
+pub(crate) fn get_log_aws_s3_request(params: GetLogAwsS3Params) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_s3_name = params.logging_s3_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/s3/{logging_s3_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_s3_name=crate::apis::urlencode(logging_s3_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogAwsS3Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/s3/{logging_s3_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_s3_name=crate::apis::urlencode(logging_s3_name));
+    crate::apis::Request::new("get_log_aws_s3", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the S3s for a particular service and version.
-pub async fn list_log_aws_s3(configuration: &mut configuration::Configuration, params: ListLogAwsS3Params) -> Result<Vec<crate::models::LoggingS3Response>, Error<ListLogAwsS3Error>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_aws_s3(configuration: &configuration::Configuration, params: ListLogAwsS3Params) -> Result<Vec<crate::models::LoggingS3Response>, Error<ListLogAwsS3Error>> {
+    list_log_aws_s3_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_aws_s3`], but also returns the status and headers of the response.
+pub async fn list_log_aws_s3_with_info(configuration: &configuration::Configuration, params: ListLogAwsS3Params) -> Result<crate::apis::Response<Vec<crate::models::LoggingS3Response>>, Error<ListLogAwsS3Error>> {
+    crate::apis::execute(configuration, list_log_aws_s3_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_aws_s3`] sends.
+pub(crate) fn list_log_aws_s3_request(params: ListLogAwsS3Params) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/s3", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogAwsS3Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/s3", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_aws_s3", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the S3 for a particular service and version.
-pub async fn update_log_aws_s3(configuration: &mut configuration::Configuration, params: UpdateLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<UpdateLogAwsS3Error>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_aws_s3(configuration: &configuration::Configuration, params: UpdateLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<UpdateLogAwsS3Error>> {
+    update_log_aws_s3_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_aws_s3`], but also returns the status and headers of the response.
+pub async fn update_log_aws_s3_with_info(configuration: &configuration::Configuration, params: UpdateLogAwsS3Params) -> Result<crate::apis::Response<crate::models::LoggingS3Response>, Error<UpdateLogAwsS3Error>> {
+    crate::apis::execute(configuration, update_log_aws_s3_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_aws_s3`] sends.
+pub(crate) fn update_log_aws_s3_request(params: UpdateLogAwsS3Params) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -522,24 +400,10 @@ pub async fn update_log_aws_s3(configuration: &mut configuration::Configuration,
     let server_side_encryption = params.server_side_encryption;
     let file_max_bytes = params.file_max_bytes;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/s3/{logging_s3_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_s3_name=crate::apis::urlencode(logging_s3_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_aws_s3", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/s3/{logging_s3_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_s3_name=crate::apis::urlencode(logging_s3_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -571,7 +435,7 @@ pub async fn update_log_aws_s3(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("gzip_level", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = access_key {
-        local_var_form_params.insert("access_key", local_var_param_value.to_string());
+        local_var_form_params.insert("access_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = acl {
         local_var_form_params.insert("acl", local_var_param_value.to_string());
@@ -595,7 +459,7 @@ pub async fn update_log_aws_s3(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("redundancy", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = server_side_encryption_kms_key_id {
         local_var_form_params.insert("server_side_encryption_kms_key_id", local_var_param_value.to_string());
@@ -606,32 +470,8 @@ pub async fn update_log_aws_s3(configuration: &mut configuration::Configuration,
     if let Some(local_var_param_value) = file_max_bytes {
         local_var_form_params.insert("file_max_bytes", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogAwsS3Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
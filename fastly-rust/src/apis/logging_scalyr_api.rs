@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_scalyr`]
@@ -31,7 +30,7 @@ pub struct CreateLogScalyrParams {
     /// The region that log data will be sent to.
     pub region: Option<String>,
     /// The token to use for authentication.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The name of the logfile within Scalyr.
     pub project_id: Option<String>
 }
@@ -89,7 +88,7 @@ pub struct UpdateLogScalyrParams {
     /// The region that log data will be sent to.
     pub region: Option<String>,
     /// The token to use for authentication.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The name of the logfile within Scalyr.
     pub project_id: Option<String>
 }
@@ -132,9 +131,17 @@ pub enum UpdateLogScalyrError {
 
 
 /// Create a Scalyr for a particular service and version.
-pub async fn create_log_scalyr(configuration: &mut configuration::Configuration, params: CreateLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<CreateLogScalyrError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_scalyr(configuration: &configuration::Configuration, params: CreateLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<CreateLogScalyrError>> {
+    create_log_scalyr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_scalyr`], but also returns the status and headers of the response.
+pub async fn create_log_scalyr_with_info(configuration: &configuration::Configuration, params: CreateLogScalyrParams) -> Result<crate::apis::Response<crate::models::LoggingScalyrResponse>, Error<CreateLogScalyrError>> {
+    crate::apis::execute(configuration, create_log_scalyr_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_scalyr`] sends.
+pub(crate) fn create_log_scalyr_request(params: CreateLogScalyrParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -147,24 +154,10 @@ pub async fn create_log_scalyr(configuration: &mut configuration::Configuration,
     let token = params.token;
     let project_id = params.project_id;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/scalyr", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_scalyr", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/scalyr", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -184,205 +177,90 @@ pub async fn create_log_scalyr(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("region", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = project_id {
         local_var_form_params.insert("project_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogScalyrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the Scalyr for a particular service and version.
-pub async fn delete_log_scalyr(configuration: &mut configuration::Configuration, params: DeleteLogScalyrParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogScalyrError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_scalyr(configuration: &configuration::Configuration, params: DeleteLogScalyrParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogScalyrError>> {
+    delete_log_scalyr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_scalyr`], but also returns the status and headers of the response.
+pub async fn delete_log_scalyr_with_info(configuration: &configuration::Configuration, params: DeleteLogScalyrParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogScalyrError>> {
+    crate::apis::execute(configuration, delete_log_scalyr_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_scalyr`] sends.
+pub(crate) fn delete_log_scalyr_request(params: DeleteLogScalyrParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_scalyr_name = params.logging_scalyr_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/scalyr/{logging_scalyr_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_scalyr_name=crate::apis::urlencode(logging_scalyr_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogScalyrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/scalyr/{logging_scalyr_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_scalyr_name=crate::apis::urlencode(logging_scalyr_name));
+    crate::apis::Request::new("delete_log_scalyr", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the Scalyr for a particular service and version.
-pub async fn get_log_scalyr(configuration: &mut configuration::Configuration, params: GetLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<GetLogScalyrError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_scalyr(configuration: &configuration::Configuration, params: GetLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<GetLogScalyrError>> {
+    get_log_scalyr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_scalyr`], but also returns the status and headers of the response.
+pub async fn get_log_scalyr_with_info(configuration: &configuration::Configuration, params: GetLogScalyrParams) -> Result<crate::apis::Response<crate::models::LoggingScalyrResponse>, Error<GetLogScalyrError>> {
+    crate::apis::execute(configuration, get_log_scalyr_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_scalyr`] sends.
+pub(crate) fn get_log_scalyr_request(params: GetLogScalyrParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_scalyr_name = params.logging_scalyr_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/scalyr/{logging_scalyr_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_scalyr_name=crate::apis::urlencode(logging_scalyr_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogScalyrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/scalyr/{logging_scalyr_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_scalyr_name=crate::apis::urlencode(logging_scalyr_name));
+    crate::apis::Request::new("get_log_scalyr", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Scalyrs for a particular service and version.
-pub async fn list_log_scalyr(configuration: &mut configuration::Configuration, params: ListLogScalyrParams) -> Result<Vec<crate::models::LoggingScalyrResponse>, Error<ListLogScalyrError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_scalyr(configuration: &configuration::Configuration, params: ListLogScalyrParams) -> Result<Vec<crate::models::LoggingScalyrResponse>, Error<ListLogScalyrError>> {
+    list_log_scalyr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_scalyr`], but also returns the status and headers of the response.
+pub async fn list_log_scalyr_with_info(configuration: &configuration::Configuration, params: ListLogScalyrParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingScalyrResponse>>, Error<ListLogScalyrError>> {
+    crate::apis::execute(configuration, list_log_scalyr_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_scalyr`] sends.
+pub(crate) fn list_log_scalyr_request(params: ListLogScalyrParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/scalyr", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogScalyrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/scalyr", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_scalyr", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the Scalyr for a particular service and version.
-pub async fn update_log_scalyr(configuration: &mut configuration::Configuration, params: UpdateLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<UpdateLogScalyrError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_scalyr(configuration: &configuration::Configuration, params: UpdateLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<UpdateLogScalyrError>> {
+    update_log_scalyr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_scalyr`], but also returns the status and headers of the response.
+pub async fn update_log_scalyr_with_info(configuration: &configuration::Configuration, params: UpdateLogScalyrParams) -> Result<crate::apis::Response<crate::models::LoggingScalyrResponse>, Error<UpdateLogScalyrError>> {
+    crate::apis::execute(configuration, update_log_scalyr_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_scalyr`] sends.
+pub(crate) fn update_log_scalyr_request(params: UpdateLogScalyrParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -396,24 +274,10 @@ pub async fn update_log_scalyr(configuration: &mut configuration::Configuration,
     let token = params.token;
     let project_id = params.project_id;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/scalyr/{logging_scalyr_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_scalyr_name=crate::apis::urlencode(logging_scalyr_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_scalyr", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/scalyr/{logging_scalyr_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_scalyr_name=crate::apis::urlencode(logging_scalyr_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -433,37 +297,13 @@ pub async fn update_log_scalyr(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("region", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = project_id {
         local_var_form_params.insert("project_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogScalyrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_sftp`]
@@ -43,13 +42,13 @@ pub struct CreateLogSftpParams {
     /// The port number.
     pub port: Option<i32>,
     /// The password for the server. If both `password` and `secret_key` are passed, `secret_key` will be used in preference.
-    pub password: Option<String>,
+    pub password: Option<crate::apis::redact::Secret>,
     /// The path to upload logs to.
     pub path: Option<String>,
     /// A PGP public key that Fastly will use to encrypt your log files before writing them to disk.
     pub public_key: Option<String>,
     /// The SSH private key for the server. If both `password` and `secret_key` are passed, `secret_key` will be used in preference.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// A list of host keys for all hosts we can connect to over SFTP.
     pub ssh_known_hosts: Option<String>,
     /// The username for the server.
@@ -121,13 +120,13 @@ pub struct UpdateLogSftpParams {
     /// The port number.
     pub port: Option<i32>,
     /// The password for the server. If both `password` and `secret_key` are passed, `secret_key` will be used in preference.
-    pub password: Option<String>,
+    pub password: Option<crate::apis::redact::Secret>,
     /// The path to upload logs to.
     pub path: Option<String>,
     /// A PGP public key that Fastly will use to encrypt your log files before writing them to disk.
     pub public_key: Option<String>,
     /// The SSH private key for the server. If both `password` and `secret_key` are passed, `secret_key` will be used in preference.
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// A list of host keys for all hosts we can connect to over SFTP.
     pub ssh_known_hosts: Option<String>,
     /// The username for the server.
@@ -172,9 +171,17 @@ pub enum UpdateLogSftpError {
 
 
 /// Create a SFTP for a particular service and version.
-pub async fn create_log_sftp(configuration: &mut configuration::Configuration, params: CreateLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<CreateLogSftpError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_sftp(configuration: &configuration::Configuration, params: CreateLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<CreateLogSftpError>> {
+    create_log_sftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_sftp`], but also returns the status and headers of the response.
+pub async fn create_log_sftp_with_info(configuration: &configuration::Configuration, params: CreateLogSftpParams) -> Result<crate::apis::Response<crate::models::LoggingSftpResponse>, Error<CreateLogSftpError>> {
+    crate::apis::execute(configuration, create_log_sftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_sftp`] sends.
+pub(crate) fn create_log_sftp_request(params: CreateLogSftpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -197,24 +204,10 @@ pub async fn create_log_sftp(configuration: &mut configuration::Configuration, p
     let ssh_known_hosts = params.ssh_known_hosts;
     let user = params.user;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sftp", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_sftp", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sftp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -252,7 +245,7 @@ pub async fn create_log_sftp(configuration: &mut configuration::Configuration, p
         local_var_form_params.insert("port", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = password {
-        local_var_form_params.insert("password", local_var_param_value.to_string());
+        local_var_form_params.insert("password", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = path {
         local_var_form_params.insert("path", local_var_param_value.to_string());
@@ -261,7 +254,7 @@ pub async fn create_log_sftp(configuration: &mut configuration::Configuration, p
         local_var_form_params.insert("public_key", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = ssh_known_hosts {
         local_var_form_params.insert("ssh_known_hosts", local_var_param_value.to_string());
@@ -269,200 +262,85 @@ pub async fn create_log_sftp(configuration: &mut configuration::Configuration, p
     if let Some(local_var_param_value) = user {
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogSftpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the SFTP for a particular service and version.
-pub async fn delete_log_sftp(configuration: &mut configuration::Configuration, params: DeleteLogSftpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSftpError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_sftp(configuration: &configuration::Configuration, params: DeleteLogSftpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSftpError>> {
+    delete_log_sftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_sftp`], but also returns the status and headers of the response.
+pub async fn delete_log_sftp_with_info(configuration: &configuration::Configuration, params: DeleteLogSftpParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogSftpError>> {
+    crate::apis::execute(configuration, delete_log_sftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_sftp`] sends.
+pub(crate) fn delete_log_sftp_request(params: DeleteLogSftpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_sftp_name = params.logging_sftp_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sftp/{logging_sftp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sftp_name=crate::apis::urlencode(logging_sftp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogSftpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sftp/{logging_sftp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sftp_name=crate::apis::urlencode(logging_sftp_name));
+    crate::apis::Request::new("delete_log_sftp", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the SFTP for a particular service and version.
-pub async fn get_log_sftp(configuration: &mut configuration::Configuration, params: GetLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<GetLogSftpError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_sftp(configuration: &configuration::Configuration, params: GetLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<GetLogSftpError>> {
+    get_log_sftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_sftp`], but also returns the status and headers of the response.
+pub async fn get_log_sftp_with_info(configuration: &configuration::Configuration, params: GetLogSftpParams) -> Result<crate::apis::Response<crate::models::LoggingSftpResponse>, Error<GetLogSftpError>> {
+    crate::apis::execute(configuration, get_log_sftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_sftp`] sends.
+pub(crate) fn get_log_sftp_request(params: GetLogSftpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_sftp_name = params.logging_sftp_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sftp/{logging_sftp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sftp_name=crate::apis::urlencode(logging_sftp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogSftpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sftp/{logging_sftp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sftp_name=crate::apis::urlencode(logging_sftp_name));
+    crate::apis::Request::new("get_log_sftp", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the SFTPs for a particular service and version.
-pub async fn list_log_sftp(configuration: &mut configuration::Configuration, params: ListLogSftpParams) -> Result<Vec<crate::models::LoggingSftpResponse>, Error<ListLogSftpError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_sftp(configuration: &configuration::Configuration, params: ListLogSftpParams) -> Result<Vec<crate::models::LoggingSftpResponse>, Error<ListLogSftpError>> {
+    list_log_sftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_sftp`], but also returns the status and headers of the response.
+pub async fn list_log_sftp_with_info(configuration: &configuration::Configuration, params: ListLogSftpParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingSftpResponse>>, Error<ListLogSftpError>> {
+    crate::apis::execute(configuration, list_log_sftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_sftp`] sends.
+pub(crate) fn list_log_sftp_request(params: ListLogSftpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sftp", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogSftpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sftp", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_sftp", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the SFTP for a particular service and version.
-pub async fn update_log_sftp(configuration: &mut configuration::Configuration, params: UpdateLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<UpdateLogSftpError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_sftp(configuration: &configuration::Configuration, params: UpdateLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<UpdateLogSftpError>> {
+    update_log_sftp_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_sftp`], but also returns the status and headers of the response.
+pub async fn update_log_sftp_with_info(configuration: &configuration::Configuration, params: UpdateLogSftpParams) -> Result<crate::apis::Response<crate::models::LoggingSftpResponse>, Error<UpdateLogSftpError>> {
+    crate::apis::execute(configuration, update_log_sftp_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_sftp`] sends.
+pub(crate) fn update_log_sftp_request(params: UpdateLogSftpParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -486,24 +364,10 @@ pub async fn update_log_sftp(configuration: &mut configuration::Configuration, p
     let ssh_known_hosts = params.ssh_known_hosts;
     let user = params.user;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sftp/{logging_sftp_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sftp_name=crate::apis::urlencode(logging_sftp_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_sftp", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sftp/{logging_sftp_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sftp_name=crate::apis::urlencode(logging_sftp_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -541,7 +405,7 @@ pub async fn update_log_sftp(configuration: &mut configuration::Configuration, p
         local_var_form_params.insert("port", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = password {
-        local_var_form_params.insert("password", local_var_param_value.to_string());
+        local_var_form_params.insert("password", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = path {
         local_var_form_params.insert("path", local_var_param_value.to_string());
@@ -550,7 +414,7 @@ pub async fn update_log_sftp(configuration: &mut configuration::Configuration, p
         local_var_form_params.insert("public_key", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = secret_key {
-        local_var_form_params.insert("secret_key", local_var_param_value.to_string());
+        local_var_form_params.insert("secret_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = ssh_known_hosts {
         local_var_form_params.insert("ssh_known_hosts", local_var_param_value.to_string());
@@ -558,32 +422,8 @@ pub async fn update_log_sftp(configuration: &mut configuration::Configuration, p
     if let Some(local_var_param_value) = user {
         local_var_form_params.insert("user", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogSftpError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
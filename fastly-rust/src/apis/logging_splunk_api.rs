@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_splunk`]
@@ -33,7 +32,7 @@ pub struct CreateLogSplunkParams {
     /// The client certificate used to make authenticated requests. Must be in PEM format.
     pub tls_client_cert: Option<String>,
     /// The client private key used to make authenticated requests. Must be in PEM format.
-    pub tls_client_key: Option<String>,
+    pub tls_client_key: Option<crate::apis::redact::Secret>,
     /// The hostname to verify the server's certificate. This should be one of the Subject Alternative Name (SAN) fields for the certificate. Common Names (CN) are not supported.
     pub tls_hostname: Option<String>,
     /// The maximum number of logs sent in one request. Defaults `0` for unbounded.
@@ -43,7 +42,7 @@ pub struct CreateLogSplunkParams {
     /// The URL to post logs to.
     pub url: Option<String>,
     /// A Splunk token for use in posting logs over HTTP to your collector.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     pub use_tls: Option<crate::models::LoggingUseTlsString>
 }
 
@@ -102,7 +101,7 @@ pub struct UpdateLogSplunkParams {
     /// The client certificate used to make authenticated requests. Must be in PEM format.
     pub tls_client_cert: Option<String>,
     /// The client private key used to make authenticated requests. Must be in PEM format.
-    pub tls_client_key: Option<String>,
+    pub tls_client_key: Option<crate::apis::redact::Secret>,
     /// The hostname to verify the server's certificate. This should be one of the Subject Alternative Name (SAN) fields for the certificate. Common Names (CN) are not supported.
     pub tls_hostname: Option<String>,
     /// The maximum number of logs sent in one request. Defaults `0` for unbounded.
@@ -112,7 +111,7 @@ pub struct UpdateLogSplunkParams {
     /// The URL to post logs to.
     pub url: Option<String>,
     /// A Splunk token for use in posting logs over HTTP to your collector.
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     pub use_tls: Option<crate::models::LoggingUseTlsString>
 }
 
@@ -154,9 +153,17 @@ pub enum UpdateLogSplunkError {
 
 
 /// Create a Splunk logging object for a particular service and version.
-pub async fn create_log_splunk(configuration: &mut configuration::Configuration, params: CreateLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<CreateLogSplunkError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_splunk(configuration: &configuration::Configuration, params: CreateLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<CreateLogSplunkError>> {
+    create_log_splunk_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_splunk`], but also returns the status and headers of the response.
+pub async fn create_log_splunk_with_info(configuration: &configuration::Configuration, params: CreateLogSplunkParams) -> Result<crate::apis::Response<crate::models::LoggingSplunkResponse>, Error<CreateLogSplunkError>> {
+    crate::apis::execute(configuration, create_log_splunk_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_splunk`] sends.
+pub(crate) fn create_log_splunk_request(params: CreateLogSplunkParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -175,24 +182,10 @@ pub async fn create_log_splunk(configuration: &mut configuration::Configuration,
     let token = params.token;
     let use_tls = params.use_tls;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/splunk", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_splunk", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/splunk", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -215,7 +208,7 @@ pub async fn create_log_splunk(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("tls_client_cert", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = tls_client_key {
-        local_var_form_params.insert("tls_client_key", local_var_param_value.to_string());
+        local_var_form_params.insert("tls_client_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = tls_hostname {
         local_var_form_params.insert("tls_hostname", local_var_param_value.to_string());
@@ -230,205 +223,90 @@ pub async fn create_log_splunk(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("url", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = use_tls {
         local_var_form_params.insert("use_tls", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogSplunkError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the Splunk logging object for a particular service and version.
-pub async fn delete_log_splunk(configuration: &mut configuration::Configuration, params: DeleteLogSplunkParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSplunkError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_splunk(configuration: &configuration::Configuration, params: DeleteLogSplunkParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSplunkError>> {
+    delete_log_splunk_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_splunk`], but also returns the status and headers of the response.
+pub async fn delete_log_splunk_with_info(configuration: &configuration::Configuration, params: DeleteLogSplunkParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogSplunkError>> {
+    crate::apis::execute(configuration, delete_log_splunk_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_splunk`] sends.
+pub(crate) fn delete_log_splunk_request(params: DeleteLogSplunkParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_splunk_name = params.logging_splunk_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/splunk/{logging_splunk_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_splunk_name=crate::apis::urlencode(logging_splunk_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogSplunkError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/splunk/{logging_splunk_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_splunk_name=crate::apis::urlencode(logging_splunk_name));
+    crate::apis::Request::new("delete_log_splunk", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the details for a Splunk logging object for a particular service and version.
-pub async fn get_log_splunk(configuration: &mut configuration::Configuration, params: GetLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<GetLogSplunkError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_splunk(configuration: &configuration::Configuration, params: GetLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<GetLogSplunkError>> {
+    get_log_splunk_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_splunk`], but also returns the status and headers of the response.
+pub async fn get_log_splunk_with_info(configuration: &configuration::Configuration, params: GetLogSplunkParams) -> Result<crate::apis::Response<crate::models::LoggingSplunkResponse>, Error<GetLogSplunkError>> {
+    crate::apis::execute(configuration, get_log_splunk_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_splunk`] sends.
+pub(crate) fn get_log_splunk_request(params: GetLogSplunkParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_splunk_name = params.logging_splunk_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/splunk/{logging_splunk_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_splunk_name=crate::apis::urlencode(logging_splunk_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogSplunkError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/splunk/{logging_splunk_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_splunk_name=crate::apis::urlencode(logging_splunk_name));
+    crate::apis::Request::new("get_log_splunk", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Splunk logging objects for a particular service and version.
-pub async fn list_log_splunk(configuration: &mut configuration::Configuration, params: ListLogSplunkParams) -> Result<Vec<crate::models::LoggingSplunkResponse>, Error<ListLogSplunkError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_splunk(configuration: &configuration::Configuration, params: ListLogSplunkParams) -> Result<Vec<crate::models::LoggingSplunkResponse>, Error<ListLogSplunkError>> {
+    list_log_splunk_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_splunk`], but also returns the status and headers of the response.
+pub async fn list_log_splunk_with_info(configuration: &configuration::Configuration, params: ListLogSplunkParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingSplunkResponse>>, Error<ListLogSplunkError>> {
+    crate::apis::execute(configuration, list_log_splunk_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_splunk`] sends.
+pub(crate) fn list_log_splunk_request(params: ListLogSplunkParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/splunk", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogSplunkError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/splunk", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_splunk", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the Splunk logging object for a particular service and version.
-pub async fn update_log_splunk(configuration: &mut configuration::Configuration, params: UpdateLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<UpdateLogSplunkError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_splunk(configuration: &configuration::Configuration, params: UpdateLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<UpdateLogSplunkError>> {
+    update_log_splunk_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_splunk`], but also returns the status and headers of the response.
+pub async fn update_log_splunk_with_info(configuration: &configuration::Configuration, params: UpdateLogSplunkParams) -> Result<crate::apis::Response<crate::models::LoggingSplunkResponse>, Error<UpdateLogSplunkError>> {
+    crate::apis::execute(configuration, update_log_splunk_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_splunk`] sends.
+pub(crate) fn update_log_splunk_request(params: UpdateLogSplunkParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -448,24 +326,10 @@ pub async fn update_log_splunk(configuration: &mut configuration::Configuration,
     let token = params.token;
     let use_tls = params.use_tls;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/splunk/{logging_splunk_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_splunk_name=crate::apis::urlencode(logging_splunk_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_splunk", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/splunk/{logging_splunk_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_splunk_name=crate::apis::urlencode(logging_splunk_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -488,7 +352,7 @@ pub async fn update_log_splunk(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("tls_client_cert", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = tls_client_key {
-        local_var_form_params.insert("tls_client_key", local_var_param_value.to_string());
+        local_var_form_params.insert("tls_client_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = tls_hostname {
         local_var_form_params.insert("tls_hostname", local_var_param_value.to_string());
@@ -503,37 +367,13 @@ pub async fn update_log_splunk(configuration: &mut configuration::Configuration,
         local_var_form_params.insert("url", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = token {
-        local_var_form_params.insert("token", local_var_param_value.to_string());
+        local_var_form_params.insert("token", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = use_tls {
         local_var_form_params.insert("use_tls", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogSplunkError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
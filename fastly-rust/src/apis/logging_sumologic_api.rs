@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_log_sumologic`]
@@ -126,9 +125,17 @@ pub enum UpdateLogSumologicError {
 
 
 /// Create a Sumologic for a particular service and version.
-pub async fn create_log_sumologic(configuration: &mut configuration::Configuration, params: CreateLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<CreateLogSumologicError>> {
-    let local_var_configuration = configuration;
+pub async fn create_log_sumologic(configuration: &configuration::Configuration, params: CreateLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<CreateLogSumologicError>> {
+    create_log_sumologic_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_log_sumologic`], but also returns the status and headers of the response.
+pub async fn create_log_sumologic_with_info(configuration: &configuration::Configuration, params: CreateLogSumologicParams) -> Result<crate::apis::Response<crate::models::LoggingSumologicResponse>, Error<CreateLogSumologicError>> {
+    crate::apis::execute(configuration, create_log_sumologic_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_log_sumologic`] sends.
+pub(crate) fn create_log_sumologic_request(params: CreateLogSumologicParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -140,24 +147,10 @@ pub async fn create_log_sumologic(configuration: &mut configuration::Configurati
     let message_type = params.message_type;
     let url = params.url;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sumologic", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_log_sumologic", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sumologic", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -179,200 +172,85 @@ pub async fn create_log_sumologic(configuration: &mut configuration::Configurati
     if let Some(local_var_param_value) = url {
         local_var_form_params.insert("url", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateLogSumologicError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the Sumologic for a particular service and version.
-pub async fn delete_log_sumologic(configuration: &mut configuration::Configuration, params: DeleteLogSumologicParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSumologicError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_log_sumologic(configuration: &configuration::Configuration, params: DeleteLogSumologicParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSumologicError>> {
+    delete_log_sumologic_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_log_sumologic`], but also returns the status and headers of the response.
+pub async fn delete_log_sumologic_with_info(configuration: &configuration::Configuration, params: DeleteLogSumologicParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogSumologicError>> {
+    crate::apis::execute(configuration, delete_log_sumologic_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_log_sumologic`] sends.
+pub(crate) fn delete_log_sumologic_request(params: DeleteLogSumologicParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_sumologic_name = params.logging_sumologic_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sumologic/{logging_sumologic_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sumologic_name=crate::apis::urlencode(logging_sumologic_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteLogSumologicError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sumologic/{logging_sumologic_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sumologic_name=crate::apis::urlencode(logging_sumologic_name));
+    crate::apis::Request::new("delete_log_sumologic", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the Sumologic for a particular service and version.
-pub async fn get_log_sumologic(configuration: &mut configuration::Configuration, params: GetLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<GetLogSumologicError>> {
-    let local_var_configuration = configuration;
+pub async fn get_log_sumologic(configuration: &configuration::Configuration, params: GetLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<GetLogSumologicError>> {
+    get_log_sumologic_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_log_sumologic`], but also returns the status and headers of the response.
+pub async fn get_log_sumologic_with_info(configuration: &configuration::Configuration, params: GetLogSumologicParams) -> Result<crate::apis::Response<crate::models::LoggingSumologicResponse>, Error<GetLogSumologicError>> {
+    crate::apis::execute(configuration, get_log_sumologic_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_log_sumologic`] sends.
+pub(crate) fn get_log_sumologic_request(params: GetLogSumologicParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let logging_sumologic_name = params.logging_sumologic_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sumologic/{logging_sumologic_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sumologic_name=crate::apis::urlencode(logging_sumologic_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLogSumologicError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sumologic/{logging_sumologic_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sumologic_name=crate::apis::urlencode(logging_sumologic_name));
+    crate::apis::Request::new("get_log_sumologic", reqwest::Method::GET, local_var_path)
 }
 
 /// List all of the Sumologics for a particular service and version.
-pub async fn list_log_sumologic(configuration: &mut configuration::Configuration, params: ListLogSumologicParams) -> Result<Vec<crate::models::LoggingSumologicResponse>, Error<ListLogSumologicError>> {
-    let local_var_configuration = configuration;
+pub async fn list_log_sumologic(configuration: &configuration::Configuration, params: ListLogSumologicParams) -> Result<Vec<crate::models::LoggingSumologicResponse>, Error<ListLogSumologicError>> {
+    list_log_sumologic_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_log_sumologic`], but also returns the status and headers of the response.
+pub async fn list_log_sumologic_with_info(configuration: &configuration::Configuration, params: ListLogSumologicParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingSumologicResponse>>, Error<ListLogSumologicError>> {
+    crate::apis::execute(configuration, list_log_sumologic_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_log_sumologic`] sends.
+pub(crate) fn list_log_sumologic_request(params: ListLogSumologicParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sumologic", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListLogSumologicError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sumologic", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_log_sumologic", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the Sumologic for a particular service and version.
-pub async fn update_log_sumologic(configuration: &mut configuration::Configuration, params: UpdateLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<UpdateLogSumologicError>> {
-    let local_var_configuration = configuration;
+pub async fn update_log_sumologic(configuration: &configuration::Configuration, params: UpdateLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<UpdateLogSumologicError>> {
+    update_log_sumologic_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_log_sumologic`], but also returns the status and headers of the response.
+pub async fn update_log_sumologic_with_info(configuration: &configuration::Configuration, params: UpdateLogSumologicParams) -> Result<crate::apis::Response<crate::models::LoggingSumologicResponse>, Error<UpdateLogSumologicError>> {
+    crate::apis::execute(configuration, update_log_sumologic_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_log_sumologic`] sends.
+pub(crate) fn update_log_sumologic_request(params: UpdateLogSumologicParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -385,24 +263,10 @@ pub async fn update_log_sumologic(configuration: &mut configuration::Configurati
     let message_type = params.message_type;
     let url = params.url;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/logging/sumologic/{logging_sumologic_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sumologic_name=crate::apis::urlencode(logging_sumologic_name));
+    let mut local_var_req = crate::apis::Request::new("update_log_sumologic", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/logging/sumologic/{logging_sumologic_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, logging_sumologic_name=crate::apis::urlencode(logging_sumologic_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -424,32 +288,8 @@ pub async fn update_log_sumologic(configuration: &mut configuration::Configurati
     if let Some(local_var_param_value) = url {
         local_var_form_params.insert("url", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateLogSumologicError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
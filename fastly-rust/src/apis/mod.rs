@@ -1,19 +1,190 @@
 use std::error;
 use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+/// The future returned by methods of object-safe traits, such as
+/// [`token_source::TokenSource::token`].
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
 #[derive(Debug, Clone)]
 pub struct ResponseContent<T> {
     pub status: reqwest::StatusCode,
     pub content: String,
     pub entity: Option<T>,
+    /// The error as reported by the API, parsed from `content`.
+    pub api_error: ApiError,
+}
+
+/// A successful response: the deserialized entity together with the status
+/// and headers it arrived with.
+///
+/// Returned by the `*_with_info` variant of every endpoint function, for
+/// callers that need `Fastly-Request-ID` for a support ticket, the `ETag`, or
+/// the rate-limit budget of this particular call.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub entity: T,
+}
+
+impl<T> Response<T> {
+    pub fn into_entity(self) -> T {
+        self.entity
+    }
+
+    /// The value of header `name`, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// The ID Fastly assigned to the request, for support tickets.
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("Fastly-Request-ID")
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.header(reqwest::header::ETAG.as_str())
+    }
+
+    pub fn link(&self) -> Option<&str> {
+        self.header(reqwest::header::LINK.as_str())
+    }
+
+    /// Requests left in the rate-limit window, from `Fastly-RateLimit-Remaining`.
+    /// Only mutating requests carry the rate-limit headers.
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        self.header("Fastly-RateLimit-Remaining").and_then(|v| v.trim().parse().ok())
+    }
+
+    /// When the rate-limit window ends, from `Fastly-RateLimit-Reset`.
+    pub fn rate_limit_reset(&self) -> Option<std::time::SystemTime> {
+        self.header("Fastly-RateLimit-Reset")
+            .and_then(|v| v.trim().parse().ok())
+            .map(|secs| std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+    }
+}
+
+/// An error reported by the Fastly API.
+///
+/// Parsed from both the classic `{"msg", "detail", "errors"}` body and the
+/// JSON:API `{"errors": [{"title", "detail", "source"}]}` body. Fields the
+/// response did not carry are left empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    pub status: reqwest::StatusCode,
+    /// The `Fastly-Request-ID` response header, useful for support tickets.
+    pub request_id: Option<String>,
+    pub message: Option<String>,
+    pub detail: Option<String>,
+    pub errors: Vec<ApiErrorDetail>,
 }
 
+/// A single entry of an error response's `errors` list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiErrorDetail {
+    pub title: Option<String>,
+    pub detail: Option<String>,
+    pub code: Option<String>,
+    /// The offending field or parameter, when the API names one.
+    pub field: Option<String>,
+}
+
+impl ApiError {
+    pub fn from_response(status: reqwest::StatusCode, headers: &reqwest::header::HeaderMap, content: &str) -> ApiError {
+        let request_id = ["Fastly-Request-ID", "X-Request-ID"]
+            .iter()
+            .find_map(|name| headers.get(*name))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned);
+        let body: serde_json::Value = serde_json::from_str(content).unwrap_or_default();
+
+        let errors: Vec<ApiErrorDetail> = match body.get("errors") {
+            Some(serde_json::Value::Array(items)) => items.iter().map(ApiErrorDetail::from_value).collect(),
+            Some(serde_json::Value::Object(fields)) => fields
+                .iter()
+                .map(|(field, v)| ApiErrorDetail {
+                    field: Some(field.clone()),
+                    ..ApiErrorDetail::from_value(v)
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let message = string_field(&body, &["msg", "message", "title", "error"])
+            .or_else(|| errors.first().and_then(|e| e.title.clone()));
+        let detail = string_field(&body, &["detail", "error_description"])
+            .or_else(|| errors.first().and_then(|e| e.detail.clone()));
+
+        ApiError { status, request_id, message, detail, errors }
+    }
+}
+
+impl ApiErrorDetail {
+    fn from_value(value: &serde_json::Value) -> ApiErrorDetail {
+        if let serde_json::Value::String(s) = value {
+            return ApiErrorDetail { detail: Some(s.clone()), ..Default::default() };
+        }
+        let field = value
+            .get("source")
+            .and_then(|source| string_field(source, &["pointer", "parameter"]))
+            .or_else(|| string_field(value, &["field", "key"]));
+        ApiErrorDetail {
+            title: string_field(value, &["title", "msg", "message"]),
+            detail: string_field(value, &["detail"]),
+            code: string_field(value, &["code"]),
+            field,
+        }
+    }
+}
+
+/// The first of `names` that holds a non-empty string (or number) in `value`.
+fn string_field(value: &serde_json::Value, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| match value.get(*name)? {
+        serde_json::Value::String(s) if !s.is_empty() => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status code {}", self.status)?;
+        match (&self.message, &self.detail) {
+            (Some(message), Some(detail)) if message != detail => write!(f, ": {}: {}", message, detail)?,
+            (Some(text), _) | (None, Some(text)) => write!(f, ": {}", text)?,
+            (None, None) => {}
+        }
+        for error in self.errors.iter().filter(|e| e.field.is_some()) {
+            let text = error.detail.as_ref().or(error.title.as_ref());
+            match (&error.field, text) {
+                (Some(field), Some(text)) => write!(f, "; {}: {}", field, text)?,
+                (Some(field), None) => write!(f, "; {}", field)?,
+                _ => {}
+            }
+        }
+        if let Some(ref request_id) = self.request_id {
+            write!(f, " (request ID {})", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for ApiError {}
+
+/// The error of every endpoint function.
+///
+/// It is big enough to trip `clippy::result_large_err`. The hand-written
+/// functions that return it allow that lint rather than box it, since the
+/// generated endpoints they share it with return it unboxed anyway.
 #[derive(Debug)]
 pub enum Error<T> {
     Reqwest(reqwest::Error),
     Serde(serde_json::Error),
     Io(std::io::Error),
     ResponseError(ResponseContent<T>),
+    /// No credential could be obtained for the request.
+    Auth(AuthError),
 }
 
 impl <T> fmt::Display for Error<T> {
@@ -22,7 +193,8 @@ impl <T> fmt::Display for Error<T> {
             Error::Reqwest(e) => ("reqwest", e.to_string()),
             Error::Serde(e) => ("serde", e.to_string()),
             Error::Io(e) => ("IO", e.to_string()),
-            Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
+            Error::ResponseError(e) => ("response", e.api_error.to_string()),
+            Error::Auth(e) => ("authentication", e.to_string()),
         };
         write!(f, "error in {}: {}", module, e)
     }
@@ -35,10 +207,21 @@ impl <T: fmt::Debug> error::Error for Error<T> {
             Error::Serde(e) => e,
             Error::Io(e) => e,
             Error::ResponseError(_) => return None,
+            Error::Auth(e) => e,
         })
     }
 }
 
+impl <T> Error<T> {
+    /// The error reported by the API, if the request got as far as a response.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Error::ResponseError(e) => Some(&e.api_error),
+            _ => None,
+        }
+    }
+}
+
 impl <T> From<reqwest::Error> for Error<T> {
     fn from(e: reqwest::Error) -> Self {
         Error::Reqwest(e)
@@ -57,130 +240,763 @@ impl <T> From<std::io::Error> for Error<T> {
     }
 }
 
+impl <T> From<AuthError> for Error<T> {
+    fn from(e: AuthError) -> Self {
+        Error::Auth(e)
+    }
+}
+
+/// Failure to obtain a credential, e.g. from a [`token_source::TokenSource`].
+#[derive(Debug)]
+pub struct AuthError {
+    message: String,
+    source: Option<Box<dyn error::Error + Send + Sync>>,
+}
+
+impl AuthError {
+    pub fn new(message: impl Into<String>) -> AuthError {
+        AuthError { message: message.into(), source: None }
+    }
+
+    pub fn with_source(message: impl Into<String>, source: impl Into<Box<dyn error::Error + Send + Sync>>) -> AuthError {
+        AuthError { message: message.into(), source: Some(source.into()) }
+    }
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source {
+            Some(ref source) => write!(f, "{}: {}", self.message, source),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl error::Error for AuthError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.source.as_ref().map(|e| e.as_ref() as &(dyn error::Error + 'static))
+    }
+}
+
 pub fn urlencode<T: AsRef<str>>(s: T) -> String {
     ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
 }
 
+/// Ordered `application/x-www-form-urlencoded` request body.
+///
+/// Scalar parameters are sent as `key=value`. Array parameters are sent as one
+/// `key[]=value` pair per element, which is the only array encoding the Fastly
+/// API accepts in form bodies.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormParams(Vec<(String, String)>);
+
+impl FormParams {
+    pub fn new() -> FormParams {
+        FormParams::default()
+    }
+
+    /// Append a scalar parameter.
+    pub fn insert(&mut self, key: &str, value: String) {
+        self.0.push((key.to_owned(), value));
+    }
+
+    /// Append an array parameter as repeated `key[]` entries.
+    pub fn insert_array<I, V>(&mut self, key: &str, values: I)
+    where
+        I: IntoIterator<Item = V>,
+        V: ToString,
+    {
+        let key = format!("{}[]", key);
+        self.0.extend(values.into_iter().map(|v| (key.clone(), v.to_string())));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// The encoded body, as it is sent on the wire.
+    pub fn encode(&self) -> String {
+        ::url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.iter())
+            .finish()
+    }
+}
+
+impl serde::Serialize for FormParams {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Ordered `multipart/form-data` request body.
+#[derive(Clone, Default)]
+pub struct MultipartParams(Vec<MultipartPart>);
+
+#[derive(Clone)]
+struct MultipartPart {
+    name: String,
+    file_name: Option<String>,
+    content: Vec<u8>,
+}
+
+impl MultipartParams {
+    pub fn new() -> MultipartParams {
+        MultipartParams::default()
+    }
+
+    /// Append a text field.
+    pub fn text(&mut self, name: &str, value: String) {
+        self.0.push(MultipartPart { name: name.to_owned(), file_name: None, content: value.into_bytes() });
+    }
+
+    /// Append a file field.
+    pub fn file(&mut self, name: &str, file_name: &str, content: Vec<u8>) {
+        self.0.push(MultipartPart { name: name.to_owned(), file_name: Some(file_name.to_owned()), content });
+    }
+
+    pub(crate) fn to_form(&self) -> reqwest::multipart::Form {
+        self.0.iter().fold(reqwest::multipart::Form::new(), |form, part| {
+            let mut local_var_part = reqwest::multipart::Part::bytes(part.content.clone());
+            if let Some(ref file_name) = part.file_name {
+                local_var_part = local_var_part.file_name(file_name.clone());
+            }
+            form.part(part.name.clone(), local_var_part)
+        })
+    }
+}
+
+/// Lists part names and sizes only; part contents may be large or secret.
+impl fmt::Debug for MultipartParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|part| format!("{} ({} bytes)", part.name, part.content.len())))
+            .finish()
+    }
+}
+
+/// Credential schemes an endpoint accepts, as declared by the API specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AuthScheme {
+    /// An API token, sent as `Fastly-Key` or `Authorization: Bearer`.
+    Token,
+    /// HTTP basic authentication.
+    Basic,
+}
+
+/// The credential attached to one attempt of a request.
+enum Credential {
+    FastlyKey(String),
+    Bearer(String),
+    Basic(String, Option<String>),
+}
+
+/// Pick the credential for `request` from `configuration`.
+///
+/// Token credentials are tried in the order `token_source`, `api_key`,
+/// `bearer_access_token`, `oauth_access_token`; the basic credential is
+/// `basic_auth`. The schemes the endpoint declares are tried first, in the
+/// endpoint's order, then any other configured credential is used. Empty
+/// values count as unset. Endpoints that declare no scheme are sent without
+/// a credential.
+async fn credential(configuration: &configuration::Configuration, request: &Request) -> Result<Option<Credential>, AuthError> {
+    if request.auth.is_empty() {
+        return Ok(None);
+    }
+    let local_var_declared = request.auth.iter().copied();
+    let local_var_fallback = [AuthScheme::Token, AuthScheme::Basic].into_iter().filter(|s| !request.auth.contains(s));
+    for local_var_scheme in local_var_declared.chain(local_var_fallback) {
+        let local_var_credential = match local_var_scheme {
+            AuthScheme::Token => token_credential(configuration).await?,
+            AuthScheme::Basic => basic_credential(configuration),
+        };
+        if local_var_credential.is_some() {
+            return Ok(local_var_credential);
+        }
+    }
+    Err(AuthError::new(format!(
+        "no credential for {}: token_source, api_key, bearer_access_token, oauth_access_token and basic_auth are all unset or empty",
+        request.operation,
+    )))
+}
+
+async fn token_credential(configuration: &configuration::Configuration) -> Result<Option<Credential>, AuthError> {
+    if let Some(ref local_var_source) = configuration.token_source {
+        let local_var_token = local_var_source.token().await?;
+        let local_var_value = local_var_token.access_token.expose().clone();
+        if local_var_value.is_empty() {
+            return Err(AuthError::new("token source returned an empty token"));
+        }
+        return Ok(Some(if local_var_token.is_bearer() {
+            Credential::Bearer(local_var_value)
+        } else {
+            Credential::FastlyKey(local_var_value)
+        }));
+    }
+    if let Some(ref local_var_apikey) = configuration.api_key {
+        let local_var_key = local_var_apikey.key.expose();
+        if !local_var_key.is_empty() {
+            return Ok(Some(Credential::FastlyKey(match local_var_apikey.prefix {
+                Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
+                None => local_var_key.clone(),
+            })));
+        }
+    }
+    let local_var_bearer = configuration.bearer_access_token.iter().chain(&configuration.oauth_access_token).find(|t| !t.expose().is_empty());
+    Ok(local_var_bearer.map(|t| Credential::Bearer(t.expose().clone())))
+}
+
+fn basic_credential(configuration: &configuration::Configuration) -> Option<Credential> {
+    match configuration.basic_auth {
+        Some((ref local_var_user, ref local_var_password)) if !local_var_user.is_empty() => {
+            Some(Credential::Basic(local_var_user.clone(), local_var_password.as_ref().map(|p| p.expose().clone())))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum RequestBody {
+    Empty,
+    Form(FormParams),
+    Json(serde_json::Value),
+    Multipart(MultipartParams),
+}
+
+/// Description of a single API call.
+///
+/// Every generated endpoint function builds one of these and hands it to
+/// [`execute`], which owns the transport concerns shared by all endpoints:
+/// base path, user agent, credentials, rate-limit tracking and turning the
+/// response into a result.
+///
+/// Its `Debug` output redacts credentials and secret fields, so it is safe to log.
+#[derive(Clone)]
+pub(crate) struct Request {
+    /// Name of the generated endpoint function, e.g. `create_healthcheck`.
+    pub operation: &'static str,
+    pub method: reqwest::Method,
+    /// Path relative to the configured base path, with path parameters already encoded.
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub body: RequestBody,
+    /// Accepted credential schemes; empty for endpoints that need none.
+    pub auth: &'static [AuthScheme],
+    /// Served from another host than `base_path`.
+    pub group: Option<configuration::EndpointGroup>,
+}
+
+impl Request {
+    pub fn new(operation: &'static str, method: reqwest::Method, path: String) -> Request {
+        Request {
+            operation,
+            method,
+            path,
+            query: Vec::new(),
+            headers: Vec::new(),
+            body: RequestBody::Empty,
+            auth: &[AuthScheme::Token],
+            group: None,
+        }
+    }
+
+    pub fn auth(mut self, schemes: &'static [AuthScheme]) -> Request {
+        self.auth = schemes;
+        self
+    }
+
+    #[cfg_attr(not(feature = "full"), allow(dead_code))]
+    pub fn group(mut self, group: configuration::EndpointGroup) -> Request {
+        self.group = Some(group);
+        self
+    }
+
+    pub fn query(mut self, key: &str, value: String) -> Request {
+        self.query.push((key.to_owned(), value));
+        self
+    }
+
+    #[cfg_attr(not(feature = "full"), allow(dead_code))]
+    pub fn header(mut self, name: &str, value: String) -> Request {
+        self.headers.push((name.to_owned(), value));
+        self
+    }
+
+    pub fn form(mut self, params: FormParams) -> Request {
+        self.body = RequestBody::Form(params);
+        self
+    }
+
+    #[cfg_attr(not(feature = "full"), allow(dead_code))]
+    pub fn json(mut self, value: serde_json::Value) -> Request {
+        self.body = RequestBody::Json(value);
+        self
+    }
+
+    #[cfg_attr(not(feature = "full"), allow(dead_code))]
+    pub fn multipart(mut self, params: MultipartParams) -> Request {
+        self.body = RequestBody::Multipart(params);
+        self
+    }
+
+    /// The service ID and version number named in the path, if any.
+    pub fn service_version(&self) -> (Option<&str>, Option<&str>) {
+        let mut segments = self.path.split('/');
+        let mut service_id = None;
+        let mut version = None;
+        while let Some(segment) = segments.next() {
+            match segment {
+                "service" if service_id.is_none() => service_id = segments.next(),
+                "version" if service_id.is_some() && version.is_none() => version = segments.next(),
+                _ => {}
+            }
+        }
+        (service_id, version)
+    }
+}
+
+impl fmt::Debug for Request {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let query: Vec<(&str, &str)> = self.query.iter().map(|(k, v)| (k.as_str(), redact::value(&self.path, k, v))).collect();
+        let headers: Vec<(&str, &str)> = self.headers.iter().map(|(k, v)| (k.as_str(), redact::value(&self.path, k, v))).collect();
+        let mut local_var_debug = f.debug_struct("Request");
+        local_var_debug
+            .field("operation", &self.operation)
+            .field("method", &self.method)
+            .field("path", &self.path)
+            .field("query", &query)
+            .field("headers", &headers);
+        match self.body {
+            RequestBody::Empty => {}
+            RequestBody::Form(ref form) => {
+                let pairs: Vec<(&str, &str)> = form.iter().map(|(k, v)| (k, redact::value(&self.path, k, v))).collect();
+                local_var_debug.field("form", &pairs);
+            }
+            RequestBody::Json(ref json) => {
+                local_var_debug.field("json", &format_args!("{}", redact::json(&self.path, json)));
+            }
+            RequestBody::Multipart(ref form) => {
+                local_var_debug.field("multipart", form);
+            }
+        }
+        local_var_debug.finish()
+    }
+}
+
+/// A successful response whose body has not been deserialized yet.
+#[derive(Debug, Clone)]
+pub(crate) struct RawResponse {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub content: String,
+    /// Whether a dry run planned the request instead of sending it.
+    pub planned: bool,
+}
+
+impl RawResponse {
+    /// The stand-in for a request that a dry run planned: status
+    /// [`dry_run::PLANNED`], no headers and no body.
+    fn planned() -> RawResponse {
+        RawResponse { status: dry_run::PLANNED, headers: reqwest::header::HeaderMap::new(), content: String::new(), planned: true }
+    }
+
+    /// Deserialize the body; a planned request gets the `Default` entity.
+    #[allow(clippy::result_large_err)]
+    pub fn json<T: serde::de::DeserializeOwned + Default, E>(self) -> Result<Response<T>, Error<E>> {
+        let entity = if self.planned { T::default() } else { serde_json::from_str(&self.content)? };
+        Ok(Response { status: self.status, headers: self.headers, entity })
+    }
+
+    /// For endpoints whose body carries nothing of interest.
+    #[cfg_attr(not(feature = "full"), allow(dead_code))]
+    pub fn empty(self) -> Response<()> {
+        Response { status: self.status, headers: self.headers, entity: () }
+    }
+}
+
+/// Send `request` using `configuration` and classify the response.
+///
+/// Under a dry run, mutating requests are added to the plan and answered
+/// with a planned response instead of being sent. Otherwise they first wait
+/// for `configuration.throttle`, if one is set. Transient failures are
+/// retried according to `configuration.retry_policy`.
+/// Client and server error statuses that are not retried become
+/// [`Error::ResponseError`], with the body parsed into the endpoint's typed
+/// error when possible.
+pub(crate) async fn execute<E: serde::de::DeserializeOwned>(configuration: &configuration::Configuration, request: Request) -> Result<RawResponse, Error<E>> {
+    instrument::in_span(instrument::request_span(&request), send(configuration, request)).await
+}
+
+/// Like [`execute`], but leaves the body of a successful response unread so
+/// it can be consumed incrementally.
+pub(crate) async fn execute_streaming<E: serde::de::DeserializeOwned>(configuration: &configuration::Configuration, request: Request) -> Result<reqwest::Response, Error<E>> {
+    instrument::in_span(instrument::request_span(&request), respond(configuration, request)).await
+}
+
+async fn send<E: serde::de::DeserializeOwned>(configuration: &configuration::Configuration, request: Request) -> Result<RawResponse, Error<E>> {
+    if request.method != reqwest::Method::GET && request.method != reqwest::Method::HEAD {
+        if let Some(ref local_var_plan) = configuration.dry_run {
+            local_var_plan.push(dry_run::PlannedRequest::new(configuration, &request));
+            return Ok(RawResponse::planned());
+        }
+    }
+
+    let local_var_resp = respond(configuration, request).await?;
+    let local_var_status = local_var_resp.status();
+    let local_var_headers = local_var_resp.headers().clone();
+    let local_var_content = local_var_resp.text().await?;
+    Ok(RawResponse { status: local_var_status, headers: local_var_headers, content: local_var_content, planned: false })
+}
+
+/// Send `request`, retrying as the policy allows, and turn error statuses
+/// into [`Error::ResponseError`].
+async fn respond<E: serde::de::DeserializeOwned>(configuration: &configuration::Configuration, request: Request) -> Result<reqwest::Response, Error<E>> {
+    let local_var_started = std::time::Instant::now();
+    let local_var_policy = configuration.retry_policy.clone();
+    let local_var_may_retry = local_var_policy.allows_method(&request.method);
+    let local_var_mutating = request.method != reqwest::Method::GET && request.method != reqwest::Method::HEAD;
+    let mut local_var_attempt = 1;
+
+    let local_var_resp = loop {
+        let local_var_permit = match configuration.throttle {
+            Some(ref local_var_throttle) if local_var_mutating => Some(local_var_throttle.acquire().await),
+            _ => None,
+        };
+        let local_var_credential = credential(configuration, &request).await?;
+        instrument::attempt(&request, local_var_attempt);
+        let local_var_req = build_request(configuration, &request, local_var_credential)?;
+        let local_var_result = match configuration.cassette {
+            Some(ref local_var_cassette) => local_var_cassette.execute(&configuration.client, local_var_req).await?,
+            None => configuration.client.execute(local_var_req).await,
+        };
+        // The request is no longer in flight, whatever its outcome.
+        drop(local_var_permit);
+        instrument::outcome(&local_var_result, local_var_started.elapsed(), local_var_attempt - 1);
+
+        let local_var_delay = match local_var_result {
+            Ok(ref local_var_resp) => {
+                let local_var_rate_limit = {
+                    let mut local_var_state = configuration.rate_limit.lock().unwrap();
+                    if local_var_state.update(local_var_resp.headers(), std::time::SystemTime::now()) {
+                        if let Some(ref local_var_throttle) = configuration.throttle {
+                            local_var_throttle.observe(&local_var_state);
+                        }
+                    }
+                    local_var_state.clone()
+                };
+                local_var_policy.delay_for_response(local_var_resp, &local_var_rate_limit, local_var_attempt)
+            }
+            Err(ref local_var_err) => local_var_policy.delay_for_error(local_var_err, local_var_attempt),
+        };
+        match local_var_delay {
+            Some(local_var_delay) if local_var_may_retry && local_var_attempt < local_var_policy.max_attempts => {
+                tokio::time::sleep(local_var_delay).await;
+                local_var_attempt += 1;
+            }
+            _ => break local_var_result?,
+        }
+    };
+
+    let local_var_status = local_var_resp.status();
+    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
+        Ok(local_var_resp)
+    } else {
+        let local_var_headers = local_var_resp.headers().clone();
+        let local_var_content = local_var_resp.text().await?;
+        let local_var_entity: Option<E> = serde_json::from_str(&local_var_content).ok();
+        let local_var_api_error = ApiError::from_response(local_var_status, &local_var_headers, &local_var_content);
+        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity, api_error: local_var_api_error };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+/// Build one attempt of `request`. Called again for every retry.
+fn build_request(configuration: &configuration::Configuration, request: &Request, credential: Option<Credential>) -> Result<reqwest::Request, reqwest::Error> {
+    let local_var_client = &configuration.client;
+
+    let local_var_uri_str = format!("{}{}", configuration.base_path_for(request.group), request.path);
+    let mut local_var_req_builder = local_var_client.request(request.method.clone(), local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = configuration.user_agent {
+        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    for (local_var_name, local_var_value) in &request.headers {
+        local_var_req_builder = local_var_req_builder.header(local_var_name.as_str(), local_var_value.as_str());
+    }
+    local_var_req_builder = match credential {
+        Some(Credential::FastlyKey(local_var_key)) => local_var_req_builder.header("Fastly-Key", local_var_key),
+        Some(Credential::Bearer(local_var_token)) => local_var_req_builder.bearer_auth(local_var_token),
+        Some(Credential::Basic(local_var_user, local_var_password)) => local_var_req_builder.basic_auth(local_var_user, local_var_password),
+        None => local_var_req_builder,
+    };
+    if !request.query.is_empty() {
+        local_var_req_builder = local_var_req_builder.query(&request.query);
+    }
+    local_var_req_builder = match request.body {
+        RequestBody::Empty => local_var_req_builder,
+        RequestBody::Form(ref local_var_form) => local_var_req_builder.form(local_var_form),
+        RequestBody::Json(ref local_var_json) => local_var_req_builder.json(local_var_json),
+        RequestBody::Multipart(ref local_var_form) => local_var_req_builder.multipart(local_var_form.to_form()),
+    };
+
+    local_var_req_builder.build()
+}
+
+#[cfg(feature = "acl")]
 pub mod acl_api;
+#[cfg(feature = "acl")]
 pub mod acl_entry_api;
+#[cfg(feature = "domains")]
 pub mod apex_redirect_api;
+#[cfg(feature = "accounts")]
 pub mod automation_tokens_api;
+#[cfg(feature = "backends")]
 pub mod backend_api;
+#[cfg(feature = "billing")]
 pub mod billing_api;
+#[cfg(feature = "billing")]
 pub mod billing_address_api;
+#[cfg(feature = "billing")]
 pub mod billing_invoices_api;
+#[cfg(feature = "billing")]
 pub mod billing_usage_metrics_api;
+#[cfg(feature = "vcl")]
 pub mod cache_settings_api;
+#[cfg(feature = "vcl")]
 pub mod condition_api;
+#[cfg(feature = "stores")]
 pub mod config_store_api;
+#[cfg(feature = "stores")]
 pub mod config_store_item_api;
+#[cfg(feature = "accounts")]
 pub mod contact_api;
+#[cfg(feature = "platform")]
 pub mod content_api;
+#[cfg(feature = "accounts")]
 pub mod customer_api;
+#[cfg(feature = "dictionary")]
 pub mod dictionary_api;
+#[cfg(feature = "dictionary")]
 pub mod dictionary_info_api;
+#[cfg(feature = "dictionary")]
 pub mod dictionary_item_api;
+#[cfg(feature = "vcl")]
 pub mod diff_api;
+#[cfg(feature = "backends")]
 pub mod director_api;
+#[cfg(feature = "backends")]
 pub mod director_backend_api;
+#[cfg(feature = "domains")]
 pub mod domain_api;
+#[cfg(feature = "stats")]
 pub mod domain_inspector_historical_api;
+#[cfg(feature = "stats")]
 pub mod domain_inspector_realtime_api;
+#[cfg(feature = "domains")]
 pub mod domain_ownerships_api;
+#[cfg(feature = "platform")]
 pub mod enabled_products_api;
+#[cfg(feature = "accounts")]
 pub mod events_api;
+#[cfg(feature = "vcl")]
 pub mod gzip_api;
+#[cfg(feature = "vcl")]
 pub mod header_api;
+#[cfg(feature = "healthcheck")]
 pub mod healthcheck_api;
+#[cfg(feature = "stats")]
+pub mod historical_api;
+#[cfg(feature = "vcl")]
 pub mod http3_api;
+#[cfg(feature = "iam")]
 pub mod iam_permissions_api;
+#[cfg(feature = "iam")]
 pub mod iam_roles_api;
+#[cfg(feature = "iam")]
 pub mod iam_service_groups_api;
+#[cfg(feature = "iam")]
 pub mod iam_user_groups_api;
+#[cfg(feature = "vcl")]
 pub mod image_optimizer_default_settings_api;
+#[cfg(feature = "accounts")]
 pub mod invitations_api;
+#[cfg(feature = "stores")]
 pub mod kv_store_api;
+#[cfg(feature = "stores")]
 pub mod kv_store_item_api;
+#[cfg(feature = "waf-legacy")]
 pub mod legacy_waf_configuration_sets_api;
+#[cfg(feature = "waf-legacy")]
 pub mod legacy_waf_firewall_api;
+#[cfg(feature = "waf-legacy")]
 pub mod legacy_waf_owasp_api;
+#[cfg(feature = "waf-legacy")]
 pub mod legacy_waf_rule_api;
+#[cfg(feature = "waf-legacy")]
 pub mod legacy_waf_rule_status_api;
+#[cfg(feature = "waf-legacy")]
 pub mod legacy_waf_ruleset_api;
+#[cfg(feature = "waf-legacy")]
 pub mod legacy_waf_tag_api;
+#[cfg(feature = "waf-legacy")]
 pub mod legacy_waf_update_status_api;
+#[cfg(feature = "logging")]
 pub mod logging_azureblob_api;
+#[cfg(feature = "logging")]
 pub mod logging_bigquery_api;
+#[cfg(feature = "logging")]
 pub mod logging_cloudfiles_api;
+#[cfg(feature = "logging")]
 pub mod logging_datadog_api;
+#[cfg(feature = "logging")]
 pub mod logging_digitalocean_api;
+#[cfg(feature = "logging")]
 pub mod logging_elasticsearch_api;
+#[cfg(feature = "logging")]
 pub mod logging_ftp_api;
+#[cfg(feature = "logging")]
 pub mod logging_gcs_api;
+#[cfg(feature = "logging")]
 pub mod logging_heroku_api;
+#[cfg(feature = "logging")]
 pub mod logging_honeycomb_api;
+#[cfg(feature = "logging")]
 pub mod logging_https_api;
+#[cfg(feature = "logging")]
 pub mod logging_kafka_api;
+#[cfg(feature = "logging")]
 pub mod logging_kinesis_api;
+#[cfg(feature = "logging")]
 pub mod logging_logentries_api;
+#[cfg(feature = "logging")]
 pub mod logging_loggly_api;
+#[cfg(feature = "logging")]
 pub mod logging_logshuttle_api;
+#[cfg(feature = "logging")]
 pub mod logging_newrelic_api;
+#[cfg(feature = "logging")]
 pub mod logging_newrelicotlp_api;
+#[cfg(feature = "logging")]
 pub mod logging_openstack_api;
+#[cfg(feature = "logging")]
 pub mod logging_papertrail_api;
+#[cfg(feature = "logging")]
 pub mod logging_pubsub_api;
+#[cfg(feature = "logging")]
 pub mod logging_s3_api;
+#[cfg(feature = "logging")]
 pub mod logging_scalyr_api;
+#[cfg(feature = "logging")]
 pub mod logging_sftp_api;
+#[cfg(feature = "logging")]
 pub mod logging_splunk_api;
+#[cfg(feature = "logging")]
 pub mod logging_sumologic_api;
+#[cfg(feature = "logging")]
 pub mod logging_syslog_api;
+#[cfg(feature = "tls")]
 pub mod mutual_authentication_api;
+#[cfg(feature = "stats")]
 pub mod observability_custom_dashboards_api;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_historical_api;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_realtime_api;
+#[cfg(feature = "compute")]
 pub mod package_api;
+#[cfg(feature = "backends")]
 pub mod pool_api;
+#[cfg(feature = "platform")]
 pub mod pop_api;
+#[cfg(feature = "platform")]
 pub mod public_ip_list_api;
+#[cfg(feature = "platform")]
 pub mod publish_api;
+#[cfg(feature = "purge")]
 pub mod purge_api;
+#[cfg(feature = "vcl")]
 pub mod rate_limiter_api;
+#[cfg(feature = "stats")]
 pub mod realtime_api;
+#[cfg(feature = "vcl")]
 pub mod request_settings_api;
+#[cfg(feature = "compute")]
 pub mod resource_api;
+#[cfg(feature = "vcl")]
 pub mod response_object_api;
+#[cfg(feature = "stores")]
 pub mod secret_store_api;
+#[cfg(feature = "stores")]
 pub mod secret_store_item_api;
+#[cfg(feature = "backends")]
 pub mod server_api;
 pub mod service_api;
+#[cfg(feature = "iam")]
 pub mod service_authorizations_api;
+#[cfg(feature = "vcl")]
 pub mod settings_api;
+#[cfg(feature = "vcl")]
 pub mod snippet_api;
+#[cfg(feature = "accounts")]
 pub mod star_api;
+#[cfg(feature = "stats")]
 pub mod stats_api;
+#[cfg(feature = "accounts")]
 pub mod sudo_api;
+#[cfg(feature = "tls")]
 pub mod tls_activations_api;
+#[cfg(feature = "tls")]
 pub mod tls_bulk_certificates_api;
+#[cfg(feature = "tls")]
 pub mod tls_certificates_api;
+#[cfg(feature = "tls")]
 pub mod tls_configurations_api;
+#[cfg(feature = "tls")]
 pub mod tls_csrs_api;
+#[cfg(feature = "tls")]
 pub mod tls_domains_api;
+#[cfg(feature = "tls")]
 pub mod tls_private_keys_api;
+#[cfg(feature = "tls")]
 pub mod tls_subscriptions_api;
+#[cfg(feature = "accounts")]
 pub mod tokens_api;
+#[cfg(feature = "accounts")]
 pub mod user_api;
+#[cfg(feature = "vcl")]
 pub mod vcl_api;
+#[cfg(feature = "vcl")]
 pub mod vcl_diff_api;
 pub mod version_api;
+#[cfg(feature = "waf")]
 pub mod waf_active_rules_api;
+#[cfg(feature = "waf")]
 pub mod waf_exclusions_api;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_versions_api;
+#[cfg(feature = "waf")]
 pub mod waf_firewalls_api;
+#[cfg(feature = "waf")]
 pub mod waf_rule_revisions_api;
+#[cfg(feature = "waf")]
 pub mod waf_rules_api;
+#[cfg(feature = "waf")]
 pub mod waf_tags_api;
+#[cfg(feature = "stats")]
 pub mod whole_platform_ddos_historical_api;
 
+pub mod batch;
+pub mod cassette;
 pub mod configuration;
+pub mod credential_chain;
+pub mod dry_run;
+mod instrument;
+pub mod pagination;
+pub mod raw;
+pub mod redact;
+pub mod streaming;
+pub mod token_source;
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_mutual_tls_authentication`]
@@ -90,280 +89,127 @@ pub enum PatchMutualAuthenticationError {
 
 
 /// Create a mutual authentication using a bundle of certificates to enable client-to-server mutual TLS.
-pub async fn create_mutual_tls_authentication(configuration: &mut configuration::Configuration, params: CreateMutualTlsAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<CreateMutualTlsAuthenticationError>> {
-    let local_var_configuration = configuration;
+pub async fn create_mutual_tls_authentication(configuration: &configuration::Configuration, params: CreateMutualTlsAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<CreateMutualTlsAuthenticationError>> {
+    create_mutual_tls_authentication_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_mutual_tls_authentication`], but also returns the status and headers of the response.
+pub async fn create_mutual_tls_authentication_with_info(configuration: &configuration::Configuration, params: CreateMutualTlsAuthenticationParams) -> Result<crate::apis::Response<crate::models::MutualAuthenticationResponse>, Error<CreateMutualTlsAuthenticationError>> {
+    crate::apis::execute(configuration, create_mutual_tls_authentication_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_mutual_tls_authentication`] sends.
+pub(crate) fn create_mutual_tls_authentication_request(params: CreateMutualTlsAuthenticationParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let mutual_authentication = params.mutual_authentication;
 
+    let local_var_path = String::from("/tls/mutual_authentications");
+    let mut local_var_req = crate::apis::Request::new("create_mutual_tls_authentication", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/mutual_authentications", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&mutual_authentication);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&mutual_authentication)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateMutualTlsAuthenticationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Remove a Mutual TLS authentication
-pub async fn delete_mutual_tls(configuration: &mut configuration::Configuration, params: DeleteMutualTlsParams) -> Result<(), Error<DeleteMutualTlsError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_mutual_tls(configuration: &configuration::Configuration, params: DeleteMutualTlsParams) -> Result<(), Error<DeleteMutualTlsError>> {
+    delete_mutual_tls_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_mutual_tls`], but also returns the status and headers of the response.
+pub async fn delete_mutual_tls_with_info(configuration: &configuration::Configuration, params: DeleteMutualTlsParams) -> Result<crate::apis::Response<()>, Error<DeleteMutualTlsError>> {
+    crate::apis::execute(configuration, delete_mutual_tls_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_mutual_tls`] sends.
+pub(crate) fn delete_mutual_tls_request(params: DeleteMutualTlsParams) -> crate::apis::Request {
     // unbox the parameters
     let mutual_authentication_id = params.mutual_authentication_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/mutual_authentications/{mutual_authentication_id}", local_var_configuration.base_path, mutual_authentication_id=crate::apis::urlencode(mutual_authentication_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteMutualTlsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/mutual_authentications/{mutual_authentication_id}", mutual_authentication_id=crate::apis::urlencode(mutual_authentication_id));
+    crate::apis::Request::new("delete_mutual_tls", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Show a Mutual Authentication.
-pub async fn get_mutual_authentication(configuration: &mut configuration::Configuration, params: GetMutualAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<GetMutualAuthenticationError>> {
-    let local_var_configuration = configuration;
+pub async fn get_mutual_authentication(configuration: &configuration::Configuration, params: GetMutualAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<GetMutualAuthenticationError>> {
+    get_mutual_authentication_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_mutual_authentication`], but also returns the status and headers of the response.
+pub async fn get_mutual_authentication_with_info(configuration: &configuration::Configuration, params: GetMutualAuthenticationParams) -> Result<crate::apis::Response<crate::models::MutualAuthenticationResponse>, Error<GetMutualAuthenticationError>> {
+    crate::apis::execute(configuration, get_mutual_authentication_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_mutual_authentication`] sends.
+pub(crate) fn get_mutual_authentication_request(params: GetMutualAuthenticationParams) -> crate::apis::Request {
     // unbox the parameters
     let mutual_authentication_id = params.mutual_authentication_id;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/mutual_authentications/{mutual_authentication_id}", local_var_configuration.base_path, mutual_authentication_id=crate::apis::urlencode(mutual_authentication_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/tls/mutual_authentications/{mutual_authentication_id}", mutual_authentication_id=crate::apis::urlencode(mutual_authentication_id));
+    let mut local_var_req = crate::apis::Request::new("get_mutual_authentication", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetMutualAuthenticationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all mutual authentications.
-pub async fn list_mutual_authentications(configuration: &mut configuration::Configuration, params: ListMutualAuthenticationsParams) -> Result<crate::models::MutualAuthenticationsResponse, Error<ListMutualAuthenticationsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_mutual_authentications(configuration: &configuration::Configuration, params: ListMutualAuthenticationsParams) -> Result<crate::models::MutualAuthenticationsResponse, Error<ListMutualAuthenticationsError>> {
+    list_mutual_authentications_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_mutual_authentications`], but also returns the status and headers of the response.
+pub async fn list_mutual_authentications_with_info(configuration: &configuration::Configuration, params: ListMutualAuthenticationsParams) -> Result<crate::apis::Response<crate::models::MutualAuthenticationsResponse>, Error<ListMutualAuthenticationsError>> {
+    crate::apis::execute(configuration, list_mutual_authentications_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_mutual_authentications`] sends.
+pub(crate) fn list_mutual_authentications_request(params: ListMutualAuthenticationsParams) -> crate::apis::Request {
     // unbox the parameters
     let include = params.include;
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/mutual_authentications", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/tls/mutual_authentications");
+    let mut local_var_req = crate::apis::Request::new("list_mutual_authentications", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListMutualAuthenticationsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a Mutual Authentication.
-pub async fn patch_mutual_authentication(configuration: &mut configuration::Configuration, params: PatchMutualAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<PatchMutualAuthenticationError>> {
-    let local_var_configuration = configuration;
+pub async fn patch_mutual_authentication(configuration: &configuration::Configuration, params: PatchMutualAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<PatchMutualAuthenticationError>> {
+    patch_mutual_authentication_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`patch_mutual_authentication`], but also returns the status and headers of the response.
+pub async fn patch_mutual_authentication_with_info(configuration: &configuration::Configuration, params: PatchMutualAuthenticationParams) -> Result<crate::apis::Response<crate::models::MutualAuthenticationResponse>, Error<PatchMutualAuthenticationError>> {
+    crate::apis::execute(configuration, patch_mutual_authentication_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`patch_mutual_authentication`] sends.
+pub(crate) fn patch_mutual_authentication_request(params: PatchMutualAuthenticationParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let mutual_authentication_id = params.mutual_authentication_id;
     let mutual_authentication = params.mutual_authentication;
 
+    let local_var_path = format!("/tls/mutual_authentications/{mutual_authentication_id}", mutual_authentication_id=crate::apis::urlencode(mutual_authentication_id));
+    let mut local_var_req = crate::apis::Request::new("patch_mutual_authentication", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/mutual_authentications/{mutual_authentication_id}", local_var_configuration.base_path, mutual_authentication_id=crate::apis::urlencode(mutual_authentication_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&mutual_authentication)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&mutual_authentication);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<PatchMutualAuthenticationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_dashboard`]
@@ -74,264 +73,103 @@ pub enum UpdateDashboardError {
 
 
 /// Create a new dashboard
-pub async fn create_dashboard(configuration: &mut configuration::Configuration, params: CreateDashboardParams) -> Result<crate::models::Dashboard, Error<CreateDashboardError>> {
-    let local_var_configuration = configuration;
+pub async fn create_dashboard(configuration: &configuration::Configuration, params: CreateDashboardParams) -> Result<crate::models::Dashboard, Error<CreateDashboardError>> {
+    create_dashboard_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_dashboard`], but also returns the status and headers of the response.
+pub async fn create_dashboard_with_info(configuration: &configuration::Configuration, params: CreateDashboardParams) -> Result<crate::apis::Response<crate::models::Dashboard>, Error<CreateDashboardError>> {
+    crate::apis::execute(configuration, create_dashboard_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_dashboard`] sends.
+pub(crate) fn create_dashboard_request(params: CreateDashboardParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let create_dashboard_request = params.create_dashboard_request;
 
+    let local_var_path = String::from("/observability/dashboards");
+    let mut local_var_req = crate::apis::Request::new("create_dashboard", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/observability/dashboards", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&create_dashboard_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateDashboardError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req = local_var_req.json(serde_json::to_value(&create_dashboard_request)?);
+
+    Ok(local_var_req)
 }
 
 /// Delete an existing dashboard
-pub async fn delete_dashboard(configuration: &mut configuration::Configuration, params: DeleteDashboardParams) -> Result<(), Error<DeleteDashboardError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_dashboard(configuration: &configuration::Configuration, params: DeleteDashboardParams) -> Result<(), Error<DeleteDashboardError>> {
+    delete_dashboard_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_dashboard`], but also returns the status and headers of the response.
+pub async fn delete_dashboard_with_info(configuration: &configuration::Configuration, params: DeleteDashboardParams) -> Result<crate::apis::Response<()>, Error<DeleteDashboardError>> {
+    crate::apis::execute(configuration, delete_dashboard_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_dashboard`] sends.
+pub(crate) fn delete_dashboard_request(params: DeleteDashboardParams) -> crate::apis::Request {
     // unbox the parameters
     let dashboard_id = params.dashboard_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/observability/dashboards/{dashboard_id}", local_var_configuration.base_path, dashboard_id=crate::apis::urlencode(dashboard_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteDashboardError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/observability/dashboards/{dashboard_id}", dashboard_id=crate::apis::urlencode(dashboard_id));
+    crate::apis::Request::new("delete_dashboard", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Retrieve a dashboard by ID
-pub async fn get_dashboard(configuration: &mut configuration::Configuration, params: GetDashboardParams) -> Result<crate::models::Dashboard, Error<GetDashboardError>> {
-    let local_var_configuration = configuration;
+pub async fn get_dashboard(configuration: &configuration::Configuration, params: GetDashboardParams) -> Result<crate::models::Dashboard, Error<GetDashboardError>> {
+    get_dashboard_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_dashboard`], but also returns the status and headers of the response.
+pub async fn get_dashboard_with_info(configuration: &configuration::Configuration, params: GetDashboardParams) -> Result<crate::apis::Response<crate::models::Dashboard>, Error<GetDashboardError>> {
+    crate::apis::execute(configuration, get_dashboard_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_dashboard`] sends.
+pub(crate) fn get_dashboard_request(params: GetDashboardParams) -> crate::apis::Request {
     // unbox the parameters
     let dashboard_id = params.dashboard_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/observability/dashboards/{dashboard_id}", local_var_configuration.base_path, dashboard_id=crate::apis::urlencode(dashboard_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetDashboardError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/observability/dashboards/{dashboard_id}", dashboard_id=crate::apis::urlencode(dashboard_id));
+    crate::apis::Request::new("get_dashboard", reqwest::Method::GET, local_var_path)
 }
 
 /// List all custom dashboards
-pub async fn list_dashboards(configuration: &mut configuration::Configuration) -> Result<crate::models::ListDashboardsResponse, Error<ListDashboardsError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
+pub async fn list_dashboards(configuration: &configuration::Configuration) -> Result<crate::models::ListDashboardsResponse, Error<ListDashboardsError>> {
+    list_dashboards_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_dashboards`], but also returns the status and headers of the response.
+pub async fn list_dashboards_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::ListDashboardsResponse>, Error<ListDashboardsError>> {
+    crate::apis::execute(configuration, list_dashboards_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/observability/dashboards", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListDashboardsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`list_dashboards`] sends.
+pub(crate) fn list_dashboards_request() -> crate::apis::Request {
+    let local_var_path = String::from("/observability/dashboards");
+    crate::apis::Request::new("list_dashboards", reqwest::Method::GET, local_var_path)
 }
 
 /// Update an existing dashboard
-pub async fn update_dashboard(configuration: &mut configuration::Configuration, params: UpdateDashboardParams) -> Result<crate::models::Dashboard, Error<UpdateDashboardError>> {
-    let local_var_configuration = configuration;
+pub async fn update_dashboard(configuration: &configuration::Configuration, params: UpdateDashboardParams) -> Result<crate::models::Dashboard, Error<UpdateDashboardError>> {
+    update_dashboard_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_dashboard`], but also returns the status and headers of the response.
+pub async fn update_dashboard_with_info(configuration: &configuration::Configuration, params: UpdateDashboardParams) -> Result<crate::apis::Response<crate::models::Dashboard>, Error<UpdateDashboardError>> {
+    crate::apis::execute(configuration, update_dashboard_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_dashboard`] sends.
+pub(crate) fn update_dashboard_request(params: UpdateDashboardParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let dashboard_id = params.dashboard_id;
     let update_dashboard_request = params.update_dashboard_request;
 
+    let local_var_path = format!("/observability/dashboards/{dashboard_id}", dashboard_id=crate::apis::urlencode(dashboard_id));
+    let mut local_var_req = crate::apis::Request::new("update_dashboard", reqwest::Method::PATCH, local_var_path);
+
+    local_var_req = local_var_req.json(serde_json::to_value(&update_dashboard_request)?);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/observability/dashboards/{dashboard_id}", local_var_configuration.base_path, dashboard_id=crate::apis::urlencode(dashboard_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&update_dashboard_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateDashboardError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_origin_inspector_historical`]
@@ -48,9 +47,17 @@ pub enum GetOriginInspectorHistoricalError {
 
 
 /// Fetches historical origin metrics for a given Fastly service, optionally filtering and grouping the results by origin host, region, or POP. 
-pub async fn get_origin_inspector_historical(configuration: &mut configuration::Configuration, params: GetOriginInspectorHistoricalParams) -> Result<crate::models::HistoricalOriginsResponse, Error<GetOriginInspectorHistoricalError>> {
-    let local_var_configuration = configuration;
+pub async fn get_origin_inspector_historical(configuration: &configuration::Configuration, params: GetOriginInspectorHistoricalParams) -> Result<crate::models::HistoricalOriginsResponse, Error<GetOriginInspectorHistoricalError>> {
+    get_origin_inspector_historical_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_origin_inspector_historical`], but also returns the status and headers of the response.
+pub async fn get_origin_inspector_historical_with_info(configuration: &configuration::Configuration, params: GetOriginInspectorHistoricalParams) -> Result<crate::apis::Response<crate::models::HistoricalOriginsResponse>, Error<GetOriginInspectorHistoricalError>> {
+    crate::apis::execute(configuration, get_origin_inspector_historical_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_origin_inspector_historical`] sends.
+pub(crate) fn get_origin_inspector_historical_request(params: GetOriginInspectorHistoricalParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let start = params.start;
@@ -64,78 +71,40 @@ pub async fn get_origin_inspector_historical(configuration: &mut configuration::
     let datacenter = params.datacenter;
     let host = params.host;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/metrics/origins/services/{service_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/metrics/origins/services/{service_id}", service_id=crate::apis::urlencode(service_id));
+    let mut local_var_req = crate::apis::Request::new("get_origin_inspector_historical", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = start {
-        local_var_req_builder = local_var_req_builder.query(&[("start", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("start", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = end {
-        local_var_req_builder = local_var_req_builder.query(&[("end", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("end", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = downsample {
-        local_var_req_builder = local_var_req_builder.query(&[("downsample", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("downsample", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = metric {
-        local_var_req_builder = local_var_req_builder.query(&[("metric", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("metric", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = group_by {
-        local_var_req_builder = local_var_req_builder.query(&[("group_by", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("group_by", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = limit {
-        local_var_req_builder = local_var_req_builder.query(&[("limit", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("limit", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = cursor {
-        local_var_req_builder = local_var_req_builder.query(&[("cursor", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("cursor", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = region {
-        local_var_req_builder = local_var_req_builder.query(&[("region", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("region", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = datacenter {
-        local_var_req_builder = local_var_req_builder.query(&[("datacenter", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("datacenter", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = host {
-        local_var_req_builder = local_var_req_builder.query(&[("host", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("host", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetOriginInspectorHistoricalError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
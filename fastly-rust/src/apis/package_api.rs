@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_package`]
@@ -50,116 +49,53 @@ pub enum PutPackageError {
 
 
 /// List detailed information about the Compute package for the specified service.
-pub async fn get_package(configuration: &mut configuration::Configuration, params: GetPackageParams) -> Result<crate::models::PackageResponse, Error<GetPackageError>> {
-    let local_var_configuration = configuration;
+pub async fn get_package(configuration: &configuration::Configuration, params: GetPackageParams) -> Result<crate::models::PackageResponse, Error<GetPackageError>> {
+    get_package_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_package`], but also returns the status and headers of the response.
+pub async fn get_package_with_info(configuration: &configuration::Configuration, params: GetPackageParams) -> Result<crate::apis::Response<crate::models::PackageResponse>, Error<GetPackageError>> {
+    crate::apis::execute(configuration, get_package_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_package`] sends.
+pub(crate) fn get_package_request(params: GetPackageParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/package", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetPackageError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/package", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("get_package", reqwest::Method::GET, local_var_path)
 }
 
 /// Upload a Compute package associated with the specified service version.
-pub async fn put_package(configuration: &mut configuration::Configuration, params: PutPackageParams) -> Result<crate::models::PackageResponse, Error<PutPackageError>> {
-    let local_var_configuration = configuration;
+pub async fn put_package(configuration: &configuration::Configuration, params: PutPackageParams) -> Result<crate::models::PackageResponse, Error<PutPackageError>> {
+    put_package_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`put_package`], but also returns the status and headers of the response.
+pub async fn put_package_with_info(configuration: &configuration::Configuration, params: PutPackageParams) -> Result<crate::apis::Response<crate::models::PackageResponse>, Error<PutPackageError>> {
+    crate::apis::execute(configuration, put_package_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`put_package`] sends.
+pub(crate) fn put_package_request(params: PutPackageParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let expect = params.expect;
     let package = params.package;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/package", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("put_package", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/package", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
     if let Some(local_var_param_value) = expect {
-        local_var_req_builder = local_var_req_builder.header("expect", local_var_param_value.to_string());
+        local_var_req = local_var_req.header("expect", local_var_param_value.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form = reqwest::multipart::Form::new();
+    let local_var_form = crate::apis::MultipartParams::new();
     // TODO: support file upload for 'package' parameter
-    local_var_req_builder = local_var_req_builder.multipart(local_var_form);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.multipart(local_var_form);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<PutPackageError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -0,0 +1,406 @@
+//! Walking list endpoints page by page.
+//!
+//! The API pages its list endpoints in three ways: `page`/`per_page` query
+//! parameters over a plain array, JSON:API `page[number]`/`page[size]` with
+//! `links` and `meta` in the body, and an opaque `cursor` returned in
+//! `meta.next_cursor`. [`paginate`] hides the difference and yields the items
+//! of every page as one stream:
+//!
+//! ```no_run
+//! # use fastly_api::apis::configuration::Configuration;
+//! # use fastly_api::apis::pagination::paginate;
+//! # use fastly_api::apis::service_api::ListServicesParams;
+//! # use futures::TryStreamExt;
+//! # async fn run(cfg: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut services = paginate(cfg, ListServicesParams::default()).page_size(100);
+//! while let Some(service) = services.try_next().await? {
+//!     println!("{:?}", service.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Pages are fetched only as items are consumed, so dropping the stream, or
+//! stopping early with `take` or `try_take_while`, saves the remaining
+//! requests. The stream ends after the first error.
+
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+
+use super::configuration::Configuration;
+use super::{BoxFuture, Error};
+
+/// One page of results and the params for the page after it.
+pub struct Page<P: Paginated> {
+    pub items: Vec<P::Item>,
+    /// `None` on the last page.
+    pub next: Option<P>,
+}
+
+/// Params of a list endpoint that can be walked with [`paginate`].
+pub trait Paginated: Clone + Send + Sized + 'static {
+    type Item: Send + 'static;
+    type Error: Send + 'static;
+
+    /// Ask for `size` results per page. The API may cap the value.
+    fn set_page_size(&mut self, size: i32);
+
+    /// Fetch the page these params point at.
+    fn fetch_page<'a>(&'a self, configuration: &'a Configuration) -> BoxFuture<'a, Result<Page<Self>, Error<Self::Error>>>;
+}
+
+/// Stream every item of the list endpoint `params` belong to, starting at
+/// the page they point at.
+pub fn paginate<P: Paginated>(configuration: &Configuration, params: P) -> Paginator<'_, P> {
+    Paginator {
+        state: State::Idle {
+            configuration,
+            params: Box::new(params),
+        },
+    }
+}
+
+/// Stream returned by [`paginate`].
+pub struct Paginator<'a, P: Paginated> {
+    state: State<'a, P>,
+}
+
+enum State<'a, P: Paginated> {
+    Idle { configuration: &'a Configuration, params: Box<P> },
+    Running(BoxStream<'a, Result<P::Item, Error<P::Error>>>),
+    Done,
+}
+
+impl<'a, P: Paginated> Paginator<'a, P> {
+    /// Ask for `size` results per page instead of the endpoint's default.
+    /// Has no effect once the stream has been polled.
+    pub fn page_size(mut self, size: i32) -> Self {
+        if let State::Idle { params, .. } = &mut self.state {
+            params.set_page_size(size);
+        }
+        self
+    }
+}
+
+impl<'a, P: Paginated> Stream for Paginator<'a, P> {
+    type Item = Result<P::Item, Error<P::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let State::Idle { .. } = this.state {
+            let State::Idle { configuration, params } = std::mem::replace(&mut this.state, State::Done) else {
+                unreachable!()
+            };
+            this.state = State::Running(pages(configuration, *params));
+        }
+        match &mut this.state {
+            State::Running(items) => items.poll_next_unpin(cx),
+            _ => Poll::Ready(None),
+        }
+    }
+}
+
+impl<'a, P: Paginated + fmt::Debug> fmt::Debug for Paginator<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.state {
+            State::Idle { params, .. } => f.debug_struct("Paginator").field("params", params).finish(),
+            _ => f.debug_struct("Paginator").finish_non_exhaustive(),
+        }
+    }
+}
+
+fn pages<P: Paginated>(configuration: &Configuration, params: P) -> BoxStream<'_, Result<P::Item, Error<P::Error>>> {
+    stream::try_unfold(Some(params), move |params: Option<P>| async move {
+        let Some(params) = params else { return Ok(None) };
+        let page = params.fetch_page(configuration).await?;
+        Ok::<_, Error<P::Error>>(Some((stream::iter(page.items.into_iter().map(Ok)), page.next)))
+    })
+    .try_flatten()
+    .boxed()
+}
+
+/// `page`/`per_page` over a plain array. Without a page size the walk ends
+/// at the first empty page; with one it also ends at the first short page.
+macro_rules! paged {
+    ($($(#[$attr:meta])* $module:ident :: $function:ident($params:ident) -> Vec<$item:ty>, $error:ident, |$body:ident| $items:expr;)*) => {$(
+        $(#[$attr])*
+        impl Paginated for super::$module::$params {
+            type Item = $item;
+            type Error = super::$module::$error;
+
+            fn set_page_size(&mut self, size: i32) {
+                self.per_page = Some(size);
+            }
+
+            fn fetch_page<'a>(&'a self, configuration: &'a Configuration) -> BoxFuture<'a, Result<Page<Self>, Error<Self::Error>>> {
+                Box::pin(async move {
+                    let $body = super::$module::$function(configuration, self.clone()).await?;
+                    let items: Vec<$item> = $items;
+                    let full = match self.per_page {
+                        Some(per_page) => items.len() >= per_page.max(1) as usize,
+                        None => !items.is_empty(),
+                    };
+                    let next = full.then(|| Self { page: Some(self.page.unwrap_or(1) + 1), ..self.clone() });
+                    Ok(Page { items, next })
+                })
+            }
+        }
+    )*};
+}
+
+/// JSON:API `page[number]`/`page[size]`. The walk goes on while there is a
+/// `links.next`, or `meta.current_page` is below `meta.total_pages`.
+///
+/// The legacy WAF endpoints are declared to return a bare `serde_json::Value`;
+/// their documents are read as a [`JsonApiPage`] and yield the raw items.
+macro_rules! numbered {
+    (@page $self:ident, $response:ident) => {{
+        let items = $response.data.unwrap_or_default();
+        let current = $response.meta.as_ref().and_then(|m| m.current_page).or($self.page_number).unwrap_or(1);
+        let more = $response.links.as_ref().and_then(|l| l.next.as_deref()).is_some_and(|next| !next.is_empty())
+            || $response.meta.as_ref().and_then(|m| m.total_pages).is_some_and(|total| current < total);
+        let next = (more && !items.is_empty()).then(|| Self { page_number: Some(current + 1), ..$self.clone() });
+        Ok(Page { items, next })
+    }};
+    ($($(#[$attr:meta])* $module:ident :: $function:ident($params:ident) -> serde_json::Value, $error:ident;)*) => {$(
+        $(#[$attr])*
+        impl Paginated for super::$module::$params {
+            type Item = serde_json::Value;
+            type Error = super::$module::$error;
+
+            fn set_page_size(&mut self, size: i32) {
+                self.page_size = Some(size);
+            }
+
+            fn fetch_page<'a>(&'a self, configuration: &'a Configuration) -> BoxFuture<'a, Result<Page<Self>, Error<Self::Error>>> {
+                Box::pin(async move {
+                    let document = super::$module::$function(configuration, self.clone()).await?;
+                    let response: JsonApiPage = serde_json::from_value(document).map_err(Error::Serde)?;
+                    numbered!(@page self, response)
+                })
+            }
+        }
+    )*};
+    ($($(#[$attr:meta])* $module:ident :: $function:ident($params:ident) -> $response:ident<$item:ty>, $error:ident;)*) => {$(
+        $(#[$attr])*
+        impl Paginated for super::$module::$params {
+            type Item = $item;
+            type Error = super::$module::$error;
+
+            fn set_page_size(&mut self, size: i32) {
+                self.page_size = Some(size);
+            }
+
+            fn fetch_page<'a>(&'a self, configuration: &'a Configuration) -> BoxFuture<'a, Result<Page<Self>, Error<Self::Error>>> {
+                Box::pin(async move {
+                    let response: crate::models::$response = super::$module::$function(configuration, self.clone()).await?;
+                    numbered!(@page self, response)
+                })
+            }
+        }
+    )*};
+}
+
+/// The parts of an untyped JSON:API document [`numbered!`] looks at.
+#[cfg(feature = "waf-legacy")]
+#[derive(Deserialize)]
+struct JsonApiPage {
+    data: Option<Vec<serde_json::Value>>,
+    links: Option<JsonApiLinks>,
+    meta: Option<JsonApiMeta>,
+}
+
+#[cfg(feature = "waf-legacy")]
+#[derive(Deserialize)]
+struct JsonApiLinks {
+    next: Option<String>,
+}
+
+#[cfg(feature = "waf-legacy")]
+#[derive(Deserialize)]
+struct JsonApiMeta {
+    current_page: Option<i32>,
+    total_pages: Option<i32>,
+}
+
+/// The cursor endpoints disagree on whether `limit` is a number or a string.
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+trait Limit {
+    fn from_page_size(size: i32) -> Self;
+}
+
+impl Limit for i32 {
+    fn from_page_size(size: i32) -> Self {
+        size
+    }
+}
+
+impl Limit for String {
+    fn from_page_size(size: i32) -> Self {
+        size.to_string()
+    }
+}
+
+/// `cursor` in the query and `meta.next_cursor` in the body. The walk ends
+/// when the API stops returning a cursor.
+macro_rules! cursor {
+    ($($(#[$attr:meta])* $module:ident :: $function:ident($params:ident) -> $response:ident<$item:ty>, $error:ident;)*) => {$(
+        $(#[$attr])*
+        impl Paginated for super::$module::$params {
+            type Item = $item;
+            type Error = super::$module::$error;
+
+            fn set_page_size(&mut self, size: i32) {
+                self.limit = Some(Limit::from_page_size(size));
+            }
+
+            fn fetch_page<'a>(&'a self, configuration: &'a Configuration) -> BoxFuture<'a, Result<Page<Self>, Error<Self::Error>>> {
+                Box::pin(async move {
+                    let response: crate::models::$response = super::$module::$function(configuration, self.clone()).await?;
+                    let items = response.data.unwrap_or_default();
+                    let next = response
+                        .meta
+                        .and_then(|m| m.next_cursor)
+                        .filter(|cursor| !cursor.is_empty() && !items.is_empty())
+                        .map(|cursor| Self { cursor: Some(cursor), ..self.clone() });
+                    Ok(Page { items, next })
+                })
+            }
+        }
+    )*};
+}
+
+paged! {
+    #[cfg(feature = "acl")]
+    acl_entry_api::list_acl_entries(ListAclEntriesParams) -> Vec<crate::models::AclEntryResponse>, ListAclEntriesError, |r| r;
+    #[cfg(feature = "accounts")]
+    automation_tokens_api::list_automation_tokens(ListAutomationTokensParams) -> Vec<crate::models::AutomationTokenResponse>, ListAutomationTokensError, |r| r;
+    #[cfg(feature = "accounts")]
+    automation_tokens_api::get_automation_tokens_id_services(GetAutomationTokensIdServicesParams) -> Vec<String>, GetAutomationTokensIdServicesError, |r| r.data.unwrap_or_default();
+    #[cfg(feature = "dictionary")]
+    dictionary_item_api::list_dictionary_items(ListDictionaryItemsParams) -> Vec<crate::models::DictionaryItemResponse>, ListDictionaryItemsError, |r| r;
+    #[cfg(feature = "iam")]
+    iam_roles_api::list_roles(ListRolesParams) -> Vec<serde_json::Value>, ListRolesError, |r| iam_items(r);
+    #[cfg(feature = "iam")]
+    iam_service_groups_api::list_service_groups(ListServiceGroupsParams) -> Vec<serde_json::Value>, ListServiceGroupsError, |r| iam_items(r);
+    #[cfg(feature = "iam")]
+    iam_user_groups_api::list_user_groups(ListUserGroupsParams) -> Vec<serde_json::Value>, ListUserGroupsError, |r| iam_items(r);
+    #[cfg(feature = "iam")]
+    iam_user_groups_api::list_user_group_members(ListUserGroupMembersParams) -> Vec<serde_json::Value>, ListUserGroupMembersError, |r| iam_items(r);
+    service_api::list_services(ListServicesParams) -> Vec<crate::models::ServiceListResponse>, ListServicesError, |r| r;
+}
+
+/// The IAM lists are declared to return a bare `serde_json::Value`: the items
+/// are under `data`, or the whole body for a plain array.
+#[cfg(feature = "iam")]
+fn iam_items(body: serde_json::Value) -> Vec<serde_json::Value> {
+    match body {
+        serde_json::Value::Array(items) => items,
+        serde_json::Value::Object(mut document) => match document.remove("data") {
+            Some(serde_json::Value::Array(items)) => items,
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+numbered! {
+    #[cfg(feature = "accounts")]
+    events_api::list_events(ListEventsParams) -> EventsResponse<crate::models::Event>, ListEventsError;
+    #[cfg(feature = "accounts")]
+    invitations_api::list_invitations(ListInvitationsParams) -> InvitationsResponse<crate::models::InvitationResponseData>, ListInvitationsError;
+    #[cfg(feature = "tls")]
+    mutual_authentication_api::list_mutual_authentications(ListMutualAuthenticationsParams) -> MutualAuthenticationsResponse<crate::models::MutualAuthenticationResponseData>, ListMutualAuthenticationsError;
+    #[cfg(feature = "iam")]
+    service_authorizations_api::list_service_authorization(ListServiceAuthorizationParams) -> ServiceAuthorizationsResponse<crate::models::ServiceAuthorizationResponseData>, ListServiceAuthorizationError;
+    #[cfg(feature = "tls")]
+    tls_activations_api::list_tls_activations(ListTlsActivationsParams) -> TlsActivationsResponse<crate::models::TlsActivationResponseData>, ListTlsActivationsError;
+    #[cfg(feature = "tls")]
+    tls_bulk_certificates_api::list_tls_bulk_certs(ListTlsBulkCertsParams) -> TlsBulkCertificatesResponse<crate::models::TlsBulkCertificateResponseData>, ListTlsBulkCertsError;
+    #[cfg(feature = "tls")]
+    tls_certificates_api::list_tls_certs(ListTlsCertsParams) -> TlsCertificatesResponse<crate::models::TlsCertificateResponseData>, ListTlsCertsError;
+    #[cfg(feature = "tls")]
+    tls_configurations_api::list_tls_configs(ListTlsConfigsParams) -> TlsConfigurationsResponse<crate::models::TlsConfigurationResponseData>, ListTlsConfigsError;
+    #[cfg(feature = "tls")]
+    tls_domains_api::list_tls_domains(ListTlsDomainsParams) -> TlsDomainsResponse<crate::models::TlsDomainData>, ListTlsDomainsError;
+    #[cfg(feature = "tls")]
+    tls_private_keys_api::list_tls_keys(ListTlsKeysParams) -> TlsPrivateKeysResponse<crate::models::TlsPrivateKeyResponseData>, ListTlsKeysError;
+    #[cfg(feature = "tls")]
+    tls_subscriptions_api::list_tls_subs(ListTlsSubsParams) -> TlsSubscriptionsResponse<crate::models::TlsSubscriptionResponse>, ListTlsSubsError;
+    #[cfg(feature = "waf")]
+    waf_active_rules_api::list_waf_active_rules(ListWafActiveRulesParams) -> WafActiveRulesResponse<crate::models::WafActiveRuleResponseData>, ListWafActiveRulesError;
+    #[cfg(feature = "waf")]
+    waf_exclusions_api::list_waf_rule_exclusions(ListWafRuleExclusionsParams) -> WafExclusionsResponse<crate::models::WafExclusionResponseData>, ListWafRuleExclusionsError;
+    #[cfg(feature = "waf")]
+    waf_firewall_versions_api::list_waf_firewall_versions(ListWafFirewallVersionsParams) -> WafFirewallVersionsResponse<crate::models::WafFirewallVersionResponseData>, ListWafFirewallVersionsError;
+    #[cfg(feature = "waf")]
+    waf_firewalls_api::list_waf_firewalls(ListWafFirewallsParams) -> WafFirewallsResponse<crate::models::WafFirewallResponseData>, ListWafFirewallsError;
+    #[cfg(feature = "waf")]
+    waf_rule_revisions_api::list_waf_rule_revisions(ListWafRuleRevisionsParams) -> WafRuleRevisionsResponse<crate::models::WafRuleRevisionResponseData>, ListWafRuleRevisionsError;
+    #[cfg(feature = "waf")]
+    waf_rules_api::list_waf_rules(ListWafRulesParams) -> WafRulesResponse<crate::models::WafRuleResponseData>, ListWafRulesError;
+    #[cfg(feature = "waf")]
+    waf_tags_api::list_waf_tags(ListWafTagsParams) -> WafTagsResponse<crate::models::WafTagsResponseDataItem>, ListWafTagsError;
+}
+
+// `legacy_waf_rule_api::list_legacy_waf_rules` is left out: it is declared to
+// return a bare array, so a page carries no `links` or `meta` to tell whether
+// another one follows.
+numbered! {
+    #[cfg(feature = "waf-legacy")]
+    legacy_waf_firewall_api::list_legacy_waf_firewalls(ListLegacyWafFirewallsParams) -> serde_json::Value, ListLegacyWafFirewallsError;
+    #[cfg(feature = "waf-legacy")]
+    legacy_waf_firewall_api::list_legacy_waf_firewalls_service(ListLegacyWafFirewallsServiceParams) -> serde_json::Value, ListLegacyWafFirewallsServiceError;
+    #[cfg(feature = "waf-legacy")]
+    legacy_waf_rule_status_api::list_waf_firewall_rule_statuses(ListWafFirewallRuleStatusesParams) -> serde_json::Value, ListWafFirewallRuleStatusesError;
+    #[cfg(feature = "waf-legacy")]
+    legacy_waf_tag_api::list_legacy_waf_tags(ListLegacyWafTagsParams) -> serde_json::Value, ListLegacyWafTagsError;
+    #[cfg(feature = "waf-legacy")]
+    legacy_waf_update_status_api::list_waf_update_statuses(ListWafUpdateStatusesParams) -> serde_json::Value, ListWafUpdateStatusesError;
+}
+
+cursor! {
+    #[cfg(feature = "billing")]
+    billing_invoices_api::list_invoices(ListInvoicesParams) -> ListEomInvoicesResponse<crate::models::Invoice>, ListInvoicesError;
+    #[cfg(feature = "stores")]
+    kv_store_api::get_stores(GetStoresParams) -> InlineResponse2003<crate::models::StoreResponse>, GetStoresError;
+    #[cfg(feature = "stores")]
+    kv_store_item_api::get_keys(GetKeysParams) -> InlineResponse2004<String>, GetKeysError;
+    #[cfg(feature = "stores")]
+    secret_store_api::get_secret_stores(GetSecretStoresParams) -> InlineResponse2005<crate::models::SecretStoreResponse>, GetSecretStoresError;
+    #[cfg(feature = "stores")]
+    secret_store_item_api::get_secrets(GetSecretsParams) -> InlineResponse2006<crate::models::SecretResponse>, GetSecretsError;
+    #[cfg(feature = "stats")]
+    domain_inspector_historical_api::get_domain_inspector_historical(GetDomainInspectorHistoricalParams) -> HistoricalDomainsResponse<crate::models::DomainInspectorEntry>, GetDomainInspectorHistoricalError;
+    #[cfg(feature = "stats")]
+    origin_inspector_historical_api::get_origin_inspector_historical(GetOriginInspectorHistoricalParams) -> HistoricalOriginsResponse<crate::models::OriginInspectorEntry>, GetOriginInspectorHistoricalError;
+}
+
+/// Service-level usage has a single `data` object; its `details` are the
+/// items and its `meta.next_cursor` points at the next page.
+#[cfg(feature = "billing")]
+impl Paginated for super::billing_usage_metrics_api::GetServiceLevelUsageParams {
+    type Item = crate::models::Serviceusagemetric;
+    type Error = super::billing_usage_metrics_api::GetServiceLevelUsageError;
+
+    fn set_page_size(&mut self, size: i32) {
+        self.limit = Some(Limit::from_page_size(size));
+    }
+
+    fn fetch_page<'a>(&'a self, configuration: &'a Configuration) -> BoxFuture<'a, Result<Page<Self>, Error<Self::Error>>> {
+        Box::pin(async move {
+            let response = super::billing_usage_metrics_api::get_service_level_usage(configuration, self.clone()).await?;
+            let data = response.data.map(|data| *data).unwrap_or_default();
+            let items = data.details.unwrap_or_default();
+            let next = data
+                .meta
+                .and_then(|m| m.next_cursor)
+                .filter(|cursor| !cursor.is_empty() && !items.is_empty())
+                .map(|cursor| Self { cursor: Some(cursor), ..self.clone() });
+            Ok(Page { items, next })
+        })
+    }
+}
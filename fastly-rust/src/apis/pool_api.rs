@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_server_pool`]
@@ -23,7 +22,7 @@ pub struct CreateServerPoolParams {
     /// The client certificate used to make authenticated requests. Must be in PEM format.
     pub tls_client_cert: Option<String>,
     /// The client private key used to make authenticated requests. Must be in PEM format.
-    pub tls_client_key: Option<String>,
+    pub tls_client_key: Option<crate::apis::redact::Secret>,
     /// The hostname used to verify a server's certificate. It can either be the Common Name (CN) or a Subject Alternative Name (SAN).
     pub tls_cert_hostname: Option<String>,
     /// Whether to use TLS.
@@ -117,7 +116,7 @@ pub struct UpdateServerPoolParams {
     /// The client certificate used to make authenticated requests. Must be in PEM format.
     pub tls_client_cert: Option<String>,
     /// The client private key used to make authenticated requests. Must be in PEM format.
-    pub tls_client_key: Option<String>,
+    pub tls_client_key: Option<crate::apis::redact::Secret>,
     /// The hostname used to verify a server's certificate. It can either be the Common Name (CN) or a Subject Alternative Name (SAN).
     pub tls_cert_hostname: Option<String>,
     /// Whether to use TLS.
@@ -204,9 +203,17 @@ pub enum UpdateServerPoolError {
 
 
 /// Creates a pool for a particular service and version.
-pub async fn create_server_pool(configuration: &mut configuration::Configuration, params: CreateServerPoolParams) -> Result<crate::models::PoolResponsePost, Error<CreateServerPoolError>> {
-    let local_var_configuration = configuration;
+pub async fn create_server_pool(configuration: &configuration::Configuration, params: CreateServerPoolParams) -> Result<crate::models::PoolResponsePost, Error<CreateServerPoolError>> {
+    create_server_pool_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_server_pool`], but also returns the status and headers of the response.
+pub async fn create_server_pool_with_info(configuration: &configuration::Configuration, params: CreateServerPoolParams) -> Result<crate::apis::Response<crate::models::PoolResponsePost>, Error<CreateServerPoolError>> {
+    crate::apis::execute(configuration, create_server_pool_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_server_pool`] sends.
+pub(crate) fn create_server_pool_request(params: CreateServerPoolParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -238,24 +245,10 @@ pub async fn create_server_pool(configuration: &mut configuration::Configuration
     let quorum = params.quorum;
     let tls_check_cert = params.tls_check_cert;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/pool", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_server_pool", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/pool", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = tls_ca_cert {
         local_var_form_params.insert("tls_ca_cert", local_var_param_value.to_string());
     }
@@ -263,7 +256,7 @@ pub async fn create_server_pool(configuration: &mut configuration::Configuration
         local_var_form_params.insert("tls_client_cert", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = tls_client_key {
-        local_var_form_params.insert("tls_client_key", local_var_param_value.to_string());
+        local_var_form_params.insert("tls_client_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = tls_cert_hostname {
         local_var_form_params.insert("tls_cert_hostname", local_var_param_value.to_string());
@@ -337,200 +330,85 @@ pub async fn create_server_pool(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = tls_check_cert {
         local_var_form_params.insert("tls_check_cert", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateServerPoolError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Deletes a specific pool for a particular service and version.
-pub async fn delete_server_pool(configuration: &mut configuration::Configuration, params: DeleteServerPoolParams) -> Result<crate::models::InlineResponse200, Error<DeleteServerPoolError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_server_pool(configuration: &configuration::Configuration, params: DeleteServerPoolParams) -> Result<crate::models::InlineResponse200, Error<DeleteServerPoolError>> {
+    delete_server_pool_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_server_pool`], but also returns the status and headers of the response.
+pub async fn delete_server_pool_with_info(configuration: &configuration::Configuration, params: DeleteServerPoolParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteServerPoolError>> {
+    crate::apis::execute(configuration, delete_server_pool_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_server_pool`] sends.
+pub(crate) fn delete_server_pool_request(params: DeleteServerPoolParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let pool_name = params.pool_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/pool/{pool_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, pool_name=crate::apis::urlencode(pool_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteServerPoolError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/pool/{pool_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, pool_name=crate::apis::urlencode(pool_name));
+    crate::apis::Request::new("delete_server_pool", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Gets a single pool for a particular service and version.
-pub async fn get_server_pool(configuration: &mut configuration::Configuration, params: GetServerPoolParams) -> Result<crate::models::PoolResponse, Error<GetServerPoolError>> {
-    let local_var_configuration = configuration;
+pub async fn get_server_pool(configuration: &configuration::Configuration, params: GetServerPoolParams) -> Result<crate::models::PoolResponse, Error<GetServerPoolError>> {
+    get_server_pool_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_server_pool`], but also returns the status and headers of the response.
+pub async fn get_server_pool_with_info(configuration: &configuration::Configuration, params: GetServerPoolParams) -> Result<crate::apis::Response<crate::models::PoolResponse>, Error<GetServerPoolError>> {
+    crate::apis::execute(configuration, get_server_pool_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_server_pool`] sends.
+pub(crate) fn get_server_pool_request(params: GetServerPoolParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let pool_name = params.pool_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/pool/{pool_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, pool_name=crate::apis::urlencode(pool_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServerPoolError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/pool/{pool_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, pool_name=crate::apis::urlencode(pool_name));
+    crate::apis::Request::new("get_server_pool", reqwest::Method::GET, local_var_path)
 }
 
 /// Lists all pools for a particular service and pool.
-pub async fn list_server_pools(configuration: &mut configuration::Configuration, params: ListServerPoolsParams) -> Result<Vec<crate::models::PoolResponse>, Error<ListServerPoolsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_server_pools(configuration: &configuration::Configuration, params: ListServerPoolsParams) -> Result<Vec<crate::models::PoolResponse>, Error<ListServerPoolsError>> {
+    list_server_pools_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_server_pools`], but also returns the status and headers of the response.
+pub async fn list_server_pools_with_info(configuration: &configuration::Configuration, params: ListServerPoolsParams) -> Result<crate::apis::Response<Vec<crate::models::PoolResponse>>, Error<ListServerPoolsError>> {
+    crate::apis::execute(configuration, list_server_pools_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_server_pools`] sends.
+pub(crate) fn list_server_pools_request(params: ListServerPoolsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/pool", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListServerPoolsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/pool", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_server_pools", reqwest::Method::GET, local_var_path)
 }
 
 /// Updates a specific pool for a particular service and version.
-pub async fn update_server_pool(configuration: &mut configuration::Configuration, params: UpdateServerPoolParams) -> Result<crate::models::PoolResponse, Error<UpdateServerPoolError>> {
-    let local_var_configuration = configuration;
+pub async fn update_server_pool(configuration: &configuration::Configuration, params: UpdateServerPoolParams) -> Result<crate::models::PoolResponse, Error<UpdateServerPoolError>> {
+    update_server_pool_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_server_pool`], but also returns the status and headers of the response.
+pub async fn update_server_pool_with_info(configuration: &configuration::Configuration, params: UpdateServerPoolParams) -> Result<crate::apis::Response<crate::models::PoolResponse>, Error<UpdateServerPoolError>> {
+    crate::apis::execute(configuration, update_server_pool_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_server_pool`] sends.
+pub(crate) fn update_server_pool_request(params: UpdateServerPoolParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -563,24 +441,10 @@ pub async fn update_server_pool(configuration: &mut configuration::Configuration
     let quorum = params.quorum;
     let tls_check_cert = params.tls_check_cert;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/pool/{pool_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, pool_name=crate::apis::urlencode(pool_name));
+    let mut local_var_req = crate::apis::Request::new("update_server_pool", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/pool/{pool_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, pool_name=crate::apis::urlencode(pool_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = tls_ca_cert {
         local_var_form_params.insert("tls_ca_cert", local_var_param_value.to_string());
     }
@@ -588,7 +452,7 @@ pub async fn update_server_pool(configuration: &mut configuration::Configuration
         local_var_form_params.insert("tls_client_cert", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = tls_client_key {
-        local_var_form_params.insert("tls_client_key", local_var_param_value.to_string());
+        local_var_form_params.insert("tls_client_key", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = tls_cert_hostname {
         local_var_form_params.insert("tls_cert_hostname", local_var_param_value.to_string());
@@ -662,32 +526,8 @@ pub async fn update_server_pool(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = tls_check_cert {
         local_var_form_params.insert("tls_check_cert", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateServerPoolError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`bulk_purge_tag`]
@@ -81,229 +80,106 @@ pub enum PurgeTagError {
 
 
 /// Instant Purge a particular service of items tagged with surrogate keys. Up to 256 surrogate keys can be purged in one batch request. As an alternative to sending the keys in a JSON object in the body of the request, this endpoint also supports listing keys in a <code>Surrogate-Key</code> request header, e.g. <code>Surrogate-Key: key_1 key_2 key_3</code>. 
-pub async fn bulk_purge_tag(configuration: &mut configuration::Configuration, params: BulkPurgeTagParams) -> Result<::std::collections::HashMap<String, String>, Error<BulkPurgeTagError>> {
-    let local_var_configuration = configuration;
+pub async fn bulk_purge_tag(configuration: &configuration::Configuration, params: BulkPurgeTagParams) -> Result<::std::collections::HashMap<String, String>, Error<BulkPurgeTagError>> {
+    bulk_purge_tag_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`bulk_purge_tag`], but also returns the status and headers of the response.
+pub async fn bulk_purge_tag_with_info(configuration: &configuration::Configuration, params: BulkPurgeTagParams) -> Result<crate::apis::Response<::std::collections::HashMap<String, String>>, Error<BulkPurgeTagError>> {
+    crate::apis::execute(configuration, bulk_purge_tag_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`bulk_purge_tag`] sends.
+pub(crate) fn bulk_purge_tag_request(params: BulkPurgeTagParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let fastly_soft_purge = params.fastly_soft_purge;
     let surrogate_key = params.surrogate_key;
     let purge_response = params.purge_response;
 
+    let local_var_path = format!("/service/{service_id}/purge", service_id=crate::apis::urlencode(service_id));
+    let mut local_var_req = crate::apis::Request::new("bulk_purge_tag", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/purge", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
     if let Some(local_var_param_value) = fastly_soft_purge {
-        local_var_req_builder = local_var_req_builder.header("fastly-soft-purge", local_var_param_value.to_string());
+        local_var_req = local_var_req.header("fastly-soft-purge", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = surrogate_key {
-        local_var_req_builder = local_var_req_builder.header("surrogate-key", local_var_param_value.to_string());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&purge_response);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.header("surrogate-key", local_var_param_value.to_string());
     }
+    local_var_req = local_var_req.json(serde_json::to_value(&purge_response)?);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<BulkPurgeTagError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Instant Purge everything from a service.  Purge-all requests cannot be done in soft mode and will always immediately invalidate all cached content associated with the service. To do a soft-purge-all, consider applying a constant [surrogate key](https://docs.fastly.com/en/guides/getting-started-with-surrogate-keys) tag (e.g., `\"all\"`) to all objects. 
-pub async fn purge_all(configuration: &mut configuration::Configuration, params: PurgeAllParams) -> Result<crate::models::InlineResponse200, Error<PurgeAllError>> {
-    let local_var_configuration = configuration;
+pub async fn purge_all(configuration: &configuration::Configuration, params: PurgeAllParams) -> Result<crate::models::InlineResponse200, Error<PurgeAllError>> {
+    purge_all_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`purge_all`], but also returns the status and headers of the response.
+pub async fn purge_all_with_info(configuration: &configuration::Configuration, params: PurgeAllParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<PurgeAllError>> {
+    crate::apis::execute(configuration, purge_all_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`purge_all`] sends.
+pub(crate) fn purge_all_request(params: PurgeAllParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/purge_all", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<PurgeAllError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/purge_all", service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("purge_all", reqwest::Method::POST, local_var_path)
 }
 
 /// Instant Purge an individual URL.
-pub async fn purge_single_url(configuration: &mut configuration::Configuration, params: PurgeSingleUrlParams) -> Result<crate::models::PurgeResponse, Error<PurgeSingleUrlError>> {
-    let local_var_configuration = configuration;
+pub async fn purge_single_url(configuration: &configuration::Configuration, params: PurgeSingleUrlParams) -> Result<crate::models::PurgeResponse, Error<PurgeSingleUrlError>> {
+    purge_single_url_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`purge_single_url`], but also returns the status and headers of the response.
+pub async fn purge_single_url_with_info(configuration: &configuration::Configuration, params: PurgeSingleUrlParams) -> Result<crate::apis::Response<crate::models::PurgeResponse>, Error<PurgeSingleUrlError>> {
+    crate::apis::execute(configuration, purge_single_url_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`purge_single_url`] sends.
+pub(crate) fn purge_single_url_request(params: PurgeSingleUrlParams) -> crate::apis::Request {
     // unbox the parameters
     let cached_url = params.cached_url;
     let fastly_soft_purge = params.fastly_soft_purge;
 
+    let local_var_path = format!("/purge/{cached_url}", cached_url=cached_url);
+    let mut local_var_req = crate::apis::Request::new("purge_single_url", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/purge/{cached_url}", local_var_configuration.base_path, cached_url=cached_url);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
     if let Some(local_var_param_value) = fastly_soft_purge {
-        local_var_req_builder = local_var_req_builder.header("fastly-soft-purge", local_var_param_value.to_string());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.header("fastly-soft-purge", local_var_param_value.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<PurgeSingleUrlError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Instant Purge a particular service of items tagged with a Surrogate Key. Only one surrogate key can be purged at a time. Multiple keys can be purged using a batch surrogate key purge request.
-pub async fn purge_tag(configuration: &mut configuration::Configuration, params: PurgeTagParams) -> Result<crate::models::PurgeResponse, Error<PurgeTagError>> {
-    let local_var_configuration = configuration;
+pub async fn purge_tag(configuration: &configuration::Configuration, params: PurgeTagParams) -> Result<crate::models::PurgeResponse, Error<PurgeTagError>> {
+    purge_tag_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`purge_tag`], but also returns the status and headers of the response.
+pub async fn purge_tag_with_info(configuration: &configuration::Configuration, params: PurgeTagParams) -> Result<crate::apis::Response<crate::models::PurgeResponse>, Error<PurgeTagError>> {
+    crate::apis::execute(configuration, purge_tag_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`purge_tag`] sends.
+pub(crate) fn purge_tag_request(params: PurgeTagParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let surrogate_key = params.surrogate_key;
     let fastly_soft_purge = params.fastly_soft_purge;
 
+    let local_var_path = format!("/service/{service_id}/purge/{surrogate_key}", service_id=crate::apis::urlencode(service_id), surrogate_key=surrogate_key);
+    let mut local_var_req = crate::apis::Request::new("purge_tag", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/purge/{surrogate_key}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), surrogate_key=surrogate_key);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
     if let Some(local_var_param_value) = fastly_soft_purge {
-        local_var_req_builder = local_var_req_builder.header("fastly-soft-purge", local_var_param_value.to_string());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.header("fastly-soft-purge", local_var_param_value.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<PurgeTagError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_rate_limiter`]
@@ -132,9 +131,17 @@ pub enum UpdateRateLimiterError {
 
 
 /// Create a rate limiter for a particular service and version.
-pub async fn create_rate_limiter(configuration: &mut configuration::Configuration, params: CreateRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<CreateRateLimiterError>> {
-    let local_var_configuration = configuration;
+pub async fn create_rate_limiter(configuration: &configuration::Configuration, params: CreateRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<CreateRateLimiterError>> {
+    create_rate_limiter_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_rate_limiter`], but also returns the status and headers of the response.
+pub async fn create_rate_limiter_with_info(configuration: &configuration::Configuration, params: CreateRateLimiterParams) -> Result<crate::apis::Response<crate::models::RateLimiterResponse>, Error<CreateRateLimiterError>> {
+    crate::apis::execute(configuration, create_rate_limiter_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_rate_limiter`] sends.
+pub(crate) fn create_rate_limiter_request(params: CreateRateLimiterParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -150,24 +157,10 @@ pub async fn create_rate_limiter(configuration: &mut configuration::Configuratio
     let logger_type = params.logger_type;
     let feature_revision = params.feature_revision;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/rate-limiters", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_rate_limiter", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/rate-limiters", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -175,7 +168,7 @@ pub async fn create_rate_limiter(configuration: &mut configuration::Configuratio
         local_var_form_params.insert("uri_dictionary_name", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = http_methods {
-        local_var_form_params.insert("http_methods", local_var_param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string());
+        local_var_form_params.insert_array("http_methods", local_var_param_value);
     }
     if let Some(local_var_param_value) = rps_limit {
         local_var_form_params.insert("rps_limit", local_var_param_value.to_string());
@@ -184,7 +177,7 @@ pub async fn create_rate_limiter(configuration: &mut configuration::Configuratio
         local_var_form_params.insert("window_size", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = client_key {
-        local_var_form_params.insert("client_key", local_var_param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string());
+        local_var_form_params.insert_array("client_key", local_var_param_value);
     }
     if let Some(local_var_param_value) = penalty_box_duration {
         local_var_form_params.insert("penalty_box_duration", local_var_param_value.to_string());
@@ -201,196 +194,81 @@ pub async fn create_rate_limiter(configuration: &mut configuration::Configuratio
     if let Some(local_var_param_value) = feature_revision {
         local_var_form_params.insert("feature_revision", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateRateLimiterError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a rate limiter by its ID.
-pub async fn delete_rate_limiter(configuration: &mut configuration::Configuration, params: DeleteRateLimiterParams) -> Result<crate::models::InlineResponse200, Error<DeleteRateLimiterError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_rate_limiter(configuration: &configuration::Configuration, params: DeleteRateLimiterParams) -> Result<crate::models::InlineResponse200, Error<DeleteRateLimiterError>> {
+    delete_rate_limiter_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_rate_limiter`], but also returns the status and headers of the response.
+pub async fn delete_rate_limiter_with_info(configuration: &configuration::Configuration, params: DeleteRateLimiterParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteRateLimiterError>> {
+    crate::apis::execute(configuration, delete_rate_limiter_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_rate_limiter`] sends.
+pub(crate) fn delete_rate_limiter_request(params: DeleteRateLimiterParams) -> crate::apis::Request {
     // unbox the parameters
     let rate_limiter_id = params.rate_limiter_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/rate-limiters/{rate_limiter_id}", local_var_configuration.base_path, rate_limiter_id=crate::apis::urlencode(rate_limiter_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteRateLimiterError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/rate-limiters/{rate_limiter_id}", rate_limiter_id=crate::apis::urlencode(rate_limiter_id));
+    crate::apis::Request::new("delete_rate_limiter", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a rate limiter by its ID.
-pub async fn get_rate_limiter(configuration: &mut configuration::Configuration, params: GetRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<GetRateLimiterError>> {
-    let local_var_configuration = configuration;
+pub async fn get_rate_limiter(configuration: &configuration::Configuration, params: GetRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<GetRateLimiterError>> {
+    get_rate_limiter_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_rate_limiter`], but also returns the status and headers of the response.
+pub async fn get_rate_limiter_with_info(configuration: &configuration::Configuration, params: GetRateLimiterParams) -> Result<crate::apis::Response<crate::models::RateLimiterResponse>, Error<GetRateLimiterError>> {
+    crate::apis::execute(configuration, get_rate_limiter_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_rate_limiter`] sends.
+pub(crate) fn get_rate_limiter_request(params: GetRateLimiterParams) -> crate::apis::Request {
     // unbox the parameters
     let rate_limiter_id = params.rate_limiter_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/rate-limiters/{rate_limiter_id}", local_var_configuration.base_path, rate_limiter_id=crate::apis::urlencode(rate_limiter_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetRateLimiterError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/rate-limiters/{rate_limiter_id}", rate_limiter_id=crate::apis::urlencode(rate_limiter_id));
+    crate::apis::Request::new("get_rate_limiter", reqwest::Method::GET, local_var_path)
 }
 
 /// List all rate limiters for a particular service and version.
-pub async fn list_rate_limiters(configuration: &mut configuration::Configuration, params: ListRateLimitersParams) -> Result<Vec<crate::models::RateLimiterResponse>, Error<ListRateLimitersError>> {
-    let local_var_configuration = configuration;
+pub async fn list_rate_limiters(configuration: &configuration::Configuration, params: ListRateLimitersParams) -> Result<Vec<crate::models::RateLimiterResponse>, Error<ListRateLimitersError>> {
+    list_rate_limiters_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_rate_limiters`], but also returns the status and headers of the response.
+pub async fn list_rate_limiters_with_info(configuration: &configuration::Configuration, params: ListRateLimitersParams) -> Result<crate::apis::Response<Vec<crate::models::RateLimiterResponse>>, Error<ListRateLimitersError>> {
+    crate::apis::execute(configuration, list_rate_limiters_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_rate_limiters`] sends.
+pub(crate) fn list_rate_limiters_request(params: ListRateLimitersParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/rate-limiters", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListRateLimitersError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/rate-limiters", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_rate_limiters", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a rate limiter by its ID.
-pub async fn update_rate_limiter(configuration: &mut configuration::Configuration, params: UpdateRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<UpdateRateLimiterError>> {
-    let local_var_configuration = configuration;
+pub async fn update_rate_limiter(configuration: &configuration::Configuration, params: UpdateRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<UpdateRateLimiterError>> {
+    update_rate_limiter_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_rate_limiter`], but also returns the status and headers of the response.
+pub async fn update_rate_limiter_with_info(configuration: &configuration::Configuration, params: UpdateRateLimiterParams) -> Result<crate::apis::Response<crate::models::RateLimiterResponse>, Error<UpdateRateLimiterError>> {
+    crate::apis::execute(configuration, update_rate_limiter_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_rate_limiter`] sends.
+pub(crate) fn update_rate_limiter_request(params: UpdateRateLimiterParams) -> crate::apis::Request {
     // unbox the parameters
     let rate_limiter_id = params.rate_limiter_id;
     let name = params.name;
@@ -405,24 +283,10 @@ pub async fn update_rate_limiter(configuration: &mut configuration::Configuratio
     let logger_type = params.logger_type;
     let feature_revision = params.feature_revision;
 
+    let local_var_path = format!("/rate-limiters/{rate_limiter_id}", rate_limiter_id=crate::apis::urlencode(rate_limiter_id));
+    let mut local_var_req = crate::apis::Request::new("update_rate_limiter", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/rate-limiters/{rate_limiter_id}", local_var_configuration.base_path, rate_limiter_id=crate::apis::urlencode(rate_limiter_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -430,7 +294,7 @@ pub async fn update_rate_limiter(configuration: &mut configuration::Configuratio
         local_var_form_params.insert("uri_dictionary_name", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = http_methods {
-        local_var_form_params.insert("http_methods", local_var_param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string());
+        local_var_form_params.insert_array("http_methods", local_var_param_value);
     }
     if let Some(local_var_param_value) = rps_limit {
         local_var_form_params.insert("rps_limit", local_var_param_value.to_string());
@@ -439,7 +303,7 @@ pub async fn update_rate_limiter(configuration: &mut configuration::Configuratio
         local_var_form_params.insert("window_size", local_var_param_value.to_string());
     }
     if let Some(local_var_param_value) = client_key {
-        local_var_form_params.insert("client_key", local_var_param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string());
+        local_var_form_params.insert_array("client_key", local_var_param_value);
     }
     if let Some(local_var_param_value) = penalty_box_duration {
         local_var_form_params.insert("penalty_box_duration", local_var_param_value.to_string());
@@ -456,32 +320,8 @@ pub async fn update_rate_limiter(configuration: &mut configuration::Configuratio
     if let Some(local_var_param_value) = feature_revision {
         local_var_form_params.insert("feature_revision", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateRateLimiterError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -0,0 +1,93 @@
+//! Calling endpoints this crate does not have a function for yet.
+//!
+//! [`request`] sends an arbitrary request through the same plumbing as the
+//! generated endpoint functions: credentials, retries, throttling, rate-limit
+//! tracking, tracing and error classification all apply. The response is
+//! returned as untyped JSON.
+//!
+//! ```no_run
+//! # use fastly_api::apis::configuration::Configuration;
+//! # use fastly_api::apis::raw::{self, Body};
+//! # async fn run(cfg: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+//! let response = raw::request(cfg, reqwest::Method::GET, "/resources/new-thing", &[("page", "2")], Body::Empty).await?;
+//! println!("{} {:?}: {}", response.status, response.request_id(), response.entity);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::configuration::Configuration;
+use super::{AuthScheme, Error, FormParams, MultipartParams, Request, RequestBody, Response};
+
+/// Body of a [`request`].
+#[derive(Debug, Clone, Default)]
+pub enum Body {
+    #[default]
+    Empty,
+    /// Sent as `application/x-www-form-urlencoded`.
+    Form(FormParams),
+    /// Sent as `application/json`.
+    Json(serde_json::Value),
+    /// Sent as `multipart/form-data`.
+    Multipart(MultipartParams),
+}
+
+impl From<FormParams> for Body {
+    fn from(params: FormParams) -> Body {
+        Body::Form(params)
+    }
+}
+
+impl From<serde_json::Value> for Body {
+    fn from(value: serde_json::Value) -> Body {
+        Body::Json(value)
+    }
+}
+
+impl From<MultipartParams> for Body {
+    fn from(params: MultipartParams) -> Body {
+        Body::Multipart(params)
+    }
+}
+
+/// Send `method path?query` with `body` and return the response as JSON.
+///
+/// `path` is relative to `configuration.base_path` and must already be
+/// percent-encoded; `query` pairs are encoded here. The request is
+/// authenticated with whichever credential the configuration holds.
+///
+/// On success, `entity` holds the parsed body: `Null` when the body is empty,
+/// and the body verbatim as a JSON string when it is not JSON. Error statuses
+/// become [`Error::ResponseError`] exactly as for the generated functions.
+///
+/// Successes come back as a [`Response`], the type the `_with_info` endpoint
+/// functions return, rather than a [`ResponseContent`](super::ResponseContent).
+/// The latter describes failed responses: its `entity` is an optional typed
+/// error and it carries the parsed [`ApiError`](super::ApiError). Both hold
+/// the status, and `Response` adds the headers, such as `Fastly-Request-ID`.
+#[allow(clippy::result_large_err)]
+pub async fn request(
+    configuration: &Configuration,
+    method: reqwest::Method,
+    path: &str,
+    query: &[(&str, &str)],
+    body: Body,
+) -> Result<Response<serde_json::Value>, Error<serde_json::Value>> {
+    let mut local_var_req = Request::new("raw_request", method, path.to_owned()).auth(&[AuthScheme::Token, AuthScheme::Basic]);
+    for (local_var_key, local_var_value) in query {
+        local_var_req = local_var_req.query(local_var_key, (*local_var_value).to_owned());
+    }
+    local_var_req.body = match body {
+        Body::Empty => RequestBody::Empty,
+        Body::Form(local_var_form) => RequestBody::Form(local_var_form),
+        Body::Json(local_var_json) => RequestBody::Json(local_var_json),
+        Body::Multipart(local_var_form) => RequestBody::Multipart(local_var_form),
+    };
+
+    let local_var_resp = super::execute(configuration, local_var_req).await?;
+    let local_var_entity = if local_var_resp.content.trim().is_empty() {
+        serde_json::Value::Null
+    } else {
+        serde_json::from_str(&local_var_resp.content).unwrap_or(serde_json::Value::String(local_var_resp.content))
+    };
+    Ok(Response { status: local_var_resp.status, headers: local_var_resp.headers, entity: local_var_entity })
+}
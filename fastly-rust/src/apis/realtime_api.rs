@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_stats_last120_seconds`]
@@ -60,160 +59,61 @@ pub enum GetStatsLastSecondError {
 
 
 /// Get data for the 120 seconds preceding the latest timestamp available for a service.
-pub async fn get_stats_last120_seconds(configuration: &mut configuration::Configuration, params: GetStatsLast120SecondsParams) -> Result<crate::models::Realtime, Error<GetStatsLast120SecondsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_stats_last120_seconds(configuration: &configuration::Configuration, params: GetStatsLast120SecondsParams) -> Result<crate::models::Realtime, Error<GetStatsLast120SecondsError>> {
+    get_stats_last120_seconds_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_stats_last120_seconds`], but also returns the status and headers of the response.
+pub async fn get_stats_last120_seconds_with_info(configuration: &configuration::Configuration, params: GetStatsLast120SecondsParams) -> Result<crate::apis::Response<crate::models::Realtime>, Error<GetStatsLast120SecondsError>> {
+    crate::apis::execute(configuration, get_stats_last120_seconds_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_stats_last120_seconds`] sends.
+pub(crate) fn get_stats_last120_seconds_request(params: GetStatsLast120SecondsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v1/channel/{service_id}/ts/h", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetStatsLast120SecondsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/v1/channel/{service_id}/ts/h", service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("get_stats_last120_seconds", reqwest::Method::GET, local_var_path).group(crate::apis::configuration::EndpointGroup::Realtime)
 }
 
 /// Get data for the 120 seconds preceding the latest timestamp available for a service, up to a maximum of `max_entries` entries.
-pub async fn get_stats_last120_seconds_limit_entries(configuration: &mut configuration::Configuration, params: GetStatsLast120SecondsLimitEntriesParams) -> Result<crate::models::Realtime, Error<GetStatsLast120SecondsLimitEntriesError>> {
-    let local_var_configuration = configuration;
+pub async fn get_stats_last120_seconds_limit_entries(configuration: &configuration::Configuration, params: GetStatsLast120SecondsLimitEntriesParams) -> Result<crate::models::Realtime, Error<GetStatsLast120SecondsLimitEntriesError>> {
+    get_stats_last120_seconds_limit_entries_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_stats_last120_seconds_limit_entries`], but also returns the status and headers of the response.
+pub async fn get_stats_last120_seconds_limit_entries_with_info(configuration: &configuration::Configuration, params: GetStatsLast120SecondsLimitEntriesParams) -> Result<crate::apis::Response<crate::models::Realtime>, Error<GetStatsLast120SecondsLimitEntriesError>> {
+    crate::apis::execute(configuration, get_stats_last120_seconds_limit_entries_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_stats_last120_seconds_limit_entries`] sends.
+pub(crate) fn get_stats_last120_seconds_limit_entries_request(params: GetStatsLast120SecondsLimitEntriesParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let max_entries = params.max_entries;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v1/channel/{service_id}/ts/h/limit/{max_entries}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), max_entries=max_entries);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetStatsLast120SecondsLimitEntriesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/v1/channel/{service_id}/ts/h/limit/{max_entries}", service_id=crate::apis::urlencode(service_id), max_entries=max_entries);
+    crate::apis::Request::new("get_stats_last120_seconds_limit_entries", reqwest::Method::GET, local_var_path).group(crate::apis::configuration::EndpointGroup::Realtime)
 }
 
 /// Get real-time data for the specified reporting period. Specify `0` to get a single entry for the last complete second. The `Timestamp` field included in the response provides the time index of the latest entry in the dataset and can be provided as the `start_timestamp` of the next request for a seamless continuation of the dataset from one request to the next.
-pub async fn get_stats_last_second(configuration: &mut configuration::Configuration, params: GetStatsLastSecondParams) -> Result<crate::models::Realtime, Error<GetStatsLastSecondError>> {
-    let local_var_configuration = configuration;
+pub async fn get_stats_last_second(configuration: &configuration::Configuration, params: GetStatsLastSecondParams) -> Result<crate::models::Realtime, Error<GetStatsLastSecondError>> {
+    get_stats_last_second_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_stats_last_second`], but also returns the status and headers of the response.
+pub async fn get_stats_last_second_with_info(configuration: &configuration::Configuration, params: GetStatsLastSecondParams) -> Result<crate::apis::Response<crate::models::Realtime>, Error<GetStatsLastSecondError>> {
+    crate::apis::execute(configuration, get_stats_last_second_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_stats_last_second`] sends.
+pub(crate) fn get_stats_last_second_request(params: GetStatsLastSecondParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let timestamp_in_seconds = params.timestamp_in_seconds;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v1/channel/{service_id}/ts/{timestamp_in_seconds}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), timestamp_in_seconds=timestamp_in_seconds);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetStatsLastSecondError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/v1/channel/{service_id}/ts/{timestamp_in_seconds}", service_id=crate::apis::urlencode(service_id), timestamp_in_seconds=timestamp_in_seconds);
+    crate::apis::Request::new("get_stats_last_second", reqwest::Method::GET, local_var_path).group(crate::apis::configuration::EndpointGroup::Realtime)
 }
 
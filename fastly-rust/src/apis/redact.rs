@@ -0,0 +1,139 @@
+//! Redaction of credentials and secret fields in logged requests.
+
+/// Placeholder printed instead of a secret value.
+pub const REDACTED: &str = "***";
+
+/// Header names that carry credentials.
+const SECRET_HEADERS: &[&str] = &["fastly-key", "authorization", "proxy-authorization", "cookie", "set-cookie"];
+
+/// Request and model field names whose values are credentials or private keys.
+const SECRET_FIELDS: &[&str] = &[
+    "access_key",
+    "access_token",
+    "account_key",
+    "api_key",
+    "client_secret",
+    "new_password",
+    "old_password",
+    "password",
+    "private_key",
+    "refresh_token",
+    "sas_token",
+    "secret_key",
+    "signing_key",
+    "ssl_client_key",
+    "tls_client_key",
+];
+
+/// Field names too generic to redact everywhere, such as a dictionary item's
+/// `key` or the syslog `token` prefix, paired with the endpoints on which they
+/// do hold a credential or private key.
+const ENDPOINT_SECRET_FIELDS: &[(&str, &str)] = &[
+    ("/logging/datadog", "token"),
+    ("/logging/heroku", "token"),
+    ("/logging/honeycomb", "token"),
+    ("/logging/logentries", "token"),
+    ("/logging/loggly", "token"),
+    ("/logging/logshuttle", "token"),
+    ("/logging/newrelic", "token"),
+    ("/logging/newrelicotlp", "token"),
+    ("/logging/scalyr", "token"),
+    ("/logging/splunk", "token"),
+    ("/resources/stores/secret", "secret"),
+    ("/tls/private_keys", "key"),
+];
+
+/// Whether a header, query, form or JSON field called `name` holds a secret
+/// in a request to, or a response from, `path`.
+///
+/// Array form fields (`name[]`) are matched by their base name.
+pub fn is_secret(path: &str, name: &str) -> bool {
+    let name = name.trim_end_matches("[]");
+    SECRET_HEADERS.iter().chain(SECRET_FIELDS).any(|s| s.eq_ignore_ascii_case(name))
+        || ENDPOINT_SECRET_FIELDS
+            .iter()
+            .any(|(endpoint, field)| field.eq_ignore_ascii_case(name) && on_endpoint(path, endpoint))
+}
+
+/// `value`, or [`REDACTED`] if `name` holds a secret on `path`.
+pub fn value<'a>(path: &str, name: &str, value: &'a str) -> &'a str {
+    if is_secret(path, name) {
+        REDACTED
+    } else {
+        value
+    }
+}
+
+/// A copy of `json` with the values of secret fields on `path` replaced, at
+/// any depth.
+pub fn json(path: &str, json: &serde_json::Value) -> serde_json::Value {
+    match json {
+        serde_json::Value::Object(fields) => fields
+            .iter()
+            .map(|(k, v)| {
+                let v = if is_secret(path, k) && !v.is_null() {
+                    serde_json::Value::String(REDACTED.to_owned())
+                } else {
+                    self::json(path, v)
+                };
+                (k.clone(), v)
+            })
+            .collect(),
+        serde_json::Value::Array(items) => items.iter().map(|item| self::json(path, item)).collect(),
+        other => other.clone(),
+    }
+}
+
+/// Whether `path` is `endpoint` or below it, so `/logging/newrelic` does not
+/// match `/logging/newrelicotlp`.
+fn on_endpoint(path: &str, endpoint: &str) -> bool {
+    path.match_indices(endpoint)
+        .any(|(i, _)| matches!(path[i + endpoint.len()..].chars().next(), None | Some('/')))
+}
+
+/// A credential or private key that must not end up in logs.
+///
+/// `Debug` and `Display` print [`REDACTED`]; call [`Secret::expose`] to get
+/// at the value. It serializes as the bare value, so models using it keep the
+/// same wire format.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Secret<T = String>(T);
+
+impl<T> Secret<T> {
+    pub fn new(value: T) -> Secret<T> {
+        Secret(value)
+    }
+
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Secret<T> {
+        Secret(value)
+    }
+}
+
+impl From<&str> for Secret<String> {
+    fn from(value: &str) -> Secret<String> {
+        Secret(value.to_owned())
+    }
+}
+
+impl<T> std::fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl<T> std::fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
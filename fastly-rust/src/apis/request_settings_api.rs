@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_request_settings`]
@@ -124,223 +123,99 @@ pub enum UpdateRequestSettingsError {
 
 
 /// Creates a new Request Settings object.
-pub async fn create_request_settings(configuration: &mut configuration::Configuration, params: CreateRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<CreateRequestSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn create_request_settings(configuration: &configuration::Configuration, params: CreateRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<CreateRequestSettingsError>> {
+    create_request_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_request_settings`], but also returns the status and headers of the response.
+pub async fn create_request_settings_with_info(configuration: &configuration::Configuration, params: CreateRequestSettingsParams) -> Result<crate::apis::Response<crate::models::RequestSettingsResponse>, Error<CreateRequestSettingsError>> {
+    crate::apis::execute(configuration, create_request_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_request_settings`] sends.
+pub(crate) fn create_request_settings_request(params: CreateRequestSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/request_settings", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateRequestSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/request_settings", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("create_request_settings", reqwest::Method::POST, local_var_path)
 }
 
 /// Removes the specified Request Settings object.
-pub async fn delete_request_settings(configuration: &mut configuration::Configuration, params: DeleteRequestSettingsParams) -> Result<crate::models::InlineResponse200, Error<DeleteRequestSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_request_settings(configuration: &configuration::Configuration, params: DeleteRequestSettingsParams) -> Result<crate::models::InlineResponse200, Error<DeleteRequestSettingsError>> {
+    delete_request_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_request_settings`], but also returns the status and headers of the response.
+pub async fn delete_request_settings_with_info(configuration: &configuration::Configuration, params: DeleteRequestSettingsParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteRequestSettingsError>> {
+    crate::apis::execute(configuration, delete_request_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_request_settings`] sends.
+pub(crate) fn delete_request_settings_request(params: DeleteRequestSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let request_settings_name = params.request_settings_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/request_settings/{request_settings_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, request_settings_name=crate::apis::urlencode(request_settings_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteRequestSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/request_settings/{request_settings_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, request_settings_name=crate::apis::urlencode(request_settings_name));
+    crate::apis::Request::new("delete_request_settings", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Gets the specified Request Settings object.
-pub async fn get_request_settings(configuration: &mut configuration::Configuration, params: GetRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<GetRequestSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_request_settings(configuration: &configuration::Configuration, params: GetRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<GetRequestSettingsError>> {
+    get_request_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_request_settings`], but also returns the status and headers of the response.
+pub async fn get_request_settings_with_info(configuration: &configuration::Configuration, params: GetRequestSettingsParams) -> Result<crate::apis::Response<crate::models::RequestSettingsResponse>, Error<GetRequestSettingsError>> {
+    crate::apis::execute(configuration, get_request_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_request_settings`] sends.
+pub(crate) fn get_request_settings_request(params: GetRequestSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let request_settings_name = params.request_settings_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/request_settings/{request_settings_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, request_settings_name=crate::apis::urlencode(request_settings_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetRequestSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/request_settings/{request_settings_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, request_settings_name=crate::apis::urlencode(request_settings_name));
+    crate::apis::Request::new("get_request_settings", reqwest::Method::GET, local_var_path)
 }
 
 /// Returns a list of all Request Settings objects for the given service and version.
-pub async fn list_request_settings(configuration: &mut configuration::Configuration, params: ListRequestSettingsParams) -> Result<Vec<crate::models::RequestSettingsResponse>, Error<ListRequestSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_request_settings(configuration: &configuration::Configuration, params: ListRequestSettingsParams) -> Result<Vec<crate::models::RequestSettingsResponse>, Error<ListRequestSettingsError>> {
+    list_request_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_request_settings`], but also returns the status and headers of the response.
+pub async fn list_request_settings_with_info(configuration: &configuration::Configuration, params: ListRequestSettingsParams) -> Result<crate::apis::Response<Vec<crate::models::RequestSettingsResponse>>, Error<ListRequestSettingsError>> {
+    crate::apis::execute(configuration, list_request_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_request_settings`] sends.
+pub(crate) fn list_request_settings_request(params: ListRequestSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/request_settings", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListRequestSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/request_settings", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_request_settings", reqwest::Method::GET, local_var_path)
 }
 
 /// Updates the specified Request Settings object.
-pub async fn update_request_settings(configuration: &mut configuration::Configuration, params: UpdateRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<UpdateRequestSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn update_request_settings(configuration: &configuration::Configuration, params: UpdateRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<UpdateRequestSettingsError>> {
+    update_request_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_request_settings`], but also returns the status and headers of the response.
+pub async fn update_request_settings_with_info(configuration: &configuration::Configuration, params: UpdateRequestSettingsParams) -> Result<crate::apis::Response<crate::models::RequestSettingsResponse>, Error<UpdateRequestSettingsError>> {
+    crate::apis::execute(configuration, update_request_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_request_settings`] sends.
+pub(crate) fn update_request_settings_request(params: UpdateRequestSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -358,24 +233,10 @@ pub async fn update_request_settings(configuration: &mut configuration::Configur
     let max_stale_age = params.max_stale_age;
     let timer_support = params.timer_support;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/request_settings/{request_settings_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, request_settings_name=crate::apis::urlencode(request_settings_name));
+    let mut local_var_req = crate::apis::Request::new("update_request_settings", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/request_settings/{request_settings_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, request_settings_name=crate::apis::urlencode(request_settings_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = action {
         local_var_form_params.insert("action", local_var_param_value.to_string());
     }
@@ -412,32 +273,8 @@ pub async fn update_request_settings(configuration: &mut configuration::Configur
     if let Some(local_var_param_value) = timer_support {
         local_var_form_params.insert("timer_support", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateRequestSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
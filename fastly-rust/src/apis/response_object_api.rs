@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_response_object`]
@@ -106,274 +105,115 @@ pub enum UpdateResponseObjectError {
 
 
 /// Creates a new Response Object.
-pub async fn create_response_object(configuration: &mut configuration::Configuration, params: CreateResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<CreateResponseObjectError>> {
-    let local_var_configuration = configuration;
+pub async fn create_response_object(configuration: &configuration::Configuration, params: CreateResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<CreateResponseObjectError>> {
+    create_response_object_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_response_object`], but also returns the status and headers of the response.
+pub async fn create_response_object_with_info(configuration: &configuration::Configuration, params: CreateResponseObjectParams) -> Result<crate::apis::Response<crate::models::ResponseObjectResponse>, Error<CreateResponseObjectError>> {
+    crate::apis::execute(configuration, create_response_object_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_response_object`] sends.
+pub(crate) fn create_response_object_request(params: CreateResponseObjectParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let create_response_object_request = params.create_response_object_request;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/response_object", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_response_object", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/response_object", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&create_response_object_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateResponseObjectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req = local_var_req.json(serde_json::to_value(&create_response_object_request)?);
+
+    Ok(local_var_req)
 }
 
 /// Deletes the specified Response Object.
-pub async fn delete_response_object(configuration: &mut configuration::Configuration, params: DeleteResponseObjectParams) -> Result<crate::models::InlineResponse200, Error<DeleteResponseObjectError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_response_object(configuration: &configuration::Configuration, params: DeleteResponseObjectParams) -> Result<crate::models::InlineResponse200, Error<DeleteResponseObjectError>> {
+    delete_response_object_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_response_object`], but also returns the status and headers of the response.
+pub async fn delete_response_object_with_info(configuration: &configuration::Configuration, params: DeleteResponseObjectParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteResponseObjectError>> {
+    crate::apis::execute(configuration, delete_response_object_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_response_object`] sends.
+pub(crate) fn delete_response_object_request(params: DeleteResponseObjectParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let response_object_name = params.response_object_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/response_object/{response_object_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, response_object_name=crate::apis::urlencode(response_object_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteResponseObjectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/response_object/{response_object_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, response_object_name=crate::apis::urlencode(response_object_name));
+    crate::apis::Request::new("delete_response_object", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Gets the specified Response Object.
-pub async fn get_response_object(configuration: &mut configuration::Configuration, params: GetResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<GetResponseObjectError>> {
-    let local_var_configuration = configuration;
+pub async fn get_response_object(configuration: &configuration::Configuration, params: GetResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<GetResponseObjectError>> {
+    get_response_object_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_response_object`], but also returns the status and headers of the response.
+pub async fn get_response_object_with_info(configuration: &configuration::Configuration, params: GetResponseObjectParams) -> Result<crate::apis::Response<crate::models::ResponseObjectResponse>, Error<GetResponseObjectError>> {
+    crate::apis::execute(configuration, get_response_object_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_response_object`] sends.
+pub(crate) fn get_response_object_request(params: GetResponseObjectParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let response_object_name = params.response_object_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/response_object/{response_object_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, response_object_name=crate::apis::urlencode(response_object_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetResponseObjectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/response_object/{response_object_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, response_object_name=crate::apis::urlencode(response_object_name));
+    crate::apis::Request::new("get_response_object", reqwest::Method::GET, local_var_path)
 }
 
 /// Returns all Response Objects for the specified service and version.
-pub async fn list_response_objects(configuration: &mut configuration::Configuration, params: ListResponseObjectsParams) -> Result<Vec<crate::models::ResponseObjectResponse>, Error<ListResponseObjectsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_response_objects(configuration: &configuration::Configuration, params: ListResponseObjectsParams) -> Result<Vec<crate::models::ResponseObjectResponse>, Error<ListResponseObjectsError>> {
+    list_response_objects_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_response_objects`], but also returns the status and headers of the response.
+pub async fn list_response_objects_with_info(configuration: &configuration::Configuration, params: ListResponseObjectsParams) -> Result<crate::apis::Response<Vec<crate::models::ResponseObjectResponse>>, Error<ListResponseObjectsError>> {
+    crate::apis::execute(configuration, list_response_objects_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_response_objects`] sends.
+pub(crate) fn list_response_objects_request(params: ListResponseObjectsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/response_object", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListResponseObjectsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/response_object", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_response_objects", reqwest::Method::GET, local_var_path)
 }
 
 /// Updates the specified Response Object.
-pub async fn update_response_object(configuration: &mut configuration::Configuration, params: UpdateResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<UpdateResponseObjectError>> {
-    let local_var_configuration = configuration;
+pub async fn update_response_object(configuration: &configuration::Configuration, params: UpdateResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<UpdateResponseObjectError>> {
+    update_response_object_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_response_object`], but also returns the status and headers of the response.
+pub async fn update_response_object_with_info(configuration: &configuration::Configuration, params: UpdateResponseObjectParams) -> Result<crate::apis::Response<crate::models::ResponseObjectResponse>, Error<UpdateResponseObjectError>> {
+    crate::apis::execute(configuration, update_response_object_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_response_object`] sends.
+pub(crate) fn update_response_object_request(params: UpdateResponseObjectParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let response_object_name = params.response_object_name;
     let create_response_object_request = params.create_response_object_request;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/response_object/{response_object_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, response_object_name=crate::apis::urlencode(response_object_name));
+    let mut local_var_req = crate::apis::Request::new("update_response_object", reqwest::Method::PUT, local_var_path);
+
+    local_var_req = local_var_req.json(serde_json::to_value(&create_response_object_request)?);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/response_object/{response_object_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, response_object_name=crate::apis::urlencode(response_object_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&create_response_object_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateResponseObjectError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_secret_store`]
@@ -85,324 +84,128 @@ pub enum SigningKeyError {
 
 
 /// Create a new client key for encrypting secrets locally before uploading.
-pub async fn client_key(configuration: &mut configuration::Configuration) -> Result<crate::models::ClientKey, Error<ClientKeyError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
-
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/client-key", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+pub async fn client_key(configuration: &configuration::Configuration) -> Result<crate::models::ClientKey, Error<ClientKeyError>> {
+    client_key_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+/// Like [`client_key`], but also returns the status and headers of the response.
+pub async fn client_key_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::ClientKey>, Error<ClientKeyError>> {
+    crate::apis::execute(configuration, client_key_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ClientKeyError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`client_key`] sends.
+pub(crate) fn client_key_request() -> crate::apis::Request {
+    let local_var_path = String::from("/resources/stores/secret/client-key");
+    crate::apis::Request::new("client_key", reqwest::Method::POST, local_var_path)
 }
 
 /// Create a new secret store.
-pub async fn create_secret_store(configuration: &mut configuration::Configuration, params: CreateSecretStoreParams) -> Result<crate::models::SecretStoreResponse, Error<CreateSecretStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn create_secret_store(configuration: &configuration::Configuration, params: CreateSecretStoreParams) -> Result<crate::models::SecretStoreResponse, Error<CreateSecretStoreError>> {
+    create_secret_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`create_secret_store`], but also returns the status and headers of the response.
+pub async fn create_secret_store_with_info(configuration: &configuration::Configuration, params: CreateSecretStoreParams) -> Result<crate::apis::Response<crate::models::SecretStoreResponse>, Error<CreateSecretStoreError>> {
+    crate::apis::execute(configuration, create_secret_store_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`create_secret_store`] sends.
+pub(crate) fn create_secret_store_request(params: CreateSecretStoreParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let secret_store = params.secret_store;
 
+    let local_var_path = String::from("/resources/stores/secret");
+    let mut local_var_req = crate::apis::Request::new("create_secret_store", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&secret_store)?);
 
-    let local_var_uri_str = format!("{}/resources/stores/secret", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&secret_store);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateSecretStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete a secret store and all of its contents.
-pub async fn delete_secret_store(configuration: &mut configuration::Configuration, params: DeleteSecretStoreParams) -> Result<(), Error<DeleteSecretStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_secret_store(configuration: &configuration::Configuration, params: DeleteSecretStoreParams) -> Result<(), Error<DeleteSecretStoreError>> {
+    delete_secret_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_secret_store`], but also returns the status and headers of the response.
+pub async fn delete_secret_store_with_info(configuration: &configuration::Configuration, params: DeleteSecretStoreParams) -> Result<crate::apis::Response<()>, Error<DeleteSecretStoreError>> {
+    crate::apis::execute(configuration, delete_secret_store_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_secret_store`] sends.
+pub(crate) fn delete_secret_store_request(params: DeleteSecretStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/{store_id}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteSecretStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/secret/{store_id}", store_id=crate::apis::urlencode(store_id));
+    crate::apis::Request::new("delete_secret_store", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a secret store by ID.
-pub async fn get_secret_store(configuration: &mut configuration::Configuration, params: GetSecretStoreParams) -> Result<crate::models::SecretStoreResponse, Error<GetSecretStoreError>> {
-    let local_var_configuration = configuration;
+pub async fn get_secret_store(configuration: &configuration::Configuration, params: GetSecretStoreParams) -> Result<crate::models::SecretStoreResponse, Error<GetSecretStoreError>> {
+    get_secret_store_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_secret_store`], but also returns the status and headers of the response.
+pub async fn get_secret_store_with_info(configuration: &configuration::Configuration, params: GetSecretStoreParams) -> Result<crate::apis::Response<crate::models::SecretStoreResponse>, Error<GetSecretStoreError>> {
+    crate::apis::execute(configuration, get_secret_store_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_secret_store`] sends.
+pub(crate) fn get_secret_store_request(params: GetSecretStoreParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/{store_id}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetSecretStoreError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/secret/{store_id}", store_id=crate::apis::urlencode(store_id));
+    crate::apis::Request::new("get_secret_store", reqwest::Method::GET, local_var_path)
 }
 
 /// Get all secret stores.
-pub async fn get_secret_stores(configuration: &mut configuration::Configuration, params: GetSecretStoresParams) -> Result<crate::models::InlineResponse2005, Error<GetSecretStoresError>> {
-    let local_var_configuration = configuration;
+pub async fn get_secret_stores(configuration: &configuration::Configuration, params: GetSecretStoresParams) -> Result<crate::models::InlineResponse2005, Error<GetSecretStoresError>> {
+    get_secret_stores_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_secret_stores`], but also returns the status and headers of the response.
+pub async fn get_secret_stores_with_info(configuration: &configuration::Configuration, params: GetSecretStoresParams) -> Result<crate::apis::Response<crate::models::InlineResponse2005>, Error<GetSecretStoresError>> {
+    crate::apis::execute(configuration, get_secret_stores_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_secret_stores`] sends.
+pub(crate) fn get_secret_stores_request(params: GetSecretStoresParams) -> crate::apis::Request {
     // unbox the parameters
     let cursor = params.cursor;
     let limit = params.limit;
     let name = params.name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/resources/stores/secret");
+    let mut local_var_req = crate::apis::Request::new("get_secret_stores", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = cursor {
-        local_var_req_builder = local_var_req_builder.query(&[("cursor", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("cursor", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = limit {
-        local_var_req_builder = local_var_req_builder.query(&[("limit", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("limit", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = name {
-        local_var_req_builder = local_var_req_builder.query(&[("name", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("name", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetSecretStoresError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Get the public key used for signing client keys.
-pub async fn signing_key(configuration: &mut configuration::Configuration) -> Result<crate::models::SigningKey, Error<SigningKeyError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
-
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/signing-key", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+pub async fn signing_key(configuration: &configuration::Configuration) -> Result<crate::models::SigningKey, Error<SigningKeyError>> {
+    signing_key_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+/// Like [`signing_key`], but also returns the status and headers of the response.
+pub async fn signing_key_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::SigningKey>, Error<SigningKeyError>> {
+    crate::apis::execute(configuration, signing_key_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<SigningKeyError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`signing_key`] sends.
+pub(crate) fn signing_key_request() -> crate::apis::Request {
+    let local_var_path = String::from("/resources/stores/secret/signing-key");
+    crate::apis::Request::new("signing_key", reqwest::Method::GET, local_var_path)
 }
 
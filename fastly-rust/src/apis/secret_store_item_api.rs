@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_secret`]
@@ -101,330 +100,144 @@ pub enum RecreateSecretError {
 
 
 /// Create a new secret in a store. Returns an error if a secret already exists with the same name. See `PUT` and `PATCH` methods for ways to recreate an existing secret.  The `secret` field must be Base64-encoded because a secret can contain binary data. In the example below, the unencoded secret is \"Hello, world!\" 
-pub async fn create_secret(configuration: &mut configuration::Configuration, params: CreateSecretParams) -> Result<crate::models::SecretResponse, Error<CreateSecretError>> {
-    let local_var_configuration = configuration;
+pub async fn create_secret(configuration: &configuration::Configuration, params: CreateSecretParams) -> Result<crate::models::SecretResponse, Error<CreateSecretError>> {
+    create_secret_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_secret`], but also returns the status and headers of the response.
+pub async fn create_secret_with_info(configuration: &configuration::Configuration, params: CreateSecretParams) -> Result<crate::apis::Response<crate::models::SecretResponse>, Error<CreateSecretError>> {
+    crate::apis::execute(configuration, create_secret_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_secret`] sends.
+pub(crate) fn create_secret_request(params: CreateSecretParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let store_id = params.store_id;
     let secret = params.secret;
 
+    let local_var_path = format!("/resources/stores/secret/{store_id}/secrets", store_id=crate::apis::urlencode(store_id));
+    let mut local_var_req = crate::apis::Request::new("create_secret", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/{store_id}/secrets", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&secret);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&secret)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateSecretError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete a secret from a store by name.
-pub async fn delete_secret(configuration: &mut configuration::Configuration, params: DeleteSecretParams) -> Result<(), Error<DeleteSecretError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_secret(configuration: &configuration::Configuration, params: DeleteSecretParams) -> Result<(), Error<DeleteSecretError>> {
+    delete_secret_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_secret`], but also returns the status and headers of the response.
+pub async fn delete_secret_with_info(configuration: &configuration::Configuration, params: DeleteSecretParams) -> Result<crate::apis::Response<()>, Error<DeleteSecretError>> {
+    crate::apis::execute(configuration, delete_secret_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_secret`] sends.
+pub(crate) fn delete_secret_request(params: DeleteSecretParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
     let secret_name = params.secret_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/{store_id}/secrets/{secret_name}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id), secret_name=crate::apis::urlencode(secret_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteSecretError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/secret/{store_id}/secrets/{secret_name}", store_id=crate::apis::urlencode(store_id), secret_name=crate::apis::urlencode(secret_name));
+    crate::apis::Request::new("delete_secret", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get metadata about a secret by name.
-pub async fn get_secret(configuration: &mut configuration::Configuration, params: GetSecretParams) -> Result<crate::models::SecretResponse, Error<GetSecretError>> {
-    let local_var_configuration = configuration;
+pub async fn get_secret(configuration: &configuration::Configuration, params: GetSecretParams) -> Result<crate::models::SecretResponse, Error<GetSecretError>> {
+    get_secret_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_secret`], but also returns the status and headers of the response.
+pub async fn get_secret_with_info(configuration: &configuration::Configuration, params: GetSecretParams) -> Result<crate::apis::Response<crate::models::SecretResponse>, Error<GetSecretError>> {
+    crate::apis::execute(configuration, get_secret_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_secret`] sends.
+pub(crate) fn get_secret_request(params: GetSecretParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
     let secret_name = params.secret_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/{store_id}/secrets/{secret_name}", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id), secret_name=crate::apis::urlencode(secret_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetSecretError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/resources/stores/secret/{store_id}/secrets/{secret_name}", store_id=crate::apis::urlencode(store_id), secret_name=crate::apis::urlencode(secret_name));
+    crate::apis::Request::new("get_secret", reqwest::Method::GET, local_var_path)
 }
 
 /// List all secrets within a store.
-pub async fn get_secrets(configuration: &mut configuration::Configuration, params: GetSecretsParams) -> Result<crate::models::InlineResponse2006, Error<GetSecretsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_secrets(configuration: &configuration::Configuration, params: GetSecretsParams) -> Result<crate::models::InlineResponse2006, Error<GetSecretsError>> {
+    get_secrets_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_secrets`], but also returns the status and headers of the response.
+pub async fn get_secrets_with_info(configuration: &configuration::Configuration, params: GetSecretsParams) -> Result<crate::apis::Response<crate::models::InlineResponse2006>, Error<GetSecretsError>> {
+    crate::apis::execute(configuration, get_secrets_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_secrets`] sends.
+pub(crate) fn get_secrets_request(params: GetSecretsParams) -> crate::apis::Request {
     // unbox the parameters
     let store_id = params.store_id;
     let cursor = params.cursor;
     let limit = params.limit;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/{store_id}/secrets", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/resources/stores/secret/{store_id}/secrets", store_id=crate::apis::urlencode(store_id));
+    let mut local_var_req = crate::apis::Request::new("get_secrets", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = cursor {
-        local_var_req_builder = local_var_req_builder.query(&[("cursor", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("cursor", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = limit {
-        local_var_req_builder = local_var_req_builder.query(&[("limit", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("limit", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetSecretsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Recreate a secret based on the secret's name. Returns an error if there is no existing secret with the same name.  The `secret` field must be Base64-encoded because a secret can contain binary data. In the example below, the unencoded secret is \"Hello, world!\" 
-pub async fn must_recreate_secret(configuration: &mut configuration::Configuration, params: MustRecreateSecretParams) -> Result<crate::models::SecretResponse, Error<MustRecreateSecretError>> {
-    let local_var_configuration = configuration;
+pub async fn must_recreate_secret(configuration: &configuration::Configuration, params: MustRecreateSecretParams) -> Result<crate::models::SecretResponse, Error<MustRecreateSecretError>> {
+    must_recreate_secret_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`must_recreate_secret`], but also returns the status and headers of the response.
+pub async fn must_recreate_secret_with_info(configuration: &configuration::Configuration, params: MustRecreateSecretParams) -> Result<crate::apis::Response<crate::models::SecretResponse>, Error<MustRecreateSecretError>> {
+    crate::apis::execute(configuration, must_recreate_secret_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`must_recreate_secret`] sends.
+pub(crate) fn must_recreate_secret_request(params: MustRecreateSecretParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let store_id = params.store_id;
     let secret = params.secret;
 
+    let local_var_path = format!("/resources/stores/secret/{store_id}/secrets", store_id=crate::apis::urlencode(store_id));
+    let mut local_var_req = crate::apis::Request::new("must_recreate_secret", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/{store_id}/secrets", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&secret)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&secret);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<MustRecreateSecretError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create or recreate a secret based on the secret's name. The response object's `recreated` field will be true if the secret was recreated.  The `secret` field must be Base64-encoded because a secret can contain binary data. In the example below, the unencoded secret is \"Hello, world!\" 
-pub async fn recreate_secret(configuration: &mut configuration::Configuration, params: RecreateSecretParams) -> Result<crate::models::SecretResponse, Error<RecreateSecretError>> {
-    let local_var_configuration = configuration;
+pub async fn recreate_secret(configuration: &configuration::Configuration, params: RecreateSecretParams) -> Result<crate::models::SecretResponse, Error<RecreateSecretError>> {
+    recreate_secret_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`recreate_secret`], but also returns the status and headers of the response.
+pub async fn recreate_secret_with_info(configuration: &configuration::Configuration, params: RecreateSecretParams) -> Result<crate::apis::Response<crate::models::SecretResponse>, Error<RecreateSecretError>> {
+    crate::apis::execute(configuration, recreate_secret_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`recreate_secret`] sends.
+pub(crate) fn recreate_secret_request(params: RecreateSecretParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let store_id = params.store_id;
     let secret = params.secret;
 
+    let local_var_path = format!("/resources/stores/secret/{store_id}/secrets", store_id=crate::apis::urlencode(store_id));
+    let mut local_var_req = crate::apis::Request::new("recreate_secret", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/resources/stores/secret/{store_id}/secrets", local_var_configuration.base_path, store_id=crate::apis::urlencode(store_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&secret)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&secret);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<RecreateSecretError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_pool_server`]
@@ -128,9 +127,17 @@ pub enum UpdatePoolServerError {
 
 
 /// Creates a single server for a particular service and pool.
-pub async fn create_pool_server(configuration: &mut configuration::Configuration, params: CreatePoolServerParams) -> Result<crate::models::ServerResponse, Error<CreatePoolServerError>> {
-    let local_var_configuration = configuration;
+pub async fn create_pool_server(configuration: &configuration::Configuration, params: CreatePoolServerParams) -> Result<crate::models::ServerResponse, Error<CreatePoolServerError>> {
+    create_pool_server_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_pool_server`], but also returns the status and headers of the response.
+pub async fn create_pool_server_with_info(configuration: &configuration::Configuration, params: CreatePoolServerParams) -> Result<crate::apis::Response<crate::models::ServerResponse>, Error<CreatePoolServerError>> {
+    crate::apis::execute(configuration, create_pool_server_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_pool_server`] sends.
+pub(crate) fn create_pool_server_request(params: CreatePoolServerParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let pool_id = params.pool_id;
@@ -142,24 +149,10 @@ pub async fn create_pool_server(configuration: &mut configuration::Configuration
     let disabled = params.disabled;
     let override_host = params.override_host;
 
+    let local_var_path = format!("/service/{service_id}/pool/{pool_id}/server", service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id));
+    let mut local_var_req = crate::apis::Request::new("create_pool_server", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/pool/{pool_id}/server", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = weight {
         local_var_form_params.insert("weight", local_var_param_value.to_string());
     }
@@ -181,200 +174,85 @@ pub async fn create_pool_server(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = override_host {
         local_var_form_params.insert("override_host", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreatePoolServerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Deletes a single server for a particular service and pool.
-pub async fn delete_pool_server(configuration: &mut configuration::Configuration, params: DeletePoolServerParams) -> Result<crate::models::InlineResponse200, Error<DeletePoolServerError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_pool_server(configuration: &configuration::Configuration, params: DeletePoolServerParams) -> Result<crate::models::InlineResponse200, Error<DeletePoolServerError>> {
+    delete_pool_server_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_pool_server`], but also returns the status and headers of the response.
+pub async fn delete_pool_server_with_info(configuration: &configuration::Configuration, params: DeletePoolServerParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeletePoolServerError>> {
+    crate::apis::execute(configuration, delete_pool_server_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_pool_server`] sends.
+pub(crate) fn delete_pool_server_request(params: DeletePoolServerParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let pool_id = params.pool_id;
     let server_id = params.server_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/pool/{pool_id}/server/{server_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id), server_id=crate::apis::urlencode(server_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeletePoolServerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/pool/{pool_id}/server/{server_id}", service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id), server_id=crate::apis::urlencode(server_id));
+    crate::apis::Request::new("delete_pool_server", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Gets a single server for a particular service and pool.
-pub async fn get_pool_server(configuration: &mut configuration::Configuration, params: GetPoolServerParams) -> Result<crate::models::ServerResponse, Error<GetPoolServerError>> {
-    let local_var_configuration = configuration;
+pub async fn get_pool_server(configuration: &configuration::Configuration, params: GetPoolServerParams) -> Result<crate::models::ServerResponse, Error<GetPoolServerError>> {
+    get_pool_server_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_pool_server`], but also returns the status and headers of the response.
+pub async fn get_pool_server_with_info(configuration: &configuration::Configuration, params: GetPoolServerParams) -> Result<crate::apis::Response<crate::models::ServerResponse>, Error<GetPoolServerError>> {
+    crate::apis::execute(configuration, get_pool_server_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_pool_server`] sends.
+pub(crate) fn get_pool_server_request(params: GetPoolServerParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let pool_id = params.pool_id;
     let server_id = params.server_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/pool/{pool_id}/server/{server_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id), server_id=crate::apis::urlencode(server_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetPoolServerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/pool/{pool_id}/server/{server_id}", service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id), server_id=crate::apis::urlencode(server_id));
+    crate::apis::Request::new("get_pool_server", reqwest::Method::GET, local_var_path)
 }
 
 /// Lists all servers for a particular service and pool.
-pub async fn list_pool_servers(configuration: &mut configuration::Configuration, params: ListPoolServersParams) -> Result<Vec<crate::models::ServerResponse>, Error<ListPoolServersError>> {
-    let local_var_configuration = configuration;
+pub async fn list_pool_servers(configuration: &configuration::Configuration, params: ListPoolServersParams) -> Result<Vec<crate::models::ServerResponse>, Error<ListPoolServersError>> {
+    list_pool_servers_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_pool_servers`], but also returns the status and headers of the response.
+pub async fn list_pool_servers_with_info(configuration: &configuration::Configuration, params: ListPoolServersParams) -> Result<crate::apis::Response<Vec<crate::models::ServerResponse>>, Error<ListPoolServersError>> {
+    crate::apis::execute(configuration, list_pool_servers_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_pool_servers`] sends.
+pub(crate) fn list_pool_servers_request(params: ListPoolServersParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let pool_id = params.pool_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/pool/{pool_id}/servers", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListPoolServersError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/pool/{pool_id}/servers", service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id));
+    crate::apis::Request::new("list_pool_servers", reqwest::Method::GET, local_var_path)
 }
 
 /// Updates a single server for a particular service and pool.
-pub async fn update_pool_server(configuration: &mut configuration::Configuration, params: UpdatePoolServerParams) -> Result<crate::models::ServerResponse, Error<UpdatePoolServerError>> {
-    let local_var_configuration = configuration;
+pub async fn update_pool_server(configuration: &configuration::Configuration, params: UpdatePoolServerParams) -> Result<crate::models::ServerResponse, Error<UpdatePoolServerError>> {
+    update_pool_server_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_pool_server`], but also returns the status and headers of the response.
+pub async fn update_pool_server_with_info(configuration: &configuration::Configuration, params: UpdatePoolServerParams) -> Result<crate::apis::Response<crate::models::ServerResponse>, Error<UpdatePoolServerError>> {
+    crate::apis::execute(configuration, update_pool_server_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_pool_server`] sends.
+pub(crate) fn update_pool_server_request(params: UpdatePoolServerParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let pool_id = params.pool_id;
@@ -387,24 +265,10 @@ pub async fn update_pool_server(configuration: &mut configuration::Configuration
     let disabled = params.disabled;
     let override_host = params.override_host;
 
+    let local_var_path = format!("/service/{service_id}/pool/{pool_id}/server/{server_id}", service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id), server_id=crate::apis::urlencode(server_id));
+    let mut local_var_req = crate::apis::Request::new("update_pool_server", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/pool/{pool_id}/server/{server_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), pool_id=crate::apis::urlencode(pool_id), server_id=crate::apis::urlencode(server_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = weight {
         local_var_form_params.insert("weight", local_var_param_value.to_string());
     }
@@ -426,32 +290,8 @@ pub async fn update_pool_server(configuration: &mut configuration::Configuration
     if let Some(local_var_param_value) = override_host {
         local_var_form_params.insert("override_host", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdatePoolServerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_service`]
@@ -146,33 +145,27 @@ pub enum UpdateServiceError {
 
 
 /// Create a service.
-pub async fn create_service(configuration: &mut configuration::Configuration, params: CreateServiceParams) -> Result<crate::models::ServiceResponse, Error<CreateServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn create_service(configuration: &configuration::Configuration, params: CreateServiceParams) -> Result<crate::models::ServiceResponse, Error<CreateServiceError>> {
+    create_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_service`], but also returns the status and headers of the response.
+pub async fn create_service_with_info(configuration: &configuration::Configuration, params: CreateServiceParams) -> Result<crate::apis::Response<crate::models::ServiceResponse>, Error<CreateServiceError>> {
+    crate::apis::execute(configuration, create_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_service`] sends.
+pub(crate) fn create_service_request(params: CreateServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let comment = params.comment;
     let name = params.name;
     let customer_id = params.customer_id;
     let _type = params._type;
 
+    let local_var_path = String::from("/service");
+    let mut local_var_req = crate::apis::Request::new("create_service", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = comment {
         local_var_form_params.insert("comment", local_var_param_value.to_string());
     }
@@ -185,395 +178,176 @@ pub async fn create_service(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = _type {
         local_var_form_params.insert("type", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a service.
-pub async fn delete_service(configuration: &mut configuration::Configuration, params: DeleteServiceParams) -> Result<crate::models::InlineResponse200, Error<DeleteServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_service(configuration: &configuration::Configuration, params: DeleteServiceParams) -> Result<crate::models::InlineResponse200, Error<DeleteServiceError>> {
+    delete_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_service`], but also returns the status and headers of the response.
+pub async fn delete_service_with_info(configuration: &configuration::Configuration, params: DeleteServiceParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteServiceError>> {
+    crate::apis::execute(configuration, delete_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_service`] sends.
+pub(crate) fn delete_service_request(params: DeleteServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}", service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("delete_service", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a specific service by id.
-pub async fn get_service(configuration: &mut configuration::Configuration, params: GetServiceParams) -> Result<crate::models::ServiceResponse, Error<GetServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn get_service(configuration: &configuration::Configuration, params: GetServiceParams) -> Result<crate::models::ServiceResponse, Error<GetServiceError>> {
+    get_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_service`], but also returns the status and headers of the response.
+pub async fn get_service_with_info(configuration: &configuration::Configuration, params: GetServiceParams) -> Result<crate::apis::Response<crate::models::ServiceResponse>, Error<GetServiceError>> {
+    crate::apis::execute(configuration, get_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_service`] sends.
+pub(crate) fn get_service_request(params: GetServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}", service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("get_service", reqwest::Method::GET, local_var_path)
 }
 
 /// List detailed information on a specified service.
-pub async fn get_service_detail(configuration: &mut configuration::Configuration, params: GetServiceDetailParams) -> Result<crate::models::ServiceDetail, Error<GetServiceDetailError>> {
-    let local_var_configuration = configuration;
+pub async fn get_service_detail(configuration: &configuration::Configuration, params: GetServiceDetailParams) -> Result<crate::models::ServiceDetail, Error<GetServiceDetailError>> {
+    get_service_detail_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_service_detail`], but also returns the status and headers of the response.
+pub async fn get_service_detail_with_info(configuration: &configuration::Configuration, params: GetServiceDetailParams) -> Result<crate::apis::Response<crate::models::ServiceDetail>, Error<GetServiceDetailError>> {
+    crate::apis::execute(configuration, get_service_detail_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_service_detail`] sends.
+pub(crate) fn get_service_detail_request(params: GetServiceDetailParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version = params.version;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/details", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/details", service_id=crate::apis::urlencode(service_id));
+    let mut local_var_req = crate::apis::Request::new("get_service_detail", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = version {
-        local_var_req_builder = local_var_req_builder.query(&[("version", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("version", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServiceDetailError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List the domains within a service.
-pub async fn list_service_domains(configuration: &mut configuration::Configuration, params: ListServiceDomainsParams) -> Result<Vec<crate::models::DomainResponse>, Error<ListServiceDomainsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_service_domains(configuration: &configuration::Configuration, params: ListServiceDomainsParams) -> Result<Vec<crate::models::DomainResponse>, Error<ListServiceDomainsError>> {
+    list_service_domains_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_service_domains`], but also returns the status and headers of the response.
+pub async fn list_service_domains_with_info(configuration: &configuration::Configuration, params: ListServiceDomainsParams) -> Result<crate::apis::Response<Vec<crate::models::DomainResponse>>, Error<ListServiceDomainsError>> {
+    crate::apis::execute(configuration, list_service_domains_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_service_domains`] sends.
+pub(crate) fn list_service_domains_request(params: ListServiceDomainsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/domain", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListServiceDomainsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/domain", service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("list_service_domains", reqwest::Method::GET, local_var_path)
 }
 
 /// List services.
-pub async fn list_services(configuration: &mut configuration::Configuration, params: ListServicesParams) -> Result<Vec<crate::models::ServiceListResponse>, Error<ListServicesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_services(configuration: &configuration::Configuration, params: ListServicesParams) -> Result<Vec<crate::models::ServiceListResponse>, Error<ListServicesError>> {
+    list_services_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_services`], but also returns the status and headers of the response.
+pub async fn list_services_with_info(configuration: &configuration::Configuration, params: ListServicesParams) -> Result<crate::apis::Response<Vec<crate::models::ServiceListResponse>>, Error<ListServicesError>> {
+    crate::apis::execute(configuration, list_services_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_services`] sends.
+pub(crate) fn list_services_request(params: ListServicesParams) -> crate::apis::Request {
     // unbox the parameters
     let page = params.page;
     let per_page = params.per_page;
     let sort = params.sort;
     let direction = params.direction;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/service");
+    let mut local_var_req = crate::apis::Request::new("list_services", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page {
-        local_var_req_builder = local_var_req_builder.query(&[("page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = per_page {
-        local_var_req_builder = local_var_req_builder.query(&[("per_page", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("per_page", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = sort {
-        local_var_req_builder = local_var_req_builder.query(&[("sort", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("sort", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = direction {
-        local_var_req_builder = local_var_req_builder.query(&[("direction", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("direction", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListServicesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Get a specific service by name.
-pub async fn search_service(configuration: &mut configuration::Configuration, params: SearchServiceParams) -> Result<crate::models::ServiceResponse, Error<SearchServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn search_service(configuration: &configuration::Configuration, params: SearchServiceParams) -> Result<crate::models::ServiceResponse, Error<SearchServiceError>> {
+    search_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`search_service`], but also returns the status and headers of the response.
+pub async fn search_service_with_info(configuration: &configuration::Configuration, params: SearchServiceParams) -> Result<crate::apis::Response<crate::models::ServiceResponse>, Error<SearchServiceError>> {
+    crate::apis::execute(configuration, search_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`search_service`] sends.
+pub(crate) fn search_service_request(params: SearchServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let name = params.name;
 
+    let local_var_path = String::from("/service/search");
+    let mut local_var_req = crate::apis::Request::new("search_service", reqwest::Method::GET, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/search", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    local_var_req = local_var_req.query("name", name.to_string());
 
-    local_var_req_builder = local_var_req_builder.query(&[("name", &name.to_string())]);
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<SearchServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a service.
-pub async fn update_service(configuration: &mut configuration::Configuration, params: UpdateServiceParams) -> Result<crate::models::ServiceResponse, Error<UpdateServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn update_service(configuration: &configuration::Configuration, params: UpdateServiceParams) -> Result<crate::models::ServiceResponse, Error<UpdateServiceError>> {
+    update_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_service`], but also returns the status and headers of the response.
+pub async fn update_service_with_info(configuration: &configuration::Configuration, params: UpdateServiceParams) -> Result<crate::apis::Response<crate::models::ServiceResponse>, Error<UpdateServiceError>> {
+    crate::apis::execute(configuration, update_service_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_service`] sends.
+pub(crate) fn update_service_request(params: UpdateServiceParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let comment = params.comment;
     let name = params.name;
     let customer_id = params.customer_id;
 
+    let local_var_path = format!("/service/{service_id}", service_id=crate::apis::urlencode(service_id));
+    let mut local_var_req = crate::apis::Request::new("update_service", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = comment {
         local_var_form_params.insert("comment", local_var_param_value.to_string());
     }
@@ -583,32 +357,8 @@ pub async fn update_service(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = customer_id {
         local_var_form_params.insert("customer_id", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_service_authorization`]
@@ -112,378 +111,162 @@ pub enum UpdateServiceAuthorization2Error {
 
 
 /// Create service authorization.
-pub async fn create_service_authorization(configuration: &mut configuration::Configuration, params: CreateServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<CreateServiceAuthorizationError>> {
-    let local_var_configuration = configuration;
+pub async fn create_service_authorization(configuration: &configuration::Configuration, params: CreateServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<CreateServiceAuthorizationError>> {
+    create_service_authorization_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_service_authorization`], but also returns the status and headers of the response.
+pub async fn create_service_authorization_with_info(configuration: &configuration::Configuration, params: CreateServiceAuthorizationParams) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationResponse>, Error<CreateServiceAuthorizationError>> {
+    crate::apis::execute(configuration, create_service_authorization_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_service_authorization`] sends.
+pub(crate) fn create_service_authorization_request(params: CreateServiceAuthorizationParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_authorization = params.service_authorization;
 
+    let local_var_path = String::from("/service-authorizations");
+    let mut local_var_req = crate::apis::Request::new("create_service_authorization", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&service_authorization)?);
 
-    let local_var_uri_str = format!("{}/service-authorizations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&service_authorization);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateServiceAuthorizationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete service authorization.
-pub async fn delete_service_authorization(configuration: &mut configuration::Configuration, params: DeleteServiceAuthorizationParams) -> Result<(), Error<DeleteServiceAuthorizationError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_service_authorization(configuration: &configuration::Configuration, params: DeleteServiceAuthorizationParams) -> Result<(), Error<DeleteServiceAuthorizationError>> {
+    delete_service_authorization_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_service_authorization`], but also returns the status and headers of the response.
+pub async fn delete_service_authorization_with_info(configuration: &configuration::Configuration, params: DeleteServiceAuthorizationParams) -> Result<crate::apis::Response<()>, Error<DeleteServiceAuthorizationError>> {
+    crate::apis::execute(configuration, delete_service_authorization_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_service_authorization`] sends.
+pub(crate) fn delete_service_authorization_request(params: DeleteServiceAuthorizationParams) -> crate::apis::Request {
     // unbox the parameters
     let service_authorization_id = params.service_authorization_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-authorizations/{service_authorization_id}", local_var_configuration.base_path, service_authorization_id=crate::apis::urlencode(service_authorization_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteServiceAuthorizationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service-authorizations/{service_authorization_id}", service_authorization_id=crate::apis::urlencode(service_authorization_id));
+    crate::apis::Request::new("delete_service_authorization", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Delete service authorizations.
-pub async fn delete_service_authorization2(configuration: &mut configuration::Configuration, params: DeleteServiceAuthorization2Params) -> Result<crate::models::InlineResponse2007, Error<DeleteServiceAuthorization2Error>> {
-    let local_var_configuration = configuration;
+pub async fn delete_service_authorization2(configuration: &configuration::Configuration, params: DeleteServiceAuthorization2Params) -> Result<crate::models::InlineResponse2007, Error<DeleteServiceAuthorization2Error>> {
+    delete_service_authorization2_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_service_authorization2`], but also returns the status and headers of the response.
+pub async fn delete_service_authorization2_with_info(configuration: &configuration::Configuration, params: DeleteServiceAuthorization2Params) -> Result<crate::apis::Response<crate::models::InlineResponse2007>, Error<DeleteServiceAuthorization2Error>> {
+    crate::apis::execute(configuration, delete_service_authorization2_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_service_authorization2`] sends.
+pub(crate) fn delete_service_authorization2_request(params: DeleteServiceAuthorization2Params) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let request_body = params.request_body;
 
+    let local_var_path = String::from("/service-authorizations");
+    let mut local_var_req = crate::apis::Request::new("delete_service_authorization2", reqwest::Method::DELETE, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-authorizations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteServiceAuthorization2Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// List service authorizations.
-pub async fn list_service_authorization(configuration: &mut configuration::Configuration, params: ListServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationsResponse, Error<ListServiceAuthorizationError>> {
-    let local_var_configuration = configuration;
+pub async fn list_service_authorization(configuration: &configuration::Configuration, params: ListServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationsResponse, Error<ListServiceAuthorizationError>> {
+    list_service_authorization_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_service_authorization`], but also returns the status and headers of the response.
+pub async fn list_service_authorization_with_info(configuration: &configuration::Configuration, params: ListServiceAuthorizationParams) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationsResponse>, Error<ListServiceAuthorizationError>> {
+    crate::apis::execute(configuration, list_service_authorization_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_service_authorization`] sends.
+pub(crate) fn list_service_authorization_request(params: ListServiceAuthorizationParams) -> crate::apis::Request {
     // unbox the parameters
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-authorizations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/service-authorizations");
+    let mut local_var_req = crate::apis::Request::new("list_service_authorization", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListServiceAuthorizationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Show service authorization.
-pub async fn show_service_authorization(configuration: &mut configuration::Configuration, params: ShowServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<ShowServiceAuthorizationError>> {
-    let local_var_configuration = configuration;
+pub async fn show_service_authorization(configuration: &configuration::Configuration, params: ShowServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<ShowServiceAuthorizationError>> {
+    show_service_authorization_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`show_service_authorization`], but also returns the status and headers of the response.
+pub async fn show_service_authorization_with_info(configuration: &configuration::Configuration, params: ShowServiceAuthorizationParams) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationResponse>, Error<ShowServiceAuthorizationError>> {
+    crate::apis::execute(configuration, show_service_authorization_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`show_service_authorization`] sends.
+pub(crate) fn show_service_authorization_request(params: ShowServiceAuthorizationParams) -> crate::apis::Request {
     // unbox the parameters
     let service_authorization_id = params.service_authorization_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-authorizations/{service_authorization_id}", local_var_configuration.base_path, service_authorization_id=crate::apis::urlencode(service_authorization_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ShowServiceAuthorizationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service-authorizations/{service_authorization_id}", service_authorization_id=crate::apis::urlencode(service_authorization_id));
+    crate::apis::Request::new("show_service_authorization", reqwest::Method::GET, local_var_path)
 }
 
 /// Update service authorization.
-pub async fn update_service_authorization(configuration: &mut configuration::Configuration, params: UpdateServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<UpdateServiceAuthorizationError>> {
-    let local_var_configuration = configuration;
+pub async fn update_service_authorization(configuration: &configuration::Configuration, params: UpdateServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<UpdateServiceAuthorizationError>> {
+    update_service_authorization_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_service_authorization`], but also returns the status and headers of the response.
+pub async fn update_service_authorization_with_info(configuration: &configuration::Configuration, params: UpdateServiceAuthorizationParams) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationResponse>, Error<UpdateServiceAuthorizationError>> {
+    crate::apis::execute(configuration, update_service_authorization_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_service_authorization`] sends.
+pub(crate) fn update_service_authorization_request(params: UpdateServiceAuthorizationParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_authorization_id = params.service_authorization_id;
     let service_authorization = params.service_authorization;
 
+    let local_var_path = format!("/service-authorizations/{service_authorization_id}", service_authorization_id=crate::apis::urlencode(service_authorization_id));
+    let mut local_var_req = crate::apis::Request::new("update_service_authorization", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-authorizations/{service_authorization_id}", local_var_configuration.base_path, service_authorization_id=crate::apis::urlencode(service_authorization_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&service_authorization);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&service_authorization)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateServiceAuthorizationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Update service authorizations.
-pub async fn update_service_authorization2(configuration: &mut configuration::Configuration, params: UpdateServiceAuthorization2Params) -> Result<crate::models::ServiceAuthorizationsResponse, Error<UpdateServiceAuthorization2Error>> {
-    let local_var_configuration = configuration;
+pub async fn update_service_authorization2(configuration: &configuration::Configuration, params: UpdateServiceAuthorization2Params) -> Result<crate::models::ServiceAuthorizationsResponse, Error<UpdateServiceAuthorization2Error>> {
+    update_service_authorization2_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_service_authorization2`], but also returns the status and headers of the response.
+pub async fn update_service_authorization2_with_info(configuration: &configuration::Configuration, params: UpdateServiceAuthorization2Params) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationsResponse>, Error<UpdateServiceAuthorization2Error>> {
+    crate::apis::execute(configuration, update_service_authorization2_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_service_authorization2`] sends.
+pub(crate) fn update_service_authorization2_request(params: UpdateServiceAuthorization2Params) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let request_body = params.request_body;
 
+    let local_var_path = String::from("/service-authorizations");
+    let mut local_var_req = crate::apis::Request::new("update_service_authorization2", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service-authorizations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateServiceAuthorization2Error> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_service_settings`]
@@ -54,62 +53,37 @@ pub enum UpdateServiceSettingsError {
 
 
 /// Get the settings for a particular service and version.
-pub async fn get_service_settings(configuration: &mut configuration::Configuration, params: GetServiceSettingsParams) -> Result<crate::models::SettingsResponse, Error<GetServiceSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_service_settings(configuration: &configuration::Configuration, params: GetServiceSettingsParams) -> Result<crate::models::SettingsResponse, Error<GetServiceSettingsError>> {
+    get_service_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_service_settings`], but also returns the status and headers of the response.
+pub async fn get_service_settings_with_info(configuration: &configuration::Configuration, params: GetServiceSettingsParams) -> Result<crate::apis::Response<crate::models::SettingsResponse>, Error<GetServiceSettingsError>> {
+    crate::apis::execute(configuration, get_service_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_service_settings`] sends.
+pub(crate) fn get_service_settings_request(params: GetServiceSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/settings", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServiceSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/settings", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("get_service_settings", reqwest::Method::GET, local_var_path)
 }
 
 /// Update the settings for a particular service and version. NOTE: If you override TTLs with custom VCL, any general.default_ttl value will not be honored and the expected behavior may change. 
-pub async fn update_service_settings(configuration: &mut configuration::Configuration, params: UpdateServiceSettingsParams) -> Result<crate::models::SettingsResponse, Error<UpdateServiceSettingsError>> {
-    let local_var_configuration = configuration;
+pub async fn update_service_settings(configuration: &configuration::Configuration, params: UpdateServiceSettingsParams) -> Result<crate::models::SettingsResponse, Error<UpdateServiceSettingsError>> {
+    update_service_settings_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_service_settings`], but also returns the status and headers of the response.
+pub async fn update_service_settings_with_info(configuration: &configuration::Configuration, params: UpdateServiceSettingsParams) -> Result<crate::apis::Response<crate::models::SettingsResponse>, Error<UpdateServiceSettingsError>> {
+    crate::apis::execute(configuration, update_service_settings_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_service_settings`] sends.
+pub(crate) fn update_service_settings_request(params: UpdateServiceSettingsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -118,24 +92,10 @@ pub async fn update_service_settings(configuration: &mut configuration::Configur
     let general_stale_if_error = params.general_stale_if_error;
     let general_stale_if_error_ttl = params.general_stale_if_error_ttl;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/settings", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("update_service_settings", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/settings", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = general_default_host {
         local_var_form_params.insert("general.default_host", local_var_param_value.to_string());
     }
@@ -148,32 +108,8 @@ pub async fn update_service_settings(configuration: &mut configuration::Configur
     if let Some(local_var_param_value) = general_stale_if_error_ttl {
         local_var_form_params.insert("general.stale_if_error_ttl", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateServiceSettingsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
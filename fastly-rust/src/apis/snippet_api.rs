@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_snippet`]
@@ -152,9 +151,17 @@ pub enum UpdateSnippetDynamicError {
 
 
 /// Create a snippet for a particular service and version.
-pub async fn create_snippet(configuration: &mut configuration::Configuration, params: CreateSnippetParams) -> Result<crate::models::SnippetResponsePost, Error<CreateSnippetError>> {
-    let local_var_configuration = configuration;
+pub async fn create_snippet(configuration: &configuration::Configuration, params: CreateSnippetParams) -> Result<crate::models::SnippetResponsePost, Error<CreateSnippetError>> {
+    create_snippet_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_snippet`], but also returns the status and headers of the response.
+pub async fn create_snippet_with_info(configuration: &configuration::Configuration, params: CreateSnippetParams) -> Result<crate::apis::Response<crate::models::SnippetResponsePost>, Error<CreateSnippetError>> {
+    crate::apis::execute(configuration, create_snippet_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_snippet`] sends.
+pub(crate) fn create_snippet_request(params: CreateSnippetParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -164,24 +171,10 @@ pub async fn create_snippet(configuration: &mut configuration::Configuration, pa
     let priority = params.priority;
     let dynamic = params.dynamic;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/snippet", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_snippet", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/snippet", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -197,307 +190,126 @@ pub async fn create_snippet(configuration: &mut configuration::Configuration, pa
     if let Some(local_var_param_value) = dynamic {
         local_var_form_params.insert("dynamic", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateSnippetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a specific snippet for a particular service and version.
-pub async fn delete_snippet(configuration: &mut configuration::Configuration, params: DeleteSnippetParams) -> Result<crate::models::InlineResponse200, Error<DeleteSnippetError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_snippet(configuration: &configuration::Configuration, params: DeleteSnippetParams) -> Result<crate::models::InlineResponse200, Error<DeleteSnippetError>> {
+    delete_snippet_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_snippet`], but also returns the status and headers of the response.
+pub async fn delete_snippet_with_info(configuration: &configuration::Configuration, params: DeleteSnippetParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteSnippetError>> {
+    crate::apis::execute(configuration, delete_snippet_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`delete_snippet`] sends.
+pub(crate) fn delete_snippet_request(params: DeleteSnippetParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let snippet_name = params.snippet_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/snippet/{snippet_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, snippet_name=crate::apis::urlencode(snippet_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteSnippetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/snippet/{snippet_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, snippet_name=crate::apis::urlencode(snippet_name));
+    crate::apis::Request::new("delete_snippet", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a single snippet for a particular service and version.
-pub async fn get_snippet(configuration: &mut configuration::Configuration, params: GetSnippetParams) -> Result<crate::models::SnippetResponse, Error<GetSnippetError>> {
-    let local_var_configuration = configuration;
+pub async fn get_snippet(configuration: &configuration::Configuration, params: GetSnippetParams) -> Result<crate::models::SnippetResponse, Error<GetSnippetError>> {
+    get_snippet_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_snippet`], but also returns the status and headers of the response.
+pub async fn get_snippet_with_info(configuration: &configuration::Configuration, params: GetSnippetParams) -> Result<crate::apis::Response<crate::models::SnippetResponse>, Error<GetSnippetError>> {
+    crate::apis::execute(configuration, get_snippet_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_snippet`] sends.
+pub(crate) fn get_snippet_request(params: GetSnippetParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let snippet_name = params.snippet_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/snippet/{snippet_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, snippet_name=crate::apis::urlencode(snippet_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetSnippetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/snippet/{snippet_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, snippet_name=crate::apis::urlencode(snippet_name));
+    crate::apis::Request::new("get_snippet", reqwest::Method::GET, local_var_path)
 }
 
 /// Get a single dynamic snippet for a particular service.
-pub async fn get_snippet_dynamic(configuration: &mut configuration::Configuration, params: GetSnippetDynamicParams) -> Result<crate::models::SnippetResponse, Error<GetSnippetDynamicError>> {
-    let local_var_configuration = configuration;
+pub async fn get_snippet_dynamic(configuration: &configuration::Configuration, params: GetSnippetDynamicParams) -> Result<crate::models::SnippetResponse, Error<GetSnippetDynamicError>> {
+    get_snippet_dynamic_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_snippet_dynamic`], but also returns the status and headers of the response.
+pub async fn get_snippet_dynamic_with_info(configuration: &configuration::Configuration, params: GetSnippetDynamicParams) -> Result<crate::apis::Response<crate::models::SnippetResponse>, Error<GetSnippetDynamicError>> {
+    crate::apis::execute(configuration, get_snippet_dynamic_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_snippet_dynamic`] sends.
+pub(crate) fn get_snippet_dynamic_request(params: GetSnippetDynamicParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let snippet_id = params.snippet_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/snippet/{snippet_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), snippet_id=crate::apis::urlencode(snippet_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetSnippetDynamicError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/snippet/{snippet_id}", service_id=crate::apis::urlencode(service_id), snippet_id=crate::apis::urlencode(snippet_id));
+    crate::apis::Request::new("get_snippet_dynamic", reqwest::Method::GET, local_var_path)
 }
 
 /// List all snippets for a particular service and version.
-pub async fn list_snippets(configuration: &mut configuration::Configuration, params: ListSnippetsParams) -> Result<Vec<crate::models::SnippetResponse>, Error<ListSnippetsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_snippets(configuration: &configuration::Configuration, params: ListSnippetsParams) -> Result<Vec<crate::models::SnippetResponse>, Error<ListSnippetsError>> {
+    list_snippets_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_snippets`], but also returns the status and headers of the response.
+pub async fn list_snippets_with_info(configuration: &configuration::Configuration, params: ListSnippetsParams) -> Result<crate::apis::Response<Vec<crate::models::SnippetResponse>>, Error<ListSnippetsError>> {
+    crate::apis::execute(configuration, list_snippets_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_snippets`] sends.
+pub(crate) fn list_snippets_request(params: ListSnippetsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/snippet", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListSnippetsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/snippet", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_snippets", reqwest::Method::GET, local_var_path)
 }
 
 /// Update a specific snippet for a particular service and version.
-pub async fn update_snippet(configuration: &mut configuration::Configuration, params: UpdateSnippetParams) -> Result<crate::models::SnippetResponse, Error<UpdateSnippetError>> {
-    let local_var_configuration = configuration;
+pub async fn update_snippet(configuration: &configuration::Configuration, params: UpdateSnippetParams) -> Result<crate::models::SnippetResponse, Error<UpdateSnippetError>> {
+    update_snippet_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_snippet`], but also returns the status and headers of the response.
+pub async fn update_snippet_with_info(configuration: &configuration::Configuration, params: UpdateSnippetParams) -> Result<crate::apis::Response<crate::models::SnippetResponse>, Error<UpdateSnippetError>> {
+    crate::apis::execute(configuration, update_snippet_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_snippet`] sends.
+pub(crate) fn update_snippet_request(params: UpdateSnippetParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let snippet_name = params.snippet_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/snippet/{snippet_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, snippet_name=crate::apis::urlencode(snippet_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateSnippetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/snippet/{snippet_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, snippet_name=crate::apis::urlencode(snippet_name));
+    crate::apis::Request::new("update_snippet", reqwest::Method::PUT, local_var_path)
 }
 
 /// Update a dynamic snippet for a particular service.
-pub async fn update_snippet_dynamic(configuration: &mut configuration::Configuration, params: UpdateSnippetDynamicParams) -> Result<crate::models::SnippetResponse, Error<UpdateSnippetDynamicError>> {
-    let local_var_configuration = configuration;
+pub async fn update_snippet_dynamic(configuration: &configuration::Configuration, params: UpdateSnippetDynamicParams) -> Result<crate::models::SnippetResponse, Error<UpdateSnippetDynamicError>> {
+    update_snippet_dynamic_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_snippet_dynamic`], but also returns the status and headers of the response.
+pub async fn update_snippet_dynamic_with_info(configuration: &configuration::Configuration, params: UpdateSnippetDynamicParams) -> Result<crate::apis::Response<crate::models::SnippetResponse>, Error<UpdateSnippetDynamicError>> {
+    crate::apis::execute(configuration, update_snippet_dynamic_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_snippet_dynamic`] sends.
+pub(crate) fn update_snippet_dynamic_request(params: UpdateSnippetDynamicParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let snippet_id = params.snippet_id;
@@ -507,24 +319,10 @@ pub async fn update_snippet_dynamic(configuration: &mut configuration::Configura
     let priority = params.priority;
     let dynamic = params.dynamic;
 
+    let local_var_path = format!("/service/{service_id}/snippet/{snippet_id}", service_id=crate::apis::urlencode(service_id), snippet_id=crate::apis::urlencode(snippet_id));
+    let mut local_var_req = crate::apis::Request::new("update_snippet_dynamic", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/snippet/{snippet_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), snippet_id=crate::apis::urlencode(snippet_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
@@ -540,32 +338,8 @@ pub async fn update_snippet_dynamic(configuration: &mut configuration::Configura
     if let Some(local_var_param_value) = dynamic {
         local_var_form_params.insert("dynamic", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateSnippetDynamicError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_service_star`]
@@ -62,210 +61,79 @@ pub enum ListServiceStarsError {
 
 
 /// Create star.
-pub async fn create_service_star(configuration: &mut configuration::Configuration, params: CreateServiceStarParams) -> Result<crate::models::StarResponse, Error<CreateServiceStarError>> {
-    let local_var_configuration = configuration;
+pub async fn create_service_star(configuration: &configuration::Configuration, params: CreateServiceStarParams) -> Result<crate::models::StarResponse, Error<CreateServiceStarError>> {
+    create_service_star_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_service_star`], but also returns the status and headers of the response.
+pub async fn create_service_star_with_info(configuration: &configuration::Configuration, params: CreateServiceStarParams) -> Result<crate::apis::Response<crate::models::StarResponse>, Error<CreateServiceStarError>> {
+    crate::apis::execute(configuration, create_service_star_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_service_star`] sends.
+pub(crate) fn create_service_star_request(params: CreateServiceStarParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let star = params.star;
 
+    let local_var_path = String::from("/stars");
+    let mut local_var_req = crate::apis::Request::new("create_service_star", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stars", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&star);
+    local_var_req = local_var_req.json(serde_json::to_value(&star)?);
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateServiceStarError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete star.
-pub async fn delete_service_star(configuration: &mut configuration::Configuration, params: DeleteServiceStarParams) -> Result<(), Error<DeleteServiceStarError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_service_star(configuration: &configuration::Configuration, params: DeleteServiceStarParams) -> Result<(), Error<DeleteServiceStarError>> {
+    delete_service_star_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_service_star`], but also returns the status and headers of the response.
+pub async fn delete_service_star_with_info(configuration: &configuration::Configuration, params: DeleteServiceStarParams) -> Result<crate::apis::Response<()>, Error<DeleteServiceStarError>> {
+    crate::apis::execute(configuration, delete_service_star_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_service_star`] sends.
+pub(crate) fn delete_service_star_request(params: DeleteServiceStarParams) -> crate::apis::Request {
     // unbox the parameters
     let star_id = params.star_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stars/{star_id}", local_var_configuration.base_path, star_id=crate::apis::urlencode(star_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteServiceStarError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/stars/{star_id}", star_id=crate::apis::urlencode(star_id));
+    crate::apis::Request::new("delete_service_star", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Show star.
-pub async fn get_service_star(configuration: &mut configuration::Configuration, params: GetServiceStarParams) -> Result<crate::models::StarResponse, Error<GetServiceStarError>> {
-    let local_var_configuration = configuration;
+pub async fn get_service_star(configuration: &configuration::Configuration, params: GetServiceStarParams) -> Result<crate::models::StarResponse, Error<GetServiceStarError>> {
+    get_service_star_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_service_star`], but also returns the status and headers of the response.
+pub async fn get_service_star_with_info(configuration: &configuration::Configuration, params: GetServiceStarParams) -> Result<crate::apis::Response<crate::models::StarResponse>, Error<GetServiceStarError>> {
+    crate::apis::execute(configuration, get_service_star_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_service_star`] sends.
+pub(crate) fn get_service_star_request(params: GetServiceStarParams) -> crate::apis::Request {
     // unbox the parameters
     let star_id = params.star_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stars/{star_id}", local_var_configuration.base_path, star_id=crate::apis::urlencode(star_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServiceStarError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/stars/{star_id}", star_id=crate::apis::urlencode(star_id));
+    crate::apis::Request::new("get_service_star", reqwest::Method::GET, local_var_path)
 }
 
 /// List stars.
-pub async fn list_service_stars(configuration: &mut configuration::Configuration) -> Result<crate::models::Pagination, Error<ListServiceStarsError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
-
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/stars", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+pub async fn list_service_stars(configuration: &configuration::Configuration) -> Result<crate::models::Pagination, Error<ListServiceStarsError>> {
+    list_service_stars_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+/// Like [`list_service_stars`], but also returns the status and headers of the response.
+pub async fn list_service_stars_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::Pagination>, Error<ListServiceStarsError>> {
+    crate::apis::execute(configuration, list_service_stars_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListServiceStarsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`list_service_stars`] sends.
+pub(crate) fn list_service_stars_request() -> crate::apis::Request {
+    let local_var_path = String::from("/stars");
+    crate::apis::Request::new("list_service_stars", reqwest::Method::GET, local_var_path)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_service_stats`]
@@ -36,9 +35,17 @@ pub enum GetServiceStatsError {
 
 
 /// Get the stats from a service for a block of time. This lists all stats by PoP location, starting with AMS. This call requires parameters to select block of time to query. Use either a timestamp range (using start_time and end_time) or a specified month/year combo (using month and year).
-pub async fn get_service_stats(configuration: &mut configuration::Configuration, params: GetServiceStatsParams) -> Result<crate::models::Stats, Error<GetServiceStatsError>> {
-    let local_var_configuration = configuration;
+pub async fn get_service_stats(configuration: &configuration::Configuration, params: GetServiceStatsParams) -> Result<crate::models::Stats, Error<GetServiceStatsError>> {
+    get_service_stats_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_service_stats`], but also returns the status and headers of the response.
+pub async fn get_service_stats_with_info(configuration: &configuration::Configuration, params: GetServiceStatsParams) -> Result<crate::apis::Response<crate::models::Stats>, Error<GetServiceStatsError>> {
+    crate::apis::execute(configuration, get_service_stats_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_service_stats`] sends.
+pub(crate) fn get_service_stats_request(params: GetServiceStatsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let month = params.month;
@@ -46,60 +53,22 @@ pub async fn get_service_stats(configuration: &mut configuration::Configuration,
     let start_time = params.start_time;
     let end_time = params.end_time;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/stats/summary", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/stats/summary", service_id=crate::apis::urlencode(service_id));
+    let mut local_var_req = crate::apis::Request::new("get_service_stats", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = month {
-        local_var_req_builder = local_var_req_builder.query(&[("month", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("month", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = year {
-        local_var_req_builder = local_var_req_builder.query(&[("year", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("year", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = start_time {
-        local_var_req_builder = local_var_req_builder.query(&[("start_time", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("start_time", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = end_time {
-        local_var_req_builder = local_var_req_builder.query(&[("end_time", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("end_time", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
 
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServiceStatsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -0,0 +1,583 @@
+//! Deserializing list responses one item at a time.
+//!
+//! The endpoint functions read the whole body before deserializing it, which
+//! on large accounts means holding every service, dictionary item or stats
+//! entry in memory at once, twice. [`stream`] sends the same request but
+//! reads the body as it arrives and yields each element of the list as soon
+//! as it is complete:
+//!
+//! ```no_run
+//! # use fastly_api::apis::configuration::Configuration;
+//! # use fastly_api::apis::service_api::ListServicesParams;
+//! # use fastly_api::apis::streaming::stream;
+//! # use futures::TryStreamExt;
+//! # async fn run(cfg: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut services = stream(cfg, ListServicesParams::default());
+//! while let Some(service) = services.try_next().await? {
+//!     println!("{:?}", service.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Only the element being read is buffered, so memory use depends on the
+//! size of the largest element rather than of the response. Fields of the
+//! body outside the list, such as `meta`, are skipped. Error responses are
+//! read whole and reported as usual; a body that breaks off or is malformed
+//! ends the stream with [`Error::Serde`]. A [`Cassette`](super::cassette::Cassette)
+//! reads each body whole to record it.
+//!
+//! Historical stats are grouped by service ID, as a map from each ID to its
+//! list of entries, and per-PoP stats by PoP. Those endpoints, such as
+//! `historical_api::get_hist_stats` with `by: Some("minute".to_owned())`,
+//! yield `(key, item)` pairs, with the items of each list in turn; see
+//! [`Layout`].
+//!
+//! Each call fetches one page; combine it with [`pagination`](super::pagination)
+//! params to walk further pages.
+
+use std::collections::VecDeque;
+
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use super::configuration::Configuration;
+use super::{BoxFuture, Error};
+
+/// Params of a list endpoint whose items can be read with [`stream`].
+pub trait Streamed: Send + Sized + 'static {
+    type Item: DeserializeOwned + Send + 'static;
+    type Error: DeserializeOwned + Send + 'static;
+
+    /// Object keys leading from the top of the body to the list of items;
+    /// empty when the body is the list.
+    const ITEMS: &'static [&'static str];
+
+    /// How the items are laid out at [`ITEMS`](Streamed::ITEMS).
+    const LAYOUT: Layout = Layout::List;
+
+    /// Send the request, leaving the body of a successful response unread.
+    fn send(self, configuration: &Configuration) -> BoxFuture<'_, Result<reqwest::Response, Error<Self::Error>>>;
+}
+
+/// Where the items are found at the end of [`Streamed::ITEMS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// A list of items.
+    List,
+    /// An object whose values are lists of items; each item is yielded with
+    /// the key of its list as a `(String, T)` pair.
+    KeyedLists,
+    /// An object whose values are the items; each is yielded with its key as
+    /// a `(String, T)` pair.
+    Keyed,
+}
+
+/// Stream the items of the list endpoint `params` belong to as the response
+/// body arrives.
+pub fn stream<P: Streamed>(configuration: &Configuration, params: P) -> BoxStream<'_, Result<P::Item, Error<P::Error>>> {
+    stream::once(async move {
+        let response = params.send(configuration).await?;
+        Ok::<_, Error<P::Error>>(items(response, P::ITEMS, P::LAYOUT))
+    })
+    .try_flatten()
+    .boxed()
+}
+
+fn items<T: DeserializeOwned + Send + 'static, E: Send + 'static>(
+    response: reqwest::Response,
+    path: &'static [&'static str],
+    layout: Layout,
+) -> BoxStream<'static, Result<T, Error<E>>> {
+    stream::try_unfold((Some(response), Scanner::new(path, layout)), |(mut response, mut scanner)| async move {
+        loop {
+            if let Some(element) = scanner.elements.pop_front() {
+                let item = serde_json::from_slice(&element)?;
+                return Ok(Some((item, (response, scanner))));
+            }
+            let Some(body) = response.as_mut() else { return Ok(None) };
+            match body.chunk().await? {
+                Some(chunk) => scanner.feed(&chunk)?,
+                None => {
+                    scanner.finish()?;
+                    response = None;
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+/// What an open `{` or `[` is, as far as finding the items is concerned.
+#[derive(Debug)]
+enum Frame {
+    /// An object on the way to the items: `level` keys of the path have been
+    /// matched to get here. `key` is the key of the member being read.
+    Path { level: usize, key: Vec<u8>, in_key: bool, expect_key: bool },
+    /// A list of items, with the key of the member it is the value of in a
+    /// [`Layout::KeyedLists`] object.
+    Items { key: Option<Vec<u8>> },
+    /// The object of a keyed layout. `key` is the key of the member being
+    /// read, still escaped as in the body.
+    Keys { key: Vec<u8>, in_key: bool, expect_key: bool },
+    /// Anything else.
+    Other,
+}
+
+/// Finds the items of one JSON array or object in a body fed to it in chunks.
+///
+/// It tracks just enough structure (nesting, strings and object keys on the
+/// path) to know where each item starts and ends; the items themselves are
+/// parsed by serde once complete. Keyed items are handed over as a
+/// `["key",item]` array so that serde reads them as a `(String, T)` pair.
+#[derive(Debug)]
+struct Scanner {
+    path: &'static [&'static str],
+    layout: Layout,
+    frames: Vec<Frame>,
+    /// Index in `frames` of the list or object whose values are the items,
+    /// once inside it.
+    items_at: Option<usize>,
+    in_string: bool,
+    escaped: bool,
+    started: bool,
+    done: bool,
+    element: Vec<u8>,
+    elements: VecDeque<Vec<u8>>,
+}
+
+impl Scanner {
+    fn new(path: &'static [&'static str], layout: Layout) -> Scanner {
+        Scanner {
+            path,
+            layout,
+            frames: Vec::new(),
+            items_at: None,
+            in_string: false,
+            escaped: false,
+            started: false,
+            done: false,
+            element: Vec::new(),
+            elements: VecDeque::new(),
+        }
+    }
+
+    fn feed(&mut self, chunk: &[u8]) -> Result<(), serde_json::Error> {
+        for &byte in chunk {
+            if self.done {
+                break;
+            }
+            self.byte(byte)?;
+        }
+        Ok(())
+    }
+
+    fn finish(&self) -> Result<(), serde_json::Error> {
+        if self.done || (self.frames.is_empty() && !self.in_string) {
+            Ok(())
+        } else {
+            Err(malformed("body ended before the list was complete"))
+        }
+    }
+
+    fn byte(&mut self, byte: u8) -> Result<(), serde_json::Error> {
+        // Everything inside the items belongs to one of them, except the keys
+        // of a keyed object.
+        let capturing = self.items_at.is_some();
+        if self.in_string {
+            let mut reading_key = false;
+            if let Some(Frame::Path { key, in_key: in_key @ true, .. } | Frame::Keys { key, in_key: in_key @ true, .. }) = self.frames.last_mut() {
+                reading_key = true;
+                if !self.escaped && byte == b'"' {
+                    *in_key = false;
+                } else {
+                    key.push(byte);
+                }
+            }
+            if capturing && !reading_key {
+                self.element.push(byte);
+            }
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            }
+            return Ok(());
+        }
+        if byte.is_ascii_whitespace() {
+            return Ok(());
+        }
+
+        match self.frames.last_mut() {
+            Some(Frame::Items { .. }) => match byte {
+                b',' => self.end_element(),
+                b']' => {
+                    self.end_element();
+                    self.items_at = None;
+                    // The list of one key is followed by those of the others.
+                    if let Some(Frame::Items { key: None }) = self.frames.pop() {
+                        self.done = true;
+                    }
+                }
+                _ => {
+                    self.element.push(byte);
+                    self.open(byte, Frame::Other);
+                }
+            },
+            Some(Frame::Keys { key, in_key, expect_key }) => match byte {
+                b'"' if *expect_key => {
+                    key.clear();
+                    *in_key = true;
+                    *expect_key = false;
+                    self.in_string = true;
+                }
+                b':' => {}
+                b',' => {
+                    *expect_key = true;
+                    self.end_element();
+                }
+                b'}' => {
+                    self.end_element();
+                    self.frames.pop();
+                    self.items_at = None;
+                    self.done = true;
+                }
+                b'[' if self.layout == Layout::KeyedLists => {
+                    let key = Some(key.clone());
+                    self.push(Frame::Items { key });
+                }
+                _ => {
+                    if capturing {
+                        self.element.push(byte);
+                    }
+                    self.open(byte, Frame::Other);
+                }
+            },
+            Some(Frame::Path { level, key, in_key, expect_key }) => match byte {
+                b'"' if *expect_key => {
+                    key.clear();
+                    *in_key = true;
+                    *expect_key = false;
+                    self.in_string = true;
+                }
+                b',' => *expect_key = true,
+                b':' => {}
+                b'}' => {
+                    self.frames.pop();
+                }
+                b'{' | b'[' => {
+                    let level = *level;
+                    let on_path = self.path.get(level).is_some_and(|k| k.as_bytes() == key.as_slice());
+                    let frame = match self.target(byte) {
+                        Some(target) if on_path && level + 1 == self.path.len() => target,
+                        _ if on_path && byte == b'{' && level + 1 < self.path.len() => Frame::Path { level: level + 1, key: Vec::new(), in_key: false, expect_key: true },
+                        _ => Frame::Other,
+                    };
+                    self.push(frame);
+                }
+                _ => self.open(byte, Frame::Other),
+            },
+            Some(Frame::Other) => {
+                if capturing {
+                    self.element.push(byte);
+                }
+                match byte {
+                    b'}' | b']' => {
+                        self.frames.pop();
+                    }
+                    _ => self.open(byte, Frame::Other),
+                }
+            }
+            // Past the end of a scalar body.
+            None if self.started => {}
+            None => {
+                self.started = true;
+                let frame = match self.target(byte) {
+                    Some(target) if self.path.is_empty() => target,
+                    _ if byte == b'{' && !self.path.is_empty() => Frame::Path { level: 0, key: Vec::new(), in_key: false, expect_key: true },
+                    _ => Frame::Other,
+                };
+                self.open(byte, frame);
+            }
+        }
+        Ok(())
+    }
+
+    /// The frame for `byte` opening the list or object holding the items,
+    /// if it can.
+    fn target(&self, byte: u8) -> Option<Frame> {
+        match (self.layout, byte) {
+            (Layout::List, b'[') => Some(Frame::Items { key: None }),
+            (Layout::KeyedLists | Layout::Keyed, b'{') => Some(Frame::Keys { key: Vec::new(), in_key: false, expect_key: true }),
+            _ => None,
+        }
+    }
+
+    /// Account for `byte` starting a string, object or array.
+    fn open(&mut self, byte: u8, frame: Frame) {
+        match byte {
+            b'"' => self.in_string = true,
+            b'{' | b'[' => self.push(frame),
+            _ => {}
+        }
+    }
+
+    fn push(&mut self, frame: Frame) {
+        match frame {
+            Frame::Items { .. } => self.items_at = Some(self.frames.len()),
+            Frame::Keys { .. } if self.layout == Layout::Keyed => self.items_at = Some(self.frames.len()),
+            _ => {}
+        }
+        self.frames.push(frame);
+    }
+
+    fn end_element(&mut self) {
+        if self.element.is_empty() {
+            return;
+        }
+        let element = std::mem::take(&mut self.element);
+        let key = match self.items_at.map(|at| &self.frames[at]) {
+            Some(Frame::Items { key: Some(key) } | Frame::Keys { key, .. }) => Some(key),
+            _ => None,
+        };
+        let element = match key {
+            Some(key) => [&b"[\""[..], key, b"\",", &element, b"]"].concat(),
+            None => element,
+        };
+        self.elements.push_back(element);
+    }
+}
+
+fn malformed(message: &str) -> serde_json::Error {
+    <serde_json::Error as serde::de::Error>::custom(message)
+}
+
+/// `$params` are sent by `$module::$request`; the items are at the path of
+/// object keys in brackets, laid out as a [`Layout::List`] unless another
+/// layout follows the path.
+macro_rules! streamed {
+    ($($(#[$attr:meta])* $module:ident :: $request:ident($params:ident) [$($key:literal),*] $($layout:ident)? -> $item:ty, $error:ident;)*) => {$(
+        $(#[$attr])*
+        impl Streamed for super::$module::$params {
+            type Item = $item;
+            type Error = super::$module::$error;
+
+            const ITEMS: &'static [&'static str] = &[$($key),*];
+            $(const LAYOUT: Layout = Layout::$layout;)?
+
+            fn send(self, configuration: &Configuration) -> BoxFuture<'_, Result<reqwest::Response, Error<Self::Error>>> {
+                Box::pin(super::execute_streaming(configuration, super::$module::$request(self)))
+            }
+        }
+    )*};
+}
+
+streamed! {
+    #[cfg(feature = "acl")]
+    acl_api::list_acls_request(ListAclsParams) [] -> crate::models::AclResponse, ListAclsError;
+    #[cfg(feature = "acl")]
+    acl_entry_api::list_acl_entries_request(ListAclEntriesParams) [] -> crate::models::AclEntryResponse, ListAclEntriesError;
+    #[cfg(feature = "domains")]
+    apex_redirect_api::list_apex_redirects_request(ListApexRedirectsParams) [] -> crate::models::ApexRedirect, ListApexRedirectsError;
+    #[cfg(feature = "accounts")]
+    automation_tokens_api::get_automation_tokens_id_services_request(GetAutomationTokensIdServicesParams) ["data"] -> String, GetAutomationTokensIdServicesError;
+    #[cfg(feature = "accounts")]
+    automation_tokens_api::list_automation_tokens_request(ListAutomationTokensParams) [] -> crate::models::AutomationTokenResponse, ListAutomationTokensError;
+    #[cfg(feature = "backends")]
+    backend_api::list_backends_request(ListBackendsParams) [] -> crate::models::BackendResponse, ListBackendsError;
+    #[cfg(feature = "billing")]
+    billing_invoices_api::list_invoices_request(ListInvoicesParams) ["data"] -> crate::models::Invoice, ListInvoicesError;
+    #[cfg(feature = "billing")]
+    billing_usage_metrics_api::get_service_level_usage_types_request(GetServiceLevelUsageTypesParams) ["data"] -> crate::models::Serviceusagetype, GetServiceLevelUsageTypesError;
+    #[cfg(feature = "vcl")]
+    cache_settings_api::list_cache_settings_request(ListCacheSettingsParams) [] -> crate::models::CacheSettingResponse, ListCacheSettingsError;
+    #[cfg(feature = "vcl")]
+    condition_api::list_conditions_request(ListConditionsParams) [] -> crate::models::ConditionResponse, ListConditionsError;
+    #[cfg(feature = "stores")]
+    config_store_api::list_config_stores_request(ListConfigStoresParams) [] -> crate::models::ConfigStoreResponse, ListConfigStoresError;
+    #[cfg(feature = "stores")]
+    config_store_item_api::list_config_store_items_request(ListConfigStoreItemsParams) [] -> crate::models::ConfigStoreItemResponse, ListConfigStoreItemsError;
+    #[cfg(feature = "accounts")]
+    contact_api::list_contacts_request(ListContactsParams) [] -> crate::models::SchemasContactResponse, ListContactsError;
+    #[cfg(feature = "platform")]
+    content_api::content_check_request(ContentCheckParams) [] -> crate::models::Content, ContentCheckError;
+    #[cfg(feature = "accounts")]
+    customer_api::list_users_request(ListUsersParams) [] -> crate::models::SchemasUserResponse, ListUsersError;
+    #[cfg(feature = "dictionary")]
+    dictionary_api::list_dictionaries_request(ListDictionariesParams) [] -> crate::models::DictionaryResponse, ListDictionariesError;
+    #[cfg(feature = "dictionary")]
+    dictionary_item_api::list_dictionary_items_request(ListDictionaryItemsParams) [] -> crate::models::DictionaryItemResponse, ListDictionaryItemsError;
+    #[cfg(feature = "backends")]
+    director_api::list_directors_request(ListDirectorsParams) [] -> crate::models::DirectorResponse, ListDirectorsError;
+    #[cfg(feature = "domains")]
+    domain_api::check_domain_request(CheckDomainParams) [] -> serde_json::Value, CheckDomainError;
+    #[cfg(feature = "domains")]
+    domain_api::list_domains_request(ListDomainsParams) [] -> crate::models::DomainResponse, ListDomainsError;
+    #[cfg(feature = "stats")]
+    domain_inspector_historical_api::get_domain_inspector_historical_request(GetDomainInspectorHistoricalParams) ["data"] -> crate::models::DomainInspectorEntry, GetDomainInspectorHistoricalError;
+    #[cfg(feature = "stats")]
+    domain_inspector_realtime_api::get_domain_inspector_last120_seconds_request(GetDomainInspectorLast120SecondsParams) ["data"] -> crate::models::DomainInspectorRealtimeEntry, GetDomainInspectorLast120SecondsError;
+    #[cfg(feature = "stats")]
+    domain_inspector_realtime_api::get_domain_inspector_last_max_entries_request(GetDomainInspectorLastMaxEntriesParams) ["data"] -> crate::models::DomainInspectorRealtimeEntry, GetDomainInspectorLastMaxEntriesError;
+    #[cfg(feature = "stats")]
+    domain_inspector_realtime_api::get_domain_inspector_last_second_request(GetDomainInspectorLastSecondParams) ["data"] -> crate::models::DomainInspectorRealtimeEntry, GetDomainInspectorLastSecondError;
+    #[cfg(feature = "accounts")]
+    events_api::list_events_request(ListEventsParams) ["data"] -> crate::models::Event, ListEventsError;
+    #[cfg(feature = "vcl")]
+    gzip_api::list_gzip_configs_request(ListGzipConfigsParams) [] -> crate::models::GzipResponse, ListGzipConfigsError;
+    #[cfg(feature = "vcl")]
+    header_api::list_header_objects_request(ListHeaderObjectsParams) [] -> crate::models::HeaderResponse, ListHeaderObjectsError;
+    #[cfg(feature = "healthcheck")]
+    healthcheck_api::list_healthchecks_request(ListHealthchecksParams) [] -> crate::models::HealthcheckResponse, ListHealthchecksError;
+    #[cfg(feature = "stats")]
+    historical_api::get_hist_stats_request(GetHistStatsParams) ["data"] KeyedLists -> (String, crate::models::Results), GetHistStatsError;
+    #[cfg(feature = "stats")]
+    historical_api::get_hist_stats_aggregated_request(GetHistStatsAggregatedParams) ["data"] -> crate::models::Results, GetHistStatsAggregatedError;
+    #[cfg(feature = "stats")]
+    historical_api::get_hist_stats_field_request(GetHistStatsFieldParams) ["data"] KeyedLists -> (String, crate::models::HistoricalFieldResultsAttributes), GetHistStatsFieldError;
+    #[cfg(feature = "stats")]
+    historical_api::get_hist_stats_service_request(GetHistStatsServiceParams) ["data"] -> crate::models::Results, GetHistStatsServiceError;
+    #[cfg(feature = "stats")]
+    historical_api::get_hist_stats_service_field_request(GetHistStatsServiceFieldParams) ["data"] -> crate::models::HistoricalFieldResultsAttributes, GetHistStatsServiceFieldError;
+    #[cfg(feature = "stats")]
+    historical_api::get_usage_month_request(GetUsageMonthParams) ["data", "services"] Keyed -> (String, crate::models::HistoricalService), GetUsageMonthError;
+    #[cfg(feature = "accounts")]
+    invitations_api::list_invitations_request(ListInvitationsParams) ["data"] -> crate::models::InvitationResponseData, ListInvitationsError;
+    #[cfg(feature = "stores")]
+    kv_store_api::get_stores_request(GetStoresParams) ["data"] -> crate::models::StoreResponse, GetStoresError;
+    #[cfg(feature = "stores")]
+    kv_store_item_api::get_keys_request(GetKeysParams) ["data"] -> String, GetKeysError;
+    #[cfg(feature = "waf-legacy")]
+    legacy_waf_rule_api::list_legacy_waf_rules_request(ListLegacyWafRulesParams) [] -> serde_json::Value, ListLegacyWafRulesError;
+    #[cfg(feature = "logging")]
+    logging_azureblob_api::list_log_azure_request(ListLogAzureParams) [] -> crate::models::LoggingAzureblobResponse, ListLogAzureError;
+    #[cfg(feature = "logging")]
+    logging_bigquery_api::list_log_bigquery_request(ListLogBigqueryParams) [] -> crate::models::LoggingBigqueryResponse, ListLogBigqueryError;
+    #[cfg(feature = "logging")]
+    logging_cloudfiles_api::list_log_cloudfiles_request(ListLogCloudfilesParams) [] -> crate::models::LoggingCloudfilesResponse, ListLogCloudfilesError;
+    #[cfg(feature = "logging")]
+    logging_datadog_api::list_log_datadog_request(ListLogDatadogParams) [] -> crate::models::LoggingDatadogResponse, ListLogDatadogError;
+    #[cfg(feature = "logging")]
+    logging_digitalocean_api::list_log_digocean_request(ListLogDigoceanParams) [] -> crate::models::LoggingDigitaloceanResponse, ListLogDigoceanError;
+    #[cfg(feature = "logging")]
+    logging_elasticsearch_api::list_log_elasticsearch_request(ListLogElasticsearchParams) [] -> crate::models::LoggingElasticsearchResponse, ListLogElasticsearchError;
+    #[cfg(feature = "logging")]
+    logging_ftp_api::list_log_ftp_request(ListLogFtpParams) [] -> crate::models::LoggingFtpResponse, ListLogFtpError;
+    #[cfg(feature = "logging")]
+    logging_gcs_api::list_log_gcs_request(ListLogGcsParams) [] -> crate::models::LoggingGcsResponse, ListLogGcsError;
+    #[cfg(feature = "logging")]
+    logging_heroku_api::list_log_heroku_request(ListLogHerokuParams) [] -> crate::models::LoggingHerokuResponse, ListLogHerokuError;
+    #[cfg(feature = "logging")]
+    logging_honeycomb_api::list_log_honeycomb_request(ListLogHoneycombParams) [] -> crate::models::LoggingHoneycombResponse, ListLogHoneycombError;
+    #[cfg(feature = "logging")]
+    logging_https_api::list_log_https_request(ListLogHttpsParams) [] -> crate::models::LoggingHttpsResponse, ListLogHttpsError;
+    #[cfg(feature = "logging")]
+    logging_kafka_api::list_log_kafka_request(ListLogKafkaParams) [] -> crate::models::LoggingKafkaResponse, ListLogKafkaError;
+    #[cfg(feature = "logging")]
+    logging_kinesis_api::list_log_kinesis_request(ListLogKinesisParams) [] -> crate::models::LoggingKinesisResponse, ListLogKinesisError;
+    #[cfg(feature = "logging")]
+    logging_logentries_api::list_log_logentries_request(ListLogLogentriesParams) [] -> crate::models::LoggingLogentriesResponse, ListLogLogentriesError;
+    #[cfg(feature = "logging")]
+    logging_loggly_api::list_log_loggly_request(ListLogLogglyParams) [] -> crate::models::LoggingLogglyResponse, ListLogLogglyError;
+    #[cfg(feature = "logging")]
+    logging_logshuttle_api::list_log_logshuttle_request(ListLogLogshuttleParams) [] -> crate::models::LoggingLogshuttleResponse, ListLogLogshuttleError;
+    #[cfg(feature = "logging")]
+    logging_newrelic_api::list_log_newrelic_request(ListLogNewrelicParams) [] -> crate::models::LoggingNewrelicResponse, ListLogNewrelicError;
+    #[cfg(feature = "logging")]
+    logging_newrelicotlp_api::list_log_newrelicotlp_request(ListLogNewrelicotlpParams) [] -> crate::models::LoggingNewrelicotlpResponse, ListLogNewrelicotlpError;
+    #[cfg(feature = "logging")]
+    logging_openstack_api::list_log_openstack_request(ListLogOpenstackParams) [] -> crate::models::LoggingOpenstackResponse, ListLogOpenstackError;
+    #[cfg(feature = "logging")]
+    logging_papertrail_api::list_log_papertrail_request(ListLogPapertrailParams) [] -> crate::models::LoggingPapertrailResponse, ListLogPapertrailError;
+    #[cfg(feature = "logging")]
+    logging_pubsub_api::list_log_gcp_pubsub_request(ListLogGcpPubsubParams) [] -> crate::models::LoggingGooglePubsubResponse, ListLogGcpPubsubError;
+    #[cfg(feature = "logging")]
+    logging_s3_api::list_log_aws_s3_request(ListLogAwsS3Params) [] -> crate::models::LoggingS3Response, ListLogAwsS3Error;
+    #[cfg(feature = "logging")]
+    logging_scalyr_api::list_log_scalyr_request(ListLogScalyrParams) [] -> crate::models::LoggingScalyrResponse, ListLogScalyrError;
+    #[cfg(feature = "logging")]
+    logging_sftp_api::list_log_sftp_request(ListLogSftpParams) [] -> crate::models::LoggingSftpResponse, ListLogSftpError;
+    #[cfg(feature = "logging")]
+    logging_splunk_api::list_log_splunk_request(ListLogSplunkParams) [] -> crate::models::LoggingSplunkResponse, ListLogSplunkError;
+    #[cfg(feature = "logging")]
+    logging_sumologic_api::list_log_sumologic_request(ListLogSumologicParams) [] -> crate::models::LoggingSumologicResponse, ListLogSumologicError;
+    #[cfg(feature = "logging")]
+    logging_syslog_api::list_log_syslog_request(ListLogSyslogParams) [] -> crate::models::LoggingSyslogResponse, ListLogSyslogError;
+    #[cfg(feature = "tls")]
+    mutual_authentication_api::list_mutual_authentications_request(ListMutualAuthenticationsParams) ["data"] -> crate::models::MutualAuthenticationResponseData, ListMutualAuthenticationsError;
+    #[cfg(feature = "stats")]
+    origin_inspector_historical_api::get_origin_inspector_historical_request(GetOriginInspectorHistoricalParams) ["data"] -> crate::models::OriginInspectorEntry, GetOriginInspectorHistoricalError;
+    #[cfg(feature = "stats")]
+    origin_inspector_realtime_api::get_origin_inspector_last120_seconds_request(GetOriginInspectorLast120SecondsParams) ["data"] -> crate::models::OriginInspectorRealtimeEntry, GetOriginInspectorLast120SecondsError;
+    #[cfg(feature = "stats")]
+    origin_inspector_realtime_api::get_origin_inspector_last_max_entries_request(GetOriginInspectorLastMaxEntriesParams) ["data"] -> crate::models::OriginInspectorRealtimeEntry, GetOriginInspectorLastMaxEntriesError;
+    #[cfg(feature = "stats")]
+    origin_inspector_realtime_api::get_origin_inspector_last_second_request(GetOriginInspectorLastSecondParams) ["data"] -> crate::models::OriginInspectorRealtimeEntry, GetOriginInspectorLastSecondError;
+    #[cfg(feature = "backends")]
+    pool_api::list_server_pools_request(ListServerPoolsParams) [] -> crate::models::PoolResponse, ListServerPoolsError;
+    #[cfg(feature = "vcl")]
+    rate_limiter_api::list_rate_limiters_request(ListRateLimitersParams) [] -> crate::models::RateLimiterResponse, ListRateLimitersError;
+    #[cfg(feature = "stats")]
+    realtime_api::get_stats_last120_seconds_request(GetStatsLast120SecondsParams) ["data"] -> crate::models::RealtimeEntry, GetStatsLast120SecondsError;
+    #[cfg(feature = "stats")]
+    realtime_api::get_stats_last120_seconds_limit_entries_request(GetStatsLast120SecondsLimitEntriesParams) ["data"] -> crate::models::RealtimeEntry, GetStatsLast120SecondsLimitEntriesError;
+    #[cfg(feature = "stats")]
+    realtime_api::get_stats_last_second_request(GetStatsLastSecondParams) ["data"] -> crate::models::RealtimeEntry, GetStatsLastSecondError;
+    #[cfg(feature = "vcl")]
+    request_settings_api::list_request_settings_request(ListRequestSettingsParams) [] -> crate::models::RequestSettingsResponse, ListRequestSettingsError;
+    #[cfg(feature = "compute")]
+    resource_api::list_resources_request(ListResourcesParams) [] -> crate::models::ResourceResponse, ListResourcesError;
+    #[cfg(feature = "vcl")]
+    response_object_api::list_response_objects_request(ListResponseObjectsParams) [] -> crate::models::ResponseObjectResponse, ListResponseObjectsError;
+    #[cfg(feature = "stores")]
+    secret_store_api::get_secret_stores_request(GetSecretStoresParams) ["data"] -> crate::models::SecretStoreResponse, GetSecretStoresError;
+    #[cfg(feature = "stores")]
+    secret_store_item_api::get_secrets_request(GetSecretsParams) ["data"] -> crate::models::SecretResponse, GetSecretsError;
+    #[cfg(feature = "backends")]
+    server_api::list_pool_servers_request(ListPoolServersParams) [] -> crate::models::ServerResponse, ListPoolServersError;
+    service_api::list_service_domains_request(ListServiceDomainsParams) [] -> crate::models::DomainResponse, ListServiceDomainsError;
+    service_api::list_services_request(ListServicesParams) [] -> crate::models::ServiceListResponse, ListServicesError;
+    #[cfg(feature = "iam")]
+    service_authorizations_api::list_service_authorization_request(ListServiceAuthorizationParams) ["data"] -> crate::models::ServiceAuthorizationResponseData, ListServiceAuthorizationError;
+    #[cfg(feature = "vcl")]
+    snippet_api::list_snippets_request(ListSnippetsParams) [] -> crate::models::SnippetResponse, ListSnippetsError;
+    #[cfg(feature = "stats")]
+    stats_api::get_service_stats_request(GetServiceStatsParams) ["stats"] Keyed -> (String, crate::models::Results), GetServiceStatsError;
+    #[cfg(feature = "tls")]
+    tls_activations_api::list_tls_activations_request(ListTlsActivationsParams) ["data"] -> crate::models::TlsActivationResponseData, ListTlsActivationsError;
+    #[cfg(feature = "tls")]
+    tls_bulk_certificates_api::list_tls_bulk_certs_request(ListTlsBulkCertsParams) ["data"] -> crate::models::TlsBulkCertificateResponseData, ListTlsBulkCertsError;
+    #[cfg(feature = "tls")]
+    tls_certificates_api::list_tls_certs_request(ListTlsCertsParams) ["data"] -> crate::models::TlsCertificateResponseData, ListTlsCertsError;
+    #[cfg(feature = "tls")]
+    tls_configurations_api::list_tls_configs_request(ListTlsConfigsParams) ["data"] -> crate::models::TlsConfigurationResponseData, ListTlsConfigsError;
+    #[cfg(feature = "tls")]
+    tls_domains_api::list_tls_domains_request(ListTlsDomainsParams) ["data"] -> crate::models::TlsDomainData, ListTlsDomainsError;
+    #[cfg(feature = "tls")]
+    tls_private_keys_api::list_tls_keys_request(ListTlsKeysParams) ["data"] -> crate::models::TlsPrivateKeyResponseData, ListTlsKeysError;
+    #[cfg(feature = "tls")]
+    tls_subscriptions_api::list_tls_subs_request(ListTlsSubsParams) ["data"] -> crate::models::TlsSubscriptionResponse, ListTlsSubsError;
+    #[cfg(feature = "accounts")]
+    tokens_api::list_tokens_customer_request(ListTokensCustomerParams) [] -> crate::models::TokenResponse, ListTokensCustomerError;
+    #[cfg(feature = "vcl")]
+    vcl_api::list_custom_vcl_request(ListCustomVclParams) [] -> crate::models::VclResponse, ListCustomVclError;
+    version_api::list_service_versions_request(ListServiceVersionsParams) [] -> crate::models::VersionResponse, ListServiceVersionsError;
+    #[cfg(feature = "waf")]
+    waf_active_rules_api::list_waf_active_rules_request(ListWafActiveRulesParams) ["data"] -> crate::models::WafActiveRuleResponseData, ListWafActiveRulesError;
+    #[cfg(feature = "waf")]
+    waf_exclusions_api::list_waf_rule_exclusions_request(ListWafRuleExclusionsParams) ["data"] -> crate::models::WafExclusionResponseData, ListWafRuleExclusionsError;
+    #[cfg(feature = "waf")]
+    waf_firewall_versions_api::list_waf_firewall_versions_request(ListWafFirewallVersionsParams) ["data"] -> crate::models::WafFirewallVersionResponseData, ListWafFirewallVersionsError;
+    #[cfg(feature = "waf")]
+    waf_firewalls_api::list_waf_firewalls_request(ListWafFirewallsParams) ["data"] -> crate::models::WafFirewallResponseData, ListWafFirewallsError;
+    #[cfg(feature = "waf")]
+    waf_rule_revisions_api::list_waf_rule_revisions_request(ListWafRuleRevisionsParams) ["data"] -> crate::models::WafRuleRevisionResponseData, ListWafRuleRevisionsError;
+    #[cfg(feature = "waf")]
+    waf_rules_api::list_waf_rules_request(ListWafRulesParams) ["data"] -> crate::models::WafRuleResponseData, ListWafRulesError;
+    #[cfg(feature = "waf")]
+    waf_tags_api::list_waf_tags_request(ListWafTagsParams) ["data"] -> crate::models::WafTagsResponseDataItem, ListWafTagsError;
+    #[cfg(feature = "stats")]
+    whole_platform_ddos_historical_api::get_platform_ddos_historical_request(GetPlatformDdosHistoricalParams) ["data"] -> crate::models::PlatformDdosEntry, GetPlatformDdosHistoricalError;
+}
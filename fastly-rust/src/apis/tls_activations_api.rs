@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_tls_activation`]
@@ -96,170 +95,85 @@ pub enum UpdateTlsActivationError {
 
 
 /// Enable TLS for a particular TLS domain and certificate combination. These relationships must be specified to create the TLS activation.
-pub async fn create_tls_activation(configuration: &mut configuration::Configuration, params: CreateTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<CreateTlsActivationError>> {
-    let local_var_configuration = configuration;
+pub async fn create_tls_activation(configuration: &configuration::Configuration, params: CreateTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<CreateTlsActivationError>> {
+    create_tls_activation_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_tls_activation`], but also returns the status and headers of the response.
+pub async fn create_tls_activation_with_info(configuration: &configuration::Configuration, params: CreateTlsActivationParams) -> Result<crate::apis::Response<crate::models::TlsActivationResponse>, Error<CreateTlsActivationError>> {
+    crate::apis::execute(configuration, create_tls_activation_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_tls_activation`] sends.
+pub(crate) fn create_tls_activation_request(params: CreateTlsActivationParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let tls_activation = params.tls_activation;
 
+    let local_var_path = String::from("/tls/activations");
+    let mut local_var_req = crate::apis::Request::new("create_tls_activation", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/activations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_activation);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_activation)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateTlsActivationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Disable TLS on the domain associated with this TLS activation.
-pub async fn delete_tls_activation(configuration: &mut configuration::Configuration, params: DeleteTlsActivationParams) -> Result<(), Error<DeleteTlsActivationError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_tls_activation(configuration: &configuration::Configuration, params: DeleteTlsActivationParams) -> Result<(), Error<DeleteTlsActivationError>> {
+    delete_tls_activation_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_tls_activation`], but also returns the status and headers of the response.
+pub async fn delete_tls_activation_with_info(configuration: &configuration::Configuration, params: DeleteTlsActivationParams) -> Result<crate::apis::Response<()>, Error<DeleteTlsActivationError>> {
+    crate::apis::execute(configuration, delete_tls_activation_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_tls_activation`] sends.
+pub(crate) fn delete_tls_activation_request(params: DeleteTlsActivationParams) -> crate::apis::Request {
     // unbox the parameters
     let tls_activation_id = params.tls_activation_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/activations/{tls_activation_id}", local_var_configuration.base_path, tls_activation_id=crate::apis::urlencode(tls_activation_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteTlsActivationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/activations/{tls_activation_id}", tls_activation_id=crate::apis::urlencode(tls_activation_id));
+    crate::apis::Request::new("delete_tls_activation", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Show a TLS activation.
-pub async fn get_tls_activation(configuration: &mut configuration::Configuration, params: GetTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<GetTlsActivationError>> {
-    let local_var_configuration = configuration;
+pub async fn get_tls_activation(configuration: &configuration::Configuration, params: GetTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<GetTlsActivationError>> {
+    get_tls_activation_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_tls_activation`], but also returns the status and headers of the response.
+pub async fn get_tls_activation_with_info(configuration: &configuration::Configuration, params: GetTlsActivationParams) -> Result<crate::apis::Response<crate::models::TlsActivationResponse>, Error<GetTlsActivationError>> {
+    crate::apis::execute(configuration, get_tls_activation_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_tls_activation`] sends.
+pub(crate) fn get_tls_activation_request(params: GetTlsActivationParams) -> crate::apis::Request {
     // unbox the parameters
     let tls_activation_id = params.tls_activation_id;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/activations/{tls_activation_id}", local_var_configuration.base_path, tls_activation_id=crate::apis::urlencode(tls_activation_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/tls/activations/{tls_activation_id}", tls_activation_id=crate::apis::urlencode(tls_activation_id));
+    let mut local_var_req = crate::apis::Request::new("get_tls_activation", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetTlsActivationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all TLS activations.
-pub async fn list_tls_activations(configuration: &mut configuration::Configuration, params: ListTlsActivationsParams) -> Result<crate::models::TlsActivationsResponse, Error<ListTlsActivationsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_tls_activations(configuration: &configuration::Configuration, params: ListTlsActivationsParams) -> Result<crate::models::TlsActivationsResponse, Error<ListTlsActivationsError>> {
+    list_tls_activations_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_tls_activations`], but also returns the status and headers of the response.
+pub async fn list_tls_activations_with_info(configuration: &configuration::Configuration, params: ListTlsActivationsParams) -> Result<crate::apis::Response<crate::models::TlsActivationsResponse>, Error<ListTlsActivationsError>> {
+    crate::apis::execute(configuration, list_tls_activations_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_tls_activations`] sends.
+pub(crate) fn list_tls_activations_request(params: ListTlsActivationsParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_tls_certificate_id = params.filter_tls_certificate_id;
     let filter_tls_configuration_id = params.filter_tls_configuration_id;
@@ -268,120 +182,52 @@ pub async fn list_tls_activations(configuration: &mut configuration::Configurati
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/activations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/tls/activations");
+    let mut local_var_req = crate::apis::Request::new("list_tls_activations", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_tls_certificate_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[tls_certificate.id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[tls_certificate.id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_tls_configuration_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[tls_configuration.id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[tls_configuration.id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_tls_domain_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[tls_domain.id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[tls_domain.id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListTlsActivationsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update the certificate used to terminate TLS traffic for the domain associated with this TLS activation.
-pub async fn update_tls_activation(configuration: &mut configuration::Configuration, params: UpdateTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<UpdateTlsActivationError>> {
-    let local_var_configuration = configuration;
+pub async fn update_tls_activation(configuration: &configuration::Configuration, params: UpdateTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<UpdateTlsActivationError>> {
+    update_tls_activation_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_tls_activation`], but also returns the status and headers of the response.
+pub async fn update_tls_activation_with_info(configuration: &configuration::Configuration, params: UpdateTlsActivationParams) -> Result<crate::apis::Response<crate::models::TlsActivationResponse>, Error<UpdateTlsActivationError>> {
+    crate::apis::execute(configuration, update_tls_activation_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_tls_activation`] sends.
+pub(crate) fn update_tls_activation_request(params: UpdateTlsActivationParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let tls_activation_id = params.tls_activation_id;
     let tls_activation = params.tls_activation;
 
+    let local_var_path = format!("/tls/activations/{tls_activation_id}", tls_activation_id=crate::apis::urlencode(tls_activation_id));
+    let mut local_var_req = crate::apis::Request::new("update_tls_activation", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/activations/{tls_activation_id}", local_var_configuration.base_path, tls_activation_id=crate::apis::urlencode(tls_activation_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_activation)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_activation);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateTlsActivationError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
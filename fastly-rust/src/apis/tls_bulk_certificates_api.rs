@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`delete_bulk_tls_cert`]
@@ -90,280 +89,124 @@ pub enum UploadTlsBulkCertError {
 
 
 /// Destroy a certificate. This disables TLS for all domains listed as SAN entries.
-pub async fn delete_bulk_tls_cert(configuration: &mut configuration::Configuration, params: DeleteBulkTlsCertParams) -> Result<(), Error<DeleteBulkTlsCertError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_bulk_tls_cert(configuration: &configuration::Configuration, params: DeleteBulkTlsCertParams) -> Result<(), Error<DeleteBulkTlsCertError>> {
+    delete_bulk_tls_cert_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_bulk_tls_cert`], but also returns the status and headers of the response.
+pub async fn delete_bulk_tls_cert_with_info(configuration: &configuration::Configuration, params: DeleteBulkTlsCertParams) -> Result<crate::apis::Response<()>, Error<DeleteBulkTlsCertError>> {
+    crate::apis::execute(configuration, delete_bulk_tls_cert_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_bulk_tls_cert`] sends.
+pub(crate) fn delete_bulk_tls_cert_request(params: DeleteBulkTlsCertParams) -> crate::apis::Request {
     // unbox the parameters
     let certificate_id = params.certificate_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/bulk/certificates/{certificate_id}", local_var_configuration.base_path, certificate_id=crate::apis::urlencode(certificate_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteBulkTlsCertError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/bulk/certificates/{certificate_id}", certificate_id=crate::apis::urlencode(certificate_id));
+    crate::apis::Request::new("delete_bulk_tls_cert", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Retrieve a single certificate.
-pub async fn get_tls_bulk_cert(configuration: &mut configuration::Configuration, params: GetTlsBulkCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<GetTlsBulkCertError>> {
-    let local_var_configuration = configuration;
+pub async fn get_tls_bulk_cert(configuration: &configuration::Configuration, params: GetTlsBulkCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<GetTlsBulkCertError>> {
+    get_tls_bulk_cert_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_tls_bulk_cert`], but also returns the status and headers of the response.
+pub async fn get_tls_bulk_cert_with_info(configuration: &configuration::Configuration, params: GetTlsBulkCertParams) -> Result<crate::apis::Response<crate::models::TlsBulkCertificateResponse>, Error<GetTlsBulkCertError>> {
+    crate::apis::execute(configuration, get_tls_bulk_cert_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_tls_bulk_cert`] sends.
+pub(crate) fn get_tls_bulk_cert_request(params: GetTlsBulkCertParams) -> crate::apis::Request {
     // unbox the parameters
     let certificate_id = params.certificate_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/bulk/certificates/{certificate_id}", local_var_configuration.base_path, certificate_id=crate::apis::urlencode(certificate_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetTlsBulkCertError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/bulk/certificates/{certificate_id}", certificate_id=crate::apis::urlencode(certificate_id));
+    crate::apis::Request::new("get_tls_bulk_cert", reqwest::Method::GET, local_var_path)
 }
 
 /// List all certificates.
-pub async fn list_tls_bulk_certs(configuration: &mut configuration::Configuration, params: ListTlsBulkCertsParams) -> Result<crate::models::TlsBulkCertificatesResponse, Error<ListTlsBulkCertsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_tls_bulk_certs(configuration: &configuration::Configuration, params: ListTlsBulkCertsParams) -> Result<crate::models::TlsBulkCertificatesResponse, Error<ListTlsBulkCertsError>> {
+    list_tls_bulk_certs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_tls_bulk_certs`], but also returns the status and headers of the response.
+pub async fn list_tls_bulk_certs_with_info(configuration: &configuration::Configuration, params: ListTlsBulkCertsParams) -> Result<crate::apis::Response<crate::models::TlsBulkCertificatesResponse>, Error<ListTlsBulkCertsError>> {
+    crate::apis::execute(configuration, list_tls_bulk_certs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_tls_bulk_certs`] sends.
+pub(crate) fn list_tls_bulk_certs_request(params: ListTlsBulkCertsParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_tls_domain_id = params.filter_tls_domain_id;
     let page_number = params.page_number;
     let page_size = params.page_size;
     let sort = params.sort;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/bulk/certificates", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/tls/bulk/certificates");
+    let mut local_var_req = crate::apis::Request::new("list_tls_bulk_certs", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_tls_domain_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[tls_domain.id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[tls_domain.id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = sort {
-        local_var_req_builder = local_var_req_builder.query(&[("sort", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("sort", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListTlsBulkCertsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Replace a certificate with a newly reissued certificate. By using this endpoint, the original certificate will cease to be used for future TLS handshakes. Thus, only SAN entries that appear in the replacement certificate will become TLS enabled. Any SAN entries that are missing in the replacement certificate will become disabled.
-pub async fn update_bulk_tls_cert(configuration: &mut configuration::Configuration, params: UpdateBulkTlsCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<UpdateBulkTlsCertError>> {
-    let local_var_configuration = configuration;
+pub async fn update_bulk_tls_cert(configuration: &configuration::Configuration, params: UpdateBulkTlsCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<UpdateBulkTlsCertError>> {
+    update_bulk_tls_cert_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_bulk_tls_cert`], but also returns the status and headers of the response.
+pub async fn update_bulk_tls_cert_with_info(configuration: &configuration::Configuration, params: UpdateBulkTlsCertParams) -> Result<crate::apis::Response<crate::models::TlsBulkCertificateResponse>, Error<UpdateBulkTlsCertError>> {
+    crate::apis::execute(configuration, update_bulk_tls_cert_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_bulk_tls_cert`] sends.
+pub(crate) fn update_bulk_tls_cert_request(params: UpdateBulkTlsCertParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let certificate_id = params.certificate_id;
     let tls_bulk_certificate = params.tls_bulk_certificate;
 
+    let local_var_path = format!("/tls/bulk/certificates/{certificate_id}", certificate_id=crate::apis::urlencode(certificate_id));
+    let mut local_var_req = crate::apis::Request::new("update_bulk_tls_cert", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/bulk/certificates/{certificate_id}", local_var_configuration.base_path, certificate_id=crate::apis::urlencode(certificate_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_bulk_certificate);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_bulk_certificate)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateBulkTlsCertError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Upload a new certificate. TLS domains are automatically enabled upon certificate creation. If a domain is already enabled on a previously uploaded certificate, that domain will be updated to use the new certificate for all future TLS handshake requests.
-pub async fn upload_tls_bulk_cert(configuration: &mut configuration::Configuration, params: UploadTlsBulkCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<UploadTlsBulkCertError>> {
-    let local_var_configuration = configuration;
+pub async fn upload_tls_bulk_cert(configuration: &configuration::Configuration, params: UploadTlsBulkCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<UploadTlsBulkCertError>> {
+    upload_tls_bulk_cert_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`upload_tls_bulk_cert`], but also returns the status and headers of the response.
+pub async fn upload_tls_bulk_cert_with_info(configuration: &configuration::Configuration, params: UploadTlsBulkCertParams) -> Result<crate::apis::Response<crate::models::TlsBulkCertificateResponse>, Error<UploadTlsBulkCertError>> {
+    crate::apis::execute(configuration, upload_tls_bulk_cert_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`upload_tls_bulk_cert`] sends.
+pub(crate) fn upload_tls_bulk_cert_request(params: UploadTlsBulkCertParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let tls_bulk_certificate = params.tls_bulk_certificate;
 
+    let local_var_path = String::from("/tls/bulk/certificates");
+    let mut local_var_req = crate::apis::Request::new("upload_tls_bulk_cert", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_bulk_certificate)?);
 
-    let local_var_uri_str = format!("{}/tls/bulk/certificates", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_bulk_certificate);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UploadTlsBulkCertError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
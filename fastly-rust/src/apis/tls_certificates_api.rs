@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_tls_cert`]
@@ -110,218 +109,97 @@ pub enum UpdateTlsCertError {
 
 
 /// Create a TLS certificate.
-pub async fn create_tls_cert(configuration: &mut configuration::Configuration, params: CreateTlsCertParams) -> Result<serde_json::Value, Error<CreateTlsCertError>> {
-    let local_var_configuration = configuration;
+pub async fn create_tls_cert(configuration: &configuration::Configuration, params: CreateTlsCertParams) -> Result<serde_json::Value, Error<CreateTlsCertError>> {
+    create_tls_cert_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_tls_cert`], but also returns the status and headers of the response.
+pub async fn create_tls_cert_with_info(configuration: &configuration::Configuration, params: CreateTlsCertParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateTlsCertError>> {
+    crate::apis::execute(configuration, create_tls_cert_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_tls_cert`] sends.
+pub(crate) fn create_tls_cert_request(params: CreateTlsCertParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let tls_certificate = params.tls_certificate;
 
+    let local_var_path = String::from("/tls/certificates");
+    let mut local_var_req = crate::apis::Request::new("create_tls_cert", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/certificates", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_certificate);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_certificate)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateTlsCertError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Destroy a TLS certificate. TLS certificates already enabled for a domain cannot be destroyed.
-pub async fn delete_tls_cert(configuration: &mut configuration::Configuration, params: DeleteTlsCertParams) -> Result<(), Error<DeleteTlsCertError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_tls_cert(configuration: &configuration::Configuration, params: DeleteTlsCertParams) -> Result<(), Error<DeleteTlsCertError>> {
+    delete_tls_cert_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_tls_cert`], but also returns the status and headers of the response.
+pub async fn delete_tls_cert_with_info(configuration: &configuration::Configuration, params: DeleteTlsCertParams) -> Result<crate::apis::Response<()>, Error<DeleteTlsCertError>> {
+    crate::apis::execute(configuration, delete_tls_cert_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_tls_cert`] sends.
+pub(crate) fn delete_tls_cert_request(params: DeleteTlsCertParams) -> crate::apis::Request {
     // unbox the parameters
     let tls_certificate_id = params.tls_certificate_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/certificates/{tls_certificate_id}", local_var_configuration.base_path, tls_certificate_id=crate::apis::urlencode(tls_certificate_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteTlsCertError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/certificates/{tls_certificate_id}", tls_certificate_id=crate::apis::urlencode(tls_certificate_id));
+    crate::apis::Request::new("delete_tls_cert", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Show a TLS certificate.
-pub async fn get_tls_cert(configuration: &mut configuration::Configuration, params: GetTlsCertParams) -> Result<crate::models::TlsCertificateResponse, Error<GetTlsCertError>> {
-    let local_var_configuration = configuration;
+pub async fn get_tls_cert(configuration: &configuration::Configuration, params: GetTlsCertParams) -> Result<crate::models::TlsCertificateResponse, Error<GetTlsCertError>> {
+    get_tls_cert_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_tls_cert`], but also returns the status and headers of the response.
+pub async fn get_tls_cert_with_info(configuration: &configuration::Configuration, params: GetTlsCertParams) -> Result<crate::apis::Response<crate::models::TlsCertificateResponse>, Error<GetTlsCertError>> {
+    crate::apis::execute(configuration, get_tls_cert_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_tls_cert`] sends.
+pub(crate) fn get_tls_cert_request(params: GetTlsCertParams) -> crate::apis::Request {
     // unbox the parameters
     let tls_certificate_id = params.tls_certificate_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/certificates/{tls_certificate_id}", local_var_configuration.base_path, tls_certificate_id=crate::apis::urlencode(tls_certificate_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetTlsCertError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/certificates/{tls_certificate_id}", tls_certificate_id=crate::apis::urlencode(tls_certificate_id));
+    crate::apis::Request::new("get_tls_cert", reqwest::Method::GET, local_var_path)
 }
 
 /// Retrieve a TLS certificate blob. This feature is part of a [limited availability](https://docs.fastly.com/products/fastly-product-lifecycle#limited-availability) release.
-pub async fn get_tls_cert_blob(configuration: &mut configuration::Configuration, params: GetTlsCertBlobParams) -> Result<crate::models::TlsCertificateBlobResponse, Error<GetTlsCertBlobError>> {
-    let local_var_configuration = configuration;
+pub async fn get_tls_cert_blob(configuration: &configuration::Configuration, params: GetTlsCertBlobParams) -> Result<crate::models::TlsCertificateBlobResponse, Error<GetTlsCertBlobError>> {
+    get_tls_cert_blob_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_tls_cert_blob`], but also returns the status and headers of the response.
+pub async fn get_tls_cert_blob_with_info(configuration: &configuration::Configuration, params: GetTlsCertBlobParams) -> Result<crate::apis::Response<crate::models::TlsCertificateBlobResponse>, Error<GetTlsCertBlobError>> {
+    crate::apis::execute(configuration, get_tls_cert_blob_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_tls_cert_blob`] sends.
+pub(crate) fn get_tls_cert_blob_request(params: GetTlsCertBlobParams) -> crate::apis::Request {
     // unbox the parameters
     let tls_certificate_id = params.tls_certificate_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/certificates/{tls_certificate_id}/blob", local_var_configuration.base_path, tls_certificate_id=crate::apis::urlencode(tls_certificate_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetTlsCertBlobError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/certificates/{tls_certificate_id}/blob", tls_certificate_id=crate::apis::urlencode(tls_certificate_id));
+    crate::apis::Request::new("get_tls_cert_blob", reqwest::Method::GET, local_var_path)
 }
 
 /// List all TLS certificates.
-pub async fn list_tls_certs(configuration: &mut configuration::Configuration, params: ListTlsCertsParams) -> Result<crate::models::TlsCertificatesResponse, Error<ListTlsCertsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_tls_certs(configuration: &configuration::Configuration, params: ListTlsCertsParams) -> Result<crate::models::TlsCertificatesResponse, Error<ListTlsCertsError>> {
+    list_tls_certs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_tls_certs`], but also returns the status and headers of the response.
+pub async fn list_tls_certs_with_info(configuration: &configuration::Configuration, params: ListTlsCertsParams) -> Result<crate::apis::Response<crate::models::TlsCertificatesResponse>, Error<ListTlsCertsError>> {
+    crate::apis::execute(configuration, list_tls_certs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_tls_certs`] sends.
+pub(crate) fn list_tls_certs_request(params: ListTlsCertsParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_in_use = params.filter_in_use;
     let filter_not_after = params.filter_not_after;
@@ -331,123 +209,55 @@ pub async fn list_tls_certs(configuration: &mut configuration::Configuration, pa
     let page_size = params.page_size;
     let sort = params.sort;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/certificates", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/tls/certificates");
+    let mut local_var_req = crate::apis::Request::new("list_tls_certs", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_in_use {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[in_use]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[in_use]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_not_after {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[not_after]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[not_after]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_tls_domains_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[tls_domains.id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[tls_domains.id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = sort {
-        local_var_req_builder = local_var_req_builder.query(&[("sort", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("sort", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListTlsCertsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Replace a TLS certificate with a newly reissued TLS certificate, or update a TLS certificate's name. If replacing a TLS certificate, the new TLS certificate must contain all SAN entries as the current TLS certificate. It must either have an exact matching list or contain a superset.
-pub async fn update_tls_cert(configuration: &mut configuration::Configuration, params: UpdateTlsCertParams) -> Result<crate::models::TlsCertificateResponse, Error<UpdateTlsCertError>> {
-    let local_var_configuration = configuration;
+pub async fn update_tls_cert(configuration: &configuration::Configuration, params: UpdateTlsCertParams) -> Result<crate::models::TlsCertificateResponse, Error<UpdateTlsCertError>> {
+    update_tls_cert_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_tls_cert`], but also returns the status and headers of the response.
+pub async fn update_tls_cert_with_info(configuration: &configuration::Configuration, params: UpdateTlsCertParams) -> Result<crate::apis::Response<crate::models::TlsCertificateResponse>, Error<UpdateTlsCertError>> {
+    crate::apis::execute(configuration, update_tls_cert_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_tls_cert`] sends.
+pub(crate) fn update_tls_cert_request(params: UpdateTlsCertParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let tls_certificate_id = params.tls_certificate_id;
     let tls_certificate = params.tls_certificate;
 
+    let local_var_path = format!("/tls/certificates/{tls_certificate_id}", tls_certificate_id=crate::apis::urlencode(tls_certificate_id));
+    let mut local_var_req = crate::apis::Request::new("update_tls_cert", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/certificates/{tls_certificate_id}", local_var_configuration.base_path, tls_certificate_id=crate::apis::urlencode(tls_certificate_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_certificate)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_certificate);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateTlsCertError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
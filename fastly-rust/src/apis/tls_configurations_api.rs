@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_tls_config`]
@@ -65,179 +64,89 @@ pub enum UpdateTlsConfigError {
 
 
 /// Show a TLS configuration.
-pub async fn get_tls_config(configuration: &mut configuration::Configuration, params: GetTlsConfigParams) -> Result<crate::models::TlsConfigurationResponse, Error<GetTlsConfigError>> {
-    let local_var_configuration = configuration;
+pub async fn get_tls_config(configuration: &configuration::Configuration, params: GetTlsConfigParams) -> Result<crate::models::TlsConfigurationResponse, Error<GetTlsConfigError>> {
+    get_tls_config_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_tls_config`], but also returns the status and headers of the response.
+pub async fn get_tls_config_with_info(configuration: &configuration::Configuration, params: GetTlsConfigParams) -> Result<crate::apis::Response<crate::models::TlsConfigurationResponse>, Error<GetTlsConfigError>> {
+    crate::apis::execute(configuration, get_tls_config_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_tls_config`] sends.
+pub(crate) fn get_tls_config_request(params: GetTlsConfigParams) -> crate::apis::Request {
     // unbox the parameters
     let tls_configuration_id = params.tls_configuration_id;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/configurations/{tls_configuration_id}", local_var_configuration.base_path, tls_configuration_id=crate::apis::urlencode(tls_configuration_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/tls/configurations/{tls_configuration_id}", tls_configuration_id=crate::apis::urlencode(tls_configuration_id));
+    let mut local_var_req = crate::apis::Request::new("get_tls_config", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetTlsConfigError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all TLS configurations.
-pub async fn list_tls_configs(configuration: &mut configuration::Configuration, params: ListTlsConfigsParams) -> Result<crate::models::TlsConfigurationsResponse, Error<ListTlsConfigsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_tls_configs(configuration: &configuration::Configuration, params: ListTlsConfigsParams) -> Result<crate::models::TlsConfigurationsResponse, Error<ListTlsConfigsError>> {
+    list_tls_configs_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_tls_configs`], but also returns the status and headers of the response.
+pub async fn list_tls_configs_with_info(configuration: &configuration::Configuration, params: ListTlsConfigsParams) -> Result<crate::apis::Response<crate::models::TlsConfigurationsResponse>, Error<ListTlsConfigsError>> {
+    crate::apis::execute(configuration, list_tls_configs_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_tls_configs`] sends.
+pub(crate) fn list_tls_configs_request(params: ListTlsConfigsParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_bulk = params.filter_bulk;
     let include = params.include;
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/configurations", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/tls/configurations");
+    let mut local_var_req = crate::apis::Request::new("list_tls_configs", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_bulk {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[bulk]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[bulk]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListTlsConfigsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a TLS configuration.
-pub async fn update_tls_config(configuration: &mut configuration::Configuration, params: UpdateTlsConfigParams) -> Result<crate::models::TlsConfigurationResponse, Error<UpdateTlsConfigError>> {
-    let local_var_configuration = configuration;
+pub async fn update_tls_config(configuration: &configuration::Configuration, params: UpdateTlsConfigParams) -> Result<crate::models::TlsConfigurationResponse, Error<UpdateTlsConfigError>> {
+    update_tls_config_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_tls_config`], but also returns the status and headers of the response.
+pub async fn update_tls_config_with_info(configuration: &configuration::Configuration, params: UpdateTlsConfigParams) -> Result<crate::apis::Response<crate::models::TlsConfigurationResponse>, Error<UpdateTlsConfigError>> {
+    crate::apis::execute(configuration, update_tls_config_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_tls_config`] sends.
+pub(crate) fn update_tls_config_request(params: UpdateTlsConfigParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let tls_configuration_id = params.tls_configuration_id;
     let tls_configuration = params.tls_configuration;
 
+    let local_var_path = format!("/tls/configurations/{tls_configuration_id}", tls_configuration_id=crate::apis::urlencode(tls_configuration_id));
+    let mut local_var_req = crate::apis::Request::new("update_tls_config", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_configuration)?);
 
-    let local_var_uri_str = format!("{}/tls/configurations/{tls_configuration_id}", local_var_configuration.base_path, tls_configuration_id=crate::apis::urlencode(tls_configuration_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_configuration);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateTlsConfigError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_csr`]
@@ -27,55 +26,25 @@ pub enum CreateCsrError {
 
 
 /// Creates a certificate signing request (CSR).
-pub async fn create_csr(configuration: &mut configuration::Configuration, params: CreateCsrParams) -> Result<crate::models::TlsCsrResponse, Error<CreateCsrError>> {
-    let local_var_configuration = configuration;
+pub async fn create_csr(configuration: &configuration::Configuration, params: CreateCsrParams) -> Result<crate::models::TlsCsrResponse, Error<CreateCsrError>> {
+    create_csr_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_csr`], but also returns the status and headers of the response.
+pub async fn create_csr_with_info(configuration: &configuration::Configuration, params: CreateCsrParams) -> Result<crate::apis::Response<crate::models::TlsCsrResponse>, Error<CreateCsrError>> {
+    crate::apis::execute(configuration, create_csr_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_csr`] sends.
+pub(crate) fn create_csr_request(params: CreateCsrParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let tls_csr = params.tls_csr;
 
+    let local_var_path = String::from("/tls/certificate_signing_requests");
+    let mut local_var_req = crate::apis::Request::new("create_csr", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/certificate_signing_requests", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_csr);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_csr)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateCsrError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_tls_key`]
@@ -73,222 +72,96 @@ pub enum ListTlsKeysError {
 
 
 /// Create a TLS private key.
-pub async fn create_tls_key(configuration: &mut configuration::Configuration, params: CreateTlsKeyParams) -> Result<crate::models::TlsPrivateKeyResponse, Error<CreateTlsKeyError>> {
-    let local_var_configuration = configuration;
+pub async fn create_tls_key(configuration: &configuration::Configuration, params: CreateTlsKeyParams) -> Result<crate::models::TlsPrivateKeyResponse, Error<CreateTlsKeyError>> {
+    create_tls_key_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_tls_key`], but also returns the status and headers of the response.
+pub async fn create_tls_key_with_info(configuration: &configuration::Configuration, params: CreateTlsKeyParams) -> Result<crate::apis::Response<crate::models::TlsPrivateKeyResponse>, Error<CreateTlsKeyError>> {
+    crate::apis::execute(configuration, create_tls_key_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_tls_key`] sends.
+pub(crate) fn create_tls_key_request(params: CreateTlsKeyParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let tls_private_key = params.tls_private_key;
 
+    let local_var_path = String::from("/tls/private_keys");
+    let mut local_var_req = crate::apis::Request::new("create_tls_key", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/private_keys", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&tls_private_key);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&tls_private_key)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateTlsKeyError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Destroy a TLS private key. Only private keys not already matched to any certificates can be deleted.
-pub async fn delete_tls_key(configuration: &mut configuration::Configuration, params: DeleteTlsKeyParams) -> Result<(), Error<DeleteTlsKeyError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_tls_key(configuration: &configuration::Configuration, params: DeleteTlsKeyParams) -> Result<(), Error<DeleteTlsKeyError>> {
+    delete_tls_key_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_tls_key`], but also returns the status and headers of the response.
+pub async fn delete_tls_key_with_info(configuration: &configuration::Configuration, params: DeleteTlsKeyParams) -> Result<crate::apis::Response<()>, Error<DeleteTlsKeyError>> {
+    crate::apis::execute(configuration, delete_tls_key_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_tls_key`] sends.
+pub(crate) fn delete_tls_key_request(params: DeleteTlsKeyParams) -> crate::apis::Request {
     // unbox the parameters
     let tls_private_key_id = params.tls_private_key_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/private_keys/{tls_private_key_id}", local_var_configuration.base_path, tls_private_key_id=crate::apis::urlencode(tls_private_key_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteTlsKeyError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/private_keys/{tls_private_key_id}", tls_private_key_id=crate::apis::urlencode(tls_private_key_id));
+    crate::apis::Request::new("delete_tls_key", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Show a TLS private key.
-pub async fn get_tls_key(configuration: &mut configuration::Configuration, params: GetTlsKeyParams) -> Result<crate::models::TlsPrivateKeyResponse, Error<GetTlsKeyError>> {
-    let local_var_configuration = configuration;
+pub async fn get_tls_key(configuration: &configuration::Configuration, params: GetTlsKeyParams) -> Result<crate::models::TlsPrivateKeyResponse, Error<GetTlsKeyError>> {
+    get_tls_key_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_tls_key`], but also returns the status and headers of the response.
+pub async fn get_tls_key_with_info(configuration: &configuration::Configuration, params: GetTlsKeyParams) -> Result<crate::apis::Response<crate::models::TlsPrivateKeyResponse>, Error<GetTlsKeyError>> {
+    crate::apis::execute(configuration, get_tls_key_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_tls_key`] sends.
+pub(crate) fn get_tls_key_request(params: GetTlsKeyParams) -> crate::apis::Request {
     // unbox the parameters
     let tls_private_key_id = params.tls_private_key_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/private_keys/{tls_private_key_id}", local_var_configuration.base_path, tls_private_key_id=crate::apis::urlencode(tls_private_key_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetTlsKeyError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tls/private_keys/{tls_private_key_id}", tls_private_key_id=crate::apis::urlencode(tls_private_key_id));
+    crate::apis::Request::new("get_tls_key", reqwest::Method::GET, local_var_path)
 }
 
 /// List all TLS private keys.
-pub async fn list_tls_keys(configuration: &mut configuration::Configuration, params: ListTlsKeysParams) -> Result<crate::models::TlsPrivateKeysResponse, Error<ListTlsKeysError>> {
-    let local_var_configuration = configuration;
+pub async fn list_tls_keys(configuration: &configuration::Configuration, params: ListTlsKeysParams) -> Result<crate::models::TlsPrivateKeysResponse, Error<ListTlsKeysError>> {
+    list_tls_keys_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_tls_keys`], but also returns the status and headers of the response.
+pub async fn list_tls_keys_with_info(configuration: &configuration::Configuration, params: ListTlsKeysParams) -> Result<crate::apis::Response<crate::models::TlsPrivateKeysResponse>, Error<ListTlsKeysError>> {
+    crate::apis::execute(configuration, list_tls_keys_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_tls_keys`] sends.
+pub(crate) fn list_tls_keys_request(params: ListTlsKeysParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_in_use = params.filter_in_use;
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tls/private_keys", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/tls/private_keys");
+    let mut local_var_req = crate::apis::Request::new("list_tls_keys", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_in_use {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[in_use]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[in_use]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListTlsKeysError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -0,0 +1,181 @@
+//! Credentials that are fetched, and refreshed, while the client runs.
+//!
+//! A [`TokenSource`] set on [`Configuration::token_source`] is asked for a
+//! token before every request, so long-running services can rotate
+//! credentials without rebuilding their `Configuration`. Wrap slow or
+//! rate-limited sources in a [`CachingTokenSource`] to reuse a token until it
+//! expires.
+//!
+//! [`Configuration::token_source`]: super::configuration::Configuration::token_source
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use super::redact::Secret;
+use super::AuthError;
+
+pub use super::BoxFuture;
+
+/// An access token and how long it may be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub access_token: Secret,
+    /// How the token is presented. `Bearer` tokens are sent in the
+    /// `Authorization` header; anything else is sent as `Fastly-Key`.
+    pub token_type: Option<String>,
+    /// When the token stops being valid; `None` if it does not expire.
+    pub expires_at: Option<SystemTime>,
+}
+
+impl Token {
+    /// A Fastly API token that does not expire.
+    pub fn api_token(token: impl Into<Secret>) -> Token {
+        Token {
+            access_token: token.into(),
+            token_type: None,
+            expires_at: None,
+        }
+    }
+
+    pub fn is_bearer(&self) -> bool {
+        self.token_type.as_deref().is_some_and(|t| t.eq_ignore_ascii_case("bearer"))
+    }
+
+    /// Whether the token is still usable at `now` with `leeway` to spare.
+    pub fn is_fresh(&self, now: SystemTime, leeway: Duration) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now + leeway < expires_at,
+            None => true,
+        }
+    }
+}
+
+/// Supplies the credential for each request.
+pub trait TokenSource: fmt::Debug + Send + Sync {
+    fn token(&self) -> BoxFuture<'_, Result<Token, AuthError>>;
+}
+
+/// Always returns the same token.
+#[derive(Debug, Clone)]
+pub struct StaticTokenSource(pub Token);
+
+impl TokenSource for StaticTokenSource {
+    fn token(&self) -> BoxFuture<'_, Result<Token, AuthError>> {
+        let token = self.0.clone();
+        Box::pin(async move { Ok(token) })
+    }
+}
+
+/// Reuses the token of an inner source until it is about to expire.
+///
+/// Concurrent callers wait for a single refresh instead of each fetching a
+/// token of their own.
+pub struct CachingTokenSource<S> {
+    inner: S,
+    leeway: Duration,
+    cached: tokio::sync::Mutex<Option<Token>>,
+}
+
+impl<S: TokenSource> CachingTokenSource<S> {
+    /// Cache tokens from `inner`, refreshing them a minute before they expire.
+    pub fn new(inner: S) -> CachingTokenSource<S> {
+        CachingTokenSource::with_leeway(inner, Duration::from_secs(60))
+    }
+
+    pub fn with_leeway(inner: S, leeway: Duration) -> CachingTokenSource<S> {
+        CachingTokenSource {
+            inner,
+            leeway,
+            cached: tokio::sync::Mutex::new(None),
+        }
+    }
+}
+
+impl<S: TokenSource> TokenSource for CachingTokenSource<S> {
+    fn token(&self) -> BoxFuture<'_, Result<Token, AuthError>> {
+        Box::pin(async move {
+            let mut cached = self.cached.lock().await;
+            if let Some(ref token) = *cached {
+                if token.is_fresh(SystemTime::now(), self.leeway) {
+                    return Ok(token.clone());
+                }
+            }
+            let token = self.inner.token().await?;
+            *cached = Some(token.clone());
+            Ok(token)
+        })
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for CachingTokenSource<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachingTokenSource")
+            .field("inner", &self.inner)
+            .field("leeway", &self.leeway)
+            .finish()
+    }
+}
+
+/// Fetches tokens from an OAuth2 token endpoint with the client credentials grant.
+#[derive(Debug, Clone)]
+pub struct ClientCredentialsTokenSource {
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: Secret,
+    pub scope: Option<String>,
+    pub client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    token_type: Option<String>,
+    expires_in: Option<u64>,
+}
+
+impl ClientCredentialsTokenSource {
+    pub fn new(token_url: impl Into<String>, client_id: impl Into<String>, client_secret: impl Into<Secret>) -> ClientCredentialsTokenSource {
+        ClientCredentialsTokenSource {
+            token_url: token_url.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            scope: None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn fetch(&self) -> Result<Token, AuthError> {
+        let mut form = vec![("grant_type", "client_credentials")];
+        if let Some(ref scope) = self.scope {
+            form.push(("scope", scope.as_str()));
+        }
+        let requested_at = SystemTime::now();
+        let response = self
+            .client
+            .post(&self.token_url)
+            .basic_auth(&self.client_id, Some(self.client_secret.expose()))
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| AuthError::with_source("token request failed", e))?;
+
+        let status = response.status();
+        let body = response.text().await.map_err(|e| AuthError::with_source("token request failed", e))?;
+        if !status.is_success() {
+            return Err(AuthError::new(format!("token endpoint returned status code {}", status)));
+        }
+        let parsed: TokenResponse = serde_json::from_str(&body).map_err(|e| AuthError::with_source("invalid token response", e))?;
+
+        Ok(Token {
+            access_token: parsed.access_token.into(),
+            token_type: parsed.token_type,
+            expires_at: parsed.expires_in.map(|secs| requested_at + Duration::from_secs(secs)),
+        })
+    }
+}
+
+impl TokenSource for ClientCredentialsTokenSource {
+    fn token(&self) -> BoxFuture<'_, Result<Token, AuthError>> {
+        Box::pin(self.fetch())
+    }
+}
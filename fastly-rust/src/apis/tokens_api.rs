@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`bulk_revoke_tokens`]
@@ -113,418 +112,146 @@ pub enum RevokeTokenCurrentError {
 
 
 /// Revoke Tokens in bulk format. Users may only revoke their own tokens. Superusers may revoke tokens of others.
-pub async fn bulk_revoke_tokens(configuration: &mut configuration::Configuration, params: BulkRevokeTokensParams) -> Result<(), Error<BulkRevokeTokensError>> {
-    let local_var_configuration = configuration;
+pub async fn bulk_revoke_tokens(configuration: &configuration::Configuration, params: BulkRevokeTokensParams) -> Result<(), Error<BulkRevokeTokensError>> {
+    bulk_revoke_tokens_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`bulk_revoke_tokens`], but also returns the status and headers of the response.
+pub async fn bulk_revoke_tokens_with_info(configuration: &configuration::Configuration, params: BulkRevokeTokensParams) -> Result<crate::apis::Response<()>, Error<BulkRevokeTokensError>> {
+    crate::apis::execute(configuration, bulk_revoke_tokens_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`bulk_revoke_tokens`] sends.
+pub(crate) fn bulk_revoke_tokens_request(params: BulkRevokeTokensParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let request_body = params.request_body;
 
+    let local_var_path = String::from("/tokens");
+    let mut local_var_req = crate::apis::Request::new("bulk_revoke_tokens", reqwest::Method::DELETE, local_var_path);
+
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tokens", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<BulkRevokeTokensError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create an API token. If two-factor authentication is enabled for your account, review [the instructions](https://www.fastly.com/documentation/reference/api/auth-tokens/user/) for including a one-time password in the request. 
-pub async fn create_token(configuration: &mut configuration::Configuration) -> Result<crate::models::TokenCreatedResponse, Error<CreateTokenError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
+pub async fn create_token(configuration: &configuration::Configuration) -> Result<crate::models::TokenCreatedResponse, Error<CreateTokenError>> {
+    create_token_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`create_token`], but also returns the status and headers of the response.
+pub async fn create_token_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::TokenCreatedResponse>, Error<CreateTokenError>> {
+    crate::apis::execute(configuration, create_token_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tokens", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-        local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateTokenError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`create_token`] sends.
+pub(crate) fn create_token_request() -> crate::apis::Request {
+    let local_var_path = String::from("/tokens");
+    crate::apis::Request::new("create_token", reqwest::Method::POST, local_var_path).auth(&[crate::apis::AuthScheme::Token, crate::apis::AuthScheme::Basic])
 }
 
 /// Get a single token by its id.
-pub async fn get_token(configuration: &mut configuration::Configuration, params: GetTokenParams) -> Result<crate::models::TokenResponse, Error<GetTokenError>> {
-    let local_var_configuration = configuration;
+pub async fn get_token(configuration: &configuration::Configuration, params: GetTokenParams) -> Result<crate::models::TokenResponse, Error<GetTokenError>> {
+    get_token_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_token`], but also returns the status and headers of the response.
+pub async fn get_token_with_info(configuration: &configuration::Configuration, params: GetTokenParams) -> Result<crate::apis::Response<crate::models::TokenResponse>, Error<GetTokenError>> {
+    crate::apis::execute(configuration, get_token_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_token`] sends.
+pub(crate) fn get_token_request(params: GetTokenParams) -> crate::apis::Request {
     // unbox the parameters
     let token_id = params.token_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tokens/{token_id}", local_var_configuration.base_path, token_id=crate::apis::urlencode(token_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetTokenError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tokens/{token_id}", token_id=crate::apis::urlencode(token_id));
+    crate::apis::Request::new("get_token", reqwest::Method::GET, local_var_path)
 }
 
 /// Get a single token based on the access_token used in the request.
-pub async fn get_token_current(configuration: &mut configuration::Configuration) -> Result<crate::models::TokenResponse, Error<GetTokenCurrentError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
+pub async fn get_token_current(configuration: &configuration::Configuration) -> Result<crate::models::TokenResponse, Error<GetTokenCurrentError>> {
+    get_token_current_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_token_current`], but also returns the status and headers of the response.
+pub async fn get_token_current_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::TokenResponse>, Error<GetTokenCurrentError>> {
+    crate::apis::execute(configuration, get_token_current_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tokens/self", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetTokenCurrentError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`get_token_current`] sends.
+pub(crate) fn get_token_current_request() -> crate::apis::Request {
+    let local_var_path = String::from("/tokens/self");
+    crate::apis::Request::new("get_token_current", reqwest::Method::GET, local_var_path)
 }
 
 /// List all tokens belonging to a specific customer.
-pub async fn list_tokens_customer(configuration: &mut configuration::Configuration, params: ListTokensCustomerParams) -> Result<Vec<crate::models::TokenResponse>, Error<ListTokensCustomerError>> {
-    let local_var_configuration = configuration;
+pub async fn list_tokens_customer(configuration: &configuration::Configuration, params: ListTokensCustomerParams) -> Result<Vec<crate::models::TokenResponse>, Error<ListTokensCustomerError>> {
+    list_tokens_customer_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_tokens_customer`], but also returns the status and headers of the response.
+pub async fn list_tokens_customer_with_info(configuration: &configuration::Configuration, params: ListTokensCustomerParams) -> Result<crate::apis::Response<Vec<crate::models::TokenResponse>>, Error<ListTokensCustomerError>> {
+    crate::apis::execute(configuration, list_tokens_customer_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_tokens_customer`] sends.
+pub(crate) fn list_tokens_customer_request(params: ListTokensCustomerParams) -> crate::apis::Request {
     // unbox the parameters
     let customer_id = params.customer_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/customer/{customer_id}/tokens", local_var_configuration.base_path, customer_id=crate::apis::urlencode(customer_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListTokensCustomerError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/customer/{customer_id}/tokens", customer_id=crate::apis::urlencode(customer_id));
+    crate::apis::Request::new("list_tokens_customer", reqwest::Method::GET, local_var_path)
 }
 
 /// List all tokens belonging to the authenticated user.
-pub async fn list_tokens_user(configuration: &mut configuration::Configuration) -> Result<Vec<crate::models::TokenResponse>, Error<ListTokensUserError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
+pub async fn list_tokens_user(configuration: &configuration::Configuration) -> Result<Vec<crate::models::TokenResponse>, Error<ListTokensUserError>> {
+    list_tokens_user_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_tokens_user`], but also returns the status and headers of the response.
+pub async fn list_tokens_user_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<Vec<crate::models::TokenResponse>>, Error<ListTokensUserError>> {
+    crate::apis::execute(configuration, list_tokens_user_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tokens", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListTokensUserError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`list_tokens_user`] sends.
+pub(crate) fn list_tokens_user_request() -> crate::apis::Request {
+    let local_var_path = String::from("/tokens");
+    crate::apis::Request::new("list_tokens_user", reqwest::Method::GET, local_var_path)
 }
 
 /// Revoke a specific token by its id.
-pub async fn revoke_token(configuration: &mut configuration::Configuration, params: RevokeTokenParams) -> Result<(), Error<RevokeTokenError>> {
-    let local_var_configuration = configuration;
+pub async fn revoke_token(configuration: &configuration::Configuration, params: RevokeTokenParams) -> Result<(), Error<RevokeTokenError>> {
+    revoke_token_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`revoke_token`], but also returns the status and headers of the response.
+pub async fn revoke_token_with_info(configuration: &configuration::Configuration, params: RevokeTokenParams) -> Result<crate::apis::Response<()>, Error<RevokeTokenError>> {
+    crate::apis::execute(configuration, revoke_token_request(params)).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`revoke_token`] sends.
+pub(crate) fn revoke_token_request(params: RevokeTokenParams) -> crate::apis::Request {
     // unbox the parameters
     let token_id = params.token_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tokens/{token_id}", local_var_configuration.base_path, token_id=crate::apis::urlencode(token_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<RevokeTokenError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/tokens/{token_id}", token_id=crate::apis::urlencode(token_id));
+    crate::apis::Request::new("revoke_token", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Revoke a token that is used to authenticate the request.
-pub async fn revoke_token_current(configuration: &mut configuration::Configuration) -> Result<(), Error<RevokeTokenCurrentError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
+pub async fn revoke_token_current(configuration: &configuration::Configuration) -> Result<(), Error<RevokeTokenCurrentError>> {
+    revoke_token_current_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`revoke_token_current`], but also returns the status and headers of the response.
+pub async fn revoke_token_current_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<()>, Error<RevokeTokenCurrentError>> {
+    crate::apis::execute(configuration, revoke_token_current_request()).await.map(crate::apis::RawResponse::empty)
+}
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/tokens/self", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<RevokeTokenCurrentError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`revoke_token_current`] sends.
+pub(crate) fn revoke_token_current_request() -> crate::apis::Request {
+    let local_var_path = String::from("/tokens/self");
+    crate::apis::Request::new("revoke_token_current", reqwest::Method::DELETE, local_var_path)
 }
 
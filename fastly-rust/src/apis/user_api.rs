@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_user`]
@@ -76,9 +75,9 @@ pub struct UpdateUserParams {
 #[derive(Clone, Debug, Default)]
 pub struct UpdateUserPasswordParams {
     /// The user's current password.
-    pub old_password: Option<String>,
+    pub old_password: Option<crate::apis::redact::Secret>,
     /// The user's new password.
-    pub new_password: Option<String>
+    pub new_password: Option<crate::apis::redact::Secret>
 }
 
 
@@ -133,9 +132,17 @@ pub enum UpdateUserPasswordError {
 
 
 /// Create a user.
-pub async fn create_user(configuration: &mut configuration::Configuration, params: CreateUserParams) -> Result<crate::models::UserResponse, Error<CreateUserError>> {
-    let local_var_configuration = configuration;
+pub async fn create_user(configuration: &configuration::Configuration, params: CreateUserParams) -> Result<crate::models::UserResponse, Error<CreateUserError>> {
+    create_user_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_user`], but also returns the status and headers of the response.
+pub async fn create_user_with_info(configuration: &configuration::Configuration, params: CreateUserParams) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<CreateUserError>> {
+    crate::apis::execute(configuration, create_user_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_user`] sends.
+pub(crate) fn create_user_request(params: CreateUserParams) -> crate::apis::Request {
     // unbox the parameters
     let login = params.login;
     let name = params.name;
@@ -146,24 +153,10 @@ pub async fn create_user(configuration: &mut configuration::Configuration, param
     let two_factor_auth_enabled = params.two_factor_auth_enabled;
     let two_factor_setup_required = params.two_factor_setup_required;
 
+    let local_var_path = String::from("/user");
+    let mut local_var_req = crate::apis::Request::new("create_user", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = login {
         local_var_form_params.insert("login", local_var_param_value.to_string());
     }
@@ -188,246 +181,96 @@ pub async fn create_user(configuration: &mut configuration::Configuration, param
     if let Some(local_var_param_value) = two_factor_setup_required {
         local_var_form_params.insert("two_factor_setup_required", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateUserError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete a user.
-pub async fn delete_user(configuration: &mut configuration::Configuration, params: DeleteUserParams) -> Result<crate::models::InlineResponse200, Error<DeleteUserError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_user(configuration: &configuration::Configuration, params: DeleteUserParams) -> Result<crate::models::InlineResponse200, Error<DeleteUserError>> {
+    delete_user_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_user`], but also returns the status and headers of the response.
+pub async fn delete_user_with_info(configuration: &configuration::Configuration, params: DeleteUserParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteUserError>> {
+    crate::apis::execute(configuration, delete_user_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_user`] sends.
+pub(crate) fn delete_user_request(params: DeleteUserParams) -> crate::apis::Request {
     // unbox the parameters
     let user_id = params.user_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user/{user_id}", local_var_configuration.base_path, user_id=crate::apis::urlencode(user_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteUserError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/user/{user_id}", user_id=crate::apis::urlencode(user_id));
+    crate::apis::Request::new("delete_user", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the logged in user.
-pub async fn get_current_user(configuration: &mut configuration::Configuration) -> Result<crate::models::UserResponse, Error<GetCurrentUserError>> {
-    let local_var_configuration = configuration;
-
-    // unbox the parameters
-
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/current_user", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+pub async fn get_current_user(configuration: &configuration::Configuration) -> Result<crate::models::UserResponse, Error<GetCurrentUserError>> {
+    get_current_user_with_info(configuration).await.map(crate::apis::Response::into_entity)
+}
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+/// Like [`get_current_user`], but also returns the status and headers of the response.
+pub async fn get_current_user_with_info(configuration: &configuration::Configuration) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<GetCurrentUserError>> {
+    crate::apis::execute(configuration, get_current_user_request()).await.and_then(crate::apis::RawResponse::json)
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCurrentUserError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// The request [`get_current_user`] sends.
+pub(crate) fn get_current_user_request() -> crate::apis::Request {
+    let local_var_path = String::from("/current_user");
+    crate::apis::Request::new("get_current_user", reqwest::Method::GET, local_var_path)
 }
 
 /// Get a specific user.
-pub async fn get_user(configuration: &mut configuration::Configuration, params: GetUserParams) -> Result<crate::models::UserResponse, Error<GetUserError>> {
-    let local_var_configuration = configuration;
+pub async fn get_user(configuration: &configuration::Configuration, params: GetUserParams) -> Result<crate::models::UserResponse, Error<GetUserError>> {
+    get_user_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_user`], but also returns the status and headers of the response.
+pub async fn get_user_with_info(configuration: &configuration::Configuration, params: GetUserParams) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<GetUserError>> {
+    crate::apis::execute(configuration, get_user_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_user`] sends.
+pub(crate) fn get_user_request(params: GetUserParams) -> crate::apis::Request {
     // unbox the parameters
     let user_id = params.user_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user/{user_id}", local_var_configuration.base_path, user_id=crate::apis::urlencode(user_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetUserError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/user/{user_id}", user_id=crate::apis::urlencode(user_id));
+    crate::apis::Request::new("get_user", reqwest::Method::GET, local_var_path)
 }
 
 /// Requests a password reset for the specified user.
-pub async fn request_password_reset(configuration: &mut configuration::Configuration, params: RequestPasswordResetParams) -> Result<crate::models::InlineResponse200, Error<RequestPasswordResetError>> {
-    let local_var_configuration = configuration;
+pub async fn request_password_reset(configuration: &configuration::Configuration, params: RequestPasswordResetParams) -> Result<crate::models::InlineResponse200, Error<RequestPasswordResetError>> {
+    request_password_reset_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`request_password_reset`], but also returns the status and headers of the response.
+pub async fn request_password_reset_with_info(configuration: &configuration::Configuration, params: RequestPasswordResetParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<RequestPasswordResetError>> {
+    crate::apis::execute(configuration, request_password_reset_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`request_password_reset`] sends.
+pub(crate) fn request_password_reset_request(params: RequestPasswordResetParams) -> crate::apis::Request {
     // unbox the parameters
     let user_login = params.user_login;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user/{user_login}/password/request_reset", local_var_configuration.base_path, user_login=crate::apis::urlencode(user_login));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<RequestPasswordResetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/user/{user_login}/password/request_reset", user_login=crate::apis::urlencode(user_login));
+    crate::apis::Request::new("request_password_reset", reqwest::Method::POST, local_var_path)
 }
 
 /// Update a user. Only users with the role of `superuser` can make changes to other users on the account. Non-superusers may use this endpoint to make changes to their own account. Two-factor attributes are not editable via this endpoint.
-pub async fn update_user(configuration: &mut configuration::Configuration, params: UpdateUserParams) -> Result<crate::models::UserResponse, Error<UpdateUserError>> {
-    let local_var_configuration = configuration;
+pub async fn update_user(configuration: &configuration::Configuration, params: UpdateUserParams) -> Result<crate::models::UserResponse, Error<UpdateUserError>> {
+    update_user_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_user`], but also returns the status and headers of the response.
+pub async fn update_user_with_info(configuration: &configuration::Configuration, params: UpdateUserParams) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<UpdateUserError>> {
+    crate::apis::execute(configuration, update_user_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_user`] sends.
+pub(crate) fn update_user_request(params: UpdateUserParams) -> crate::apis::Request {
     // unbox the parameters
     let user_id = params.user_id;
     let login = params.login;
@@ -439,24 +282,10 @@ pub async fn update_user(configuration: &mut configuration::Configuration, param
     let two_factor_auth_enabled = params.two_factor_auth_enabled;
     let two_factor_setup_required = params.two_factor_setup_required;
 
+    let local_var_path = format!("/user/{user_id}", user_id=crate::apis::urlencode(user_id));
+    let mut local_var_req = crate::apis::Request::new("update_user", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/user/{user_id}", local_var_configuration.base_path, user_id=crate::apis::urlencode(user_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = login {
         local_var_form_params.insert("login", local_var_param_value.to_string());
     }
@@ -481,88 +310,39 @@ pub async fn update_user(configuration: &mut configuration::Configuration, param
     if let Some(local_var_param_value) = two_factor_setup_required {
         local_var_form_params.insert("two_factor_setup_required", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateUserError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update the user's password to a new one.
-pub async fn update_user_password(configuration: &mut configuration::Configuration, params: UpdateUserPasswordParams) -> Result<crate::models::UserResponse, Error<UpdateUserPasswordError>> {
-    let local_var_configuration = configuration;
+pub async fn update_user_password(configuration: &configuration::Configuration, params: UpdateUserPasswordParams) -> Result<crate::models::UserResponse, Error<UpdateUserPasswordError>> {
+    update_user_password_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_user_password`], but also returns the status and headers of the response.
+pub async fn update_user_password_with_info(configuration: &configuration::Configuration, params: UpdateUserPasswordParams) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<UpdateUserPasswordError>> {
+    crate::apis::execute(configuration, update_user_password_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_user_password`] sends.
+pub(crate) fn update_user_password_request(params: UpdateUserPasswordParams) -> crate::apis::Request {
     // unbox the parameters
     let old_password = params.old_password;
     let new_password = params.new_password;
 
+    let local_var_path = String::from("/current_user/password");
+    let mut local_var_req = crate::apis::Request::new("update_user_password", reqwest::Method::POST, local_var_path).auth(&[crate::apis::AuthScheme::Basic]);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/current_user/password", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
-        local_var_req_builder = local_var_req_builder.basic_auth(local_var_auth_conf.0.to_owned(), local_var_auth_conf.1.to_owned());
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = old_password {
-        local_var_form_params.insert("old_password", local_var_param_value.to_string());
+        local_var_form_params.insert("old_password", local_var_param_value.expose().to_string());
     }
     if let Some(local_var_param_value) = new_password {
-        local_var_form_params.insert("new_password", local_var_param_value.to_string());
-    }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_form_params.insert("new_password", local_var_param_value.expose().to_string());
     }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateUserPasswordError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
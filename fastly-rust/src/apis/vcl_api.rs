@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_custom_vcl`]
@@ -244,9 +243,17 @@ pub enum UpdateCustomVclError {
 
 
 /// Upload a VCL for a particular service and version.
-pub async fn create_custom_vcl(configuration: &mut configuration::Configuration, params: CreateCustomVclParams) -> Result<crate::models::VclResponse, Error<CreateCustomVclError>> {
-    let local_var_configuration = configuration;
+pub async fn create_custom_vcl(configuration: &configuration::Configuration, params: CreateCustomVclParams) -> Result<crate::models::VclResponse, Error<CreateCustomVclError>> {
+    create_custom_vcl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_custom_vcl`], but also returns the status and headers of the response.
+pub async fn create_custom_vcl_with_info(configuration: &configuration::Configuration, params: CreateCustomVclParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<CreateCustomVclError>> {
+    crate::apis::execute(configuration, create_custom_vcl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_custom_vcl`] sends.
+pub(crate) fn create_custom_vcl_request(params: CreateCustomVclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -254,24 +261,10 @@ pub async fn create_custom_vcl(configuration: &mut configuration::Configuration,
     let main = params.main;
     let name = params.name;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/vcl", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_custom_vcl", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/vcl", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = content {
         local_var_form_params.insert("content", local_var_param_value.to_string());
     }
@@ -281,632 +274,262 @@ pub async fn create_custom_vcl(configuration: &mut configuration::Configuration,
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateCustomVclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Delete the uploaded VCL for a particular service and version.
-pub async fn delete_custom_vcl(configuration: &mut configuration::Configuration, params: DeleteCustomVclParams) -> Result<crate::models::InlineResponse200, Error<DeleteCustomVclError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_custom_vcl(configuration: &configuration::Configuration, params: DeleteCustomVclParams) -> Result<crate::models::InlineResponse200, Error<DeleteCustomVclError>> {
+    delete_custom_vcl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_custom_vcl`], but also returns the status and headers of the response.
+pub async fn delete_custom_vcl_with_info(configuration: &configuration::Configuration, params: DeleteCustomVclParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteCustomVclError>> {
+    crate::apis::execute(configuration, delete_custom_vcl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`delete_custom_vcl`] sends.
+pub(crate) fn delete_custom_vcl_request(params: DeleteCustomVclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let vcl_name = params.vcl_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/vcl/{vcl_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeleteCustomVclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/vcl/{vcl_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
+    crate::apis::Request::new("delete_custom_vcl", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get the uploaded VCL for a particular service and version.
-pub async fn get_custom_vcl(configuration: &mut configuration::Configuration, params: GetCustomVclParams) -> Result<crate::models::VclResponse, Error<GetCustomVclError>> {
-    let local_var_configuration = configuration;
+pub async fn get_custom_vcl(configuration: &configuration::Configuration, params: GetCustomVclParams) -> Result<crate::models::VclResponse, Error<GetCustomVclError>> {
+    get_custom_vcl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_custom_vcl`], but also returns the status and headers of the response.
+pub async fn get_custom_vcl_with_info(configuration: &configuration::Configuration, params: GetCustomVclParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<GetCustomVclError>> {
+    crate::apis::execute(configuration, get_custom_vcl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_custom_vcl`] sends.
+pub(crate) fn get_custom_vcl_request(params: GetCustomVclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let vcl_name = params.vcl_name;
     let no_content = params.no_content;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/vcl/{vcl_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/vcl/{vcl_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
+    let mut local_var_req = crate::apis::Request::new("get_custom_vcl", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = no_content {
-        local_var_req_builder = local_var_req_builder.query(&[("no_content", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("no_content", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCustomVclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Return boilerplate VCL with the service's TTL from the [settings](https://www.fastly.com/documentation/reference/api/vcl-services/settings/).
-pub async fn get_custom_vcl_boilerplate(configuration: &mut configuration::Configuration, params: GetCustomVclBoilerplateParams) -> Result<String, Error<GetCustomVclBoilerplateError>> {
-    let local_var_configuration = configuration;
+pub async fn get_custom_vcl_boilerplate(configuration: &configuration::Configuration, params: GetCustomVclBoilerplateParams) -> Result<String, Error<GetCustomVclBoilerplateError>> {
+    get_custom_vcl_boilerplate_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_custom_vcl_boilerplate`], but also returns the status and headers of the response.
+pub async fn get_custom_vcl_boilerplate_with_info(configuration: &configuration::Configuration, params: GetCustomVclBoilerplateParams) -> Result<crate::apis::Response<String>, Error<GetCustomVclBoilerplateError>> {
+    crate::apis::execute(configuration, get_custom_vcl_boilerplate_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_custom_vcl_boilerplate`] sends.
+pub(crate) fn get_custom_vcl_boilerplate_request(params: GetCustomVclBoilerplateParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/boilerplate", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCustomVclBoilerplateError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/boilerplate", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("get_custom_vcl_boilerplate", reqwest::Method::GET, local_var_path)
 }
 
 /// Display the generated VCL for a particular service and version.
-pub async fn get_custom_vcl_generated(configuration: &mut configuration::Configuration, params: GetCustomVclGeneratedParams) -> Result<crate::models::VclResponse, Error<GetCustomVclGeneratedError>> {
-    let local_var_configuration = configuration;
+pub async fn get_custom_vcl_generated(configuration: &configuration::Configuration, params: GetCustomVclGeneratedParams) -> Result<crate::models::VclResponse, Error<GetCustomVclGeneratedError>> {
+    get_custom_vcl_generated_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_custom_vcl_generated`], but also returns the status and headers of the response.
+pub async fn get_custom_vcl_generated_with_info(configuration: &configuration::Configuration, params: GetCustomVclGeneratedParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<GetCustomVclGeneratedError>> {
+    crate::apis::execute(configuration, get_custom_vcl_generated_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_custom_vcl_generated`] sends.
+pub(crate) fn get_custom_vcl_generated_request(params: GetCustomVclGeneratedParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/generated_vcl", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCustomVclGeneratedError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/generated_vcl", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("get_custom_vcl_generated", reqwest::Method::GET, local_var_path)
 }
 
 /// Display the content of generated VCL with HTML syntax highlighting. Include line numbers by sending `lineno=true` as a request parameter.
-pub async fn get_custom_vcl_generated_highlighted(configuration: &mut configuration::Configuration, params: GetCustomVclGeneratedHighlightedParams) -> Result<crate::models::VclSyntaxHighlightingResponse, Error<GetCustomVclGeneratedHighlightedError>> {
-    let local_var_configuration = configuration;
+pub async fn get_custom_vcl_generated_highlighted(configuration: &configuration::Configuration, params: GetCustomVclGeneratedHighlightedParams) -> Result<crate::models::VclSyntaxHighlightingResponse, Error<GetCustomVclGeneratedHighlightedError>> {
+    get_custom_vcl_generated_highlighted_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_custom_vcl_generated_highlighted`], but also returns the status and headers of the response.
+pub async fn get_custom_vcl_generated_highlighted_with_info(configuration: &configuration::Configuration, params: GetCustomVclGeneratedHighlightedParams) -> Result<crate::apis::Response<crate::models::VclSyntaxHighlightingResponse>, Error<GetCustomVclGeneratedHighlightedError>> {
+    crate::apis::execute(configuration, get_custom_vcl_generated_highlighted_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_custom_vcl_generated_highlighted`] sends.
+pub(crate) fn get_custom_vcl_generated_highlighted_request(params: GetCustomVclGeneratedHighlightedParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/generated_vcl/content", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCustomVclGeneratedHighlightedError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/generated_vcl/content", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("get_custom_vcl_generated_highlighted", reqwest::Method::GET, local_var_path)
 }
 
 /// Get the uploaded VCL for a particular service and version with HTML syntax highlighting. Include line numbers by sending `lineno=true` as a request parameter.
-pub async fn get_custom_vcl_highlighted(configuration: &mut configuration::Configuration, params: GetCustomVclHighlightedParams) -> Result<crate::models::VclSyntaxHighlightingResponse, Error<GetCustomVclHighlightedError>> {
-    let local_var_configuration = configuration;
+pub async fn get_custom_vcl_highlighted(configuration: &configuration::Configuration, params: GetCustomVclHighlightedParams) -> Result<crate::models::VclSyntaxHighlightingResponse, Error<GetCustomVclHighlightedError>> {
+    get_custom_vcl_highlighted_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`get_custom_vcl_highlighted`], but also returns the status and headers of the response.
+pub async fn get_custom_vcl_highlighted_with_info(configuration: &configuration::Configuration, params: GetCustomVclHighlightedParams) -> Result<crate::apis::Response<crate::models::VclSyntaxHighlightingResponse>, Error<GetCustomVclHighlightedError>> {
+    crate::apis::execute(configuration, get_custom_vcl_highlighted_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`get_custom_vcl_highlighted`] sends.
+pub(crate) fn get_custom_vcl_highlighted_request(params: GetCustomVclHighlightedParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let vcl_name = params.vcl_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/vcl/{vcl_name}/content", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCustomVclHighlightedError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/vcl/{vcl_name}/content", service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
+    crate::apis::Request::new("get_custom_vcl_highlighted", reqwest::Method::GET, local_var_path)
 }
 
 /// Download the specified VCL.
-pub async fn get_custom_vcl_raw(configuration: &mut configuration::Configuration, params: GetCustomVclRawParams) -> Result<String, Error<GetCustomVclRawError>> {
-    let local_var_configuration = configuration;
+pub async fn get_custom_vcl_raw(configuration: &configuration::Configuration, params: GetCustomVclRawParams) -> Result<String, Error<GetCustomVclRawError>> {
+    get_custom_vcl_raw_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_custom_vcl_raw`], but also returns the status and headers of the response.
+pub async fn get_custom_vcl_raw_with_info(configuration: &configuration::Configuration, params: GetCustomVclRawParams) -> Result<crate::apis::Response<String>, Error<GetCustomVclRawError>> {
+    crate::apis::execute(configuration, get_custom_vcl_raw_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_custom_vcl_raw`] sends.
+pub(crate) fn get_custom_vcl_raw_request(params: GetCustomVclRawParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let vcl_name = params.vcl_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/vcl/{vcl_name}/download", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetCustomVclRawError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/vcl/{vcl_name}/download", service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
+    crate::apis::Request::new("get_custom_vcl_raw", reqwest::Method::GET, local_var_path)
 }
 
 /// This endpoint validates the submitted VCL against a default set of enabled flags. Consider using the `/service/{service_id}/lint` operation to validate VCL in the context of a specific service.
-pub async fn lint_vcl_default(configuration: &mut configuration::Configuration, params: LintVclDefaultParams) -> Result<crate::models::ValidatorResult, Error<LintVclDefaultError>> {
-    let local_var_configuration = configuration;
+pub async fn lint_vcl_default(configuration: &configuration::Configuration, params: LintVclDefaultParams) -> Result<crate::models::ValidatorResult, Error<LintVclDefaultError>> {
+    lint_vcl_default_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`lint_vcl_default`], but also returns the status and headers of the response.
+pub async fn lint_vcl_default_with_info(configuration: &configuration::Configuration, params: LintVclDefaultParams) -> Result<crate::apis::Response<crate::models::ValidatorResult>, Error<LintVclDefaultError>> {
+    crate::apis::execute(configuration, lint_vcl_default_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`lint_vcl_default`] sends.
+pub(crate) fn lint_vcl_default_request(params: LintVclDefaultParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let inline_object1 = params.inline_object1;
 
+    let local_var_path = String::from("/vcl_lint");
+    let mut local_var_req = crate::apis::Request::new("lint_vcl_default", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/vcl_lint", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&inline_object1);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&inline_object1)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<LintVclDefaultError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Services may have flags set by a Fastly employee or by the purchase of products as addons to the service, which modify the way VCL is interpreted by that service.  This endpoint validates the submitted VCL in the context of the specified service.
-pub async fn lint_vcl_for_service(configuration: &mut configuration::Configuration, params: LintVclForServiceParams) -> Result<crate::models::ValidatorResult, Error<LintVclForServiceError>> {
-    let local_var_configuration = configuration;
+pub async fn lint_vcl_for_service(configuration: &configuration::Configuration, params: LintVclForServiceParams) -> Result<crate::models::ValidatorResult, Error<LintVclForServiceError>> {
+    lint_vcl_for_service_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`lint_vcl_for_service`], but also returns the status and headers of the response.
+pub async fn lint_vcl_for_service_with_info(configuration: &configuration::Configuration, params: LintVclForServiceParams) -> Result<crate::apis::Response<crate::models::ValidatorResult>, Error<LintVclForServiceError>> {
+    crate::apis::execute(configuration, lint_vcl_for_service_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`lint_vcl_for_service`] sends.
+pub(crate) fn lint_vcl_for_service_request(params: LintVclForServiceParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let service_id = params.service_id;
     let inline_object = params.inline_object;
 
+    let local_var_path = format!("/service/{service_id}/lint", service_id=crate::apis::urlencode(service_id));
+    let mut local_var_req = crate::apis::Request::new("lint_vcl_for_service", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/lint", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&inline_object);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&inline_object)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<LintVclForServiceError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// List the uploaded VCLs for a particular service and version.
-pub async fn list_custom_vcl(configuration: &mut configuration::Configuration, params: ListCustomVclParams) -> Result<Vec<crate::models::VclResponse>, Error<ListCustomVclError>> {
-    let local_var_configuration = configuration;
+pub async fn list_custom_vcl(configuration: &configuration::Configuration, params: ListCustomVclParams) -> Result<Vec<crate::models::VclResponse>, Error<ListCustomVclError>> {
+    list_custom_vcl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_custom_vcl`], but also returns the status and headers of the response.
+pub async fn list_custom_vcl_with_info(configuration: &configuration::Configuration, params: ListCustomVclParams) -> Result<crate::apis::Response<Vec<crate::models::VclResponse>>, Error<ListCustomVclError>> {
+    crate::apis::execute(configuration, list_custom_vcl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_custom_vcl`] sends.
+pub(crate) fn list_custom_vcl_request(params: ListCustomVclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/vcl", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListCustomVclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/vcl", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("list_custom_vcl", reqwest::Method::GET, local_var_path)
 }
 
 /// Set the specified VCL as the main.
-pub async fn set_custom_vcl_main(configuration: &mut configuration::Configuration, params: SetCustomVclMainParams) -> Result<crate::models::VclResponse, Error<SetCustomVclMainError>> {
-    let local_var_configuration = configuration;
+pub async fn set_custom_vcl_main(configuration: &configuration::Configuration, params: SetCustomVclMainParams) -> Result<crate::models::VclResponse, Error<SetCustomVclMainError>> {
+    set_custom_vcl_main_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`set_custom_vcl_main`], but also returns the status and headers of the response.
+pub async fn set_custom_vcl_main_with_info(configuration: &configuration::Configuration, params: SetCustomVclMainParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<SetCustomVclMainError>> {
+    crate::apis::execute(configuration, set_custom_vcl_main_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`set_custom_vcl_main`] sends.
+pub(crate) fn set_custom_vcl_main_request(params: SetCustomVclMainParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
     let vcl_name = params.vcl_name;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/vcl/{vcl_name}/main", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<SetCustomVclMainError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/vcl/{vcl_name}/main", service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
+    crate::apis::Request::new("set_custom_vcl_main", reqwest::Method::PUT, local_var_path)
 }
 
 /// Update the uploaded VCL for a particular service and version.
-pub async fn update_custom_vcl(configuration: &mut configuration::Configuration, params: UpdateCustomVclParams) -> Result<crate::models::VclResponse, Error<UpdateCustomVclError>> {
-    let local_var_configuration = configuration;
+pub async fn update_custom_vcl(configuration: &configuration::Configuration, params: UpdateCustomVclParams) -> Result<crate::models::VclResponse, Error<UpdateCustomVclError>> {
+    update_custom_vcl_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_custom_vcl`], but also returns the status and headers of the response.
+pub async fn update_custom_vcl_with_info(configuration: &configuration::Configuration, params: UpdateCustomVclParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<UpdateCustomVclError>> {
+    crate::apis::execute(configuration, update_custom_vcl_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_custom_vcl`] sends.
+pub(crate) fn update_custom_vcl_request(params: UpdateCustomVclParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -915,24 +538,10 @@ pub async fn update_custom_vcl(configuration: &mut configuration::Configuration,
     let main = params.main;
     let name = params.name;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/vcl/{vcl_name}", service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
+    let mut local_var_req = crate::apis::Request::new("update_custom_vcl", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/vcl/{vcl_name}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id, vcl_name=crate::apis::urlencode(vcl_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = content {
         local_var_form_params.insert("content", local_var_param_value.to_string());
     }
@@ -942,32 +551,8 @@ pub async fn update_custom_vcl(configuration: &mut configuration::Configuration,
     if let Some(local_var_param_value) = name {
         local_var_form_params.insert("name", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateCustomVclError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`activate_service_version`]
@@ -168,378 +167,155 @@ pub enum ValidateServiceVersionError {
 
 
 /// Activate the current version.
-pub async fn activate_service_version(configuration: &mut configuration::Configuration, params: ActivateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<ActivateServiceVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn activate_service_version(configuration: &configuration::Configuration, params: ActivateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<ActivateServiceVersionError>> {
+    activate_service_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`activate_service_version`], but also returns the status and headers of the response.
+pub async fn activate_service_version_with_info(configuration: &configuration::Configuration, params: ActivateServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionResponse>, Error<ActivateServiceVersionError>> {
+    crate::apis::execute(configuration, activate_service_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`activate_service_version`] sends.
+pub(crate) fn activate_service_version_request(params: ActivateServiceVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/activate", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ActivateServiceVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/activate", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("activate_service_version", reqwest::Method::PUT, local_var_path)
 }
 
 /// Clone the current configuration into a new version.
-pub async fn clone_service_version(configuration: &mut configuration::Configuration, params: CloneServiceVersionParams) -> Result<crate::models::Version, Error<CloneServiceVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn clone_service_version(configuration: &configuration::Configuration, params: CloneServiceVersionParams) -> Result<crate::models::Version, Error<CloneServiceVersionError>> {
+    clone_service_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`clone_service_version`], but also returns the status and headers of the response.
+pub async fn clone_service_version_with_info(configuration: &configuration::Configuration, params: CloneServiceVersionParams) -> Result<crate::apis::Response<crate::models::Version>, Error<CloneServiceVersionError>> {
+    crate::apis::execute(configuration, clone_service_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`clone_service_version`] sends.
+pub(crate) fn clone_service_version_request(params: CloneServiceVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/clone", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CloneServiceVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/clone", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("clone_service_version", reqwest::Method::PUT, local_var_path)
 }
 
 /// Create a version for a particular service.
-pub async fn create_service_version(configuration: &mut configuration::Configuration, params: CreateServiceVersionParams) -> Result<crate::models::VersionCreateResponse, Error<CreateServiceVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn create_service_version(configuration: &configuration::Configuration, params: CreateServiceVersionParams) -> Result<crate::models::VersionCreateResponse, Error<CreateServiceVersionError>> {
+    create_service_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_service_version`], but also returns the status and headers of the response.
+pub async fn create_service_version_with_info(configuration: &configuration::Configuration, params: CreateServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionCreateResponse>, Error<CreateServiceVersionError>> {
+    crate::apis::execute(configuration, create_service_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_service_version`] sends.
+pub(crate) fn create_service_version_request(params: CreateServiceVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateServiceVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version", service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("create_service_version", reqwest::Method::POST, local_var_path)
 }
 
 /// Deactivate the current version.
-pub async fn deactivate_service_version(configuration: &mut configuration::Configuration, params: DeactivateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<DeactivateServiceVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn deactivate_service_version(configuration: &configuration::Configuration, params: DeactivateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<DeactivateServiceVersionError>> {
+    deactivate_service_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`deactivate_service_version`], but also returns the status and headers of the response.
+pub async fn deactivate_service_version_with_info(configuration: &configuration::Configuration, params: DeactivateServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionResponse>, Error<DeactivateServiceVersionError>> {
+    crate::apis::execute(configuration, deactivate_service_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`deactivate_service_version`] sends.
+pub(crate) fn deactivate_service_version_request(params: DeactivateServiceVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/deactivate", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeactivateServiceVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/deactivate", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("deactivate_service_version", reqwest::Method::PUT, local_var_path)
 }
 
 /// Get the version for a particular service.
-pub async fn get_service_version(configuration: &mut configuration::Configuration, params: GetServiceVersionParams) -> Result<crate::models::VersionResponse, Error<GetServiceVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn get_service_version(configuration: &configuration::Configuration, params: GetServiceVersionParams) -> Result<crate::models::VersionResponse, Error<GetServiceVersionError>> {
+    get_service_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_service_version`], but also returns the status and headers of the response.
+pub async fn get_service_version_with_info(configuration: &configuration::Configuration, params: GetServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionResponse>, Error<GetServiceVersionError>> {
+    crate::apis::execute(configuration, get_service_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_service_version`] sends.
+pub(crate) fn get_service_version_request(params: GetServiceVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetServiceVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("get_service_version", reqwest::Method::GET, local_var_path)
 }
 
 /// List the versions for a particular service.
-pub async fn list_service_versions(configuration: &mut configuration::Configuration, params: ListServiceVersionsParams) -> Result<Vec<crate::models::VersionResponse>, Error<ListServiceVersionsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_service_versions(configuration: &configuration::Configuration, params: ListServiceVersionsParams) -> Result<Vec<crate::models::VersionResponse>, Error<ListServiceVersionsError>> {
+    list_service_versions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_service_versions`], but also returns the status and headers of the response.
+pub async fn list_service_versions_with_info(configuration: &configuration::Configuration, params: ListServiceVersionsParams) -> Result<crate::apis::Response<Vec<crate::models::VersionResponse>>, Error<ListServiceVersionsError>> {
+    crate::apis::execute(configuration, list_service_versions_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_service_versions`] sends.
+pub(crate) fn list_service_versions_request(params: ListServiceVersionsParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListServiceVersionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version", service_id=crate::apis::urlencode(service_id));
+    crate::apis::Request::new("list_service_versions", reqwest::Method::GET, local_var_path)
 }
 
 /// Locks the specified version.
-pub async fn lock_service_version(configuration: &mut configuration::Configuration, params: LockServiceVersionParams) -> Result<crate::models::Version, Error<LockServiceVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn lock_service_version(configuration: &configuration::Configuration, params: LockServiceVersionParams) -> Result<crate::models::Version, Error<LockServiceVersionError>> {
+    lock_service_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`lock_service_version`], but also returns the status and headers of the response.
+pub async fn lock_service_version_with_info(configuration: &configuration::Configuration, params: LockServiceVersionParams) -> Result<crate::apis::Response<crate::models::Version>, Error<LockServiceVersionError>> {
+    crate::apis::execute(configuration, lock_service_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`lock_service_version`] sends.
+pub(crate) fn lock_service_version_request(params: LockServiceVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/lock", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<LockServiceVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/lock", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("lock_service_version", reqwest::Method::PUT, local_var_path)
 }
 
 /// Update a particular version for a particular service.
-pub async fn update_service_version(configuration: &mut configuration::Configuration, params: UpdateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<UpdateServiceVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn update_service_version(configuration: &configuration::Configuration, params: UpdateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<UpdateServiceVersionError>> {
+    update_service_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_service_version`], but also returns the status and headers of the response.
+pub async fn update_service_version_with_info(configuration: &configuration::Configuration, params: UpdateServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionResponse>, Error<UpdateServiceVersionError>> {
+    crate::apis::execute(configuration, update_service_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_service_version`] sends.
+pub(crate) fn update_service_version_request(params: UpdateServiceVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
@@ -551,24 +327,10 @@ pub async fn update_service_version(configuration: &mut configuration::Configura
     let staging = params.staging;
     let testing = params.testing;
 
+    let local_var_path = format!("/service/{service_id}/version/{version_id}", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("update_service_version", reqwest::Method::PUT, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    let mut local_var_form_params = std::collections::HashMap::new();
+    let mut local_var_form_params = crate::apis::FormParams::new();
     if let Some(local_var_param_value) = active {
         local_var_form_params.insert("active", local_var_param_value.to_string());
     }
@@ -590,85 +352,28 @@ pub async fn update_service_version(configuration: &mut configuration::Configura
     if let Some(local_var_param_value) = testing {
         local_var_form_params.insert("testing", local_var_param_value.to_string());
     }
-    local_var_req_builder = local_var_req_builder.form(&local_var_form_params);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.form(local_var_form_params);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateServiceVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Validate the version for a particular service and version.
-pub async fn validate_service_version(configuration: &mut configuration::Configuration, params: ValidateServiceVersionParams) -> Result<crate::models::InlineResponse200, Error<ValidateServiceVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn validate_service_version(configuration: &configuration::Configuration, params: ValidateServiceVersionParams) -> Result<crate::models::InlineResponse200, Error<ValidateServiceVersionError>> {
+    validate_service_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`validate_service_version`], but also returns the status and headers of the response.
+pub async fn validate_service_version_with_info(configuration: &configuration::Configuration, params: ValidateServiceVersionParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<ValidateServiceVersionError>> {
+    crate::apis::execute(configuration, validate_service_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`validate_service_version`] sends.
+pub(crate) fn validate_service_version_request(params: ValidateServiceVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let service_id = params.service_id;
     let version_id = params.version_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/service/{service_id}/version/{version_id}/validate", local_var_configuration.base_path, service_id=crate::apis::urlencode(service_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ValidateServiceVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/service/{service_id}/version/{version_id}/validate", service_id=crate::apis::urlencode(service_id), version_id=version_id);
+    crate::apis::Request::new("validate_service_version", reqwest::Method::GET, local_var_path)
 }
 
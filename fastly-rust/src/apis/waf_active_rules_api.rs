@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`bulk_delete_waf_active_rules`]
@@ -171,342 +170,167 @@ pub enum UpdateWafActiveRuleError {
 
 
 /// Delete many active rules on a particular firewall version using the active rule ID. Limited to 500 rules per request.
-pub async fn bulk_delete_waf_active_rules(configuration: &mut configuration::Configuration, params: BulkDeleteWafActiveRulesParams) -> Result<(), Error<BulkDeleteWafActiveRulesError>> {
-    let local_var_configuration = configuration;
+pub async fn bulk_delete_waf_active_rules(configuration: &configuration::Configuration, params: BulkDeleteWafActiveRulesParams) -> Result<(), Error<BulkDeleteWafActiveRulesError>> {
+    bulk_delete_waf_active_rules_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`bulk_delete_waf_active_rules`], but also returns the status and headers of the response.
+pub async fn bulk_delete_waf_active_rules_with_info(configuration: &configuration::Configuration, params: BulkDeleteWafActiveRulesParams) -> Result<crate::apis::Response<()>, Error<BulkDeleteWafActiveRulesError>> {
+    crate::apis::execute(configuration, bulk_delete_waf_active_rules_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`bulk_delete_waf_active_rules`] sends.
+pub(crate) fn bulk_delete_waf_active_rules_request(params: BulkDeleteWafActiveRulesParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let version_id = params.version_id;
     let request_body = params.request_body;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules", firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("bulk_delete_waf_active_rules", reqwest::Method::DELETE, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&request_body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&request_body)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<BulkDeleteWafActiveRulesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Bulk update all active rules on a [firewall version](https://www.fastly.com/documentation/reference/api/waf/firewall-version/). This endpoint will not add new active rules, only update existing active rules.
-pub async fn bulk_update_waf_active_rules(configuration: &mut configuration::Configuration, params: BulkUpdateWafActiveRulesParams) -> Result<(), Error<BulkUpdateWafActiveRulesError>> {
-    let local_var_configuration = configuration;
+pub async fn bulk_update_waf_active_rules(configuration: &configuration::Configuration, params: BulkUpdateWafActiveRulesParams) -> Result<(), Error<BulkUpdateWafActiveRulesError>> {
+    bulk_update_waf_active_rules_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`bulk_update_waf_active_rules`], but also returns the status and headers of the response.
+pub async fn bulk_update_waf_active_rules_with_info(configuration: &configuration::Configuration, params: BulkUpdateWafActiveRulesParams) -> Result<crate::apis::Response<()>, Error<BulkUpdateWafActiveRulesError>> {
+    crate::apis::execute(configuration, bulk_update_waf_active_rules_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`bulk_update_waf_active_rules`] sends.
+pub(crate) fn bulk_update_waf_active_rules_request(params: BulkUpdateWafActiveRulesParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let version_id = params.version_id;
     let body = params.body;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules/bulk", firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("bulk_update_waf_active_rules", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules/bulk", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&body)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&body);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<BulkUpdateWafActiveRulesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create an active rule for a particular firewall version.
-pub async fn create_waf_active_rule(configuration: &mut configuration::Configuration, params: CreateWafActiveRuleParams) -> Result<crate::models::WafActiveRuleCreationResponse, Error<CreateWafActiveRuleError>> {
-    let local_var_configuration = configuration;
+pub async fn create_waf_active_rule(configuration: &configuration::Configuration, params: CreateWafActiveRuleParams) -> Result<crate::models::WafActiveRuleCreationResponse, Error<CreateWafActiveRuleError>> {
+    create_waf_active_rule_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`create_waf_active_rule`], but also returns the status and headers of the response.
+pub async fn create_waf_active_rule_with_info(configuration: &configuration::Configuration, params: CreateWafActiveRuleParams) -> Result<crate::apis::Response<crate::models::WafActiveRuleCreationResponse>, Error<CreateWafActiveRuleError>> {
+    crate::apis::execute(configuration, create_waf_active_rule_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`create_waf_active_rule`] sends.
+pub(crate) fn create_waf_active_rule_request(params: CreateWafActiveRuleParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let version_id = params.version_id;
     let waf_active_rule = params.waf_active_rule;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules", firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("create_waf_active_rule", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_active_rule)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_active_rule);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateWafActiveRuleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Create active rules by tag. This endpoint will create active rules using the latest revision available for each rule.
-pub async fn create_waf_active_rules_tag(configuration: &mut configuration::Configuration, params: CreateWafActiveRulesTagParams) -> Result<(), Error<CreateWafActiveRulesTagError>> {
-    let local_var_configuration = configuration;
+pub async fn create_waf_active_rules_tag(configuration: &configuration::Configuration, params: CreateWafActiveRulesTagParams) -> Result<(), Error<CreateWafActiveRulesTagError>> {
+    create_waf_active_rules_tag_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`create_waf_active_rules_tag`], but also returns the status and headers of the response.
+pub async fn create_waf_active_rules_tag_with_info(configuration: &configuration::Configuration, params: CreateWafActiveRulesTagParams) -> Result<crate::apis::Response<()>, Error<CreateWafActiveRulesTagError>> {
+    crate::apis::execute(configuration, create_waf_active_rules_tag_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`create_waf_active_rules_tag`] sends.
+pub(crate) fn create_waf_active_rules_tag_request(params: CreateWafActiveRulesTagParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let version_id = params.version_id;
     let waf_tag_name = params.waf_tag_name;
     let waf_active_rule = params.waf_active_rule;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{version_id}/tags/{waf_tag_name}/active-rules", firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id, waf_tag_name=crate::apis::urlencode(waf_tag_name));
+    let mut local_var_req = crate::apis::Request::new("create_waf_active_rules_tag", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{version_id}/tags/{waf_tag_name}/active-rules", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id, waf_tag_name=crate::apis::urlencode(waf_tag_name));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_active_rule)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_active_rule);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<CreateWafActiveRulesTagError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete an active rule for a particular firewall version.
-pub async fn delete_waf_active_rule(configuration: &mut configuration::Configuration, params: DeleteWafActiveRuleParams) -> Result<(), Error<DeleteWafActiveRuleError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_waf_active_rule(configuration: &configuration::Configuration, params: DeleteWafActiveRuleParams) -> Result<(), Error<DeleteWafActiveRuleError>> {
+    delete_waf_active_rule_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_waf_active_rule`], but also returns the status and headers of the response.
+pub async fn delete_waf_active_rule_with_info(configuration: &configuration::Configuration, params: DeleteWafActiveRuleParams) -> Result<crate::apis::Response<()>, Error<DeleteWafActiveRuleError>> {
+    crate::apis::execute(configuration, delete_waf_active_rule_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_waf_active_rule`] sends.
+pub(crate) fn delete_waf_active_rule_request(params: DeleteWafActiveRuleParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let version_id = params.version_id;
     let waf_rule_id = params.waf_rule_id;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules/{waf_rule_id}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id, waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteWafActiveRuleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules/{waf_rule_id}", firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id, waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    crate::apis::Request::new("delete_waf_active_rule", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a specific active rule object. Includes details of the rule revision associated with the active rule object by default.
-pub async fn get_waf_active_rule(configuration: &mut configuration::Configuration, params: GetWafActiveRuleParams) -> Result<crate::models::WafActiveRuleResponse, Error<GetWafActiveRuleError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_active_rule(configuration: &configuration::Configuration, params: GetWafActiveRuleParams) -> Result<crate::models::WafActiveRuleResponse, Error<GetWafActiveRuleError>> {
+    get_waf_active_rule_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_active_rule`], but also returns the status and headers of the response.
+pub async fn get_waf_active_rule_with_info(configuration: &configuration::Configuration, params: GetWafActiveRuleParams) -> Result<crate::apis::Response<crate::models::WafActiveRuleResponse>, Error<GetWafActiveRuleError>> {
+    crate::apis::execute(configuration, get_waf_active_rule_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_active_rule`] sends.
+pub(crate) fn get_waf_active_rule_request(params: GetWafActiveRuleParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let version_id = params.version_id;
     let waf_rule_id = params.waf_rule_id;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules/{waf_rule_id}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id, waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules/{waf_rule_id}", firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id, waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    let mut local_var_req = crate::apis::Request::new("get_waf_active_rule", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafActiveRuleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all active rules for a particular firewall version.
-pub async fn list_waf_active_rules(configuration: &mut configuration::Configuration, params: ListWafActiveRulesParams) -> Result<crate::models::WafActiveRulesResponse, Error<ListWafActiveRulesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_waf_active_rules(configuration: &configuration::Configuration, params: ListWafActiveRulesParams) -> Result<crate::models::WafActiveRulesResponse, Error<ListWafActiveRulesError>> {
+    list_waf_active_rules_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_waf_active_rules`], but also returns the status and headers of the response.
+pub async fn list_waf_active_rules_with_info(configuration: &configuration::Configuration, params: ListWafActiveRulesParams) -> Result<crate::apis::Response<crate::models::WafActiveRulesResponse>, Error<ListWafActiveRulesError>> {
+    crate::apis::execute(configuration, list_waf_active_rules_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_waf_active_rules`] sends.
+pub(crate) fn list_waf_active_rules_request(params: ListWafActiveRulesParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let version_id = params.version_id;
@@ -518,125 +342,57 @@ pub async fn list_waf_active_rules(configuration: &mut configuration::Configurat
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules", firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id);
+    let mut local_var_req = crate::apis::Request::new("list_waf_active_rules", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_status {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[status]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[status]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_waf_rule_revision_message {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[waf_rule_revision][message]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[waf_rule_revision][message]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_waf_rule_revision_modsec_rule_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[waf_rule_revision][modsec_rule_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[waf_rule_revision][modsec_rule_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_outdated {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[outdated]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[outdated]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafActiveRulesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update an active rule's status for a particular firewall version.
-pub async fn update_waf_active_rule(configuration: &mut configuration::Configuration, params: UpdateWafActiveRuleParams) -> Result<crate::models::WafActiveRuleResponse, Error<UpdateWafActiveRuleError>> {
-    let local_var_configuration = configuration;
+pub async fn update_waf_active_rule(configuration: &configuration::Configuration, params: UpdateWafActiveRuleParams) -> Result<crate::models::WafActiveRuleResponse, Error<UpdateWafActiveRuleError>> {
+    update_waf_active_rule_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_waf_active_rule`], but also returns the status and headers of the response.
+pub async fn update_waf_active_rule_with_info(configuration: &configuration::Configuration, params: UpdateWafActiveRuleParams) -> Result<crate::apis::Response<crate::models::WafActiveRuleResponse>, Error<UpdateWafActiveRuleError>> {
+    crate::apis::execute(configuration, update_waf_active_rule_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_waf_active_rule`] sends.
+pub(crate) fn update_waf_active_rule_request(params: UpdateWafActiveRuleParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let version_id = params.version_id;
     let waf_rule_id = params.waf_rule_id;
     let waf_active_rule = params.waf_active_rule;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules/{waf_rule_id}", firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id, waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    let mut local_var_req = crate::apis::Request::new("update_waf_active_rule", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_active_rule)?);
 
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{version_id}/active-rules/{waf_rule_id}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), version_id=version_id, waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_active_rule);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateWafActiveRuleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_waf_rule_exclusion`]
@@ -114,172 +113,84 @@ pub enum UpdateWafRuleExclusionError {
 
 
 /// Create a WAF exclusion for a particular firewall version.
-pub async fn create_waf_rule_exclusion(configuration: &mut configuration::Configuration, params: CreateWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<CreateWafRuleExclusionError>> {
-    let local_var_configuration = configuration;
+pub async fn create_waf_rule_exclusion(configuration: &configuration::Configuration, params: CreateWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<CreateWafRuleExclusionError>> {
+    create_waf_rule_exclusion_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_waf_rule_exclusion`], but also returns the status and headers of the response.
+pub async fn create_waf_rule_exclusion_with_info(configuration: &configuration::Configuration, params: CreateWafRuleExclusionParams) -> Result<crate::apis::Response<crate::models::WafExclusionResponse>, Error<CreateWafRuleExclusionError>> {
+    crate::apis::execute(configuration, create_waf_rule_exclusion_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_waf_rule_exclusion`] sends.
+pub(crate) fn create_waf_rule_exclusion_request(params: CreateWafRuleExclusionParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
     let waf_exclusion = params.waf_exclusion;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
+    let mut local_var_req = crate::apis::Request::new("create_waf_rule_exclusion", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_exclusion);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_exclusion)?);
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateWafRuleExclusionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete a WAF exclusion for a particular firewall version.
-pub async fn delete_waf_rule_exclusion(configuration: &mut configuration::Configuration, params: DeleteWafRuleExclusionParams) -> Result<(), Error<DeleteWafRuleExclusionError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_waf_rule_exclusion(configuration: &configuration::Configuration, params: DeleteWafRuleExclusionParams) -> Result<(), Error<DeleteWafRuleExclusionError>> {
+    delete_waf_rule_exclusion_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`delete_waf_rule_exclusion`], but also returns the status and headers of the response.
+pub async fn delete_waf_rule_exclusion_with_info(configuration: &configuration::Configuration, params: DeleteWafRuleExclusionParams) -> Result<crate::apis::Response<()>, Error<DeleteWafRuleExclusionError>> {
+    crate::apis::execute(configuration, delete_waf_rule_exclusion_request(params)).await.map(crate::apis::RawResponse::empty)
+}
+
+/// The request [`delete_waf_rule_exclusion`] sends.
+pub(crate) fn delete_waf_rule_exclusion_request(params: DeleteWafRuleExclusionParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
     let exclusion_number = params.exclusion_number;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions/{exclusion_number}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number, exclusion_number=exclusion_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteWafRuleExclusionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions/{exclusion_number}", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number, exclusion_number=exclusion_number);
+    crate::apis::Request::new("delete_waf_rule_exclusion", reqwest::Method::DELETE, local_var_path)
 }
 
 /// Get a specific WAF exclusion object.
-pub async fn get_waf_rule_exclusion(configuration: &mut configuration::Configuration, params: GetWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<GetWafRuleExclusionError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_rule_exclusion(configuration: &configuration::Configuration, params: GetWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<GetWafRuleExclusionError>> {
+    get_waf_rule_exclusion_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_rule_exclusion`], but also returns the status and headers of the response.
+pub async fn get_waf_rule_exclusion_with_info(configuration: &configuration::Configuration, params: GetWafRuleExclusionParams) -> Result<crate::apis::Response<crate::models::WafExclusionResponse>, Error<GetWafRuleExclusionError>> {
+    crate::apis::execute(configuration, get_waf_rule_exclusion_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_rule_exclusion`] sends.
+pub(crate) fn get_waf_rule_exclusion_request(params: GetWafRuleExclusionParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
     let exclusion_number = params.exclusion_number;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions/{exclusion_number}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number, exclusion_number=exclusion_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafRuleExclusionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions/{exclusion_number}", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number, exclusion_number=exclusion_number);
+    crate::apis::Request::new("get_waf_rule_exclusion", reqwest::Method::GET, local_var_path)
 }
 
 /// List all exclusions for a particular firewall version.
-pub async fn list_waf_rule_exclusions(configuration: &mut configuration::Configuration, params: ListWafRuleExclusionsParams) -> Result<crate::models::WafExclusionsResponse, Error<ListWafRuleExclusionsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_waf_rule_exclusions(configuration: &configuration::Configuration, params: ListWafRuleExclusionsParams) -> Result<crate::models::WafExclusionsResponse, Error<ListWafRuleExclusionsError>> {
+    list_waf_rule_exclusions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_waf_rule_exclusions`], but also returns the status and headers of the response.
+pub async fn list_waf_rule_exclusions_with_info(configuration: &configuration::Configuration, params: ListWafRuleExclusionsParams) -> Result<crate::apis::Response<crate::models::WafExclusionsResponse>, Error<ListWafRuleExclusionsError>> {
+    crate::apis::execute(configuration, list_waf_rule_exclusions_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_waf_rule_exclusions`] sends.
+pub(crate) fn list_waf_rule_exclusions_request(params: ListWafRuleExclusionsParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
@@ -290,122 +201,54 @@ pub async fn list_waf_rule_exclusions(configuration: &mut configuration::Configu
     let page_size = params.page_size;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
+    let mut local_var_req = crate::apis::Request::new("list_waf_rule_exclusions", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_exclusion_type {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[exclusion_type]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[exclusion_type]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_name {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[name]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[name]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_waf_rules_modsec_rule_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[waf_rules.modsec_rule_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[waf_rules.modsec_rule_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafRuleExclusionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a WAF exclusion for a particular firewall version.
-pub async fn update_waf_rule_exclusion(configuration: &mut configuration::Configuration, params: UpdateWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<UpdateWafRuleExclusionError>> {
-    let local_var_configuration = configuration;
+pub async fn update_waf_rule_exclusion(configuration: &configuration::Configuration, params: UpdateWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<UpdateWafRuleExclusionError>> {
+    update_waf_rule_exclusion_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_waf_rule_exclusion`], but also returns the status and headers of the response.
+pub async fn update_waf_rule_exclusion_with_info(configuration: &configuration::Configuration, params: UpdateWafRuleExclusionParams) -> Result<crate::apis::Response<crate::models::WafExclusionResponse>, Error<UpdateWafRuleExclusionError>> {
+    crate::apis::execute(configuration, update_waf_rule_exclusion_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_waf_rule_exclusion`] sends.
+pub(crate) fn update_waf_rule_exclusion_request(params: UpdateWafRuleExclusionParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
     let exclusion_number = params.exclusion_number;
     let waf_exclusion = params.waf_exclusion;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions/{exclusion_number}", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number, exclusion_number=exclusion_number);
+    let mut local_var_req = crate::apis::Request::new("update_waf_rule_exclusion", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_exclusion)?);
 
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/exclusions/{exclusion_number}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number, exclusion_number=exclusion_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_exclusion);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateWafRuleExclusionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
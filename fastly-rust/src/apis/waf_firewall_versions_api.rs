@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`clone_waf_firewall_version`]
@@ -116,338 +115,152 @@ pub enum UpdateWafFirewallVersionError {
 
 
 /// Clone a specific, existing firewall version into a new, draft firewall version.
-pub async fn clone_waf_firewall_version(configuration: &mut configuration::Configuration, params: CloneWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<CloneWafFirewallVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn clone_waf_firewall_version(configuration: &configuration::Configuration, params: CloneWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<CloneWafFirewallVersionError>> {
+    clone_waf_firewall_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`clone_waf_firewall_version`], but also returns the status and headers of the response.
+pub async fn clone_waf_firewall_version_with_info(configuration: &configuration::Configuration, params: CloneWafFirewallVersionParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionResponse>, Error<CloneWafFirewallVersionError>> {
+    crate::apis::execute(configuration, clone_waf_firewall_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`clone_waf_firewall_version`] sends.
+pub(crate) fn clone_waf_firewall_version_request(params: CloneWafFirewallVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/clone", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CloneWafFirewallVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/clone", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
+    crate::apis::Request::new("clone_waf_firewall_version", reqwest::Method::PUT, local_var_path)
 }
 
 /// Create a new, draft firewall version.
-pub async fn create_waf_firewall_version(configuration: &mut configuration::Configuration, params: CreateWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<CreateWafFirewallVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn create_waf_firewall_version(configuration: &configuration::Configuration, params: CreateWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<CreateWafFirewallVersionError>> {
+    create_waf_firewall_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`create_waf_firewall_version`], but also returns the status and headers of the response.
+pub async fn create_waf_firewall_version_with_info(configuration: &configuration::Configuration, params: CreateWafFirewallVersionParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionResponse>, Error<CreateWafFirewallVersionError>> {
+    crate::apis::execute(configuration, create_waf_firewall_version_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`create_waf_firewall_version`] sends.
+pub(crate) fn create_waf_firewall_version_request(params: CreateWafFirewallVersionParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let waf_firewall_version = params.waf_firewall_version;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions", firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("create_waf_firewall_version", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_firewall_version)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_firewall_version);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateWafFirewallVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Deploy or activate a specific firewall version. If a firewall has been disabled, deploying a firewall version will automatically enable the firewall again.
-pub async fn deploy_activate_waf_firewall_version(configuration: &mut configuration::Configuration, params: DeployActivateWafFirewallVersionParams) -> Result<serde_json::Value, Error<DeployActivateWafFirewallVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn deploy_activate_waf_firewall_version(configuration: &configuration::Configuration, params: DeployActivateWafFirewallVersionParams) -> Result<serde_json::Value, Error<DeployActivateWafFirewallVersionError>> {
+    deploy_activate_waf_firewall_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`deploy_activate_waf_firewall_version`], but also returns the status and headers of the response.
+pub async fn deploy_activate_waf_firewall_version_with_info(configuration: &configuration::Configuration, params: DeployActivateWafFirewallVersionParams) -> Result<crate::apis::Response<serde_json::Value>, Error<DeployActivateWafFirewallVersionError>> {
+    crate::apis::execute(configuration, deploy_activate_waf_firewall_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`deploy_activate_waf_firewall_version`] sends.
+pub(crate) fn deploy_activate_waf_firewall_version_request(params: DeployActivateWafFirewallVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/activate", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PUT" != "GET" && "PUT" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<DeployActivateWafFirewallVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}/activate", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
+    crate::apis::Request::new("deploy_activate_waf_firewall_version", reqwest::Method::PUT, local_var_path)
 }
 
 /// Get details about a specific firewall version.
-pub async fn get_waf_firewall_version(configuration: &mut configuration::Configuration, params: GetWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<GetWafFirewallVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_firewall_version(configuration: &configuration::Configuration, params: GetWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<GetWafFirewallVersionError>> {
+    get_waf_firewall_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_firewall_version`], but also returns the status and headers of the response.
+pub async fn get_waf_firewall_version_with_info(configuration: &configuration::Configuration, params: GetWafFirewallVersionParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionResponse>, Error<GetWafFirewallVersionError>> {
+    crate::apis::execute(configuration, get_waf_firewall_version_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_firewall_version`] sends.
+pub(crate) fn get_waf_firewall_version_request(params: GetWafFirewallVersionParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
+    let mut local_var_req = crate::apis::Request::new("get_waf_firewall_version", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafFirewallVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Get a list of firewall versions associated with a specific firewall.
-pub async fn list_waf_firewall_versions(configuration: &mut configuration::Configuration, params: ListWafFirewallVersionsParams) -> Result<crate::models::WafFirewallVersionsResponse, Error<ListWafFirewallVersionsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_waf_firewall_versions(configuration: &configuration::Configuration, params: ListWafFirewallVersionsParams) -> Result<crate::models::WafFirewallVersionsResponse, Error<ListWafFirewallVersionsError>> {
+    list_waf_firewall_versions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_waf_firewall_versions`], but also returns the status and headers of the response.
+pub async fn list_waf_firewall_versions_with_info(configuration: &configuration::Configuration, params: ListWafFirewallVersionsParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionsResponse>, Error<ListWafFirewallVersionsError>> {
+    crate::apis::execute(configuration, list_waf_firewall_versions_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_waf_firewall_versions`] sends.
+pub(crate) fn list_waf_firewall_versions_request(params: ListWafFirewallVersionsParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let include = params.include;
     let page_number = params.page_number;
     let page_size = params.page_size;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions", firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("list_waf_firewall_versions", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafFirewallVersionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a specific firewall version.
-pub async fn update_waf_firewall_version(configuration: &mut configuration::Configuration, params: UpdateWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<UpdateWafFirewallVersionError>> {
-    let local_var_configuration = configuration;
+pub async fn update_waf_firewall_version(configuration: &configuration::Configuration, params: UpdateWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<UpdateWafFirewallVersionError>> {
+    update_waf_firewall_version_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`update_waf_firewall_version`], but also returns the status and headers of the response.
+pub async fn update_waf_firewall_version_with_info(configuration: &configuration::Configuration, params: UpdateWafFirewallVersionParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionResponse>, Error<UpdateWafFirewallVersionError>> {
+    crate::apis::execute(configuration, update_waf_firewall_version_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`update_waf_firewall_version`] sends.
+pub(crate) fn update_waf_firewall_version_request(params: UpdateWafFirewallVersionParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let firewall_version_number = params.firewall_version_number;
     let waf_firewall_version = params.waf_firewall_version;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}/versions/{firewall_version_number}", firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
+    let mut local_var_req = crate::apis::Request::new("update_waf_firewall_version", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}/versions/{firewall_version_number}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id), firewall_version_number=firewall_version_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_firewall_version)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_firewall_version);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateWafFirewallVersionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
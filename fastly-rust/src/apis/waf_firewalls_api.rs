@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`create_waf_firewall`]
@@ -97,176 +96,94 @@ pub enum UpdateWafFirewallError {
 
 
 /// Create a firewall object for a particular service and service version using a defined `prefetch_condition` and `response`. If the `prefetch_condition` or the `response` is missing from the request body, Fastly will generate a default object on your service. 
-pub async fn create_waf_firewall(configuration: &mut configuration::Configuration, params: CreateWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<CreateWafFirewallError>> {
-    let local_var_configuration = configuration;
+pub async fn create_waf_firewall(configuration: &configuration::Configuration, params: CreateWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<CreateWafFirewallError>> {
+    create_waf_firewall_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`create_waf_firewall`], but also returns the status and headers of the response.
+pub async fn create_waf_firewall_with_info(configuration: &configuration::Configuration, params: CreateWafFirewallParams) -> Result<crate::apis::Response<crate::models::WafFirewallResponse>, Error<CreateWafFirewallError>> {
+    crate::apis::execute(configuration, create_waf_firewall_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`create_waf_firewall`] sends.
+pub(crate) fn create_waf_firewall_request(params: CreateWafFirewallParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let waf_firewall = params.waf_firewall;
 
+    let local_var_path = String::from("/waf/firewalls");
+    let mut local_var_req = crate::apis::Request::new("create_waf_firewall", reqwest::Method::POST, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_firewall);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "POST" != "GET" && "POST" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_firewall)?);
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateWafFirewallError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Delete the firewall object for a particular service and service version. 
-pub async fn delete_waf_firewall(configuration: &mut configuration::Configuration, params: DeleteWafFirewallParams) -> Result<(), Error<DeleteWafFirewallError>> {
-    let local_var_configuration = configuration;
+pub async fn delete_waf_firewall(configuration: &configuration::Configuration, params: DeleteWafFirewallParams) -> Result<(), Error<DeleteWafFirewallError>> {
+    delete_waf_firewall_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`delete_waf_firewall`], but also returns the status and headers of the response.
+pub async fn delete_waf_firewall_with_info(configuration: &configuration::Configuration, params: DeleteWafFirewallParams) -> Result<crate::apis::Response<()>, Error<DeleteWafFirewallError>> {
+    crate::apis::execute(configuration, delete_waf_firewall_request(params)?).await.map(crate::apis::RawResponse::empty)
+}
 
+/// The request [`delete_waf_firewall`] sends.
+pub(crate) fn delete_waf_firewall_request(params: DeleteWafFirewallParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let waf_firewall = params.waf_firewall;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}", firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("delete_waf_firewall", reqwest::Method::DELETE, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_firewall)?);
 
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_firewall);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "DELETE" != "GET" && "DELETE" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteWafFirewallError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
 /// Get a specific firewall object.
-pub async fn get_waf_firewall(configuration: &mut configuration::Configuration, params: GetWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<GetWafFirewallError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_firewall(configuration: &configuration::Configuration, params: GetWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<GetWafFirewallError>> {
+    get_waf_firewall_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_firewall`], but also returns the status and headers of the response.
+pub async fn get_waf_firewall_with_info(configuration: &configuration::Configuration, params: GetWafFirewallParams) -> Result<crate::apis::Response<crate::models::WafFirewallResponse>, Error<GetWafFirewallError>> {
+    crate::apis::execute(configuration, get_waf_firewall_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_firewall`] sends.
+pub(crate) fn get_waf_firewall_request(params: GetWafFirewallParams) -> crate::apis::Request {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let filter_service_version_number = params.filter_service_version_number;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/firewalls/{firewall_id}", firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("get_waf_firewall", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_service_version_number {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[service_version_number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[service_version_number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafFirewallError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all firewall objects.
-pub async fn list_waf_firewalls(configuration: &mut configuration::Configuration, params: ListWafFirewallsParams) -> Result<crate::models::WafFirewallsResponse, Error<ListWafFirewallsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_waf_firewalls(configuration: &configuration::Configuration, params: ListWafFirewallsParams) -> Result<crate::models::WafFirewallsResponse, Error<ListWafFirewallsError>> {
+    list_waf_firewalls_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`list_waf_firewalls`], but also returns the status and headers of the response.
+pub async fn list_waf_firewalls_with_info(configuration: &configuration::Configuration, params: ListWafFirewallsParams) -> Result<crate::apis::Response<crate::models::WafFirewallsResponse>, Error<ListWafFirewallsError>> {
+    crate::apis::execute(configuration, list_waf_firewalls_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`list_waf_firewalls`] sends.
+pub(crate) fn list_waf_firewalls_request(params: ListWafFirewallsParams) -> crate::apis::Request {
     // unbox the parameters
     let page_number = params.page_number;
     let page_size = params.page_size;
@@ -274,117 +191,49 @@ pub async fn list_waf_firewalls(configuration: &mut configuration::Configuration
     let filter_service_version_number = params.filter_service_version_number;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/waf/firewalls");
+    let mut local_var_req = crate::apis::Request::new("list_waf_firewalls", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_service_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[service_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[service_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_service_version_number {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[service_version_number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[service_version_number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafFirewallsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// Update a firewall object for a particular service and service version. Specifying a `service_version_number` is required. 
-pub async fn update_waf_firewall(configuration: &mut configuration::Configuration, params: UpdateWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<UpdateWafFirewallError>> {
-    let local_var_configuration = configuration;
+pub async fn update_waf_firewall(configuration: &configuration::Configuration, params: UpdateWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<UpdateWafFirewallError>> {
+    update_waf_firewall_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
 
+/// Like [`update_waf_firewall`], but also returns the status and headers of the response.
+pub async fn update_waf_firewall_with_info(configuration: &configuration::Configuration, params: UpdateWafFirewallParams) -> Result<crate::apis::Response<crate::models::WafFirewallResponse>, Error<UpdateWafFirewallError>> {
+    crate::apis::execute(configuration, update_waf_firewall_request(params)?).await.and_then(crate::apis::RawResponse::json)
+}
+
+/// The request [`update_waf_firewall`] sends.
+pub(crate) fn update_waf_firewall_request(params: UpdateWafFirewallParams) -> Result<crate::apis::Request, serde_json::Error> {
     // unbox the parameters
     let firewall_id = params.firewall_id;
     let waf_firewall = params.waf_firewall;
 
+    let local_var_path = format!("/waf/firewalls/{firewall_id}", firewall_id=crate::apis::urlencode(firewall_id));
+    let mut local_var_req = crate::apis::Request::new("update_waf_firewall", reqwest::Method::PATCH, local_var_path);
 
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/firewalls/{firewall_id}", local_var_configuration.base_path, firewall_id=crate::apis::urlencode(firewall_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PATCH, local_var_uri_str.as_str());
+    local_var_req = local_var_req.json(serde_json::to_value(&waf_firewall)?);
 
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-    local_var_req_builder = local_var_req_builder.json(&waf_firewall);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "PATCH" != "GET" && "PATCH" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
-    }
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<UpdateWafFirewallError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    Ok(local_var_req)
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_waf_rule_revision`]
@@ -52,123 +51,63 @@ pub enum ListWafRuleRevisionsError {
 
 
 /// Get a specific rule revision.
-pub async fn get_waf_rule_revision(configuration: &mut configuration::Configuration, params: GetWafRuleRevisionParams) -> Result<crate::models::WafRuleRevisionResponse, Error<GetWafRuleRevisionError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_rule_revision(configuration: &configuration::Configuration, params: GetWafRuleRevisionParams) -> Result<crate::models::WafRuleRevisionResponse, Error<GetWafRuleRevisionError>> {
+    get_waf_rule_revision_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_rule_revision`], but also returns the status and headers of the response.
+pub async fn get_waf_rule_revision_with_info(configuration: &configuration::Configuration, params: GetWafRuleRevisionParams) -> Result<crate::apis::Response<crate::models::WafRuleRevisionResponse>, Error<GetWafRuleRevisionError>> {
+    crate::apis::execute(configuration, get_waf_rule_revision_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_rule_revision`] sends.
+pub(crate) fn get_waf_rule_revision_request(params: GetWafRuleRevisionParams) -> crate::apis::Request {
     // unbox the parameters
     let waf_rule_id = params.waf_rule_id;
     let waf_rule_revision_number = params.waf_rule_revision_number;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/rules/{waf_rule_id}/revisions/{waf_rule_revision_number}", local_var_configuration.base_path, waf_rule_id=crate::apis::urlencode(waf_rule_id), waf_rule_revision_number=waf_rule_revision_number);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/rules/{waf_rule_id}/revisions/{waf_rule_revision_number}", waf_rule_id=crate::apis::urlencode(waf_rule_id), waf_rule_revision_number=waf_rule_revision_number);
+    let mut local_var_req = crate::apis::Request::new("get_waf_rule_revision", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafRuleRevisionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all revisions for a specific rule. The `rule_id` provided can be the ModSecurity Rule ID or the Fastly generated rule ID.
-pub async fn list_waf_rule_revisions(configuration: &mut configuration::Configuration, params: ListWafRuleRevisionsParams) -> Result<crate::models::WafRuleRevisionsResponse, Error<ListWafRuleRevisionsError>> {
-    let local_var_configuration = configuration;
+pub async fn list_waf_rule_revisions(configuration: &configuration::Configuration, params: ListWafRuleRevisionsParams) -> Result<crate::models::WafRuleRevisionsResponse, Error<ListWafRuleRevisionsError>> {
+    list_waf_rule_revisions_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_waf_rule_revisions`], but also returns the status and headers of the response.
+pub async fn list_waf_rule_revisions_with_info(configuration: &configuration::Configuration, params: ListWafRuleRevisionsParams) -> Result<crate::apis::Response<crate::models::WafRuleRevisionsResponse>, Error<ListWafRuleRevisionsError>> {
+    crate::apis::execute(configuration, list_waf_rule_revisions_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_waf_rule_revisions`] sends.
+pub(crate) fn list_waf_rule_revisions_request(params: ListWafRuleRevisionsParams) -> crate::apis::Request {
     // unbox the parameters
     let waf_rule_id = params.waf_rule_id;
     let page_number = params.page_number;
     let page_size = params.page_size;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/rules/{waf_rule_id}/revisions", local_var_configuration.base_path, waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/rules/{waf_rule_id}/revisions", waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    let mut local_var_req = crate::apis::Request::new("list_waf_rule_revisions", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafRuleRevisionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -8,7 +8,6 @@
 
 use reqwest;
 
-use crate::apis::ResponseContent;
 use super::{Error, configuration};
 
 /// struct for passing parameters to the method [`get_waf_rule`]
@@ -56,65 +55,43 @@ pub enum ListWafRulesError {
 
 
 /// Get a specific rule. The `id` provided can be the ModSecurity Rule ID or the Fastly generated rule ID.
-pub async fn get_waf_rule(configuration: &mut configuration::Configuration, params: GetWafRuleParams) -> Result<crate::models::WafRuleResponse, Error<GetWafRuleError>> {
-    let local_var_configuration = configuration;
+pub async fn get_waf_rule(configuration: &configuration::Configuration, params: GetWafRuleParams) -> Result<crate::models::WafRuleResponse, Error<GetWafRuleError>> {
+    get_waf_rule_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`get_waf_rule`], but also returns the status and headers of the response.
+pub async fn get_waf_rule_with_info(configuration: &configuration::Configuration, params: GetWafRuleParams) -> Result<crate::apis::Response<crate::models::WafRuleResponse>, Error<GetWafRuleError>> {
+    crate::apis::execute(configuration, get_waf_rule_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`get_waf_rule`] sends.
+pub(crate) fn get_waf_rule_request(params: GetWafRuleParams) -> crate::apis::Request {
     // unbox the parameters
     let waf_rule_id = params.waf_rule_id;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/rules/{waf_rule_id}", local_var_configuration.base_path, waf_rule_id=crate::apis::urlencode(waf_rule_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = format!("/waf/rules/{waf_rule_id}", waf_rule_id=crate::apis::urlencode(waf_rule_id));
+    let mut local_var_req = crate::apis::Request::new("get_waf_rule", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetWafRuleError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
 /// List all available WAF rules.
-pub async fn list_waf_rules(configuration: &mut configuration::Configuration, params: ListWafRulesParams) -> Result<crate::models::WafRulesResponse, Error<ListWafRulesError>> {
-    let local_var_configuration = configuration;
+pub async fn list_waf_rules(configuration: &configuration::Configuration, params: ListWafRulesParams) -> Result<crate::models::WafRulesResponse, Error<ListWafRulesError>> {
+    list_waf_rules_with_info(configuration, params).await.map(crate::apis::Response::into_entity)
+}
+
+/// Like [`list_waf_rules`], but also returns the status and headers of the response.
+pub async fn list_waf_rules_with_info(configuration: &configuration::Configuration, params: ListWafRulesParams) -> Result<crate::apis::Response<crate::models::WafRulesResponse>, Error<ListWafRulesError>> {
+    crate::apis::execute(configuration, list_waf_rules_request(params)).await.and_then(crate::apis::RawResponse::json)
+}
 
+/// The request [`list_waf_rules`] sends.
+pub(crate) fn list_waf_rules_request(params: ListWafRulesParams) -> crate::apis::Request {
     // unbox the parameters
     let filter_modsec_rule_id = params.filter_modsec_rule_id;
     let filter_waf_tags_name = params.filter_waf_tags_name;
@@ -124,69 +101,31 @@ pub async fn list_waf_rules(configuration: &mut configuration::Configuration, pa
     let page_size = params.page_size;
     let include = params.include;
 
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/waf/rules", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
+    let local_var_path = String::from("/waf/rules");
+    let mut local_var_req = crate::apis::Request::new("list_waf_rules", reqwest::Method::GET, local_var_path);
 
     if let Some(ref local_var_str) = filter_modsec_rule_id {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[modsec_rule_id]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[modsec_rule_id]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_waf_tags_name {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[waf_tags][name]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[waf_tags][name]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_waf_rule_revisions_source {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[waf_rule_revisions][source]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[waf_rule_revisions][source]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = filter_waf_firewall_id_not_match {
-        local_var_req_builder = local_var_req_builder.query(&[("filter[waf_firewall.id][not][match]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("filter[waf_firewall.id][not][match]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_number {
-        local_var_req_builder = local_var_req_builder.query(&[("page[number]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[number]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = page_size {
-        local_var_req_builder = local_var_req_builder.query(&[("page[size]", &local_var_str.to_string())]);
+        local_var_req = local_var_req.query("page[size]", local_var_str.to_string());
     }
     if let Some(ref local_var_str) = include {
-        local_var_req_builder = local_var_req_builder.query(&[("include", &local_var_str.to_string())]);
-    }
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    if let Some(ref local_var_apikey) = local_var_configuration.api_key {
-        let local_var_key = local_var_apikey.key.clone();
-        let local_var_value = match local_var_apikey.prefix {
-            Some(ref local_var_prefix) => format!("{} {}", local_var_prefix, local_var_key),
-            None => local_var_key,
-        };
-        local_var_req_builder = local_var_req_builder.header("Fastly-Key", local_var_value);
-    };
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    if "GET" != "GET" && "GET" != "HEAD" {
-      let headers = local_var_resp.headers();
-      local_var_configuration.rate_limit_remaining = match headers.get("Fastly-RateLimit-Remaining") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => configuration::DEFAULT_RATELIMIT,
-      };
-      local_var_configuration.rate_limit_reset = match headers.get("Fastly-RateLimit-Reset") {
-          Some(v) => v.to_str().unwrap().parse().unwrap(),
-          None => 0,
-      };
+        local_var_req = local_var_req.query("include", local_var_str.to_string());
     }
 
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<ListWafRulesError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+    local_var_req
 }
 
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{acl_api, Error};
+
+pub use crate::apis::acl_api::{CreateAclError, CreateAclParams, DeleteAclError, DeleteAclParams, GetAclError, GetAclParams, ListAclsError, ListAclsParams, UpdateAclError, UpdateAclParams};
+
+/// Create a new ACL attached to the specified service version. A new, empty ACL must be attached to a draft version of a service. The version associated with the ACL must be activated to be used.
+pub fn create_acl(configuration: &Configuration, params: CreateAclParams) -> Result<crate::models::AclResponse, Error<CreateAclError>> {
+    super::block_on(acl_api::create_acl(configuration, params))
+}
+
+/// Like [`create_acl`], but also returns the status and headers of the response.
+pub fn create_acl_with_info(configuration: &Configuration, params: CreateAclParams) -> Result<crate::apis::Response<crate::models::AclResponse>, Error<CreateAclError>> {
+    super::block_on(acl_api::create_acl_with_info(configuration, params))
+}
+
+/// Delete an ACL from the specified service version. To remove an ACL from use, the ACL must be deleted from a draft version and the version without the ACL must be activated.
+pub fn delete_acl(configuration: &Configuration, params: DeleteAclParams) -> Result<crate::models::InlineResponse200, Error<DeleteAclError>> {
+    super::block_on(acl_api::delete_acl(configuration, params))
+}
+
+/// Like [`delete_acl`], but also returns the status and headers of the response.
+pub fn delete_acl_with_info(configuration: &Configuration, params: DeleteAclParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteAclError>> {
+    super::block_on(acl_api::delete_acl_with_info(configuration, params))
+}
+
+/// Retrieve a single ACL by name for the version and service.
+pub fn get_acl(configuration: &Configuration, params: GetAclParams) -> Result<crate::models::AclResponse, Error<GetAclError>> {
+    super::block_on(acl_api::get_acl(configuration, params))
+}
+
+/// Like [`get_acl`], but also returns the status and headers of the response.
+pub fn get_acl_with_info(configuration: &Configuration, params: GetAclParams) -> Result<crate::apis::Response<crate::models::AclResponse>, Error<GetAclError>> {
+    super::block_on(acl_api::get_acl_with_info(configuration, params))
+}
+
+/// List ACLs.
+pub fn list_acls(configuration: &Configuration, params: ListAclsParams) -> Result<Vec<crate::models::AclResponse>, Error<ListAclsError>> {
+    super::block_on(acl_api::list_acls(configuration, params))
+}
+
+/// Like [`list_acls`], but also returns the status and headers of the response.
+pub fn list_acls_with_info(configuration: &Configuration, params: ListAclsParams) -> Result<crate::apis::Response<Vec<crate::models::AclResponse>>, Error<ListAclsError>> {
+    super::block_on(acl_api::list_acls_with_info(configuration, params))
+}
+
+/// Update an ACL for a particular service and version.
+pub fn update_acl(configuration: &Configuration, params: UpdateAclParams) -> Result<crate::models::AclResponse, Error<UpdateAclError>> {
+    super::block_on(acl_api::update_acl(configuration, params))
+}
+
+/// Like [`update_acl`], but also returns the status and headers of the response.
+pub fn update_acl_with_info(configuration: &Configuration, params: UpdateAclParams) -> Result<crate::apis::Response<crate::models::AclResponse>, Error<UpdateAclError>> {
+    super::block_on(acl_api::update_acl_with_info(configuration, params))
+}
@@ -0,0 +1,71 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{acl_entry_api, Error};
+
+pub use crate::apis::acl_entry_api::{BulkUpdateAclEntriesError, BulkUpdateAclEntriesParams, CreateAclEntryError, CreateAclEntryParams, DeleteAclEntryError, DeleteAclEntryParams, GetAclEntryError, GetAclEntryParams, ListAclEntriesError, ListAclEntriesParams, UpdateAclEntryError, UpdateAclEntryParams};
+
+/// Update multiple ACL entries on the same ACL. For faster updates to your service, group your changes into large batches. The maximum batch size is 1000 entries. [Contact support](https://support.fastly.com/) to discuss raising this limit.
+pub fn bulk_update_acl_entries(configuration: &Configuration, params: BulkUpdateAclEntriesParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateAclEntriesError>> {
+    super::block_on(acl_entry_api::bulk_update_acl_entries(configuration, params))
+}
+
+/// Like [`bulk_update_acl_entries`], but also returns the status and headers of the response.
+pub fn bulk_update_acl_entries_with_info(configuration: &Configuration, params: BulkUpdateAclEntriesParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<BulkUpdateAclEntriesError>> {
+    super::block_on(acl_entry_api::bulk_update_acl_entries_with_info(configuration, params))
+}
+
+/// Add an ACL entry to an ACL.
+pub fn create_acl_entry(configuration: &Configuration, params: CreateAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<CreateAclEntryError>> {
+    super::block_on(acl_entry_api::create_acl_entry(configuration, params))
+}
+
+/// Like [`create_acl_entry`], but also returns the status and headers of the response.
+pub fn create_acl_entry_with_info(configuration: &Configuration, params: CreateAclEntryParams) -> Result<crate::apis::Response<crate::models::AclEntryResponse>, Error<CreateAclEntryError>> {
+    super::block_on(acl_entry_api::create_acl_entry_with_info(configuration, params))
+}
+
+/// Delete an ACL entry from a specified ACL.
+pub fn delete_acl_entry(configuration: &Configuration, params: DeleteAclEntryParams) -> Result<crate::models::InlineResponse200, Error<DeleteAclEntryError>> {
+    super::block_on(acl_entry_api::delete_acl_entry(configuration, params))
+}
+
+/// Like [`delete_acl_entry`], but also returns the status and headers of the response.
+pub fn delete_acl_entry_with_info(configuration: &Configuration, params: DeleteAclEntryParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteAclEntryError>> {
+    super::block_on(acl_entry_api::delete_acl_entry_with_info(configuration, params))
+}
+
+/// Retrieve a single ACL entry.
+pub fn get_acl_entry(configuration: &Configuration, params: GetAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<GetAclEntryError>> {
+    super::block_on(acl_entry_api::get_acl_entry(configuration, params))
+}
+
+/// Like [`get_acl_entry`], but also returns the status and headers of the response.
+pub fn get_acl_entry_with_info(configuration: &Configuration, params: GetAclEntryParams) -> Result<crate::apis::Response<crate::models::AclEntryResponse>, Error<GetAclEntryError>> {
+    super::block_on(acl_entry_api::get_acl_entry_with_info(configuration, params))
+}
+
+/// List ACL entries for a specified ACL.
+pub fn list_acl_entries(configuration: &Configuration, params: ListAclEntriesParams) -> Result<Vec<crate::models::AclEntryResponse>, Error<ListAclEntriesError>> {
+    super::block_on(acl_entry_api::list_acl_entries(configuration, params))
+}
+
+/// Like [`list_acl_entries`], but also returns the status and headers of the response.
+pub fn list_acl_entries_with_info(configuration: &Configuration, params: ListAclEntriesParams) -> Result<crate::apis::Response<Vec<crate::models::AclEntryResponse>>, Error<ListAclEntriesError>> {
+    super::block_on(acl_entry_api::list_acl_entries_with_info(configuration, params))
+}
+
+/// Update an ACL entry for a specified ACL.
+pub fn update_acl_entry(configuration: &Configuration, params: UpdateAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<UpdateAclEntryError>> {
+    super::block_on(acl_entry_api::update_acl_entry(configuration, params))
+}
+
+/// Like [`update_acl_entry`], but also returns the status and headers of the response.
+pub fn update_acl_entry_with_info(configuration: &Configuration, params: UpdateAclEntryParams) -> Result<crate::apis::Response<crate::models::AclEntryResponse>, Error<UpdateAclEntryError>> {
+    super::block_on(acl_entry_api::update_acl_entry_with_info(configuration, params))
+}
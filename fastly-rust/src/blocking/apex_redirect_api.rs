@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{apex_redirect_api, Error};
+
+pub use crate::apis::apex_redirect_api::{CreateApexRedirectError, CreateApexRedirectParams, DeleteApexRedirectError, DeleteApexRedirectParams, GetApexRedirectError, GetApexRedirectParams, ListApexRedirectsError, ListApexRedirectsParams, UpdateApexRedirectError, UpdateApexRedirectParams};
+
+/// Create an apex redirect for a particular service and version.
+pub fn create_apex_redirect(configuration: &Configuration, params: CreateApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<CreateApexRedirectError>> {
+    super::block_on(apex_redirect_api::create_apex_redirect(configuration, params))
+}
+
+/// Like [`create_apex_redirect`], but also returns the status and headers of the response.
+pub fn create_apex_redirect_with_info(configuration: &Configuration, params: CreateApexRedirectParams) -> Result<crate::apis::Response<crate::models::ApexRedirect>, Error<CreateApexRedirectError>> {
+    super::block_on(apex_redirect_api::create_apex_redirect_with_info(configuration, params))
+}
+
+/// Delete an apex redirect by its ID.
+pub fn delete_apex_redirect(configuration: &Configuration, params: DeleteApexRedirectParams) -> Result<crate::models::InlineResponse200, Error<DeleteApexRedirectError>> {
+    super::block_on(apex_redirect_api::delete_apex_redirect(configuration, params))
+}
+
+/// Like [`delete_apex_redirect`], but also returns the status and headers of the response.
+pub fn delete_apex_redirect_with_info(configuration: &Configuration, params: DeleteApexRedirectParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteApexRedirectError>> {
+    super::block_on(apex_redirect_api::delete_apex_redirect_with_info(configuration, params))
+}
+
+/// Get an apex redirect by its ID.
+pub fn get_apex_redirect(configuration: &Configuration, params: GetApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<GetApexRedirectError>> {
+    super::block_on(apex_redirect_api::get_apex_redirect(configuration, params))
+}
+
+/// Like [`get_apex_redirect`], but also returns the status and headers of the response.
+pub fn get_apex_redirect_with_info(configuration: &Configuration, params: GetApexRedirectParams) -> Result<crate::apis::Response<crate::models::ApexRedirect>, Error<GetApexRedirectError>> {
+    super::block_on(apex_redirect_api::get_apex_redirect_with_info(configuration, params))
+}
+
+/// List all apex redirects for a particular service and version.
+pub fn list_apex_redirects(configuration: &Configuration, params: ListApexRedirectsParams) -> Result<Vec<crate::models::ApexRedirect>, Error<ListApexRedirectsError>> {
+    super::block_on(apex_redirect_api::list_apex_redirects(configuration, params))
+}
+
+/// Like [`list_apex_redirects`], but also returns the status and headers of the response.
+pub fn list_apex_redirects_with_info(configuration: &Configuration, params: ListApexRedirectsParams) -> Result<crate::apis::Response<Vec<crate::models::ApexRedirect>>, Error<ListApexRedirectsError>> {
+    super::block_on(apex_redirect_api::list_apex_redirects_with_info(configuration, params))
+}
+
+/// Update an apex redirect by its ID.
+pub fn update_apex_redirect(configuration: &Configuration, params: UpdateApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<UpdateApexRedirectError>> {
+    super::block_on(apex_redirect_api::update_apex_redirect(configuration, params))
+}
+
+/// Like [`update_apex_redirect`], but also returns the status and headers of the response.
+pub fn update_apex_redirect_with_info(configuration: &Configuration, params: UpdateApexRedirectParams) -> Result<crate::apis::Response<crate::models::ApexRedirect>, Error<UpdateApexRedirectError>> {
+    super::block_on(apex_redirect_api::update_apex_redirect_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{automation_tokens_api, Error};
+
+pub use crate::apis::automation_tokens_api::{CreateAutomationTokenError, CreateAutomationTokenParams, GetAutomationTokenIdError, GetAutomationTokenIdParams, GetAutomationTokensIdServicesError, GetAutomationTokensIdServicesParams, ListAutomationTokensError, ListAutomationTokensParams, RevokeAutomationTokenIdError, RevokeAutomationTokenIdParams};
+
+/// Creates a new automation token.
+pub fn create_automation_token(configuration: &Configuration, params: CreateAutomationTokenParams) -> Result<crate::models::AutomationTokenCreateResponse, Error<CreateAutomationTokenError>> {
+    super::block_on(automation_tokens_api::create_automation_token(configuration, params))
+}
+
+/// Like [`create_automation_token`], but also returns the status and headers of the response.
+pub fn create_automation_token_with_info(configuration: &Configuration, params: CreateAutomationTokenParams) -> Result<crate::apis::Response<crate::models::AutomationTokenCreateResponse>, Error<CreateAutomationTokenError>> {
+    super::block_on(automation_tokens_api::create_automation_token_with_info(configuration, params))
+}
+
+/// Retrieves an automation token by ID.
+pub fn get_automation_token_id(configuration: &Configuration, params: GetAutomationTokenIdParams) -> Result<crate::models::AutomationTokenResponse, Error<GetAutomationTokenIdError>> {
+    super::block_on(automation_tokens_api::get_automation_token_id(configuration, params))
+}
+
+/// Like [`get_automation_token_id`], but also returns the status and headers of the response.
+pub fn get_automation_token_id_with_info(configuration: &Configuration, params: GetAutomationTokenIdParams) -> Result<crate::apis::Response<crate::models::AutomationTokenResponse>, Error<GetAutomationTokenIdError>> {
+    super::block_on(automation_tokens_api::get_automation_token_id_with_info(configuration, params))
+}
+
+/// List of services associated with the automation token.
+pub fn get_automation_tokens_id_services(configuration: &Configuration, params: GetAutomationTokensIdServicesParams) -> Result<crate::models::InlineResponse2001, Error<GetAutomationTokensIdServicesError>> {
+    super::block_on(automation_tokens_api::get_automation_tokens_id_services(configuration, params))
+}
+
+/// Like [`get_automation_tokens_id_services`], but also returns the status and headers of the response.
+pub fn get_automation_tokens_id_services_with_info(configuration: &Configuration, params: GetAutomationTokensIdServicesParams) -> Result<crate::apis::Response<crate::models::InlineResponse2001>, Error<GetAutomationTokensIdServicesError>> {
+    super::block_on(automation_tokens_api::get_automation_tokens_id_services_with_info(configuration, params))
+}
+
+/// Lists all automation tokens for a customer.
+pub fn list_automation_tokens(configuration: &Configuration, params: ListAutomationTokensParams) -> Result<Vec<crate::models::AutomationTokenResponse>, Error<ListAutomationTokensError>> {
+    super::block_on(automation_tokens_api::list_automation_tokens(configuration, params))
+}
+
+/// Like [`list_automation_tokens`], but also returns the status and headers of the response.
+pub fn list_automation_tokens_with_info(configuration: &Configuration, params: ListAutomationTokensParams) -> Result<crate::apis::Response<Vec<crate::models::AutomationTokenResponse>>, Error<ListAutomationTokensError>> {
+    super::block_on(automation_tokens_api::list_automation_tokens_with_info(configuration, params))
+}
+
+/// Revoke an automation token by ID.
+pub fn revoke_automation_token_id(configuration: &Configuration, params: RevokeAutomationTokenIdParams) -> Result<crate::models::AutomationTokenErrorResponse, Error<RevokeAutomationTokenIdError>> {
+    super::block_on(automation_tokens_api::revoke_automation_token_id(configuration, params))
+}
+
+/// Like [`revoke_automation_token_id`], but also returns the status and headers of the response.
+pub fn revoke_automation_token_id_with_info(configuration: &Configuration, params: RevokeAutomationTokenIdParams) -> Result<crate::apis::Response<crate::models::AutomationTokenErrorResponse>, Error<RevokeAutomationTokenIdError>> {
+    super::block_on(automation_tokens_api::revoke_automation_token_id_with_info(configuration, params))
+}
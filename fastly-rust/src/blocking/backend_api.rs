@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{backend_api, Error};
+
+pub use crate::apis::backend_api::{CreateBackendError, CreateBackendParams, DeleteBackendError, DeleteBackendParams, GetBackendError, GetBackendParams, ListBackendsError, ListBackendsParams, UpdateBackendError, UpdateBackendParams};
+
+/// Create a backend for a particular service and version.
+pub fn create_backend(configuration: &Configuration, params: CreateBackendParams) -> Result<crate::models::BackendResponse, Error<CreateBackendError>> {
+    super::block_on(backend_api::create_backend(configuration, params))
+}
+
+/// Like [`create_backend`], but also returns the status and headers of the response.
+pub fn create_backend_with_info(configuration: &Configuration, params: CreateBackendParams) -> Result<crate::apis::Response<crate::models::BackendResponse>, Error<CreateBackendError>> {
+    super::block_on(backend_api::create_backend_with_info(configuration, params))
+}
+
+/// Delete the backend for a particular service and version.
+pub fn delete_backend(configuration: &Configuration, params: DeleteBackendParams) -> Result<crate::models::InlineResponse200, Error<DeleteBackendError>> {
+    super::block_on(backend_api::delete_backend(configuration, params))
+}
+
+/// Like [`delete_backend`], but also returns the status and headers of the response.
+pub fn delete_backend_with_info(configuration: &Configuration, params: DeleteBackendParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteBackendError>> {
+    super::block_on(backend_api::delete_backend_with_info(configuration, params))
+}
+
+/// Get the backend for a particular service and version.
+pub fn get_backend(configuration: &Configuration, params: GetBackendParams) -> Result<crate::models::BackendResponse, Error<GetBackendError>> {
+    super::block_on(backend_api::get_backend(configuration, params))
+}
+
+/// Like [`get_backend`], but also returns the status and headers of the response.
+pub fn get_backend_with_info(configuration: &Configuration, params: GetBackendParams) -> Result<crate::apis::Response<crate::models::BackendResponse>, Error<GetBackendError>> {
+    super::block_on(backend_api::get_backend_with_info(configuration, params))
+}
+
+/// List all backends for a particular service and version.
+pub fn list_backends(configuration: &Configuration, params: ListBackendsParams) -> Result<Vec<crate::models::BackendResponse>, Error<ListBackendsError>> {
+    super::block_on(backend_api::list_backends(configuration, params))
+}
+
+/// Like [`list_backends`], but also returns the status and headers of the response.
+pub fn list_backends_with_info(configuration: &Configuration, params: ListBackendsParams) -> Result<crate::apis::Response<Vec<crate::models::BackendResponse>>, Error<ListBackendsError>> {
+    super::block_on(backend_api::list_backends_with_info(configuration, params))
+}
+
+/// Update the backend for a particular service and version.
+pub fn update_backend(configuration: &Configuration, params: UpdateBackendParams) -> Result<crate::models::BackendResponse, Error<UpdateBackendError>> {
+    super::block_on(backend_api::update_backend(configuration, params))
+}
+
+/// Like [`update_backend`], but also returns the status and headers of the response.
+pub fn update_backend_with_info(configuration: &Configuration, params: UpdateBackendParams) -> Result<crate::apis::Response<crate::models::BackendResponse>, Error<UpdateBackendError>> {
+    super::block_on(backend_api::update_backend_with_info(configuration, params))
+}
@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{billing_address_api, Error};
+
+pub use crate::apis::billing_address_api::{AddBillingAddrError, AddBillingAddrParams, DeleteBillingAddrError, DeleteBillingAddrParams, GetBillingAddrError, GetBillingAddrParams, UpdateBillingAddrError, UpdateBillingAddrParams};
+
+/// Add a billing address to a customer.
+pub fn add_billing_addr(configuration: &Configuration, params: AddBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<AddBillingAddrError>> {
+    super::block_on(billing_address_api::add_billing_addr(configuration, params))
+}
+
+/// Like [`add_billing_addr`], but also returns the status and headers of the response.
+pub fn add_billing_addr_with_info(configuration: &Configuration, params: AddBillingAddrParams) -> Result<crate::apis::Response<crate::models::BillingAddressResponse>, Error<AddBillingAddrError>> {
+    super::block_on(billing_address_api::add_billing_addr_with_info(configuration, params))
+}
+
+/// Delete a customer's billing address.
+pub fn delete_billing_addr(configuration: &Configuration, params: DeleteBillingAddrParams) -> Result<(), Error<DeleteBillingAddrError>> {
+    super::block_on(billing_address_api::delete_billing_addr(configuration, params))
+}
+
+/// Like [`delete_billing_addr`], but also returns the status and headers of the response.
+pub fn delete_billing_addr_with_info(configuration: &Configuration, params: DeleteBillingAddrParams) -> Result<crate::apis::Response<()>, Error<DeleteBillingAddrError>> {
+    super::block_on(billing_address_api::delete_billing_addr_with_info(configuration, params))
+}
+
+/// Get a customer's billing address.
+pub fn get_billing_addr(configuration: &Configuration, params: GetBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<GetBillingAddrError>> {
+    super::block_on(billing_address_api::get_billing_addr(configuration, params))
+}
+
+/// Like [`get_billing_addr`], but also returns the status and headers of the response.
+pub fn get_billing_addr_with_info(configuration: &Configuration, params: GetBillingAddrParams) -> Result<crate::apis::Response<crate::models::BillingAddressResponse>, Error<GetBillingAddrError>> {
+    super::block_on(billing_address_api::get_billing_addr_with_info(configuration, params))
+}
+
+/// Update a customer's billing address. You may update only part of the customer's billing address.
+pub fn update_billing_addr(configuration: &Configuration, params: UpdateBillingAddrParams) -> Result<crate::models::BillingAddressResponse, Error<UpdateBillingAddrError>> {
+    super::block_on(billing_address_api::update_billing_addr(configuration, params))
+}
+
+/// Like [`update_billing_addr`], but also returns the status and headers of the response.
+pub fn update_billing_addr_with_info(configuration: &Configuration, params: UpdateBillingAddrParams) -> Result<crate::apis::Response<crate::models::BillingAddressResponse>, Error<UpdateBillingAddrError>> {
+    super::block_on(billing_address_api::update_billing_addr_with_info(configuration, params))
+}
@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{billing_api, Error};
+
+pub use crate::apis::billing_api::{GetInvoiceByIdError, GetInvoiceByIdParams, GetInvoiceError, GetInvoiceMtdError, GetInvoiceMtdParams, GetInvoiceParams};
+
+/// Get the invoice for a given year and month. Can be any month from when the Customer was created to the current month.
+pub fn get_invoice(configuration: &Configuration, params: GetInvoiceParams) -> Result<crate::models::BillingResponse, Error<GetInvoiceError>> {
+    super::block_on(billing_api::get_invoice(configuration, params))
+}
+
+/// Like [`get_invoice`], but also returns the status and headers of the response.
+pub fn get_invoice_with_info(configuration: &Configuration, params: GetInvoiceParams) -> Result<crate::apis::Response<crate::models::BillingResponse>, Error<GetInvoiceError>> {
+    super::block_on(billing_api::get_invoice_with_info(configuration, params))
+}
+
+/// Get the invoice for the given invoice_id.
+pub fn get_invoice_by_id(configuration: &Configuration, params: GetInvoiceByIdParams) -> Result<crate::models::BillingResponse, Error<GetInvoiceByIdError>> {
+    super::block_on(billing_api::get_invoice_by_id(configuration, params))
+}
+
+/// Like [`get_invoice_by_id`], but also returns the status and headers of the response.
+pub fn get_invoice_by_id_with_info(configuration: &Configuration, params: GetInvoiceByIdParams) -> Result<crate::apis::Response<crate::models::BillingResponse>, Error<GetInvoiceByIdError>> {
+    super::block_on(billing_api::get_invoice_by_id_with_info(configuration, params))
+}
+
+/// Get the current month-to-date estimate. This endpoint has two different responses. Under normal circumstances, it generally takes less than 5 seconds to generate but in certain cases can take up to 60 seconds. Once generated the month-to-date estimate is cached for 4 hours, and is available the next request will return the JSON representation of the month-to-date estimate. While a report is being generated in the background, this endpoint will return a `202 Accepted` response. The full format of which can be found in detail in our [billing calculation guide](https://docs.fastly.com/en/guides/how-we-calculate-your-bill). There are certain accounts for which we are unable to generate a month-to-date estimate. For example, accounts who have parent-pay are unable to generate an MTD estimate. The parent accounts are able to generate a month-to-date estimate but that estimate will not include the child accounts amounts at this time.
+pub fn get_invoice_mtd(configuration: &Configuration, params: GetInvoiceMtdParams) -> Result<crate::models::BillingEstimateResponse, Error<GetInvoiceMtdError>> {
+    super::block_on(billing_api::get_invoice_mtd(configuration, params))
+}
+
+/// Like [`get_invoice_mtd`], but also returns the status and headers of the response.
+pub fn get_invoice_mtd_with_info(configuration: &Configuration, params: GetInvoiceMtdParams) -> Result<crate::apis::Response<crate::models::BillingEstimateResponse>, Error<GetInvoiceMtdError>> {
+    super::block_on(billing_api::get_invoice_mtd_with_info(configuration, params))
+}
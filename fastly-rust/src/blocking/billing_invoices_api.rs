@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{billing_invoices_api, Error};
+
+pub use crate::apis::billing_invoices_api::{GetInvoiceByInvoiceIdError, GetInvoiceByInvoiceIdParams, GetMonthToDateInvoiceError, ListInvoicesError, ListInvoicesParams};
+
+/// Returns invoice associated with the invoice id.
+pub fn get_invoice_by_invoice_id(configuration: &Configuration, params: GetInvoiceByInvoiceIdParams) -> Result<crate::models::EomInvoiceResponse, Error<GetInvoiceByInvoiceIdError>> {
+    super::block_on(billing_invoices_api::get_invoice_by_invoice_id(configuration, params))
+}
+
+/// Like [`get_invoice_by_invoice_id`], but also returns the status and headers of the response.
+pub fn get_invoice_by_invoice_id_with_info(configuration: &Configuration, params: GetInvoiceByInvoiceIdParams) -> Result<crate::apis::Response<crate::models::EomInvoiceResponse>, Error<GetInvoiceByInvoiceIdError>> {
+    super::block_on(billing_invoices_api::get_invoice_by_invoice_id_with_info(configuration, params))
+}
+
+/// Returns month-to-date invoice for the current month.
+pub fn get_month_to_date_invoice(configuration: &Configuration) -> Result<crate::models::MtdInvoiceResponse, Error<GetMonthToDateInvoiceError>> {
+    super::block_on(billing_invoices_api::get_month_to_date_invoice(configuration))
+}
+
+/// Like [`get_month_to_date_invoice`], but also returns the status and headers of the response.
+pub fn get_month_to_date_invoice_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::MtdInvoiceResponse>, Error<GetMonthToDateInvoiceError>> {
+    super::block_on(billing_invoices_api::get_month_to_date_invoice_with_info(configuration))
+}
+
+/// Returns the list of invoices, sorted by billing start date (newest to oldest).
+pub fn list_invoices(configuration: &Configuration, params: ListInvoicesParams) -> Result<crate::models::ListEomInvoicesResponse, Error<ListInvoicesError>> {
+    super::block_on(billing_invoices_api::list_invoices(configuration, params))
+}
+
+/// Like [`list_invoices`], but also returns the status and headers of the response.
+pub fn list_invoices_with_info(configuration: &Configuration, params: ListInvoicesParams) -> Result<crate::apis::Response<crate::models::ListEomInvoicesResponse>, Error<ListInvoicesError>> {
+    super::block_on(billing_invoices_api::list_invoices_with_info(configuration, params))
+}
@@ -0,0 +1,31 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{billing_usage_metrics_api, Error};
+
+pub use crate::apis::billing_usage_metrics_api::{GetServiceLevelUsageError, GetServiceLevelUsageParams, GetServiceLevelUsageTypesError, GetServiceLevelUsageTypesParams};
+
+/// Returns product usage, broken down by service.
+pub fn get_service_level_usage(configuration: &Configuration, params: GetServiceLevelUsageParams) -> Result<crate::models::Serviceusagemetrics, Error<GetServiceLevelUsageError>> {
+    super::block_on(billing_usage_metrics_api::get_service_level_usage(configuration, params))
+}
+
+/// Like [`get_service_level_usage`], but also returns the status and headers of the response.
+pub fn get_service_level_usage_with_info(configuration: &Configuration, params: GetServiceLevelUsageParams) -> Result<crate::apis::Response<crate::models::Serviceusagemetrics>, Error<GetServiceLevelUsageError>> {
+    super::block_on(billing_usage_metrics_api::get_service_level_usage_with_info(configuration, params))
+}
+
+/// Returns product usage types reported by the customer's services.
+pub fn get_service_level_usage_types(configuration: &Configuration, params: GetServiceLevelUsageTypesParams) -> Result<crate::models::Serviceusagetypes, Error<GetServiceLevelUsageTypesError>> {
+    super::block_on(billing_usage_metrics_api::get_service_level_usage_types(configuration, params))
+}
+
+/// Like [`get_service_level_usage_types`], but also returns the status and headers of the response.
+pub fn get_service_level_usage_types_with_info(configuration: &Configuration, params: GetServiceLevelUsageTypesParams) -> Result<crate::apis::Response<crate::models::Serviceusagetypes>, Error<GetServiceLevelUsageTypesError>> {
+    super::block_on(billing_usage_metrics_api::get_service_level_usage_types_with_info(configuration, params))
+}
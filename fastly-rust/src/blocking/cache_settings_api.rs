@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{cache_settings_api, Error};
+
+pub use crate::apis::cache_settings_api::{CreateCacheSettingsError, CreateCacheSettingsParams, DeleteCacheSettingsError, DeleteCacheSettingsParams, GetCacheSettingsError, GetCacheSettingsParams, ListCacheSettingsError, ListCacheSettingsParams, UpdateCacheSettingsError, UpdateCacheSettingsParams};
+
+/// Create a cache settings object.
+pub fn create_cache_settings(configuration: &Configuration, params: CreateCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<CreateCacheSettingsError>> {
+    super::block_on(cache_settings_api::create_cache_settings(configuration, params))
+}
+
+/// Like [`create_cache_settings`], but also returns the status and headers of the response.
+pub fn create_cache_settings_with_info(configuration: &Configuration, params: CreateCacheSettingsParams) -> Result<crate::apis::Response<crate::models::CacheSettingResponse>, Error<CreateCacheSettingsError>> {
+    super::block_on(cache_settings_api::create_cache_settings_with_info(configuration, params))
+}
+
+/// Delete a specific cache settings object.
+pub fn delete_cache_settings(configuration: &Configuration, params: DeleteCacheSettingsParams) -> Result<crate::models::InlineResponse200, Error<DeleteCacheSettingsError>> {
+    super::block_on(cache_settings_api::delete_cache_settings(configuration, params))
+}
+
+/// Like [`delete_cache_settings`], but also returns the status and headers of the response.
+pub fn delete_cache_settings_with_info(configuration: &Configuration, params: DeleteCacheSettingsParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteCacheSettingsError>> {
+    super::block_on(cache_settings_api::delete_cache_settings_with_info(configuration, params))
+}
+
+/// Get a specific cache settings object.
+pub fn get_cache_settings(configuration: &Configuration, params: GetCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<GetCacheSettingsError>> {
+    super::block_on(cache_settings_api::get_cache_settings(configuration, params))
+}
+
+/// Like [`get_cache_settings`], but also returns the status and headers of the response.
+pub fn get_cache_settings_with_info(configuration: &Configuration, params: GetCacheSettingsParams) -> Result<crate::apis::Response<crate::models::CacheSettingResponse>, Error<GetCacheSettingsError>> {
+    super::block_on(cache_settings_api::get_cache_settings_with_info(configuration, params))
+}
+
+/// Get a list of all cache settings for a particular service and version.
+pub fn list_cache_settings(configuration: &Configuration, params: ListCacheSettingsParams) -> Result<Vec<crate::models::CacheSettingResponse>, Error<ListCacheSettingsError>> {
+    super::block_on(cache_settings_api::list_cache_settings(configuration, params))
+}
+
+/// Like [`list_cache_settings`], but also returns the status and headers of the response.
+pub fn list_cache_settings_with_info(configuration: &Configuration, params: ListCacheSettingsParams) -> Result<crate::apis::Response<Vec<crate::models::CacheSettingResponse>>, Error<ListCacheSettingsError>> {
+    super::block_on(cache_settings_api::list_cache_settings_with_info(configuration, params))
+}
+
+/// Update a specific cache settings object.
+pub fn update_cache_settings(configuration: &Configuration, params: UpdateCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<UpdateCacheSettingsError>> {
+    super::block_on(cache_settings_api::update_cache_settings(configuration, params))
+}
+
+/// Like [`update_cache_settings`], but also returns the status and headers of the response.
+pub fn update_cache_settings_with_info(configuration: &Configuration, params: UpdateCacheSettingsParams) -> Result<crate::apis::Response<crate::models::CacheSettingResponse>, Error<UpdateCacheSettingsError>> {
+    super::block_on(cache_settings_api::update_cache_settings_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{condition_api, Error};
+
+pub use crate::apis::condition_api::{CreateConditionError, CreateConditionParams, DeleteConditionError, DeleteConditionParams, GetConditionError, GetConditionParams, ListConditionsError, ListConditionsParams, UpdateConditionError, UpdateConditionParams};
+
+/// Creates a new condition.
+pub fn create_condition(configuration: &Configuration, params: CreateConditionParams) -> Result<crate::models::ConditionResponse, Error<CreateConditionError>> {
+    super::block_on(condition_api::create_condition(configuration, params))
+}
+
+/// Like [`create_condition`], but also returns the status and headers of the response.
+pub fn create_condition_with_info(configuration: &Configuration, params: CreateConditionParams) -> Result<crate::apis::Response<crate::models::ConditionResponse>, Error<CreateConditionError>> {
+    super::block_on(condition_api::create_condition_with_info(configuration, params))
+}
+
+/// Deletes the specified condition.
+pub fn delete_condition(configuration: &Configuration, params: DeleteConditionParams) -> Result<crate::models::InlineResponse200, Error<DeleteConditionError>> {
+    super::block_on(condition_api::delete_condition(configuration, params))
+}
+
+/// Like [`delete_condition`], but also returns the status and headers of the response.
+pub fn delete_condition_with_info(configuration: &Configuration, params: DeleteConditionParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteConditionError>> {
+    super::block_on(condition_api::delete_condition_with_info(configuration, params))
+}
+
+/// Gets the specified condition.
+pub fn get_condition(configuration: &Configuration, params: GetConditionParams) -> Result<crate::models::ConditionResponse, Error<GetConditionError>> {
+    super::block_on(condition_api::get_condition(configuration, params))
+}
+
+/// Like [`get_condition`], but also returns the status and headers of the response.
+pub fn get_condition_with_info(configuration: &Configuration, params: GetConditionParams) -> Result<crate::apis::Response<crate::models::ConditionResponse>, Error<GetConditionError>> {
+    super::block_on(condition_api::get_condition_with_info(configuration, params))
+}
+
+/// Gets all conditions for a particular service and version.
+pub fn list_conditions(configuration: &Configuration, params: ListConditionsParams) -> Result<Vec<crate::models::ConditionResponse>, Error<ListConditionsError>> {
+    super::block_on(condition_api::list_conditions(configuration, params))
+}
+
+/// Like [`list_conditions`], but also returns the status and headers of the response.
+pub fn list_conditions_with_info(configuration: &Configuration, params: ListConditionsParams) -> Result<crate::apis::Response<Vec<crate::models::ConditionResponse>>, Error<ListConditionsError>> {
+    super::block_on(condition_api::list_conditions_with_info(configuration, params))
+}
+
+/// Updates the specified condition.
+pub fn update_condition(configuration: &Configuration, params: UpdateConditionParams) -> Result<crate::models::ConditionResponse, Error<UpdateConditionError>> {
+    super::block_on(condition_api::update_condition(configuration, params))
+}
+
+/// Like [`update_condition`], but also returns the status and headers of the response.
+pub fn update_condition_with_info(configuration: &Configuration, params: UpdateConditionParams) -> Result<crate::apis::Response<crate::models::ConditionResponse>, Error<UpdateConditionError>> {
+    super::block_on(condition_api::update_condition_with_info(configuration, params))
+}
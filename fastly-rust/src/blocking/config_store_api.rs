@@ -0,0 +1,81 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{config_store_api, Error};
+
+pub use crate::apis::config_store_api::{CreateConfigStoreError, CreateConfigStoreParams, DeleteConfigStoreError, DeleteConfigStoreParams, GetConfigStoreError, GetConfigStoreInfoError, GetConfigStoreInfoParams, GetConfigStoreParams, ListConfigStoreServicesError, ListConfigStoreServicesParams, ListConfigStoresError, ListConfigStoresParams, UpdateConfigStoreError, UpdateConfigStoreParams};
+
+/// Create a config store.
+pub fn create_config_store(configuration: &Configuration, params: CreateConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<CreateConfigStoreError>> {
+    super::block_on(config_store_api::create_config_store(configuration, params))
+}
+
+/// Like [`create_config_store`], but also returns the status and headers of the response.
+pub fn create_config_store_with_info(configuration: &Configuration, params: CreateConfigStoreParams) -> Result<crate::apis::Response<crate::models::ConfigStoreResponse>, Error<CreateConfigStoreError>> {
+    super::block_on(config_store_api::create_config_store_with_info(configuration, params))
+}
+
+/// Delete a config store.
+pub fn delete_config_store(configuration: &Configuration, params: DeleteConfigStoreParams) -> Result<crate::models::InlineResponse200, Error<DeleteConfigStoreError>> {
+    super::block_on(config_store_api::delete_config_store(configuration, params))
+}
+
+/// Like [`delete_config_store`], but also returns the status and headers of the response.
+pub fn delete_config_store_with_info(configuration: &Configuration, params: DeleteConfigStoreParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteConfigStoreError>> {
+    super::block_on(config_store_api::delete_config_store_with_info(configuration, params))
+}
+
+/// Describe a config store by its identifier.
+pub fn get_config_store(configuration: &Configuration, params: GetConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<GetConfigStoreError>> {
+    super::block_on(config_store_api::get_config_store(configuration, params))
+}
+
+/// Like [`get_config_store`], but also returns the status and headers of the response.
+pub fn get_config_store_with_info(configuration: &Configuration, params: GetConfigStoreParams) -> Result<crate::apis::Response<crate::models::ConfigStoreResponse>, Error<GetConfigStoreError>> {
+    super::block_on(config_store_api::get_config_store_with_info(configuration, params))
+}
+
+/// Retrieve metadata for a single config store.
+pub fn get_config_store_info(configuration: &Configuration, params: GetConfigStoreInfoParams) -> Result<crate::models::ConfigStoreInfoResponse, Error<GetConfigStoreInfoError>> {
+    super::block_on(config_store_api::get_config_store_info(configuration, params))
+}
+
+/// Like [`get_config_store_info`], but also returns the status and headers of the response.
+pub fn get_config_store_info_with_info(configuration: &Configuration, params: GetConfigStoreInfoParams) -> Result<crate::apis::Response<crate::models::ConfigStoreInfoResponse>, Error<GetConfigStoreInfoError>> {
+    super::block_on(config_store_api::get_config_store_info_with_info(configuration, params))
+}
+
+/// List services linked to a config store
+pub fn list_config_store_services(configuration: &Configuration, params: ListConfigStoreServicesParams) -> Result<serde_json::Value, Error<ListConfigStoreServicesError>> {
+    super::block_on(config_store_api::list_config_store_services(configuration, params))
+}
+
+/// Like [`list_config_store_services`], but also returns the status and headers of the response.
+pub fn list_config_store_services_with_info(configuration: &Configuration, params: ListConfigStoreServicesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListConfigStoreServicesError>> {
+    super::block_on(config_store_api::list_config_store_services_with_info(configuration, params))
+}
+
+/// List config stores.
+pub fn list_config_stores(configuration: &Configuration, params: ListConfigStoresParams) -> Result<Vec<crate::models::ConfigStoreResponse>, Error<ListConfigStoresError>> {
+    super::block_on(config_store_api::list_config_stores(configuration, params))
+}
+
+/// Like [`list_config_stores`], but also returns the status and headers of the response.
+pub fn list_config_stores_with_info(configuration: &Configuration, params: ListConfigStoresParams) -> Result<crate::apis::Response<Vec<crate::models::ConfigStoreResponse>>, Error<ListConfigStoresError>> {
+    super::block_on(config_store_api::list_config_stores_with_info(configuration, params))
+}
+
+/// Update a config store.
+pub fn update_config_store(configuration: &Configuration, params: UpdateConfigStoreParams) -> Result<crate::models::ConfigStoreResponse, Error<UpdateConfigStoreError>> {
+    super::block_on(config_store_api::update_config_store(configuration, params))
+}
+
+/// Like [`update_config_store`], but also returns the status and headers of the response.
+pub fn update_config_store_with_info(configuration: &Configuration, params: UpdateConfigStoreParams) -> Result<crate::apis::Response<crate::models::ConfigStoreResponse>, Error<UpdateConfigStoreError>> {
+    super::block_on(config_store_api::update_config_store_with_info(configuration, params))
+}
@@ -0,0 +1,81 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{config_store_item_api, Error};
+
+pub use crate::apis::config_store_item_api::{BulkUpdateConfigStoreItemError, BulkUpdateConfigStoreItemParams, CreateConfigStoreItemError, CreateConfigStoreItemParams, DeleteConfigStoreItemError, DeleteConfigStoreItemParams, GetConfigStoreItemError, GetConfigStoreItemParams, ListConfigStoreItemsError, ListConfigStoreItemsParams, UpdateConfigStoreItemError, UpdateConfigStoreItemParams, UpsertConfigStoreItemError, UpsertConfigStoreItemParams};
+
+/// Add multiple key-value pairs to an individual config store, specified by ID.
+pub fn bulk_update_config_store_item(configuration: &Configuration, params: BulkUpdateConfigStoreItemParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateConfigStoreItemError>> {
+    super::block_on(config_store_item_api::bulk_update_config_store_item(configuration, params))
+}
+
+/// Like [`bulk_update_config_store_item`], but also returns the status and headers of the response.
+pub fn bulk_update_config_store_item_with_info(configuration: &Configuration, params: BulkUpdateConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<BulkUpdateConfigStoreItemError>> {
+    super::block_on(config_store_item_api::bulk_update_config_store_item_with_info(configuration, params))
+}
+
+/// Add a single key-value pair to an individual config store, specified by ID.
+pub fn create_config_store_item(configuration: &Configuration, params: CreateConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<CreateConfigStoreItemError>> {
+    super::block_on(config_store_item_api::create_config_store_item(configuration, params))
+}
+
+/// Like [`create_config_store_item`], but also returns the status and headers of the response.
+pub fn create_config_store_item_with_info(configuration: &Configuration, params: CreateConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::ConfigStoreItemResponse>, Error<CreateConfigStoreItemError>> {
+    super::block_on(config_store_item_api::create_config_store_item_with_info(configuration, params))
+}
+
+/// Delete an entry in a config store given a config store ID, and item key.
+pub fn delete_config_store_item(configuration: &Configuration, params: DeleteConfigStoreItemParams) -> Result<crate::models::InlineResponse200, Error<DeleteConfigStoreItemError>> {
+    super::block_on(config_store_item_api::delete_config_store_item(configuration, params))
+}
+
+/// Like [`delete_config_store_item`], but also returns the status and headers of the response.
+pub fn delete_config_store_item_with_info(configuration: &Configuration, params: DeleteConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteConfigStoreItemError>> {
+    super::block_on(config_store_item_api::delete_config_store_item_with_info(configuration, params))
+}
+
+/// Retrieve a config store entry given a config store ID and item key.
+pub fn get_config_store_item(configuration: &Configuration, params: GetConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<GetConfigStoreItemError>> {
+    super::block_on(config_store_item_api::get_config_store_item(configuration, params))
+}
+
+/// Like [`get_config_store_item`], but also returns the status and headers of the response.
+pub fn get_config_store_item_with_info(configuration: &Configuration, params: GetConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::ConfigStoreItemResponse>, Error<GetConfigStoreItemError>> {
+    super::block_on(config_store_item_api::get_config_store_item_with_info(configuration, params))
+}
+
+/// List the key-value pairs associated with a given config store ID.
+pub fn list_config_store_items(configuration: &Configuration, params: ListConfigStoreItemsParams) -> Result<Vec<crate::models::ConfigStoreItemResponse>, Error<ListConfigStoreItemsError>> {
+    super::block_on(config_store_item_api::list_config_store_items(configuration, params))
+}
+
+/// Like [`list_config_store_items`], but also returns the status and headers of the response.
+pub fn list_config_store_items_with_info(configuration: &Configuration, params: ListConfigStoreItemsParams) -> Result<crate::apis::Response<Vec<crate::models::ConfigStoreItemResponse>>, Error<ListConfigStoreItemsError>> {
+    super::block_on(config_store_item_api::list_config_store_items_with_info(configuration, params))
+}
+
+/// Update an entry in a config store given a config store ID, item key, and item value.
+pub fn update_config_store_item(configuration: &Configuration, params: UpdateConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<UpdateConfigStoreItemError>> {
+    super::block_on(config_store_item_api::update_config_store_item(configuration, params))
+}
+
+/// Like [`update_config_store_item`], but also returns the status and headers of the response.
+pub fn update_config_store_item_with_info(configuration: &Configuration, params: UpdateConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::ConfigStoreItemResponse>, Error<UpdateConfigStoreItemError>> {
+    super::block_on(config_store_item_api::update_config_store_item_with_info(configuration, params))
+}
+
+/// Insert or update an entry in a config store given a config store ID, item key, and item value.
+pub fn upsert_config_store_item(configuration: &Configuration, params: UpsertConfigStoreItemParams) -> Result<crate::models::ConfigStoreItemResponse, Error<UpsertConfigStoreItemError>> {
+    super::block_on(config_store_item_api::upsert_config_store_item(configuration, params))
+}
+
+/// Like [`upsert_config_store_item`], but also returns the status and headers of the response.
+pub fn upsert_config_store_item_with_info(configuration: &Configuration, params: UpsertConfigStoreItemParams) -> Result<crate::apis::Response<crate::models::ConfigStoreItemResponse>, Error<UpsertConfigStoreItemError>> {
+    super::block_on(config_store_item_api::upsert_config_store_item_with_info(configuration, params))
+}
@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{contact_api, Error};
+
+pub use crate::apis::contact_api::{CreateContactsError, CreateContactsParams, DeleteContactError, DeleteContactParams, ListContactsError, ListContactsParams};
+
+/// Create a contact.
+pub fn create_contacts(configuration: &Configuration, params: CreateContactsParams) -> Result<crate::models::ContactResponse, Error<CreateContactsError>> {
+    super::block_on(contact_api::create_contacts(configuration, params))
+}
+
+/// Like [`create_contacts`], but also returns the status and headers of the response.
+pub fn create_contacts_with_info(configuration: &Configuration, params: CreateContactsParams) -> Result<crate::apis::Response<crate::models::ContactResponse>, Error<CreateContactsError>> {
+    super::block_on(contact_api::create_contacts_with_info(configuration, params))
+}
+
+/// Delete a contact.
+pub fn delete_contact(configuration: &Configuration, params: DeleteContactParams) -> Result<crate::models::InlineResponse200, Error<DeleteContactError>> {
+    super::block_on(contact_api::delete_contact(configuration, params))
+}
+
+/// Like [`delete_contact`], but also returns the status and headers of the response.
+pub fn delete_contact_with_info(configuration: &Configuration, params: DeleteContactParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteContactError>> {
+    super::block_on(contact_api::delete_contact_with_info(configuration, params))
+}
+
+/// List all contacts from a specified customer ID.
+pub fn list_contacts(configuration: &Configuration, params: ListContactsParams) -> Result<Vec<crate::models::SchemasContactResponse>, Error<ListContactsError>> {
+    super::block_on(contact_api::list_contacts(configuration, params))
+}
+
+/// Like [`list_contacts`], but also returns the status and headers of the response.
+pub fn list_contacts_with_info(configuration: &Configuration, params: ListContactsParams) -> Result<crate::apis::Response<Vec<crate::models::SchemasContactResponse>>, Error<ListContactsError>> {
+    super::block_on(contact_api::list_contacts_with_info(configuration, params))
+}
@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{content_api, Error};
+
+pub use crate::apis::content_api::{ContentCheckError, ContentCheckParams};
+
+/// Retrieve headers and MD5 hash of the content for a particular URL from each Fastly edge server. This API is limited to 200 requests per hour. If the content takes too long to download, the hash will be set to `error-timeout-$pop`. If the response is too large, it will be set to `warning-too-large-$pop`.
+pub fn content_check(configuration: &Configuration, params: ContentCheckParams) -> Result<Vec<crate::models::Content>, Error<ContentCheckError>> {
+    super::block_on(content_api::content_check(configuration, params))
+}
+
+/// Like [`content_check`], but also returns the status and headers of the response.
+pub fn content_check_with_info(configuration: &Configuration, params: ContentCheckParams) -> Result<crate::apis::Response<Vec<crate::models::Content>>, Error<ContentCheckError>> {
+    super::block_on(content_api::content_check_with_info(configuration, params))
+}
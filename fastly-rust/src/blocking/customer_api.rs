@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{customer_api, Error};
+
+pub use crate::apis::customer_api::{DeleteCustomerError, DeleteCustomerParams, GetCustomerError, GetCustomerParams, GetLoggedInCustomerError, ListUsersError, ListUsersParams, UpdateCustomerError, UpdateCustomerParams};
+
+/// Delete a customer.
+pub fn delete_customer(configuration: &Configuration, params: DeleteCustomerParams) -> Result<crate::models::InlineResponse200, Error<DeleteCustomerError>> {
+    super::block_on(customer_api::delete_customer(configuration, params))
+}
+
+/// Like [`delete_customer`], but also returns the status and headers of the response.
+pub fn delete_customer_with_info(configuration: &Configuration, params: DeleteCustomerParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteCustomerError>> {
+    super::block_on(customer_api::delete_customer_with_info(configuration, params))
+}
+
+/// Get a specific customer.
+pub fn get_customer(configuration: &Configuration, params: GetCustomerParams) -> Result<crate::models::CustomerResponse, Error<GetCustomerError>> {
+    super::block_on(customer_api::get_customer(configuration, params))
+}
+
+/// Like [`get_customer`], but also returns the status and headers of the response.
+pub fn get_customer_with_info(configuration: &Configuration, params: GetCustomerParams) -> Result<crate::apis::Response<crate::models::CustomerResponse>, Error<GetCustomerError>> {
+    super::block_on(customer_api::get_customer_with_info(configuration, params))
+}
+
+/// Get the logged in customer.
+pub fn get_logged_in_customer(configuration: &Configuration) -> Result<crate::models::CustomerResponse, Error<GetLoggedInCustomerError>> {
+    super::block_on(customer_api::get_logged_in_customer(configuration))
+}
+
+/// Like [`get_logged_in_customer`], but also returns the status and headers of the response.
+pub fn get_logged_in_customer_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::CustomerResponse>, Error<GetLoggedInCustomerError>> {
+    super::block_on(customer_api::get_logged_in_customer_with_info(configuration))
+}
+
+/// List all users from a specified customer id.
+pub fn list_users(configuration: &Configuration, params: ListUsersParams) -> Result<Vec<crate::models::SchemasUserResponse>, Error<ListUsersError>> {
+    super::block_on(customer_api::list_users(configuration, params))
+}
+
+/// Like [`list_users`], but also returns the status and headers of the response.
+pub fn list_users_with_info(configuration: &Configuration, params: ListUsersParams) -> Result<crate::apis::Response<Vec<crate::models::SchemasUserResponse>>, Error<ListUsersError>> {
+    super::block_on(customer_api::list_users_with_info(configuration, params))
+}
+
+/// Update a customer.
+pub fn update_customer(configuration: &Configuration, params: UpdateCustomerParams) -> Result<crate::models::CustomerResponse, Error<UpdateCustomerError>> {
+    super::block_on(customer_api::update_customer(configuration, params))
+}
+
+/// Like [`update_customer`], but also returns the status and headers of the response.
+pub fn update_customer_with_info(configuration: &Configuration, params: UpdateCustomerParams) -> Result<crate::apis::Response<crate::models::CustomerResponse>, Error<UpdateCustomerError>> {
+    super::block_on(customer_api::update_customer_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{dictionary_api, Error};
+
+pub use crate::apis::dictionary_api::{CreateDictionaryError, CreateDictionaryParams, DeleteDictionaryError, DeleteDictionaryParams, GetDictionaryError, GetDictionaryParams, ListDictionariesError, ListDictionariesParams, UpdateDictionaryError, UpdateDictionaryParams};
+
+/// Create named dictionary for a particular service and version.
+pub fn create_dictionary(configuration: &Configuration, params: CreateDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<CreateDictionaryError>> {
+    super::block_on(dictionary_api::create_dictionary(configuration, params))
+}
+
+/// Like [`create_dictionary`], but also returns the status and headers of the response.
+pub fn create_dictionary_with_info(configuration: &Configuration, params: CreateDictionaryParams) -> Result<crate::apis::Response<crate::models::DictionaryResponse>, Error<CreateDictionaryError>> {
+    super::block_on(dictionary_api::create_dictionary_with_info(configuration, params))
+}
+
+/// Delete named dictionary for a particular service and version.
+pub fn delete_dictionary(configuration: &Configuration, params: DeleteDictionaryParams) -> Result<crate::models::InlineResponse200, Error<DeleteDictionaryError>> {
+    super::block_on(dictionary_api::delete_dictionary(configuration, params))
+}
+
+/// Like [`delete_dictionary`], but also returns the status and headers of the response.
+pub fn delete_dictionary_with_info(configuration: &Configuration, params: DeleteDictionaryParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDictionaryError>> {
+    super::block_on(dictionary_api::delete_dictionary_with_info(configuration, params))
+}
+
+/// Retrieve a single dictionary by name for the version and service.
+pub fn get_dictionary(configuration: &Configuration, params: GetDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<GetDictionaryError>> {
+    super::block_on(dictionary_api::get_dictionary(configuration, params))
+}
+
+/// Like [`get_dictionary`], but also returns the status and headers of the response.
+pub fn get_dictionary_with_info(configuration: &Configuration, params: GetDictionaryParams) -> Result<crate::apis::Response<crate::models::DictionaryResponse>, Error<GetDictionaryError>> {
+    super::block_on(dictionary_api::get_dictionary_with_info(configuration, params))
+}
+
+/// List all dictionaries for the version of the service.
+pub fn list_dictionaries(configuration: &Configuration, params: ListDictionariesParams) -> Result<Vec<crate::models::DictionaryResponse>, Error<ListDictionariesError>> {
+    super::block_on(dictionary_api::list_dictionaries(configuration, params))
+}
+
+/// Like [`list_dictionaries`], but also returns the status and headers of the response.
+pub fn list_dictionaries_with_info(configuration: &Configuration, params: ListDictionariesParams) -> Result<crate::apis::Response<Vec<crate::models::DictionaryResponse>>, Error<ListDictionariesError>> {
+    super::block_on(dictionary_api::list_dictionaries_with_info(configuration, params))
+}
+
+/// Update named dictionary for a particular service and version.
+pub fn update_dictionary(configuration: &Configuration, params: UpdateDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<UpdateDictionaryError>> {
+    super::block_on(dictionary_api::update_dictionary(configuration, params))
+}
+
+/// Like [`update_dictionary`], but also returns the status and headers of the response.
+pub fn update_dictionary_with_info(configuration: &Configuration, params: UpdateDictionaryParams) -> Result<crate::apis::Response<crate::models::DictionaryResponse>, Error<UpdateDictionaryError>> {
+    super::block_on(dictionary_api::update_dictionary_with_info(configuration, params))
+}
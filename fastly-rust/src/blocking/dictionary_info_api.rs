@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{dictionary_info_api, Error};
+
+pub use crate::apis::dictionary_info_api::{GetDictionaryInfoError, GetDictionaryInfoParams};
+
+/// Retrieve metadata for a single dictionary by ID for a version and service.
+pub fn get_dictionary_info(configuration: &Configuration, params: GetDictionaryInfoParams) -> Result<crate::models::DictionaryInfoResponse, Error<GetDictionaryInfoError>> {
+    super::block_on(dictionary_info_api::get_dictionary_info(configuration, params))
+}
+
+/// Like [`get_dictionary_info`], but also returns the status and headers of the response.
+pub fn get_dictionary_info_with_info(configuration: &Configuration, params: GetDictionaryInfoParams) -> Result<crate::apis::Response<crate::models::DictionaryInfoResponse>, Error<GetDictionaryInfoError>> {
+    super::block_on(dictionary_info_api::get_dictionary_info_with_info(configuration, params))
+}
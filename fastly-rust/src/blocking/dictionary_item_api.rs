@@ -0,0 +1,81 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{dictionary_item_api, Error};
+
+pub use crate::apis::dictionary_item_api::{BulkUpdateDictionaryItemError, BulkUpdateDictionaryItemParams, CreateDictionaryItemError, CreateDictionaryItemParams, DeleteDictionaryItemError, DeleteDictionaryItemParams, GetDictionaryItemError, GetDictionaryItemParams, ListDictionaryItemsError, ListDictionaryItemsParams, UpdateDictionaryItemError, UpdateDictionaryItemParams, UpsertDictionaryItemError, UpsertDictionaryItemParams};
+
+/// Update multiple items in the same dictionary. For faster updates to your service, group your changes into large batches. The maximum batch size is 1000 items. [Contact support](https://support.fastly.com/) to discuss raising this limit.
+pub fn bulk_update_dictionary_item(configuration: &Configuration, params: BulkUpdateDictionaryItemParams) -> Result<crate::models::InlineResponse200, Error<BulkUpdateDictionaryItemError>> {
+    super::block_on(dictionary_item_api::bulk_update_dictionary_item(configuration, params))
+}
+
+/// Like [`bulk_update_dictionary_item`], but also returns the status and headers of the response.
+pub fn bulk_update_dictionary_item_with_info(configuration: &Configuration, params: BulkUpdateDictionaryItemParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<BulkUpdateDictionaryItemError>> {
+    super::block_on(dictionary_item_api::bulk_update_dictionary_item_with_info(configuration, params))
+}
+
+/// Create DictionaryItem given service, dictionary ID, item key, and item value.
+pub fn create_dictionary_item(configuration: &Configuration, params: CreateDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<CreateDictionaryItemError>> {
+    super::block_on(dictionary_item_api::create_dictionary_item(configuration, params))
+}
+
+/// Like [`create_dictionary_item`], but also returns the status and headers of the response.
+pub fn create_dictionary_item_with_info(configuration: &Configuration, params: CreateDictionaryItemParams) -> Result<crate::apis::Response<crate::models::DictionaryItemResponse>, Error<CreateDictionaryItemError>> {
+    super::block_on(dictionary_item_api::create_dictionary_item_with_info(configuration, params))
+}
+
+/// Delete DictionaryItem given service, dictionary ID, and item key.
+pub fn delete_dictionary_item(configuration: &Configuration, params: DeleteDictionaryItemParams) -> Result<crate::models::InlineResponse200, Error<DeleteDictionaryItemError>> {
+    super::block_on(dictionary_item_api::delete_dictionary_item(configuration, params))
+}
+
+/// Like [`delete_dictionary_item`], but also returns the status and headers of the response.
+pub fn delete_dictionary_item_with_info(configuration: &Configuration, params: DeleteDictionaryItemParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDictionaryItemError>> {
+    super::block_on(dictionary_item_api::delete_dictionary_item_with_info(configuration, params))
+}
+
+/// Retrieve a single DictionaryItem given service, dictionary ID and item key.
+pub fn get_dictionary_item(configuration: &Configuration, params: GetDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<GetDictionaryItemError>> {
+    super::block_on(dictionary_item_api::get_dictionary_item(configuration, params))
+}
+
+/// Like [`get_dictionary_item`], but also returns the status and headers of the response.
+pub fn get_dictionary_item_with_info(configuration: &Configuration, params: GetDictionaryItemParams) -> Result<crate::apis::Response<crate::models::DictionaryItemResponse>, Error<GetDictionaryItemError>> {
+    super::block_on(dictionary_item_api::get_dictionary_item_with_info(configuration, params))
+}
+
+/// List of DictionaryItems given service and dictionary ID.
+pub fn list_dictionary_items(configuration: &Configuration, params: ListDictionaryItemsParams) -> Result<Vec<crate::models::DictionaryItemResponse>, Error<ListDictionaryItemsError>> {
+    super::block_on(dictionary_item_api::list_dictionary_items(configuration, params))
+}
+
+/// Like [`list_dictionary_items`], but also returns the status and headers of the response.
+pub fn list_dictionary_items_with_info(configuration: &Configuration, params: ListDictionaryItemsParams) -> Result<crate::apis::Response<Vec<crate::models::DictionaryItemResponse>>, Error<ListDictionaryItemsError>> {
+    super::block_on(dictionary_item_api::list_dictionary_items_with_info(configuration, params))
+}
+
+/// Update DictionaryItem given service, dictionary ID, item key, and item value.
+pub fn update_dictionary_item(configuration: &Configuration, params: UpdateDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<UpdateDictionaryItemError>> {
+    super::block_on(dictionary_item_api::update_dictionary_item(configuration, params))
+}
+
+/// Like [`update_dictionary_item`], but also returns the status and headers of the response.
+pub fn update_dictionary_item_with_info(configuration: &Configuration, params: UpdateDictionaryItemParams) -> Result<crate::apis::Response<crate::models::DictionaryItemResponse>, Error<UpdateDictionaryItemError>> {
+    super::block_on(dictionary_item_api::update_dictionary_item_with_info(configuration, params))
+}
+
+/// Upsert DictionaryItem given service, dictionary ID, item key, and item value.
+pub fn upsert_dictionary_item(configuration: &Configuration, params: UpsertDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<UpsertDictionaryItemError>> {
+    super::block_on(dictionary_item_api::upsert_dictionary_item(configuration, params))
+}
+
+/// Like [`upsert_dictionary_item`], but also returns the status and headers of the response.
+pub fn upsert_dictionary_item_with_info(configuration: &Configuration, params: UpsertDictionaryItemParams) -> Result<crate::apis::Response<crate::models::DictionaryItemResponse>, Error<UpsertDictionaryItemError>> {
+    super::block_on(dictionary_item_api::upsert_dictionary_item_with_info(configuration, params))
+}
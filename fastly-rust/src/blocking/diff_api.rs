@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{diff_api, Error};
+
+pub use crate::apis::diff_api::{DiffServiceVersionsError, DiffServiceVersionsParams};
+
+/// Get diff between two versions.
+pub fn diff_service_versions(configuration: &Configuration, params: DiffServiceVersionsParams) -> Result<crate::models::DiffResponse, Error<DiffServiceVersionsError>> {
+    super::block_on(diff_api::diff_service_versions(configuration, params))
+}
+
+/// Like [`diff_service_versions`], but also returns the status and headers of the response.
+pub fn diff_service_versions_with_info(configuration: &Configuration, params: DiffServiceVersionsParams) -> Result<crate::apis::Response<crate::models::DiffResponse>, Error<DiffServiceVersionsError>> {
+    super::block_on(diff_api::diff_service_versions_with_info(configuration, params))
+}
@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{director_api, Error};
+
+pub use crate::apis::director_api::{DeleteDirectorError, DeleteDirectorParams, GetDirectorError, GetDirectorParams, ListDirectorsError, ListDirectorsParams, UpdateDirectorError, UpdateDirectorParams};
+
+/// Delete the director for a particular service and version.
+pub fn delete_director(configuration: &Configuration, params: DeleteDirectorParams) -> Result<crate::models::InlineResponse200, Error<DeleteDirectorError>> {
+    super::block_on(director_api::delete_director(configuration, params))
+}
+
+/// Like [`delete_director`], but also returns the status and headers of the response.
+pub fn delete_director_with_info(configuration: &Configuration, params: DeleteDirectorParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDirectorError>> {
+    super::block_on(director_api::delete_director_with_info(configuration, params))
+}
+
+/// Get the director for a particular service and version.
+pub fn get_director(configuration: &Configuration, params: GetDirectorParams) -> Result<crate::models::DirectorResponse, Error<GetDirectorError>> {
+    super::block_on(director_api::get_director(configuration, params))
+}
+
+/// Like [`get_director`], but also returns the status and headers of the response.
+pub fn get_director_with_info(configuration: &Configuration, params: GetDirectorParams) -> Result<crate::apis::Response<crate::models::DirectorResponse>, Error<GetDirectorError>> {
+    super::block_on(director_api::get_director_with_info(configuration, params))
+}
+
+/// List the directors for a particular service and version.
+pub fn list_directors(configuration: &Configuration, params: ListDirectorsParams) -> Result<Vec<crate::models::DirectorResponse>, Error<ListDirectorsError>> {
+    super::block_on(director_api::list_directors(configuration, params))
+}
+
+/// Like [`list_directors`], but also returns the status and headers of the response.
+pub fn list_directors_with_info(configuration: &Configuration, params: ListDirectorsParams) -> Result<crate::apis::Response<Vec<crate::models::DirectorResponse>>, Error<ListDirectorsError>> {
+    super::block_on(director_api::list_directors_with_info(configuration, params))
+}
+
+/// Update the director for a particular service and version.
+pub fn update_director(configuration: &Configuration, params: UpdateDirectorParams) -> Result<crate::models::DirectorResponse, Error<UpdateDirectorError>> {
+    super::block_on(director_api::update_director(configuration, params))
+}
+
+/// Like [`update_director`], but also returns the status and headers of the response.
+pub fn update_director_with_info(configuration: &Configuration, params: UpdateDirectorParams) -> Result<crate::apis::Response<crate::models::DirectorResponse>, Error<UpdateDirectorError>> {
+    super::block_on(director_api::update_director_with_info(configuration, params))
+}
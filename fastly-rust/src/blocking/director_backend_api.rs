@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{director_backend_api, Error};
+
+pub use crate::apis::director_backend_api::{CreateDirectorBackendError, CreateDirectorBackendParams, DeleteDirectorBackendError, DeleteDirectorBackendParams, GetDirectorBackendError, GetDirectorBackendParams};
+
+/// Establishes a relationship between a Backend and a Director. The Backend is then considered a member of the Director and can be used to balance traffic onto.
+pub fn create_director_backend(configuration: &Configuration, params: CreateDirectorBackendParams) -> Result<crate::models::DirectorBackend, Error<CreateDirectorBackendError>> {
+    super::block_on(director_backend_api::create_director_backend(configuration, params))
+}
+
+/// Like [`create_director_backend`], but also returns the status and headers of the response.
+pub fn create_director_backend_with_info(configuration: &Configuration, params: CreateDirectorBackendParams) -> Result<crate::apis::Response<crate::models::DirectorBackend>, Error<CreateDirectorBackendError>> {
+    super::block_on(director_backend_api::create_director_backend_with_info(configuration, params))
+}
+
+/// Deletes the relationship between a Backend and a Director. The Backend is no longer considered a member of the Director and thus will not have traffic balanced onto it from this Director.
+pub fn delete_director_backend(configuration: &Configuration, params: DeleteDirectorBackendParams) -> Result<crate::models::InlineResponse200, Error<DeleteDirectorBackendError>> {
+    super::block_on(director_backend_api::delete_director_backend(configuration, params))
+}
+
+/// Like [`delete_director_backend`], but also returns the status and headers of the response.
+pub fn delete_director_backend_with_info(configuration: &Configuration, params: DeleteDirectorBackendParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDirectorBackendError>> {
+    super::block_on(director_backend_api::delete_director_backend_with_info(configuration, params))
+}
+
+/// Returns the relationship between a Backend and a Director. If the Backend has been associated with the Director, it returns a simple record indicating this. Otherwise, returns a 404.
+pub fn get_director_backend(configuration: &Configuration, params: GetDirectorBackendParams) -> Result<crate::models::DirectorBackend, Error<GetDirectorBackendError>> {
+    super::block_on(director_backend_api::get_director_backend(configuration, params))
+}
+
+/// Like [`get_director_backend`], but also returns the status and headers of the response.
+pub fn get_director_backend_with_info(configuration: &Configuration, params: GetDirectorBackendParams) -> Result<crate::apis::Response<crate::models::DirectorBackend>, Error<GetDirectorBackendError>> {
+    super::block_on(director_backend_api::get_director_backend_with_info(configuration, params))
+}
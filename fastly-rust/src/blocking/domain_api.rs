@@ -0,0 +1,71 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{domain_api, Error};
+
+pub use crate::apis::domain_api::{CheckDomainError, CheckDomainParams, CreateDomainError, CreateDomainParams, DeleteDomainError, DeleteDomainParams, GetDomainError, GetDomainParams, ListDomainsError, ListDomainsParams, UpdateDomainError, UpdateDomainParams};
+
+/// Checks the status of a specific domain's DNS record for a Service Version. Returns an array in the same format as domain/check_all.
+pub fn check_domain(configuration: &Configuration, params: CheckDomainParams) -> Result<Vec<serde_json::Value>, Error<CheckDomainError>> {
+    super::block_on(domain_api::check_domain(configuration, params))
+}
+
+/// Like [`check_domain`], but also returns the status and headers of the response.
+pub fn check_domain_with_info(configuration: &Configuration, params: CheckDomainParams) -> Result<crate::apis::Response<Vec<serde_json::Value>>, Error<CheckDomainError>> {
+    super::block_on(domain_api::check_domain_with_info(configuration, params))
+}
+
+/// Create a domain for a particular service and version.
+pub fn create_domain(configuration: &Configuration, params: CreateDomainParams) -> Result<crate::models::DomainResponse, Error<CreateDomainError>> {
+    super::block_on(domain_api::create_domain(configuration, params))
+}
+
+/// Like [`create_domain`], but also returns the status and headers of the response.
+pub fn create_domain_with_info(configuration: &Configuration, params: CreateDomainParams) -> Result<crate::apis::Response<crate::models::DomainResponse>, Error<CreateDomainError>> {
+    super::block_on(domain_api::create_domain_with_info(configuration, params))
+}
+
+/// Delete the domain for a particular service and versions.
+pub fn delete_domain(configuration: &Configuration, params: DeleteDomainParams) -> Result<crate::models::InlineResponse200, Error<DeleteDomainError>> {
+    super::block_on(domain_api::delete_domain(configuration, params))
+}
+
+/// Like [`delete_domain`], but also returns the status and headers of the response.
+pub fn delete_domain_with_info(configuration: &Configuration, params: DeleteDomainParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteDomainError>> {
+    super::block_on(domain_api::delete_domain_with_info(configuration, params))
+}
+
+/// Get the domain for a particular service and version.
+pub fn get_domain(configuration: &Configuration, params: GetDomainParams) -> Result<crate::models::DomainResponse, Error<GetDomainError>> {
+    super::block_on(domain_api::get_domain(configuration, params))
+}
+
+/// Like [`get_domain`], but also returns the status and headers of the response.
+pub fn get_domain_with_info(configuration: &Configuration, params: GetDomainParams) -> Result<crate::apis::Response<crate::models::DomainResponse>, Error<GetDomainError>> {
+    super::block_on(domain_api::get_domain_with_info(configuration, params))
+}
+
+/// List all the domains for a particular service and version.
+pub fn list_domains(configuration: &Configuration, params: ListDomainsParams) -> Result<Vec<crate::models::DomainResponse>, Error<ListDomainsError>> {
+    super::block_on(domain_api::list_domains(configuration, params))
+}
+
+/// Like [`list_domains`], but also returns the status and headers of the response.
+pub fn list_domains_with_info(configuration: &Configuration, params: ListDomainsParams) -> Result<crate::apis::Response<Vec<crate::models::DomainResponse>>, Error<ListDomainsError>> {
+    super::block_on(domain_api::list_domains_with_info(configuration, params))
+}
+
+/// Update the domain for a particular service and version.
+pub fn update_domain(configuration: &Configuration, params: UpdateDomainParams) -> Result<crate::models::DomainResponse, Error<UpdateDomainError>> {
+    super::block_on(domain_api::update_domain(configuration, params))
+}
+
+/// Like [`update_domain`], but also returns the status and headers of the response.
+pub fn update_domain_with_info(configuration: &Configuration, params: UpdateDomainParams) -> Result<crate::apis::Response<crate::models::DomainResponse>, Error<UpdateDomainError>> {
+    super::block_on(domain_api::update_domain_with_info(configuration, params))
+}
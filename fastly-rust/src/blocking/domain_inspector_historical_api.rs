@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{domain_inspector_historical_api, Error};
+
+pub use crate::apis::domain_inspector_historical_api::{GetDomainInspectorHistoricalError, GetDomainInspectorHistoricalParams};
+
+/// Fetches historical domain metrics for a given Fastly service, optionally filtering and grouping the results by domain, region, or POP. 
+pub fn get_domain_inspector_historical(configuration: &Configuration, params: GetDomainInspectorHistoricalParams) -> Result<crate::models::HistoricalDomainsResponse, Error<GetDomainInspectorHistoricalError>> {
+    super::block_on(domain_inspector_historical_api::get_domain_inspector_historical(configuration, params))
+}
+
+/// Like [`get_domain_inspector_historical`], but also returns the status and headers of the response.
+pub fn get_domain_inspector_historical_with_info(configuration: &Configuration, params: GetDomainInspectorHistoricalParams) -> Result<crate::apis::Response<crate::models::HistoricalDomainsResponse>, Error<GetDomainInspectorHistoricalError>> {
+    super::block_on(domain_inspector_historical_api::get_domain_inspector_historical_with_info(configuration, params))
+}
@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{domain_ownerships_api, Error};
+
+pub use crate::apis::domain_ownerships_api::{ListDomainOwnershipsError};
+
+/// List all domain-ownerships.
+pub fn list_domain_ownerships(configuration: &Configuration) -> Result<crate::models::InlineResponse2002, Error<ListDomainOwnershipsError>> {
+    super::block_on(domain_ownerships_api::list_domain_ownerships(configuration))
+}
+
+/// Like [`list_domain_ownerships`], but also returns the status and headers of the response.
+pub fn list_domain_ownerships_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::InlineResponse2002>, Error<ListDomainOwnershipsError>> {
+    super::block_on(domain_ownerships_api::list_domain_ownerships_with_info(configuration))
+}
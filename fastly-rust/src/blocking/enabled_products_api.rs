@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{enabled_products_api, Error};
+
+pub use crate::apis::enabled_products_api::{DisableProductError, DisableProductParams, EnableProductError, EnableProductParams, GetEnabledProductError, GetEnabledProductParams};
+
+/// Disable a product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
+pub fn disable_product(configuration: &Configuration, params: DisableProductParams) -> Result<(), Error<DisableProductError>> {
+    super::block_on(enabled_products_api::disable_product(configuration, params))
+}
+
+/// Like [`disable_product`], but also returns the status and headers of the response.
+pub fn disable_product_with_info(configuration: &Configuration, params: DisableProductParams) -> Result<crate::apis::Response<()>, Error<DisableProductError>> {
+    super::block_on(enabled_products_api::disable_product_with_info(configuration, params))
+}
+
+/// Enable a product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
+pub fn enable_product(configuration: &Configuration, params: EnableProductParams) -> Result<crate::models::EnabledProductResponse, Error<EnableProductError>> {
+    super::block_on(enabled_products_api::enable_product(configuration, params))
+}
+
+/// Like [`enable_product`], but also returns the status and headers of the response.
+pub fn enable_product_with_info(configuration: &Configuration, params: EnableProductParams) -> Result<crate::apis::Response<crate::models::EnabledProductResponse>, Error<EnableProductError>> {
+    super::block_on(enabled_products_api::enable_product_with_info(configuration, params))
+}
+
+/// Get enabled product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
+pub fn get_enabled_product(configuration: &Configuration, params: GetEnabledProductParams) -> Result<crate::models::EnabledProductResponse, Error<GetEnabledProductError>> {
+    super::block_on(enabled_products_api::get_enabled_product(configuration, params))
+}
+
+/// Like [`get_enabled_product`], but also returns the status and headers of the response.
+pub fn get_enabled_product_with_info(configuration: &Configuration, params: GetEnabledProductParams) -> Result<crate::apis::Response<crate::models::EnabledProductResponse>, Error<GetEnabledProductError>> {
+    super::block_on(enabled_products_api::get_enabled_product_with_info(configuration, params))
+}
@@ -0,0 +1,31 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{events_api, Error};
+
+pub use crate::apis::events_api::{GetEventError, GetEventParams, ListEventsError, ListEventsParams};
+
+/// Get a specific event.
+pub fn get_event(configuration: &Configuration, params: GetEventParams) -> Result<crate::models::EventResponse, Error<GetEventError>> {
+    super::block_on(events_api::get_event(configuration, params))
+}
+
+/// Like [`get_event`], but also returns the status and headers of the response.
+pub fn get_event_with_info(configuration: &Configuration, params: GetEventParams) -> Result<crate::apis::Response<crate::models::EventResponse>, Error<GetEventError>> {
+    super::block_on(events_api::get_event_with_info(configuration, params))
+}
+
+/// List all events for a particular customer. Events can be filtered by user, customer and event type. Events can be sorted by date.
+pub fn list_events(configuration: &Configuration, params: ListEventsParams) -> Result<crate::models::EventsResponse, Error<ListEventsError>> {
+    super::block_on(events_api::list_events(configuration, params))
+}
+
+/// Like [`list_events`], but also returns the status and headers of the response.
+pub fn list_events_with_info(configuration: &Configuration, params: ListEventsParams) -> Result<crate::apis::Response<crate::models::EventsResponse>, Error<ListEventsError>> {
+    super::block_on(events_api::list_events_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{gzip_api, Error};
+
+pub use crate::apis::gzip_api::{CreateGzipConfigError, CreateGzipConfigParams, DeleteGzipConfigError, DeleteGzipConfigParams, GetGzipConfigsError, GetGzipConfigsParams, ListGzipConfigsError, ListGzipConfigsParams, UpdateGzipConfigError, UpdateGzipConfigParams};
+
+/// Create a named gzip configuration on a particular service and version.
+pub fn create_gzip_config(configuration: &Configuration, params: CreateGzipConfigParams) -> Result<crate::models::GzipResponse, Error<CreateGzipConfigError>> {
+    super::block_on(gzip_api::create_gzip_config(configuration, params))
+}
+
+/// Like [`create_gzip_config`], but also returns the status and headers of the response.
+pub fn create_gzip_config_with_info(configuration: &Configuration, params: CreateGzipConfigParams) -> Result<crate::apis::Response<crate::models::GzipResponse>, Error<CreateGzipConfigError>> {
+    super::block_on(gzip_api::create_gzip_config_with_info(configuration, params))
+}
+
+/// Delete a named gzip configuration on a particular service and version.
+pub fn delete_gzip_config(configuration: &Configuration, params: DeleteGzipConfigParams) -> Result<crate::models::InlineResponse200, Error<DeleteGzipConfigError>> {
+    super::block_on(gzip_api::delete_gzip_config(configuration, params))
+}
+
+/// Like [`delete_gzip_config`], but also returns the status and headers of the response.
+pub fn delete_gzip_config_with_info(configuration: &Configuration, params: DeleteGzipConfigParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteGzipConfigError>> {
+    super::block_on(gzip_api::delete_gzip_config_with_info(configuration, params))
+}
+
+/// Get the gzip configuration for a particular service, version, and name.
+pub fn get_gzip_configs(configuration: &Configuration, params: GetGzipConfigsParams) -> Result<crate::models::GzipResponse, Error<GetGzipConfigsError>> {
+    super::block_on(gzip_api::get_gzip_configs(configuration, params))
+}
+
+/// Like [`get_gzip_configs`], but also returns the status and headers of the response.
+pub fn get_gzip_configs_with_info(configuration: &Configuration, params: GetGzipConfigsParams) -> Result<crate::apis::Response<crate::models::GzipResponse>, Error<GetGzipConfigsError>> {
+    super::block_on(gzip_api::get_gzip_configs_with_info(configuration, params))
+}
+
+/// List all gzip configurations for a particular service and version.
+pub fn list_gzip_configs(configuration: &Configuration, params: ListGzipConfigsParams) -> Result<Vec<crate::models::GzipResponse>, Error<ListGzipConfigsError>> {
+    super::block_on(gzip_api::list_gzip_configs(configuration, params))
+}
+
+/// Like [`list_gzip_configs`], but also returns the status and headers of the response.
+pub fn list_gzip_configs_with_info(configuration: &Configuration, params: ListGzipConfigsParams) -> Result<crate::apis::Response<Vec<crate::models::GzipResponse>>, Error<ListGzipConfigsError>> {
+    super::block_on(gzip_api::list_gzip_configs_with_info(configuration, params))
+}
+
+/// Update a named gzip configuration on a particular service and version.
+pub fn update_gzip_config(configuration: &Configuration, params: UpdateGzipConfigParams) -> Result<crate::models::GzipResponse, Error<UpdateGzipConfigError>> {
+    super::block_on(gzip_api::update_gzip_config(configuration, params))
+}
+
+/// Like [`update_gzip_config`], but also returns the status and headers of the response.
+pub fn update_gzip_config_with_info(configuration: &Configuration, params: UpdateGzipConfigParams) -> Result<crate::apis::Response<crate::models::GzipResponse>, Error<UpdateGzipConfigError>> {
+    super::block_on(gzip_api::update_gzip_config_with_info(configuration, params))
+}
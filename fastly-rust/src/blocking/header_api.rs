@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{header_api, Error};
+
+pub use crate::apis::header_api::{CreateHeaderObjectError, CreateHeaderObjectParams, DeleteHeaderObjectError, DeleteHeaderObjectParams, GetHeaderObjectError, GetHeaderObjectParams, ListHeaderObjectsError, ListHeaderObjectsParams, UpdateHeaderObjectError, UpdateHeaderObjectParams};
+
+/// Creates a new Header object.
+pub fn create_header_object(configuration: &Configuration, params: CreateHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<CreateHeaderObjectError>> {
+    super::block_on(header_api::create_header_object(configuration, params))
+}
+
+/// Like [`create_header_object`], but also returns the status and headers of the response.
+pub fn create_header_object_with_info(configuration: &Configuration, params: CreateHeaderObjectParams) -> Result<crate::apis::Response<crate::models::HeaderResponse>, Error<CreateHeaderObjectError>> {
+    super::block_on(header_api::create_header_object_with_info(configuration, params))
+}
+
+/// Deletes a Header object by name.
+pub fn delete_header_object(configuration: &Configuration, params: DeleteHeaderObjectParams) -> Result<crate::models::InlineResponse200, Error<DeleteHeaderObjectError>> {
+    super::block_on(header_api::delete_header_object(configuration, params))
+}
+
+/// Like [`delete_header_object`], but also returns the status and headers of the response.
+pub fn delete_header_object_with_info(configuration: &Configuration, params: DeleteHeaderObjectParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteHeaderObjectError>> {
+    super::block_on(header_api::delete_header_object_with_info(configuration, params))
+}
+
+/// Retrieves a Header object by name.
+pub fn get_header_object(configuration: &Configuration, params: GetHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<GetHeaderObjectError>> {
+    super::block_on(header_api::get_header_object(configuration, params))
+}
+
+/// Like [`get_header_object`], but also returns the status and headers of the response.
+pub fn get_header_object_with_info(configuration: &Configuration, params: GetHeaderObjectParams) -> Result<crate::apis::Response<crate::models::HeaderResponse>, Error<GetHeaderObjectError>> {
+    super::block_on(header_api::get_header_object_with_info(configuration, params))
+}
+
+/// Retrieves all Header objects for a particular Version of a Service.
+pub fn list_header_objects(configuration: &Configuration, params: ListHeaderObjectsParams) -> Result<Vec<crate::models::HeaderResponse>, Error<ListHeaderObjectsError>> {
+    super::block_on(header_api::list_header_objects(configuration, params))
+}
+
+/// Like [`list_header_objects`], but also returns the status and headers of the response.
+pub fn list_header_objects_with_info(configuration: &Configuration, params: ListHeaderObjectsParams) -> Result<crate::apis::Response<Vec<crate::models::HeaderResponse>>, Error<ListHeaderObjectsError>> {
+    super::block_on(header_api::list_header_objects_with_info(configuration, params))
+}
+
+/// Modifies an existing Header object by name.
+pub fn update_header_object(configuration: &Configuration, params: UpdateHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<UpdateHeaderObjectError>> {
+    super::block_on(header_api::update_header_object(configuration, params))
+}
+
+/// Like [`update_header_object`], but also returns the status and headers of the response.
+pub fn update_header_object_with_info(configuration: &Configuration, params: UpdateHeaderObjectParams) -> Result<crate::apis::Response<crate::models::HeaderResponse>, Error<UpdateHeaderObjectError>> {
+    super::block_on(header_api::update_header_object_with_info(configuration, params))
+}
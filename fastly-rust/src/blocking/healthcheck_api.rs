@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{healthcheck_api, Error};
+
+pub use crate::apis::healthcheck_api::{CreateHealthcheckError, CreateHealthcheckParams, DeleteHealthcheckError, DeleteHealthcheckParams, GetHealthcheckError, GetHealthcheckParams, ListHealthchecksError, ListHealthchecksParams, UpdateHealthcheckError, UpdateHealthcheckParams};
+
+/// Create a health check for a particular service and version.
+pub fn create_healthcheck(configuration: &Configuration, params: CreateHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<CreateHealthcheckError>> {
+    super::block_on(healthcheck_api::create_healthcheck(configuration, params))
+}
+
+/// Like [`create_healthcheck`], but also returns the status and headers of the response.
+pub fn create_healthcheck_with_info(configuration: &Configuration, params: CreateHealthcheckParams) -> Result<crate::apis::Response<crate::models::HealthcheckResponse>, Error<CreateHealthcheckError>> {
+    super::block_on(healthcheck_api::create_healthcheck_with_info(configuration, params))
+}
+
+/// Delete the health check for a particular service and version.
+pub fn delete_healthcheck(configuration: &Configuration, params: DeleteHealthcheckParams) -> Result<crate::models::InlineResponse200, Error<DeleteHealthcheckError>> {
+    super::block_on(healthcheck_api::delete_healthcheck(configuration, params))
+}
+
+/// Like [`delete_healthcheck`], but also returns the status and headers of the response.
+pub fn delete_healthcheck_with_info(configuration: &Configuration, params: DeleteHealthcheckParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteHealthcheckError>> {
+    super::block_on(healthcheck_api::delete_healthcheck_with_info(configuration, params))
+}
+
+/// Get the health check for a particular service and version.
+pub fn get_healthcheck(configuration: &Configuration, params: GetHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<GetHealthcheckError>> {
+    super::block_on(healthcheck_api::get_healthcheck(configuration, params))
+}
+
+/// Like [`get_healthcheck`], but also returns the status and headers of the response.
+pub fn get_healthcheck_with_info(configuration: &Configuration, params: GetHealthcheckParams) -> Result<crate::apis::Response<crate::models::HealthcheckResponse>, Error<GetHealthcheckError>> {
+    super::block_on(healthcheck_api::get_healthcheck_with_info(configuration, params))
+}
+
+/// List all of the health checks for a particular service and version.
+pub fn list_healthchecks(configuration: &Configuration, params: ListHealthchecksParams) -> Result<Vec<crate::models::HealthcheckResponse>, Error<ListHealthchecksError>> {
+    super::block_on(healthcheck_api::list_healthchecks(configuration, params))
+}
+
+/// Like [`list_healthchecks`], but also returns the status and headers of the response.
+pub fn list_healthchecks_with_info(configuration: &Configuration, params: ListHealthchecksParams) -> Result<crate::apis::Response<Vec<crate::models::HealthcheckResponse>>, Error<ListHealthchecksError>> {
+    super::block_on(healthcheck_api::list_healthchecks_with_info(configuration, params))
+}
+
+/// Update the health check for a particular service and version.
+pub fn update_healthcheck(configuration: &Configuration, params: UpdateHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<UpdateHealthcheckError>> {
+    super::block_on(healthcheck_api::update_healthcheck(configuration, params))
+}
+
+/// Like [`update_healthcheck`], but also returns the status and headers of the response.
+pub fn update_healthcheck_with_info(configuration: &Configuration, params: UpdateHealthcheckParams) -> Result<crate::apis::Response<crate::models::HealthcheckResponse>, Error<UpdateHealthcheckError>> {
+    super::block_on(healthcheck_api::update_healthcheck_with_info(configuration, params))
+}
@@ -0,0 +1,101 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{historical_api, Error};
+
+pub use crate::apis::historical_api::{GetHistStatsAggregatedError, GetHistStatsAggregatedParams, GetHistStatsError, GetHistStatsFieldError, GetHistStatsFieldParams, GetHistStatsParams, GetHistStatsServiceError, GetHistStatsServiceFieldError, GetHistStatsServiceFieldParams, GetHistStatsServiceParams, GetRegionsError, GetUsageError, GetUsageMonthError, GetUsageMonthParams, GetUsageParams, GetUsageServiceError, GetUsageServiceParams};
+
+/// Fetches historical stats for each of your Fastly services and groups the results by service ID.
+pub fn get_hist_stats(configuration: &Configuration, params: GetHistStatsParams) -> Result<crate::models::HistoricalResponse, Error<GetHistStatsError>> {
+    super::block_on(historical_api::get_hist_stats(configuration, params))
+}
+
+/// Like [`get_hist_stats`], but also returns the status and headers of the response.
+pub fn get_hist_stats_with_info(configuration: &Configuration, params: GetHistStatsParams) -> Result<crate::apis::Response<crate::models::HistoricalResponse>, Error<GetHistStatsError>> {
+    super::block_on(historical_api::get_hist_stats_with_info(configuration, params))
+}
+
+/// Fetches historical stats information aggregated across all of your Fastly services.
+pub fn get_hist_stats_aggregated(configuration: &Configuration, params: GetHistStatsAggregatedParams) -> Result<crate::models::HistoricalAggregateResponse, Error<GetHistStatsAggregatedError>> {
+    super::block_on(historical_api::get_hist_stats_aggregated(configuration, params))
+}
+
+/// Like [`get_hist_stats_aggregated`], but also returns the status and headers of the response.
+pub fn get_hist_stats_aggregated_with_info(configuration: &Configuration, params: GetHistStatsAggregatedParams) -> Result<crate::apis::Response<crate::models::HistoricalAggregateResponse>, Error<GetHistStatsAggregatedError>> {
+    super::block_on(historical_api::get_hist_stats_aggregated_with_info(configuration, params))
+}
+
+/// Fetches the specified field from the historical stats for each of your services and groups the results by service ID.
+pub fn get_hist_stats_field(configuration: &Configuration, params: GetHistStatsFieldParams) -> Result<crate::models::HistoricalFieldResponse, Error<GetHistStatsFieldError>> {
+    super::block_on(historical_api::get_hist_stats_field(configuration, params))
+}
+
+/// Like [`get_hist_stats_field`], but also returns the status and headers of the response.
+pub fn get_hist_stats_field_with_info(configuration: &Configuration, params: GetHistStatsFieldParams) -> Result<crate::apis::Response<crate::models::HistoricalFieldResponse>, Error<GetHistStatsFieldError>> {
+    super::block_on(historical_api::get_hist_stats_field_with_info(configuration, params))
+}
+
+/// Fetches historical stats for a given service.
+pub fn get_hist_stats_service(configuration: &Configuration, params: GetHistStatsServiceParams) -> Result<crate::models::HistoricalAggregateResponse, Error<GetHistStatsServiceError>> {
+    super::block_on(historical_api::get_hist_stats_service(configuration, params))
+}
+
+/// Like [`get_hist_stats_service`], but also returns the status and headers of the response.
+pub fn get_hist_stats_service_with_info(configuration: &Configuration, params: GetHistStatsServiceParams) -> Result<crate::apis::Response<crate::models::HistoricalAggregateResponse>, Error<GetHistStatsServiceError>> {
+    super::block_on(historical_api::get_hist_stats_service_with_info(configuration, params))
+}
+
+/// Fetches the specified field from the historical stats for a given service.
+pub fn get_hist_stats_service_field(configuration: &Configuration, params: GetHistStatsServiceFieldParams) -> Result<crate::models::HistoricalFieldAggregateResponse, Error<GetHistStatsServiceFieldError>> {
+    super::block_on(historical_api::get_hist_stats_service_field(configuration, params))
+}
+
+/// Like [`get_hist_stats_service_field`], but also returns the status and headers of the response.
+pub fn get_hist_stats_service_field_with_info(configuration: &Configuration, params: GetHistStatsServiceFieldParams) -> Result<crate::apis::Response<crate::models::HistoricalFieldAggregateResponse>, Error<GetHistStatsServiceFieldError>> {
+    super::block_on(historical_api::get_hist_stats_service_field_with_info(configuration, params))
+}
+
+/// Fetches the list of codes for regions that are covered by the Fastly CDN service.
+pub fn get_regions(configuration: &Configuration) -> Result<crate::models::HistoricalRegionsResponse, Error<GetRegionsError>> {
+    super::block_on(historical_api::get_regions(configuration))
+}
+
+/// Like [`get_regions`], but also returns the status and headers of the response.
+pub fn get_regions_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::HistoricalRegionsResponse>, Error<GetRegionsError>> {
+    super::block_on(historical_api::get_regions_with_info(configuration))
+}
+
+/// Returns usage information aggregated across all Fastly services and grouped by region. To aggregate across all Fastly services by time period, see [`/stats/aggregate`](#get-hist-stats-aggregated).
+pub fn get_usage(configuration: &Configuration, params: GetUsageParams) -> Result<crate::models::HistoricalUsageAggregateResponse, Error<GetUsageError>> {
+    super::block_on(historical_api::get_usage(configuration, params))
+}
+
+/// Like [`get_usage`], but also returns the status and headers of the response.
+pub fn get_usage_with_info(configuration: &Configuration, params: GetUsageParams) -> Result<crate::apis::Response<crate::models::HistoricalUsageAggregateResponse>, Error<GetUsageError>> {
+    super::block_on(historical_api::get_usage_with_info(configuration, params))
+}
+
+/// Returns month-to-date usage details for a given month and year. Usage details are aggregated by service and across all Fastly services, and then grouped by region. This endpoint does not use the `from` or `to` fields for selecting the date for which data is requested. Instead, it uses `month` and `year` integer fields. Both fields are optional and default to the current month and year respectively. When set, an optional `billable_units` field will convert bandwidth to GB and divide requests by 10,000.
+pub fn get_usage_month(configuration: &Configuration, params: GetUsageMonthParams) -> Result<crate::models::HistoricalUsageMonthResponse, Error<GetUsageMonthError>> {
+    super::block_on(historical_api::get_usage_month(configuration, params))
+}
+
+/// Like [`get_usage_month`], but also returns the status and headers of the response.
+pub fn get_usage_month_with_info(configuration: &Configuration, params: GetUsageMonthParams) -> Result<crate::apis::Response<crate::models::HistoricalUsageMonthResponse>, Error<GetUsageMonthError>> {
+    super::block_on(historical_api::get_usage_month_with_info(configuration, params))
+}
+
+/// Returns usage information aggregated by service and grouped by service and region. For service stats by time period, see [`/stats`](#get-hist-stats) and [`/stats/field/:field`](#get-hist-stats-field).
+pub fn get_usage_service(configuration: &Configuration, params: GetUsageServiceParams) -> Result<crate::models::HistoricalUsageServiceResponse, Error<GetUsageServiceError>> {
+    super::block_on(historical_api::get_usage_service(configuration, params))
+}
+
+/// Like [`get_usage_service`], but also returns the status and headers of the response.
+pub fn get_usage_service_with_info(configuration: &Configuration, params: GetUsageServiceParams) -> Result<crate::apis::Response<crate::models::HistoricalUsageServiceResponse>, Error<GetUsageServiceError>> {
+    super::block_on(historical_api::get_usage_service_with_info(configuration, params))
+}
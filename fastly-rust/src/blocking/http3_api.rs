@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{http3_api, Error};
+
+pub use crate::apis::http3_api::{CreateHttp3Error, CreateHttp3Params, DeleteHttp3Error, DeleteHttp3Params, GetHttp3Error, GetHttp3Params};
+
+/// Enable HTTP/3 (QUIC) support for a particular service and version.
+pub fn create_http3(configuration: &Configuration, params: CreateHttp3Params) -> Result<crate::models::Http3, Error<CreateHttp3Error>> {
+    super::block_on(http3_api::create_http3(configuration, params))
+}
+
+/// Like [`create_http3`], but also returns the status and headers of the response.
+pub fn create_http3_with_info(configuration: &Configuration, params: CreateHttp3Params) -> Result<crate::apis::Response<crate::models::Http3>, Error<CreateHttp3Error>> {
+    super::block_on(http3_api::create_http3_with_info(configuration, params))
+}
+
+/// Disable HTTP/3 (QUIC) support for a particular service and version.
+pub fn delete_http3(configuration: &Configuration, params: DeleteHttp3Params) -> Result<crate::models::InlineResponse200, Error<DeleteHttp3Error>> {
+    super::block_on(http3_api::delete_http3(configuration, params))
+}
+
+/// Like [`delete_http3`], but also returns the status and headers of the response.
+pub fn delete_http3_with_info(configuration: &Configuration, params: DeleteHttp3Params) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteHttp3Error>> {
+    super::block_on(http3_api::delete_http3_with_info(configuration, params))
+}
+
+/// Get the status of HTTP/3 (QUIC) support for a particular service and version.
+pub fn get_http3(configuration: &Configuration, params: GetHttp3Params) -> Result<crate::models::Http3, Error<GetHttp3Error>> {
+    super::block_on(http3_api::get_http3(configuration, params))
+}
+
+/// Like [`get_http3`], but also returns the status and headers of the response.
+pub fn get_http3_with_info(configuration: &Configuration, params: GetHttp3Params) -> Result<crate::apis::Response<crate::models::Http3>, Error<GetHttp3Error>> {
+    super::block_on(http3_api::get_http3_with_info(configuration, params))
+}
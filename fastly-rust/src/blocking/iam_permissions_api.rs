@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{iam_permissions_api, Error};
+
+pub use crate::apis::iam_permissions_api::{ListPermissionsError};
+
+/// List all permissions.
+pub fn list_permissions(configuration: &Configuration) -> Result<serde_json::Value, Error<ListPermissionsError>> {
+    super::block_on(iam_permissions_api::list_permissions(configuration))
+}
+
+/// Like [`list_permissions`], but also returns the status and headers of the response.
+pub fn list_permissions_with_info(configuration: &Configuration) -> Result<crate::apis::Response<serde_json::Value>, Error<ListPermissionsError>> {
+    super::block_on(iam_permissions_api::list_permissions_with_info(configuration))
+}
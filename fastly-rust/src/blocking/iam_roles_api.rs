@@ -0,0 +1,91 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{iam_roles_api, Error};
+
+pub use crate::apis::iam_roles_api::{AddRolePermissionsError, AddRolePermissionsParams, CreateARoleError, CreateARoleParams, DeleteARoleError, DeleteARoleParams, GetARoleError, GetARoleParams, ListRolePermissionsError, ListRolePermissionsParams, ListRolesError, ListRolesParams, RemoveRolePermissionsError, RemoveRolePermissionsParams, UpdateARoleError, UpdateARoleParams};
+
+/// Add permissions to a role.
+pub fn add_role_permissions(configuration: &Configuration, params: AddRolePermissionsParams) -> Result<serde_json::Value, Error<AddRolePermissionsError>> {
+    super::block_on(iam_roles_api::add_role_permissions(configuration, params))
+}
+
+/// Like [`add_role_permissions`], but also returns the status and headers of the response.
+pub fn add_role_permissions_with_info(configuration: &Configuration, params: AddRolePermissionsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddRolePermissionsError>> {
+    super::block_on(iam_roles_api::add_role_permissions_with_info(configuration, params))
+}
+
+/// Create a role.
+pub fn create_a_role(configuration: &Configuration, params: CreateARoleParams) -> Result<serde_json::Value, Error<CreateARoleError>> {
+    super::block_on(iam_roles_api::create_a_role(configuration, params))
+}
+
+/// Like [`create_a_role`], but also returns the status and headers of the response.
+pub fn create_a_role_with_info(configuration: &Configuration, params: CreateARoleParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateARoleError>> {
+    super::block_on(iam_roles_api::create_a_role_with_info(configuration, params))
+}
+
+/// Delete a role.
+pub fn delete_a_role(configuration: &Configuration, params: DeleteARoleParams) -> Result<(), Error<DeleteARoleError>> {
+    super::block_on(iam_roles_api::delete_a_role(configuration, params))
+}
+
+/// Like [`delete_a_role`], but also returns the status and headers of the response.
+pub fn delete_a_role_with_info(configuration: &Configuration, params: DeleteARoleParams) -> Result<crate::apis::Response<()>, Error<DeleteARoleError>> {
+    super::block_on(iam_roles_api::delete_a_role_with_info(configuration, params))
+}
+
+/// Get a role.
+pub fn get_a_role(configuration: &Configuration, params: GetARoleParams) -> Result<serde_json::Value, Error<GetARoleError>> {
+    super::block_on(iam_roles_api::get_a_role(configuration, params))
+}
+
+/// Like [`get_a_role`], but also returns the status and headers of the response.
+pub fn get_a_role_with_info(configuration: &Configuration, params: GetARoleParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetARoleError>> {
+    super::block_on(iam_roles_api::get_a_role_with_info(configuration, params))
+}
+
+/// List all permissions in a role.
+pub fn list_role_permissions(configuration: &Configuration, params: ListRolePermissionsParams) -> Result<serde_json::Value, Error<ListRolePermissionsError>> {
+    super::block_on(iam_roles_api::list_role_permissions(configuration, params))
+}
+
+/// Like [`list_role_permissions`], but also returns the status and headers of the response.
+pub fn list_role_permissions_with_info(configuration: &Configuration, params: ListRolePermissionsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListRolePermissionsError>> {
+    super::block_on(iam_roles_api::list_role_permissions_with_info(configuration, params))
+}
+
+/// List all roles.
+pub fn list_roles(configuration: &Configuration, params: ListRolesParams) -> Result<serde_json::Value, Error<ListRolesError>> {
+    super::block_on(iam_roles_api::list_roles(configuration, params))
+}
+
+/// Like [`list_roles`], but also returns the status and headers of the response.
+pub fn list_roles_with_info(configuration: &Configuration, params: ListRolesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListRolesError>> {
+    super::block_on(iam_roles_api::list_roles_with_info(configuration, params))
+}
+
+/// Remove permissions from a role.
+pub fn remove_role_permissions(configuration: &Configuration, params: RemoveRolePermissionsParams) -> Result<(), Error<RemoveRolePermissionsError>> {
+    super::block_on(iam_roles_api::remove_role_permissions(configuration, params))
+}
+
+/// Like [`remove_role_permissions`], but also returns the status and headers of the response.
+pub fn remove_role_permissions_with_info(configuration: &Configuration, params: RemoveRolePermissionsParams) -> Result<crate::apis::Response<()>, Error<RemoveRolePermissionsError>> {
+    super::block_on(iam_roles_api::remove_role_permissions_with_info(configuration, params))
+}
+
+/// Update a role.
+pub fn update_a_role(configuration: &Configuration, params: UpdateARoleParams) -> Result<serde_json::Value, Error<UpdateARoleError>> {
+    super::block_on(iam_roles_api::update_a_role(configuration, params))
+}
+
+/// Like [`update_a_role`], but also returns the status and headers of the response.
+pub fn update_a_role_with_info(configuration: &Configuration, params: UpdateARoleParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateARoleError>> {
+    super::block_on(iam_roles_api::update_a_role_with_info(configuration, params))
+}
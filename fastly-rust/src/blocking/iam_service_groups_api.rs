@@ -0,0 +1,91 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{iam_service_groups_api, Error};
+
+pub use crate::apis::iam_service_groups_api::{AddServiceGroupServicesError, AddServiceGroupServicesParams, CreateAServiceGroupError, CreateAServiceGroupParams, DeleteAServiceGroupError, DeleteAServiceGroupParams, GetAServiceGroupError, GetAServiceGroupParams, ListServiceGroupServicesError, ListServiceGroupServicesParams, ListServiceGroupsError, ListServiceGroupsParams, RemoveServiceGroupServicesError, RemoveServiceGroupServicesParams, UpdateAServiceGroupError, UpdateAServiceGroupParams};
+
+/// Add services in a service group.
+pub fn add_service_group_services(configuration: &Configuration, params: AddServiceGroupServicesParams) -> Result<serde_json::Value, Error<AddServiceGroupServicesError>> {
+    super::block_on(iam_service_groups_api::add_service_group_services(configuration, params))
+}
+
+/// Like [`add_service_group_services`], but also returns the status and headers of the response.
+pub fn add_service_group_services_with_info(configuration: &Configuration, params: AddServiceGroupServicesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddServiceGroupServicesError>> {
+    super::block_on(iam_service_groups_api::add_service_group_services_with_info(configuration, params))
+}
+
+/// Create a service group.
+pub fn create_a_service_group(configuration: &Configuration, params: CreateAServiceGroupParams) -> Result<serde_json::Value, Error<CreateAServiceGroupError>> {
+    super::block_on(iam_service_groups_api::create_a_service_group(configuration, params))
+}
+
+/// Like [`create_a_service_group`], but also returns the status and headers of the response.
+pub fn create_a_service_group_with_info(configuration: &Configuration, params: CreateAServiceGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateAServiceGroupError>> {
+    super::block_on(iam_service_groups_api::create_a_service_group_with_info(configuration, params))
+}
+
+/// Delete a service group.
+pub fn delete_a_service_group(configuration: &Configuration, params: DeleteAServiceGroupParams) -> Result<(), Error<DeleteAServiceGroupError>> {
+    super::block_on(iam_service_groups_api::delete_a_service_group(configuration, params))
+}
+
+/// Like [`delete_a_service_group`], but also returns the status and headers of the response.
+pub fn delete_a_service_group_with_info(configuration: &Configuration, params: DeleteAServiceGroupParams) -> Result<crate::apis::Response<()>, Error<DeleteAServiceGroupError>> {
+    super::block_on(iam_service_groups_api::delete_a_service_group_with_info(configuration, params))
+}
+
+/// Get a service group.
+pub fn get_a_service_group(configuration: &Configuration, params: GetAServiceGroupParams) -> Result<serde_json::Value, Error<GetAServiceGroupError>> {
+    super::block_on(iam_service_groups_api::get_a_service_group(configuration, params))
+}
+
+/// Like [`get_a_service_group`], but also returns the status and headers of the response.
+pub fn get_a_service_group_with_info(configuration: &Configuration, params: GetAServiceGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetAServiceGroupError>> {
+    super::block_on(iam_service_groups_api::get_a_service_group_with_info(configuration, params))
+}
+
+/// List services to a service group.
+pub fn list_service_group_services(configuration: &Configuration, params: ListServiceGroupServicesParams) -> Result<serde_json::Value, Error<ListServiceGroupServicesError>> {
+    super::block_on(iam_service_groups_api::list_service_group_services(configuration, params))
+}
+
+/// Like [`list_service_group_services`], but also returns the status and headers of the response.
+pub fn list_service_group_services_with_info(configuration: &Configuration, params: ListServiceGroupServicesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListServiceGroupServicesError>> {
+    super::block_on(iam_service_groups_api::list_service_group_services_with_info(configuration, params))
+}
+
+/// List all service groups.
+pub fn list_service_groups(configuration: &Configuration, params: ListServiceGroupsParams) -> Result<serde_json::Value, Error<ListServiceGroupsError>> {
+    super::block_on(iam_service_groups_api::list_service_groups(configuration, params))
+}
+
+/// Like [`list_service_groups`], but also returns the status and headers of the response.
+pub fn list_service_groups_with_info(configuration: &Configuration, params: ListServiceGroupsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListServiceGroupsError>> {
+    super::block_on(iam_service_groups_api::list_service_groups_with_info(configuration, params))
+}
+
+/// Remove services from a service group.
+pub fn remove_service_group_services(configuration: &Configuration, params: RemoveServiceGroupServicesParams) -> Result<(), Error<RemoveServiceGroupServicesError>> {
+    super::block_on(iam_service_groups_api::remove_service_group_services(configuration, params))
+}
+
+/// Like [`remove_service_group_services`], but also returns the status and headers of the response.
+pub fn remove_service_group_services_with_info(configuration: &Configuration, params: RemoveServiceGroupServicesParams) -> Result<crate::apis::Response<()>, Error<RemoveServiceGroupServicesError>> {
+    super::block_on(iam_service_groups_api::remove_service_group_services_with_info(configuration, params))
+}
+
+/// Update a service group.
+pub fn update_a_service_group(configuration: &Configuration, params: UpdateAServiceGroupParams) -> Result<serde_json::Value, Error<UpdateAServiceGroupError>> {
+    super::block_on(iam_service_groups_api::update_a_service_group(configuration, params))
+}
+
+/// Like [`update_a_service_group`], but also returns the status and headers of the response.
+pub fn update_a_service_group_with_info(configuration: &Configuration, params: UpdateAServiceGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateAServiceGroupError>> {
+    super::block_on(iam_service_groups_api::update_a_service_group_with_info(configuration, params))
+}
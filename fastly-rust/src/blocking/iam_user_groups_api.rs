@@ -0,0 +1,151 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{iam_user_groups_api, Error};
+
+pub use crate::apis::iam_user_groups_api::{AddUserGroupMembersError, AddUserGroupMembersParams, AddUserGroupRolesError, AddUserGroupRolesParams, AddUserGroupServiceGroupsError, AddUserGroupServiceGroupsParams, CreateAUserGroupError, CreateAUserGroupParams, DeleteAUserGroupError, DeleteAUserGroupParams, GetAUserGroupError, GetAUserGroupParams, ListUserGroupMembersError, ListUserGroupMembersParams, ListUserGroupRolesError, ListUserGroupRolesParams, ListUserGroupServiceGroupsError, ListUserGroupServiceGroupsParams, ListUserGroupsError, ListUserGroupsParams, RemoveUserGroupMembersError, RemoveUserGroupMembersParams, RemoveUserGroupRolesError, RemoveUserGroupRolesParams, RemoveUserGroupServiceGroupsError, RemoveUserGroupServiceGroupsParams, UpdateAUserGroupError, UpdateAUserGroupParams};
+
+/// Add members to a user group.
+pub fn add_user_group_members(configuration: &Configuration, params: AddUserGroupMembersParams) -> Result<serde_json::Value, Error<AddUserGroupMembersError>> {
+    super::block_on(iam_user_groups_api::add_user_group_members(configuration, params))
+}
+
+/// Like [`add_user_group_members`], but also returns the status and headers of the response.
+pub fn add_user_group_members_with_info(configuration: &Configuration, params: AddUserGroupMembersParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddUserGroupMembersError>> {
+    super::block_on(iam_user_groups_api::add_user_group_members_with_info(configuration, params))
+}
+
+/// Add roles to a user group.
+pub fn add_user_group_roles(configuration: &Configuration, params: AddUserGroupRolesParams) -> Result<serde_json::Value, Error<AddUserGroupRolesError>> {
+    super::block_on(iam_user_groups_api::add_user_group_roles(configuration, params))
+}
+
+/// Like [`add_user_group_roles`], but also returns the status and headers of the response.
+pub fn add_user_group_roles_with_info(configuration: &Configuration, params: AddUserGroupRolesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddUserGroupRolesError>> {
+    super::block_on(iam_user_groups_api::add_user_group_roles_with_info(configuration, params))
+}
+
+/// Add service groups to a user group.
+pub fn add_user_group_service_groups(configuration: &Configuration, params: AddUserGroupServiceGroupsParams) -> Result<serde_json::Value, Error<AddUserGroupServiceGroupsError>> {
+    super::block_on(iam_user_groups_api::add_user_group_service_groups(configuration, params))
+}
+
+/// Like [`add_user_group_service_groups`], but also returns the status and headers of the response.
+pub fn add_user_group_service_groups_with_info(configuration: &Configuration, params: AddUserGroupServiceGroupsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<AddUserGroupServiceGroupsError>> {
+    super::block_on(iam_user_groups_api::add_user_group_service_groups_with_info(configuration, params))
+}
+
+/// Create a user group.
+pub fn create_a_user_group(configuration: &Configuration, params: CreateAUserGroupParams) -> Result<serde_json::Value, Error<CreateAUserGroupError>> {
+    super::block_on(iam_user_groups_api::create_a_user_group(configuration, params))
+}
+
+/// Like [`create_a_user_group`], but also returns the status and headers of the response.
+pub fn create_a_user_group_with_info(configuration: &Configuration, params: CreateAUserGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateAUserGroupError>> {
+    super::block_on(iam_user_groups_api::create_a_user_group_with_info(configuration, params))
+}
+
+/// Delete a user group.
+pub fn delete_a_user_group(configuration: &Configuration, params: DeleteAUserGroupParams) -> Result<(), Error<DeleteAUserGroupError>> {
+    super::block_on(iam_user_groups_api::delete_a_user_group(configuration, params))
+}
+
+/// Like [`delete_a_user_group`], but also returns the status and headers of the response.
+pub fn delete_a_user_group_with_info(configuration: &Configuration, params: DeleteAUserGroupParams) -> Result<crate::apis::Response<()>, Error<DeleteAUserGroupError>> {
+    super::block_on(iam_user_groups_api::delete_a_user_group_with_info(configuration, params))
+}
+
+/// Get a user group.
+pub fn get_a_user_group(configuration: &Configuration, params: GetAUserGroupParams) -> Result<serde_json::Value, Error<GetAUserGroupError>> {
+    super::block_on(iam_user_groups_api::get_a_user_group(configuration, params))
+}
+
+/// Like [`get_a_user_group`], but also returns the status and headers of the response.
+pub fn get_a_user_group_with_info(configuration: &Configuration, params: GetAUserGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetAUserGroupError>> {
+    super::block_on(iam_user_groups_api::get_a_user_group_with_info(configuration, params))
+}
+
+/// List members of a user group.
+pub fn list_user_group_members(configuration: &Configuration, params: ListUserGroupMembersParams) -> Result<serde_json::Value, Error<ListUserGroupMembersError>> {
+    super::block_on(iam_user_groups_api::list_user_group_members(configuration, params))
+}
+
+/// Like [`list_user_group_members`], but also returns the status and headers of the response.
+pub fn list_user_group_members_with_info(configuration: &Configuration, params: ListUserGroupMembersParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListUserGroupMembersError>> {
+    super::block_on(iam_user_groups_api::list_user_group_members_with_info(configuration, params))
+}
+
+/// List roles in a user group.
+pub fn list_user_group_roles(configuration: &Configuration, params: ListUserGroupRolesParams) -> Result<serde_json::Value, Error<ListUserGroupRolesError>> {
+    super::block_on(iam_user_groups_api::list_user_group_roles(configuration, params))
+}
+
+/// Like [`list_user_group_roles`], but also returns the status and headers of the response.
+pub fn list_user_group_roles_with_info(configuration: &Configuration, params: ListUserGroupRolesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListUserGroupRolesError>> {
+    super::block_on(iam_user_groups_api::list_user_group_roles_with_info(configuration, params))
+}
+
+/// List service groups in a user group.
+pub fn list_user_group_service_groups(configuration: &Configuration, params: ListUserGroupServiceGroupsParams) -> Result<serde_json::Value, Error<ListUserGroupServiceGroupsError>> {
+    super::block_on(iam_user_groups_api::list_user_group_service_groups(configuration, params))
+}
+
+/// Like [`list_user_group_service_groups`], but also returns the status and headers of the response.
+pub fn list_user_group_service_groups_with_info(configuration: &Configuration, params: ListUserGroupServiceGroupsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListUserGroupServiceGroupsError>> {
+    super::block_on(iam_user_groups_api::list_user_group_service_groups_with_info(configuration, params))
+}
+
+/// List all user groups.
+pub fn list_user_groups(configuration: &Configuration, params: ListUserGroupsParams) -> Result<serde_json::Value, Error<ListUserGroupsError>> {
+    super::block_on(iam_user_groups_api::list_user_groups(configuration, params))
+}
+
+/// Like [`list_user_groups`], but also returns the status and headers of the response.
+pub fn list_user_groups_with_info(configuration: &Configuration, params: ListUserGroupsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListUserGroupsError>> {
+    super::block_on(iam_user_groups_api::list_user_groups_with_info(configuration, params))
+}
+
+/// Remove members of a user group
+pub fn remove_user_group_members(configuration: &Configuration, params: RemoveUserGroupMembersParams) -> Result<(), Error<RemoveUserGroupMembersError>> {
+    super::block_on(iam_user_groups_api::remove_user_group_members(configuration, params))
+}
+
+/// Like [`remove_user_group_members`], but also returns the status and headers of the response.
+pub fn remove_user_group_members_with_info(configuration: &Configuration, params: RemoveUserGroupMembersParams) -> Result<crate::apis::Response<()>, Error<RemoveUserGroupMembersError>> {
+    super::block_on(iam_user_groups_api::remove_user_group_members_with_info(configuration, params))
+}
+
+/// Remove roles from a user group.
+pub fn remove_user_group_roles(configuration: &Configuration, params: RemoveUserGroupRolesParams) -> Result<(), Error<RemoveUserGroupRolesError>> {
+    super::block_on(iam_user_groups_api::remove_user_group_roles(configuration, params))
+}
+
+/// Like [`remove_user_group_roles`], but also returns the status and headers of the response.
+pub fn remove_user_group_roles_with_info(configuration: &Configuration, params: RemoveUserGroupRolesParams) -> Result<crate::apis::Response<()>, Error<RemoveUserGroupRolesError>> {
+    super::block_on(iam_user_groups_api::remove_user_group_roles_with_info(configuration, params))
+}
+
+/// Remove service groups from a user group.
+pub fn remove_user_group_service_groups(configuration: &Configuration, params: RemoveUserGroupServiceGroupsParams) -> Result<(), Error<RemoveUserGroupServiceGroupsError>> {
+    super::block_on(iam_user_groups_api::remove_user_group_service_groups(configuration, params))
+}
+
+/// Like [`remove_user_group_service_groups`], but also returns the status and headers of the response.
+pub fn remove_user_group_service_groups_with_info(configuration: &Configuration, params: RemoveUserGroupServiceGroupsParams) -> Result<crate::apis::Response<()>, Error<RemoveUserGroupServiceGroupsError>> {
+    super::block_on(iam_user_groups_api::remove_user_group_service_groups_with_info(configuration, params))
+}
+
+/// Update a user group.
+pub fn update_a_user_group(configuration: &Configuration, params: UpdateAUserGroupParams) -> Result<serde_json::Value, Error<UpdateAUserGroupError>> {
+    super::block_on(iam_user_groups_api::update_a_user_group(configuration, params))
+}
+
+/// Like [`update_a_user_group`], but also returns the status and headers of the response.
+pub fn update_a_user_group_with_info(configuration: &Configuration, params: UpdateAUserGroupParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateAUserGroupError>> {
+    super::block_on(iam_user_groups_api::update_a_user_group_with_info(configuration, params))
+}
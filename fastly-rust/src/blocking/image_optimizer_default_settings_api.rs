@@ -0,0 +1,31 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{image_optimizer_default_settings_api, Error};
+
+pub use crate::apis::image_optimizer_default_settings_api::{GetDefaultSettingsError, GetDefaultSettingsParams, UpdateDefaultSettingsError, UpdateDefaultSettingsParams};
+
+/// Retrieve the current Image Optimizer default settings. All properties in the response will be populated. 
+pub fn get_default_settings(configuration: &Configuration, params: GetDefaultSettingsParams) -> Result<crate::models::DefaultSettingsResponse, Error<GetDefaultSettingsError>> {
+    super::block_on(image_optimizer_default_settings_api::get_default_settings(configuration, params))
+}
+
+/// Like [`get_default_settings`], but also returns the status and headers of the response.
+pub fn get_default_settings_with_info(configuration: &Configuration, params: GetDefaultSettingsParams) -> Result<crate::apis::Response<crate::models::DefaultSettingsResponse>, Error<GetDefaultSettingsError>> {
+    super::block_on(image_optimizer_default_settings_api::get_default_settings_with_info(configuration, params))
+}
+
+/// Update one or more default settings. A minimum of one property is required. The endpoint will respond with the new Image Optimizer default settings, with all properties populated. 
+pub fn update_default_settings(configuration: &Configuration, params: UpdateDefaultSettingsParams) -> Result<crate::models::DefaultSettingsResponse, Error<UpdateDefaultSettingsError>> {
+    super::block_on(image_optimizer_default_settings_api::update_default_settings(configuration, params))
+}
+
+/// Like [`update_default_settings`], but also returns the status and headers of the response.
+pub fn update_default_settings_with_info(configuration: &Configuration, params: UpdateDefaultSettingsParams) -> Result<crate::apis::Response<crate::models::DefaultSettingsResponse>, Error<UpdateDefaultSettingsError>> {
+    super::block_on(image_optimizer_default_settings_api::update_default_settings_with_info(configuration, params))
+}
@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{invitations_api, Error};
+
+pub use crate::apis::invitations_api::{CreateInvitationError, CreateInvitationParams, DeleteInvitationError, DeleteInvitationParams, ListInvitationsError, ListInvitationsParams};
+
+/// Create an invitation.
+pub fn create_invitation(configuration: &Configuration, params: CreateInvitationParams) -> Result<crate::models::InvitationResponse, Error<CreateInvitationError>> {
+    super::block_on(invitations_api::create_invitation(configuration, params))
+}
+
+/// Like [`create_invitation`], but also returns the status and headers of the response.
+pub fn create_invitation_with_info(configuration: &Configuration, params: CreateInvitationParams) -> Result<crate::apis::Response<crate::models::InvitationResponse>, Error<CreateInvitationError>> {
+    super::block_on(invitations_api::create_invitation_with_info(configuration, params))
+}
+
+/// Delete an invitation.
+pub fn delete_invitation(configuration: &Configuration, params: DeleteInvitationParams) -> Result<(), Error<DeleteInvitationError>> {
+    super::block_on(invitations_api::delete_invitation(configuration, params))
+}
+
+/// Like [`delete_invitation`], but also returns the status and headers of the response.
+pub fn delete_invitation_with_info(configuration: &Configuration, params: DeleteInvitationParams) -> Result<crate::apis::Response<()>, Error<DeleteInvitationError>> {
+    super::block_on(invitations_api::delete_invitation_with_info(configuration, params))
+}
+
+/// List all invitations.
+pub fn list_invitations(configuration: &Configuration, params: ListInvitationsParams) -> Result<crate::models::InvitationsResponse, Error<ListInvitationsError>> {
+    super::block_on(invitations_api::list_invitations(configuration, params))
+}
+
+/// Like [`list_invitations`], but also returns the status and headers of the response.
+pub fn list_invitations_with_info(configuration: &Configuration, params: ListInvitationsParams) -> Result<crate::apis::Response<crate::models::InvitationsResponse>, Error<ListInvitationsError>> {
+    super::block_on(invitations_api::list_invitations_with_info(configuration, params))
+}
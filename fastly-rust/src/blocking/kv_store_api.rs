@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{kv_store_api, Error};
+
+pub use crate::apis::kv_store_api::{CreateStoreError, CreateStoreParams, DeleteStoreError, DeleteStoreParams, GetStoreError, GetStoreParams, GetStoresError, GetStoresParams};
+
+/// Create a new KV store.
+pub fn create_store(configuration: &Configuration, params: CreateStoreParams) -> Result<crate::models::StoreResponse, Error<CreateStoreError>> {
+    super::block_on(kv_store_api::create_store(configuration, params))
+}
+
+/// Like [`create_store`], but also returns the status and headers of the response.
+pub fn create_store_with_info(configuration: &Configuration, params: CreateStoreParams) -> Result<crate::apis::Response<crate::models::StoreResponse>, Error<CreateStoreError>> {
+    super::block_on(kv_store_api::create_store_with_info(configuration, params))
+}
+
+/// A KV store must be empty before it can be deleted.  Deleting a KV store that still contains keys will result in a `409` (Conflict).
+pub fn delete_store(configuration: &Configuration, params: DeleteStoreParams) -> Result<(), Error<DeleteStoreError>> {
+    super::block_on(kv_store_api::delete_store(configuration, params))
+}
+
+/// Like [`delete_store`], but also returns the status and headers of the response.
+pub fn delete_store_with_info(configuration: &Configuration, params: DeleteStoreParams) -> Result<crate::apis::Response<()>, Error<DeleteStoreError>> {
+    super::block_on(kv_store_api::delete_store_with_info(configuration, params))
+}
+
+/// Get a KV store by ID.
+pub fn get_store(configuration: &Configuration, params: GetStoreParams) -> Result<crate::models::StoreResponse, Error<GetStoreError>> {
+    super::block_on(kv_store_api::get_store(configuration, params))
+}
+
+/// Like [`get_store`], but also returns the status and headers of the response.
+pub fn get_store_with_info(configuration: &Configuration, params: GetStoreParams) -> Result<crate::apis::Response<crate::models::StoreResponse>, Error<GetStoreError>> {
+    super::block_on(kv_store_api::get_store_with_info(configuration, params))
+}
+
+/// Get all stores for a given customer.
+pub fn get_stores(configuration: &Configuration, params: GetStoresParams) -> Result<crate::models::InlineResponse2003, Error<GetStoresError>> {
+    super::block_on(kv_store_api::get_stores(configuration, params))
+}
+
+/// Like [`get_stores`], but also returns the status and headers of the response.
+pub fn get_stores_with_info(configuration: &Configuration, params: GetStoresParams) -> Result<crate::apis::Response<crate::models::InlineResponse2003>, Error<GetStoresError>> {
+    super::block_on(kv_store_api::get_stores_with_info(configuration, params))
+}
@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{kv_store_item_api, Error};
+
+pub use crate::apis::kv_store_item_api::{DeleteKeyFromStoreError, DeleteKeyFromStoreParams, GetKeysError, GetKeysParams, GetValueForKeyError, GetValueForKeyParams, SetValueForKeyError, SetValueForKeyParams};
+
+/// Delete an item from an kv store
+pub fn delete_key_from_store(configuration: &Configuration, params: DeleteKeyFromStoreParams) -> Result<(), Error<DeleteKeyFromStoreError>> {
+    super::block_on(kv_store_item_api::delete_key_from_store(configuration, params))
+}
+
+/// Like [`delete_key_from_store`], but also returns the status and headers of the response.
+pub fn delete_key_from_store_with_info(configuration: &Configuration, params: DeleteKeyFromStoreParams) -> Result<crate::apis::Response<()>, Error<DeleteKeyFromStoreError>> {
+    super::block_on(kv_store_item_api::delete_key_from_store_with_info(configuration, params))
+}
+
+/// List the keys of all items within an kv store.
+pub fn get_keys(configuration: &Configuration, params: GetKeysParams) -> Result<crate::models::InlineResponse2004, Error<GetKeysError>> {
+    super::block_on(kv_store_item_api::get_keys(configuration, params))
+}
+
+/// Like [`get_keys`], but also returns the status and headers of the response.
+pub fn get_keys_with_info(configuration: &Configuration, params: GetKeysParams) -> Result<crate::apis::Response<crate::models::InlineResponse2004>, Error<GetKeysError>> {
+    super::block_on(kv_store_item_api::get_keys_with_info(configuration, params))
+}
+
+/// Get the value associated with a key.
+pub fn get_value_for_key(configuration: &Configuration, params: GetValueForKeyParams) -> Result<String, Error<GetValueForKeyError>> {
+    super::block_on(kv_store_item_api::get_value_for_key(configuration, params))
+}
+
+/// Like [`get_value_for_key`], but also returns the status and headers of the response.
+pub fn get_value_for_key_with_info(configuration: &Configuration, params: GetValueForKeyParams) -> Result<crate::apis::Response<String>, Error<GetValueForKeyError>> {
+    super::block_on(kv_store_item_api::get_value_for_key_with_info(configuration, params))
+}
+
+/// Set a new value for a new or existing key in an kv store.
+pub fn set_value_for_key(configuration: &Configuration, params: SetValueForKeyParams) -> Result<String, Error<SetValueForKeyError>> {
+    super::block_on(kv_store_item_api::set_value_for_key(configuration, params))
+}
+
+/// Like [`set_value_for_key`], but also returns the status and headers of the response.
+pub fn set_value_for_key_with_info(configuration: &Configuration, params: SetValueForKeyParams) -> Result<crate::apis::Response<String>, Error<SetValueForKeyError>> {
+    super::block_on(kv_store_item_api::set_value_for_key_with_info(configuration, params))
+}
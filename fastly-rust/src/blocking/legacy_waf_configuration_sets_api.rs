@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{legacy_waf_configuration_sets_api, Error};
+
+pub use crate::apis::legacy_waf_configuration_sets_api::{ListWafConfigSetsError, ListWafsConfigSetError, ListWafsConfigSetParams, UseWafConfigSetError, UseWafConfigSetParams};
+
+/// List all Configuration sets.
+pub fn list_waf_config_sets(configuration: &Configuration) -> Result<serde_json::Value, Error<ListWafConfigSetsError>> {
+    super::block_on(legacy_waf_configuration_sets_api::list_waf_config_sets(configuration))
+}
+
+/// Like [`list_waf_config_sets`], but also returns the status and headers of the response.
+pub fn list_waf_config_sets_with_info(configuration: &Configuration) -> Result<crate::apis::Response<serde_json::Value>, Error<ListWafConfigSetsError>> {
+    super::block_on(legacy_waf_configuration_sets_api::list_waf_config_sets_with_info(configuration))
+}
+
+/// List the WAF objects currently using the specified configuration set.
+pub fn list_wafs_config_set(configuration: &Configuration, params: ListWafsConfigSetParams) -> Result<serde_json::Value, Error<ListWafsConfigSetError>> {
+    super::block_on(legacy_waf_configuration_sets_api::list_wafs_config_set(configuration, params))
+}
+
+/// Like [`list_wafs_config_set`], but also returns the status and headers of the response.
+pub fn list_wafs_config_set_with_info(configuration: &Configuration, params: ListWafsConfigSetParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListWafsConfigSetError>> {
+    super::block_on(legacy_waf_configuration_sets_api::list_wafs_config_set_with_info(configuration, params))
+}
+
+/// Update one or more WAF objects to use the specified configuration set.
+pub fn use_waf_config_set(configuration: &Configuration, params: UseWafConfigSetParams) -> Result<serde_json::Value, Error<UseWafConfigSetError>> {
+    super::block_on(legacy_waf_configuration_sets_api::use_waf_config_set(configuration, params))
+}
+
+/// Like [`use_waf_config_set`], but also returns the status and headers of the response.
+pub fn use_waf_config_set_with_info(configuration: &Configuration, params: UseWafConfigSetParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UseWafConfigSetError>> {
+    super::block_on(legacy_waf_configuration_sets_api::use_waf_config_set_with_info(configuration, params))
+}
@@ -0,0 +1,91 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{legacy_waf_firewall_api, Error};
+
+pub use crate::apis::legacy_waf_firewall_api::{CreateLegacyWafFirewallServiceError, CreateLegacyWafFirewallServiceParams, DisableLegacyWafFirewallError, DisableLegacyWafFirewallParams, EnableLegacyWafFirewallError, EnableLegacyWafFirewallParams, GetLegacyWafFirewallError, GetLegacyWafFirewallParams, GetLegacyWafFirewallServiceError, GetLegacyWafFirewallServiceParams, ListLegacyWafFirewallsError, ListLegacyWafFirewallsParams, ListLegacyWafFirewallsServiceError, ListLegacyWafFirewallsServiceParams, UpdateLegacyWafFirewallServiceError, UpdateLegacyWafFirewallServiceParams};
+
+/// Create a firewall object for a particular service and version.
+pub fn create_legacy_waf_firewall_service(configuration: &Configuration, params: CreateLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<CreateLegacyWafFirewallServiceError>> {
+    super::block_on(legacy_waf_firewall_api::create_legacy_waf_firewall_service(configuration, params))
+}
+
+/// Like [`create_legacy_waf_firewall_service`], but also returns the status and headers of the response.
+pub fn create_legacy_waf_firewall_service_with_info(configuration: &Configuration, params: CreateLegacyWafFirewallServiceParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateLegacyWafFirewallServiceError>> {
+    super::block_on(legacy_waf_firewall_api::create_legacy_waf_firewall_service_with_info(configuration, params))
+}
+
+/// Disable a firewall for a particular service and version. This endpoint is intended to be used in an emergency. Disabling a firewall object for a specific service and version replaces your existing WAF ruleset with an empty ruleset. While disabled, your WAF ruleset will not be applied to your origin traffic. This endpoint is only available to users assigned the role of superuser or above. This is an asynchronous action. To check on the completion of this action, use the related link returned in the response to check on the Update Status of the action.
+pub fn disable_legacy_waf_firewall(configuration: &Configuration, params: DisableLegacyWafFirewallParams) -> Result<serde_json::Value, Error<DisableLegacyWafFirewallError>> {
+    super::block_on(legacy_waf_firewall_api::disable_legacy_waf_firewall(configuration, params))
+}
+
+/// Like [`disable_legacy_waf_firewall`], but also returns the status and headers of the response.
+pub fn disable_legacy_waf_firewall_with_info(configuration: &Configuration, params: DisableLegacyWafFirewallParams) -> Result<crate::apis::Response<serde_json::Value>, Error<DisableLegacyWafFirewallError>> {
+    super::block_on(legacy_waf_firewall_api::disable_legacy_waf_firewall_with_info(configuration, params))
+}
+
+/// Re-enable a firewall object for a particular service and version after it has been disabled. This endpoint is intended to be used in an emergency. When a firewall object is re-enabled, a newly generated WAF ruleset VCL based on the current WAF configuration is used to replace the empty ruleset. This endpoint is only available to users assigned the role of superuser or above. This is an asynchronous action. To check on the completion of this action, use the related link returned in the response to check on the Update Status of the action.
+pub fn enable_legacy_waf_firewall(configuration: &Configuration, params: EnableLegacyWafFirewallParams) -> Result<serde_json::Value, Error<EnableLegacyWafFirewallError>> {
+    super::block_on(legacy_waf_firewall_api::enable_legacy_waf_firewall(configuration, params))
+}
+
+/// Like [`enable_legacy_waf_firewall`], but also returns the status and headers of the response.
+pub fn enable_legacy_waf_firewall_with_info(configuration: &Configuration, params: EnableLegacyWafFirewallParams) -> Result<crate::apis::Response<serde_json::Value>, Error<EnableLegacyWafFirewallError>> {
+    super::block_on(legacy_waf_firewall_api::enable_legacy_waf_firewall_with_info(configuration, params))
+}
+
+/// Get a specific firewall object.
+pub fn get_legacy_waf_firewall(configuration: &Configuration, params: GetLegacyWafFirewallParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallError>> {
+    super::block_on(legacy_waf_firewall_api::get_legacy_waf_firewall(configuration, params))
+}
+
+/// Like [`get_legacy_waf_firewall`], but also returns the status and headers of the response.
+pub fn get_legacy_waf_firewall_with_info(configuration: &Configuration, params: GetLegacyWafFirewallParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafFirewallError>> {
+    super::block_on(legacy_waf_firewall_api::get_legacy_waf_firewall_with_info(configuration, params))
+}
+
+/// Get a specific firewall object.
+pub fn get_legacy_waf_firewall_service(configuration: &Configuration, params: GetLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallServiceError>> {
+    super::block_on(legacy_waf_firewall_api::get_legacy_waf_firewall_service(configuration, params))
+}
+
+/// Like [`get_legacy_waf_firewall_service`], but also returns the status and headers of the response.
+pub fn get_legacy_waf_firewall_service_with_info(configuration: &Configuration, params: GetLegacyWafFirewallServiceParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafFirewallServiceError>> {
+    super::block_on(legacy_waf_firewall_api::get_legacy_waf_firewall_service_with_info(configuration, params))
+}
+
+/// List all active firewall objects.
+pub fn list_legacy_waf_firewalls(configuration: &Configuration, params: ListLegacyWafFirewallsParams) -> Result<serde_json::Value, Error<ListLegacyWafFirewallsError>> {
+    super::block_on(legacy_waf_firewall_api::list_legacy_waf_firewalls(configuration, params))
+}
+
+/// Like [`list_legacy_waf_firewalls`], but also returns the status and headers of the response.
+pub fn list_legacy_waf_firewalls_with_info(configuration: &Configuration, params: ListLegacyWafFirewallsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListLegacyWafFirewallsError>> {
+    super::block_on(legacy_waf_firewall_api::list_legacy_waf_firewalls_with_info(configuration, params))
+}
+
+/// List all firewall objects for a particular service and version.
+pub fn list_legacy_waf_firewalls_service(configuration: &Configuration, params: ListLegacyWafFirewallsServiceParams) -> Result<serde_json::Value, Error<ListLegacyWafFirewallsServiceError>> {
+    super::block_on(legacy_waf_firewall_api::list_legacy_waf_firewalls_service(configuration, params))
+}
+
+/// Like [`list_legacy_waf_firewalls_service`], but also returns the status and headers of the response.
+pub fn list_legacy_waf_firewalls_service_with_info(configuration: &Configuration, params: ListLegacyWafFirewallsServiceParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListLegacyWafFirewallsServiceError>> {
+    super::block_on(legacy_waf_firewall_api::list_legacy_waf_firewalls_service_with_info(configuration, params))
+}
+
+/// Update a firewall object for a particular service and version. 
+pub fn update_legacy_waf_firewall_service(configuration: &Configuration, params: UpdateLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<UpdateLegacyWafFirewallServiceError>> {
+    super::block_on(legacy_waf_firewall_api::update_legacy_waf_firewall_service(configuration, params))
+}
+
+/// Like [`update_legacy_waf_firewall_service`], but also returns the status and headers of the response.
+pub fn update_legacy_waf_firewall_service_with_info(configuration: &Configuration, params: UpdateLegacyWafFirewallServiceParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateLegacyWafFirewallServiceError>> {
+    super::block_on(legacy_waf_firewall_api::update_legacy_waf_firewall_service_with_info(configuration, params))
+}
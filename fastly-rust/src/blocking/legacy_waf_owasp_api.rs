@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{legacy_waf_owasp_api, Error};
+
+pub use crate::apis::legacy_waf_owasp_api::{CreateOwaspSettingsError, CreateOwaspSettingsParams, GetOwaspSettingsError, GetOwaspSettingsParams, UpdateOwaspSettingsError, UpdateOwaspSettingsParams};
+
+/// Create an OWASP settings object for a particular service and firewall.
+pub fn create_owasp_settings(configuration: &Configuration, params: CreateOwaspSettingsParams) -> Result<serde_json::Value, Error<CreateOwaspSettingsError>> {
+    super::block_on(legacy_waf_owasp_api::create_owasp_settings(configuration, params))
+}
+
+/// Like [`create_owasp_settings`], but also returns the status and headers of the response.
+pub fn create_owasp_settings_with_info(configuration: &Configuration, params: CreateOwaspSettingsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateOwaspSettingsError>> {
+    super::block_on(legacy_waf_owasp_api::create_owasp_settings_with_info(configuration, params))
+}
+
+/// Get the OWASP settings object for a particular service and firewall.
+pub fn get_owasp_settings(configuration: &Configuration, params: GetOwaspSettingsParams) -> Result<serde_json::Value, Error<GetOwaspSettingsError>> {
+    super::block_on(legacy_waf_owasp_api::get_owasp_settings(configuration, params))
+}
+
+/// Like [`get_owasp_settings`], but also returns the status and headers of the response.
+pub fn get_owasp_settings_with_info(configuration: &Configuration, params: GetOwaspSettingsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetOwaspSettingsError>> {
+    super::block_on(legacy_waf_owasp_api::get_owasp_settings_with_info(configuration, params))
+}
+
+/// Update the OWASP settings object for a particular service and firewall.
+pub fn update_owasp_settings(configuration: &Configuration, params: UpdateOwaspSettingsParams) -> Result<serde_json::Value, Error<UpdateOwaspSettingsError>> {
+    super::block_on(legacy_waf_owasp_api::update_owasp_settings(configuration, params))
+}
+
+/// Like [`update_owasp_settings`], but also returns the status and headers of the response.
+pub fn update_owasp_settings_with_info(configuration: &Configuration, params: UpdateOwaspSettingsParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateOwaspSettingsError>> {
+    super::block_on(legacy_waf_owasp_api::update_owasp_settings_with_info(configuration, params))
+}
@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{legacy_waf_rule_api, Error};
+
+pub use crate::apis::legacy_waf_rule_api::{GetLegacyWafFirewallRuleVclError, GetLegacyWafFirewallRuleVclParams, GetLegacyWafRuleError, GetLegacyWafRuleParams, GetLegacyWafRuleVclError, GetLegacyWafRuleVclParams, ListLegacyWafRulesError, ListLegacyWafRulesParams};
+
+/// Get associated VCL for a specific rule associated with a specific firewall.
+pub fn get_legacy_waf_firewall_rule_vcl(configuration: &Configuration, params: GetLegacyWafFirewallRuleVclParams) -> Result<serde_json::Value, Error<GetLegacyWafFirewallRuleVclError>> {
+    super::block_on(legacy_waf_rule_api::get_legacy_waf_firewall_rule_vcl(configuration, params))
+}
+
+/// Like [`get_legacy_waf_firewall_rule_vcl`], but also returns the status and headers of the response.
+pub fn get_legacy_waf_firewall_rule_vcl_with_info(configuration: &Configuration, params: GetLegacyWafFirewallRuleVclParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafFirewallRuleVclError>> {
+    super::block_on(legacy_waf_rule_api::get_legacy_waf_firewall_rule_vcl_with_info(configuration, params))
+}
+
+/// Get a specific rule.
+pub fn get_legacy_waf_rule(configuration: &Configuration, params: GetLegacyWafRuleParams) -> Result<serde_json::Value, Error<GetLegacyWafRuleError>> {
+    super::block_on(legacy_waf_rule_api::get_legacy_waf_rule(configuration, params))
+}
+
+/// Like [`get_legacy_waf_rule`], but also returns the status and headers of the response.
+pub fn get_legacy_waf_rule_with_info(configuration: &Configuration, params: GetLegacyWafRuleParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafRuleError>> {
+    super::block_on(legacy_waf_rule_api::get_legacy_waf_rule_with_info(configuration, params))
+}
+
+/// Get associated VCL for a specific rule.
+pub fn get_legacy_waf_rule_vcl(configuration: &Configuration, params: GetLegacyWafRuleVclParams) -> Result<serde_json::Value, Error<GetLegacyWafRuleVclError>> {
+    super::block_on(legacy_waf_rule_api::get_legacy_waf_rule_vcl(configuration, params))
+}
+
+/// Like [`get_legacy_waf_rule_vcl`], but also returns the status and headers of the response.
+pub fn get_legacy_waf_rule_vcl_with_info(configuration: &Configuration, params: GetLegacyWafRuleVclParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetLegacyWafRuleVclError>> {
+    super::block_on(legacy_waf_rule_api::get_legacy_waf_rule_vcl_with_info(configuration, params))
+}
+
+/// List all rules in the latest configuration set.
+pub fn list_legacy_waf_rules(configuration: &Configuration, params: ListLegacyWafRulesParams) -> Result<Vec<serde_json::Value>, Error<ListLegacyWafRulesError>> {
+    super::block_on(legacy_waf_rule_api::list_legacy_waf_rules(configuration, params))
+}
+
+/// Like [`list_legacy_waf_rules`], but also returns the status and headers of the response.
+pub fn list_legacy_waf_rules_with_info(configuration: &Configuration, params: ListLegacyWafRulesParams) -> Result<crate::apis::Response<Vec<serde_json::Value>>, Error<ListLegacyWafRulesError>> {
+    super::block_on(legacy_waf_rule_api::list_legacy_waf_rules_with_info(configuration, params))
+}
@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{legacy_waf_rule_status_api, Error};
+
+pub use crate::apis::legacy_waf_rule_status_api::{GetWafFirewallRuleStatusError, GetWafFirewallRuleStatusParams, ListWafFirewallRuleStatusesError, ListWafFirewallRuleStatusesParams, UpdateWafFirewallRuleStatusError, UpdateWafFirewallRuleStatusParams, UpdateWafFirewallRuleStatusesTagError, UpdateWafFirewallRuleStatusesTagParams};
+
+/// Get a specific rule status object for a particular service, firewall, and rule.
+pub fn get_waf_firewall_rule_status(configuration: &Configuration, params: GetWafFirewallRuleStatusParams) -> Result<serde_json::Value, Error<GetWafFirewallRuleStatusError>> {
+    super::block_on(legacy_waf_rule_status_api::get_waf_firewall_rule_status(configuration, params))
+}
+
+/// Like [`get_waf_firewall_rule_status`], but also returns the status and headers of the response.
+pub fn get_waf_firewall_rule_status_with_info(configuration: &Configuration, params: GetWafFirewallRuleStatusParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetWafFirewallRuleStatusError>> {
+    super::block_on(legacy_waf_rule_status_api::get_waf_firewall_rule_status_with_info(configuration, params))
+}
+
+/// List all rule statuses for a particular service and firewall.
+pub fn list_waf_firewall_rule_statuses(configuration: &Configuration, params: ListWafFirewallRuleStatusesParams) -> Result<serde_json::Value, Error<ListWafFirewallRuleStatusesError>> {
+    super::block_on(legacy_waf_rule_status_api::list_waf_firewall_rule_statuses(configuration, params))
+}
+
+/// Like [`list_waf_firewall_rule_statuses`], but also returns the status and headers of the response.
+pub fn list_waf_firewall_rule_statuses_with_info(configuration: &Configuration, params: ListWafFirewallRuleStatusesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListWafFirewallRuleStatusesError>> {
+    super::block_on(legacy_waf_rule_status_api::list_waf_firewall_rule_statuses_with_info(configuration, params))
+}
+
+/// Update a rule status for a particular service, firewall, and rule.
+pub fn update_waf_firewall_rule_status(configuration: &Configuration, params: UpdateWafFirewallRuleStatusParams) -> Result<serde_json::Value, Error<UpdateWafFirewallRuleStatusError>> {
+    super::block_on(legacy_waf_rule_status_api::update_waf_firewall_rule_status(configuration, params))
+}
+
+/// Like [`update_waf_firewall_rule_status`], but also returns the status and headers of the response.
+pub fn update_waf_firewall_rule_status_with_info(configuration: &Configuration, params: UpdateWafFirewallRuleStatusParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateWafFirewallRuleStatusError>> {
+    super::block_on(legacy_waf_rule_status_api::update_waf_firewall_rule_status_with_info(configuration, params))
+}
+
+/// Create or update all rule statuses for a particular service and firewall, based on tag name. By default, only rule status for enabled rules (with status log or block) will be updated. To update rule statuses for disabled rules under the specified tag, use the force attribute.
+pub fn update_waf_firewall_rule_statuses_tag(configuration: &Configuration, params: UpdateWafFirewallRuleStatusesTagParams) -> Result<serde_json::Value, Error<UpdateWafFirewallRuleStatusesTagError>> {
+    super::block_on(legacy_waf_rule_status_api::update_waf_firewall_rule_statuses_tag(configuration, params))
+}
+
+/// Like [`update_waf_firewall_rule_statuses_tag`], but also returns the status and headers of the response.
+pub fn update_waf_firewall_rule_statuses_tag_with_info(configuration: &Configuration, params: UpdateWafFirewallRuleStatusesTagParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateWafFirewallRuleStatusesTagError>> {
+    super::block_on(legacy_waf_rule_status_api::update_waf_firewall_rule_statuses_tag_with_info(configuration, params))
+}
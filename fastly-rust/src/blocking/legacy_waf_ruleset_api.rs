@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{legacy_waf_ruleset_api, Error};
+
+pub use crate::apis::legacy_waf_ruleset_api::{GetWafRulesetError, GetWafRulesetParams, GetWafRulesetVclError, GetWafRulesetVclParams, UpdateWafRulesetError, UpdateWafRulesetParams};
+
+/// Get a WAF ruleset for a particular service and firewall object.
+pub fn get_waf_ruleset(configuration: &Configuration, params: GetWafRulesetParams) -> Result<serde_json::Value, Error<GetWafRulesetError>> {
+    super::block_on(legacy_waf_ruleset_api::get_waf_ruleset(configuration, params))
+}
+
+/// Like [`get_waf_ruleset`], but also returns the status and headers of the response.
+pub fn get_waf_ruleset_with_info(configuration: &Configuration, params: GetWafRulesetParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetWafRulesetError>> {
+    super::block_on(legacy_waf_ruleset_api::get_waf_ruleset_with_info(configuration, params))
+}
+
+/// Get a preview of the WAF ruleset VCL for a particular service and firewall object based on changes to WAF configuration before deploying the ruleset. The response will include a link to status of the background VCL generation job. Once the background job is completed, the preview WAF ruleset VCL can be retrieved from the status response.
+pub fn get_waf_ruleset_vcl(configuration: &Configuration, params: GetWafRulesetVclParams) -> Result<serde_json::Value, Error<GetWafRulesetVclError>> {
+    super::block_on(legacy_waf_ruleset_api::get_waf_ruleset_vcl(configuration, params))
+}
+
+/// Like [`get_waf_ruleset_vcl`], but also returns the status and headers of the response.
+pub fn get_waf_ruleset_vcl_with_info(configuration: &Configuration, params: GetWafRulesetVclParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetWafRulesetVclError>> {
+    super::block_on(legacy_waf_ruleset_api::get_waf_ruleset_vcl_with_info(configuration, params))
+}
+
+/// Update the WAF ruleset for a particular service and firewall object. Use the URL in the response to view the WAF ruleset deploy status.
+pub fn update_waf_ruleset(configuration: &Configuration, params: UpdateWafRulesetParams) -> Result<serde_json::Value, Error<UpdateWafRulesetError>> {
+    super::block_on(legacy_waf_ruleset_api::update_waf_ruleset(configuration, params))
+}
+
+/// Like [`update_waf_ruleset`], but also returns the status and headers of the response.
+pub fn update_waf_ruleset_with_info(configuration: &Configuration, params: UpdateWafRulesetParams) -> Result<crate::apis::Response<serde_json::Value>, Error<UpdateWafRulesetError>> {
+    super::block_on(legacy_waf_ruleset_api::update_waf_ruleset_with_info(configuration, params))
+}
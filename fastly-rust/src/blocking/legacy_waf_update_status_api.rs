@@ -0,0 +1,31 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{legacy_waf_update_status_api, Error};
+
+pub use crate::apis::legacy_waf_update_status_api::{GetWafUpdateStatusError, GetWafUpdateStatusParams, ListWafUpdateStatusesError, ListWafUpdateStatusesParams};
+
+/// Get a specific update status object for a particular service and firewall object.
+pub fn get_waf_update_status(configuration: &Configuration, params: GetWafUpdateStatusParams) -> Result<serde_json::Value, Error<GetWafUpdateStatusError>> {
+    super::block_on(legacy_waf_update_status_api::get_waf_update_status(configuration, params))
+}
+
+/// Like [`get_waf_update_status`], but also returns the status and headers of the response.
+pub fn get_waf_update_status_with_info(configuration: &Configuration, params: GetWafUpdateStatusParams) -> Result<crate::apis::Response<serde_json::Value>, Error<GetWafUpdateStatusError>> {
+    super::block_on(legacy_waf_update_status_api::get_waf_update_status_with_info(configuration, params))
+}
+
+/// List all update statuses for a particular service and firewall object.
+pub fn list_waf_update_statuses(configuration: &Configuration, params: ListWafUpdateStatusesParams) -> Result<serde_json::Value, Error<ListWafUpdateStatusesError>> {
+    super::block_on(legacy_waf_update_status_api::list_waf_update_statuses(configuration, params))
+}
+
+/// Like [`list_waf_update_statuses`], but also returns the status and headers of the response.
+pub fn list_waf_update_statuses_with_info(configuration: &Configuration, params: ListWafUpdateStatusesParams) -> Result<crate::apis::Response<serde_json::Value>, Error<ListWafUpdateStatusesError>> {
+    super::block_on(legacy_waf_update_status_api::list_waf_update_statuses_with_info(configuration, params))
+}
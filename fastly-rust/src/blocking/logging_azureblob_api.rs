@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_azureblob_api, Error};
+
+pub use crate::apis::logging_azureblob_api::{CreateLogAzureError, CreateLogAzureParams, DeleteLogAzureError, DeleteLogAzureParams, GetLogAzureError, GetLogAzureParams, ListLogAzureError, ListLogAzureParams, UpdateLogAzureError, UpdateLogAzureParams};
+
+/// Create an Azure Blob Storage logging endpoint for a particular service and version.
+pub fn create_log_azure(configuration: &Configuration, params: CreateLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<CreateLogAzureError>> {
+    super::block_on(logging_azureblob_api::create_log_azure(configuration, params))
+}
+
+/// Like [`create_log_azure`], but also returns the status and headers of the response.
+pub fn create_log_azure_with_info(configuration: &Configuration, params: CreateLogAzureParams) -> Result<crate::apis::Response<crate::models::LoggingAzureblobResponse>, Error<CreateLogAzureError>> {
+    super::block_on(logging_azureblob_api::create_log_azure_with_info(configuration, params))
+}
+
+/// Delete the Azure Blob Storage logging endpoint for a particular service and version.
+pub fn delete_log_azure(configuration: &Configuration, params: DeleteLogAzureParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogAzureError>> {
+    super::block_on(logging_azureblob_api::delete_log_azure(configuration, params))
+}
+
+/// Like [`delete_log_azure`], but also returns the status and headers of the response.
+pub fn delete_log_azure_with_info(configuration: &Configuration, params: DeleteLogAzureParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogAzureError>> {
+    super::block_on(logging_azureblob_api::delete_log_azure_with_info(configuration, params))
+}
+
+/// Get the Azure Blob Storage logging endpoint for a particular service and version.
+pub fn get_log_azure(configuration: &Configuration, params: GetLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<GetLogAzureError>> {
+    super::block_on(logging_azureblob_api::get_log_azure(configuration, params))
+}
+
+/// Like [`get_log_azure`], but also returns the status and headers of the response.
+pub fn get_log_azure_with_info(configuration: &Configuration, params: GetLogAzureParams) -> Result<crate::apis::Response<crate::models::LoggingAzureblobResponse>, Error<GetLogAzureError>> {
+    super::block_on(logging_azureblob_api::get_log_azure_with_info(configuration, params))
+}
+
+/// List all of the Azure Blob Storage logging endpoints for a particular service and version.
+pub fn list_log_azure(configuration: &Configuration, params: ListLogAzureParams) -> Result<Vec<crate::models::LoggingAzureblobResponse>, Error<ListLogAzureError>> {
+    super::block_on(logging_azureblob_api::list_log_azure(configuration, params))
+}
+
+/// Like [`list_log_azure`], but also returns the status and headers of the response.
+pub fn list_log_azure_with_info(configuration: &Configuration, params: ListLogAzureParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingAzureblobResponse>>, Error<ListLogAzureError>> {
+    super::block_on(logging_azureblob_api::list_log_azure_with_info(configuration, params))
+}
+
+/// Update the Azure Blob Storage logging endpoint for a particular service and version.
+pub fn update_log_azure(configuration: &Configuration, params: UpdateLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<UpdateLogAzureError>> {
+    super::block_on(logging_azureblob_api::update_log_azure(configuration, params))
+}
+
+/// Like [`update_log_azure`], but also returns the status and headers of the response.
+pub fn update_log_azure_with_info(configuration: &Configuration, params: UpdateLogAzureParams) -> Result<crate::apis::Response<crate::models::LoggingAzureblobResponse>, Error<UpdateLogAzureError>> {
+    super::block_on(logging_azureblob_api::update_log_azure_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_bigquery_api, Error};
+
+pub use crate::apis::logging_bigquery_api::{CreateLogBigqueryError, CreateLogBigqueryParams, DeleteLogBigqueryError, DeleteLogBigqueryParams, GetLogBigqueryError, GetLogBigqueryParams, ListLogBigqueryError, ListLogBigqueryParams, UpdateLogBigqueryError, UpdateLogBigqueryParams};
+
+/// Create a BigQuery logging object for a particular service and version.
+pub fn create_log_bigquery(configuration: &Configuration, params: CreateLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<CreateLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::create_log_bigquery(configuration, params))
+}
+
+/// Like [`create_log_bigquery`], but also returns the status and headers of the response.
+pub fn create_log_bigquery_with_info(configuration: &Configuration, params: CreateLogBigqueryParams) -> Result<crate::apis::Response<crate::models::LoggingBigqueryResponse>, Error<CreateLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::create_log_bigquery_with_info(configuration, params))
+}
+
+/// Delete a BigQuery logging object for a particular service and version.
+pub fn delete_log_bigquery(configuration: &Configuration, params: DeleteLogBigqueryParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::delete_log_bigquery(configuration, params))
+}
+
+/// Like [`delete_log_bigquery`], but also returns the status and headers of the response.
+pub fn delete_log_bigquery_with_info(configuration: &Configuration, params: DeleteLogBigqueryParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::delete_log_bigquery_with_info(configuration, params))
+}
+
+/// Get the details for a BigQuery logging object for a particular service and version.
+pub fn get_log_bigquery(configuration: &Configuration, params: GetLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<GetLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::get_log_bigquery(configuration, params))
+}
+
+/// Like [`get_log_bigquery`], but also returns the status and headers of the response.
+pub fn get_log_bigquery_with_info(configuration: &Configuration, params: GetLogBigqueryParams) -> Result<crate::apis::Response<crate::models::LoggingBigqueryResponse>, Error<GetLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::get_log_bigquery_with_info(configuration, params))
+}
+
+/// List all of the BigQuery logging objects for a particular service and version.
+pub fn list_log_bigquery(configuration: &Configuration, params: ListLogBigqueryParams) -> Result<Vec<crate::models::LoggingBigqueryResponse>, Error<ListLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::list_log_bigquery(configuration, params))
+}
+
+/// Like [`list_log_bigquery`], but also returns the status and headers of the response.
+pub fn list_log_bigquery_with_info(configuration: &Configuration, params: ListLogBigqueryParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingBigqueryResponse>>, Error<ListLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::list_log_bigquery_with_info(configuration, params))
+}
+
+/// Update a BigQuery logging object for a particular service and version.
+pub fn update_log_bigquery(configuration: &Configuration, params: UpdateLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<UpdateLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::update_log_bigquery(configuration, params))
+}
+
+/// Like [`update_log_bigquery`], but also returns the status and headers of the response.
+pub fn update_log_bigquery_with_info(configuration: &Configuration, params: UpdateLogBigqueryParams) -> Result<crate::apis::Response<crate::models::LoggingBigqueryResponse>, Error<UpdateLogBigqueryError>> {
+    super::block_on(logging_bigquery_api::update_log_bigquery_with_info(configuration, params))
+}
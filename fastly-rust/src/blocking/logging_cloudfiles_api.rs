@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_cloudfiles_api, Error};
+
+pub use crate::apis::logging_cloudfiles_api::{CreateLogCloudfilesError, CreateLogCloudfilesParams, DeleteLogCloudfilesError, DeleteLogCloudfilesParams, GetLogCloudfilesError, GetLogCloudfilesParams, ListLogCloudfilesError, ListLogCloudfilesParams, UpdateLogCloudfilesError, UpdateLogCloudfilesParams};
+
+/// Create a Cloud Files log endpoint for a particular service and version.
+pub fn create_log_cloudfiles(configuration: &Configuration, params: CreateLogCloudfilesParams) -> Result<crate::models::LoggingCloudfilesResponse, Error<CreateLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::create_log_cloudfiles(configuration, params))
+}
+
+/// Like [`create_log_cloudfiles`], but also returns the status and headers of the response.
+pub fn create_log_cloudfiles_with_info(configuration: &Configuration, params: CreateLogCloudfilesParams) -> Result<crate::apis::Response<crate::models::LoggingCloudfilesResponse>, Error<CreateLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::create_log_cloudfiles_with_info(configuration, params))
+}
+
+/// Delete the Cloud Files log endpoint for a particular service and version.
+pub fn delete_log_cloudfiles(configuration: &Configuration, params: DeleteLogCloudfilesParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::delete_log_cloudfiles(configuration, params))
+}
+
+/// Like [`delete_log_cloudfiles`], but also returns the status and headers of the response.
+pub fn delete_log_cloudfiles_with_info(configuration: &Configuration, params: DeleteLogCloudfilesParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::delete_log_cloudfiles_with_info(configuration, params))
+}
+
+/// Get the Cloud Files log endpoint for a particular service and version.
+pub fn get_log_cloudfiles(configuration: &Configuration, params: GetLogCloudfilesParams) -> Result<crate::models::LoggingCloudfilesResponse, Error<GetLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::get_log_cloudfiles(configuration, params))
+}
+
+/// Like [`get_log_cloudfiles`], but also returns the status and headers of the response.
+pub fn get_log_cloudfiles_with_info(configuration: &Configuration, params: GetLogCloudfilesParams) -> Result<crate::apis::Response<crate::models::LoggingCloudfilesResponse>, Error<GetLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::get_log_cloudfiles_with_info(configuration, params))
+}
+
+/// List all of the Cloud Files log endpoints for a particular service and version.
+pub fn list_log_cloudfiles(configuration: &Configuration, params: ListLogCloudfilesParams) -> Result<Vec<crate::models::LoggingCloudfilesResponse>, Error<ListLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::list_log_cloudfiles(configuration, params))
+}
+
+/// Like [`list_log_cloudfiles`], but also returns the status and headers of the response.
+pub fn list_log_cloudfiles_with_info(configuration: &Configuration, params: ListLogCloudfilesParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingCloudfilesResponse>>, Error<ListLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::list_log_cloudfiles_with_info(configuration, params))
+}
+
+/// Update the Cloud Files log endpoint for a particular service and version.
+pub fn update_log_cloudfiles(configuration: &Configuration, params: UpdateLogCloudfilesParams) -> Result<crate::models::LoggingCloudfilesResponse, Error<UpdateLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::update_log_cloudfiles(configuration, params))
+}
+
+/// Like [`update_log_cloudfiles`], but also returns the status and headers of the response.
+pub fn update_log_cloudfiles_with_info(configuration: &Configuration, params: UpdateLogCloudfilesParams) -> Result<crate::apis::Response<crate::models::LoggingCloudfilesResponse>, Error<UpdateLogCloudfilesError>> {
+    super::block_on(logging_cloudfiles_api::update_log_cloudfiles_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_datadog_api, Error};
+
+pub use crate::apis::logging_datadog_api::{CreateLogDatadogError, CreateLogDatadogParams, DeleteLogDatadogError, DeleteLogDatadogParams, GetLogDatadogError, GetLogDatadogParams, ListLogDatadogError, ListLogDatadogParams, UpdateLogDatadogError, UpdateLogDatadogParams};
+
+/// Create a Datadog logging object for a particular service and version.
+pub fn create_log_datadog(configuration: &Configuration, params: CreateLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<CreateLogDatadogError>> {
+    super::block_on(logging_datadog_api::create_log_datadog(configuration, params))
+}
+
+/// Like [`create_log_datadog`], but also returns the status and headers of the response.
+pub fn create_log_datadog_with_info(configuration: &Configuration, params: CreateLogDatadogParams) -> Result<crate::apis::Response<crate::models::LoggingDatadogResponse>, Error<CreateLogDatadogError>> {
+    super::block_on(logging_datadog_api::create_log_datadog_with_info(configuration, params))
+}
+
+/// Delete the Datadog logging object for a particular service and version.
+pub fn delete_log_datadog(configuration: &Configuration, params: DeleteLogDatadogParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogDatadogError>> {
+    super::block_on(logging_datadog_api::delete_log_datadog(configuration, params))
+}
+
+/// Like [`delete_log_datadog`], but also returns the status and headers of the response.
+pub fn delete_log_datadog_with_info(configuration: &Configuration, params: DeleteLogDatadogParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogDatadogError>> {
+    super::block_on(logging_datadog_api::delete_log_datadog_with_info(configuration, params))
+}
+
+/// Get the details for a Datadog logging object for a particular service and version.
+pub fn get_log_datadog(configuration: &Configuration, params: GetLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<GetLogDatadogError>> {
+    super::block_on(logging_datadog_api::get_log_datadog(configuration, params))
+}
+
+/// Like [`get_log_datadog`], but also returns the status and headers of the response.
+pub fn get_log_datadog_with_info(configuration: &Configuration, params: GetLogDatadogParams) -> Result<crate::apis::Response<crate::models::LoggingDatadogResponse>, Error<GetLogDatadogError>> {
+    super::block_on(logging_datadog_api::get_log_datadog_with_info(configuration, params))
+}
+
+/// List all of the Datadog logging objects for a particular service and version.
+pub fn list_log_datadog(configuration: &Configuration, params: ListLogDatadogParams) -> Result<Vec<crate::models::LoggingDatadogResponse>, Error<ListLogDatadogError>> {
+    super::block_on(logging_datadog_api::list_log_datadog(configuration, params))
+}
+
+/// Like [`list_log_datadog`], but also returns the status and headers of the response.
+pub fn list_log_datadog_with_info(configuration: &Configuration, params: ListLogDatadogParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingDatadogResponse>>, Error<ListLogDatadogError>> {
+    super::block_on(logging_datadog_api::list_log_datadog_with_info(configuration, params))
+}
+
+/// Update the Datadog logging object for a particular service and version.
+pub fn update_log_datadog(configuration: &Configuration, params: UpdateLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<UpdateLogDatadogError>> {
+    super::block_on(logging_datadog_api::update_log_datadog(configuration, params))
+}
+
+/// Like [`update_log_datadog`], but also returns the status and headers of the response.
+pub fn update_log_datadog_with_info(configuration: &Configuration, params: UpdateLogDatadogParams) -> Result<crate::apis::Response<crate::models::LoggingDatadogResponse>, Error<UpdateLogDatadogError>> {
+    super::block_on(logging_datadog_api::update_log_datadog_with_info(configuration, params))
+}
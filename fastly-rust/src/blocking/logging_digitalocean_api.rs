@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_digitalocean_api, Error};
+
+pub use crate::apis::logging_digitalocean_api::{CreateLogDigoceanError, CreateLogDigoceanParams, DeleteLogDigoceanError, DeleteLogDigoceanParams, GetLogDigoceanError, GetLogDigoceanParams, ListLogDigoceanError, ListLogDigoceanParams, UpdateLogDigoceanError, UpdateLogDigoceanParams};
+
+/// Create a DigitalOcean Space for a particular service and version.
+pub fn create_log_digocean(configuration: &Configuration, params: CreateLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<CreateLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::create_log_digocean(configuration, params))
+}
+
+/// Like [`create_log_digocean`], but also returns the status and headers of the response.
+pub fn create_log_digocean_with_info(configuration: &Configuration, params: CreateLogDigoceanParams) -> Result<crate::apis::Response<crate::models::LoggingDigitaloceanResponse>, Error<CreateLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::create_log_digocean_with_info(configuration, params))
+}
+
+/// Delete the DigitalOcean Space for a particular service and version.
+pub fn delete_log_digocean(configuration: &Configuration, params: DeleteLogDigoceanParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::delete_log_digocean(configuration, params))
+}
+
+/// Like [`delete_log_digocean`], but also returns the status and headers of the response.
+pub fn delete_log_digocean_with_info(configuration: &Configuration, params: DeleteLogDigoceanParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::delete_log_digocean_with_info(configuration, params))
+}
+
+/// Get the DigitalOcean Space for a particular service and version.
+pub fn get_log_digocean(configuration: &Configuration, params: GetLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<GetLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::get_log_digocean(configuration, params))
+}
+
+/// Like [`get_log_digocean`], but also returns the status and headers of the response.
+pub fn get_log_digocean_with_info(configuration: &Configuration, params: GetLogDigoceanParams) -> Result<crate::apis::Response<crate::models::LoggingDigitaloceanResponse>, Error<GetLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::get_log_digocean_with_info(configuration, params))
+}
+
+/// List all of the DigitalOcean Spaces for a particular service and version.
+pub fn list_log_digocean(configuration: &Configuration, params: ListLogDigoceanParams) -> Result<Vec<crate::models::LoggingDigitaloceanResponse>, Error<ListLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::list_log_digocean(configuration, params))
+}
+
+/// Like [`list_log_digocean`], but also returns the status and headers of the response.
+pub fn list_log_digocean_with_info(configuration: &Configuration, params: ListLogDigoceanParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingDigitaloceanResponse>>, Error<ListLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::list_log_digocean_with_info(configuration, params))
+}
+
+/// Update the DigitalOcean Space for a particular service and version.
+pub fn update_log_digocean(configuration: &Configuration, params: UpdateLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<UpdateLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::update_log_digocean(configuration, params))
+}
+
+/// Like [`update_log_digocean`], but also returns the status and headers of the response.
+pub fn update_log_digocean_with_info(configuration: &Configuration, params: UpdateLogDigoceanParams) -> Result<crate::apis::Response<crate::models::LoggingDigitaloceanResponse>, Error<UpdateLogDigoceanError>> {
+    super::block_on(logging_digitalocean_api::update_log_digocean_with_info(configuration, params))
+}
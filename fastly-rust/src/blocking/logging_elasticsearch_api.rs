@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_elasticsearch_api, Error};
+
+pub use crate::apis::logging_elasticsearch_api::{CreateLogElasticsearchError, CreateLogElasticsearchParams, DeleteLogElasticsearchError, DeleteLogElasticsearchParams, GetLogElasticsearchError, GetLogElasticsearchParams, ListLogElasticsearchError, ListLogElasticsearchParams, UpdateLogElasticsearchError, UpdateLogElasticsearchParams};
+
+/// Create a Elasticsearch logging endpoint for a particular service and version.
+pub fn create_log_elasticsearch(configuration: &Configuration, params: CreateLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<CreateLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::create_log_elasticsearch(configuration, params))
+}
+
+/// Like [`create_log_elasticsearch`], but also returns the status and headers of the response.
+pub fn create_log_elasticsearch_with_info(configuration: &Configuration, params: CreateLogElasticsearchParams) -> Result<crate::apis::Response<crate::models::LoggingElasticsearchResponse>, Error<CreateLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::create_log_elasticsearch_with_info(configuration, params))
+}
+
+/// Delete the Elasticsearch logging endpoint for a particular service and version.
+pub fn delete_log_elasticsearch(configuration: &Configuration, params: DeleteLogElasticsearchParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::delete_log_elasticsearch(configuration, params))
+}
+
+/// Like [`delete_log_elasticsearch`], but also returns the status and headers of the response.
+pub fn delete_log_elasticsearch_with_info(configuration: &Configuration, params: DeleteLogElasticsearchParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::delete_log_elasticsearch_with_info(configuration, params))
+}
+
+/// Get the Elasticsearch logging endpoint for a particular service and version.
+pub fn get_log_elasticsearch(configuration: &Configuration, params: GetLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<GetLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::get_log_elasticsearch(configuration, params))
+}
+
+/// Like [`get_log_elasticsearch`], but also returns the status and headers of the response.
+pub fn get_log_elasticsearch_with_info(configuration: &Configuration, params: GetLogElasticsearchParams) -> Result<crate::apis::Response<crate::models::LoggingElasticsearchResponse>, Error<GetLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::get_log_elasticsearch_with_info(configuration, params))
+}
+
+/// List all of the Elasticsearch logging endpoints for a particular service and version.
+pub fn list_log_elasticsearch(configuration: &Configuration, params: ListLogElasticsearchParams) -> Result<Vec<crate::models::LoggingElasticsearchResponse>, Error<ListLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::list_log_elasticsearch(configuration, params))
+}
+
+/// Like [`list_log_elasticsearch`], but also returns the status and headers of the response.
+pub fn list_log_elasticsearch_with_info(configuration: &Configuration, params: ListLogElasticsearchParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingElasticsearchResponse>>, Error<ListLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::list_log_elasticsearch_with_info(configuration, params))
+}
+
+/// Update the Elasticsearch logging endpoint for a particular service and version.
+pub fn update_log_elasticsearch(configuration: &Configuration, params: UpdateLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<UpdateLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::update_log_elasticsearch(configuration, params))
+}
+
+/// Like [`update_log_elasticsearch`], but also returns the status and headers of the response.
+pub fn update_log_elasticsearch_with_info(configuration: &Configuration, params: UpdateLogElasticsearchParams) -> Result<crate::apis::Response<crate::models::LoggingElasticsearchResponse>, Error<UpdateLogElasticsearchError>> {
+    super::block_on(logging_elasticsearch_api::update_log_elasticsearch_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_ftp_api, Error};
+
+pub use crate::apis::logging_ftp_api::{CreateLogFtpError, CreateLogFtpParams, DeleteLogFtpError, DeleteLogFtpParams, GetLogFtpError, GetLogFtpParams, ListLogFtpError, ListLogFtpParams, UpdateLogFtpError, UpdateLogFtpParams};
+
+/// Create a FTP for a particular service and version.
+pub fn create_log_ftp(configuration: &Configuration, params: CreateLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<CreateLogFtpError>> {
+    super::block_on(logging_ftp_api::create_log_ftp(configuration, params))
+}
+
+/// Like [`create_log_ftp`], but also returns the status and headers of the response.
+pub fn create_log_ftp_with_info(configuration: &Configuration, params: CreateLogFtpParams) -> Result<crate::apis::Response<crate::models::LoggingFtpResponse>, Error<CreateLogFtpError>> {
+    super::block_on(logging_ftp_api::create_log_ftp_with_info(configuration, params))
+}
+
+/// Delete the FTP for a particular service and version.
+pub fn delete_log_ftp(configuration: &Configuration, params: DeleteLogFtpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogFtpError>> {
+    super::block_on(logging_ftp_api::delete_log_ftp(configuration, params))
+}
+
+/// Like [`delete_log_ftp`], but also returns the status and headers of the response.
+pub fn delete_log_ftp_with_info(configuration: &Configuration, params: DeleteLogFtpParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogFtpError>> {
+    super::block_on(logging_ftp_api::delete_log_ftp_with_info(configuration, params))
+}
+
+/// Get the FTP for a particular service and version.
+pub fn get_log_ftp(configuration: &Configuration, params: GetLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<GetLogFtpError>> {
+    super::block_on(logging_ftp_api::get_log_ftp(configuration, params))
+}
+
+/// Like [`get_log_ftp`], but also returns the status and headers of the response.
+pub fn get_log_ftp_with_info(configuration: &Configuration, params: GetLogFtpParams) -> Result<crate::apis::Response<crate::models::LoggingFtpResponse>, Error<GetLogFtpError>> {
+    super::block_on(logging_ftp_api::get_log_ftp_with_info(configuration, params))
+}
+
+/// List all of the FTPs for a particular service and version.
+pub fn list_log_ftp(configuration: &Configuration, params: ListLogFtpParams) -> Result<Vec<crate::models::LoggingFtpResponse>, Error<ListLogFtpError>> {
+    super::block_on(logging_ftp_api::list_log_ftp(configuration, params))
+}
+
+/// Like [`list_log_ftp`], but also returns the status and headers of the response.
+pub fn list_log_ftp_with_info(configuration: &Configuration, params: ListLogFtpParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingFtpResponse>>, Error<ListLogFtpError>> {
+    super::block_on(logging_ftp_api::list_log_ftp_with_info(configuration, params))
+}
+
+/// Update the FTP for a particular service and version.
+pub fn update_log_ftp(configuration: &Configuration, params: UpdateLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<UpdateLogFtpError>> {
+    super::block_on(logging_ftp_api::update_log_ftp(configuration, params))
+}
+
+/// Like [`update_log_ftp`], but also returns the status and headers of the response.
+pub fn update_log_ftp_with_info(configuration: &Configuration, params: UpdateLogFtpParams) -> Result<crate::apis::Response<crate::models::LoggingFtpResponse>, Error<UpdateLogFtpError>> {
+    super::block_on(logging_ftp_api::update_log_ftp_with_info(configuration, params))
+}
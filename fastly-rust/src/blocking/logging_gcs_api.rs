@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_gcs_api, Error};
+
+pub use crate::apis::logging_gcs_api::{CreateLogGcsError, CreateLogGcsParams, DeleteLogGcsError, DeleteLogGcsParams, GetLogGcsError, GetLogGcsParams, ListLogGcsError, ListLogGcsParams, UpdateLogGcsError, UpdateLogGcsParams};
+
+/// Create GCS logging for a particular service and version.
+pub fn create_log_gcs(configuration: &Configuration, params: CreateLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<CreateLogGcsError>> {
+    super::block_on(logging_gcs_api::create_log_gcs(configuration, params))
+}
+
+/// Like [`create_log_gcs`], but also returns the status and headers of the response.
+pub fn create_log_gcs_with_info(configuration: &Configuration, params: CreateLogGcsParams) -> Result<crate::apis::Response<crate::models::LoggingGcsResponse>, Error<CreateLogGcsError>> {
+    super::block_on(logging_gcs_api::create_log_gcs_with_info(configuration, params))
+}
+
+/// Delete the GCS Logging for a particular service and version.
+pub fn delete_log_gcs(configuration: &Configuration, params: DeleteLogGcsParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogGcsError>> {
+    super::block_on(logging_gcs_api::delete_log_gcs(configuration, params))
+}
+
+/// Like [`delete_log_gcs`], but also returns the status and headers of the response.
+pub fn delete_log_gcs_with_info(configuration: &Configuration, params: DeleteLogGcsParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogGcsError>> {
+    super::block_on(logging_gcs_api::delete_log_gcs_with_info(configuration, params))
+}
+
+/// Get the GCS Logging for a particular service and version.
+pub fn get_log_gcs(configuration: &Configuration, params: GetLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<GetLogGcsError>> {
+    super::block_on(logging_gcs_api::get_log_gcs(configuration, params))
+}
+
+/// Like [`get_log_gcs`], but also returns the status and headers of the response.
+pub fn get_log_gcs_with_info(configuration: &Configuration, params: GetLogGcsParams) -> Result<crate::apis::Response<crate::models::LoggingGcsResponse>, Error<GetLogGcsError>> {
+    super::block_on(logging_gcs_api::get_log_gcs_with_info(configuration, params))
+}
+
+/// List all of the GCS log endpoints for a particular service and version.
+pub fn list_log_gcs(configuration: &Configuration, params: ListLogGcsParams) -> Result<Vec<crate::models::LoggingGcsResponse>, Error<ListLogGcsError>> {
+    super::block_on(logging_gcs_api::list_log_gcs(configuration, params))
+}
+
+/// Like [`list_log_gcs`], but also returns the status and headers of the response.
+pub fn list_log_gcs_with_info(configuration: &Configuration, params: ListLogGcsParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingGcsResponse>>, Error<ListLogGcsError>> {
+    super::block_on(logging_gcs_api::list_log_gcs_with_info(configuration, params))
+}
+
+/// Update the GCS for a particular service and version.
+pub fn update_log_gcs(configuration: &Configuration, params: UpdateLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<UpdateLogGcsError>> {
+    super::block_on(logging_gcs_api::update_log_gcs(configuration, params))
+}
+
+/// Like [`update_log_gcs`], but also returns the status and headers of the response.
+pub fn update_log_gcs_with_info(configuration: &Configuration, params: UpdateLogGcsParams) -> Result<crate::apis::Response<crate::models::LoggingGcsResponse>, Error<UpdateLogGcsError>> {
+    super::block_on(logging_gcs_api::update_log_gcs_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_heroku_api, Error};
+
+pub use crate::apis::logging_heroku_api::{CreateLogHerokuError, CreateLogHerokuParams, DeleteLogHerokuError, DeleteLogHerokuParams, GetLogHerokuError, GetLogHerokuParams, ListLogHerokuError, ListLogHerokuParams, UpdateLogHerokuError, UpdateLogHerokuParams};
+
+/// Create a Heroku for a particular service and version.
+pub fn create_log_heroku(configuration: &Configuration, params: CreateLogHerokuParams) -> Result<crate::models::LoggingHerokuResponse, Error<CreateLogHerokuError>> {
+    super::block_on(logging_heroku_api::create_log_heroku(configuration, params))
+}
+
+/// Like [`create_log_heroku`], but also returns the status and headers of the response.
+pub fn create_log_heroku_with_info(configuration: &Configuration, params: CreateLogHerokuParams) -> Result<crate::apis::Response<crate::models::LoggingHerokuResponse>, Error<CreateLogHerokuError>> {
+    super::block_on(logging_heroku_api::create_log_heroku_with_info(configuration, params))
+}
+
+/// Delete the Heroku for a particular service and version.
+pub fn delete_log_heroku(configuration: &Configuration, params: DeleteLogHerokuParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogHerokuError>> {
+    super::block_on(logging_heroku_api::delete_log_heroku(configuration, params))
+}
+
+/// Like [`delete_log_heroku`], but also returns the status and headers of the response.
+pub fn delete_log_heroku_with_info(configuration: &Configuration, params: DeleteLogHerokuParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogHerokuError>> {
+    super::block_on(logging_heroku_api::delete_log_heroku_with_info(configuration, params))
+}
+
+/// Get the Heroku for a particular service and version.
+pub fn get_log_heroku(configuration: &Configuration, params: GetLogHerokuParams) -> Result<crate::models::LoggingHerokuResponse, Error<GetLogHerokuError>> {
+    super::block_on(logging_heroku_api::get_log_heroku(configuration, params))
+}
+
+/// Like [`get_log_heroku`], but also returns the status and headers of the response.
+pub fn get_log_heroku_with_info(configuration: &Configuration, params: GetLogHerokuParams) -> Result<crate::apis::Response<crate::models::LoggingHerokuResponse>, Error<GetLogHerokuError>> {
+    super::block_on(logging_heroku_api::get_log_heroku_with_info(configuration, params))
+}
+
+/// List all of the Herokus for a particular service and version.
+pub fn list_log_heroku(configuration: &Configuration, params: ListLogHerokuParams) -> Result<Vec<crate::models::LoggingHerokuResponse>, Error<ListLogHerokuError>> {
+    super::block_on(logging_heroku_api::list_log_heroku(configuration, params))
+}
+
+/// Like [`list_log_heroku`], but also returns the status and headers of the response.
+pub fn list_log_heroku_with_info(configuration: &Configuration, params: ListLogHerokuParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingHerokuResponse>>, Error<ListLogHerokuError>> {
+    super::block_on(logging_heroku_api::list_log_heroku_with_info(configuration, params))
+}
+
+/// Update the Heroku for a particular service and version.
+pub fn update_log_heroku(configuration: &Configuration, params: UpdateLogHerokuParams) -> Result<crate::models::LoggingHerokuResponse, Error<UpdateLogHerokuError>> {
+    super::block_on(logging_heroku_api::update_log_heroku(configuration, params))
+}
+
+/// Like [`update_log_heroku`], but also returns the status and headers of the response.
+pub fn update_log_heroku_with_info(configuration: &Configuration, params: UpdateLogHerokuParams) -> Result<crate::apis::Response<crate::models::LoggingHerokuResponse>, Error<UpdateLogHerokuError>> {
+    super::block_on(logging_heroku_api::update_log_heroku_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_honeycomb_api, Error};
+
+pub use crate::apis::logging_honeycomb_api::{CreateLogHoneycombError, CreateLogHoneycombParams, DeleteLogHoneycombError, DeleteLogHoneycombParams, GetLogHoneycombError, GetLogHoneycombParams, ListLogHoneycombError, ListLogHoneycombParams, UpdateLogHoneycombError, UpdateLogHoneycombParams};
+
+/// Create a Honeycomb logging object for a particular service and version.
+pub fn create_log_honeycomb(configuration: &Configuration, params: CreateLogHoneycombParams) -> Result<crate::models::LoggingHoneycombResponse, Error<CreateLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::create_log_honeycomb(configuration, params))
+}
+
+/// Like [`create_log_honeycomb`], but also returns the status and headers of the response.
+pub fn create_log_honeycomb_with_info(configuration: &Configuration, params: CreateLogHoneycombParams) -> Result<crate::apis::Response<crate::models::LoggingHoneycombResponse>, Error<CreateLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::create_log_honeycomb_with_info(configuration, params))
+}
+
+/// Delete the Honeycomb logging object for a particular service and version.
+pub fn delete_log_honeycomb(configuration: &Configuration, params: DeleteLogHoneycombParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::delete_log_honeycomb(configuration, params))
+}
+
+/// Like [`delete_log_honeycomb`], but also returns the status and headers of the response.
+pub fn delete_log_honeycomb_with_info(configuration: &Configuration, params: DeleteLogHoneycombParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::delete_log_honeycomb_with_info(configuration, params))
+}
+
+/// Get the details of a Honeycomb logging object for a particular service and version.
+pub fn get_log_honeycomb(configuration: &Configuration, params: GetLogHoneycombParams) -> Result<crate::models::LoggingHoneycombResponse, Error<GetLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::get_log_honeycomb(configuration, params))
+}
+
+/// Like [`get_log_honeycomb`], but also returns the status and headers of the response.
+pub fn get_log_honeycomb_with_info(configuration: &Configuration, params: GetLogHoneycombParams) -> Result<crate::apis::Response<crate::models::LoggingHoneycombResponse>, Error<GetLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::get_log_honeycomb_with_info(configuration, params))
+}
+
+/// List all of the Honeycomb logging objects for a particular service and version.
+pub fn list_log_honeycomb(configuration: &Configuration, params: ListLogHoneycombParams) -> Result<Vec<crate::models::LoggingHoneycombResponse>, Error<ListLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::list_log_honeycomb(configuration, params))
+}
+
+/// Like [`list_log_honeycomb`], but also returns the status and headers of the response.
+pub fn list_log_honeycomb_with_info(configuration: &Configuration, params: ListLogHoneycombParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingHoneycombResponse>>, Error<ListLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::list_log_honeycomb_with_info(configuration, params))
+}
+
+/// Update a Honeycomb logging object for a particular service and version.
+pub fn update_log_honeycomb(configuration: &Configuration, params: UpdateLogHoneycombParams) -> Result<crate::models::LoggingHoneycombResponse, Error<UpdateLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::update_log_honeycomb(configuration, params))
+}
+
+/// Like [`update_log_honeycomb`], but also returns the status and headers of the response.
+pub fn update_log_honeycomb_with_info(configuration: &Configuration, params: UpdateLogHoneycombParams) -> Result<crate::apis::Response<crate::models::LoggingHoneycombResponse>, Error<UpdateLogHoneycombError>> {
+    super::block_on(logging_honeycomb_api::update_log_honeycomb_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_https_api, Error};
+
+pub use crate::apis::logging_https_api::{CreateLogHttpsError, CreateLogHttpsParams, DeleteLogHttpsError, DeleteLogHttpsParams, GetLogHttpsError, GetLogHttpsParams, ListLogHttpsError, ListLogHttpsParams, UpdateLogHttpsError, UpdateLogHttpsParams};
+
+/// Create an HTTPS object for a particular service and version.
+pub fn create_log_https(configuration: &Configuration, params: CreateLogHttpsParams) -> Result<crate::models::LoggingHttpsResponse, Error<CreateLogHttpsError>> {
+    super::block_on(logging_https_api::create_log_https(configuration, params))
+}
+
+/// Like [`create_log_https`], but also returns the status and headers of the response.
+pub fn create_log_https_with_info(configuration: &Configuration, params: CreateLogHttpsParams) -> Result<crate::apis::Response<crate::models::LoggingHttpsResponse>, Error<CreateLogHttpsError>> {
+    super::block_on(logging_https_api::create_log_https_with_info(configuration, params))
+}
+
+/// Delete the HTTPS object for a particular service and version.
+pub fn delete_log_https(configuration: &Configuration, params: DeleteLogHttpsParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogHttpsError>> {
+    super::block_on(logging_https_api::delete_log_https(configuration, params))
+}
+
+/// Like [`delete_log_https`], but also returns the status and headers of the response.
+pub fn delete_log_https_with_info(configuration: &Configuration, params: DeleteLogHttpsParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogHttpsError>> {
+    super::block_on(logging_https_api::delete_log_https_with_info(configuration, params))
+}
+
+/// Get the HTTPS object for a particular service and version.
+pub fn get_log_https(configuration: &Configuration, params: GetLogHttpsParams) -> Result<crate::models::LoggingHttpsResponse, Error<GetLogHttpsError>> {
+    super::block_on(logging_https_api::get_log_https(configuration, params))
+}
+
+/// Like [`get_log_https`], but also returns the status and headers of the response.
+pub fn get_log_https_with_info(configuration: &Configuration, params: GetLogHttpsParams) -> Result<crate::apis::Response<crate::models::LoggingHttpsResponse>, Error<GetLogHttpsError>> {
+    super::block_on(logging_https_api::get_log_https_with_info(configuration, params))
+}
+
+/// List all of the HTTPS objects for a particular service and version.
+pub fn list_log_https(configuration: &Configuration, params: ListLogHttpsParams) -> Result<Vec<crate::models::LoggingHttpsResponse>, Error<ListLogHttpsError>> {
+    super::block_on(logging_https_api::list_log_https(configuration, params))
+}
+
+/// Like [`list_log_https`], but also returns the status and headers of the response.
+pub fn list_log_https_with_info(configuration: &Configuration, params: ListLogHttpsParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingHttpsResponse>>, Error<ListLogHttpsError>> {
+    super::block_on(logging_https_api::list_log_https_with_info(configuration, params))
+}
+
+/// Update the HTTPS object for a particular service and version.
+pub fn update_log_https(configuration: &Configuration, params: UpdateLogHttpsParams) -> Result<crate::models::LoggingHttpsResponse, Error<UpdateLogHttpsError>> {
+    super::block_on(logging_https_api::update_log_https(configuration, params))
+}
+
+/// Like [`update_log_https`], but also returns the status and headers of the response.
+pub fn update_log_https_with_info(configuration: &Configuration, params: UpdateLogHttpsParams) -> Result<crate::apis::Response<crate::models::LoggingHttpsResponse>, Error<UpdateLogHttpsError>> {
+    super::block_on(logging_https_api::update_log_https_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_kafka_api, Error};
+
+pub use crate::apis::logging_kafka_api::{CreateLogKafkaError, CreateLogKafkaParams, DeleteLogKafkaError, DeleteLogKafkaParams, GetLogKafkaError, GetLogKafkaParams, ListLogKafkaError, ListLogKafkaParams, UpdateLogKafkaError, UpdateLogKafkaParams};
+
+/// Create a Kafka logging endpoint for a particular service and version.
+pub fn create_log_kafka(configuration: &Configuration, params: CreateLogKafkaParams) -> Result<crate::models::LoggingKafkaResponsePost, Error<CreateLogKafkaError>> {
+    super::block_on(logging_kafka_api::create_log_kafka(configuration, params))
+}
+
+/// Like [`create_log_kafka`], but also returns the status and headers of the response.
+pub fn create_log_kafka_with_info(configuration: &Configuration, params: CreateLogKafkaParams) -> Result<crate::apis::Response<crate::models::LoggingKafkaResponsePost>, Error<CreateLogKafkaError>> {
+    super::block_on(logging_kafka_api::create_log_kafka_with_info(configuration, params))
+}
+
+/// Delete the Kafka logging endpoint for a particular service and version.
+pub fn delete_log_kafka(configuration: &Configuration, params: DeleteLogKafkaParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogKafkaError>> {
+    super::block_on(logging_kafka_api::delete_log_kafka(configuration, params))
+}
+
+/// Like [`delete_log_kafka`], but also returns the status and headers of the response.
+pub fn delete_log_kafka_with_info(configuration: &Configuration, params: DeleteLogKafkaParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogKafkaError>> {
+    super::block_on(logging_kafka_api::delete_log_kafka_with_info(configuration, params))
+}
+
+/// Get the Kafka logging endpoint for a particular service and version.
+pub fn get_log_kafka(configuration: &Configuration, params: GetLogKafkaParams) -> Result<crate::models::LoggingKafkaResponse, Error<GetLogKafkaError>> {
+    super::block_on(logging_kafka_api::get_log_kafka(configuration, params))
+}
+
+/// Like [`get_log_kafka`], but also returns the status and headers of the response.
+pub fn get_log_kafka_with_info(configuration: &Configuration, params: GetLogKafkaParams) -> Result<crate::apis::Response<crate::models::LoggingKafkaResponse>, Error<GetLogKafkaError>> {
+    super::block_on(logging_kafka_api::get_log_kafka_with_info(configuration, params))
+}
+
+/// List all of the Kafka logging endpoints for a particular service and version.
+pub fn list_log_kafka(configuration: &Configuration, params: ListLogKafkaParams) -> Result<Vec<crate::models::LoggingKafkaResponse>, Error<ListLogKafkaError>> {
+    super::block_on(logging_kafka_api::list_log_kafka(configuration, params))
+}
+
+/// Like [`list_log_kafka`], but also returns the status and headers of the response.
+pub fn list_log_kafka_with_info(configuration: &Configuration, params: ListLogKafkaParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingKafkaResponse>>, Error<ListLogKafkaError>> {
+    super::block_on(logging_kafka_api::list_log_kafka_with_info(configuration, params))
+}
+
+/// Update the Kafka logging endpoint for a particular service and version.
+pub fn update_log_kafka(configuration: &Configuration, params: UpdateLogKafkaParams) -> Result<crate::models::LoggingKafkaResponse, Error<UpdateLogKafkaError>> {
+    super::block_on(logging_kafka_api::update_log_kafka(configuration, params))
+}
+
+/// Like [`update_log_kafka`], but also returns the status and headers of the response.
+pub fn update_log_kafka_with_info(configuration: &Configuration, params: UpdateLogKafkaParams) -> Result<crate::apis::Response<crate::models::LoggingKafkaResponse>, Error<UpdateLogKafkaError>> {
+    super::block_on(logging_kafka_api::update_log_kafka_with_info(configuration, params))
+}
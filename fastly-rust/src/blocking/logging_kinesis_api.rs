@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_kinesis_api, Error};
+
+pub use crate::apis::logging_kinesis_api::{CreateLogKinesisError, CreateLogKinesisParams, DeleteLogKinesisError, DeleteLogKinesisParams, GetLogKinesisError, GetLogKinesisParams, ListLogKinesisError, ListLogKinesisParams, UpdateLogKinesisError, UpdateLogKinesisParams};
+
+/// Create an Amazon Kinesis Data Streams logging object for a particular service and version.
+pub fn create_log_kinesis(configuration: &Configuration, params: CreateLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<CreateLogKinesisError>> {
+    super::block_on(logging_kinesis_api::create_log_kinesis(configuration, params))
+}
+
+/// Like [`create_log_kinesis`], but also returns the status and headers of the response.
+pub fn create_log_kinesis_with_info(configuration: &Configuration, params: CreateLogKinesisParams) -> Result<crate::apis::Response<crate::models::LoggingKinesisResponse>, Error<CreateLogKinesisError>> {
+    super::block_on(logging_kinesis_api::create_log_kinesis_with_info(configuration, params))
+}
+
+/// Delete an Amazon Kinesis Data Streams logging object for a particular service and version.
+pub fn delete_log_kinesis(configuration: &Configuration, params: DeleteLogKinesisParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogKinesisError>> {
+    super::block_on(logging_kinesis_api::delete_log_kinesis(configuration, params))
+}
+
+/// Like [`delete_log_kinesis`], but also returns the status and headers of the response.
+pub fn delete_log_kinesis_with_info(configuration: &Configuration, params: DeleteLogKinesisParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogKinesisError>> {
+    super::block_on(logging_kinesis_api::delete_log_kinesis_with_info(configuration, params))
+}
+
+/// Get the details for an Amazon Kinesis Data Streams logging object for a particular service and version.
+pub fn get_log_kinesis(configuration: &Configuration, params: GetLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<GetLogKinesisError>> {
+    super::block_on(logging_kinesis_api::get_log_kinesis(configuration, params))
+}
+
+/// Like [`get_log_kinesis`], but also returns the status and headers of the response.
+pub fn get_log_kinesis_with_info(configuration: &Configuration, params: GetLogKinesisParams) -> Result<crate::apis::Response<crate::models::LoggingKinesisResponse>, Error<GetLogKinesisError>> {
+    super::block_on(logging_kinesis_api::get_log_kinesis_with_info(configuration, params))
+}
+
+/// List all of the Amazon Kinesis Data Streams logging objects for a particular service and version.
+pub fn list_log_kinesis(configuration: &Configuration, params: ListLogKinesisParams) -> Result<Vec<crate::models::LoggingKinesisResponse>, Error<ListLogKinesisError>> {
+    super::block_on(logging_kinesis_api::list_log_kinesis(configuration, params))
+}
+
+/// Like [`list_log_kinesis`], but also returns the status and headers of the response.
+pub fn list_log_kinesis_with_info(configuration: &Configuration, params: ListLogKinesisParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingKinesisResponse>>, Error<ListLogKinesisError>> {
+    super::block_on(logging_kinesis_api::list_log_kinesis_with_info(configuration, params))
+}
+
+/// Update an Amazon Kinesis Data Streams logging object for a particular service and version.
+pub fn update_log_kinesis(configuration: &Configuration, params: UpdateLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<UpdateLogKinesisError>> {
+    super::block_on(logging_kinesis_api::update_log_kinesis(configuration, params))
+}
+
+/// Like [`update_log_kinesis`], but also returns the status and headers of the response.
+pub fn update_log_kinesis_with_info(configuration: &Configuration, params: UpdateLogKinesisParams) -> Result<crate::apis::Response<crate::models::LoggingKinesisResponse>, Error<UpdateLogKinesisError>> {
+    super::block_on(logging_kinesis_api::update_log_kinesis_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_logentries_api, Error};
+
+pub use crate::apis::logging_logentries_api::{CreateLogLogentriesError, CreateLogLogentriesParams, DeleteLogLogentriesError, DeleteLogLogentriesParams, GetLogLogentriesError, GetLogLogentriesParams, ListLogLogentriesError, ListLogLogentriesParams, UpdateLogLogentriesError, UpdateLogLogentriesParams};
+
+/// Create a Logentry for a particular service and version.
+pub fn create_log_logentries(configuration: &Configuration, params: CreateLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<CreateLogLogentriesError>> {
+    super::block_on(logging_logentries_api::create_log_logentries(configuration, params))
+}
+
+/// Like [`create_log_logentries`], but also returns the status and headers of the response.
+pub fn create_log_logentries_with_info(configuration: &Configuration, params: CreateLogLogentriesParams) -> Result<crate::apis::Response<crate::models::LoggingLogentriesResponse>, Error<CreateLogLogentriesError>> {
+    super::block_on(logging_logentries_api::create_log_logentries_with_info(configuration, params))
+}
+
+/// Delete the Logentry for a particular service and version.
+pub fn delete_log_logentries(configuration: &Configuration, params: DeleteLogLogentriesParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogLogentriesError>> {
+    super::block_on(logging_logentries_api::delete_log_logentries(configuration, params))
+}
+
+/// Like [`delete_log_logentries`], but also returns the status and headers of the response.
+pub fn delete_log_logentries_with_info(configuration: &Configuration, params: DeleteLogLogentriesParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogLogentriesError>> {
+    super::block_on(logging_logentries_api::delete_log_logentries_with_info(configuration, params))
+}
+
+/// Get the Logentry for a particular service and version.
+pub fn get_log_logentries(configuration: &Configuration, params: GetLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<GetLogLogentriesError>> {
+    super::block_on(logging_logentries_api::get_log_logentries(configuration, params))
+}
+
+/// Like [`get_log_logentries`], but also returns the status and headers of the response.
+pub fn get_log_logentries_with_info(configuration: &Configuration, params: GetLogLogentriesParams) -> Result<crate::apis::Response<crate::models::LoggingLogentriesResponse>, Error<GetLogLogentriesError>> {
+    super::block_on(logging_logentries_api::get_log_logentries_with_info(configuration, params))
+}
+
+/// List all of the Logentries for a particular service and version.
+pub fn list_log_logentries(configuration: &Configuration, params: ListLogLogentriesParams) -> Result<Vec<crate::models::LoggingLogentriesResponse>, Error<ListLogLogentriesError>> {
+    super::block_on(logging_logentries_api::list_log_logentries(configuration, params))
+}
+
+/// Like [`list_log_logentries`], but also returns the status and headers of the response.
+pub fn list_log_logentries_with_info(configuration: &Configuration, params: ListLogLogentriesParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingLogentriesResponse>>, Error<ListLogLogentriesError>> {
+    super::block_on(logging_logentries_api::list_log_logentries_with_info(configuration, params))
+}
+
+/// Update the Logentry for a particular service and version.
+pub fn update_log_logentries(configuration: &Configuration, params: UpdateLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<UpdateLogLogentriesError>> {
+    super::block_on(logging_logentries_api::update_log_logentries(configuration, params))
+}
+
+/// Like [`update_log_logentries`], but also returns the status and headers of the response.
+pub fn update_log_logentries_with_info(configuration: &Configuration, params: UpdateLogLogentriesParams) -> Result<crate::apis::Response<crate::models::LoggingLogentriesResponse>, Error<UpdateLogLogentriesError>> {
+    super::block_on(logging_logentries_api::update_log_logentries_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_loggly_api, Error};
+
+pub use crate::apis::logging_loggly_api::{CreateLogLogglyError, CreateLogLogglyParams, DeleteLogLogglyError, DeleteLogLogglyParams, GetLogLogglyError, GetLogLogglyParams, ListLogLogglyError, ListLogLogglyParams, UpdateLogLogglyError, UpdateLogLogglyParams};
+
+/// Create a Loggly logging object for a particular service and version.
+pub fn create_log_loggly(configuration: &Configuration, params: CreateLogLogglyParams) -> Result<crate::models::LoggingLogglyResponse, Error<CreateLogLogglyError>> {
+    super::block_on(logging_loggly_api::create_log_loggly(configuration, params))
+}
+
+/// Like [`create_log_loggly`], but also returns the status and headers of the response.
+pub fn create_log_loggly_with_info(configuration: &Configuration, params: CreateLogLogglyParams) -> Result<crate::apis::Response<crate::models::LoggingLogglyResponse>, Error<CreateLogLogglyError>> {
+    super::block_on(logging_loggly_api::create_log_loggly_with_info(configuration, params))
+}
+
+/// Delete the Loggly logging object for a particular service and version.
+pub fn delete_log_loggly(configuration: &Configuration, params: DeleteLogLogglyParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogLogglyError>> {
+    super::block_on(logging_loggly_api::delete_log_loggly(configuration, params))
+}
+
+/// Like [`delete_log_loggly`], but also returns the status and headers of the response.
+pub fn delete_log_loggly_with_info(configuration: &Configuration, params: DeleteLogLogglyParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogLogglyError>> {
+    super::block_on(logging_loggly_api::delete_log_loggly_with_info(configuration, params))
+}
+
+/// Get the Loggly logging object for a particular service and version.
+pub fn get_log_loggly(configuration: &Configuration, params: GetLogLogglyParams) -> Result<crate::models::LoggingLogglyResponse, Error<GetLogLogglyError>> {
+    super::block_on(logging_loggly_api::get_log_loggly(configuration, params))
+}
+
+/// Like [`get_log_loggly`], but also returns the status and headers of the response.
+pub fn get_log_loggly_with_info(configuration: &Configuration, params: GetLogLogglyParams) -> Result<crate::apis::Response<crate::models::LoggingLogglyResponse>, Error<GetLogLogglyError>> {
+    super::block_on(logging_loggly_api::get_log_loggly_with_info(configuration, params))
+}
+
+/// List all Loggly logging objects for a particular service and version.
+pub fn list_log_loggly(configuration: &Configuration, params: ListLogLogglyParams) -> Result<Vec<crate::models::LoggingLogglyResponse>, Error<ListLogLogglyError>> {
+    super::block_on(logging_loggly_api::list_log_loggly(configuration, params))
+}
+
+/// Like [`list_log_loggly`], but also returns the status and headers of the response.
+pub fn list_log_loggly_with_info(configuration: &Configuration, params: ListLogLogglyParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingLogglyResponse>>, Error<ListLogLogglyError>> {
+    super::block_on(logging_loggly_api::list_log_loggly_with_info(configuration, params))
+}
+
+/// Update the Loggly logging object for a particular service and version.
+pub fn update_log_loggly(configuration: &Configuration, params: UpdateLogLogglyParams) -> Result<crate::models::LoggingLogglyResponse, Error<UpdateLogLogglyError>> {
+    super::block_on(logging_loggly_api::update_log_loggly(configuration, params))
+}
+
+/// Like [`update_log_loggly`], but also returns the status and headers of the response.
+pub fn update_log_loggly_with_info(configuration: &Configuration, params: UpdateLogLogglyParams) -> Result<crate::apis::Response<crate::models::LoggingLogglyResponse>, Error<UpdateLogLogglyError>> {
+    super::block_on(logging_loggly_api::update_log_loggly_with_info(configuration, params))
+}
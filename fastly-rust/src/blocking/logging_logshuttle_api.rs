@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_logshuttle_api, Error};
+
+pub use crate::apis::logging_logshuttle_api::{CreateLogLogshuttleError, CreateLogLogshuttleParams, DeleteLogLogshuttleError, DeleteLogLogshuttleParams, GetLogLogshuttleError, GetLogLogshuttleParams, ListLogLogshuttleError, ListLogLogshuttleParams, UpdateLogLogshuttleError, UpdateLogLogshuttleParams};
+
+/// Create a Log Shuttle logging endpoint for a particular service and version.
+pub fn create_log_logshuttle(configuration: &Configuration, params: CreateLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<CreateLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::create_log_logshuttle(configuration, params))
+}
+
+/// Like [`create_log_logshuttle`], but also returns the status and headers of the response.
+pub fn create_log_logshuttle_with_info(configuration: &Configuration, params: CreateLogLogshuttleParams) -> Result<crate::apis::Response<crate::models::LoggingLogshuttleResponse>, Error<CreateLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::create_log_logshuttle_with_info(configuration, params))
+}
+
+/// Delete the Log Shuttle logging endpoint for a particular service and version.
+pub fn delete_log_logshuttle(configuration: &Configuration, params: DeleteLogLogshuttleParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::delete_log_logshuttle(configuration, params))
+}
+
+/// Like [`delete_log_logshuttle`], but also returns the status and headers of the response.
+pub fn delete_log_logshuttle_with_info(configuration: &Configuration, params: DeleteLogLogshuttleParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::delete_log_logshuttle_with_info(configuration, params))
+}
+
+/// Get the Log Shuttle logging endpoint for a particular service and version.
+pub fn get_log_logshuttle(configuration: &Configuration, params: GetLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<GetLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::get_log_logshuttle(configuration, params))
+}
+
+/// Like [`get_log_logshuttle`], but also returns the status and headers of the response.
+pub fn get_log_logshuttle_with_info(configuration: &Configuration, params: GetLogLogshuttleParams) -> Result<crate::apis::Response<crate::models::LoggingLogshuttleResponse>, Error<GetLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::get_log_logshuttle_with_info(configuration, params))
+}
+
+/// List all of the Log Shuttle logging endpoints for a particular service and version.
+pub fn list_log_logshuttle(configuration: &Configuration, params: ListLogLogshuttleParams) -> Result<Vec<crate::models::LoggingLogshuttleResponse>, Error<ListLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::list_log_logshuttle(configuration, params))
+}
+
+/// Like [`list_log_logshuttle`], but also returns the status and headers of the response.
+pub fn list_log_logshuttle_with_info(configuration: &Configuration, params: ListLogLogshuttleParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingLogshuttleResponse>>, Error<ListLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::list_log_logshuttle_with_info(configuration, params))
+}
+
+/// Update the Log Shuttle logging endpoint for a particular service and version.
+pub fn update_log_logshuttle(configuration: &Configuration, params: UpdateLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<UpdateLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::update_log_logshuttle(configuration, params))
+}
+
+/// Like [`update_log_logshuttle`], but also returns the status and headers of the response.
+pub fn update_log_logshuttle_with_info(configuration: &Configuration, params: UpdateLogLogshuttleParams) -> Result<crate::apis::Response<crate::models::LoggingLogshuttleResponse>, Error<UpdateLogLogshuttleError>> {
+    super::block_on(logging_logshuttle_api::update_log_logshuttle_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_newrelic_api, Error};
+
+pub use crate::apis::logging_newrelic_api::{CreateLogNewrelicError, CreateLogNewrelicParams, DeleteLogNewrelicError, DeleteLogNewrelicParams, GetLogNewrelicError, GetLogNewrelicParams, ListLogNewrelicError, ListLogNewrelicParams, UpdateLogNewrelicError, UpdateLogNewrelicParams};
+
+/// Create a New Relic Logs logging object for a particular service and version.
+pub fn create_log_newrelic(configuration: &Configuration, params: CreateLogNewrelicParams) -> Result<crate::models::LoggingNewrelicResponse, Error<CreateLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::create_log_newrelic(configuration, params))
+}
+
+/// Like [`create_log_newrelic`], but also returns the status and headers of the response.
+pub fn create_log_newrelic_with_info(configuration: &Configuration, params: CreateLogNewrelicParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicResponse>, Error<CreateLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::create_log_newrelic_with_info(configuration, params))
+}
+
+/// Delete the New Relic Logs logging object for a particular service and version.
+pub fn delete_log_newrelic(configuration: &Configuration, params: DeleteLogNewrelicParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::delete_log_newrelic(configuration, params))
+}
+
+/// Like [`delete_log_newrelic`], but also returns the status and headers of the response.
+pub fn delete_log_newrelic_with_info(configuration: &Configuration, params: DeleteLogNewrelicParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::delete_log_newrelic_with_info(configuration, params))
+}
+
+/// Get the details of a New Relic Logs logging object for a particular service and version.
+pub fn get_log_newrelic(configuration: &Configuration, params: GetLogNewrelicParams) -> Result<crate::models::LoggingNewrelicResponse, Error<GetLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::get_log_newrelic(configuration, params))
+}
+
+/// Like [`get_log_newrelic`], but also returns the status and headers of the response.
+pub fn get_log_newrelic_with_info(configuration: &Configuration, params: GetLogNewrelicParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicResponse>, Error<GetLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::get_log_newrelic_with_info(configuration, params))
+}
+
+/// List all of the New Relic Logs logging objects for a particular service and version.
+pub fn list_log_newrelic(configuration: &Configuration, params: ListLogNewrelicParams) -> Result<Vec<crate::models::LoggingNewrelicResponse>, Error<ListLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::list_log_newrelic(configuration, params))
+}
+
+/// Like [`list_log_newrelic`], but also returns the status and headers of the response.
+pub fn list_log_newrelic_with_info(configuration: &Configuration, params: ListLogNewrelicParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingNewrelicResponse>>, Error<ListLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::list_log_newrelic_with_info(configuration, params))
+}
+
+/// Update a New Relic Logs logging object for a particular service and version.
+pub fn update_log_newrelic(configuration: &Configuration, params: UpdateLogNewrelicParams) -> Result<crate::models::LoggingNewrelicResponse, Error<UpdateLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::update_log_newrelic(configuration, params))
+}
+
+/// Like [`update_log_newrelic`], but also returns the status and headers of the response.
+pub fn update_log_newrelic_with_info(configuration: &Configuration, params: UpdateLogNewrelicParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicResponse>, Error<UpdateLogNewrelicError>> {
+    super::block_on(logging_newrelic_api::update_log_newrelic_with_info(configuration, params))
+}
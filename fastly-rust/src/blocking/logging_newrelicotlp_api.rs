@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_newrelicotlp_api, Error};
+
+pub use crate::apis::logging_newrelicotlp_api::{CreateLogNewrelicotlpError, CreateLogNewrelicotlpParams, DeleteLogNewrelicotlpError, DeleteLogNewrelicotlpParams, GetLogNewrelicotlpError, GetLogNewrelicotlpParams, ListLogNewrelicotlpError, ListLogNewrelicotlpParams, UpdateLogNewrelicotlpError, UpdateLogNewrelicotlpParams};
+
+/// Create a New Relic OTLP logging object for a particular service and version.
+pub fn create_log_newrelicotlp(configuration: &Configuration, params: CreateLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<CreateLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::create_log_newrelicotlp(configuration, params))
+}
+
+/// Like [`create_log_newrelicotlp`], but also returns the status and headers of the response.
+pub fn create_log_newrelicotlp_with_info(configuration: &Configuration, params: CreateLogNewrelicotlpParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicotlpResponse>, Error<CreateLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::create_log_newrelicotlp_with_info(configuration, params))
+}
+
+/// Delete the New Relic OTLP logging object for a particular service and version.
+pub fn delete_log_newrelicotlp(configuration: &Configuration, params: DeleteLogNewrelicotlpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::delete_log_newrelicotlp(configuration, params))
+}
+
+/// Like [`delete_log_newrelicotlp`], but also returns the status and headers of the response.
+pub fn delete_log_newrelicotlp_with_info(configuration: &Configuration, params: DeleteLogNewrelicotlpParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::delete_log_newrelicotlp_with_info(configuration, params))
+}
+
+/// Get the details of a New Relic OTLP logging object for a particular service and version.
+pub fn get_log_newrelicotlp(configuration: &Configuration, params: GetLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<GetLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::get_log_newrelicotlp(configuration, params))
+}
+
+/// Like [`get_log_newrelicotlp`], but also returns the status and headers of the response.
+pub fn get_log_newrelicotlp_with_info(configuration: &Configuration, params: GetLogNewrelicotlpParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicotlpResponse>, Error<GetLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::get_log_newrelicotlp_with_info(configuration, params))
+}
+
+/// List all of the New Relic OTLP logging objects for a particular service and version.
+pub fn list_log_newrelicotlp(configuration: &Configuration, params: ListLogNewrelicotlpParams) -> Result<Vec<crate::models::LoggingNewrelicotlpResponse>, Error<ListLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::list_log_newrelicotlp(configuration, params))
+}
+
+/// Like [`list_log_newrelicotlp`], but also returns the status and headers of the response.
+pub fn list_log_newrelicotlp_with_info(configuration: &Configuration, params: ListLogNewrelicotlpParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingNewrelicotlpResponse>>, Error<ListLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::list_log_newrelicotlp_with_info(configuration, params))
+}
+
+/// Update a New Relic OTLP logging object for a particular service and version.
+pub fn update_log_newrelicotlp(configuration: &Configuration, params: UpdateLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<UpdateLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::update_log_newrelicotlp(configuration, params))
+}
+
+/// Like [`update_log_newrelicotlp`], but also returns the status and headers of the response.
+pub fn update_log_newrelicotlp_with_info(configuration: &Configuration, params: UpdateLogNewrelicotlpParams) -> Result<crate::apis::Response<crate::models::LoggingNewrelicotlpResponse>, Error<UpdateLogNewrelicotlpError>> {
+    super::block_on(logging_newrelicotlp_api::update_log_newrelicotlp_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_openstack_api, Error};
+
+pub use crate::apis::logging_openstack_api::{CreateLogOpenstackError, CreateLogOpenstackParams, DeleteLogOpenstackError, DeleteLogOpenstackParams, GetLogOpenstackError, GetLogOpenstackParams, ListLogOpenstackError, ListLogOpenstackParams, UpdateLogOpenstackError, UpdateLogOpenstackParams};
+
+/// Create a openstack for a particular service and version.
+pub fn create_log_openstack(configuration: &Configuration, params: CreateLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<CreateLogOpenstackError>> {
+    super::block_on(logging_openstack_api::create_log_openstack(configuration, params))
+}
+
+/// Like [`create_log_openstack`], but also returns the status and headers of the response.
+pub fn create_log_openstack_with_info(configuration: &Configuration, params: CreateLogOpenstackParams) -> Result<crate::apis::Response<crate::models::LoggingOpenstackResponse>, Error<CreateLogOpenstackError>> {
+    super::block_on(logging_openstack_api::create_log_openstack_with_info(configuration, params))
+}
+
+/// Delete the openstack for a particular service and version.
+pub fn delete_log_openstack(configuration: &Configuration, params: DeleteLogOpenstackParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogOpenstackError>> {
+    super::block_on(logging_openstack_api::delete_log_openstack(configuration, params))
+}
+
+/// Like [`delete_log_openstack`], but also returns the status and headers of the response.
+pub fn delete_log_openstack_with_info(configuration: &Configuration, params: DeleteLogOpenstackParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogOpenstackError>> {
+    super::block_on(logging_openstack_api::delete_log_openstack_with_info(configuration, params))
+}
+
+/// Get the openstack for a particular service and version.
+pub fn get_log_openstack(configuration: &Configuration, params: GetLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<GetLogOpenstackError>> {
+    super::block_on(logging_openstack_api::get_log_openstack(configuration, params))
+}
+
+/// Like [`get_log_openstack`], but also returns the status and headers of the response.
+pub fn get_log_openstack_with_info(configuration: &Configuration, params: GetLogOpenstackParams) -> Result<crate::apis::Response<crate::models::LoggingOpenstackResponse>, Error<GetLogOpenstackError>> {
+    super::block_on(logging_openstack_api::get_log_openstack_with_info(configuration, params))
+}
+
+/// List all of the openstacks for a particular service and version.
+pub fn list_log_openstack(configuration: &Configuration, params: ListLogOpenstackParams) -> Result<Vec<crate::models::LoggingOpenstackResponse>, Error<ListLogOpenstackError>> {
+    super::block_on(logging_openstack_api::list_log_openstack(configuration, params))
+}
+
+/// Like [`list_log_openstack`], but also returns the status and headers of the response.
+pub fn list_log_openstack_with_info(configuration: &Configuration, params: ListLogOpenstackParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingOpenstackResponse>>, Error<ListLogOpenstackError>> {
+    super::block_on(logging_openstack_api::list_log_openstack_with_info(configuration, params))
+}
+
+/// Update the openstack for a particular service and version.
+pub fn update_log_openstack(configuration: &Configuration, params: UpdateLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<UpdateLogOpenstackError>> {
+    super::block_on(logging_openstack_api::update_log_openstack(configuration, params))
+}
+
+/// Like [`update_log_openstack`], but also returns the status and headers of the response.
+pub fn update_log_openstack_with_info(configuration: &Configuration, params: UpdateLogOpenstackParams) -> Result<crate::apis::Response<crate::models::LoggingOpenstackResponse>, Error<UpdateLogOpenstackError>> {
+    super::block_on(logging_openstack_api::update_log_openstack_with_info(configuration, params))
+}
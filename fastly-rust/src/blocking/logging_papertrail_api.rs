@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_papertrail_api, Error};
+
+pub use crate::apis::logging_papertrail_api::{CreateLogPapertrailError, CreateLogPapertrailParams, DeleteLogPapertrailError, DeleteLogPapertrailParams, GetLogPapertrailError, GetLogPapertrailParams, ListLogPapertrailError, ListLogPapertrailParams, UpdateLogPapertrailError, UpdateLogPapertrailParams};
+
+/// Create a Papertrail for a particular service and version.
+pub fn create_log_papertrail(configuration: &Configuration, params: CreateLogPapertrailParams) -> Result<crate::models::LoggingPapertrailResponse, Error<CreateLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::create_log_papertrail(configuration, params))
+}
+
+/// Like [`create_log_papertrail`], but also returns the status and headers of the response.
+pub fn create_log_papertrail_with_info(configuration: &Configuration, params: CreateLogPapertrailParams) -> Result<crate::apis::Response<crate::models::LoggingPapertrailResponse>, Error<CreateLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::create_log_papertrail_with_info(configuration, params))
+}
+
+/// Delete the Papertrail for a particular service and version.
+pub fn delete_log_papertrail(configuration: &Configuration, params: DeleteLogPapertrailParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::delete_log_papertrail(configuration, params))
+}
+
+/// Like [`delete_log_papertrail`], but also returns the status and headers of the response.
+pub fn delete_log_papertrail_with_info(configuration: &Configuration, params: DeleteLogPapertrailParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::delete_log_papertrail_with_info(configuration, params))
+}
+
+/// Get the Papertrail for a particular service and version.
+pub fn get_log_papertrail(configuration: &Configuration, params: GetLogPapertrailParams) -> Result<crate::models::LoggingPapertrailResponse, Error<GetLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::get_log_papertrail(configuration, params))
+}
+
+/// Like [`get_log_papertrail`], but also returns the status and headers of the response.
+pub fn get_log_papertrail_with_info(configuration: &Configuration, params: GetLogPapertrailParams) -> Result<crate::apis::Response<crate::models::LoggingPapertrailResponse>, Error<GetLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::get_log_papertrail_with_info(configuration, params))
+}
+
+/// List all of the Papertrails for a particular service and version.
+pub fn list_log_papertrail(configuration: &Configuration, params: ListLogPapertrailParams) -> Result<Vec<crate::models::LoggingPapertrailResponse>, Error<ListLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::list_log_papertrail(configuration, params))
+}
+
+/// Like [`list_log_papertrail`], but also returns the status and headers of the response.
+pub fn list_log_papertrail_with_info(configuration: &Configuration, params: ListLogPapertrailParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingPapertrailResponse>>, Error<ListLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::list_log_papertrail_with_info(configuration, params))
+}
+
+/// Update the Papertrail for a particular service and version.
+pub fn update_log_papertrail(configuration: &Configuration, params: UpdateLogPapertrailParams) -> Result<crate::models::LoggingPapertrailResponse, Error<UpdateLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::update_log_papertrail(configuration, params))
+}
+
+/// Like [`update_log_papertrail`], but also returns the status and headers of the response.
+pub fn update_log_papertrail_with_info(configuration: &Configuration, params: UpdateLogPapertrailParams) -> Result<crate::apis::Response<crate::models::LoggingPapertrailResponse>, Error<UpdateLogPapertrailError>> {
+    super::block_on(logging_papertrail_api::update_log_papertrail_with_info(configuration, params))
+}
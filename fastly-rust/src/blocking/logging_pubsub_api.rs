@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_pubsub_api, Error};
+
+pub use crate::apis::logging_pubsub_api::{CreateLogGcpPubsubError, CreateLogGcpPubsubParams, DeleteLogGcpPubsubError, DeleteLogGcpPubsubParams, GetLogGcpPubsubError, GetLogGcpPubsubParams, ListLogGcpPubsubError, ListLogGcpPubsubParams, UpdateLogGcpPubsubError, UpdateLogGcpPubsubParams};
+
+/// Create a Pub/Sub logging object for a particular service and version.
+pub fn create_log_gcp_pubsub(configuration: &Configuration, params: CreateLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<CreateLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::create_log_gcp_pubsub(configuration, params))
+}
+
+/// Like [`create_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub fn create_log_gcp_pubsub_with_info(configuration: &Configuration, params: CreateLogGcpPubsubParams) -> Result<crate::apis::Response<crate::models::LoggingGooglePubsubResponse>, Error<CreateLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::create_log_gcp_pubsub_with_info(configuration, params))
+}
+
+/// Delete a Pub/Sub logging object for a particular service and version.
+pub fn delete_log_gcp_pubsub(configuration: &Configuration, params: DeleteLogGcpPubsubParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::delete_log_gcp_pubsub(configuration, params))
+}
+
+/// Like [`delete_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub fn delete_log_gcp_pubsub_with_info(configuration: &Configuration, params: DeleteLogGcpPubsubParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::delete_log_gcp_pubsub_with_info(configuration, params))
+}
+
+/// Get the details for a Pub/Sub logging object for a particular service and version.
+pub fn get_log_gcp_pubsub(configuration: &Configuration, params: GetLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<GetLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::get_log_gcp_pubsub(configuration, params))
+}
+
+/// Like [`get_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub fn get_log_gcp_pubsub_with_info(configuration: &Configuration, params: GetLogGcpPubsubParams) -> Result<crate::apis::Response<crate::models::LoggingGooglePubsubResponse>, Error<GetLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::get_log_gcp_pubsub_with_info(configuration, params))
+}
+
+/// List all of the Pub/Sub logging objects for a particular service and version.
+pub fn list_log_gcp_pubsub(configuration: &Configuration, params: ListLogGcpPubsubParams) -> Result<Vec<crate::models::LoggingGooglePubsubResponse>, Error<ListLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::list_log_gcp_pubsub(configuration, params))
+}
+
+/// Like [`list_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub fn list_log_gcp_pubsub_with_info(configuration: &Configuration, params: ListLogGcpPubsubParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingGooglePubsubResponse>>, Error<ListLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::list_log_gcp_pubsub_with_info(configuration, params))
+}
+
+/// Update a Pub/Sub logging object for a particular service and version.
+pub fn update_log_gcp_pubsub(configuration: &Configuration, params: UpdateLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<UpdateLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::update_log_gcp_pubsub(configuration, params))
+}
+
+/// Like [`update_log_gcp_pubsub`], but also returns the status and headers of the response.
+pub fn update_log_gcp_pubsub_with_info(configuration: &Configuration, params: UpdateLogGcpPubsubParams) -> Result<crate::apis::Response<crate::models::LoggingGooglePubsubResponse>, Error<UpdateLogGcpPubsubError>> {
+    super::block_on(logging_pubsub_api::update_log_gcp_pubsub_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_s3_api, Error};
+
+pub use crate::apis::logging_s3_api::{CreateLogAwsS3Error, CreateLogAwsS3Params, DeleteLogAwsS3Error, DeleteLogAwsS3Params, GetLogAwsS3Error, GetLogAwsS3Params, ListLogAwsS3Error, ListLogAwsS3Params, UpdateLogAwsS3Error, UpdateLogAwsS3Params};
+
+/// Create a S3 for a particular service and version.
+pub fn create_log_aws_s3(configuration: &Configuration, params: CreateLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<CreateLogAwsS3Error>> {
+    super::block_on(logging_s3_api::create_log_aws_s3(configuration, params))
+}
+
+/// Like [`create_log_aws_s3`], but also returns the status and headers of the response.
+pub fn create_log_aws_s3_with_info(configuration: &Configuration, params: CreateLogAwsS3Params) -> Result<crate::apis::Response<crate::models::LoggingS3Response>, Error<CreateLogAwsS3Error>> {
+    super::block_on(logging_s3_api::create_log_aws_s3_with_info(configuration, params))
+}
+
+/// Delete the S3 for a particular service and version.
+pub fn delete_log_aws_s3(configuration: &Configuration, params: DeleteLogAwsS3Params) -> Result<crate::models::InlineResponse200, Error<DeleteLogAwsS3Error>> {
+    super::block_on(logging_s3_api::delete_log_aws_s3(configuration, params))
+}
+
+/// Like [`delete_log_aws_s3`], but also returns the status and headers of the response.
+pub fn delete_log_aws_s3_with_info(configuration: &Configuration, params: DeleteLogAwsS3Params) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogAwsS3Error>> {
+    super::block_on(logging_s3_api::delete_log_aws_s3_with_info(configuration, params))
+}
+
+/// Get the S3 for a particular service and version.
+pub fn get_log_aws_s3(configuration: &Configuration, params: GetLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<GetLogAwsS3Error>> {
+    super::block_on(logging_s3_api::get_log_aws_s3(configuration, params))
+}
+
+/// Like [`get_log_aws_s3`], but also returns the status and headers of the response.
+pub fn get_log_aws_s3_with_info(configuration: &Configuration, params: GetLogAwsS3Params) -> Result<crate::apis::Response<crate::models::LoggingS3Response>, Error<GetLogAwsS3Error>> {
+    super::block_on(logging_s3_api::get_log_aws_s3_with_info(configuration, params))
+}
+
+/// List all of the S3s for a particular service and version.
+pub fn list_log_aws_s3(configuration: &Configuration, params: ListLogAwsS3Params) -> Result<Vec<crate::models::LoggingS3Response>, Error<ListLogAwsS3Error>> {
+    super::block_on(logging_s3_api::list_log_aws_s3(configuration, params))
+}
+
+/// Like [`list_log_aws_s3`], but also returns the status and headers of the response.
+pub fn list_log_aws_s3_with_info(configuration: &Configuration, params: ListLogAwsS3Params) -> Result<crate::apis::Response<Vec<crate::models::LoggingS3Response>>, Error<ListLogAwsS3Error>> {
+    super::block_on(logging_s3_api::list_log_aws_s3_with_info(configuration, params))
+}
+
+/// Update the S3 for a particular service and version.
+pub fn update_log_aws_s3(configuration: &Configuration, params: UpdateLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<UpdateLogAwsS3Error>> {
+    super::block_on(logging_s3_api::update_log_aws_s3(configuration, params))
+}
+
+/// Like [`update_log_aws_s3`], but also returns the status and headers of the response.
+pub fn update_log_aws_s3_with_info(configuration: &Configuration, params: UpdateLogAwsS3Params) -> Result<crate::apis::Response<crate::models::LoggingS3Response>, Error<UpdateLogAwsS3Error>> {
+    super::block_on(logging_s3_api::update_log_aws_s3_with_info(configuration, params))
+}
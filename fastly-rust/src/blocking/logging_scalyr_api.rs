@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_scalyr_api, Error};
+
+pub use crate::apis::logging_scalyr_api::{CreateLogScalyrError, CreateLogScalyrParams, DeleteLogScalyrError, DeleteLogScalyrParams, GetLogScalyrError, GetLogScalyrParams, ListLogScalyrError, ListLogScalyrParams, UpdateLogScalyrError, UpdateLogScalyrParams};
+
+/// Create a Scalyr for a particular service and version.
+pub fn create_log_scalyr(configuration: &Configuration, params: CreateLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<CreateLogScalyrError>> {
+    super::block_on(logging_scalyr_api::create_log_scalyr(configuration, params))
+}
+
+/// Like [`create_log_scalyr`], but also returns the status and headers of the response.
+pub fn create_log_scalyr_with_info(configuration: &Configuration, params: CreateLogScalyrParams) -> Result<crate::apis::Response<crate::models::LoggingScalyrResponse>, Error<CreateLogScalyrError>> {
+    super::block_on(logging_scalyr_api::create_log_scalyr_with_info(configuration, params))
+}
+
+/// Delete the Scalyr for a particular service and version.
+pub fn delete_log_scalyr(configuration: &Configuration, params: DeleteLogScalyrParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogScalyrError>> {
+    super::block_on(logging_scalyr_api::delete_log_scalyr(configuration, params))
+}
+
+/// Like [`delete_log_scalyr`], but also returns the status and headers of the response.
+pub fn delete_log_scalyr_with_info(configuration: &Configuration, params: DeleteLogScalyrParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogScalyrError>> {
+    super::block_on(logging_scalyr_api::delete_log_scalyr_with_info(configuration, params))
+}
+
+/// Get the Scalyr for a particular service and version.
+pub fn get_log_scalyr(configuration: &Configuration, params: GetLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<GetLogScalyrError>> {
+    super::block_on(logging_scalyr_api::get_log_scalyr(configuration, params))
+}
+
+/// Like [`get_log_scalyr`], but also returns the status and headers of the response.
+pub fn get_log_scalyr_with_info(configuration: &Configuration, params: GetLogScalyrParams) -> Result<crate::apis::Response<crate::models::LoggingScalyrResponse>, Error<GetLogScalyrError>> {
+    super::block_on(logging_scalyr_api::get_log_scalyr_with_info(configuration, params))
+}
+
+/// List all of the Scalyrs for a particular service and version.
+pub fn list_log_scalyr(configuration: &Configuration, params: ListLogScalyrParams) -> Result<Vec<crate::models::LoggingScalyrResponse>, Error<ListLogScalyrError>> {
+    super::block_on(logging_scalyr_api::list_log_scalyr(configuration, params))
+}
+
+/// Like [`list_log_scalyr`], but also returns the status and headers of the response.
+pub fn list_log_scalyr_with_info(configuration: &Configuration, params: ListLogScalyrParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingScalyrResponse>>, Error<ListLogScalyrError>> {
+    super::block_on(logging_scalyr_api::list_log_scalyr_with_info(configuration, params))
+}
+
+/// Update the Scalyr for a particular service and version.
+pub fn update_log_scalyr(configuration: &Configuration, params: UpdateLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<UpdateLogScalyrError>> {
+    super::block_on(logging_scalyr_api::update_log_scalyr(configuration, params))
+}
+
+/// Like [`update_log_scalyr`], but also returns the status and headers of the response.
+pub fn update_log_scalyr_with_info(configuration: &Configuration, params: UpdateLogScalyrParams) -> Result<crate::apis::Response<crate::models::LoggingScalyrResponse>, Error<UpdateLogScalyrError>> {
+    super::block_on(logging_scalyr_api::update_log_scalyr_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_sftp_api, Error};
+
+pub use crate::apis::logging_sftp_api::{CreateLogSftpError, CreateLogSftpParams, DeleteLogSftpError, DeleteLogSftpParams, GetLogSftpError, GetLogSftpParams, ListLogSftpError, ListLogSftpParams, UpdateLogSftpError, UpdateLogSftpParams};
+
+/// Create a SFTP for a particular service and version.
+pub fn create_log_sftp(configuration: &Configuration, params: CreateLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<CreateLogSftpError>> {
+    super::block_on(logging_sftp_api::create_log_sftp(configuration, params))
+}
+
+/// Like [`create_log_sftp`], but also returns the status and headers of the response.
+pub fn create_log_sftp_with_info(configuration: &Configuration, params: CreateLogSftpParams) -> Result<crate::apis::Response<crate::models::LoggingSftpResponse>, Error<CreateLogSftpError>> {
+    super::block_on(logging_sftp_api::create_log_sftp_with_info(configuration, params))
+}
+
+/// Delete the SFTP for a particular service and version.
+pub fn delete_log_sftp(configuration: &Configuration, params: DeleteLogSftpParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSftpError>> {
+    super::block_on(logging_sftp_api::delete_log_sftp(configuration, params))
+}
+
+/// Like [`delete_log_sftp`], but also returns the status and headers of the response.
+pub fn delete_log_sftp_with_info(configuration: &Configuration, params: DeleteLogSftpParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogSftpError>> {
+    super::block_on(logging_sftp_api::delete_log_sftp_with_info(configuration, params))
+}
+
+/// Get the SFTP for a particular service and version.
+pub fn get_log_sftp(configuration: &Configuration, params: GetLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<GetLogSftpError>> {
+    super::block_on(logging_sftp_api::get_log_sftp(configuration, params))
+}
+
+/// Like [`get_log_sftp`], but also returns the status and headers of the response.
+pub fn get_log_sftp_with_info(configuration: &Configuration, params: GetLogSftpParams) -> Result<crate::apis::Response<crate::models::LoggingSftpResponse>, Error<GetLogSftpError>> {
+    super::block_on(logging_sftp_api::get_log_sftp_with_info(configuration, params))
+}
+
+/// List all of the SFTPs for a particular service and version.
+pub fn list_log_sftp(configuration: &Configuration, params: ListLogSftpParams) -> Result<Vec<crate::models::LoggingSftpResponse>, Error<ListLogSftpError>> {
+    super::block_on(logging_sftp_api::list_log_sftp(configuration, params))
+}
+
+/// Like [`list_log_sftp`], but also returns the status and headers of the response.
+pub fn list_log_sftp_with_info(configuration: &Configuration, params: ListLogSftpParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingSftpResponse>>, Error<ListLogSftpError>> {
+    super::block_on(logging_sftp_api::list_log_sftp_with_info(configuration, params))
+}
+
+/// Update the SFTP for a particular service and version.
+pub fn update_log_sftp(configuration: &Configuration, params: UpdateLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<UpdateLogSftpError>> {
+    super::block_on(logging_sftp_api::update_log_sftp(configuration, params))
+}
+
+/// Like [`update_log_sftp`], but also returns the status and headers of the response.
+pub fn update_log_sftp_with_info(configuration: &Configuration, params: UpdateLogSftpParams) -> Result<crate::apis::Response<crate::models::LoggingSftpResponse>, Error<UpdateLogSftpError>> {
+    super::block_on(logging_sftp_api::update_log_sftp_with_info(configuration, params))
+}
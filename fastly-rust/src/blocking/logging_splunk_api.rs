@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_splunk_api, Error};
+
+pub use crate::apis::logging_splunk_api::{CreateLogSplunkError, CreateLogSplunkParams, DeleteLogSplunkError, DeleteLogSplunkParams, GetLogSplunkError, GetLogSplunkParams, ListLogSplunkError, ListLogSplunkParams, UpdateLogSplunkError, UpdateLogSplunkParams};
+
+/// Create a Splunk logging object for a particular service and version.
+pub fn create_log_splunk(configuration: &Configuration, params: CreateLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<CreateLogSplunkError>> {
+    super::block_on(logging_splunk_api::create_log_splunk(configuration, params))
+}
+
+/// Like [`create_log_splunk`], but also returns the status and headers of the response.
+pub fn create_log_splunk_with_info(configuration: &Configuration, params: CreateLogSplunkParams) -> Result<crate::apis::Response<crate::models::LoggingSplunkResponse>, Error<CreateLogSplunkError>> {
+    super::block_on(logging_splunk_api::create_log_splunk_with_info(configuration, params))
+}
+
+/// Delete the Splunk logging object for a particular service and version.
+pub fn delete_log_splunk(configuration: &Configuration, params: DeleteLogSplunkParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSplunkError>> {
+    super::block_on(logging_splunk_api::delete_log_splunk(configuration, params))
+}
+
+/// Like [`delete_log_splunk`], but also returns the status and headers of the response.
+pub fn delete_log_splunk_with_info(configuration: &Configuration, params: DeleteLogSplunkParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogSplunkError>> {
+    super::block_on(logging_splunk_api::delete_log_splunk_with_info(configuration, params))
+}
+
+/// Get the details for a Splunk logging object for a particular service and version.
+pub fn get_log_splunk(configuration: &Configuration, params: GetLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<GetLogSplunkError>> {
+    super::block_on(logging_splunk_api::get_log_splunk(configuration, params))
+}
+
+/// Like [`get_log_splunk`], but also returns the status and headers of the response.
+pub fn get_log_splunk_with_info(configuration: &Configuration, params: GetLogSplunkParams) -> Result<crate::apis::Response<crate::models::LoggingSplunkResponse>, Error<GetLogSplunkError>> {
+    super::block_on(logging_splunk_api::get_log_splunk_with_info(configuration, params))
+}
+
+/// List all of the Splunk logging objects for a particular service and version.
+pub fn list_log_splunk(configuration: &Configuration, params: ListLogSplunkParams) -> Result<Vec<crate::models::LoggingSplunkResponse>, Error<ListLogSplunkError>> {
+    super::block_on(logging_splunk_api::list_log_splunk(configuration, params))
+}
+
+/// Like [`list_log_splunk`], but also returns the status and headers of the response.
+pub fn list_log_splunk_with_info(configuration: &Configuration, params: ListLogSplunkParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingSplunkResponse>>, Error<ListLogSplunkError>> {
+    super::block_on(logging_splunk_api::list_log_splunk_with_info(configuration, params))
+}
+
+/// Update the Splunk logging object for a particular service and version.
+pub fn update_log_splunk(configuration: &Configuration, params: UpdateLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<UpdateLogSplunkError>> {
+    super::block_on(logging_splunk_api::update_log_splunk(configuration, params))
+}
+
+/// Like [`update_log_splunk`], but also returns the status and headers of the response.
+pub fn update_log_splunk_with_info(configuration: &Configuration, params: UpdateLogSplunkParams) -> Result<crate::apis::Response<crate::models::LoggingSplunkResponse>, Error<UpdateLogSplunkError>> {
+    super::block_on(logging_splunk_api::update_log_splunk_with_info(configuration, params))
+}
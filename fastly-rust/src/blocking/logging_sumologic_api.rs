@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_sumologic_api, Error};
+
+pub use crate::apis::logging_sumologic_api::{CreateLogSumologicError, CreateLogSumologicParams, DeleteLogSumologicError, DeleteLogSumologicParams, GetLogSumologicError, GetLogSumologicParams, ListLogSumologicError, ListLogSumologicParams, UpdateLogSumologicError, UpdateLogSumologicParams};
+
+/// Create a Sumologic for a particular service and version.
+pub fn create_log_sumologic(configuration: &Configuration, params: CreateLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<CreateLogSumologicError>> {
+    super::block_on(logging_sumologic_api::create_log_sumologic(configuration, params))
+}
+
+/// Like [`create_log_sumologic`], but also returns the status and headers of the response.
+pub fn create_log_sumologic_with_info(configuration: &Configuration, params: CreateLogSumologicParams) -> Result<crate::apis::Response<crate::models::LoggingSumologicResponse>, Error<CreateLogSumologicError>> {
+    super::block_on(logging_sumologic_api::create_log_sumologic_with_info(configuration, params))
+}
+
+/// Delete the Sumologic for a particular service and version.
+pub fn delete_log_sumologic(configuration: &Configuration, params: DeleteLogSumologicParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSumologicError>> {
+    super::block_on(logging_sumologic_api::delete_log_sumologic(configuration, params))
+}
+
+/// Like [`delete_log_sumologic`], but also returns the status and headers of the response.
+pub fn delete_log_sumologic_with_info(configuration: &Configuration, params: DeleteLogSumologicParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogSumologicError>> {
+    super::block_on(logging_sumologic_api::delete_log_sumologic_with_info(configuration, params))
+}
+
+/// Get the Sumologic for a particular service and version.
+pub fn get_log_sumologic(configuration: &Configuration, params: GetLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<GetLogSumologicError>> {
+    super::block_on(logging_sumologic_api::get_log_sumologic(configuration, params))
+}
+
+/// Like [`get_log_sumologic`], but also returns the status and headers of the response.
+pub fn get_log_sumologic_with_info(configuration: &Configuration, params: GetLogSumologicParams) -> Result<crate::apis::Response<crate::models::LoggingSumologicResponse>, Error<GetLogSumologicError>> {
+    super::block_on(logging_sumologic_api::get_log_sumologic_with_info(configuration, params))
+}
+
+/// List all of the Sumologics for a particular service and version.
+pub fn list_log_sumologic(configuration: &Configuration, params: ListLogSumologicParams) -> Result<Vec<crate::models::LoggingSumologicResponse>, Error<ListLogSumologicError>> {
+    super::block_on(logging_sumologic_api::list_log_sumologic(configuration, params))
+}
+
+/// Like [`list_log_sumologic`], but also returns the status and headers of the response.
+pub fn list_log_sumologic_with_info(configuration: &Configuration, params: ListLogSumologicParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingSumologicResponse>>, Error<ListLogSumologicError>> {
+    super::block_on(logging_sumologic_api::list_log_sumologic_with_info(configuration, params))
+}
+
+/// Update the Sumologic for a particular service and version.
+pub fn update_log_sumologic(configuration: &Configuration, params: UpdateLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<UpdateLogSumologicError>> {
+    super::block_on(logging_sumologic_api::update_log_sumologic(configuration, params))
+}
+
+/// Like [`update_log_sumologic`], but also returns the status and headers of the response.
+pub fn update_log_sumologic_with_info(configuration: &Configuration, params: UpdateLogSumologicParams) -> Result<crate::apis::Response<crate::models::LoggingSumologicResponse>, Error<UpdateLogSumologicError>> {
+    super::block_on(logging_sumologic_api::update_log_sumologic_with_info(configuration, params))
+}
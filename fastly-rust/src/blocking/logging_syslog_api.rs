@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{logging_syslog_api, Error};
+
+pub use crate::apis::logging_syslog_api::{CreateLogSyslogError, CreateLogSyslogParams, DeleteLogSyslogError, DeleteLogSyslogParams, GetLogSyslogError, GetLogSyslogParams, ListLogSyslogError, ListLogSyslogParams, UpdateLogSyslogError, UpdateLogSyslogParams};
+
+/// Create a Syslog for a particular service and version.
+pub fn create_log_syslog(configuration: &Configuration, params: CreateLogSyslogParams) -> Result<crate::models::LoggingSyslogResponse, Error<CreateLogSyslogError>> {
+    super::block_on(logging_syslog_api::create_log_syslog(configuration, params))
+}
+
+/// Like [`create_log_syslog`], but also returns the status and headers of the response.
+pub fn create_log_syslog_with_info(configuration: &Configuration, params: CreateLogSyslogParams) -> Result<crate::apis::Response<crate::models::LoggingSyslogResponse>, Error<CreateLogSyslogError>> {
+    super::block_on(logging_syslog_api::create_log_syslog_with_info(configuration, params))
+}
+
+/// Delete the Syslog for a particular service and version.
+pub fn delete_log_syslog(configuration: &Configuration, params: DeleteLogSyslogParams) -> Result<crate::models::InlineResponse200, Error<DeleteLogSyslogError>> {
+    super::block_on(logging_syslog_api::delete_log_syslog(configuration, params))
+}
+
+/// Like [`delete_log_syslog`], but also returns the status and headers of the response.
+pub fn delete_log_syslog_with_info(configuration: &Configuration, params: DeleteLogSyslogParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteLogSyslogError>> {
+    super::block_on(logging_syslog_api::delete_log_syslog_with_info(configuration, params))
+}
+
+/// Get the Syslog for a particular service and version.
+pub fn get_log_syslog(configuration: &Configuration, params: GetLogSyslogParams) -> Result<crate::models::LoggingSyslogResponse, Error<GetLogSyslogError>> {
+    super::block_on(logging_syslog_api::get_log_syslog(configuration, params))
+}
+
+/// Like [`get_log_syslog`], but also returns the status and headers of the response.
+pub fn get_log_syslog_with_info(configuration: &Configuration, params: GetLogSyslogParams) -> Result<crate::apis::Response<crate::models::LoggingSyslogResponse>, Error<GetLogSyslogError>> {
+    super::block_on(logging_syslog_api::get_log_syslog_with_info(configuration, params))
+}
+
+/// List all of the Syslogs for a particular service and version.
+pub fn list_log_syslog(configuration: &Configuration, params: ListLogSyslogParams) -> Result<Vec<crate::models::LoggingSyslogResponse>, Error<ListLogSyslogError>> {
+    super::block_on(logging_syslog_api::list_log_syslog(configuration, params))
+}
+
+/// Like [`list_log_syslog`], but also returns the status and headers of the response.
+pub fn list_log_syslog_with_info(configuration: &Configuration, params: ListLogSyslogParams) -> Result<crate::apis::Response<Vec<crate::models::LoggingSyslogResponse>>, Error<ListLogSyslogError>> {
+    super::block_on(logging_syslog_api::list_log_syslog_with_info(configuration, params))
+}
+
+/// Update the Syslog for a particular service and version.
+pub fn update_log_syslog(configuration: &Configuration, params: UpdateLogSyslogParams) -> Result<crate::models::LoggingSyslogResponse, Error<UpdateLogSyslogError>> {
+    super::block_on(logging_syslog_api::update_log_syslog(configuration, params))
+}
+
+/// Like [`update_log_syslog`], but also returns the status and headers of the response.
+pub fn update_log_syslog_with_info(configuration: &Configuration, params: UpdateLogSyslogParams) -> Result<crate::apis::Response<crate::models::LoggingSyslogResponse>, Error<UpdateLogSyslogError>> {
+    super::block_on(logging_syslog_api::update_log_syslog_with_info(configuration, params))
+}
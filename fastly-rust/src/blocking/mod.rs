@@ -0,0 +1,295 @@
+//! Synchronous versions of the [`apis`](crate::apis) functions, for build
+//! scripts and tools that do not run an async runtime. Enabled by the
+//! `blocking` feature.
+//!
+//! Every `apis::<group>_api` module has a counterpart here with the same
+//! functions, params, errors and models:
+//!
+//! ```no_run
+//! # use fastly_api::apis::configuration::Configuration;
+//! use fastly_api::blocking::version_api::{get_service_version, GetServiceVersionParams};
+//! # fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let cfg = Configuration::from_env_chain()?;
+//! let params = GetServiceVersionParams { service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(), version_id: 1 };
+//! let version = get_service_version(&cfg, params)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The functions run the async ones to completion on a small runtime owned
+//! by this module, so authentication, retries, rate-limit tracking and errors
+//! behave exactly as they do for async callers. Like `reqwest::blocking`,
+//! they panic when called from within an async runtime; use the async
+//! functions there.
+
+// `Error` is the public error type of every endpoint, returned unboxed by the
+// async functions too.
+#![allow(clippy::result_large_err)]
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::Runtime;
+
+#[cfg(feature = "acl")]
+pub mod acl_api;
+#[cfg(feature = "acl")]
+pub mod acl_entry_api;
+#[cfg(feature = "domains")]
+pub mod apex_redirect_api;
+#[cfg(feature = "accounts")]
+pub mod automation_tokens_api;
+#[cfg(feature = "backends")]
+pub mod backend_api;
+#[cfg(feature = "billing")]
+pub mod billing_api;
+#[cfg(feature = "billing")]
+pub mod billing_address_api;
+#[cfg(feature = "billing")]
+pub mod billing_invoices_api;
+#[cfg(feature = "billing")]
+pub mod billing_usage_metrics_api;
+#[cfg(feature = "vcl")]
+pub mod cache_settings_api;
+#[cfg(feature = "vcl")]
+pub mod condition_api;
+#[cfg(feature = "stores")]
+pub mod config_store_api;
+#[cfg(feature = "stores")]
+pub mod config_store_item_api;
+#[cfg(feature = "accounts")]
+pub mod contact_api;
+#[cfg(feature = "platform")]
+pub mod content_api;
+#[cfg(feature = "accounts")]
+pub mod customer_api;
+#[cfg(feature = "dictionary")]
+pub mod dictionary_api;
+#[cfg(feature = "dictionary")]
+pub mod dictionary_info_api;
+#[cfg(feature = "dictionary")]
+pub mod dictionary_item_api;
+#[cfg(feature = "vcl")]
+pub mod diff_api;
+#[cfg(feature = "backends")]
+pub mod director_api;
+#[cfg(feature = "backends")]
+pub mod director_backend_api;
+#[cfg(feature = "domains")]
+pub mod domain_api;
+#[cfg(feature = "stats")]
+pub mod domain_inspector_historical_api;
+#[cfg(feature = "stats")]
+pub mod domain_inspector_realtime_api;
+#[cfg(feature = "domains")]
+pub mod domain_ownerships_api;
+#[cfg(feature = "platform")]
+pub mod enabled_products_api;
+#[cfg(feature = "accounts")]
+pub mod events_api;
+#[cfg(feature = "vcl")]
+pub mod gzip_api;
+#[cfg(feature = "vcl")]
+pub mod header_api;
+#[cfg(feature = "healthcheck")]
+pub mod healthcheck_api;
+#[cfg(feature = "stats")]
+pub mod historical_api;
+#[cfg(feature = "vcl")]
+pub mod http3_api;
+#[cfg(feature = "iam")]
+pub mod iam_permissions_api;
+#[cfg(feature = "iam")]
+pub mod iam_roles_api;
+#[cfg(feature = "iam")]
+pub mod iam_service_groups_api;
+#[cfg(feature = "iam")]
+pub mod iam_user_groups_api;
+#[cfg(feature = "vcl")]
+pub mod image_optimizer_default_settings_api;
+#[cfg(feature = "accounts")]
+pub mod invitations_api;
+#[cfg(feature = "stores")]
+pub mod kv_store_api;
+#[cfg(feature = "stores")]
+pub mod kv_store_item_api;
+#[cfg(feature = "waf-legacy")]
+pub mod legacy_waf_configuration_sets_api;
+#[cfg(feature = "waf-legacy")]
+pub mod legacy_waf_firewall_api;
+#[cfg(feature = "waf-legacy")]
+pub mod legacy_waf_owasp_api;
+#[cfg(feature = "waf-legacy")]
+pub mod legacy_waf_rule_api;
+#[cfg(feature = "waf-legacy")]
+pub mod legacy_waf_rule_status_api;
+#[cfg(feature = "waf-legacy")]
+pub mod legacy_waf_ruleset_api;
+#[cfg(feature = "waf-legacy")]
+pub mod legacy_waf_tag_api;
+#[cfg(feature = "waf-legacy")]
+pub mod legacy_waf_update_status_api;
+#[cfg(feature = "logging")]
+pub mod logging_azureblob_api;
+#[cfg(feature = "logging")]
+pub mod logging_bigquery_api;
+#[cfg(feature = "logging")]
+pub mod logging_cloudfiles_api;
+#[cfg(feature = "logging")]
+pub mod logging_datadog_api;
+#[cfg(feature = "logging")]
+pub mod logging_digitalocean_api;
+#[cfg(feature = "logging")]
+pub mod logging_elasticsearch_api;
+#[cfg(feature = "logging")]
+pub mod logging_ftp_api;
+#[cfg(feature = "logging")]
+pub mod logging_gcs_api;
+#[cfg(feature = "logging")]
+pub mod logging_heroku_api;
+#[cfg(feature = "logging")]
+pub mod logging_honeycomb_api;
+#[cfg(feature = "logging")]
+pub mod logging_https_api;
+#[cfg(feature = "logging")]
+pub mod logging_kafka_api;
+#[cfg(feature = "logging")]
+pub mod logging_kinesis_api;
+#[cfg(feature = "logging")]
+pub mod logging_logentries_api;
+#[cfg(feature = "logging")]
+pub mod logging_loggly_api;
+#[cfg(feature = "logging")]
+pub mod logging_logshuttle_api;
+#[cfg(feature = "logging")]
+pub mod logging_newrelic_api;
+#[cfg(feature = "logging")]
+pub mod logging_newrelicotlp_api;
+#[cfg(feature = "logging")]
+pub mod logging_openstack_api;
+#[cfg(feature = "logging")]
+pub mod logging_papertrail_api;
+#[cfg(feature = "logging")]
+pub mod logging_pubsub_api;
+#[cfg(feature = "logging")]
+pub mod logging_s3_api;
+#[cfg(feature = "logging")]
+pub mod logging_scalyr_api;
+#[cfg(feature = "logging")]
+pub mod logging_sftp_api;
+#[cfg(feature = "logging")]
+pub mod logging_splunk_api;
+#[cfg(feature = "logging")]
+pub mod logging_sumologic_api;
+#[cfg(feature = "logging")]
+pub mod logging_syslog_api;
+#[cfg(feature = "tls")]
+pub mod mutual_authentication_api;
+#[cfg(feature = "stats")]
+pub mod observability_custom_dashboards_api;
+#[cfg(feature = "stats")]
+pub mod origin_inspector_historical_api;
+#[cfg(feature = "stats")]
+pub mod origin_inspector_realtime_api;
+#[cfg(feature = "compute")]
+pub mod package_api;
+#[cfg(feature = "backends")]
+pub mod pool_api;
+#[cfg(feature = "platform")]
+pub mod pop_api;
+#[cfg(feature = "platform")]
+pub mod public_ip_list_api;
+#[cfg(feature = "platform")]
+pub mod publish_api;
+#[cfg(feature = "purge")]
+pub mod purge_api;
+#[cfg(feature = "vcl")]
+pub mod rate_limiter_api;
+#[cfg(feature = "stats")]
+pub mod realtime_api;
+#[cfg(feature = "vcl")]
+pub mod request_settings_api;
+#[cfg(feature = "compute")]
+pub mod resource_api;
+#[cfg(feature = "vcl")]
+pub mod response_object_api;
+#[cfg(feature = "stores")]
+pub mod secret_store_api;
+#[cfg(feature = "stores")]
+pub mod secret_store_item_api;
+#[cfg(feature = "backends")]
+pub mod server_api;
+pub mod service_api;
+#[cfg(feature = "iam")]
+pub mod service_authorizations_api;
+#[cfg(feature = "vcl")]
+pub mod settings_api;
+#[cfg(feature = "vcl")]
+pub mod snippet_api;
+#[cfg(feature = "accounts")]
+pub mod star_api;
+#[cfg(feature = "stats")]
+pub mod stats_api;
+#[cfg(feature = "accounts")]
+pub mod sudo_api;
+#[cfg(feature = "tls")]
+pub mod tls_activations_api;
+#[cfg(feature = "tls")]
+pub mod tls_bulk_certificates_api;
+#[cfg(feature = "tls")]
+pub mod tls_certificates_api;
+#[cfg(feature = "tls")]
+pub mod tls_configurations_api;
+#[cfg(feature = "tls")]
+pub mod tls_csrs_api;
+#[cfg(feature = "tls")]
+pub mod tls_domains_api;
+#[cfg(feature = "tls")]
+pub mod tls_private_keys_api;
+#[cfg(feature = "tls")]
+pub mod tls_subscriptions_api;
+#[cfg(feature = "accounts")]
+pub mod tokens_api;
+#[cfg(feature = "accounts")]
+pub mod user_api;
+#[cfg(feature = "vcl")]
+pub mod vcl_api;
+#[cfg(feature = "vcl")]
+pub mod vcl_diff_api;
+pub mod version_api;
+#[cfg(feature = "waf")]
+pub mod waf_active_rules_api;
+#[cfg(feature = "waf")]
+pub mod waf_exclusions_api;
+#[cfg(feature = "waf")]
+pub mod waf_firewall_versions_api;
+#[cfg(feature = "waf")]
+pub mod waf_firewalls_api;
+#[cfg(feature = "waf")]
+pub mod waf_rule_revisions_api;
+#[cfg(feature = "waf")]
+pub mod waf_rules_api;
+#[cfg(feature = "waf")]
+pub mod waf_tags_api;
+#[cfg(feature = "stats")]
+pub mod whole_platform_ddos_historical_api;
+pub mod pagination;
+pub mod raw;
+
+/// Shared by all calls, so connections are pooled across them. Its worker
+/// thread keeps pooled connections alive between calls.
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("fastly-api-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to start the runtime for blocking calls")
+    })
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{mutual_authentication_api, Error};
+
+pub use crate::apis::mutual_authentication_api::{CreateMutualTlsAuthenticationError, CreateMutualTlsAuthenticationParams, DeleteMutualTlsError, DeleteMutualTlsParams, GetMutualAuthenticationError, GetMutualAuthenticationParams, ListMutualAuthenticationsError, ListMutualAuthenticationsParams, PatchMutualAuthenticationError, PatchMutualAuthenticationParams};
+
+/// Create a mutual authentication using a bundle of certificates to enable client-to-server mutual TLS.
+pub fn create_mutual_tls_authentication(configuration: &Configuration, params: CreateMutualTlsAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<CreateMutualTlsAuthenticationError>> {
+    super::block_on(mutual_authentication_api::create_mutual_tls_authentication(configuration, params))
+}
+
+/// Like [`create_mutual_tls_authentication`], but also returns the status and headers of the response.
+pub fn create_mutual_tls_authentication_with_info(configuration: &Configuration, params: CreateMutualTlsAuthenticationParams) -> Result<crate::apis::Response<crate::models::MutualAuthenticationResponse>, Error<CreateMutualTlsAuthenticationError>> {
+    super::block_on(mutual_authentication_api::create_mutual_tls_authentication_with_info(configuration, params))
+}
+
+/// Remove a Mutual TLS authentication
+pub fn delete_mutual_tls(configuration: &Configuration, params: DeleteMutualTlsParams) -> Result<(), Error<DeleteMutualTlsError>> {
+    super::block_on(mutual_authentication_api::delete_mutual_tls(configuration, params))
+}
+
+/// Like [`delete_mutual_tls`], but also returns the status and headers of the response.
+pub fn delete_mutual_tls_with_info(configuration: &Configuration, params: DeleteMutualTlsParams) -> Result<crate::apis::Response<()>, Error<DeleteMutualTlsError>> {
+    super::block_on(mutual_authentication_api::delete_mutual_tls_with_info(configuration, params))
+}
+
+/// Show a Mutual Authentication.
+pub fn get_mutual_authentication(configuration: &Configuration, params: GetMutualAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<GetMutualAuthenticationError>> {
+    super::block_on(mutual_authentication_api::get_mutual_authentication(configuration, params))
+}
+
+/// Like [`get_mutual_authentication`], but also returns the status and headers of the response.
+pub fn get_mutual_authentication_with_info(configuration: &Configuration, params: GetMutualAuthenticationParams) -> Result<crate::apis::Response<crate::models::MutualAuthenticationResponse>, Error<GetMutualAuthenticationError>> {
+    super::block_on(mutual_authentication_api::get_mutual_authentication_with_info(configuration, params))
+}
+
+/// List all mutual authentications.
+pub fn list_mutual_authentications(configuration: &Configuration, params: ListMutualAuthenticationsParams) -> Result<crate::models::MutualAuthenticationsResponse, Error<ListMutualAuthenticationsError>> {
+    super::block_on(mutual_authentication_api::list_mutual_authentications(configuration, params))
+}
+
+/// Like [`list_mutual_authentications`], but also returns the status and headers of the response.
+pub fn list_mutual_authentications_with_info(configuration: &Configuration, params: ListMutualAuthenticationsParams) -> Result<crate::apis::Response<crate::models::MutualAuthenticationsResponse>, Error<ListMutualAuthenticationsError>> {
+    super::block_on(mutual_authentication_api::list_mutual_authentications_with_info(configuration, params))
+}
+
+/// Update a Mutual Authentication.
+pub fn patch_mutual_authentication(configuration: &Configuration, params: PatchMutualAuthenticationParams) -> Result<crate::models::MutualAuthenticationResponse, Error<PatchMutualAuthenticationError>> {
+    super::block_on(mutual_authentication_api::patch_mutual_authentication(configuration, params))
+}
+
+/// Like [`patch_mutual_authentication`], but also returns the status and headers of the response.
+pub fn patch_mutual_authentication_with_info(configuration: &Configuration, params: PatchMutualAuthenticationParams) -> Result<crate::apis::Response<crate::models::MutualAuthenticationResponse>, Error<PatchMutualAuthenticationError>> {
+    super::block_on(mutual_authentication_api::patch_mutual_authentication_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{observability_custom_dashboards_api, Error};
+
+pub use crate::apis::observability_custom_dashboards_api::{CreateDashboardError, CreateDashboardParams, DeleteDashboardError, DeleteDashboardParams, GetDashboardError, GetDashboardParams, ListDashboardsError, UpdateDashboardError, UpdateDashboardParams};
+
+/// Create a new dashboard
+pub fn create_dashboard(configuration: &Configuration, params: CreateDashboardParams) -> Result<crate::models::Dashboard, Error<CreateDashboardError>> {
+    super::block_on(observability_custom_dashboards_api::create_dashboard(configuration, params))
+}
+
+/// Like [`create_dashboard`], but also returns the status and headers of the response.
+pub fn create_dashboard_with_info(configuration: &Configuration, params: CreateDashboardParams) -> Result<crate::apis::Response<crate::models::Dashboard>, Error<CreateDashboardError>> {
+    super::block_on(observability_custom_dashboards_api::create_dashboard_with_info(configuration, params))
+}
+
+/// Delete an existing dashboard
+pub fn delete_dashboard(configuration: &Configuration, params: DeleteDashboardParams) -> Result<(), Error<DeleteDashboardError>> {
+    super::block_on(observability_custom_dashboards_api::delete_dashboard(configuration, params))
+}
+
+/// Like [`delete_dashboard`], but also returns the status and headers of the response.
+pub fn delete_dashboard_with_info(configuration: &Configuration, params: DeleteDashboardParams) -> Result<crate::apis::Response<()>, Error<DeleteDashboardError>> {
+    super::block_on(observability_custom_dashboards_api::delete_dashboard_with_info(configuration, params))
+}
+
+/// Retrieve a dashboard by ID
+pub fn get_dashboard(configuration: &Configuration, params: GetDashboardParams) -> Result<crate::models::Dashboard, Error<GetDashboardError>> {
+    super::block_on(observability_custom_dashboards_api::get_dashboard(configuration, params))
+}
+
+/// Like [`get_dashboard`], but also returns the status and headers of the response.
+pub fn get_dashboard_with_info(configuration: &Configuration, params: GetDashboardParams) -> Result<crate::apis::Response<crate::models::Dashboard>, Error<GetDashboardError>> {
+    super::block_on(observability_custom_dashboards_api::get_dashboard_with_info(configuration, params))
+}
+
+/// List all custom dashboards
+pub fn list_dashboards(configuration: &Configuration) -> Result<crate::models::ListDashboardsResponse, Error<ListDashboardsError>> {
+    super::block_on(observability_custom_dashboards_api::list_dashboards(configuration))
+}
+
+/// Like [`list_dashboards`], but also returns the status and headers of the response.
+pub fn list_dashboards_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::ListDashboardsResponse>, Error<ListDashboardsError>> {
+    super::block_on(observability_custom_dashboards_api::list_dashboards_with_info(configuration))
+}
+
+/// Update an existing dashboard
+pub fn update_dashboard(configuration: &Configuration, params: UpdateDashboardParams) -> Result<crate::models::Dashboard, Error<UpdateDashboardError>> {
+    super::block_on(observability_custom_dashboards_api::update_dashboard(configuration, params))
+}
+
+/// Like [`update_dashboard`], but also returns the status and headers of the response.
+pub fn update_dashboard_with_info(configuration: &Configuration, params: UpdateDashboardParams) -> Result<crate::apis::Response<crate::models::Dashboard>, Error<UpdateDashboardError>> {
+    super::block_on(observability_custom_dashboards_api::update_dashboard_with_info(configuration, params))
+}
@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{origin_inspector_realtime_api, Error};
+
+pub use crate::apis::origin_inspector_realtime_api::{GetOriginInspectorLast120SecondsError, GetOriginInspectorLast120SecondsParams, GetOriginInspectorLastMaxEntriesError, GetOriginInspectorLastMaxEntriesParams, GetOriginInspectorLastSecondError, GetOriginInspectorLastSecondParams};
+
+/// Get data for the 120 seconds preceding the latest timestamp available for a service.
+pub fn get_origin_inspector_last120_seconds(configuration: &Configuration, params: GetOriginInspectorLast120SecondsParams) -> Result<crate::models::OriginInspector, Error<GetOriginInspectorLast120SecondsError>> {
+    super::block_on(origin_inspector_realtime_api::get_origin_inspector_last120_seconds(configuration, params))
+}
+
+/// Like [`get_origin_inspector_last120_seconds`], but also returns the status and headers of the response.
+pub fn get_origin_inspector_last120_seconds_with_info(configuration: &Configuration, params: GetOriginInspectorLast120SecondsParams) -> Result<crate::apis::Response<crate::models::OriginInspector>, Error<GetOriginInspectorLast120SecondsError>> {
+    super::block_on(origin_inspector_realtime_api::get_origin_inspector_last120_seconds_with_info(configuration, params))
+}
+
+/// Get data for the `max_entries` seconds preceding the latest timestamp available for a service, up to a maximum of 120 entries.
+pub fn get_origin_inspector_last_max_entries(configuration: &Configuration, params: GetOriginInspectorLastMaxEntriesParams) -> Result<crate::models::OriginInspector, Error<GetOriginInspectorLastMaxEntriesError>> {
+    super::block_on(origin_inspector_realtime_api::get_origin_inspector_last_max_entries(configuration, params))
+}
+
+/// Like [`get_origin_inspector_last_max_entries`], but also returns the status and headers of the response.
+pub fn get_origin_inspector_last_max_entries_with_info(configuration: &Configuration, params: GetOriginInspectorLastMaxEntriesParams) -> Result<crate::apis::Response<crate::models::OriginInspector>, Error<GetOriginInspectorLastMaxEntriesError>> {
+    super::block_on(origin_inspector_realtime_api::get_origin_inspector_last_max_entries_with_info(configuration, params))
+}
+
+/// Get real-time origin data for the specified reporting period. Specify `0` to get a single entry for the last complete second. The `Timestamp` field included in the response provides the time index of the latest entry in the dataset and can be provided as the `start_timestamp` of the next request for a seamless continuation of the dataset from one request to the next. Due to processing latency, the earliest entry in the response dataset may be earlier than `start_timestamp` by the value of `AggregateDelay`. 
+pub fn get_origin_inspector_last_second(configuration: &Configuration, params: GetOriginInspectorLastSecondParams) -> Result<crate::models::OriginInspector, Error<GetOriginInspectorLastSecondError>> {
+    super::block_on(origin_inspector_realtime_api::get_origin_inspector_last_second(configuration, params))
+}
+
+/// Like [`get_origin_inspector_last_second`], but also returns the status and headers of the response.
+pub fn get_origin_inspector_last_second_with_info(configuration: &Configuration, params: GetOriginInspectorLastSecondParams) -> Result<crate::apis::Response<crate::models::OriginInspector>, Error<GetOriginInspectorLastSecondError>> {
+    super::block_on(origin_inspector_realtime_api::get_origin_inspector_last_second_with_info(configuration, params))
+}
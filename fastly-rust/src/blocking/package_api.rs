@@ -0,0 +1,31 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{package_api, Error};
+
+pub use crate::apis::package_api::{GetPackageError, GetPackageParams, PutPackageError, PutPackageParams};
+
+/// List detailed information about the Compute package for the specified service.
+pub fn get_package(configuration: &Configuration, params: GetPackageParams) -> Result<crate::models::PackageResponse, Error<GetPackageError>> {
+    super::block_on(package_api::get_package(configuration, params))
+}
+
+/// Like [`get_package`], but also returns the status and headers of the response.
+pub fn get_package_with_info(configuration: &Configuration, params: GetPackageParams) -> Result<crate::apis::Response<crate::models::PackageResponse>, Error<GetPackageError>> {
+    super::block_on(package_api::get_package_with_info(configuration, params))
+}
+
+/// Upload a Compute package associated with the specified service version.
+pub fn put_package(configuration: &Configuration, params: PutPackageParams) -> Result<crate::models::PackageResponse, Error<PutPackageError>> {
+    super::block_on(package_api::put_package(configuration, params))
+}
+
+/// Like [`put_package`], but also returns the status and headers of the response.
+pub fn put_package_with_info(configuration: &Configuration, params: PutPackageParams) -> Result<crate::apis::Response<crate::models::PackageResponse>, Error<PutPackageError>> {
+    super::block_on(package_api::put_package_with_info(configuration, params))
+}
@@ -0,0 +1,59 @@
+//! Walking list endpoints page by page, as an iterator.
+//!
+//! The blocking counterpart of [`apis::pagination`](crate::apis::pagination):
+//!
+//! ```no_run
+//! # use fastly_api::apis::configuration::Configuration;
+//! # use fastly_api::apis::service_api::ListServicesParams;
+//! # use fastly_api::blocking::pagination::paginate;
+//! # fn run(cfg: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+//! for service in paginate(cfg, ListServicesParams::default()).page_size(100) {
+//!     println!("{:?}", service?.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use futures::StreamExt;
+
+use crate::apis::configuration::Configuration;
+use crate::apis::pagination::{self, Paginated};
+use crate::apis::Error;
+
+pub use crate::apis::pagination::Page;
+
+/// Iterate over every item of the list endpoint `params` belong to, starting
+/// at the page they point at.
+pub fn paginate<P: Paginated>(configuration: &Configuration, params: P) -> Paginator<'_, P> {
+    Paginator { stream: pagination::paginate(configuration, params) }
+}
+
+/// Iterator returned by [`paginate`]. Pages are fetched as items are
+/// consumed; it ends after the first error.
+pub struct Paginator<'a, P: Paginated> {
+    stream: pagination::Paginator<'a, P>,
+}
+
+impl<'a, P: Paginated> Paginator<'a, P> {
+    /// Ask for `size` results per page instead of the endpoint's default.
+    /// Has no effect once iteration has started.
+    pub fn page_size(self, size: i32) -> Self {
+        Paginator { stream: self.stream.page_size(size) }
+    }
+}
+
+impl<'a, P: Paginated> Iterator for Paginator<'a, P> {
+    type Item = Result<P::Item, Error<P::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        super::block_on(self.stream.next())
+    }
+}
+
+impl<'a, P: Paginated + fmt::Debug> fmt::Debug for Paginator<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.stream.fmt(f)
+    }
+}
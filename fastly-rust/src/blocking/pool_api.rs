@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{pool_api, Error};
+
+pub use crate::apis::pool_api::{CreateServerPoolError, CreateServerPoolParams, DeleteServerPoolError, DeleteServerPoolParams, GetServerPoolError, GetServerPoolParams, ListServerPoolsError, ListServerPoolsParams, UpdateServerPoolError, UpdateServerPoolParams};
+
+/// Creates a pool for a particular service and version.
+pub fn create_server_pool(configuration: &Configuration, params: CreateServerPoolParams) -> Result<crate::models::PoolResponsePost, Error<CreateServerPoolError>> {
+    super::block_on(pool_api::create_server_pool(configuration, params))
+}
+
+/// Like [`create_server_pool`], but also returns the status and headers of the response.
+pub fn create_server_pool_with_info(configuration: &Configuration, params: CreateServerPoolParams) -> Result<crate::apis::Response<crate::models::PoolResponsePost>, Error<CreateServerPoolError>> {
+    super::block_on(pool_api::create_server_pool_with_info(configuration, params))
+}
+
+/// Deletes a specific pool for a particular service and version.
+pub fn delete_server_pool(configuration: &Configuration, params: DeleteServerPoolParams) -> Result<crate::models::InlineResponse200, Error<DeleteServerPoolError>> {
+    super::block_on(pool_api::delete_server_pool(configuration, params))
+}
+
+/// Like [`delete_server_pool`], but also returns the status and headers of the response.
+pub fn delete_server_pool_with_info(configuration: &Configuration, params: DeleteServerPoolParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteServerPoolError>> {
+    super::block_on(pool_api::delete_server_pool_with_info(configuration, params))
+}
+
+/// Gets a single pool for a particular service and version.
+pub fn get_server_pool(configuration: &Configuration, params: GetServerPoolParams) -> Result<crate::models::PoolResponse, Error<GetServerPoolError>> {
+    super::block_on(pool_api::get_server_pool(configuration, params))
+}
+
+/// Like [`get_server_pool`], but also returns the status and headers of the response.
+pub fn get_server_pool_with_info(configuration: &Configuration, params: GetServerPoolParams) -> Result<crate::apis::Response<crate::models::PoolResponse>, Error<GetServerPoolError>> {
+    super::block_on(pool_api::get_server_pool_with_info(configuration, params))
+}
+
+/// Lists all pools for a particular service and pool.
+pub fn list_server_pools(configuration: &Configuration, params: ListServerPoolsParams) -> Result<Vec<crate::models::PoolResponse>, Error<ListServerPoolsError>> {
+    super::block_on(pool_api::list_server_pools(configuration, params))
+}
+
+/// Like [`list_server_pools`], but also returns the status and headers of the response.
+pub fn list_server_pools_with_info(configuration: &Configuration, params: ListServerPoolsParams) -> Result<crate::apis::Response<Vec<crate::models::PoolResponse>>, Error<ListServerPoolsError>> {
+    super::block_on(pool_api::list_server_pools_with_info(configuration, params))
+}
+
+/// Updates a specific pool for a particular service and version.
+pub fn update_server_pool(configuration: &Configuration, params: UpdateServerPoolParams) -> Result<crate::models::PoolResponse, Error<UpdateServerPoolError>> {
+    super::block_on(pool_api::update_server_pool(configuration, params))
+}
+
+/// Like [`update_server_pool`], but also returns the status and headers of the response.
+pub fn update_server_pool_with_info(configuration: &Configuration, params: UpdateServerPoolParams) -> Result<crate::apis::Response<crate::models::PoolResponse>, Error<UpdateServerPoolError>> {
+    super::block_on(pool_api::update_server_pool_with_info(configuration, params))
+}
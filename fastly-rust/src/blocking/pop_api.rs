@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{pop_api, Error};
+
+pub use crate::apis::pop_api::{ListPopsError};
+
+/// Get a list of all Fastly POPs.
+pub fn list_pops(configuration: &Configuration) -> Result<Vec<crate::models::Pop>, Error<ListPopsError>> {
+    super::block_on(pop_api::list_pops(configuration))
+}
+
+/// Like [`list_pops`], but also returns the status and headers of the response.
+pub fn list_pops_with_info(configuration: &Configuration) -> Result<crate::apis::Response<Vec<crate::models::Pop>>, Error<ListPopsError>> {
+    super::block_on(pop_api::list_pops_with_info(configuration))
+}
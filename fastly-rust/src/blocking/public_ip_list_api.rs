@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{public_ip_list_api, Error};
+
+pub use crate::apis::public_ip_list_api::{ListFastlyIpsError};
+
+/// List the public IP addresses for the Fastly network.
+pub fn list_fastly_ips(configuration: &Configuration) -> Result<crate::models::PublicIpList, Error<ListFastlyIpsError>> {
+    super::block_on(public_ip_list_api::list_fastly_ips(configuration))
+}
+
+/// Like [`list_fastly_ips`], but also returns the status and headers of the response.
+pub fn list_fastly_ips_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::PublicIpList>, Error<ListFastlyIpsError>> {
+    super::block_on(public_ip_list_api::list_fastly_ips_with_info(configuration))
+}
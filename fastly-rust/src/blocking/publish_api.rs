@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{publish_api, Error};
+
+pub use crate::apis::publish_api::{PublishError, PublishParams};
+
+/// Send one or more messages to [Fanout](https://www.fastly.com/documentation/learning/concepts/real-time-messaging/fanout) subscribers. Each message specifies a channel, and Fanout will deliver the message to all subscribers of its channel. > **IMPORTANT:** For compatibility with GRIP, this endpoint requires a trailing slash, and the API token may be provided in the `Authorization` header (instead of the `Fastly-Key` header) using the `Bearer` scheme. 
+pub fn publish(configuration: &Configuration, params: PublishParams) -> Result<String, Error<PublishError>> {
+    super::block_on(publish_api::publish(configuration, params))
+}
+
+/// Like [`publish`], but also returns the status and headers of the response.
+pub fn publish_with_info(configuration: &Configuration, params: PublishParams) -> Result<crate::apis::Response<String>, Error<PublishError>> {
+    super::block_on(publish_api::publish_with_info(configuration, params))
+}
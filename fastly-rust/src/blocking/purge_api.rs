@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{purge_api, Error};
+
+pub use crate::apis::purge_api::{BulkPurgeTagError, BulkPurgeTagParams, PurgeAllError, PurgeAllParams, PurgeSingleUrlError, PurgeSingleUrlParams, PurgeTagError, PurgeTagParams};
+
+/// Instant Purge a particular service of items tagged with surrogate keys. Up to 256 surrogate keys can be purged in one batch request. As an alternative to sending the keys in a JSON object in the body of the request, this endpoint also supports listing keys in a <code>Surrogate-Key</code> request header, e.g. <code>Surrogate-Key: key_1 key_2 key_3</code>. 
+pub fn bulk_purge_tag(configuration: &Configuration, params: BulkPurgeTagParams) -> Result<::std::collections::HashMap<String, String>, Error<BulkPurgeTagError>> {
+    super::block_on(purge_api::bulk_purge_tag(configuration, params))
+}
+
+/// Like [`bulk_purge_tag`], but also returns the status and headers of the response.
+pub fn bulk_purge_tag_with_info(configuration: &Configuration, params: BulkPurgeTagParams) -> Result<crate::apis::Response<::std::collections::HashMap<String, String>>, Error<BulkPurgeTagError>> {
+    super::block_on(purge_api::bulk_purge_tag_with_info(configuration, params))
+}
+
+/// Instant Purge everything from a service.  Purge-all requests cannot be done in soft mode and will always immediately invalidate all cached content associated with the service. To do a soft-purge-all, consider applying a constant [surrogate key](https://docs.fastly.com/en/guides/getting-started-with-surrogate-keys) tag (e.g., `\"all\"`) to all objects. 
+pub fn purge_all(configuration: &Configuration, params: PurgeAllParams) -> Result<crate::models::InlineResponse200, Error<PurgeAllError>> {
+    super::block_on(purge_api::purge_all(configuration, params))
+}
+
+/// Like [`purge_all`], but also returns the status and headers of the response.
+pub fn purge_all_with_info(configuration: &Configuration, params: PurgeAllParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<PurgeAllError>> {
+    super::block_on(purge_api::purge_all_with_info(configuration, params))
+}
+
+/// Instant Purge an individual URL.
+pub fn purge_single_url(configuration: &Configuration, params: PurgeSingleUrlParams) -> Result<crate::models::PurgeResponse, Error<PurgeSingleUrlError>> {
+    super::block_on(purge_api::purge_single_url(configuration, params))
+}
+
+/// Like [`purge_single_url`], but also returns the status and headers of the response.
+pub fn purge_single_url_with_info(configuration: &Configuration, params: PurgeSingleUrlParams) -> Result<crate::apis::Response<crate::models::PurgeResponse>, Error<PurgeSingleUrlError>> {
+    super::block_on(purge_api::purge_single_url_with_info(configuration, params))
+}
+
+/// Instant Purge a particular service of items tagged with a Surrogate Key. Only one surrogate key can be purged at a time. Multiple keys can be purged using a batch surrogate key purge request.
+pub fn purge_tag(configuration: &Configuration, params: PurgeTagParams) -> Result<crate::models::PurgeResponse, Error<PurgeTagError>> {
+    super::block_on(purge_api::purge_tag(configuration, params))
+}
+
+/// Like [`purge_tag`], but also returns the status and headers of the response.
+pub fn purge_tag_with_info(configuration: &Configuration, params: PurgeTagParams) -> Result<crate::apis::Response<crate::models::PurgeResponse>, Error<PurgeTagError>> {
+    super::block_on(purge_api::purge_tag_with_info(configuration, params))
+}
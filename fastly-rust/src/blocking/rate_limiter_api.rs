@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{rate_limiter_api, Error};
+
+pub use crate::apis::rate_limiter_api::{CreateRateLimiterError, CreateRateLimiterParams, DeleteRateLimiterError, DeleteRateLimiterParams, GetRateLimiterError, GetRateLimiterParams, ListRateLimitersError, ListRateLimitersParams, UpdateRateLimiterError, UpdateRateLimiterParams};
+
+/// Create a rate limiter for a particular service and version.
+pub fn create_rate_limiter(configuration: &Configuration, params: CreateRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<CreateRateLimiterError>> {
+    super::block_on(rate_limiter_api::create_rate_limiter(configuration, params))
+}
+
+/// Like [`create_rate_limiter`], but also returns the status and headers of the response.
+pub fn create_rate_limiter_with_info(configuration: &Configuration, params: CreateRateLimiterParams) -> Result<crate::apis::Response<crate::models::RateLimiterResponse>, Error<CreateRateLimiterError>> {
+    super::block_on(rate_limiter_api::create_rate_limiter_with_info(configuration, params))
+}
+
+/// Delete a rate limiter by its ID.
+pub fn delete_rate_limiter(configuration: &Configuration, params: DeleteRateLimiterParams) -> Result<crate::models::InlineResponse200, Error<DeleteRateLimiterError>> {
+    super::block_on(rate_limiter_api::delete_rate_limiter(configuration, params))
+}
+
+/// Like [`delete_rate_limiter`], but also returns the status and headers of the response.
+pub fn delete_rate_limiter_with_info(configuration: &Configuration, params: DeleteRateLimiterParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteRateLimiterError>> {
+    super::block_on(rate_limiter_api::delete_rate_limiter_with_info(configuration, params))
+}
+
+/// Get a rate limiter by its ID.
+pub fn get_rate_limiter(configuration: &Configuration, params: GetRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<GetRateLimiterError>> {
+    super::block_on(rate_limiter_api::get_rate_limiter(configuration, params))
+}
+
+/// Like [`get_rate_limiter`], but also returns the status and headers of the response.
+pub fn get_rate_limiter_with_info(configuration: &Configuration, params: GetRateLimiterParams) -> Result<crate::apis::Response<crate::models::RateLimiterResponse>, Error<GetRateLimiterError>> {
+    super::block_on(rate_limiter_api::get_rate_limiter_with_info(configuration, params))
+}
+
+/// List all rate limiters for a particular service and version.
+pub fn list_rate_limiters(configuration: &Configuration, params: ListRateLimitersParams) -> Result<Vec<crate::models::RateLimiterResponse>, Error<ListRateLimitersError>> {
+    super::block_on(rate_limiter_api::list_rate_limiters(configuration, params))
+}
+
+/// Like [`list_rate_limiters`], but also returns the status and headers of the response.
+pub fn list_rate_limiters_with_info(configuration: &Configuration, params: ListRateLimitersParams) -> Result<crate::apis::Response<Vec<crate::models::RateLimiterResponse>>, Error<ListRateLimitersError>> {
+    super::block_on(rate_limiter_api::list_rate_limiters_with_info(configuration, params))
+}
+
+/// Update a rate limiter by its ID.
+pub fn update_rate_limiter(configuration: &Configuration, params: UpdateRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<UpdateRateLimiterError>> {
+    super::block_on(rate_limiter_api::update_rate_limiter(configuration, params))
+}
+
+/// Like [`update_rate_limiter`], but also returns the status and headers of the response.
+pub fn update_rate_limiter_with_info(configuration: &Configuration, params: UpdateRateLimiterParams) -> Result<crate::apis::Response<crate::models::RateLimiterResponse>, Error<UpdateRateLimiterError>> {
+    super::block_on(rate_limiter_api::update_rate_limiter_with_info(configuration, params))
+}
@@ -0,0 +1,18 @@
+//! Calling endpoints this crate does not have a function for yet; the
+//! blocking counterpart of [`apis::raw`](crate::apis::raw).
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{raw, Error, Response};
+
+pub use crate::apis::raw::Body;
+
+/// Send `method path?query` with `body`. See [`apis::raw::request`](crate::apis::raw::request).
+pub fn request(
+    configuration: &Configuration,
+    method: reqwest::Method,
+    path: &str,
+    query: &[(&str, &str)],
+    body: Body,
+) -> Result<Response<serde_json::Value>, Error<serde_json::Value>> {
+    super::block_on(raw::request(configuration, method, path, query, body))
+}
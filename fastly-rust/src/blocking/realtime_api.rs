@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{realtime_api, Error};
+
+pub use crate::apis::realtime_api::{GetStatsLast120SecondsError, GetStatsLast120SecondsLimitEntriesError, GetStatsLast120SecondsLimitEntriesParams, GetStatsLast120SecondsParams, GetStatsLastSecondError, GetStatsLastSecondParams};
+
+/// Get data for the 120 seconds preceding the latest timestamp available for a service.
+pub fn get_stats_last120_seconds(configuration: &Configuration, params: GetStatsLast120SecondsParams) -> Result<crate::models::Realtime, Error<GetStatsLast120SecondsError>> {
+    super::block_on(realtime_api::get_stats_last120_seconds(configuration, params))
+}
+
+/// Like [`get_stats_last120_seconds`], but also returns the status and headers of the response.
+pub fn get_stats_last120_seconds_with_info(configuration: &Configuration, params: GetStatsLast120SecondsParams) -> Result<crate::apis::Response<crate::models::Realtime>, Error<GetStatsLast120SecondsError>> {
+    super::block_on(realtime_api::get_stats_last120_seconds_with_info(configuration, params))
+}
+
+/// Get data for the 120 seconds preceding the latest timestamp available for a service, up to a maximum of `max_entries` entries.
+pub fn get_stats_last120_seconds_limit_entries(configuration: &Configuration, params: GetStatsLast120SecondsLimitEntriesParams) -> Result<crate::models::Realtime, Error<GetStatsLast120SecondsLimitEntriesError>> {
+    super::block_on(realtime_api::get_stats_last120_seconds_limit_entries(configuration, params))
+}
+
+/// Like [`get_stats_last120_seconds_limit_entries`], but also returns the status and headers of the response.
+pub fn get_stats_last120_seconds_limit_entries_with_info(configuration: &Configuration, params: GetStatsLast120SecondsLimitEntriesParams) -> Result<crate::apis::Response<crate::models::Realtime>, Error<GetStatsLast120SecondsLimitEntriesError>> {
+    super::block_on(realtime_api::get_stats_last120_seconds_limit_entries_with_info(configuration, params))
+}
+
+/// Get real-time data for the specified reporting period. Specify `0` to get a single entry for the last complete second. The `Timestamp` field included in the response provides the time index of the latest entry in the dataset and can be provided as the `start_timestamp` of the next request for a seamless continuation of the dataset from one request to the next.
+pub fn get_stats_last_second(configuration: &Configuration, params: GetStatsLastSecondParams) -> Result<crate::models::Realtime, Error<GetStatsLastSecondError>> {
+    super::block_on(realtime_api::get_stats_last_second(configuration, params))
+}
+
+/// Like [`get_stats_last_second`], but also returns the status and headers of the response.
+pub fn get_stats_last_second_with_info(configuration: &Configuration, params: GetStatsLastSecondParams) -> Result<crate::apis::Response<crate::models::Realtime>, Error<GetStatsLastSecondError>> {
+    super::block_on(realtime_api::get_stats_last_second_with_info(configuration, params))
+}
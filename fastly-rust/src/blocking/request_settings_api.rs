@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{request_settings_api, Error};
+
+pub use crate::apis::request_settings_api::{CreateRequestSettingsError, CreateRequestSettingsParams, DeleteRequestSettingsError, DeleteRequestSettingsParams, GetRequestSettingsError, GetRequestSettingsParams, ListRequestSettingsError, ListRequestSettingsParams, UpdateRequestSettingsError, UpdateRequestSettingsParams};
+
+/// Creates a new Request Settings object.
+pub fn create_request_settings(configuration: &Configuration, params: CreateRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<CreateRequestSettingsError>> {
+    super::block_on(request_settings_api::create_request_settings(configuration, params))
+}
+
+/// Like [`create_request_settings`], but also returns the status and headers of the response.
+pub fn create_request_settings_with_info(configuration: &Configuration, params: CreateRequestSettingsParams) -> Result<crate::apis::Response<crate::models::RequestSettingsResponse>, Error<CreateRequestSettingsError>> {
+    super::block_on(request_settings_api::create_request_settings_with_info(configuration, params))
+}
+
+/// Removes the specified Request Settings object.
+pub fn delete_request_settings(configuration: &Configuration, params: DeleteRequestSettingsParams) -> Result<crate::models::InlineResponse200, Error<DeleteRequestSettingsError>> {
+    super::block_on(request_settings_api::delete_request_settings(configuration, params))
+}
+
+/// Like [`delete_request_settings`], but also returns the status and headers of the response.
+pub fn delete_request_settings_with_info(configuration: &Configuration, params: DeleteRequestSettingsParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteRequestSettingsError>> {
+    super::block_on(request_settings_api::delete_request_settings_with_info(configuration, params))
+}
+
+/// Gets the specified Request Settings object.
+pub fn get_request_settings(configuration: &Configuration, params: GetRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<GetRequestSettingsError>> {
+    super::block_on(request_settings_api::get_request_settings(configuration, params))
+}
+
+/// Like [`get_request_settings`], but also returns the status and headers of the response.
+pub fn get_request_settings_with_info(configuration: &Configuration, params: GetRequestSettingsParams) -> Result<crate::apis::Response<crate::models::RequestSettingsResponse>, Error<GetRequestSettingsError>> {
+    super::block_on(request_settings_api::get_request_settings_with_info(configuration, params))
+}
+
+/// Returns a list of all Request Settings objects for the given service and version.
+pub fn list_request_settings(configuration: &Configuration, params: ListRequestSettingsParams) -> Result<Vec<crate::models::RequestSettingsResponse>, Error<ListRequestSettingsError>> {
+    super::block_on(request_settings_api::list_request_settings(configuration, params))
+}
+
+/// Like [`list_request_settings`], but also returns the status and headers of the response.
+pub fn list_request_settings_with_info(configuration: &Configuration, params: ListRequestSettingsParams) -> Result<crate::apis::Response<Vec<crate::models::RequestSettingsResponse>>, Error<ListRequestSettingsError>> {
+    super::block_on(request_settings_api::list_request_settings_with_info(configuration, params))
+}
+
+/// Updates the specified Request Settings object.
+pub fn update_request_settings(configuration: &Configuration, params: UpdateRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<UpdateRequestSettingsError>> {
+    super::block_on(request_settings_api::update_request_settings(configuration, params))
+}
+
+/// Like [`update_request_settings`], but also returns the status and headers of the response.
+pub fn update_request_settings_with_info(configuration: &Configuration, params: UpdateRequestSettingsParams) -> Result<crate::apis::Response<crate::models::RequestSettingsResponse>, Error<UpdateRequestSettingsError>> {
+    super::block_on(request_settings_api::update_request_settings_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{resource_api, Error};
+
+pub use crate::apis::resource_api::{CreateResourceError, CreateResourceParams, DeleteResourceError, DeleteResourceParams, GetResourceError, GetResourceParams, ListResourcesError, ListResourcesParams, UpdateResourceError, UpdateResourceParams};
+
+/// Create a link between a resource and a service version.
+pub fn create_resource(configuration: &Configuration, params: CreateResourceParams) -> Result<crate::models::ResourceResponse, Error<CreateResourceError>> {
+    super::block_on(resource_api::create_resource(configuration, params))
+}
+
+/// Like [`create_resource`], but also returns the status and headers of the response.
+pub fn create_resource_with_info(configuration: &Configuration, params: CreateResourceParams) -> Result<crate::apis::Response<crate::models::ResourceResponse>, Error<CreateResourceError>> {
+    super::block_on(resource_api::create_resource_with_info(configuration, params))
+}
+
+/// Delete a link between a resource and a service version.
+pub fn delete_resource(configuration: &Configuration, params: DeleteResourceParams) -> Result<crate::models::InlineResponse200, Error<DeleteResourceError>> {
+    super::block_on(resource_api::delete_resource(configuration, params))
+}
+
+/// Like [`delete_resource`], but also returns the status and headers of the response.
+pub fn delete_resource_with_info(configuration: &Configuration, params: DeleteResourceParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteResourceError>> {
+    super::block_on(resource_api::delete_resource_with_info(configuration, params))
+}
+
+/// Display a resource link by its identifier.
+pub fn get_resource(configuration: &Configuration, params: GetResourceParams) -> Result<crate::models::ResourceResponse, Error<GetResourceError>> {
+    super::block_on(resource_api::get_resource(configuration, params))
+}
+
+/// Like [`get_resource`], but also returns the status and headers of the response.
+pub fn get_resource_with_info(configuration: &Configuration, params: GetResourceParams) -> Result<crate::apis::Response<crate::models::ResourceResponse>, Error<GetResourceError>> {
+    super::block_on(resource_api::get_resource_with_info(configuration, params))
+}
+
+/// List links between resources and services
+pub fn list_resources(configuration: &Configuration, params: ListResourcesParams) -> Result<Vec<crate::models::ResourceResponse>, Error<ListResourcesError>> {
+    super::block_on(resource_api::list_resources(configuration, params))
+}
+
+/// Like [`list_resources`], but also returns the status and headers of the response.
+pub fn list_resources_with_info(configuration: &Configuration, params: ListResourcesParams) -> Result<crate::apis::Response<Vec<crate::models::ResourceResponse>>, Error<ListResourcesError>> {
+    super::block_on(resource_api::list_resources_with_info(configuration, params))
+}
+
+/// Update a link between a resource and a service version.
+pub fn update_resource(configuration: &Configuration, params: UpdateResourceParams) -> Result<crate::models::ResourceResponse, Error<UpdateResourceError>> {
+    super::block_on(resource_api::update_resource(configuration, params))
+}
+
+/// Like [`update_resource`], but also returns the status and headers of the response.
+pub fn update_resource_with_info(configuration: &Configuration, params: UpdateResourceParams) -> Result<crate::apis::Response<crate::models::ResourceResponse>, Error<UpdateResourceError>> {
+    super::block_on(resource_api::update_resource_with_info(configuration, params))
+}
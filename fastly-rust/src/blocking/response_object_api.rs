@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{response_object_api, Error};
+
+pub use crate::apis::response_object_api::{CreateResponseObjectError, CreateResponseObjectParams, DeleteResponseObjectError, DeleteResponseObjectParams, GetResponseObjectError, GetResponseObjectParams, ListResponseObjectsError, ListResponseObjectsParams, UpdateResponseObjectError, UpdateResponseObjectParams};
+
+/// Creates a new Response Object.
+pub fn create_response_object(configuration: &Configuration, params: CreateResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<CreateResponseObjectError>> {
+    super::block_on(response_object_api::create_response_object(configuration, params))
+}
+
+/// Like [`create_response_object`], but also returns the status and headers of the response.
+pub fn create_response_object_with_info(configuration: &Configuration, params: CreateResponseObjectParams) -> Result<crate::apis::Response<crate::models::ResponseObjectResponse>, Error<CreateResponseObjectError>> {
+    super::block_on(response_object_api::create_response_object_with_info(configuration, params))
+}
+
+/// Deletes the specified Response Object.
+pub fn delete_response_object(configuration: &Configuration, params: DeleteResponseObjectParams) -> Result<crate::models::InlineResponse200, Error<DeleteResponseObjectError>> {
+    super::block_on(response_object_api::delete_response_object(configuration, params))
+}
+
+/// Like [`delete_response_object`], but also returns the status and headers of the response.
+pub fn delete_response_object_with_info(configuration: &Configuration, params: DeleteResponseObjectParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteResponseObjectError>> {
+    super::block_on(response_object_api::delete_response_object_with_info(configuration, params))
+}
+
+/// Gets the specified Response Object.
+pub fn get_response_object(configuration: &Configuration, params: GetResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<GetResponseObjectError>> {
+    super::block_on(response_object_api::get_response_object(configuration, params))
+}
+
+/// Like [`get_response_object`], but also returns the status and headers of the response.
+pub fn get_response_object_with_info(configuration: &Configuration, params: GetResponseObjectParams) -> Result<crate::apis::Response<crate::models::ResponseObjectResponse>, Error<GetResponseObjectError>> {
+    super::block_on(response_object_api::get_response_object_with_info(configuration, params))
+}
+
+/// Returns all Response Objects for the specified service and version.
+pub fn list_response_objects(configuration: &Configuration, params: ListResponseObjectsParams) -> Result<Vec<crate::models::ResponseObjectResponse>, Error<ListResponseObjectsError>> {
+    super::block_on(response_object_api::list_response_objects(configuration, params))
+}
+
+/// Like [`list_response_objects`], but also returns the status and headers of the response.
+pub fn list_response_objects_with_info(configuration: &Configuration, params: ListResponseObjectsParams) -> Result<crate::apis::Response<Vec<crate::models::ResponseObjectResponse>>, Error<ListResponseObjectsError>> {
+    super::block_on(response_object_api::list_response_objects_with_info(configuration, params))
+}
+
+/// Updates the specified Response Object.
+pub fn update_response_object(configuration: &Configuration, params: UpdateResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<UpdateResponseObjectError>> {
+    super::block_on(response_object_api::update_response_object(configuration, params))
+}
+
+/// Like [`update_response_object`], but also returns the status and headers of the response.
+pub fn update_response_object_with_info(configuration: &Configuration, params: UpdateResponseObjectParams) -> Result<crate::apis::Response<crate::models::ResponseObjectResponse>, Error<UpdateResponseObjectError>> {
+    super::block_on(response_object_api::update_response_object_with_info(configuration, params))
+}
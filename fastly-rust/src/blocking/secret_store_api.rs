@@ -0,0 +1,71 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{secret_store_api, Error};
+
+pub use crate::apis::secret_store_api::{ClientKeyError, CreateSecretStoreError, CreateSecretStoreParams, DeleteSecretStoreError, DeleteSecretStoreParams, GetSecretStoreError, GetSecretStoreParams, GetSecretStoresError, GetSecretStoresParams, SigningKeyError};
+
+/// Create a new client key for encrypting secrets locally before uploading.
+pub fn client_key(configuration: &Configuration) -> Result<crate::models::ClientKey, Error<ClientKeyError>> {
+    super::block_on(secret_store_api::client_key(configuration))
+}
+
+/// Like [`client_key`], but also returns the status and headers of the response.
+pub fn client_key_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::ClientKey>, Error<ClientKeyError>> {
+    super::block_on(secret_store_api::client_key_with_info(configuration))
+}
+
+/// Create a new secret store.
+pub fn create_secret_store(configuration: &Configuration, params: CreateSecretStoreParams) -> Result<crate::models::SecretStoreResponse, Error<CreateSecretStoreError>> {
+    super::block_on(secret_store_api::create_secret_store(configuration, params))
+}
+
+/// Like [`create_secret_store`], but also returns the status and headers of the response.
+pub fn create_secret_store_with_info(configuration: &Configuration, params: CreateSecretStoreParams) -> Result<crate::apis::Response<crate::models::SecretStoreResponse>, Error<CreateSecretStoreError>> {
+    super::block_on(secret_store_api::create_secret_store_with_info(configuration, params))
+}
+
+/// Delete a secret store and all of its contents.
+pub fn delete_secret_store(configuration: &Configuration, params: DeleteSecretStoreParams) -> Result<(), Error<DeleteSecretStoreError>> {
+    super::block_on(secret_store_api::delete_secret_store(configuration, params))
+}
+
+/// Like [`delete_secret_store`], but also returns the status and headers of the response.
+pub fn delete_secret_store_with_info(configuration: &Configuration, params: DeleteSecretStoreParams) -> Result<crate::apis::Response<()>, Error<DeleteSecretStoreError>> {
+    super::block_on(secret_store_api::delete_secret_store_with_info(configuration, params))
+}
+
+/// Get a secret store by ID.
+pub fn get_secret_store(configuration: &Configuration, params: GetSecretStoreParams) -> Result<crate::models::SecretStoreResponse, Error<GetSecretStoreError>> {
+    super::block_on(secret_store_api::get_secret_store(configuration, params))
+}
+
+/// Like [`get_secret_store`], but also returns the status and headers of the response.
+pub fn get_secret_store_with_info(configuration: &Configuration, params: GetSecretStoreParams) -> Result<crate::apis::Response<crate::models::SecretStoreResponse>, Error<GetSecretStoreError>> {
+    super::block_on(secret_store_api::get_secret_store_with_info(configuration, params))
+}
+
+/// Get all secret stores.
+pub fn get_secret_stores(configuration: &Configuration, params: GetSecretStoresParams) -> Result<crate::models::InlineResponse2005, Error<GetSecretStoresError>> {
+    super::block_on(secret_store_api::get_secret_stores(configuration, params))
+}
+
+/// Like [`get_secret_stores`], but also returns the status and headers of the response.
+pub fn get_secret_stores_with_info(configuration: &Configuration, params: GetSecretStoresParams) -> Result<crate::apis::Response<crate::models::InlineResponse2005>, Error<GetSecretStoresError>> {
+    super::block_on(secret_store_api::get_secret_stores_with_info(configuration, params))
+}
+
+/// Get the public key used for signing client keys.
+pub fn signing_key(configuration: &Configuration) -> Result<crate::models::SigningKey, Error<SigningKeyError>> {
+    super::block_on(secret_store_api::signing_key(configuration))
+}
+
+/// Like [`signing_key`], but also returns the status and headers of the response.
+pub fn signing_key_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::SigningKey>, Error<SigningKeyError>> {
+    super::block_on(secret_store_api::signing_key_with_info(configuration))
+}
@@ -0,0 +1,71 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{secret_store_item_api, Error};
+
+pub use crate::apis::secret_store_item_api::{CreateSecretError, CreateSecretParams, DeleteSecretError, DeleteSecretParams, GetSecretError, GetSecretParams, GetSecretsError, GetSecretsParams, MustRecreateSecretError, MustRecreateSecretParams, RecreateSecretError, RecreateSecretParams};
+
+/// Create a new secret in a store. Returns an error if a secret already exists with the same name. See `PUT` and `PATCH` methods for ways to recreate an existing secret.  The `secret` field must be Base64-encoded because a secret can contain binary data. In the example below, the unencoded secret is \"Hello, world!\" 
+pub fn create_secret(configuration: &Configuration, params: CreateSecretParams) -> Result<crate::models::SecretResponse, Error<CreateSecretError>> {
+    super::block_on(secret_store_item_api::create_secret(configuration, params))
+}
+
+/// Like [`create_secret`], but also returns the status and headers of the response.
+pub fn create_secret_with_info(configuration: &Configuration, params: CreateSecretParams) -> Result<crate::apis::Response<crate::models::SecretResponse>, Error<CreateSecretError>> {
+    super::block_on(secret_store_item_api::create_secret_with_info(configuration, params))
+}
+
+/// Delete a secret from a store by name.
+pub fn delete_secret(configuration: &Configuration, params: DeleteSecretParams) -> Result<(), Error<DeleteSecretError>> {
+    super::block_on(secret_store_item_api::delete_secret(configuration, params))
+}
+
+/// Like [`delete_secret`], but also returns the status and headers of the response.
+pub fn delete_secret_with_info(configuration: &Configuration, params: DeleteSecretParams) -> Result<crate::apis::Response<()>, Error<DeleteSecretError>> {
+    super::block_on(secret_store_item_api::delete_secret_with_info(configuration, params))
+}
+
+/// Get metadata about a secret by name.
+pub fn get_secret(configuration: &Configuration, params: GetSecretParams) -> Result<crate::models::SecretResponse, Error<GetSecretError>> {
+    super::block_on(secret_store_item_api::get_secret(configuration, params))
+}
+
+/// Like [`get_secret`], but also returns the status and headers of the response.
+pub fn get_secret_with_info(configuration: &Configuration, params: GetSecretParams) -> Result<crate::apis::Response<crate::models::SecretResponse>, Error<GetSecretError>> {
+    super::block_on(secret_store_item_api::get_secret_with_info(configuration, params))
+}
+
+/// List all secrets within a store.
+pub fn get_secrets(configuration: &Configuration, params: GetSecretsParams) -> Result<crate::models::InlineResponse2006, Error<GetSecretsError>> {
+    super::block_on(secret_store_item_api::get_secrets(configuration, params))
+}
+
+/// Like [`get_secrets`], but also returns the status and headers of the response.
+pub fn get_secrets_with_info(configuration: &Configuration, params: GetSecretsParams) -> Result<crate::apis::Response<crate::models::InlineResponse2006>, Error<GetSecretsError>> {
+    super::block_on(secret_store_item_api::get_secrets_with_info(configuration, params))
+}
+
+/// Recreate a secret based on the secret's name. Returns an error if there is no existing secret with the same name.  The `secret` field must be Base64-encoded because a secret can contain binary data. In the example below, the unencoded secret is \"Hello, world!\" 
+pub fn must_recreate_secret(configuration: &Configuration, params: MustRecreateSecretParams) -> Result<crate::models::SecretResponse, Error<MustRecreateSecretError>> {
+    super::block_on(secret_store_item_api::must_recreate_secret(configuration, params))
+}
+
+/// Like [`must_recreate_secret`], but also returns the status and headers of the response.
+pub fn must_recreate_secret_with_info(configuration: &Configuration, params: MustRecreateSecretParams) -> Result<crate::apis::Response<crate::models::SecretResponse>, Error<MustRecreateSecretError>> {
+    super::block_on(secret_store_item_api::must_recreate_secret_with_info(configuration, params))
+}
+
+/// Create or recreate a secret based on the secret's name. The response object's `recreated` field will be true if the secret was recreated.  The `secret` field must be Base64-encoded because a secret can contain binary data. In the example below, the unencoded secret is \"Hello, world!\" 
+pub fn recreate_secret(configuration: &Configuration, params: RecreateSecretParams) -> Result<crate::models::SecretResponse, Error<RecreateSecretError>> {
+    super::block_on(secret_store_item_api::recreate_secret(configuration, params))
+}
+
+/// Like [`recreate_secret`], but also returns the status and headers of the response.
+pub fn recreate_secret_with_info(configuration: &Configuration, params: RecreateSecretParams) -> Result<crate::apis::Response<crate::models::SecretResponse>, Error<RecreateSecretError>> {
+    super::block_on(secret_store_item_api::recreate_secret_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{server_api, Error};
+
+pub use crate::apis::server_api::{CreatePoolServerError, CreatePoolServerParams, DeletePoolServerError, DeletePoolServerParams, GetPoolServerError, GetPoolServerParams, ListPoolServersError, ListPoolServersParams, UpdatePoolServerError, UpdatePoolServerParams};
+
+/// Creates a single server for a particular service and pool.
+pub fn create_pool_server(configuration: &Configuration, params: CreatePoolServerParams) -> Result<crate::models::ServerResponse, Error<CreatePoolServerError>> {
+    super::block_on(server_api::create_pool_server(configuration, params))
+}
+
+/// Like [`create_pool_server`], but also returns the status and headers of the response.
+pub fn create_pool_server_with_info(configuration: &Configuration, params: CreatePoolServerParams) -> Result<crate::apis::Response<crate::models::ServerResponse>, Error<CreatePoolServerError>> {
+    super::block_on(server_api::create_pool_server_with_info(configuration, params))
+}
+
+/// Deletes a single server for a particular service and pool.
+pub fn delete_pool_server(configuration: &Configuration, params: DeletePoolServerParams) -> Result<crate::models::InlineResponse200, Error<DeletePoolServerError>> {
+    super::block_on(server_api::delete_pool_server(configuration, params))
+}
+
+/// Like [`delete_pool_server`], but also returns the status and headers of the response.
+pub fn delete_pool_server_with_info(configuration: &Configuration, params: DeletePoolServerParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeletePoolServerError>> {
+    super::block_on(server_api::delete_pool_server_with_info(configuration, params))
+}
+
+/// Gets a single server for a particular service and pool.
+pub fn get_pool_server(configuration: &Configuration, params: GetPoolServerParams) -> Result<crate::models::ServerResponse, Error<GetPoolServerError>> {
+    super::block_on(server_api::get_pool_server(configuration, params))
+}
+
+/// Like [`get_pool_server`], but also returns the status and headers of the response.
+pub fn get_pool_server_with_info(configuration: &Configuration, params: GetPoolServerParams) -> Result<crate::apis::Response<crate::models::ServerResponse>, Error<GetPoolServerError>> {
+    super::block_on(server_api::get_pool_server_with_info(configuration, params))
+}
+
+/// Lists all servers for a particular service and pool.
+pub fn list_pool_servers(configuration: &Configuration, params: ListPoolServersParams) -> Result<Vec<crate::models::ServerResponse>, Error<ListPoolServersError>> {
+    super::block_on(server_api::list_pool_servers(configuration, params))
+}
+
+/// Like [`list_pool_servers`], but also returns the status and headers of the response.
+pub fn list_pool_servers_with_info(configuration: &Configuration, params: ListPoolServersParams) -> Result<crate::apis::Response<Vec<crate::models::ServerResponse>>, Error<ListPoolServersError>> {
+    super::block_on(server_api::list_pool_servers_with_info(configuration, params))
+}
+
+/// Updates a single server for a particular service and pool.
+pub fn update_pool_server(configuration: &Configuration, params: UpdatePoolServerParams) -> Result<crate::models::ServerResponse, Error<UpdatePoolServerError>> {
+    super::block_on(server_api::update_pool_server(configuration, params))
+}
+
+/// Like [`update_pool_server`], but also returns the status and headers of the response.
+pub fn update_pool_server_with_info(configuration: &Configuration, params: UpdatePoolServerParams) -> Result<crate::apis::Response<crate::models::ServerResponse>, Error<UpdatePoolServerError>> {
+    super::block_on(server_api::update_pool_server_with_info(configuration, params))
+}
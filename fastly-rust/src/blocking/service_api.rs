@@ -0,0 +1,91 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{service_api, Error};
+
+pub use crate::apis::service_api::{CreateServiceError, CreateServiceParams, DeleteServiceError, DeleteServiceParams, GetServiceDetailError, GetServiceDetailParams, GetServiceError, GetServiceParams, ListServiceDomainsError, ListServiceDomainsParams, ListServicesError, ListServicesParams, SearchServiceError, SearchServiceParams, UpdateServiceError, UpdateServiceParams};
+
+/// Create a service.
+pub fn create_service(configuration: &Configuration, params: CreateServiceParams) -> Result<crate::models::ServiceResponse, Error<CreateServiceError>> {
+    super::block_on(service_api::create_service(configuration, params))
+}
+
+/// Like [`create_service`], but also returns the status and headers of the response.
+pub fn create_service_with_info(configuration: &Configuration, params: CreateServiceParams) -> Result<crate::apis::Response<crate::models::ServiceResponse>, Error<CreateServiceError>> {
+    super::block_on(service_api::create_service_with_info(configuration, params))
+}
+
+/// Delete a service.
+pub fn delete_service(configuration: &Configuration, params: DeleteServiceParams) -> Result<crate::models::InlineResponse200, Error<DeleteServiceError>> {
+    super::block_on(service_api::delete_service(configuration, params))
+}
+
+/// Like [`delete_service`], but also returns the status and headers of the response.
+pub fn delete_service_with_info(configuration: &Configuration, params: DeleteServiceParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteServiceError>> {
+    super::block_on(service_api::delete_service_with_info(configuration, params))
+}
+
+/// Get a specific service by id.
+pub fn get_service(configuration: &Configuration, params: GetServiceParams) -> Result<crate::models::ServiceResponse, Error<GetServiceError>> {
+    super::block_on(service_api::get_service(configuration, params))
+}
+
+/// Like [`get_service`], but also returns the status and headers of the response.
+pub fn get_service_with_info(configuration: &Configuration, params: GetServiceParams) -> Result<crate::apis::Response<crate::models::ServiceResponse>, Error<GetServiceError>> {
+    super::block_on(service_api::get_service_with_info(configuration, params))
+}
+
+/// List detailed information on a specified service.
+pub fn get_service_detail(configuration: &Configuration, params: GetServiceDetailParams) -> Result<crate::models::ServiceDetail, Error<GetServiceDetailError>> {
+    super::block_on(service_api::get_service_detail(configuration, params))
+}
+
+/// Like [`get_service_detail`], but also returns the status and headers of the response.
+pub fn get_service_detail_with_info(configuration: &Configuration, params: GetServiceDetailParams) -> Result<crate::apis::Response<crate::models::ServiceDetail>, Error<GetServiceDetailError>> {
+    super::block_on(service_api::get_service_detail_with_info(configuration, params))
+}
+
+/// List the domains within a service.
+pub fn list_service_domains(configuration: &Configuration, params: ListServiceDomainsParams) -> Result<Vec<crate::models::DomainResponse>, Error<ListServiceDomainsError>> {
+    super::block_on(service_api::list_service_domains(configuration, params))
+}
+
+/// Like [`list_service_domains`], but also returns the status and headers of the response.
+pub fn list_service_domains_with_info(configuration: &Configuration, params: ListServiceDomainsParams) -> Result<crate::apis::Response<Vec<crate::models::DomainResponse>>, Error<ListServiceDomainsError>> {
+    super::block_on(service_api::list_service_domains_with_info(configuration, params))
+}
+
+/// List services.
+pub fn list_services(configuration: &Configuration, params: ListServicesParams) -> Result<Vec<crate::models::ServiceListResponse>, Error<ListServicesError>> {
+    super::block_on(service_api::list_services(configuration, params))
+}
+
+/// Like [`list_services`], but also returns the status and headers of the response.
+pub fn list_services_with_info(configuration: &Configuration, params: ListServicesParams) -> Result<crate::apis::Response<Vec<crate::models::ServiceListResponse>>, Error<ListServicesError>> {
+    super::block_on(service_api::list_services_with_info(configuration, params))
+}
+
+/// Get a specific service by name.
+pub fn search_service(configuration: &Configuration, params: SearchServiceParams) -> Result<crate::models::ServiceResponse, Error<SearchServiceError>> {
+    super::block_on(service_api::search_service(configuration, params))
+}
+
+/// Like [`search_service`], but also returns the status and headers of the response.
+pub fn search_service_with_info(configuration: &Configuration, params: SearchServiceParams) -> Result<crate::apis::Response<crate::models::ServiceResponse>, Error<SearchServiceError>> {
+    super::block_on(service_api::search_service_with_info(configuration, params))
+}
+
+/// Update a service.
+pub fn update_service(configuration: &Configuration, params: UpdateServiceParams) -> Result<crate::models::ServiceResponse, Error<UpdateServiceError>> {
+    super::block_on(service_api::update_service(configuration, params))
+}
+
+/// Like [`update_service`], but also returns the status and headers of the response.
+pub fn update_service_with_info(configuration: &Configuration, params: UpdateServiceParams) -> Result<crate::apis::Response<crate::models::ServiceResponse>, Error<UpdateServiceError>> {
+    super::block_on(service_api::update_service_with_info(configuration, params))
+}
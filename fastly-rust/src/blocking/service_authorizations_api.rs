@@ -0,0 +1,81 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{service_authorizations_api, Error};
+
+pub use crate::apis::service_authorizations_api::{CreateServiceAuthorizationError, CreateServiceAuthorizationParams, DeleteServiceAuthorization2Error, DeleteServiceAuthorization2Params, DeleteServiceAuthorizationError, DeleteServiceAuthorizationParams, ListServiceAuthorizationError, ListServiceAuthorizationParams, ShowServiceAuthorizationError, ShowServiceAuthorizationParams, UpdateServiceAuthorization2Error, UpdateServiceAuthorization2Params, UpdateServiceAuthorizationError, UpdateServiceAuthorizationParams};
+
+/// Create service authorization.
+pub fn create_service_authorization(configuration: &Configuration, params: CreateServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<CreateServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::create_service_authorization(configuration, params))
+}
+
+/// Like [`create_service_authorization`], but also returns the status and headers of the response.
+pub fn create_service_authorization_with_info(configuration: &Configuration, params: CreateServiceAuthorizationParams) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationResponse>, Error<CreateServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::create_service_authorization_with_info(configuration, params))
+}
+
+/// Delete service authorization.
+pub fn delete_service_authorization(configuration: &Configuration, params: DeleteServiceAuthorizationParams) -> Result<(), Error<DeleteServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::delete_service_authorization(configuration, params))
+}
+
+/// Like [`delete_service_authorization`], but also returns the status and headers of the response.
+pub fn delete_service_authorization_with_info(configuration: &Configuration, params: DeleteServiceAuthorizationParams) -> Result<crate::apis::Response<()>, Error<DeleteServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::delete_service_authorization_with_info(configuration, params))
+}
+
+/// Delete service authorizations.
+pub fn delete_service_authorization2(configuration: &Configuration, params: DeleteServiceAuthorization2Params) -> Result<crate::models::InlineResponse2007, Error<DeleteServiceAuthorization2Error>> {
+    super::block_on(service_authorizations_api::delete_service_authorization2(configuration, params))
+}
+
+/// Like [`delete_service_authorization2`], but also returns the status and headers of the response.
+pub fn delete_service_authorization2_with_info(configuration: &Configuration, params: DeleteServiceAuthorization2Params) -> Result<crate::apis::Response<crate::models::InlineResponse2007>, Error<DeleteServiceAuthorization2Error>> {
+    super::block_on(service_authorizations_api::delete_service_authorization2_with_info(configuration, params))
+}
+
+/// List service authorizations.
+pub fn list_service_authorization(configuration: &Configuration, params: ListServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationsResponse, Error<ListServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::list_service_authorization(configuration, params))
+}
+
+/// Like [`list_service_authorization`], but also returns the status and headers of the response.
+pub fn list_service_authorization_with_info(configuration: &Configuration, params: ListServiceAuthorizationParams) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationsResponse>, Error<ListServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::list_service_authorization_with_info(configuration, params))
+}
+
+/// Show service authorization.
+pub fn show_service_authorization(configuration: &Configuration, params: ShowServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<ShowServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::show_service_authorization(configuration, params))
+}
+
+/// Like [`show_service_authorization`], but also returns the status and headers of the response.
+pub fn show_service_authorization_with_info(configuration: &Configuration, params: ShowServiceAuthorizationParams) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationResponse>, Error<ShowServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::show_service_authorization_with_info(configuration, params))
+}
+
+/// Update service authorization.
+pub fn update_service_authorization(configuration: &Configuration, params: UpdateServiceAuthorizationParams) -> Result<crate::models::ServiceAuthorizationResponse, Error<UpdateServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::update_service_authorization(configuration, params))
+}
+
+/// Like [`update_service_authorization`], but also returns the status and headers of the response.
+pub fn update_service_authorization_with_info(configuration: &Configuration, params: UpdateServiceAuthorizationParams) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationResponse>, Error<UpdateServiceAuthorizationError>> {
+    super::block_on(service_authorizations_api::update_service_authorization_with_info(configuration, params))
+}
+
+/// Update service authorizations.
+pub fn update_service_authorization2(configuration: &Configuration, params: UpdateServiceAuthorization2Params) -> Result<crate::models::ServiceAuthorizationsResponse, Error<UpdateServiceAuthorization2Error>> {
+    super::block_on(service_authorizations_api::update_service_authorization2(configuration, params))
+}
+
+/// Like [`update_service_authorization2`], but also returns the status and headers of the response.
+pub fn update_service_authorization2_with_info(configuration: &Configuration, params: UpdateServiceAuthorization2Params) -> Result<crate::apis::Response<crate::models::ServiceAuthorizationsResponse>, Error<UpdateServiceAuthorization2Error>> {
+    super::block_on(service_authorizations_api::update_service_authorization2_with_info(configuration, params))
+}
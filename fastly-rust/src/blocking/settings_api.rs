@@ -0,0 +1,31 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{settings_api, Error};
+
+pub use crate::apis::settings_api::{GetServiceSettingsError, GetServiceSettingsParams, UpdateServiceSettingsError, UpdateServiceSettingsParams};
+
+/// Get the settings for a particular service and version.
+pub fn get_service_settings(configuration: &Configuration, params: GetServiceSettingsParams) -> Result<crate::models::SettingsResponse, Error<GetServiceSettingsError>> {
+    super::block_on(settings_api::get_service_settings(configuration, params))
+}
+
+/// Like [`get_service_settings`], but also returns the status and headers of the response.
+pub fn get_service_settings_with_info(configuration: &Configuration, params: GetServiceSettingsParams) -> Result<crate::apis::Response<crate::models::SettingsResponse>, Error<GetServiceSettingsError>> {
+    super::block_on(settings_api::get_service_settings_with_info(configuration, params))
+}
+
+/// Update the settings for a particular service and version. NOTE: If you override TTLs with custom VCL, any general.default_ttl value will not be honored and the expected behavior may change. 
+pub fn update_service_settings(configuration: &Configuration, params: UpdateServiceSettingsParams) -> Result<crate::models::SettingsResponse, Error<UpdateServiceSettingsError>> {
+    super::block_on(settings_api::update_service_settings(configuration, params))
+}
+
+/// Like [`update_service_settings`], but also returns the status and headers of the response.
+pub fn update_service_settings_with_info(configuration: &Configuration, params: UpdateServiceSettingsParams) -> Result<crate::apis::Response<crate::models::SettingsResponse>, Error<UpdateServiceSettingsError>> {
+    super::block_on(settings_api::update_service_settings_with_info(configuration, params))
+}
@@ -0,0 +1,81 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{snippet_api, Error};
+
+pub use crate::apis::snippet_api::{CreateSnippetError, CreateSnippetParams, DeleteSnippetError, DeleteSnippetParams, GetSnippetDynamicError, GetSnippetDynamicParams, GetSnippetError, GetSnippetParams, ListSnippetsError, ListSnippetsParams, UpdateSnippetDynamicError, UpdateSnippetDynamicParams, UpdateSnippetError, UpdateSnippetParams};
+
+/// Create a snippet for a particular service and version.
+pub fn create_snippet(configuration: &Configuration, params: CreateSnippetParams) -> Result<crate::models::SnippetResponsePost, Error<CreateSnippetError>> {
+    super::block_on(snippet_api::create_snippet(configuration, params))
+}
+
+/// Like [`create_snippet`], but also returns the status and headers of the response.
+pub fn create_snippet_with_info(configuration: &Configuration, params: CreateSnippetParams) -> Result<crate::apis::Response<crate::models::SnippetResponsePost>, Error<CreateSnippetError>> {
+    super::block_on(snippet_api::create_snippet_with_info(configuration, params))
+}
+
+/// Delete a specific snippet for a particular service and version.
+pub fn delete_snippet(configuration: &Configuration, params: DeleteSnippetParams) -> Result<crate::models::InlineResponse200, Error<DeleteSnippetError>> {
+    super::block_on(snippet_api::delete_snippet(configuration, params))
+}
+
+/// Like [`delete_snippet`], but also returns the status and headers of the response.
+pub fn delete_snippet_with_info(configuration: &Configuration, params: DeleteSnippetParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteSnippetError>> {
+    super::block_on(snippet_api::delete_snippet_with_info(configuration, params))
+}
+
+/// Get a single snippet for a particular service and version.
+pub fn get_snippet(configuration: &Configuration, params: GetSnippetParams) -> Result<crate::models::SnippetResponse, Error<GetSnippetError>> {
+    super::block_on(snippet_api::get_snippet(configuration, params))
+}
+
+/// Like [`get_snippet`], but also returns the status and headers of the response.
+pub fn get_snippet_with_info(configuration: &Configuration, params: GetSnippetParams) -> Result<crate::apis::Response<crate::models::SnippetResponse>, Error<GetSnippetError>> {
+    super::block_on(snippet_api::get_snippet_with_info(configuration, params))
+}
+
+/// Get a single dynamic snippet for a particular service.
+pub fn get_snippet_dynamic(configuration: &Configuration, params: GetSnippetDynamicParams) -> Result<crate::models::SnippetResponse, Error<GetSnippetDynamicError>> {
+    super::block_on(snippet_api::get_snippet_dynamic(configuration, params))
+}
+
+/// Like [`get_snippet_dynamic`], but also returns the status and headers of the response.
+pub fn get_snippet_dynamic_with_info(configuration: &Configuration, params: GetSnippetDynamicParams) -> Result<crate::apis::Response<crate::models::SnippetResponse>, Error<GetSnippetDynamicError>> {
+    super::block_on(snippet_api::get_snippet_dynamic_with_info(configuration, params))
+}
+
+/// List all snippets for a particular service and version.
+pub fn list_snippets(configuration: &Configuration, params: ListSnippetsParams) -> Result<Vec<crate::models::SnippetResponse>, Error<ListSnippetsError>> {
+    super::block_on(snippet_api::list_snippets(configuration, params))
+}
+
+/// Like [`list_snippets`], but also returns the status and headers of the response.
+pub fn list_snippets_with_info(configuration: &Configuration, params: ListSnippetsParams) -> Result<crate::apis::Response<Vec<crate::models::SnippetResponse>>, Error<ListSnippetsError>> {
+    super::block_on(snippet_api::list_snippets_with_info(configuration, params))
+}
+
+/// Update a specific snippet for a particular service and version.
+pub fn update_snippet(configuration: &Configuration, params: UpdateSnippetParams) -> Result<crate::models::SnippetResponse, Error<UpdateSnippetError>> {
+    super::block_on(snippet_api::update_snippet(configuration, params))
+}
+
+/// Like [`update_snippet`], but also returns the status and headers of the response.
+pub fn update_snippet_with_info(configuration: &Configuration, params: UpdateSnippetParams) -> Result<crate::apis::Response<crate::models::SnippetResponse>, Error<UpdateSnippetError>> {
+    super::block_on(snippet_api::update_snippet_with_info(configuration, params))
+}
+
+/// Update a dynamic snippet for a particular service.
+pub fn update_snippet_dynamic(configuration: &Configuration, params: UpdateSnippetDynamicParams) -> Result<crate::models::SnippetResponse, Error<UpdateSnippetDynamicError>> {
+    super::block_on(snippet_api::update_snippet_dynamic(configuration, params))
+}
+
+/// Like [`update_snippet_dynamic`], but also returns the status and headers of the response.
+pub fn update_snippet_dynamic_with_info(configuration: &Configuration, params: UpdateSnippetDynamicParams) -> Result<crate::apis::Response<crate::models::SnippetResponse>, Error<UpdateSnippetDynamicError>> {
+    super::block_on(snippet_api::update_snippet_dynamic_with_info(configuration, params))
+}
@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{star_api, Error};
+
+pub use crate::apis::star_api::{CreateServiceStarError, CreateServiceStarParams, DeleteServiceStarError, DeleteServiceStarParams, GetServiceStarError, GetServiceStarParams, ListServiceStarsError};
+
+/// Create star.
+pub fn create_service_star(configuration: &Configuration, params: CreateServiceStarParams) -> Result<crate::models::StarResponse, Error<CreateServiceStarError>> {
+    super::block_on(star_api::create_service_star(configuration, params))
+}
+
+/// Like [`create_service_star`], but also returns the status and headers of the response.
+pub fn create_service_star_with_info(configuration: &Configuration, params: CreateServiceStarParams) -> Result<crate::apis::Response<crate::models::StarResponse>, Error<CreateServiceStarError>> {
+    super::block_on(star_api::create_service_star_with_info(configuration, params))
+}
+
+/// Delete star.
+pub fn delete_service_star(configuration: &Configuration, params: DeleteServiceStarParams) -> Result<(), Error<DeleteServiceStarError>> {
+    super::block_on(star_api::delete_service_star(configuration, params))
+}
+
+/// Like [`delete_service_star`], but also returns the status and headers of the response.
+pub fn delete_service_star_with_info(configuration: &Configuration, params: DeleteServiceStarParams) -> Result<crate::apis::Response<()>, Error<DeleteServiceStarError>> {
+    super::block_on(star_api::delete_service_star_with_info(configuration, params))
+}
+
+/// Show star.
+pub fn get_service_star(configuration: &Configuration, params: GetServiceStarParams) -> Result<crate::models::StarResponse, Error<GetServiceStarError>> {
+    super::block_on(star_api::get_service_star(configuration, params))
+}
+
+/// Like [`get_service_star`], but also returns the status and headers of the response.
+pub fn get_service_star_with_info(configuration: &Configuration, params: GetServiceStarParams) -> Result<crate::apis::Response<crate::models::StarResponse>, Error<GetServiceStarError>> {
+    super::block_on(star_api::get_service_star_with_info(configuration, params))
+}
+
+/// List stars.
+pub fn list_service_stars(configuration: &Configuration) -> Result<crate::models::Pagination, Error<ListServiceStarsError>> {
+    super::block_on(star_api::list_service_stars(configuration))
+}
+
+/// Like [`list_service_stars`], but also returns the status and headers of the response.
+pub fn list_service_stars_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::Pagination>, Error<ListServiceStarsError>> {
+    super::block_on(star_api::list_service_stars_with_info(configuration))
+}
@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{stats_api, Error};
+
+pub use crate::apis::stats_api::{GetServiceStatsError, GetServiceStatsParams};
+
+/// Get the stats from a service for a block of time. This lists all stats by PoP location, starting with AMS. This call requires parameters to select block of time to query. Use either a timestamp range (using start_time and end_time) or a specified month/year combo (using month and year).
+pub fn get_service_stats(configuration: &Configuration, params: GetServiceStatsParams) -> Result<crate::models::Stats, Error<GetServiceStatsError>> {
+    super::block_on(stats_api::get_service_stats(configuration, params))
+}
+
+/// Like [`get_service_stats`], but also returns the status and headers of the response.
+pub fn get_service_stats_with_info(configuration: &Configuration, params: GetServiceStatsParams) -> Result<crate::apis::Response<crate::models::Stats>, Error<GetServiceStatsError>> {
+    super::block_on(stats_api::get_service_stats_with_info(configuration, params))
+}
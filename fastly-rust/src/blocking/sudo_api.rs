@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{sudo_api, Error};
+
+pub use crate::apis::sudo_api::{RequestSudoAccessError, RequestSudoAccessParams};
+
+/// Re-authenticate to allow the provided user to obtain sudo access.
+pub fn request_sudo_access(configuration: &Configuration, params: RequestSudoAccessParams) -> Result<crate::models::SudoResponse, Error<RequestSudoAccessError>> {
+    super::block_on(sudo_api::request_sudo_access(configuration, params))
+}
+
+/// Like [`request_sudo_access`], but also returns the status and headers of the response.
+pub fn request_sudo_access_with_info(configuration: &Configuration, params: RequestSudoAccessParams) -> Result<crate::apis::Response<crate::models::SudoResponse>, Error<RequestSudoAccessError>> {
+    super::block_on(sudo_api::request_sudo_access_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tls_activations_api, Error};
+
+pub use crate::apis::tls_activations_api::{CreateTlsActivationError, CreateTlsActivationParams, DeleteTlsActivationError, DeleteTlsActivationParams, GetTlsActivationError, GetTlsActivationParams, ListTlsActivationsError, ListTlsActivationsParams, UpdateTlsActivationError, UpdateTlsActivationParams};
+
+/// Enable TLS for a particular TLS domain and certificate combination. These relationships must be specified to create the TLS activation.
+pub fn create_tls_activation(configuration: &Configuration, params: CreateTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<CreateTlsActivationError>> {
+    super::block_on(tls_activations_api::create_tls_activation(configuration, params))
+}
+
+/// Like [`create_tls_activation`], but also returns the status and headers of the response.
+pub fn create_tls_activation_with_info(configuration: &Configuration, params: CreateTlsActivationParams) -> Result<crate::apis::Response<crate::models::TlsActivationResponse>, Error<CreateTlsActivationError>> {
+    super::block_on(tls_activations_api::create_tls_activation_with_info(configuration, params))
+}
+
+/// Disable TLS on the domain associated with this TLS activation.
+pub fn delete_tls_activation(configuration: &Configuration, params: DeleteTlsActivationParams) -> Result<(), Error<DeleteTlsActivationError>> {
+    super::block_on(tls_activations_api::delete_tls_activation(configuration, params))
+}
+
+/// Like [`delete_tls_activation`], but also returns the status and headers of the response.
+pub fn delete_tls_activation_with_info(configuration: &Configuration, params: DeleteTlsActivationParams) -> Result<crate::apis::Response<()>, Error<DeleteTlsActivationError>> {
+    super::block_on(tls_activations_api::delete_tls_activation_with_info(configuration, params))
+}
+
+/// Show a TLS activation.
+pub fn get_tls_activation(configuration: &Configuration, params: GetTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<GetTlsActivationError>> {
+    super::block_on(tls_activations_api::get_tls_activation(configuration, params))
+}
+
+/// Like [`get_tls_activation`], but also returns the status and headers of the response.
+pub fn get_tls_activation_with_info(configuration: &Configuration, params: GetTlsActivationParams) -> Result<crate::apis::Response<crate::models::TlsActivationResponse>, Error<GetTlsActivationError>> {
+    super::block_on(tls_activations_api::get_tls_activation_with_info(configuration, params))
+}
+
+/// List all TLS activations.
+pub fn list_tls_activations(configuration: &Configuration, params: ListTlsActivationsParams) -> Result<crate::models::TlsActivationsResponse, Error<ListTlsActivationsError>> {
+    super::block_on(tls_activations_api::list_tls_activations(configuration, params))
+}
+
+/// Like [`list_tls_activations`], but also returns the status and headers of the response.
+pub fn list_tls_activations_with_info(configuration: &Configuration, params: ListTlsActivationsParams) -> Result<crate::apis::Response<crate::models::TlsActivationsResponse>, Error<ListTlsActivationsError>> {
+    super::block_on(tls_activations_api::list_tls_activations_with_info(configuration, params))
+}
+
+/// Update the certificate used to terminate TLS traffic for the domain associated with this TLS activation.
+pub fn update_tls_activation(configuration: &Configuration, params: UpdateTlsActivationParams) -> Result<crate::models::TlsActivationResponse, Error<UpdateTlsActivationError>> {
+    super::block_on(tls_activations_api::update_tls_activation(configuration, params))
+}
+
+/// Like [`update_tls_activation`], but also returns the status and headers of the response.
+pub fn update_tls_activation_with_info(configuration: &Configuration, params: UpdateTlsActivationParams) -> Result<crate::apis::Response<crate::models::TlsActivationResponse>, Error<UpdateTlsActivationError>> {
+    super::block_on(tls_activations_api::update_tls_activation_with_info(configuration, params))
+}
@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tls_bulk_certificates_api, Error};
+
+pub use crate::apis::tls_bulk_certificates_api::{DeleteBulkTlsCertError, DeleteBulkTlsCertParams, GetTlsBulkCertError, GetTlsBulkCertParams, ListTlsBulkCertsError, ListTlsBulkCertsParams, UpdateBulkTlsCertError, UpdateBulkTlsCertParams, UploadTlsBulkCertError, UploadTlsBulkCertParams};
+
+/// Destroy a certificate. This disables TLS for all domains listed as SAN entries.
+pub fn delete_bulk_tls_cert(configuration: &Configuration, params: DeleteBulkTlsCertParams) -> Result<(), Error<DeleteBulkTlsCertError>> {
+    super::block_on(tls_bulk_certificates_api::delete_bulk_tls_cert(configuration, params))
+}
+
+/// Like [`delete_bulk_tls_cert`], but also returns the status and headers of the response.
+pub fn delete_bulk_tls_cert_with_info(configuration: &Configuration, params: DeleteBulkTlsCertParams) -> Result<crate::apis::Response<()>, Error<DeleteBulkTlsCertError>> {
+    super::block_on(tls_bulk_certificates_api::delete_bulk_tls_cert_with_info(configuration, params))
+}
+
+/// Retrieve a single certificate.
+pub fn get_tls_bulk_cert(configuration: &Configuration, params: GetTlsBulkCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<GetTlsBulkCertError>> {
+    super::block_on(tls_bulk_certificates_api::get_tls_bulk_cert(configuration, params))
+}
+
+/// Like [`get_tls_bulk_cert`], but also returns the status and headers of the response.
+pub fn get_tls_bulk_cert_with_info(configuration: &Configuration, params: GetTlsBulkCertParams) -> Result<crate::apis::Response<crate::models::TlsBulkCertificateResponse>, Error<GetTlsBulkCertError>> {
+    super::block_on(tls_bulk_certificates_api::get_tls_bulk_cert_with_info(configuration, params))
+}
+
+/// List all certificates.
+pub fn list_tls_bulk_certs(configuration: &Configuration, params: ListTlsBulkCertsParams) -> Result<crate::models::TlsBulkCertificatesResponse, Error<ListTlsBulkCertsError>> {
+    super::block_on(tls_bulk_certificates_api::list_tls_bulk_certs(configuration, params))
+}
+
+/// Like [`list_tls_bulk_certs`], but also returns the status and headers of the response.
+pub fn list_tls_bulk_certs_with_info(configuration: &Configuration, params: ListTlsBulkCertsParams) -> Result<crate::apis::Response<crate::models::TlsBulkCertificatesResponse>, Error<ListTlsBulkCertsError>> {
+    super::block_on(tls_bulk_certificates_api::list_tls_bulk_certs_with_info(configuration, params))
+}
+
+/// Replace a certificate with a newly reissued certificate. By using this endpoint, the original certificate will cease to be used for future TLS handshakes. Thus, only SAN entries that appear in the replacement certificate will become TLS enabled. Any SAN entries that are missing in the replacement certificate will become disabled.
+pub fn update_bulk_tls_cert(configuration: &Configuration, params: UpdateBulkTlsCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<UpdateBulkTlsCertError>> {
+    super::block_on(tls_bulk_certificates_api::update_bulk_tls_cert(configuration, params))
+}
+
+/// Like [`update_bulk_tls_cert`], but also returns the status and headers of the response.
+pub fn update_bulk_tls_cert_with_info(configuration: &Configuration, params: UpdateBulkTlsCertParams) -> Result<crate::apis::Response<crate::models::TlsBulkCertificateResponse>, Error<UpdateBulkTlsCertError>> {
+    super::block_on(tls_bulk_certificates_api::update_bulk_tls_cert_with_info(configuration, params))
+}
+
+/// Upload a new certificate. TLS domains are automatically enabled upon certificate creation. If a domain is already enabled on a previously uploaded certificate, that domain will be updated to use the new certificate for all future TLS handshake requests.
+pub fn upload_tls_bulk_cert(configuration: &Configuration, params: UploadTlsBulkCertParams) -> Result<crate::models::TlsBulkCertificateResponse, Error<UploadTlsBulkCertError>> {
+    super::block_on(tls_bulk_certificates_api::upload_tls_bulk_cert(configuration, params))
+}
+
+/// Like [`upload_tls_bulk_cert`], but also returns the status and headers of the response.
+pub fn upload_tls_bulk_cert_with_info(configuration: &Configuration, params: UploadTlsBulkCertParams) -> Result<crate::apis::Response<crate::models::TlsBulkCertificateResponse>, Error<UploadTlsBulkCertError>> {
+    super::block_on(tls_bulk_certificates_api::upload_tls_bulk_cert_with_info(configuration, params))
+}
@@ -0,0 +1,71 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tls_certificates_api, Error};
+
+pub use crate::apis::tls_certificates_api::{CreateTlsCertError, CreateTlsCertParams, DeleteTlsCertError, DeleteTlsCertParams, GetTlsCertBlobError, GetTlsCertBlobParams, GetTlsCertError, GetTlsCertParams, ListTlsCertsError, ListTlsCertsParams, UpdateTlsCertError, UpdateTlsCertParams};
+
+/// Create a TLS certificate.
+pub fn create_tls_cert(configuration: &Configuration, params: CreateTlsCertParams) -> Result<serde_json::Value, Error<CreateTlsCertError>> {
+    super::block_on(tls_certificates_api::create_tls_cert(configuration, params))
+}
+
+/// Like [`create_tls_cert`], but also returns the status and headers of the response.
+pub fn create_tls_cert_with_info(configuration: &Configuration, params: CreateTlsCertParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateTlsCertError>> {
+    super::block_on(tls_certificates_api::create_tls_cert_with_info(configuration, params))
+}
+
+/// Destroy a TLS certificate. TLS certificates already enabled for a domain cannot be destroyed.
+pub fn delete_tls_cert(configuration: &Configuration, params: DeleteTlsCertParams) -> Result<(), Error<DeleteTlsCertError>> {
+    super::block_on(tls_certificates_api::delete_tls_cert(configuration, params))
+}
+
+/// Like [`delete_tls_cert`], but also returns the status and headers of the response.
+pub fn delete_tls_cert_with_info(configuration: &Configuration, params: DeleteTlsCertParams) -> Result<crate::apis::Response<()>, Error<DeleteTlsCertError>> {
+    super::block_on(tls_certificates_api::delete_tls_cert_with_info(configuration, params))
+}
+
+/// Show a TLS certificate.
+pub fn get_tls_cert(configuration: &Configuration, params: GetTlsCertParams) -> Result<crate::models::TlsCertificateResponse, Error<GetTlsCertError>> {
+    super::block_on(tls_certificates_api::get_tls_cert(configuration, params))
+}
+
+/// Like [`get_tls_cert`], but also returns the status and headers of the response.
+pub fn get_tls_cert_with_info(configuration: &Configuration, params: GetTlsCertParams) -> Result<crate::apis::Response<crate::models::TlsCertificateResponse>, Error<GetTlsCertError>> {
+    super::block_on(tls_certificates_api::get_tls_cert_with_info(configuration, params))
+}
+
+/// Retrieve a TLS certificate blob. This feature is part of a [limited availability](https://docs.fastly.com/products/fastly-product-lifecycle#limited-availability) release.
+pub fn get_tls_cert_blob(configuration: &Configuration, params: GetTlsCertBlobParams) -> Result<crate::models::TlsCertificateBlobResponse, Error<GetTlsCertBlobError>> {
+    super::block_on(tls_certificates_api::get_tls_cert_blob(configuration, params))
+}
+
+/// Like [`get_tls_cert_blob`], but also returns the status and headers of the response.
+pub fn get_tls_cert_blob_with_info(configuration: &Configuration, params: GetTlsCertBlobParams) -> Result<crate::apis::Response<crate::models::TlsCertificateBlobResponse>, Error<GetTlsCertBlobError>> {
+    super::block_on(tls_certificates_api::get_tls_cert_blob_with_info(configuration, params))
+}
+
+/// List all TLS certificates.
+pub fn list_tls_certs(configuration: &Configuration, params: ListTlsCertsParams) -> Result<crate::models::TlsCertificatesResponse, Error<ListTlsCertsError>> {
+    super::block_on(tls_certificates_api::list_tls_certs(configuration, params))
+}
+
+/// Like [`list_tls_certs`], but also returns the status and headers of the response.
+pub fn list_tls_certs_with_info(configuration: &Configuration, params: ListTlsCertsParams) -> Result<crate::apis::Response<crate::models::TlsCertificatesResponse>, Error<ListTlsCertsError>> {
+    super::block_on(tls_certificates_api::list_tls_certs_with_info(configuration, params))
+}
+
+/// Replace a TLS certificate with a newly reissued TLS certificate, or update a TLS certificate's name. If replacing a TLS certificate, the new TLS certificate must contain all SAN entries as the current TLS certificate. It must either have an exact matching list or contain a superset.
+pub fn update_tls_cert(configuration: &Configuration, params: UpdateTlsCertParams) -> Result<crate::models::TlsCertificateResponse, Error<UpdateTlsCertError>> {
+    super::block_on(tls_certificates_api::update_tls_cert(configuration, params))
+}
+
+/// Like [`update_tls_cert`], but also returns the status and headers of the response.
+pub fn update_tls_cert_with_info(configuration: &Configuration, params: UpdateTlsCertParams) -> Result<crate::apis::Response<crate::models::TlsCertificateResponse>, Error<UpdateTlsCertError>> {
+    super::block_on(tls_certificates_api::update_tls_cert_with_info(configuration, params))
+}
@@ -0,0 +1,41 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tls_configurations_api, Error};
+
+pub use crate::apis::tls_configurations_api::{GetTlsConfigError, GetTlsConfigParams, ListTlsConfigsError, ListTlsConfigsParams, UpdateTlsConfigError, UpdateTlsConfigParams};
+
+/// Show a TLS configuration.
+pub fn get_tls_config(configuration: &Configuration, params: GetTlsConfigParams) -> Result<crate::models::TlsConfigurationResponse, Error<GetTlsConfigError>> {
+    super::block_on(tls_configurations_api::get_tls_config(configuration, params))
+}
+
+/// Like [`get_tls_config`], but also returns the status and headers of the response.
+pub fn get_tls_config_with_info(configuration: &Configuration, params: GetTlsConfigParams) -> Result<crate::apis::Response<crate::models::TlsConfigurationResponse>, Error<GetTlsConfigError>> {
+    super::block_on(tls_configurations_api::get_tls_config_with_info(configuration, params))
+}
+
+/// List all TLS configurations.
+pub fn list_tls_configs(configuration: &Configuration, params: ListTlsConfigsParams) -> Result<crate::models::TlsConfigurationsResponse, Error<ListTlsConfigsError>> {
+    super::block_on(tls_configurations_api::list_tls_configs(configuration, params))
+}
+
+/// Like [`list_tls_configs`], but also returns the status and headers of the response.
+pub fn list_tls_configs_with_info(configuration: &Configuration, params: ListTlsConfigsParams) -> Result<crate::apis::Response<crate::models::TlsConfigurationsResponse>, Error<ListTlsConfigsError>> {
+    super::block_on(tls_configurations_api::list_tls_configs_with_info(configuration, params))
+}
+
+/// Update a TLS configuration.
+pub fn update_tls_config(configuration: &Configuration, params: UpdateTlsConfigParams) -> Result<crate::models::TlsConfigurationResponse, Error<UpdateTlsConfigError>> {
+    super::block_on(tls_configurations_api::update_tls_config(configuration, params))
+}
+
+/// Like [`update_tls_config`], but also returns the status and headers of the response.
+pub fn update_tls_config_with_info(configuration: &Configuration, params: UpdateTlsConfigParams) -> Result<crate::apis::Response<crate::models::TlsConfigurationResponse>, Error<UpdateTlsConfigError>> {
+    super::block_on(tls_configurations_api::update_tls_config_with_info(configuration, params))
+}
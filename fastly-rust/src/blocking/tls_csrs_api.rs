@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tls_csrs_api, Error};
+
+pub use crate::apis::tls_csrs_api::{CreateCsrError, CreateCsrParams};
+
+/// Creates a certificate signing request (CSR).
+pub fn create_csr(configuration: &Configuration, params: CreateCsrParams) -> Result<crate::models::TlsCsrResponse, Error<CreateCsrError>> {
+    super::block_on(tls_csrs_api::create_csr(configuration, params))
+}
+
+/// Like [`create_csr`], but also returns the status and headers of the response.
+pub fn create_csr_with_info(configuration: &Configuration, params: CreateCsrParams) -> Result<crate::apis::Response<crate::models::TlsCsrResponse>, Error<CreateCsrError>> {
+    super::block_on(tls_csrs_api::create_csr_with_info(configuration, params))
+}
@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tls_domains_api, Error};
+
+pub use crate::apis::tls_domains_api::{ListTlsDomainsError, ListTlsDomainsParams};
+
+/// List all TLS domains.
+pub fn list_tls_domains(configuration: &Configuration, params: ListTlsDomainsParams) -> Result<crate::models::TlsDomainsResponse, Error<ListTlsDomainsError>> {
+    super::block_on(tls_domains_api::list_tls_domains(configuration, params))
+}
+
+/// Like [`list_tls_domains`], but also returns the status and headers of the response.
+pub fn list_tls_domains_with_info(configuration: &Configuration, params: ListTlsDomainsParams) -> Result<crate::apis::Response<crate::models::TlsDomainsResponse>, Error<ListTlsDomainsError>> {
+    super::block_on(tls_domains_api::list_tls_domains_with_info(configuration, params))
+}
@@ -0,0 +1,51 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tls_private_keys_api, Error};
+
+pub use crate::apis::tls_private_keys_api::{CreateTlsKeyError, CreateTlsKeyParams, DeleteTlsKeyError, DeleteTlsKeyParams, GetTlsKeyError, GetTlsKeyParams, ListTlsKeysError, ListTlsKeysParams};
+
+/// Create a TLS private key.
+pub fn create_tls_key(configuration: &Configuration, params: CreateTlsKeyParams) -> Result<crate::models::TlsPrivateKeyResponse, Error<CreateTlsKeyError>> {
+    super::block_on(tls_private_keys_api::create_tls_key(configuration, params))
+}
+
+/// Like [`create_tls_key`], but also returns the status and headers of the response.
+pub fn create_tls_key_with_info(configuration: &Configuration, params: CreateTlsKeyParams) -> Result<crate::apis::Response<crate::models::TlsPrivateKeyResponse>, Error<CreateTlsKeyError>> {
+    super::block_on(tls_private_keys_api::create_tls_key_with_info(configuration, params))
+}
+
+/// Destroy a TLS private key. Only private keys not already matched to any certificates can be deleted.
+pub fn delete_tls_key(configuration: &Configuration, params: DeleteTlsKeyParams) -> Result<(), Error<DeleteTlsKeyError>> {
+    super::block_on(tls_private_keys_api::delete_tls_key(configuration, params))
+}
+
+/// Like [`delete_tls_key`], but also returns the status and headers of the response.
+pub fn delete_tls_key_with_info(configuration: &Configuration, params: DeleteTlsKeyParams) -> Result<crate::apis::Response<()>, Error<DeleteTlsKeyError>> {
+    super::block_on(tls_private_keys_api::delete_tls_key_with_info(configuration, params))
+}
+
+/// Show a TLS private key.
+pub fn get_tls_key(configuration: &Configuration, params: GetTlsKeyParams) -> Result<crate::models::TlsPrivateKeyResponse, Error<GetTlsKeyError>> {
+    super::block_on(tls_private_keys_api::get_tls_key(configuration, params))
+}
+
+/// Like [`get_tls_key`], but also returns the status and headers of the response.
+pub fn get_tls_key_with_info(configuration: &Configuration, params: GetTlsKeyParams) -> Result<crate::apis::Response<crate::models::TlsPrivateKeyResponse>, Error<GetTlsKeyError>> {
+    super::block_on(tls_private_keys_api::get_tls_key_with_info(configuration, params))
+}
+
+/// List all TLS private keys.
+pub fn list_tls_keys(configuration: &Configuration, params: ListTlsKeysParams) -> Result<crate::models::TlsPrivateKeysResponse, Error<ListTlsKeysError>> {
+    super::block_on(tls_private_keys_api::list_tls_keys(configuration, params))
+}
+
+/// Like [`list_tls_keys`], but also returns the status and headers of the response.
+pub fn list_tls_keys_with_info(configuration: &Configuration, params: ListTlsKeysParams) -> Result<crate::apis::Response<crate::models::TlsPrivateKeysResponse>, Error<ListTlsKeysError>> {
+    super::block_on(tls_private_keys_api::list_tls_keys_with_info(configuration, params))
+}
@@ -0,0 +1,81 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tls_subscriptions_api, Error};
+
+pub use crate::apis::tls_subscriptions_api::{CreateGlobalsignEmailChallengeError, CreateGlobalsignEmailChallengeParams, CreateTlsSubError, CreateTlsSubParams, DeleteGlobalsignEmailChallengeError, DeleteGlobalsignEmailChallengeParams, DeleteTlsSubError, DeleteTlsSubParams, GetTlsSubError, GetTlsSubParams, ListTlsSubsError, ListTlsSubsParams, PatchTlsSubError, PatchTlsSubParams};
+
+/// Creates an email challenge for a domain on a GlobalSign subscription. An email challenge will generate an email that can be used to validate domain ownership. If this challenge is created, then the domain can only be validated using email for the given subscription. 
+pub fn create_globalsign_email_challenge(configuration: &Configuration, params: CreateGlobalsignEmailChallengeParams) -> Result<serde_json::Value, Error<CreateGlobalsignEmailChallengeError>> {
+    super::block_on(tls_subscriptions_api::create_globalsign_email_challenge(configuration, params))
+}
+
+/// Like [`create_globalsign_email_challenge`], but also returns the status and headers of the response.
+pub fn create_globalsign_email_challenge_with_info(configuration: &Configuration, params: CreateGlobalsignEmailChallengeParams) -> Result<crate::apis::Response<serde_json::Value>, Error<CreateGlobalsignEmailChallengeError>> {
+    super::block_on(tls_subscriptions_api::create_globalsign_email_challenge_with_info(configuration, params))
+}
+
+/// Create a new TLS subscription. This response includes a list of possible challenges to verify domain ownership.
+pub fn create_tls_sub(configuration: &Configuration, params: CreateTlsSubParams) -> Result<crate::models::TlsSubscriptionResponse, Error<CreateTlsSubError>> {
+    super::block_on(tls_subscriptions_api::create_tls_sub(configuration, params))
+}
+
+/// Like [`create_tls_sub`], but also returns the status and headers of the response.
+pub fn create_tls_sub_with_info(configuration: &Configuration, params: CreateTlsSubParams) -> Result<crate::apis::Response<crate::models::TlsSubscriptionResponse>, Error<CreateTlsSubError>> {
+    super::block_on(tls_subscriptions_api::create_tls_sub_with_info(configuration, params))
+}
+
+/// Deletes a GlobalSign email challenge. After a GlobalSign email challenge is deleted, the domain can use HTTP and DNS validation methods again.
+pub fn delete_globalsign_email_challenge(configuration: &Configuration, params: DeleteGlobalsignEmailChallengeParams) -> Result<(), Error<DeleteGlobalsignEmailChallengeError>> {
+    super::block_on(tls_subscriptions_api::delete_globalsign_email_challenge(configuration, params))
+}
+
+/// Like [`delete_globalsign_email_challenge`], but also returns the status and headers of the response.
+pub fn delete_globalsign_email_challenge_with_info(configuration: &Configuration, params: DeleteGlobalsignEmailChallengeParams) -> Result<crate::apis::Response<()>, Error<DeleteGlobalsignEmailChallengeError>> {
+    super::block_on(tls_subscriptions_api::delete_globalsign_email_challenge_with_info(configuration, params))
+}
+
+/// Destroy a TLS subscription. A subscription cannot be destroyed if there are domains in the TLS enabled state.
+pub fn delete_tls_sub(configuration: &Configuration, params: DeleteTlsSubParams) -> Result<(), Error<DeleteTlsSubError>> {
+    super::block_on(tls_subscriptions_api::delete_tls_sub(configuration, params))
+}
+
+/// Like [`delete_tls_sub`], but also returns the status and headers of the response.
+pub fn delete_tls_sub_with_info(configuration: &Configuration, params: DeleteTlsSubParams) -> Result<crate::apis::Response<()>, Error<DeleteTlsSubError>> {
+    super::block_on(tls_subscriptions_api::delete_tls_sub_with_info(configuration, params))
+}
+
+/// Show a TLS subscription.
+pub fn get_tls_sub(configuration: &Configuration, params: GetTlsSubParams) -> Result<crate::models::TlsSubscriptionResponse, Error<GetTlsSubError>> {
+    super::block_on(tls_subscriptions_api::get_tls_sub(configuration, params))
+}
+
+/// Like [`get_tls_sub`], but also returns the status and headers of the response.
+pub fn get_tls_sub_with_info(configuration: &Configuration, params: GetTlsSubParams) -> Result<crate::apis::Response<crate::models::TlsSubscriptionResponse>, Error<GetTlsSubError>> {
+    super::block_on(tls_subscriptions_api::get_tls_sub_with_info(configuration, params))
+}
+
+/// List all TLS subscriptions.
+pub fn list_tls_subs(configuration: &Configuration, params: ListTlsSubsParams) -> Result<crate::models::TlsSubscriptionsResponse, Error<ListTlsSubsError>> {
+    super::block_on(tls_subscriptions_api::list_tls_subs(configuration, params))
+}
+
+/// Like [`list_tls_subs`], but also returns the status and headers of the response.
+pub fn list_tls_subs_with_info(configuration: &Configuration, params: ListTlsSubsParams) -> Result<crate::apis::Response<crate::models::TlsSubscriptionsResponse>, Error<ListTlsSubsError>> {
+    super::block_on(tls_subscriptions_api::list_tls_subs_with_info(configuration, params))
+}
+
+/// Change the TLS domains or common name associated with this subscription, update the TLS configuration for this set of domains, or retry a subscription with state `failed` by setting the state to `retry`.
+pub fn patch_tls_sub(configuration: &Configuration, params: PatchTlsSubParams) -> Result<crate::models::TlsSubscriptionResponse, Error<PatchTlsSubError>> {
+    super::block_on(tls_subscriptions_api::patch_tls_sub(configuration, params))
+}
+
+/// Like [`patch_tls_sub`], but also returns the status and headers of the response.
+pub fn patch_tls_sub_with_info(configuration: &Configuration, params: PatchTlsSubParams) -> Result<crate::apis::Response<crate::models::TlsSubscriptionResponse>, Error<PatchTlsSubError>> {
+    super::block_on(tls_subscriptions_api::patch_tls_sub_with_info(configuration, params))
+}
@@ -0,0 +1,91 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{tokens_api, Error};
+
+pub use crate::apis::tokens_api::{BulkRevokeTokensError, BulkRevokeTokensParams, CreateTokenError, GetTokenCurrentError, GetTokenError, GetTokenParams, ListTokensCustomerError, ListTokensCustomerParams, ListTokensUserError, RevokeTokenCurrentError, RevokeTokenError, RevokeTokenParams};
+
+/// Revoke Tokens in bulk format. Users may only revoke their own tokens. Superusers may revoke tokens of others.
+pub fn bulk_revoke_tokens(configuration: &Configuration, params: BulkRevokeTokensParams) -> Result<(), Error<BulkRevokeTokensError>> {
+    super::block_on(tokens_api::bulk_revoke_tokens(configuration, params))
+}
+
+/// Like [`bulk_revoke_tokens`], but also returns the status and headers of the response.
+pub fn bulk_revoke_tokens_with_info(configuration: &Configuration, params: BulkRevokeTokensParams) -> Result<crate::apis::Response<()>, Error<BulkRevokeTokensError>> {
+    super::block_on(tokens_api::bulk_revoke_tokens_with_info(configuration, params))
+}
+
+/// Create an API token. If two-factor authentication is enabled for your account, review [the instructions](https://www.fastly.com/documentation/reference/api/auth-tokens/user/) for including a one-time password in the request. 
+pub fn create_token(configuration: &Configuration) -> Result<crate::models::TokenCreatedResponse, Error<CreateTokenError>> {
+    super::block_on(tokens_api::create_token(configuration))
+}
+
+/// Like [`create_token`], but also returns the status and headers of the response.
+pub fn create_token_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::TokenCreatedResponse>, Error<CreateTokenError>> {
+    super::block_on(tokens_api::create_token_with_info(configuration))
+}
+
+/// Get a single token by its id.
+pub fn get_token(configuration: &Configuration, params: GetTokenParams) -> Result<crate::models::TokenResponse, Error<GetTokenError>> {
+    super::block_on(tokens_api::get_token(configuration, params))
+}
+
+/// Like [`get_token`], but also returns the status and headers of the response.
+pub fn get_token_with_info(configuration: &Configuration, params: GetTokenParams) -> Result<crate::apis::Response<crate::models::TokenResponse>, Error<GetTokenError>> {
+    super::block_on(tokens_api::get_token_with_info(configuration, params))
+}
+
+/// Get a single token based on the access_token used in the request.
+pub fn get_token_current(configuration: &Configuration) -> Result<crate::models::TokenResponse, Error<GetTokenCurrentError>> {
+    super::block_on(tokens_api::get_token_current(configuration))
+}
+
+/// Like [`get_token_current`], but also returns the status and headers of the response.
+pub fn get_token_current_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::TokenResponse>, Error<GetTokenCurrentError>> {
+    super::block_on(tokens_api::get_token_current_with_info(configuration))
+}
+
+/// List all tokens belonging to a specific customer.
+pub fn list_tokens_customer(configuration: &Configuration, params: ListTokensCustomerParams) -> Result<Vec<crate::models::TokenResponse>, Error<ListTokensCustomerError>> {
+    super::block_on(tokens_api::list_tokens_customer(configuration, params))
+}
+
+/// Like [`list_tokens_customer`], but also returns the status and headers of the response.
+pub fn list_tokens_customer_with_info(configuration: &Configuration, params: ListTokensCustomerParams) -> Result<crate::apis::Response<Vec<crate::models::TokenResponse>>, Error<ListTokensCustomerError>> {
+    super::block_on(tokens_api::list_tokens_customer_with_info(configuration, params))
+}
+
+/// List all tokens belonging to the authenticated user.
+pub fn list_tokens_user(configuration: &Configuration) -> Result<Vec<crate::models::TokenResponse>, Error<ListTokensUserError>> {
+    super::block_on(tokens_api::list_tokens_user(configuration))
+}
+
+/// Like [`list_tokens_user`], but also returns the status and headers of the response.
+pub fn list_tokens_user_with_info(configuration: &Configuration) -> Result<crate::apis::Response<Vec<crate::models::TokenResponse>>, Error<ListTokensUserError>> {
+    super::block_on(tokens_api::list_tokens_user_with_info(configuration))
+}
+
+/// Revoke a specific token by its id.
+pub fn revoke_token(configuration: &Configuration, params: RevokeTokenParams) -> Result<(), Error<RevokeTokenError>> {
+    super::block_on(tokens_api::revoke_token(configuration, params))
+}
+
+/// Like [`revoke_token`], but also returns the status and headers of the response.
+pub fn revoke_token_with_info(configuration: &Configuration, params: RevokeTokenParams) -> Result<crate::apis::Response<()>, Error<RevokeTokenError>> {
+    super::block_on(tokens_api::revoke_token_with_info(configuration, params))
+}
+
+/// Revoke a token that is used to authenticate the request.
+pub fn revoke_token_current(configuration: &Configuration) -> Result<(), Error<RevokeTokenCurrentError>> {
+    super::block_on(tokens_api::revoke_token_current(configuration))
+}
+
+/// Like [`revoke_token_current`], but also returns the status and headers of the response.
+pub fn revoke_token_current_with_info(configuration: &Configuration) -> Result<crate::apis::Response<()>, Error<RevokeTokenCurrentError>> {
+    super::block_on(tokens_api::revoke_token_current_with_info(configuration))
+}
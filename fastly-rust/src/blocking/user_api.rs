@@ -0,0 +1,81 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{user_api, Error};
+
+pub use crate::apis::user_api::{CreateUserError, CreateUserParams, DeleteUserError, DeleteUserParams, GetCurrentUserError, GetUserError, GetUserParams, RequestPasswordResetError, RequestPasswordResetParams, UpdateUserError, UpdateUserParams, UpdateUserPasswordError, UpdateUserPasswordParams};
+
+/// Create a user.
+pub fn create_user(configuration: &Configuration, params: CreateUserParams) -> Result<crate::models::UserResponse, Error<CreateUserError>> {
+    super::block_on(user_api::create_user(configuration, params))
+}
+
+/// Like [`create_user`], but also returns the status and headers of the response.
+pub fn create_user_with_info(configuration: &Configuration, params: CreateUserParams) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<CreateUserError>> {
+    super::block_on(user_api::create_user_with_info(configuration, params))
+}
+
+/// Delete a user.
+pub fn delete_user(configuration: &Configuration, params: DeleteUserParams) -> Result<crate::models::InlineResponse200, Error<DeleteUserError>> {
+    super::block_on(user_api::delete_user(configuration, params))
+}
+
+/// Like [`delete_user`], but also returns the status and headers of the response.
+pub fn delete_user_with_info(configuration: &Configuration, params: DeleteUserParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteUserError>> {
+    super::block_on(user_api::delete_user_with_info(configuration, params))
+}
+
+/// Get the logged in user.
+pub fn get_current_user(configuration: &Configuration) -> Result<crate::models::UserResponse, Error<GetCurrentUserError>> {
+    super::block_on(user_api::get_current_user(configuration))
+}
+
+/// Like [`get_current_user`], but also returns the status and headers of the response.
+pub fn get_current_user_with_info(configuration: &Configuration) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<GetCurrentUserError>> {
+    super::block_on(user_api::get_current_user_with_info(configuration))
+}
+
+/// Get a specific user.
+pub fn get_user(configuration: &Configuration, params: GetUserParams) -> Result<crate::models::UserResponse, Error<GetUserError>> {
+    super::block_on(user_api::get_user(configuration, params))
+}
+
+/// Like [`get_user`], but also returns the status and headers of the response.
+pub fn get_user_with_info(configuration: &Configuration, params: GetUserParams) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<GetUserError>> {
+    super::block_on(user_api::get_user_with_info(configuration, params))
+}
+
+/// Requests a password reset for the specified user.
+pub fn request_password_reset(configuration: &Configuration, params: RequestPasswordResetParams) -> Result<crate::models::InlineResponse200, Error<RequestPasswordResetError>> {
+    super::block_on(user_api::request_password_reset(configuration, params))
+}
+
+/// Like [`request_password_reset`], but also returns the status and headers of the response.
+pub fn request_password_reset_with_info(configuration: &Configuration, params: RequestPasswordResetParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<RequestPasswordResetError>> {
+    super::block_on(user_api::request_password_reset_with_info(configuration, params))
+}
+
+/// Update a user. Only users with the role of `superuser` can make changes to other users on the account. Non-superusers may use this endpoint to make changes to their own account. Two-factor attributes are not editable via this endpoint.
+pub fn update_user(configuration: &Configuration, params: UpdateUserParams) -> Result<crate::models::UserResponse, Error<UpdateUserError>> {
+    super::block_on(user_api::update_user(configuration, params))
+}
+
+/// Like [`update_user`], but also returns the status and headers of the response.
+pub fn update_user_with_info(configuration: &Configuration, params: UpdateUserParams) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<UpdateUserError>> {
+    super::block_on(user_api::update_user_with_info(configuration, params))
+}
+
+/// Update the user's password to a new one.
+pub fn update_user_password(configuration: &Configuration, params: UpdateUserPasswordParams) -> Result<crate::models::UserResponse, Error<UpdateUserPasswordError>> {
+    super::block_on(user_api::update_user_password(configuration, params))
+}
+
+/// Like [`update_user_password`], but also returns the status and headers of the response.
+pub fn update_user_password_with_info(configuration: &Configuration, params: UpdateUserPasswordParams) -> Result<crate::apis::Response<crate::models::UserResponse>, Error<UpdateUserPasswordError>> {
+    super::block_on(user_api::update_user_password_with_info(configuration, params))
+}
@@ -0,0 +1,141 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{vcl_api, Error};
+
+pub use crate::apis::vcl_api::{CreateCustomVclError, CreateCustomVclParams, DeleteCustomVclError, DeleteCustomVclParams, GetCustomVclBoilerplateError, GetCustomVclBoilerplateParams, GetCustomVclError, GetCustomVclGeneratedError, GetCustomVclGeneratedHighlightedError, GetCustomVclGeneratedHighlightedParams, GetCustomVclGeneratedParams, GetCustomVclHighlightedError, GetCustomVclHighlightedParams, GetCustomVclParams, GetCustomVclRawError, GetCustomVclRawParams, LintVclDefaultError, LintVclDefaultParams, LintVclForServiceError, LintVclForServiceParams, ListCustomVclError, ListCustomVclParams, SetCustomVclMainError, SetCustomVclMainParams, UpdateCustomVclError, UpdateCustomVclParams};
+
+/// Upload a VCL for a particular service and version.
+pub fn create_custom_vcl(configuration: &Configuration, params: CreateCustomVclParams) -> Result<crate::models::VclResponse, Error<CreateCustomVclError>> {
+    super::block_on(vcl_api::create_custom_vcl(configuration, params))
+}
+
+/// Like [`create_custom_vcl`], but also returns the status and headers of the response.
+pub fn create_custom_vcl_with_info(configuration: &Configuration, params: CreateCustomVclParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<CreateCustomVclError>> {
+    super::block_on(vcl_api::create_custom_vcl_with_info(configuration, params))
+}
+
+/// Delete the uploaded VCL for a particular service and version.
+pub fn delete_custom_vcl(configuration: &Configuration, params: DeleteCustomVclParams) -> Result<crate::models::InlineResponse200, Error<DeleteCustomVclError>> {
+    super::block_on(vcl_api::delete_custom_vcl(configuration, params))
+}
+
+/// Like [`delete_custom_vcl`], but also returns the status and headers of the response.
+pub fn delete_custom_vcl_with_info(configuration: &Configuration, params: DeleteCustomVclParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<DeleteCustomVclError>> {
+    super::block_on(vcl_api::delete_custom_vcl_with_info(configuration, params))
+}
+
+/// Get the uploaded VCL for a particular service and version.
+pub fn get_custom_vcl(configuration: &Configuration, params: GetCustomVclParams) -> Result<crate::models::VclResponse, Error<GetCustomVclError>> {
+    super::block_on(vcl_api::get_custom_vcl(configuration, params))
+}
+
+/// Like [`get_custom_vcl`], but also returns the status and headers of the response.
+pub fn get_custom_vcl_with_info(configuration: &Configuration, params: GetCustomVclParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<GetCustomVclError>> {
+    super::block_on(vcl_api::get_custom_vcl_with_info(configuration, params))
+}
+
+/// Return boilerplate VCL with the service's TTL from the [settings](https://www.fastly.com/documentation/reference/api/vcl-services/settings/).
+pub fn get_custom_vcl_boilerplate(configuration: &Configuration, params: GetCustomVclBoilerplateParams) -> Result<String, Error<GetCustomVclBoilerplateError>> {
+    super::block_on(vcl_api::get_custom_vcl_boilerplate(configuration, params))
+}
+
+/// Like [`get_custom_vcl_boilerplate`], but also returns the status and headers of the response.
+pub fn get_custom_vcl_boilerplate_with_info(configuration: &Configuration, params: GetCustomVclBoilerplateParams) -> Result<crate::apis::Response<String>, Error<GetCustomVclBoilerplateError>> {
+    super::block_on(vcl_api::get_custom_vcl_boilerplate_with_info(configuration, params))
+}
+
+/// Display the generated VCL for a particular service and version.
+pub fn get_custom_vcl_generated(configuration: &Configuration, params: GetCustomVclGeneratedParams) -> Result<crate::models::VclResponse, Error<GetCustomVclGeneratedError>> {
+    super::block_on(vcl_api::get_custom_vcl_generated(configuration, params))
+}
+
+/// Like [`get_custom_vcl_generated`], but also returns the status and headers of the response.
+pub fn get_custom_vcl_generated_with_info(configuration: &Configuration, params: GetCustomVclGeneratedParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<GetCustomVclGeneratedError>> {
+    super::block_on(vcl_api::get_custom_vcl_generated_with_info(configuration, params))
+}
+
+/// Display the content of generated VCL with HTML syntax highlighting. Include line numbers by sending `lineno=true` as a request parameter.
+pub fn get_custom_vcl_generated_highlighted(configuration: &Configuration, params: GetCustomVclGeneratedHighlightedParams) -> Result<crate::models::VclSyntaxHighlightingResponse, Error<GetCustomVclGeneratedHighlightedError>> {
+    super::block_on(vcl_api::get_custom_vcl_generated_highlighted(configuration, params))
+}
+
+/// Like [`get_custom_vcl_generated_highlighted`], but also returns the status and headers of the response.
+pub fn get_custom_vcl_generated_highlighted_with_info(configuration: &Configuration, params: GetCustomVclGeneratedHighlightedParams) -> Result<crate::apis::Response<crate::models::VclSyntaxHighlightingResponse>, Error<GetCustomVclGeneratedHighlightedError>> {
+    super::block_on(vcl_api::get_custom_vcl_generated_highlighted_with_info(configuration, params))
+}
+
+/// Get the uploaded VCL for a particular service and version with HTML syntax highlighting. Include line numbers by sending `lineno=true` as a request parameter.
+pub fn get_custom_vcl_highlighted(configuration: &Configuration, params: GetCustomVclHighlightedParams) -> Result<crate::models::VclSyntaxHighlightingResponse, Error<GetCustomVclHighlightedError>> {
+    super::block_on(vcl_api::get_custom_vcl_highlighted(configuration, params))
+}
+
+/// Like [`get_custom_vcl_highlighted`], but also returns the status and headers of the response.
+pub fn get_custom_vcl_highlighted_with_info(configuration: &Configuration, params: GetCustomVclHighlightedParams) -> Result<crate::apis::Response<crate::models::VclSyntaxHighlightingResponse>, Error<GetCustomVclHighlightedError>> {
+    super::block_on(vcl_api::get_custom_vcl_highlighted_with_info(configuration, params))
+}
+
+/// Download the specified VCL.
+pub fn get_custom_vcl_raw(configuration: &Configuration, params: GetCustomVclRawParams) -> Result<String, Error<GetCustomVclRawError>> {
+    super::block_on(vcl_api::get_custom_vcl_raw(configuration, params))
+}
+
+/// Like [`get_custom_vcl_raw`], but also returns the status and headers of the response.
+pub fn get_custom_vcl_raw_with_info(configuration: &Configuration, params: GetCustomVclRawParams) -> Result<crate::apis::Response<String>, Error<GetCustomVclRawError>> {
+    super::block_on(vcl_api::get_custom_vcl_raw_with_info(configuration, params))
+}
+
+/// This endpoint validates the submitted VCL against a default set of enabled flags. Consider using the `/service/{service_id}/lint` operation to validate VCL in the context of a specific service.
+pub fn lint_vcl_default(configuration: &Configuration, params: LintVclDefaultParams) -> Result<crate::models::ValidatorResult, Error<LintVclDefaultError>> {
+    super::block_on(vcl_api::lint_vcl_default(configuration, params))
+}
+
+/// Like [`lint_vcl_default`], but also returns the status and headers of the response.
+pub fn lint_vcl_default_with_info(configuration: &Configuration, params: LintVclDefaultParams) -> Result<crate::apis::Response<crate::models::ValidatorResult>, Error<LintVclDefaultError>> {
+    super::block_on(vcl_api::lint_vcl_default_with_info(configuration, params))
+}
+
+/// Services may have flags set by a Fastly employee or by the purchase of products as addons to the service, which modify the way VCL is interpreted by that service.  This endpoint validates the submitted VCL in the context of the specified service.
+pub fn lint_vcl_for_service(configuration: &Configuration, params: LintVclForServiceParams) -> Result<crate::models::ValidatorResult, Error<LintVclForServiceError>> {
+    super::block_on(vcl_api::lint_vcl_for_service(configuration, params))
+}
+
+/// Like [`lint_vcl_for_service`], but also returns the status and headers of the response.
+pub fn lint_vcl_for_service_with_info(configuration: &Configuration, params: LintVclForServiceParams) -> Result<crate::apis::Response<crate::models::ValidatorResult>, Error<LintVclForServiceError>> {
+    super::block_on(vcl_api::lint_vcl_for_service_with_info(configuration, params))
+}
+
+/// List the uploaded VCLs for a particular service and version.
+pub fn list_custom_vcl(configuration: &Configuration, params: ListCustomVclParams) -> Result<Vec<crate::models::VclResponse>, Error<ListCustomVclError>> {
+    super::block_on(vcl_api::list_custom_vcl(configuration, params))
+}
+
+/// Like [`list_custom_vcl`], but also returns the status and headers of the response.
+pub fn list_custom_vcl_with_info(configuration: &Configuration, params: ListCustomVclParams) -> Result<crate::apis::Response<Vec<crate::models::VclResponse>>, Error<ListCustomVclError>> {
+    super::block_on(vcl_api::list_custom_vcl_with_info(configuration, params))
+}
+
+/// Set the specified VCL as the main.
+pub fn set_custom_vcl_main(configuration: &Configuration, params: SetCustomVclMainParams) -> Result<crate::models::VclResponse, Error<SetCustomVclMainError>> {
+    super::block_on(vcl_api::set_custom_vcl_main(configuration, params))
+}
+
+/// Like [`set_custom_vcl_main`], but also returns the status and headers of the response.
+pub fn set_custom_vcl_main_with_info(configuration: &Configuration, params: SetCustomVclMainParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<SetCustomVclMainError>> {
+    super::block_on(vcl_api::set_custom_vcl_main_with_info(configuration, params))
+}
+
+/// Update the uploaded VCL for a particular service and version.
+pub fn update_custom_vcl(configuration: &Configuration, params: UpdateCustomVclParams) -> Result<crate::models::VclResponse, Error<UpdateCustomVclError>> {
+    super::block_on(vcl_api::update_custom_vcl(configuration, params))
+}
+
+/// Like [`update_custom_vcl`], but also returns the status and headers of the response.
+pub fn update_custom_vcl_with_info(configuration: &Configuration, params: UpdateCustomVclParams) -> Result<crate::apis::Response<crate::models::VclResponse>, Error<UpdateCustomVclError>> {
+    super::block_on(vcl_api::update_custom_vcl_with_info(configuration, params))
+}
@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{vcl_diff_api, Error};
+
+pub use crate::apis::vcl_diff_api::{VclDiffServiceVersionsError, VclDiffServiceVersionsParams};
+
+/// Get a comparison of the VCL changes between two service versions.
+pub fn vcl_diff_service_versions(configuration: &Configuration, params: VclDiffServiceVersionsParams) -> Result<crate::models::VclDiff, Error<VclDiffServiceVersionsError>> {
+    super::block_on(vcl_diff_api::vcl_diff_service_versions(configuration, params))
+}
+
+/// Like [`vcl_diff_service_versions`], but also returns the status and headers of the response.
+pub fn vcl_diff_service_versions_with_info(configuration: &Configuration, params: VclDiffServiceVersionsParams) -> Result<crate::apis::Response<crate::models::VclDiff>, Error<VclDiffServiceVersionsError>> {
+    super::block_on(vcl_diff_api::vcl_diff_service_versions_with_info(configuration, params))
+}
@@ -0,0 +1,101 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{version_api, Error};
+
+pub use crate::apis::version_api::{ActivateServiceVersionError, ActivateServiceVersionParams, CloneServiceVersionError, CloneServiceVersionParams, CreateServiceVersionError, CreateServiceVersionParams, DeactivateServiceVersionError, DeactivateServiceVersionParams, GetServiceVersionError, GetServiceVersionParams, ListServiceVersionsError, ListServiceVersionsParams, LockServiceVersionError, LockServiceVersionParams, UpdateServiceVersionError, UpdateServiceVersionParams, ValidateServiceVersionError, ValidateServiceVersionParams};
+
+/// Activate the current version.
+pub fn activate_service_version(configuration: &Configuration, params: ActivateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<ActivateServiceVersionError>> {
+    super::block_on(version_api::activate_service_version(configuration, params))
+}
+
+/// Like [`activate_service_version`], but also returns the status and headers of the response.
+pub fn activate_service_version_with_info(configuration: &Configuration, params: ActivateServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionResponse>, Error<ActivateServiceVersionError>> {
+    super::block_on(version_api::activate_service_version_with_info(configuration, params))
+}
+
+/// Clone the current configuration into a new version.
+pub fn clone_service_version(configuration: &Configuration, params: CloneServiceVersionParams) -> Result<crate::models::Version, Error<CloneServiceVersionError>> {
+    super::block_on(version_api::clone_service_version(configuration, params))
+}
+
+/// Like [`clone_service_version`], but also returns the status and headers of the response.
+pub fn clone_service_version_with_info(configuration: &Configuration, params: CloneServiceVersionParams) -> Result<crate::apis::Response<crate::models::Version>, Error<CloneServiceVersionError>> {
+    super::block_on(version_api::clone_service_version_with_info(configuration, params))
+}
+
+/// Create a version for a particular service.
+pub fn create_service_version(configuration: &Configuration, params: CreateServiceVersionParams) -> Result<crate::models::VersionCreateResponse, Error<CreateServiceVersionError>> {
+    super::block_on(version_api::create_service_version(configuration, params))
+}
+
+/// Like [`create_service_version`], but also returns the status and headers of the response.
+pub fn create_service_version_with_info(configuration: &Configuration, params: CreateServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionCreateResponse>, Error<CreateServiceVersionError>> {
+    super::block_on(version_api::create_service_version_with_info(configuration, params))
+}
+
+/// Deactivate the current version.
+pub fn deactivate_service_version(configuration: &Configuration, params: DeactivateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<DeactivateServiceVersionError>> {
+    super::block_on(version_api::deactivate_service_version(configuration, params))
+}
+
+/// Like [`deactivate_service_version`], but also returns the status and headers of the response.
+pub fn deactivate_service_version_with_info(configuration: &Configuration, params: DeactivateServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionResponse>, Error<DeactivateServiceVersionError>> {
+    super::block_on(version_api::deactivate_service_version_with_info(configuration, params))
+}
+
+/// Get the version for a particular service.
+pub fn get_service_version(configuration: &Configuration, params: GetServiceVersionParams) -> Result<crate::models::VersionResponse, Error<GetServiceVersionError>> {
+    super::block_on(version_api::get_service_version(configuration, params))
+}
+
+/// Like [`get_service_version`], but also returns the status and headers of the response.
+pub fn get_service_version_with_info(configuration: &Configuration, params: GetServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionResponse>, Error<GetServiceVersionError>> {
+    super::block_on(version_api::get_service_version_with_info(configuration, params))
+}
+
+/// List the versions for a particular service.
+pub fn list_service_versions(configuration: &Configuration, params: ListServiceVersionsParams) -> Result<Vec<crate::models::VersionResponse>, Error<ListServiceVersionsError>> {
+    super::block_on(version_api::list_service_versions(configuration, params))
+}
+
+/// Like [`list_service_versions`], but also returns the status and headers of the response.
+pub fn list_service_versions_with_info(configuration: &Configuration, params: ListServiceVersionsParams) -> Result<crate::apis::Response<Vec<crate::models::VersionResponse>>, Error<ListServiceVersionsError>> {
+    super::block_on(version_api::list_service_versions_with_info(configuration, params))
+}
+
+/// Locks the specified version.
+pub fn lock_service_version(configuration: &Configuration, params: LockServiceVersionParams) -> Result<crate::models::Version, Error<LockServiceVersionError>> {
+    super::block_on(version_api::lock_service_version(configuration, params))
+}
+
+/// Like [`lock_service_version`], but also returns the status and headers of the response.
+pub fn lock_service_version_with_info(configuration: &Configuration, params: LockServiceVersionParams) -> Result<crate::apis::Response<crate::models::Version>, Error<LockServiceVersionError>> {
+    super::block_on(version_api::lock_service_version_with_info(configuration, params))
+}
+
+/// Update a particular version for a particular service.
+pub fn update_service_version(configuration: &Configuration, params: UpdateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<UpdateServiceVersionError>> {
+    super::block_on(version_api::update_service_version(configuration, params))
+}
+
+/// Like [`update_service_version`], but also returns the status and headers of the response.
+pub fn update_service_version_with_info(configuration: &Configuration, params: UpdateServiceVersionParams) -> Result<crate::apis::Response<crate::models::VersionResponse>, Error<UpdateServiceVersionError>> {
+    super::block_on(version_api::update_service_version_with_info(configuration, params))
+}
+
+/// Validate the version for a particular service and version.
+pub fn validate_service_version(configuration: &Configuration, params: ValidateServiceVersionParams) -> Result<crate::models::InlineResponse200, Error<ValidateServiceVersionError>> {
+    super::block_on(version_api::validate_service_version(configuration, params))
+}
+
+/// Like [`validate_service_version`], but also returns the status and headers of the response.
+pub fn validate_service_version_with_info(configuration: &Configuration, params: ValidateServiceVersionParams) -> Result<crate::apis::Response<crate::models::InlineResponse200>, Error<ValidateServiceVersionError>> {
+    super::block_on(version_api::validate_service_version_with_info(configuration, params))
+}
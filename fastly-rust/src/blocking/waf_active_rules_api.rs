@@ -0,0 +1,91 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{waf_active_rules_api, Error};
+
+pub use crate::apis::waf_active_rules_api::{BulkDeleteWafActiveRulesError, BulkDeleteWafActiveRulesParams, BulkUpdateWafActiveRulesError, BulkUpdateWafActiveRulesParams, CreateWafActiveRuleError, CreateWafActiveRuleParams, CreateWafActiveRulesTagError, CreateWafActiveRulesTagParams, DeleteWafActiveRuleError, DeleteWafActiveRuleParams, GetWafActiveRuleError, GetWafActiveRuleParams, ListWafActiveRulesError, ListWafActiveRulesParams, UpdateWafActiveRuleError, UpdateWafActiveRuleParams};
+
+/// Delete many active rules on a particular firewall version using the active rule ID. Limited to 500 rules per request.
+pub fn bulk_delete_waf_active_rules(configuration: &Configuration, params: BulkDeleteWafActiveRulesParams) -> Result<(), Error<BulkDeleteWafActiveRulesError>> {
+    super::block_on(waf_active_rules_api::bulk_delete_waf_active_rules(configuration, params))
+}
+
+/// Like [`bulk_delete_waf_active_rules`], but also returns the status and headers of the response.
+pub fn bulk_delete_waf_active_rules_with_info(configuration: &Configuration, params: BulkDeleteWafActiveRulesParams) -> Result<crate::apis::Response<()>, Error<BulkDeleteWafActiveRulesError>> {
+    super::block_on(waf_active_rules_api::bulk_delete_waf_active_rules_with_info(configuration, params))
+}
+
+/// Bulk update all active rules on a [firewall version](https://www.fastly.com/documentation/reference/api/waf/firewall-version/). This endpoint will not add new active rules, only update existing active rules.
+pub fn bulk_update_waf_active_rules(configuration: &Configuration, params: BulkUpdateWafActiveRulesParams) -> Result<(), Error<BulkUpdateWafActiveRulesError>> {
+    super::block_on(waf_active_rules_api::bulk_update_waf_active_rules(configuration, params))
+}
+
+/// Like [`bulk_update_waf_active_rules`], but also returns the status and headers of the response.
+pub fn bulk_update_waf_active_rules_with_info(configuration: &Configuration, params: BulkUpdateWafActiveRulesParams) -> Result<crate::apis::Response<()>, Error<BulkUpdateWafActiveRulesError>> {
+    super::block_on(waf_active_rules_api::bulk_update_waf_active_rules_with_info(configuration, params))
+}
+
+/// Create an active rule for a particular firewall version.
+pub fn create_waf_active_rule(configuration: &Configuration, params: CreateWafActiveRuleParams) -> Result<crate::models::WafActiveRuleCreationResponse, Error<CreateWafActiveRuleError>> {
+    super::block_on(waf_active_rules_api::create_waf_active_rule(configuration, params))
+}
+
+/// Like [`create_waf_active_rule`], but also returns the status and headers of the response.
+pub fn create_waf_active_rule_with_info(configuration: &Configuration, params: CreateWafActiveRuleParams) -> Result<crate::apis::Response<crate::models::WafActiveRuleCreationResponse>, Error<CreateWafActiveRuleError>> {
+    super::block_on(waf_active_rules_api::create_waf_active_rule_with_info(configuration, params))
+}
+
+/// Create active rules by tag. This endpoint will create active rules using the latest revision available for each rule.
+pub fn create_waf_active_rules_tag(configuration: &Configuration, params: CreateWafActiveRulesTagParams) -> Result<(), Error<CreateWafActiveRulesTagError>> {
+    super::block_on(waf_active_rules_api::create_waf_active_rules_tag(configuration, params))
+}
+
+/// Like [`create_waf_active_rules_tag`], but also returns the status and headers of the response.
+pub fn create_waf_active_rules_tag_with_info(configuration: &Configuration, params: CreateWafActiveRulesTagParams) -> Result<crate::apis::Response<()>, Error<CreateWafActiveRulesTagError>> {
+    super::block_on(waf_active_rules_api::create_waf_active_rules_tag_with_info(configuration, params))
+}
+
+/// Delete an active rule for a particular firewall version.
+pub fn delete_waf_active_rule(configuration: &Configuration, params: DeleteWafActiveRuleParams) -> Result<(), Error<DeleteWafActiveRuleError>> {
+    super::block_on(waf_active_rules_api::delete_waf_active_rule(configuration, params))
+}
+
+/// Like [`delete_waf_active_rule`], but also returns the status and headers of the response.
+pub fn delete_waf_active_rule_with_info(configuration: &Configuration, params: DeleteWafActiveRuleParams) -> Result<crate::apis::Response<()>, Error<DeleteWafActiveRuleError>> {
+    super::block_on(waf_active_rules_api::delete_waf_active_rule_with_info(configuration, params))
+}
+
+/// Get a specific active rule object. Includes details of the rule revision associated with the active rule object by default.
+pub fn get_waf_active_rule(configuration: &Configuration, params: GetWafActiveRuleParams) -> Result<crate::models::WafActiveRuleResponse, Error<GetWafActiveRuleError>> {
+    super::block_on(waf_active_rules_api::get_waf_active_rule(configuration, params))
+}
+
+/// Like [`get_waf_active_rule`], but also returns the status and headers of the response.
+pub fn get_waf_active_rule_with_info(configuration: &Configuration, params: GetWafActiveRuleParams) -> Result<crate::apis::Response<crate::models::WafActiveRuleResponse>, Error<GetWafActiveRuleError>> {
+    super::block_on(waf_active_rules_api::get_waf_active_rule_with_info(configuration, params))
+}
+
+/// List all active rules for a particular firewall version.
+pub fn list_waf_active_rules(configuration: &Configuration, params: ListWafActiveRulesParams) -> Result<crate::models::WafActiveRulesResponse, Error<ListWafActiveRulesError>> {
+    super::block_on(waf_active_rules_api::list_waf_active_rules(configuration, params))
+}
+
+/// Like [`list_waf_active_rules`], but also returns the status and headers of the response.
+pub fn list_waf_active_rules_with_info(configuration: &Configuration, params: ListWafActiveRulesParams) -> Result<crate::apis::Response<crate::models::WafActiveRulesResponse>, Error<ListWafActiveRulesError>> {
+    super::block_on(waf_active_rules_api::list_waf_active_rules_with_info(configuration, params))
+}
+
+/// Update an active rule's status for a particular firewall version.
+pub fn update_waf_active_rule(configuration: &Configuration, params: UpdateWafActiveRuleParams) -> Result<crate::models::WafActiveRuleResponse, Error<UpdateWafActiveRuleError>> {
+    super::block_on(waf_active_rules_api::update_waf_active_rule(configuration, params))
+}
+
+/// Like [`update_waf_active_rule`], but also returns the status and headers of the response.
+pub fn update_waf_active_rule_with_info(configuration: &Configuration, params: UpdateWafActiveRuleParams) -> Result<crate::apis::Response<crate::models::WafActiveRuleResponse>, Error<UpdateWafActiveRuleError>> {
+    super::block_on(waf_active_rules_api::update_waf_active_rule_with_info(configuration, params))
+}
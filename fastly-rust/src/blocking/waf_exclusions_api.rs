@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{waf_exclusions_api, Error};
+
+pub use crate::apis::waf_exclusions_api::{CreateWafRuleExclusionError, CreateWafRuleExclusionParams, DeleteWafRuleExclusionError, DeleteWafRuleExclusionParams, GetWafRuleExclusionError, GetWafRuleExclusionParams, ListWafRuleExclusionsError, ListWafRuleExclusionsParams, UpdateWafRuleExclusionError, UpdateWafRuleExclusionParams};
+
+/// Create a WAF exclusion for a particular firewall version.
+pub fn create_waf_rule_exclusion(configuration: &Configuration, params: CreateWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<CreateWafRuleExclusionError>> {
+    super::block_on(waf_exclusions_api::create_waf_rule_exclusion(configuration, params))
+}
+
+/// Like [`create_waf_rule_exclusion`], but also returns the status and headers of the response.
+pub fn create_waf_rule_exclusion_with_info(configuration: &Configuration, params: CreateWafRuleExclusionParams) -> Result<crate::apis::Response<crate::models::WafExclusionResponse>, Error<CreateWafRuleExclusionError>> {
+    super::block_on(waf_exclusions_api::create_waf_rule_exclusion_with_info(configuration, params))
+}
+
+/// Delete a WAF exclusion for a particular firewall version.
+pub fn delete_waf_rule_exclusion(configuration: &Configuration, params: DeleteWafRuleExclusionParams) -> Result<(), Error<DeleteWafRuleExclusionError>> {
+    super::block_on(waf_exclusions_api::delete_waf_rule_exclusion(configuration, params))
+}
+
+/// Like [`delete_waf_rule_exclusion`], but also returns the status and headers of the response.
+pub fn delete_waf_rule_exclusion_with_info(configuration: &Configuration, params: DeleteWafRuleExclusionParams) -> Result<crate::apis::Response<()>, Error<DeleteWafRuleExclusionError>> {
+    super::block_on(waf_exclusions_api::delete_waf_rule_exclusion_with_info(configuration, params))
+}
+
+/// Get a specific WAF exclusion object.
+pub fn get_waf_rule_exclusion(configuration: &Configuration, params: GetWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<GetWafRuleExclusionError>> {
+    super::block_on(waf_exclusions_api::get_waf_rule_exclusion(configuration, params))
+}
+
+/// Like [`get_waf_rule_exclusion`], but also returns the status and headers of the response.
+pub fn get_waf_rule_exclusion_with_info(configuration: &Configuration, params: GetWafRuleExclusionParams) -> Result<crate::apis::Response<crate::models::WafExclusionResponse>, Error<GetWafRuleExclusionError>> {
+    super::block_on(waf_exclusions_api::get_waf_rule_exclusion_with_info(configuration, params))
+}
+
+/// List all exclusions for a particular firewall version.
+pub fn list_waf_rule_exclusions(configuration: &Configuration, params: ListWafRuleExclusionsParams) -> Result<crate::models::WafExclusionsResponse, Error<ListWafRuleExclusionsError>> {
+    super::block_on(waf_exclusions_api::list_waf_rule_exclusions(configuration, params))
+}
+
+/// Like [`list_waf_rule_exclusions`], but also returns the status and headers of the response.
+pub fn list_waf_rule_exclusions_with_info(configuration: &Configuration, params: ListWafRuleExclusionsParams) -> Result<crate::apis::Response<crate::models::WafExclusionsResponse>, Error<ListWafRuleExclusionsError>> {
+    super::block_on(waf_exclusions_api::list_waf_rule_exclusions_with_info(configuration, params))
+}
+
+/// Update a WAF exclusion for a particular firewall version.
+pub fn update_waf_rule_exclusion(configuration: &Configuration, params: UpdateWafRuleExclusionParams) -> Result<crate::models::WafExclusionResponse, Error<UpdateWafRuleExclusionError>> {
+    super::block_on(waf_exclusions_api::update_waf_rule_exclusion(configuration, params))
+}
+
+/// Like [`update_waf_rule_exclusion`], but also returns the status and headers of the response.
+pub fn update_waf_rule_exclusion_with_info(configuration: &Configuration, params: UpdateWafRuleExclusionParams) -> Result<crate::apis::Response<crate::models::WafExclusionResponse>, Error<UpdateWafRuleExclusionError>> {
+    super::block_on(waf_exclusions_api::update_waf_rule_exclusion_with_info(configuration, params))
+}
@@ -0,0 +1,71 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{waf_firewall_versions_api, Error};
+
+pub use crate::apis::waf_firewall_versions_api::{CloneWafFirewallVersionError, CloneWafFirewallVersionParams, CreateWafFirewallVersionError, CreateWafFirewallVersionParams, DeployActivateWafFirewallVersionError, DeployActivateWafFirewallVersionParams, GetWafFirewallVersionError, GetWafFirewallVersionParams, ListWafFirewallVersionsError, ListWafFirewallVersionsParams, UpdateWafFirewallVersionError, UpdateWafFirewallVersionParams};
+
+/// Clone a specific, existing firewall version into a new, draft firewall version.
+pub fn clone_waf_firewall_version(configuration: &Configuration, params: CloneWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<CloneWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::clone_waf_firewall_version(configuration, params))
+}
+
+/// Like [`clone_waf_firewall_version`], but also returns the status and headers of the response.
+pub fn clone_waf_firewall_version_with_info(configuration: &Configuration, params: CloneWafFirewallVersionParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionResponse>, Error<CloneWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::clone_waf_firewall_version_with_info(configuration, params))
+}
+
+/// Create a new, draft firewall version.
+pub fn create_waf_firewall_version(configuration: &Configuration, params: CreateWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<CreateWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::create_waf_firewall_version(configuration, params))
+}
+
+/// Like [`create_waf_firewall_version`], but also returns the status and headers of the response.
+pub fn create_waf_firewall_version_with_info(configuration: &Configuration, params: CreateWafFirewallVersionParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionResponse>, Error<CreateWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::create_waf_firewall_version_with_info(configuration, params))
+}
+
+/// Deploy or activate a specific firewall version. If a firewall has been disabled, deploying a firewall version will automatically enable the firewall again.
+pub fn deploy_activate_waf_firewall_version(configuration: &Configuration, params: DeployActivateWafFirewallVersionParams) -> Result<serde_json::Value, Error<DeployActivateWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::deploy_activate_waf_firewall_version(configuration, params))
+}
+
+/// Like [`deploy_activate_waf_firewall_version`], but also returns the status and headers of the response.
+pub fn deploy_activate_waf_firewall_version_with_info(configuration: &Configuration, params: DeployActivateWafFirewallVersionParams) -> Result<crate::apis::Response<serde_json::Value>, Error<DeployActivateWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::deploy_activate_waf_firewall_version_with_info(configuration, params))
+}
+
+/// Get details about a specific firewall version.
+pub fn get_waf_firewall_version(configuration: &Configuration, params: GetWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<GetWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::get_waf_firewall_version(configuration, params))
+}
+
+/// Like [`get_waf_firewall_version`], but also returns the status and headers of the response.
+pub fn get_waf_firewall_version_with_info(configuration: &Configuration, params: GetWafFirewallVersionParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionResponse>, Error<GetWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::get_waf_firewall_version_with_info(configuration, params))
+}
+
+/// Get a list of firewall versions associated with a specific firewall.
+pub fn list_waf_firewall_versions(configuration: &Configuration, params: ListWafFirewallVersionsParams) -> Result<crate::models::WafFirewallVersionsResponse, Error<ListWafFirewallVersionsError>> {
+    super::block_on(waf_firewall_versions_api::list_waf_firewall_versions(configuration, params))
+}
+
+/// Like [`list_waf_firewall_versions`], but also returns the status and headers of the response.
+pub fn list_waf_firewall_versions_with_info(configuration: &Configuration, params: ListWafFirewallVersionsParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionsResponse>, Error<ListWafFirewallVersionsError>> {
+    super::block_on(waf_firewall_versions_api::list_waf_firewall_versions_with_info(configuration, params))
+}
+
+/// Update a specific firewall version.
+pub fn update_waf_firewall_version(configuration: &Configuration, params: UpdateWafFirewallVersionParams) -> Result<crate::models::WafFirewallVersionResponse, Error<UpdateWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::update_waf_firewall_version(configuration, params))
+}
+
+/// Like [`update_waf_firewall_version`], but also returns the status and headers of the response.
+pub fn update_waf_firewall_version_with_info(configuration: &Configuration, params: UpdateWafFirewallVersionParams) -> Result<crate::apis::Response<crate::models::WafFirewallVersionResponse>, Error<UpdateWafFirewallVersionError>> {
+    super::block_on(waf_firewall_versions_api::update_waf_firewall_version_with_info(configuration, params))
+}
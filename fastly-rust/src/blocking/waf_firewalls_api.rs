@@ -0,0 +1,61 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{waf_firewalls_api, Error};
+
+pub use crate::apis::waf_firewalls_api::{CreateWafFirewallError, CreateWafFirewallParams, DeleteWafFirewallError, DeleteWafFirewallParams, GetWafFirewallError, GetWafFirewallParams, ListWafFirewallsError, ListWafFirewallsParams, UpdateWafFirewallError, UpdateWafFirewallParams};
+
+/// Create a firewall object for a particular service and service version using a defined `prefetch_condition` and `response`. If the `prefetch_condition` or the `response` is missing from the request body, Fastly will generate a default object on your service. 
+pub fn create_waf_firewall(configuration: &Configuration, params: CreateWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<CreateWafFirewallError>> {
+    super::block_on(waf_firewalls_api::create_waf_firewall(configuration, params))
+}
+
+/// Like [`create_waf_firewall`], but also returns the status and headers of the response.
+pub fn create_waf_firewall_with_info(configuration: &Configuration, params: CreateWafFirewallParams) -> Result<crate::apis::Response<crate::models::WafFirewallResponse>, Error<CreateWafFirewallError>> {
+    super::block_on(waf_firewalls_api::create_waf_firewall_with_info(configuration, params))
+}
+
+/// Delete the firewall object for a particular service and service version. 
+pub fn delete_waf_firewall(configuration: &Configuration, params: DeleteWafFirewallParams) -> Result<(), Error<DeleteWafFirewallError>> {
+    super::block_on(waf_firewalls_api::delete_waf_firewall(configuration, params))
+}
+
+/// Like [`delete_waf_firewall`], but also returns the status and headers of the response.
+pub fn delete_waf_firewall_with_info(configuration: &Configuration, params: DeleteWafFirewallParams) -> Result<crate::apis::Response<()>, Error<DeleteWafFirewallError>> {
+    super::block_on(waf_firewalls_api::delete_waf_firewall_with_info(configuration, params))
+}
+
+/// Get a specific firewall object.
+pub fn get_waf_firewall(configuration: &Configuration, params: GetWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<GetWafFirewallError>> {
+    super::block_on(waf_firewalls_api::get_waf_firewall(configuration, params))
+}
+
+/// Like [`get_waf_firewall`], but also returns the status and headers of the response.
+pub fn get_waf_firewall_with_info(configuration: &Configuration, params: GetWafFirewallParams) -> Result<crate::apis::Response<crate::models::WafFirewallResponse>, Error<GetWafFirewallError>> {
+    super::block_on(waf_firewalls_api::get_waf_firewall_with_info(configuration, params))
+}
+
+/// List all firewall objects.
+pub fn list_waf_firewalls(configuration: &Configuration, params: ListWafFirewallsParams) -> Result<crate::models::WafFirewallsResponse, Error<ListWafFirewallsError>> {
+    super::block_on(waf_firewalls_api::list_waf_firewalls(configuration, params))
+}
+
+/// Like [`list_waf_firewalls`], but also returns the status and headers of the response.
+pub fn list_waf_firewalls_with_info(configuration: &Configuration, params: ListWafFirewallsParams) -> Result<crate::apis::Response<crate::models::WafFirewallsResponse>, Error<ListWafFirewallsError>> {
+    super::block_on(waf_firewalls_api::list_waf_firewalls_with_info(configuration, params))
+}
+
+/// Update a firewall object for a particular service and service version. Specifying a `service_version_number` is required. 
+pub fn update_waf_firewall(configuration: &Configuration, params: UpdateWafFirewallParams) -> Result<crate::models::WafFirewallResponse, Error<UpdateWafFirewallError>> {
+    super::block_on(waf_firewalls_api::update_waf_firewall(configuration, params))
+}
+
+/// Like [`update_waf_firewall`], but also returns the status and headers of the response.
+pub fn update_waf_firewall_with_info(configuration: &Configuration, params: UpdateWafFirewallParams) -> Result<crate::apis::Response<crate::models::WafFirewallResponse>, Error<UpdateWafFirewallError>> {
+    super::block_on(waf_firewalls_api::update_waf_firewall_with_info(configuration, params))
+}
@@ -0,0 +1,31 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{waf_rule_revisions_api, Error};
+
+pub use crate::apis::waf_rule_revisions_api::{GetWafRuleRevisionError, GetWafRuleRevisionParams, ListWafRuleRevisionsError, ListWafRuleRevisionsParams};
+
+/// Get a specific rule revision.
+pub fn get_waf_rule_revision(configuration: &Configuration, params: GetWafRuleRevisionParams) -> Result<crate::models::WafRuleRevisionResponse, Error<GetWafRuleRevisionError>> {
+    super::block_on(waf_rule_revisions_api::get_waf_rule_revision(configuration, params))
+}
+
+/// Like [`get_waf_rule_revision`], but also returns the status and headers of the response.
+pub fn get_waf_rule_revision_with_info(configuration: &Configuration, params: GetWafRuleRevisionParams) -> Result<crate::apis::Response<crate::models::WafRuleRevisionResponse>, Error<GetWafRuleRevisionError>> {
+    super::block_on(waf_rule_revisions_api::get_waf_rule_revision_with_info(configuration, params))
+}
+
+/// List all revisions for a specific rule. The `rule_id` provided can be the ModSecurity Rule ID or the Fastly generated rule ID.
+pub fn list_waf_rule_revisions(configuration: &Configuration, params: ListWafRuleRevisionsParams) -> Result<crate::models::WafRuleRevisionsResponse, Error<ListWafRuleRevisionsError>> {
+    super::block_on(waf_rule_revisions_api::list_waf_rule_revisions(configuration, params))
+}
+
+/// Like [`list_waf_rule_revisions`], but also returns the status and headers of the response.
+pub fn list_waf_rule_revisions_with_info(configuration: &Configuration, params: ListWafRuleRevisionsParams) -> Result<crate::apis::Response<crate::models::WafRuleRevisionsResponse>, Error<ListWafRuleRevisionsError>> {
+    super::block_on(waf_rule_revisions_api::list_waf_rule_revisions_with_info(configuration, params))
+}
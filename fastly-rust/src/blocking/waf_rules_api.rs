@@ -0,0 +1,31 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{waf_rules_api, Error};
+
+pub use crate::apis::waf_rules_api::{GetWafRuleError, GetWafRuleParams, ListWafRulesError, ListWafRulesParams};
+
+/// Get a specific rule. The `id` provided can be the ModSecurity Rule ID or the Fastly generated rule ID.
+pub fn get_waf_rule(configuration: &Configuration, params: GetWafRuleParams) -> Result<crate::models::WafRuleResponse, Error<GetWafRuleError>> {
+    super::block_on(waf_rules_api::get_waf_rule(configuration, params))
+}
+
+/// Like [`get_waf_rule`], but also returns the status and headers of the response.
+pub fn get_waf_rule_with_info(configuration: &Configuration, params: GetWafRuleParams) -> Result<crate::apis::Response<crate::models::WafRuleResponse>, Error<GetWafRuleError>> {
+    super::block_on(waf_rules_api::get_waf_rule_with_info(configuration, params))
+}
+
+/// List all available WAF rules.
+pub fn list_waf_rules(configuration: &Configuration, params: ListWafRulesParams) -> Result<crate::models::WafRulesResponse, Error<ListWafRulesError>> {
+    super::block_on(waf_rules_api::list_waf_rules(configuration, params))
+}
+
+/// Like [`list_waf_rules`], but also returns the status and headers of the response.
+pub fn list_waf_rules_with_info(configuration: &Configuration, params: ListWafRulesParams) -> Result<crate::apis::Response<crate::models::WafRulesResponse>, Error<ListWafRulesError>> {
+    super::block_on(waf_rules_api::list_waf_rules_with_info(configuration, params))
+}
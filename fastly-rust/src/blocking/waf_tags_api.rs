@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{waf_tags_api, Error};
+
+pub use crate::apis::waf_tags_api::{ListWafTagsError, ListWafTagsParams};
+
+/// List all tags.
+pub fn list_waf_tags(configuration: &Configuration, params: ListWafTagsParams) -> Result<crate::models::WafTagsResponse, Error<ListWafTagsError>> {
+    super::block_on(waf_tags_api::list_waf_tags(configuration, params))
+}
+
+/// Like [`list_waf_tags`], but also returns the status and headers of the response.
+pub fn list_waf_tags_with_info(configuration: &Configuration, params: ListWafTagsParams) -> Result<crate::apis::Response<crate::models::WafTagsResponse>, Error<ListWafTagsError>> {
+    super::block_on(waf_tags_api::list_waf_tags_with_info(configuration, params))
+}
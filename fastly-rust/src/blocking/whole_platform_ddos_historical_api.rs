@@ -0,0 +1,21 @@
+/*
+ * Fastly API
+ *
+ * Via the Fastly API you can perform any of the operations that are possible within the management console,  including creating services, domains, and backends, configuring rules or uploading your own application code, as well as account operations such as user administration and billing reports. The API is organized into collections of endpoints that allow manipulation of objects related to Fastly services and accounts. For the most accurate and up-to-date API reference content, visit our [Developer Hub](https://www.fastly.com/documentation/reference/api/) 
+ *
+ */
+
+use crate::apis::configuration::Configuration;
+use crate::apis::{whole_platform_ddos_historical_api, Error};
+
+pub use crate::apis::whole_platform_ddos_historical_api::{GetPlatformDdosHistoricalError, GetPlatformDdosHistoricalParams};
+
+/// Fetches historical DDoS metrics for the entire Fastly platform.
+pub fn get_platform_ddos_historical(configuration: &Configuration, params: GetPlatformDdosHistoricalParams) -> Result<crate::models::PlatformDdosResponse, Error<GetPlatformDdosHistoricalError>> {
+    super::block_on(whole_platform_ddos_historical_api::get_platform_ddos_historical(configuration, params))
+}
+
+/// Like [`get_platform_ddos_historical`], but also returns the status and headers of the response.
+pub fn get_platform_ddos_historical_with_info(configuration: &Configuration, params: GetPlatformDdosHistoricalParams) -> Result<crate::apis::Response<crate::models::PlatformDdosResponse>, Error<GetPlatformDdosHistoricalError>> {
+    super::block_on(whole_platform_ddos_historical_api::get_platform_ddos_historical_with_info(configuration, params))
+}
@@ -0,0 +1,99 @@
+//! A resource-oriented facade over the [`apis`](crate::apis) modules.
+//!
+//! A [`FastlyClient`] shares one [`Configuration`] between all calls. The
+//! clients it hands out for a service, and for a version of that service,
+//! fill in `service_id` and `version_id`, so params structs can leave them at
+//! their defaults:
+//!
+//! ```no_run
+//! # use fastly_api::apis::configuration::Configuration;
+//! # use fastly_api::apis::version_api::UpdateServiceVersionParams;
+//! # use fastly_api::client::FastlyClient;
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = FastlyClient::new(Configuration::from_env_chain()?);
+//! let version = client.service("SU1Z0isxPaozGVKXdv0eY").version(1);
+//!
+//! let params = UpdateServiceVersionParams { comment: Some("origin failover".to_owned()), ..Default::default() };
+//! version.update(params).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Endpoints that are not scoped to a service are called through the
+//! `apis` modules with [`FastlyClient::configuration`].
+
+use std::sync::Arc;
+
+use crate::apis::configuration::Configuration;
+
+pub mod service;
+pub mod version;
+
+#[derive(Debug, Clone)]
+pub struct FastlyClient {
+    configuration: Arc<Configuration>,
+}
+
+impl FastlyClient {
+    pub fn new(configuration: Configuration) -> FastlyClient {
+        FastlyClient { configuration: Arc::new(configuration) }
+    }
+
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Endpoints scoped to the service `service_id`.
+    pub fn service(&self, service_id: impl Into<String>) -> ServiceClient {
+        ServiceClient {
+            configuration: self.configuration.clone(),
+            service_id: service_id.into(),
+        }
+    }
+}
+
+impl From<Configuration> for FastlyClient {
+    fn from(configuration: Configuration) -> FastlyClient {
+        FastlyClient::new(configuration)
+    }
+}
+
+/// Endpoints scoped to one service. See [`service`] for its sub-clients.
+#[derive(Debug, Clone)]
+pub struct ServiceClient {
+    configuration: Arc<Configuration>,
+    service_id: String,
+}
+
+impl ServiceClient {
+    pub fn service_id(&self) -> &str {
+        &self.service_id
+    }
+
+    /// Endpoints scoped to version `version_id` of this service.
+    pub fn version(&self, version_id: i32) -> VersionClient {
+        VersionClient {
+            configuration: self.configuration.clone(),
+            service_id: self.service_id.clone(),
+            version_id,
+        }
+    }
+}
+
+/// Endpoints scoped to one version of a service. See [`version`] for its sub-clients.
+#[derive(Debug, Clone)]
+pub struct VersionClient {
+    configuration: Arc<Configuration>,
+    service_id: String,
+    version_id: i32,
+}
+
+impl VersionClient {
+    pub fn service_id(&self) -> &str {
+        &self.service_id
+    }
+
+    pub fn version_id(&self) -> i32 {
+        self.version_id
+    }
+}
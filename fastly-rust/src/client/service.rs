@@ -0,0 +1,671 @@
+use crate::apis::Error;
+use crate::apis::{service_api, version_api};
+#[cfg(feature = "acl")]
+use crate::apis::acl_entry_api;
+#[cfg(feature = "dictionary")]
+use crate::apis::dictionary_item_api;
+#[cfg(feature = "vcl")]
+use crate::apis::diff_api;
+#[cfg(feature = "stats")]
+use crate::apis::domain_inspector_historical_api;
+#[cfg(feature = "stats")]
+use crate::apis::domain_inspector_realtime_api;
+#[cfg(feature = "platform")]
+use crate::apis::enabled_products_api;
+#[cfg(feature = "waf-legacy")]
+use crate::apis::legacy_waf_owasp_api;
+#[cfg(feature = "waf-legacy")]
+use crate::apis::legacy_waf_rule_status_api;
+#[cfg(feature = "waf-legacy")]
+use crate::apis::legacy_waf_ruleset_api;
+#[cfg(feature = "waf-legacy")]
+use crate::apis::legacy_waf_update_status_api;
+#[cfg(feature = "stats")]
+use crate::apis::origin_inspector_historical_api;
+#[cfg(feature = "stats")]
+use crate::apis::origin_inspector_realtime_api;
+#[cfg(feature = "platform")]
+use crate::apis::publish_api;
+#[cfg(feature = "purge")]
+use crate::apis::purge_api;
+#[cfg(feature = "stats")]
+use crate::apis::realtime_api;
+#[cfg(feature = "backends")]
+use crate::apis::server_api;
+#[cfg(feature = "vcl")]
+use crate::apis::snippet_api;
+#[cfg(feature = "stats")]
+use crate::apis::stats_api;
+#[cfg(feature = "vcl")]
+use crate::apis::vcl_api;
+#[cfg(feature = "vcl")]
+use crate::apis::vcl_diff_api;
+
+use super::ServiceClient;
+
+impl ServiceClient {
+    /// Delete a service.
+    pub async fn delete(&self) -> Result<crate::models::InlineResponse200, Error<service_api::DeleteServiceError>> {
+        service_api::delete_service(&self.configuration, service_api::DeleteServiceParams { service_id: self.service_id.clone() }).await
+    }
+
+    /// Get a specific service by id.
+    pub async fn get(&self) -> Result<crate::models::ServiceResponse, Error<service_api::GetServiceError>> {
+        service_api::get_service(&self.configuration, service_api::GetServiceParams { service_id: self.service_id.clone() }).await
+    }
+
+    /// List detailed information on a specified service.
+    pub async fn detail(&self, params: service_api::GetServiceDetailParams) -> Result<crate::models::ServiceDetail, Error<service_api::GetServiceDetailError>> {
+        service_api::get_service_detail(&self.configuration, service_api::GetServiceDetailParams { service_id: self.service_id.clone(), ..params }).await
+    }
+
+    /// List the domains within a service.
+    pub async fn list_domains(&self) -> Result<Vec<crate::models::DomainResponse>, Error<service_api::ListServiceDomainsError>> {
+        service_api::list_service_domains(&self.configuration, service_api::ListServiceDomainsParams { service_id: self.service_id.clone() }).await
+    }
+
+    /// Update a service.
+    pub async fn update(&self, params: service_api::UpdateServiceParams) -> Result<crate::models::ServiceResponse, Error<service_api::UpdateServiceError>> {
+        service_api::update_service(&self.configuration, service_api::UpdateServiceParams { service_id: self.service_id.clone(), ..params }).await
+    }
+
+    /// Create a version for a particular service.
+    pub async fn create_version(&self) -> Result<crate::models::VersionCreateResponse, Error<version_api::CreateServiceVersionError>> {
+        version_api::create_service_version(&self.configuration, version_api::CreateServiceVersionParams { service_id: self.service_id.clone() }).await
+    }
+
+    /// List the versions for a particular service.
+    pub async fn list_versions(&self) -> Result<Vec<crate::models::VersionResponse>, Error<version_api::ListServiceVersionsError>> {
+        version_api::list_service_versions(&self.configuration, version_api::ListServiceVersionsParams { service_id: self.service_id.clone() }).await
+    }
+
+    #[cfg(feature = "acl")]
+    /// Endpoints of [`acl_entry_api`].
+    pub fn acl_entries(&self) -> AclEntriesClient<'_> {
+        AclEntriesClient { service: self }
+    }
+
+    #[cfg(feature = "dictionary")]
+    /// Endpoints of [`dictionary_item_api`].
+    pub fn dictionary_items(&self) -> DictionaryItemsClient<'_> {
+        DictionaryItemsClient { service: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`diff_api`].
+    pub fn diff(&self) -> DiffClient<'_> {
+        DiffClient { service: self }
+    }
+
+    #[cfg(feature = "stats")]
+    /// Endpoints of [`domain_inspector_historical_api`].
+    pub fn domain_inspector_historical(&self) -> DomainInspectorHistoricalClient<'_> {
+        DomainInspectorHistoricalClient { service: self }
+    }
+
+    #[cfg(feature = "stats")]
+    /// Endpoints of [`domain_inspector_realtime_api`].
+    pub fn domain_inspector_realtime(&self) -> DomainInspectorRealtimeClient<'_> {
+        DomainInspectorRealtimeClient { service: self }
+    }
+
+    #[cfg(feature = "platform")]
+    /// Endpoints of [`enabled_products_api`].
+    pub fn products(&self) -> ProductsClient<'_> {
+        ProductsClient { service: self }
+    }
+
+    #[cfg(feature = "waf-legacy")]
+    /// Endpoints of [`legacy_waf_owasp_api`].
+    pub fn legacy_waf_owasp(&self) -> LegacyWafOwaspClient<'_> {
+        LegacyWafOwaspClient { service: self }
+    }
+
+    #[cfg(feature = "waf-legacy")]
+    /// Endpoints of [`legacy_waf_rule_status_api`].
+    pub fn legacy_waf_rule_statuses(&self) -> LegacyWafRuleStatusesClient<'_> {
+        LegacyWafRuleStatusesClient { service: self }
+    }
+
+    #[cfg(feature = "waf-legacy")]
+    /// Endpoints of [`legacy_waf_ruleset_api`].
+    pub fn legacy_waf_ruleset(&self) -> LegacyWafRulesetClient<'_> {
+        LegacyWafRulesetClient { service: self }
+    }
+
+    #[cfg(feature = "waf-legacy")]
+    /// Endpoints of [`legacy_waf_update_status_api`].
+    pub fn legacy_waf_update_statuses(&self) -> LegacyWafUpdateStatusesClient<'_> {
+        LegacyWafUpdateStatusesClient { service: self }
+    }
+
+    #[cfg(feature = "stats")]
+    /// Endpoints of [`origin_inspector_historical_api`].
+    pub fn origin_inspector_historical(&self) -> OriginInspectorHistoricalClient<'_> {
+        OriginInspectorHistoricalClient { service: self }
+    }
+
+    #[cfg(feature = "stats")]
+    /// Endpoints of [`origin_inspector_realtime_api`].
+    pub fn origin_inspector_realtime(&self) -> OriginInspectorRealtimeClient<'_> {
+        OriginInspectorRealtimeClient { service: self }
+    }
+
+    #[cfg(feature = "platform")]
+    /// Endpoints of [`publish_api`].
+    pub fn publish(&self) -> PublishClient<'_> {
+        PublishClient { service: self }
+    }
+
+    #[cfg(feature = "purge")]
+    /// Endpoints of [`purge_api`].
+    pub fn purge(&self) -> PurgeClient<'_> {
+        PurgeClient { service: self }
+    }
+
+    #[cfg(feature = "stats")]
+    /// Endpoints of [`realtime_api`].
+    pub fn realtime(&self) -> RealtimeClient<'_> {
+        RealtimeClient { service: self }
+    }
+
+    #[cfg(feature = "backends")]
+    /// Endpoints of [`server_api`].
+    pub fn pool_servers(&self) -> PoolServersClient<'_> {
+        PoolServersClient { service: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`snippet_api`].
+    pub fn dynamic_snippets(&self) -> DynamicSnippetsClient<'_> {
+        DynamicSnippetsClient { service: self }
+    }
+
+    #[cfg(feature = "stats")]
+    /// Endpoints of [`stats_api`].
+    pub fn stats(&self) -> StatsClient<'_> {
+        StatsClient { service: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`vcl_api`].
+    pub fn vcl(&self) -> VclClient<'_> {
+        VclClient { service: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`vcl_diff_api`].
+    pub fn vcl_diff(&self) -> VclDiffClient<'_> {
+        VclDiffClient { service: self }
+    }
+}
+
+#[cfg(feature = "acl")]
+/// [`acl_entry_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct AclEntriesClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "acl")]
+impl AclEntriesClient<'_> {
+    /// Update multiple ACL entries on the same ACL. For faster updates to your service, group your changes into large batches. The maximum batch size is 1000 entries. [Contact support](https://support.fastly.com/) to discuss raising this limit.
+    pub async fn bulk_update(&self, params: acl_entry_api::BulkUpdateAclEntriesParams) -> Result<crate::models::InlineResponse200, Error<acl_entry_api::BulkUpdateAclEntriesError>> {
+        acl_entry_api::bulk_update_acl_entries(&self.service.configuration, acl_entry_api::BulkUpdateAclEntriesParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Add an ACL entry to an ACL.
+    pub async fn create(&self, params: acl_entry_api::CreateAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<acl_entry_api::CreateAclEntryError>> {
+        acl_entry_api::create_acl_entry(&self.service.configuration, acl_entry_api::CreateAclEntryParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Delete an ACL entry from a specified ACL.
+    pub async fn delete(&self, params: acl_entry_api::DeleteAclEntryParams) -> Result<crate::models::InlineResponse200, Error<acl_entry_api::DeleteAclEntryError>> {
+        acl_entry_api::delete_acl_entry(&self.service.configuration, acl_entry_api::DeleteAclEntryParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Retrieve a single ACL entry.
+    pub async fn get(&self, params: acl_entry_api::GetAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<acl_entry_api::GetAclEntryError>> {
+        acl_entry_api::get_acl_entry(&self.service.configuration, acl_entry_api::GetAclEntryParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// List ACL entries for a specified ACL.
+    pub async fn list(&self, params: acl_entry_api::ListAclEntriesParams) -> Result<Vec<crate::models::AclEntryResponse>, Error<acl_entry_api::ListAclEntriesError>> {
+        acl_entry_api::list_acl_entries(&self.service.configuration, acl_entry_api::ListAclEntriesParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Update an ACL entry for a specified ACL.
+    pub async fn update(&self, params: acl_entry_api::UpdateAclEntryParams) -> Result<crate::models::AclEntryResponse, Error<acl_entry_api::UpdateAclEntryError>> {
+        acl_entry_api::update_acl_entry(&self.service.configuration, acl_entry_api::UpdateAclEntryParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "dictionary")]
+/// [`dictionary_item_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct DictionaryItemsClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "dictionary")]
+impl DictionaryItemsClient<'_> {
+    /// Update multiple items in the same dictionary. For faster updates to your service, group your changes into large batches. The maximum batch size is 1000 items. [Contact support](https://support.fastly.com/) to discuss raising this limit.
+    pub async fn bulk_update(&self, params: dictionary_item_api::BulkUpdateDictionaryItemParams) -> Result<crate::models::InlineResponse200, Error<dictionary_item_api::BulkUpdateDictionaryItemError>> {
+        dictionary_item_api::bulk_update_dictionary_item(&self.service.configuration, dictionary_item_api::BulkUpdateDictionaryItemParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Create DictionaryItem given service, dictionary ID, item key, and item value.
+    pub async fn create(&self, params: dictionary_item_api::CreateDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<dictionary_item_api::CreateDictionaryItemError>> {
+        dictionary_item_api::create_dictionary_item(&self.service.configuration, dictionary_item_api::CreateDictionaryItemParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Delete DictionaryItem given service, dictionary ID, and item key.
+    pub async fn delete(&self, params: dictionary_item_api::DeleteDictionaryItemParams) -> Result<crate::models::InlineResponse200, Error<dictionary_item_api::DeleteDictionaryItemError>> {
+        dictionary_item_api::delete_dictionary_item(&self.service.configuration, dictionary_item_api::DeleteDictionaryItemParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Retrieve a single DictionaryItem given service, dictionary ID and item key.
+    pub async fn get(&self, params: dictionary_item_api::GetDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<dictionary_item_api::GetDictionaryItemError>> {
+        dictionary_item_api::get_dictionary_item(&self.service.configuration, dictionary_item_api::GetDictionaryItemParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// List of DictionaryItems given service and dictionary ID.
+    pub async fn list(&self, params: dictionary_item_api::ListDictionaryItemsParams) -> Result<Vec<crate::models::DictionaryItemResponse>, Error<dictionary_item_api::ListDictionaryItemsError>> {
+        dictionary_item_api::list_dictionary_items(&self.service.configuration, dictionary_item_api::ListDictionaryItemsParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Update DictionaryItem given service, dictionary ID, item key, and item value.
+    pub async fn update(&self, params: dictionary_item_api::UpdateDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<dictionary_item_api::UpdateDictionaryItemError>> {
+        dictionary_item_api::update_dictionary_item(&self.service.configuration, dictionary_item_api::UpdateDictionaryItemParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Upsert DictionaryItem given service, dictionary ID, item key, and item value.
+    pub async fn upsert(&self, params: dictionary_item_api::UpsertDictionaryItemParams) -> Result<crate::models::DictionaryItemResponse, Error<dictionary_item_api::UpsertDictionaryItemError>> {
+        dictionary_item_api::upsert_dictionary_item(&self.service.configuration, dictionary_item_api::UpsertDictionaryItemParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`diff_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct DiffClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "vcl")]
+impl DiffClient<'_> {
+    /// Get diff between two versions.
+    pub async fn diff_service_versions(&self, params: diff_api::DiffServiceVersionsParams) -> Result<crate::models::DiffResponse, Error<diff_api::DiffServiceVersionsError>> {
+        diff_api::diff_service_versions(&self.service.configuration, diff_api::DiffServiceVersionsParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "stats")]
+/// [`domain_inspector_historical_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct DomainInspectorHistoricalClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "stats")]
+impl DomainInspectorHistoricalClient<'_> {
+    /// Fetches historical domain metrics for a given Fastly service, optionally filtering and grouping the results by domain, region, or POP. 
+    pub async fn get_domain_inspector_historical(&self, params: domain_inspector_historical_api::GetDomainInspectorHistoricalParams) -> Result<crate::models::HistoricalDomainsResponse, Error<domain_inspector_historical_api::GetDomainInspectorHistoricalError>> {
+        domain_inspector_historical_api::get_domain_inspector_historical(&self.service.configuration, domain_inspector_historical_api::GetDomainInspectorHistoricalParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "stats")]
+/// [`domain_inspector_realtime_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct DomainInspectorRealtimeClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "stats")]
+impl DomainInspectorRealtimeClient<'_> {
+    /// Get data for the 120 seconds preceding the latest timestamp available for a service.
+    pub async fn get_domain_inspector_last120_seconds(&self) -> Result<crate::models::DomainInspector, Error<domain_inspector_realtime_api::GetDomainInspectorLast120SecondsError>> {
+        domain_inspector_realtime_api::get_domain_inspector_last120_seconds(&self.service.configuration, domain_inspector_realtime_api::GetDomainInspectorLast120SecondsParams { service_id: self.service.service_id.clone() }).await
+    }
+
+    /// Get data for the `max_entries` seconds preceding the latest timestamp available for a service, up to a maximum of 120 entries.
+    pub async fn get_domain_inspector_last_max_entries(&self, params: domain_inspector_realtime_api::GetDomainInspectorLastMaxEntriesParams) -> Result<crate::models::DomainInspector, Error<domain_inspector_realtime_api::GetDomainInspectorLastMaxEntriesError>> {
+        domain_inspector_realtime_api::get_domain_inspector_last_max_entries(&self.service.configuration, domain_inspector_realtime_api::GetDomainInspectorLastMaxEntriesParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Get real-time domain data for the specified reporting period. Specify `0` to get a single entry for the last complete second. The `Timestamp` field included in the response provides the time index of the latest entry in the dataset and can be provided as the `start_timestamp` of the next request for a seamless continuation of the dataset from one request to the next. Due to processing latency, the earliest entry in the response dataset may be earlier than `start_timestamp` by the value of `AggregateDelay`. 
+    pub async fn get_domain_inspector_last_second(&self, params: domain_inspector_realtime_api::GetDomainInspectorLastSecondParams) -> Result<crate::models::DomainInspector, Error<domain_inspector_realtime_api::GetDomainInspectorLastSecondError>> {
+        domain_inspector_realtime_api::get_domain_inspector_last_second(&self.service.configuration, domain_inspector_realtime_api::GetDomainInspectorLastSecondParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "platform")]
+/// [`enabled_products_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct ProductsClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "platform")]
+impl ProductsClient<'_> {
+    /// Disable a product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
+    pub async fn disable(&self, params: enabled_products_api::DisableProductParams) -> Result<(), Error<enabled_products_api::DisableProductError>> {
+        enabled_products_api::disable_product(&self.service.configuration, enabled_products_api::DisableProductParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Enable a product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
+    pub async fn enable(&self, params: enabled_products_api::EnableProductParams) -> Result<crate::models::EnabledProductResponse, Error<enabled_products_api::EnableProductError>> {
+        enabled_products_api::enable_product(&self.service.configuration, enabled_products_api::EnableProductParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Get enabled product on a service. Supported product IDs: `brotli_compression`,`domain_inspector`,`fanout`,`image_optimizer`,`origin_inspector`, and `websockets`.
+    pub async fn get(&self, params: enabled_products_api::GetEnabledProductParams) -> Result<crate::models::EnabledProductResponse, Error<enabled_products_api::GetEnabledProductError>> {
+        enabled_products_api::get_enabled_product(&self.service.configuration, enabled_products_api::GetEnabledProductParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "waf-legacy")]
+/// [`legacy_waf_owasp_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct LegacyWafOwaspClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "waf-legacy")]
+impl LegacyWafOwaspClient<'_> {
+    /// Create an OWASP settings object for a particular service and firewall.
+    pub async fn create(&self, params: legacy_waf_owasp_api::CreateOwaspSettingsParams) -> Result<serde_json::Value, Error<legacy_waf_owasp_api::CreateOwaspSettingsError>> {
+        legacy_waf_owasp_api::create_owasp_settings(&self.service.configuration, legacy_waf_owasp_api::CreateOwaspSettingsParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Get the OWASP settings object for a particular service and firewall.
+    pub async fn get(&self, params: legacy_waf_owasp_api::GetOwaspSettingsParams) -> Result<serde_json::Value, Error<legacy_waf_owasp_api::GetOwaspSettingsError>> {
+        legacy_waf_owasp_api::get_owasp_settings(&self.service.configuration, legacy_waf_owasp_api::GetOwaspSettingsParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Update the OWASP settings object for a particular service and firewall.
+    pub async fn update(&self, params: legacy_waf_owasp_api::UpdateOwaspSettingsParams) -> Result<serde_json::Value, Error<legacy_waf_owasp_api::UpdateOwaspSettingsError>> {
+        legacy_waf_owasp_api::update_owasp_settings(&self.service.configuration, legacy_waf_owasp_api::UpdateOwaspSettingsParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "waf-legacy")]
+/// [`legacy_waf_rule_status_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct LegacyWafRuleStatusesClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "waf-legacy")]
+impl LegacyWafRuleStatusesClient<'_> {
+    /// Get a specific rule status object for a particular service, firewall, and rule.
+    pub async fn get(&self, params: legacy_waf_rule_status_api::GetWafFirewallRuleStatusParams) -> Result<serde_json::Value, Error<legacy_waf_rule_status_api::GetWafFirewallRuleStatusError>> {
+        legacy_waf_rule_status_api::get_waf_firewall_rule_status(&self.service.configuration, legacy_waf_rule_status_api::GetWafFirewallRuleStatusParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// List all rule statuses for a particular service and firewall.
+    pub async fn list(&self, params: legacy_waf_rule_status_api::ListWafFirewallRuleStatusesParams) -> Result<serde_json::Value, Error<legacy_waf_rule_status_api::ListWafFirewallRuleStatusesError>> {
+        legacy_waf_rule_status_api::list_waf_firewall_rule_statuses(&self.service.configuration, legacy_waf_rule_status_api::ListWafFirewallRuleStatusesParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Update a rule status for a particular service, firewall, and rule.
+    pub async fn update(&self, params: legacy_waf_rule_status_api::UpdateWafFirewallRuleStatusParams) -> Result<serde_json::Value, Error<legacy_waf_rule_status_api::UpdateWafFirewallRuleStatusError>> {
+        legacy_waf_rule_status_api::update_waf_firewall_rule_status(&self.service.configuration, legacy_waf_rule_status_api::UpdateWafFirewallRuleStatusParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Create or update all rule statuses for a particular service and firewall, based on tag name. By default, only rule status for enabled rules (with status log or block) will be updated. To update rule statuses for disabled rules under the specified tag, use the force attribute.
+    pub async fn update_tag(&self, params: legacy_waf_rule_status_api::UpdateWafFirewallRuleStatusesTagParams) -> Result<serde_json::Value, Error<legacy_waf_rule_status_api::UpdateWafFirewallRuleStatusesTagError>> {
+        legacy_waf_rule_status_api::update_waf_firewall_rule_statuses_tag(&self.service.configuration, legacy_waf_rule_status_api::UpdateWafFirewallRuleStatusesTagParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "waf-legacy")]
+/// [`legacy_waf_ruleset_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct LegacyWafRulesetClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "waf-legacy")]
+impl LegacyWafRulesetClient<'_> {
+    /// Get a WAF ruleset for a particular service and firewall object.
+    pub async fn get(&self, params: legacy_waf_ruleset_api::GetWafRulesetParams) -> Result<serde_json::Value, Error<legacy_waf_ruleset_api::GetWafRulesetError>> {
+        legacy_waf_ruleset_api::get_waf_ruleset(&self.service.configuration, legacy_waf_ruleset_api::GetWafRulesetParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Get a preview of the WAF ruleset VCL for a particular service and firewall object based on changes to WAF configuration before deploying the ruleset. The response will include a link to status of the background VCL generation job. Once the background job is completed, the preview WAF ruleset VCL can be retrieved from the status response.
+    pub async fn get_vcl(&self, params: legacy_waf_ruleset_api::GetWafRulesetVclParams) -> Result<serde_json::Value, Error<legacy_waf_ruleset_api::GetWafRulesetVclError>> {
+        legacy_waf_ruleset_api::get_waf_ruleset_vcl(&self.service.configuration, legacy_waf_ruleset_api::GetWafRulesetVclParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Update the WAF ruleset for a particular service and firewall object. Use the URL in the response to view the WAF ruleset deploy status.
+    pub async fn update(&self, params: legacy_waf_ruleset_api::UpdateWafRulesetParams) -> Result<serde_json::Value, Error<legacy_waf_ruleset_api::UpdateWafRulesetError>> {
+        legacy_waf_ruleset_api::update_waf_ruleset(&self.service.configuration, legacy_waf_ruleset_api::UpdateWafRulesetParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "waf-legacy")]
+/// [`legacy_waf_update_status_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct LegacyWafUpdateStatusesClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "waf-legacy")]
+impl LegacyWafUpdateStatusesClient<'_> {
+    /// Get a specific update status object for a particular service and firewall object.
+    pub async fn get(&self, params: legacy_waf_update_status_api::GetWafUpdateStatusParams) -> Result<serde_json::Value, Error<legacy_waf_update_status_api::GetWafUpdateStatusError>> {
+        legacy_waf_update_status_api::get_waf_update_status(&self.service.configuration, legacy_waf_update_status_api::GetWafUpdateStatusParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// List all update statuses for a particular service and firewall object.
+    pub async fn list(&self, params: legacy_waf_update_status_api::ListWafUpdateStatusesParams) -> Result<serde_json::Value, Error<legacy_waf_update_status_api::ListWafUpdateStatusesError>> {
+        legacy_waf_update_status_api::list_waf_update_statuses(&self.service.configuration, legacy_waf_update_status_api::ListWafUpdateStatusesParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "stats")]
+/// [`origin_inspector_historical_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct OriginInspectorHistoricalClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "stats")]
+impl OriginInspectorHistoricalClient<'_> {
+    /// Fetches historical origin metrics for a given Fastly service, optionally filtering and grouping the results by origin host, region, or POP. 
+    pub async fn get_origin_inspector_historical(&self, params: origin_inspector_historical_api::GetOriginInspectorHistoricalParams) -> Result<crate::models::HistoricalOriginsResponse, Error<origin_inspector_historical_api::GetOriginInspectorHistoricalError>> {
+        origin_inspector_historical_api::get_origin_inspector_historical(&self.service.configuration, origin_inspector_historical_api::GetOriginInspectorHistoricalParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "stats")]
+/// [`origin_inspector_realtime_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct OriginInspectorRealtimeClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "stats")]
+impl OriginInspectorRealtimeClient<'_> {
+    /// Get data for the 120 seconds preceding the latest timestamp available for a service.
+    pub async fn get_origin_inspector_last120_seconds(&self) -> Result<crate::models::OriginInspector, Error<origin_inspector_realtime_api::GetOriginInspectorLast120SecondsError>> {
+        origin_inspector_realtime_api::get_origin_inspector_last120_seconds(&self.service.configuration, origin_inspector_realtime_api::GetOriginInspectorLast120SecondsParams { service_id: self.service.service_id.clone() }).await
+    }
+
+    /// Get data for the `max_entries` seconds preceding the latest timestamp available for a service, up to a maximum of 120 entries.
+    pub async fn get_origin_inspector_last_max_entries(&self, params: origin_inspector_realtime_api::GetOriginInspectorLastMaxEntriesParams) -> Result<crate::models::OriginInspector, Error<origin_inspector_realtime_api::GetOriginInspectorLastMaxEntriesError>> {
+        origin_inspector_realtime_api::get_origin_inspector_last_max_entries(&self.service.configuration, origin_inspector_realtime_api::GetOriginInspectorLastMaxEntriesParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Get real-time origin data for the specified reporting period. Specify `0` to get a single entry for the last complete second. The `Timestamp` field included in the response provides the time index of the latest entry in the dataset and can be provided as the `start_timestamp` of the next request for a seamless continuation of the dataset from one request to the next. Due to processing latency, the earliest entry in the response dataset may be earlier than `start_timestamp` by the value of `AggregateDelay`. 
+    pub async fn get_origin_inspector_last_second(&self, params: origin_inspector_realtime_api::GetOriginInspectorLastSecondParams) -> Result<crate::models::OriginInspector, Error<origin_inspector_realtime_api::GetOriginInspectorLastSecondError>> {
+        origin_inspector_realtime_api::get_origin_inspector_last_second(&self.service.configuration, origin_inspector_realtime_api::GetOriginInspectorLastSecondParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "platform")]
+/// [`publish_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct PublishClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "platform")]
+impl PublishClient<'_> {
+    /// Send one or more messages to [Fanout](https://www.fastly.com/documentation/learning/concepts/real-time-messaging/fanout) subscribers. Each message specifies a channel, and Fanout will deliver the message to all subscribers of its channel. > **IMPORTANT:** For compatibility with GRIP, this endpoint requires a trailing slash, and the API token may be provided in the `Authorization` header (instead of the `Fastly-Key` header) using the `Bearer` scheme. 
+    pub async fn publish(&self, params: publish_api::PublishParams) -> Result<String, Error<publish_api::PublishError>> {
+        publish_api::publish(&self.service.configuration, publish_api::PublishParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "purge")]
+/// [`purge_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct PurgeClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "purge")]
+impl PurgeClient<'_> {
+    /// Instant Purge a particular service of items tagged with surrogate keys. Up to 256 surrogate keys can be purged in one batch request. As an alternative to sending the keys in a JSON object in the body of the request, this endpoint also supports listing keys in a <code>Surrogate-Key</code> request header, e.g. <code>Surrogate-Key: key_1 key_2 key_3</code>. 
+    pub async fn bulk_purge_tag(&self, params: purge_api::BulkPurgeTagParams) -> Result<::std::collections::HashMap<String, String>, Error<purge_api::BulkPurgeTagError>> {
+        purge_api::bulk_purge_tag(&self.service.configuration, purge_api::BulkPurgeTagParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Instant Purge everything from a service.  Purge-all requests cannot be done in soft mode and will always immediately invalidate all cached content associated with the service. To do a soft-purge-all, consider applying a constant [surrogate key](https://docs.fastly.com/en/guides/getting-started-with-surrogate-keys) tag (e.g., `\"all\"`) to all objects. 
+    pub async fn purge_all(&self) -> Result<crate::models::InlineResponse200, Error<purge_api::PurgeAllError>> {
+        purge_api::purge_all(&self.service.configuration, purge_api::PurgeAllParams { service_id: self.service.service_id.clone() }).await
+    }
+
+    /// Instant Purge a particular service of items tagged with a Surrogate Key. Only one surrogate key can be purged at a time. Multiple keys can be purged using a batch surrogate key purge request.
+    pub async fn purge_tag(&self, params: purge_api::PurgeTagParams) -> Result<crate::models::PurgeResponse, Error<purge_api::PurgeTagError>> {
+        purge_api::purge_tag(&self.service.configuration, purge_api::PurgeTagParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "stats")]
+/// [`realtime_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct RealtimeClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "stats")]
+impl RealtimeClient<'_> {
+    /// Get data for the 120 seconds preceding the latest timestamp available for a service.
+    pub async fn get_stats_last120_seconds(&self) -> Result<crate::models::Realtime, Error<realtime_api::GetStatsLast120SecondsError>> {
+        realtime_api::get_stats_last120_seconds(&self.service.configuration, realtime_api::GetStatsLast120SecondsParams { service_id: self.service.service_id.clone() }).await
+    }
+
+    /// Get data for the 120 seconds preceding the latest timestamp available for a service, up to a maximum of `max_entries` entries.
+    pub async fn get_stats_last120_seconds_limit_entries(&self, params: realtime_api::GetStatsLast120SecondsLimitEntriesParams) -> Result<crate::models::Realtime, Error<realtime_api::GetStatsLast120SecondsLimitEntriesError>> {
+        realtime_api::get_stats_last120_seconds_limit_entries(&self.service.configuration, realtime_api::GetStatsLast120SecondsLimitEntriesParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Get real-time data for the specified reporting period. Specify `0` to get a single entry for the last complete second. The `Timestamp` field included in the response provides the time index of the latest entry in the dataset and can be provided as the `start_timestamp` of the next request for a seamless continuation of the dataset from one request to the next.
+    pub async fn get_stats_last_second(&self, params: realtime_api::GetStatsLastSecondParams) -> Result<crate::models::Realtime, Error<realtime_api::GetStatsLastSecondError>> {
+        realtime_api::get_stats_last_second(&self.service.configuration, realtime_api::GetStatsLastSecondParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "backends")]
+/// [`server_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct PoolServersClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "backends")]
+impl PoolServersClient<'_> {
+    /// Creates a single server for a particular service and pool.
+    pub async fn create(&self, params: server_api::CreatePoolServerParams) -> Result<crate::models::ServerResponse, Error<server_api::CreatePoolServerError>> {
+        server_api::create_pool_server(&self.service.configuration, server_api::CreatePoolServerParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Deletes a single server for a particular service and pool.
+    pub async fn delete(&self, params: server_api::DeletePoolServerParams) -> Result<crate::models::InlineResponse200, Error<server_api::DeletePoolServerError>> {
+        server_api::delete_pool_server(&self.service.configuration, server_api::DeletePoolServerParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Gets a single server for a particular service and pool.
+    pub async fn get(&self, params: server_api::GetPoolServerParams) -> Result<crate::models::ServerResponse, Error<server_api::GetPoolServerError>> {
+        server_api::get_pool_server(&self.service.configuration, server_api::GetPoolServerParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Lists all servers for a particular service and pool.
+    pub async fn list(&self, params: server_api::ListPoolServersParams) -> Result<Vec<crate::models::ServerResponse>, Error<server_api::ListPoolServersError>> {
+        server_api::list_pool_servers(&self.service.configuration, server_api::ListPoolServersParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Updates a single server for a particular service and pool.
+    pub async fn update(&self, params: server_api::UpdatePoolServerParams) -> Result<crate::models::ServerResponse, Error<server_api::UpdatePoolServerError>> {
+        server_api::update_pool_server(&self.service.configuration, server_api::UpdatePoolServerParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`snippet_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicSnippetsClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "vcl")]
+impl DynamicSnippetsClient<'_> {
+    /// Get a single dynamic snippet for a particular service.
+    pub async fn get(&self, params: snippet_api::GetSnippetDynamicParams) -> Result<crate::models::SnippetResponse, Error<snippet_api::GetSnippetDynamicError>> {
+        snippet_api::get_snippet_dynamic(&self.service.configuration, snippet_api::GetSnippetDynamicParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+
+    /// Update a dynamic snippet for a particular service.
+    pub async fn update(&self, params: snippet_api::UpdateSnippetDynamicParams) -> Result<crate::models::SnippetResponse, Error<snippet_api::UpdateSnippetDynamicError>> {
+        snippet_api::update_snippet_dynamic(&self.service.configuration, snippet_api::UpdateSnippetDynamicParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "stats")]
+/// [`stats_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct StatsClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "stats")]
+impl StatsClient<'_> {
+    /// Get the stats from a service for a block of time. This lists all stats by PoP location, starting with AMS. This call requires parameters to select block of time to query. Use either a timestamp range (using start_time and end_time) or a specified month/year combo (using month and year).
+    pub async fn get_service_stats(&self, params: stats_api::GetServiceStatsParams) -> Result<crate::models::Stats, Error<stats_api::GetServiceStatsError>> {
+        stats_api::get_service_stats(&self.service.configuration, stats_api::GetServiceStatsParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`vcl_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct VclClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "vcl")]
+impl VclClient<'_> {
+    /// Services may have flags set by a Fastly employee or by the purchase of products as addons to the service, which modify the way VCL is interpreted by that service.  This endpoint validates the submitted VCL in the context of the specified service.
+    pub async fn lint_vcl_for_service(&self, params: vcl_api::LintVclForServiceParams) -> Result<crate::models::ValidatorResult, Error<vcl_api::LintVclForServiceError>> {
+        vcl_api::lint_vcl_for_service(&self.service.configuration, vcl_api::LintVclForServiceParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`vcl_diff_api`] endpoints, scoped to a service.
+#[derive(Debug, Clone, Copy)]
+pub struct VclDiffClient<'a> {
+    service: &'a ServiceClient,
+}
+
+#[cfg(feature = "vcl")]
+impl VclDiffClient<'_> {
+    /// Get a comparison of the VCL changes between two service versions.
+    pub async fn vcl_diff_service_versions(&self, params: vcl_diff_api::VclDiffServiceVersionsParams) -> Result<crate::models::VclDiff, Error<vcl_diff_api::VclDiffServiceVersionsError>> {
+        vcl_diff_api::vcl_diff_service_versions(&self.service.configuration, vcl_diff_api::VclDiffServiceVersionsParams { service_id: self.service.service_id.clone(), ..params }).await
+    }
+}
@@ -0,0 +1,2187 @@
+use crate::apis::Error;
+use crate::apis::version_api;
+#[cfg(feature = "acl")]
+use crate::apis::acl_api;
+#[cfg(feature = "domains")]
+use crate::apis::apex_redirect_api;
+#[cfg(feature = "backends")]
+use crate::apis::backend_api;
+#[cfg(feature = "vcl")]
+use crate::apis::cache_settings_api;
+#[cfg(feature = "vcl")]
+use crate::apis::condition_api;
+#[cfg(feature = "dictionary")]
+use crate::apis::dictionary_api;
+#[cfg(feature = "dictionary")]
+use crate::apis::dictionary_info_api;
+#[cfg(feature = "backends")]
+use crate::apis::director_api;
+#[cfg(feature = "backends")]
+use crate::apis::director_backend_api;
+#[cfg(feature = "domains")]
+use crate::apis::domain_api;
+#[cfg(feature = "vcl")]
+use crate::apis::gzip_api;
+#[cfg(feature = "vcl")]
+use crate::apis::header_api;
+#[cfg(feature = "healthcheck")]
+use crate::apis::healthcheck_api;
+#[cfg(feature = "vcl")]
+use crate::apis::http3_api;
+#[cfg(feature = "vcl")]
+use crate::apis::image_optimizer_default_settings_api;
+#[cfg(feature = "waf-legacy")]
+use crate::apis::legacy_waf_firewall_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_azureblob_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_bigquery_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_cloudfiles_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_datadog_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_digitalocean_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_elasticsearch_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_ftp_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_gcs_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_heroku_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_honeycomb_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_https_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_kafka_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_kinesis_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_logentries_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_loggly_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_logshuttle_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_newrelic_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_newrelicotlp_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_openstack_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_papertrail_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_pubsub_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_s3_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_scalyr_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_sftp_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_splunk_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_sumologic_api;
+#[cfg(feature = "logging")]
+use crate::apis::logging_syslog_api;
+#[cfg(feature = "compute")]
+use crate::apis::package_api;
+#[cfg(feature = "backends")]
+use crate::apis::pool_api;
+#[cfg(feature = "vcl")]
+use crate::apis::rate_limiter_api;
+#[cfg(feature = "vcl")]
+use crate::apis::request_settings_api;
+#[cfg(feature = "compute")]
+use crate::apis::resource_api;
+#[cfg(feature = "vcl")]
+use crate::apis::response_object_api;
+#[cfg(feature = "vcl")]
+use crate::apis::settings_api;
+#[cfg(feature = "vcl")]
+use crate::apis::snippet_api;
+#[cfg(feature = "vcl")]
+use crate::apis::vcl_api;
+
+use super::VersionClient;
+
+impl VersionClient {
+    /// Activate the current version.
+    pub async fn activate(&self) -> Result<crate::models::VersionResponse, Error<version_api::ActivateServiceVersionError>> {
+        version_api::activate_service_version(&self.configuration, version_api::ActivateServiceVersionParams { service_id: self.service_id.clone(), version_id: self.version_id }).await
+    }
+
+    /// Clone the current configuration into a new version.
+    pub async fn clone_version(&self) -> Result<crate::models::Version, Error<version_api::CloneServiceVersionError>> {
+        version_api::clone_service_version(&self.configuration, version_api::CloneServiceVersionParams { service_id: self.service_id.clone(), version_id: self.version_id }).await
+    }
+
+    /// Deactivate the current version.
+    pub async fn deactivate(&self) -> Result<crate::models::VersionResponse, Error<version_api::DeactivateServiceVersionError>> {
+        version_api::deactivate_service_version(&self.configuration, version_api::DeactivateServiceVersionParams { service_id: self.service_id.clone(), version_id: self.version_id }).await
+    }
+
+    /// Get the version for a particular service.
+    pub async fn get(&self) -> Result<crate::models::VersionResponse, Error<version_api::GetServiceVersionError>> {
+        version_api::get_service_version(&self.configuration, version_api::GetServiceVersionParams { service_id: self.service_id.clone(), version_id: self.version_id }).await
+    }
+
+    /// Locks the specified version.
+    pub async fn lock(&self) -> Result<crate::models::Version, Error<version_api::LockServiceVersionError>> {
+        version_api::lock_service_version(&self.configuration, version_api::LockServiceVersionParams { service_id: self.service_id.clone(), version_id: self.version_id }).await
+    }
+
+    /// Update a particular version for a particular service.
+    pub async fn update(&self, params: version_api::UpdateServiceVersionParams) -> Result<crate::models::VersionResponse, Error<version_api::UpdateServiceVersionError>> {
+        version_api::update_service_version(&self.configuration, version_api::UpdateServiceVersionParams { service_id: self.service_id.clone(), version_id: self.version_id, ..params }).await
+    }
+
+    /// Validate the version for a particular service and version.
+    pub async fn validate(&self) -> Result<crate::models::InlineResponse200, Error<version_api::ValidateServiceVersionError>> {
+        version_api::validate_service_version(&self.configuration, version_api::ValidateServiceVersionParams { service_id: self.service_id.clone(), version_id: self.version_id }).await
+    }
+
+    #[cfg(feature = "acl")]
+    /// Endpoints of [`acl_api`].
+    pub fn acls(&self) -> AclsClient<'_> {
+        AclsClient { version: self }
+    }
+
+    #[cfg(feature = "domains")]
+    /// Endpoints of [`apex_redirect_api`].
+    pub fn apex_redirects(&self) -> ApexRedirectsClient<'_> {
+        ApexRedirectsClient { version: self }
+    }
+
+    #[cfg(feature = "backends")]
+    /// Endpoints of [`backend_api`].
+    pub fn backends(&self) -> BackendsClient<'_> {
+        BackendsClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`cache_settings_api`].
+    pub fn cache_settings(&self) -> CacheSettingsClient<'_> {
+        CacheSettingsClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`condition_api`].
+    pub fn conditions(&self) -> ConditionsClient<'_> {
+        ConditionsClient { version: self }
+    }
+
+    #[cfg(feature = "dictionary")]
+    /// Endpoints of [`dictionary_api`].
+    pub fn dictionaries(&self) -> DictionariesClient<'_> {
+        DictionariesClient { version: self }
+    }
+
+    #[cfg(feature = "dictionary")]
+    /// Endpoints of [`dictionary_info_api`].
+    pub fn dictionary_info(&self) -> DictionaryInfoClient<'_> {
+        DictionaryInfoClient { version: self }
+    }
+
+    #[cfg(feature = "backends")]
+    /// Endpoints of [`director_api`].
+    pub fn directors(&self) -> DirectorsClient<'_> {
+        DirectorsClient { version: self }
+    }
+
+    #[cfg(feature = "backends")]
+    /// Endpoints of [`director_backend_api`].
+    pub fn director_backends(&self) -> DirectorBackendsClient<'_> {
+        DirectorBackendsClient { version: self }
+    }
+
+    #[cfg(feature = "domains")]
+    /// Endpoints of [`domain_api`].
+    pub fn domains(&self) -> DomainsClient<'_> {
+        DomainsClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`gzip_api`].
+    pub fn gzip(&self) -> GzipClient<'_> {
+        GzipClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`header_api`].
+    pub fn headers(&self) -> HeadersClient<'_> {
+        HeadersClient { version: self }
+    }
+
+    #[cfg(feature = "healthcheck")]
+    /// Endpoints of [`healthcheck_api`].
+    pub fn healthchecks(&self) -> HealthchecksClient<'_> {
+        HealthchecksClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`http3_api`].
+    pub fn http3(&self) -> Http3Client<'_> {
+        Http3Client { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`image_optimizer_default_settings_api`].
+    pub fn image_optimizer_default_settings(&self) -> ImageOptimizerDefaultSettingsClient<'_> {
+        ImageOptimizerDefaultSettingsClient { version: self }
+    }
+
+    #[cfg(feature = "waf-legacy")]
+    /// Endpoints of [`legacy_waf_firewall_api`].
+    pub fn legacy_waf_firewalls(&self) -> LegacyWafFirewallsClient<'_> {
+        LegacyWafFirewallsClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_azureblob_api`].
+    pub fn logging_azureblob(&self) -> LoggingAzureblobClient<'_> {
+        LoggingAzureblobClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_bigquery_api`].
+    pub fn logging_bigquery(&self) -> LoggingBigqueryClient<'_> {
+        LoggingBigqueryClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_cloudfiles_api`].
+    pub fn logging_cloudfiles(&self) -> LoggingCloudfilesClient<'_> {
+        LoggingCloudfilesClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_datadog_api`].
+    pub fn logging_datadog(&self) -> LoggingDatadogClient<'_> {
+        LoggingDatadogClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_digitalocean_api`].
+    pub fn logging_digitalocean(&self) -> LoggingDigitaloceanClient<'_> {
+        LoggingDigitaloceanClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_elasticsearch_api`].
+    pub fn logging_elasticsearch(&self) -> LoggingElasticsearchClient<'_> {
+        LoggingElasticsearchClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_ftp_api`].
+    pub fn logging_ftp(&self) -> LoggingFtpClient<'_> {
+        LoggingFtpClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_gcs_api`].
+    pub fn logging_gcs(&self) -> LoggingGcsClient<'_> {
+        LoggingGcsClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_heroku_api`].
+    pub fn logging_heroku(&self) -> LoggingHerokuClient<'_> {
+        LoggingHerokuClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_honeycomb_api`].
+    pub fn logging_honeycomb(&self) -> LoggingHoneycombClient<'_> {
+        LoggingHoneycombClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_https_api`].
+    pub fn logging_https(&self) -> LoggingHttpsClient<'_> {
+        LoggingHttpsClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_kafka_api`].
+    pub fn logging_kafka(&self) -> LoggingKafkaClient<'_> {
+        LoggingKafkaClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_kinesis_api`].
+    pub fn logging_kinesis(&self) -> LoggingKinesisClient<'_> {
+        LoggingKinesisClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_logentries_api`].
+    pub fn logging_logentries(&self) -> LoggingLogentriesClient<'_> {
+        LoggingLogentriesClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_loggly_api`].
+    pub fn logging_loggly(&self) -> LoggingLogglyClient<'_> {
+        LoggingLogglyClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_logshuttle_api`].
+    pub fn logging_logshuttle(&self) -> LoggingLogshuttleClient<'_> {
+        LoggingLogshuttleClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_newrelic_api`].
+    pub fn logging_newrelic(&self) -> LoggingNewrelicClient<'_> {
+        LoggingNewrelicClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_newrelicotlp_api`].
+    pub fn logging_newrelicotlp(&self) -> LoggingNewrelicotlpClient<'_> {
+        LoggingNewrelicotlpClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_openstack_api`].
+    pub fn logging_openstack(&self) -> LoggingOpenstackClient<'_> {
+        LoggingOpenstackClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_papertrail_api`].
+    pub fn logging_papertrail(&self) -> LoggingPapertrailClient<'_> {
+        LoggingPapertrailClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_pubsub_api`].
+    pub fn logging_pubsub(&self) -> LoggingPubsubClient<'_> {
+        LoggingPubsubClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_s3_api`].
+    pub fn logging_s3(&self) -> LoggingS3Client<'_> {
+        LoggingS3Client { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_scalyr_api`].
+    pub fn logging_scalyr(&self) -> LoggingScalyrClient<'_> {
+        LoggingScalyrClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_sftp_api`].
+    pub fn logging_sftp(&self) -> LoggingSftpClient<'_> {
+        LoggingSftpClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_splunk_api`].
+    pub fn logging_splunk(&self) -> LoggingSplunkClient<'_> {
+        LoggingSplunkClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_sumologic_api`].
+    pub fn logging_sumologic(&self) -> LoggingSumologicClient<'_> {
+        LoggingSumologicClient { version: self }
+    }
+
+    #[cfg(feature = "logging")]
+    /// Endpoints of [`logging_syslog_api`].
+    pub fn logging_syslog(&self) -> LoggingSyslogClient<'_> {
+        LoggingSyslogClient { version: self }
+    }
+
+    #[cfg(feature = "compute")]
+    /// Endpoints of [`package_api`].
+    pub fn package(&self) -> PackageClient<'_> {
+        PackageClient { version: self }
+    }
+
+    #[cfg(feature = "backends")]
+    /// Endpoints of [`pool_api`].
+    pub fn pools(&self) -> PoolsClient<'_> {
+        PoolsClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`rate_limiter_api`].
+    pub fn rate_limiters(&self) -> RateLimitersClient<'_> {
+        RateLimitersClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`request_settings_api`].
+    pub fn request_settings(&self) -> RequestSettingsClient<'_> {
+        RequestSettingsClient { version: self }
+    }
+
+    #[cfg(feature = "compute")]
+    /// Endpoints of [`resource_api`].
+    pub fn resources(&self) -> ResourcesClient<'_> {
+        ResourcesClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`response_object_api`].
+    pub fn response_objects(&self) -> ResponseObjectsClient<'_> {
+        ResponseObjectsClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`settings_api`].
+    pub fn settings(&self) -> SettingsClient<'_> {
+        SettingsClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`snippet_api`].
+    pub fn snippets(&self) -> SnippetsClient<'_> {
+        SnippetsClient { version: self }
+    }
+
+    #[cfg(feature = "vcl")]
+    /// Endpoints of [`vcl_api`].
+    pub fn vcls(&self) -> VclsClient<'_> {
+        VclsClient { version: self }
+    }
+}
+
+#[cfg(feature = "acl")]
+/// [`acl_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct AclsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "acl")]
+impl AclsClient<'_> {
+    /// Create a new ACL attached to the specified service version. A new, empty ACL must be attached to a draft version of a service. The version associated with the ACL must be activated to be used.
+    pub async fn create(&self, params: acl_api::CreateAclParams) -> Result<crate::models::AclResponse, Error<acl_api::CreateAclError>> {
+        acl_api::create_acl(&self.version.configuration, acl_api::CreateAclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete an ACL from the specified service version. To remove an ACL from use, the ACL must be deleted from a draft version and the version without the ACL must be activated.
+    pub async fn delete(&self, params: acl_api::DeleteAclParams) -> Result<crate::models::InlineResponse200, Error<acl_api::DeleteAclError>> {
+        acl_api::delete_acl(&self.version.configuration, acl_api::DeleteAclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Retrieve a single ACL by name for the version and service.
+    pub async fn get(&self, params: acl_api::GetAclParams) -> Result<crate::models::AclResponse, Error<acl_api::GetAclError>> {
+        acl_api::get_acl(&self.version.configuration, acl_api::GetAclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List ACLs.
+    pub async fn list(&self) -> Result<Vec<crate::models::AclResponse>, Error<acl_api::ListAclsError>> {
+        acl_api::list_acls(&self.version.configuration, acl_api::ListAclsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update an ACL for a particular service and version.
+    pub async fn update(&self, params: acl_api::UpdateAclParams) -> Result<crate::models::AclResponse, Error<acl_api::UpdateAclError>> {
+        acl_api::update_acl(&self.version.configuration, acl_api::UpdateAclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "domains")]
+/// [`apex_redirect_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct ApexRedirectsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "domains")]
+impl ApexRedirectsClient<'_> {
+    /// Create an apex redirect for a particular service and version.
+    pub async fn create(&self, params: apex_redirect_api::CreateApexRedirectParams) -> Result<crate::models::ApexRedirect, Error<apex_redirect_api::CreateApexRedirectError>> {
+        apex_redirect_api::create_apex_redirect(&self.version.configuration, apex_redirect_api::CreateApexRedirectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all apex redirects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::ApexRedirect>, Error<apex_redirect_api::ListApexRedirectsError>> {
+        apex_redirect_api::list_apex_redirects(&self.version.configuration, apex_redirect_api::ListApexRedirectsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+}
+
+#[cfg(feature = "backends")]
+/// [`backend_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct BackendsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "backends")]
+impl BackendsClient<'_> {
+    /// Create a backend for a particular service and version.
+    pub async fn create(&self, params: backend_api::CreateBackendParams) -> Result<crate::models::BackendResponse, Error<backend_api::CreateBackendError>> {
+        backend_api::create_backend(&self.version.configuration, backend_api::CreateBackendParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the backend for a particular service and version.
+    pub async fn delete(&self, params: backend_api::DeleteBackendParams) -> Result<crate::models::InlineResponse200, Error<backend_api::DeleteBackendError>> {
+        backend_api::delete_backend(&self.version.configuration, backend_api::DeleteBackendParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the backend for a particular service and version.
+    pub async fn get(&self, params: backend_api::GetBackendParams) -> Result<crate::models::BackendResponse, Error<backend_api::GetBackendError>> {
+        backend_api::get_backend(&self.version.configuration, backend_api::GetBackendParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all backends for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::BackendResponse>, Error<backend_api::ListBackendsError>> {
+        backend_api::list_backends(&self.version.configuration, backend_api::ListBackendsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the backend for a particular service and version.
+    pub async fn update(&self, params: backend_api::UpdateBackendParams) -> Result<crate::models::BackendResponse, Error<backend_api::UpdateBackendError>> {
+        backend_api::update_backend(&self.version.configuration, backend_api::UpdateBackendParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`cache_settings_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct CacheSettingsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl CacheSettingsClient<'_> {
+    /// Create a cache settings object.
+    pub async fn create(&self, params: cache_settings_api::CreateCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<cache_settings_api::CreateCacheSettingsError>> {
+        cache_settings_api::create_cache_settings(&self.version.configuration, cache_settings_api::CreateCacheSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete a specific cache settings object.
+    pub async fn delete(&self, params: cache_settings_api::DeleteCacheSettingsParams) -> Result<crate::models::InlineResponse200, Error<cache_settings_api::DeleteCacheSettingsError>> {
+        cache_settings_api::delete_cache_settings(&self.version.configuration, cache_settings_api::DeleteCacheSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get a specific cache settings object.
+    pub async fn get(&self, params: cache_settings_api::GetCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<cache_settings_api::GetCacheSettingsError>> {
+        cache_settings_api::get_cache_settings(&self.version.configuration, cache_settings_api::GetCacheSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get a list of all cache settings for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::CacheSettingResponse>, Error<cache_settings_api::ListCacheSettingsError>> {
+        cache_settings_api::list_cache_settings(&self.version.configuration, cache_settings_api::ListCacheSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a specific cache settings object.
+    pub async fn update(&self, params: cache_settings_api::UpdateCacheSettingsParams) -> Result<crate::models::CacheSettingResponse, Error<cache_settings_api::UpdateCacheSettingsError>> {
+        cache_settings_api::update_cache_settings(&self.version.configuration, cache_settings_api::UpdateCacheSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`condition_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl ConditionsClient<'_> {
+    /// Creates a new condition.
+    pub async fn create(&self, params: condition_api::CreateConditionParams) -> Result<crate::models::ConditionResponse, Error<condition_api::CreateConditionError>> {
+        condition_api::create_condition(&self.version.configuration, condition_api::CreateConditionParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Deletes the specified condition.
+    pub async fn delete(&self, params: condition_api::DeleteConditionParams) -> Result<crate::models::InlineResponse200, Error<condition_api::DeleteConditionError>> {
+        condition_api::delete_condition(&self.version.configuration, condition_api::DeleteConditionParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Gets the specified condition.
+    pub async fn get(&self, params: condition_api::GetConditionParams) -> Result<crate::models::ConditionResponse, Error<condition_api::GetConditionError>> {
+        condition_api::get_condition(&self.version.configuration, condition_api::GetConditionParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Gets all conditions for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::ConditionResponse>, Error<condition_api::ListConditionsError>> {
+        condition_api::list_conditions(&self.version.configuration, condition_api::ListConditionsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Updates the specified condition.
+    pub async fn update(&self, params: condition_api::UpdateConditionParams) -> Result<crate::models::ConditionResponse, Error<condition_api::UpdateConditionError>> {
+        condition_api::update_condition(&self.version.configuration, condition_api::UpdateConditionParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "dictionary")]
+/// [`dictionary_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct DictionariesClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "dictionary")]
+impl DictionariesClient<'_> {
+    /// Create named dictionary for a particular service and version.
+    pub async fn create(&self, params: dictionary_api::CreateDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<dictionary_api::CreateDictionaryError>> {
+        dictionary_api::create_dictionary(&self.version.configuration, dictionary_api::CreateDictionaryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete named dictionary for a particular service and version.
+    pub async fn delete(&self, params: dictionary_api::DeleteDictionaryParams) -> Result<crate::models::InlineResponse200, Error<dictionary_api::DeleteDictionaryError>> {
+        dictionary_api::delete_dictionary(&self.version.configuration, dictionary_api::DeleteDictionaryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Retrieve a single dictionary by name for the version and service.
+    pub async fn get(&self, params: dictionary_api::GetDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<dictionary_api::GetDictionaryError>> {
+        dictionary_api::get_dictionary(&self.version.configuration, dictionary_api::GetDictionaryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all dictionaries for the version of the service.
+    pub async fn list(&self) -> Result<Vec<crate::models::DictionaryResponse>, Error<dictionary_api::ListDictionariesError>> {
+        dictionary_api::list_dictionaries(&self.version.configuration, dictionary_api::ListDictionariesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update named dictionary for a particular service and version.
+    pub async fn update(&self, params: dictionary_api::UpdateDictionaryParams) -> Result<crate::models::DictionaryResponse, Error<dictionary_api::UpdateDictionaryError>> {
+        dictionary_api::update_dictionary(&self.version.configuration, dictionary_api::UpdateDictionaryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "dictionary")]
+/// [`dictionary_info_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct DictionaryInfoClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "dictionary")]
+impl DictionaryInfoClient<'_> {
+    /// Retrieve metadata for a single dictionary by ID for a version and service.
+    pub async fn get_dictionary_info(&self, params: dictionary_info_api::GetDictionaryInfoParams) -> Result<crate::models::DictionaryInfoResponse, Error<dictionary_info_api::GetDictionaryInfoError>> {
+        dictionary_info_api::get_dictionary_info(&self.version.configuration, dictionary_info_api::GetDictionaryInfoParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "backends")]
+/// [`director_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct DirectorsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "backends")]
+impl DirectorsClient<'_> {
+    /// Delete the director for a particular service and version.
+    pub async fn delete(&self, params: director_api::DeleteDirectorParams) -> Result<crate::models::InlineResponse200, Error<director_api::DeleteDirectorError>> {
+        director_api::delete_director(&self.version.configuration, director_api::DeleteDirectorParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the director for a particular service and version.
+    pub async fn get(&self, params: director_api::GetDirectorParams) -> Result<crate::models::DirectorResponse, Error<director_api::GetDirectorError>> {
+        director_api::get_director(&self.version.configuration, director_api::GetDirectorParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List the directors for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::DirectorResponse>, Error<director_api::ListDirectorsError>> {
+        director_api::list_directors(&self.version.configuration, director_api::ListDirectorsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the director for a particular service and version.
+    pub async fn update(&self, params: director_api::UpdateDirectorParams) -> Result<crate::models::DirectorResponse, Error<director_api::UpdateDirectorError>> {
+        director_api::update_director(&self.version.configuration, director_api::UpdateDirectorParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "backends")]
+/// [`director_backend_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct DirectorBackendsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "backends")]
+impl DirectorBackendsClient<'_> {
+    /// Establishes a relationship between a Backend and a Director. The Backend is then considered a member of the Director and can be used to balance traffic onto.
+    pub async fn create(&self, params: director_backend_api::CreateDirectorBackendParams) -> Result<crate::models::DirectorBackend, Error<director_backend_api::CreateDirectorBackendError>> {
+        director_backend_api::create_director_backend(&self.version.configuration, director_backend_api::CreateDirectorBackendParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Deletes the relationship between a Backend and a Director. The Backend is no longer considered a member of the Director and thus will not have traffic balanced onto it from this Director.
+    pub async fn delete(&self, params: director_backend_api::DeleteDirectorBackendParams) -> Result<crate::models::InlineResponse200, Error<director_backend_api::DeleteDirectorBackendError>> {
+        director_backend_api::delete_director_backend(&self.version.configuration, director_backend_api::DeleteDirectorBackendParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Returns the relationship between a Backend and a Director. If the Backend has been associated with the Director, it returns a simple record indicating this. Otherwise, returns a 404.
+    pub async fn get(&self, params: director_backend_api::GetDirectorBackendParams) -> Result<crate::models::DirectorBackend, Error<director_backend_api::GetDirectorBackendError>> {
+        director_backend_api::get_director_backend(&self.version.configuration, director_backend_api::GetDirectorBackendParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "domains")]
+/// [`domain_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct DomainsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "domains")]
+impl DomainsClient<'_> {
+    /// Checks the status of a specific domain's DNS record for a Service Version. Returns an array in the same format as domain/check_all.
+    pub async fn check(&self, params: domain_api::CheckDomainParams) -> Result<Vec<serde_json::Value>, Error<domain_api::CheckDomainError>> {
+        domain_api::check_domain(&self.version.configuration, domain_api::CheckDomainParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Create a domain for a particular service and version.
+    pub async fn create(&self, params: domain_api::CreateDomainParams) -> Result<crate::models::DomainResponse, Error<domain_api::CreateDomainError>> {
+        domain_api::create_domain(&self.version.configuration, domain_api::CreateDomainParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the domain for a particular service and versions.
+    pub async fn delete(&self, params: domain_api::DeleteDomainParams) -> Result<crate::models::InlineResponse200, Error<domain_api::DeleteDomainError>> {
+        domain_api::delete_domain(&self.version.configuration, domain_api::DeleteDomainParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the domain for a particular service and version.
+    pub async fn get(&self, params: domain_api::GetDomainParams) -> Result<crate::models::DomainResponse, Error<domain_api::GetDomainError>> {
+        domain_api::get_domain(&self.version.configuration, domain_api::GetDomainParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all the domains for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::DomainResponse>, Error<domain_api::ListDomainsError>> {
+        domain_api::list_domains(&self.version.configuration, domain_api::ListDomainsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the domain for a particular service and version.
+    pub async fn update(&self, params: domain_api::UpdateDomainParams) -> Result<crate::models::DomainResponse, Error<domain_api::UpdateDomainError>> {
+        domain_api::update_domain(&self.version.configuration, domain_api::UpdateDomainParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`gzip_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct GzipClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl GzipClient<'_> {
+    /// Create a named gzip configuration on a particular service and version.
+    pub async fn create(&self, params: gzip_api::CreateGzipConfigParams) -> Result<crate::models::GzipResponse, Error<gzip_api::CreateGzipConfigError>> {
+        gzip_api::create_gzip_config(&self.version.configuration, gzip_api::CreateGzipConfigParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete a named gzip configuration on a particular service and version.
+    pub async fn delete(&self, params: gzip_api::DeleteGzipConfigParams) -> Result<crate::models::InlineResponse200, Error<gzip_api::DeleteGzipConfigError>> {
+        gzip_api::delete_gzip_config(&self.version.configuration, gzip_api::DeleteGzipConfigParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the gzip configuration for a particular service, version, and name.
+    pub async fn get(&self, params: gzip_api::GetGzipConfigsParams) -> Result<crate::models::GzipResponse, Error<gzip_api::GetGzipConfigsError>> {
+        gzip_api::get_gzip_configs(&self.version.configuration, gzip_api::GetGzipConfigsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all gzip configurations for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::GzipResponse>, Error<gzip_api::ListGzipConfigsError>> {
+        gzip_api::list_gzip_configs(&self.version.configuration, gzip_api::ListGzipConfigsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a named gzip configuration on a particular service and version.
+    pub async fn update(&self, params: gzip_api::UpdateGzipConfigParams) -> Result<crate::models::GzipResponse, Error<gzip_api::UpdateGzipConfigError>> {
+        gzip_api::update_gzip_config(&self.version.configuration, gzip_api::UpdateGzipConfigParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`header_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct HeadersClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl HeadersClient<'_> {
+    /// Creates a new Header object.
+    pub async fn create(&self, params: header_api::CreateHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<header_api::CreateHeaderObjectError>> {
+        header_api::create_header_object(&self.version.configuration, header_api::CreateHeaderObjectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Deletes a Header object by name.
+    pub async fn delete(&self, params: header_api::DeleteHeaderObjectParams) -> Result<crate::models::InlineResponse200, Error<header_api::DeleteHeaderObjectError>> {
+        header_api::delete_header_object(&self.version.configuration, header_api::DeleteHeaderObjectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Retrieves a Header object by name.
+    pub async fn get(&self, params: header_api::GetHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<header_api::GetHeaderObjectError>> {
+        header_api::get_header_object(&self.version.configuration, header_api::GetHeaderObjectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Retrieves all Header objects for a particular Version of a Service.
+    pub async fn list(&self) -> Result<Vec<crate::models::HeaderResponse>, Error<header_api::ListHeaderObjectsError>> {
+        header_api::list_header_objects(&self.version.configuration, header_api::ListHeaderObjectsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Modifies an existing Header object by name.
+    pub async fn update(&self, params: header_api::UpdateHeaderObjectParams) -> Result<crate::models::HeaderResponse, Error<header_api::UpdateHeaderObjectError>> {
+        header_api::update_header_object(&self.version.configuration, header_api::UpdateHeaderObjectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "healthcheck")]
+/// [`healthcheck_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct HealthchecksClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "healthcheck")]
+impl HealthchecksClient<'_> {
+    /// Create a health check for a particular service and version.
+    pub async fn create(&self, params: healthcheck_api::CreateHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<healthcheck_api::CreateHealthcheckError>> {
+        healthcheck_api::create_healthcheck(&self.version.configuration, healthcheck_api::CreateHealthcheckParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the health check for a particular service and version.
+    pub async fn delete(&self, params: healthcheck_api::DeleteHealthcheckParams) -> Result<crate::models::InlineResponse200, Error<healthcheck_api::DeleteHealthcheckError>> {
+        healthcheck_api::delete_healthcheck(&self.version.configuration, healthcheck_api::DeleteHealthcheckParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the health check for a particular service and version.
+    pub async fn get(&self, params: healthcheck_api::GetHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<healthcheck_api::GetHealthcheckError>> {
+        healthcheck_api::get_healthcheck(&self.version.configuration, healthcheck_api::GetHealthcheckParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the health checks for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::HealthcheckResponse>, Error<healthcheck_api::ListHealthchecksError>> {
+        healthcheck_api::list_healthchecks(&self.version.configuration, healthcheck_api::ListHealthchecksParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the health check for a particular service and version.
+    pub async fn update(&self, params: healthcheck_api::UpdateHealthcheckParams) -> Result<crate::models::HealthcheckResponse, Error<healthcheck_api::UpdateHealthcheckError>> {
+        healthcheck_api::update_healthcheck(&self.version.configuration, healthcheck_api::UpdateHealthcheckParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`http3_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct Http3Client<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl Http3Client<'_> {
+    /// Enable HTTP/3 (QUIC) support for a particular service and version.
+    pub async fn create(&self, params: http3_api::CreateHttp3Params) -> Result<crate::models::Http3, Error<http3_api::CreateHttp3Error>> {
+        http3_api::create_http3(&self.version.configuration, http3_api::CreateHttp3Params { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Disable HTTP/3 (QUIC) support for a particular service and version.
+    pub async fn delete(&self) -> Result<crate::models::InlineResponse200, Error<http3_api::DeleteHttp3Error>> {
+        http3_api::delete_http3(&self.version.configuration, http3_api::DeleteHttp3Params { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Get the status of HTTP/3 (QUIC) support for a particular service and version.
+    pub async fn get(&self) -> Result<crate::models::Http3, Error<http3_api::GetHttp3Error>> {
+        http3_api::get_http3(&self.version.configuration, http3_api::GetHttp3Params { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`image_optimizer_default_settings_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageOptimizerDefaultSettingsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl ImageOptimizerDefaultSettingsClient<'_> {
+    /// Retrieve the current Image Optimizer default settings. All properties in the response will be populated. 
+    pub async fn get(&self) -> Result<crate::models::DefaultSettingsResponse, Error<image_optimizer_default_settings_api::GetDefaultSettingsError>> {
+        image_optimizer_default_settings_api::get_default_settings(&self.version.configuration, image_optimizer_default_settings_api::GetDefaultSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update one or more default settings. A minimum of one property is required. The endpoint will respond with the new Image Optimizer default settings, with all properties populated. 
+    pub async fn update(&self, params: image_optimizer_default_settings_api::UpdateDefaultSettingsParams) -> Result<crate::models::DefaultSettingsResponse, Error<image_optimizer_default_settings_api::UpdateDefaultSettingsError>> {
+        image_optimizer_default_settings_api::update_default_settings(&self.version.configuration, image_optimizer_default_settings_api::UpdateDefaultSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "waf-legacy")]
+/// [`legacy_waf_firewall_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LegacyWafFirewallsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "waf-legacy")]
+impl LegacyWafFirewallsClient<'_> {
+    /// Create a firewall object for a particular service and version.
+    pub async fn create(&self, params: legacy_waf_firewall_api::CreateLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<legacy_waf_firewall_api::CreateLegacyWafFirewallServiceError>> {
+        legacy_waf_firewall_api::create_legacy_waf_firewall_service(&self.version.configuration, legacy_waf_firewall_api::CreateLegacyWafFirewallServiceParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get a specific firewall object.
+    pub async fn get(&self, params: legacy_waf_firewall_api::GetLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<legacy_waf_firewall_api::GetLegacyWafFirewallServiceError>> {
+        legacy_waf_firewall_api::get_legacy_waf_firewall_service(&self.version.configuration, legacy_waf_firewall_api::GetLegacyWafFirewallServiceParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all firewall objects for a particular service and version.
+    pub async fn list(&self, params: legacy_waf_firewall_api::ListLegacyWafFirewallsServiceParams) -> Result<serde_json::Value, Error<legacy_waf_firewall_api::ListLegacyWafFirewallsServiceError>> {
+        legacy_waf_firewall_api::list_legacy_waf_firewalls_service(&self.version.configuration, legacy_waf_firewall_api::ListLegacyWafFirewallsServiceParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Update a firewall object for a particular service and version. 
+    pub async fn update(&self, params: legacy_waf_firewall_api::UpdateLegacyWafFirewallServiceParams) -> Result<serde_json::Value, Error<legacy_waf_firewall_api::UpdateLegacyWafFirewallServiceError>> {
+        legacy_waf_firewall_api::update_legacy_waf_firewall_service(&self.version.configuration, legacy_waf_firewall_api::UpdateLegacyWafFirewallServiceParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_azureblob_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingAzureblobClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingAzureblobClient<'_> {
+    /// Create an Azure Blob Storage logging endpoint for a particular service and version.
+    pub async fn create(&self, params: logging_azureblob_api::CreateLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<logging_azureblob_api::CreateLogAzureError>> {
+        logging_azureblob_api::create_log_azure(&self.version.configuration, logging_azureblob_api::CreateLogAzureParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Azure Blob Storage logging endpoint for a particular service and version.
+    pub async fn delete(&self, params: logging_azureblob_api::DeleteLogAzureParams) -> Result<crate::models::InlineResponse200, Error<logging_azureblob_api::DeleteLogAzureError>> {
+        logging_azureblob_api::delete_log_azure(&self.version.configuration, logging_azureblob_api::DeleteLogAzureParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Azure Blob Storage logging endpoint for a particular service and version.
+    pub async fn get(&self, params: logging_azureblob_api::GetLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<logging_azureblob_api::GetLogAzureError>> {
+        logging_azureblob_api::get_log_azure(&self.version.configuration, logging_azureblob_api::GetLogAzureParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Azure Blob Storage logging endpoints for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingAzureblobResponse>, Error<logging_azureblob_api::ListLogAzureError>> {
+        logging_azureblob_api::list_log_azure(&self.version.configuration, logging_azureblob_api::ListLogAzureParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Azure Blob Storage logging endpoint for a particular service and version.
+    pub async fn update(&self, params: logging_azureblob_api::UpdateLogAzureParams) -> Result<crate::models::LoggingAzureblobResponse, Error<logging_azureblob_api::UpdateLogAzureError>> {
+        logging_azureblob_api::update_log_azure(&self.version.configuration, logging_azureblob_api::UpdateLogAzureParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_bigquery_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingBigqueryClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingBigqueryClient<'_> {
+    /// Create a BigQuery logging object for a particular service and version.
+    pub async fn create(&self, params: logging_bigquery_api::CreateLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<logging_bigquery_api::CreateLogBigqueryError>> {
+        logging_bigquery_api::create_log_bigquery(&self.version.configuration, logging_bigquery_api::CreateLogBigqueryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete a BigQuery logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_bigquery_api::DeleteLogBigqueryParams) -> Result<crate::models::InlineResponse200, Error<logging_bigquery_api::DeleteLogBigqueryError>> {
+        logging_bigquery_api::delete_log_bigquery(&self.version.configuration, logging_bigquery_api::DeleteLogBigqueryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the details for a BigQuery logging object for a particular service and version.
+    pub async fn get(&self, params: logging_bigquery_api::GetLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<logging_bigquery_api::GetLogBigqueryError>> {
+        logging_bigquery_api::get_log_bigquery(&self.version.configuration, logging_bigquery_api::GetLogBigqueryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the BigQuery logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingBigqueryResponse>, Error<logging_bigquery_api::ListLogBigqueryError>> {
+        logging_bigquery_api::list_log_bigquery(&self.version.configuration, logging_bigquery_api::ListLogBigqueryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a BigQuery logging object for a particular service and version.
+    pub async fn update(&self, params: logging_bigquery_api::UpdateLogBigqueryParams) -> Result<crate::models::LoggingBigqueryResponse, Error<logging_bigquery_api::UpdateLogBigqueryError>> {
+        logging_bigquery_api::update_log_bigquery(&self.version.configuration, logging_bigquery_api::UpdateLogBigqueryParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_cloudfiles_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingCloudfilesClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingCloudfilesClient<'_> {
+    /// Create a Cloud Files log endpoint for a particular service and version.
+    pub async fn create(&self, params: logging_cloudfiles_api::CreateLogCloudfilesParams) -> Result<crate::models::LoggingCloudfilesResponse, Error<logging_cloudfiles_api::CreateLogCloudfilesError>> {
+        logging_cloudfiles_api::create_log_cloudfiles(&self.version.configuration, logging_cloudfiles_api::CreateLogCloudfilesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Cloud Files log endpoint for a particular service and version.
+    pub async fn delete(&self, params: logging_cloudfiles_api::DeleteLogCloudfilesParams) -> Result<crate::models::InlineResponse200, Error<logging_cloudfiles_api::DeleteLogCloudfilesError>> {
+        logging_cloudfiles_api::delete_log_cloudfiles(&self.version.configuration, logging_cloudfiles_api::DeleteLogCloudfilesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Cloud Files log endpoint for a particular service and version.
+    pub async fn get(&self, params: logging_cloudfiles_api::GetLogCloudfilesParams) -> Result<crate::models::LoggingCloudfilesResponse, Error<logging_cloudfiles_api::GetLogCloudfilesError>> {
+        logging_cloudfiles_api::get_log_cloudfiles(&self.version.configuration, logging_cloudfiles_api::GetLogCloudfilesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Cloud Files log endpoints for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingCloudfilesResponse>, Error<logging_cloudfiles_api::ListLogCloudfilesError>> {
+        logging_cloudfiles_api::list_log_cloudfiles(&self.version.configuration, logging_cloudfiles_api::ListLogCloudfilesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Cloud Files log endpoint for a particular service and version.
+    pub async fn update(&self, params: logging_cloudfiles_api::UpdateLogCloudfilesParams) -> Result<crate::models::LoggingCloudfilesResponse, Error<logging_cloudfiles_api::UpdateLogCloudfilesError>> {
+        logging_cloudfiles_api::update_log_cloudfiles(&self.version.configuration, logging_cloudfiles_api::UpdateLogCloudfilesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_datadog_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingDatadogClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingDatadogClient<'_> {
+    /// Create a Datadog logging object for a particular service and version.
+    pub async fn create(&self, params: logging_datadog_api::CreateLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<logging_datadog_api::CreateLogDatadogError>> {
+        logging_datadog_api::create_log_datadog(&self.version.configuration, logging_datadog_api::CreateLogDatadogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Datadog logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_datadog_api::DeleteLogDatadogParams) -> Result<crate::models::InlineResponse200, Error<logging_datadog_api::DeleteLogDatadogError>> {
+        logging_datadog_api::delete_log_datadog(&self.version.configuration, logging_datadog_api::DeleteLogDatadogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the details for a Datadog logging object for a particular service and version.
+    pub async fn get(&self, params: logging_datadog_api::GetLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<logging_datadog_api::GetLogDatadogError>> {
+        logging_datadog_api::get_log_datadog(&self.version.configuration, logging_datadog_api::GetLogDatadogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Datadog logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingDatadogResponse>, Error<logging_datadog_api::ListLogDatadogError>> {
+        logging_datadog_api::list_log_datadog(&self.version.configuration, logging_datadog_api::ListLogDatadogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Datadog logging object for a particular service and version.
+    pub async fn update(&self, params: logging_datadog_api::UpdateLogDatadogParams) -> Result<crate::models::LoggingDatadogResponse, Error<logging_datadog_api::UpdateLogDatadogError>> {
+        logging_datadog_api::update_log_datadog(&self.version.configuration, logging_datadog_api::UpdateLogDatadogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_digitalocean_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingDigitaloceanClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingDigitaloceanClient<'_> {
+    /// Create a DigitalOcean Space for a particular service and version.
+    pub async fn create(&self, params: logging_digitalocean_api::CreateLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<logging_digitalocean_api::CreateLogDigoceanError>> {
+        logging_digitalocean_api::create_log_digocean(&self.version.configuration, logging_digitalocean_api::CreateLogDigoceanParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the DigitalOcean Space for a particular service and version.
+    pub async fn delete(&self, params: logging_digitalocean_api::DeleteLogDigoceanParams) -> Result<crate::models::InlineResponse200, Error<logging_digitalocean_api::DeleteLogDigoceanError>> {
+        logging_digitalocean_api::delete_log_digocean(&self.version.configuration, logging_digitalocean_api::DeleteLogDigoceanParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the DigitalOcean Space for a particular service and version.
+    pub async fn get(&self, params: logging_digitalocean_api::GetLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<logging_digitalocean_api::GetLogDigoceanError>> {
+        logging_digitalocean_api::get_log_digocean(&self.version.configuration, logging_digitalocean_api::GetLogDigoceanParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the DigitalOcean Spaces for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingDigitaloceanResponse>, Error<logging_digitalocean_api::ListLogDigoceanError>> {
+        logging_digitalocean_api::list_log_digocean(&self.version.configuration, logging_digitalocean_api::ListLogDigoceanParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the DigitalOcean Space for a particular service and version.
+    pub async fn update(&self, params: logging_digitalocean_api::UpdateLogDigoceanParams) -> Result<crate::models::LoggingDigitaloceanResponse, Error<logging_digitalocean_api::UpdateLogDigoceanError>> {
+        logging_digitalocean_api::update_log_digocean(&self.version.configuration, logging_digitalocean_api::UpdateLogDigoceanParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_elasticsearch_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingElasticsearchClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingElasticsearchClient<'_> {
+    /// Create a Elasticsearch logging endpoint for a particular service and version.
+    pub async fn create(&self, params: logging_elasticsearch_api::CreateLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<logging_elasticsearch_api::CreateLogElasticsearchError>> {
+        logging_elasticsearch_api::create_log_elasticsearch(&self.version.configuration, logging_elasticsearch_api::CreateLogElasticsearchParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Elasticsearch logging endpoint for a particular service and version.
+    pub async fn delete(&self, params: logging_elasticsearch_api::DeleteLogElasticsearchParams) -> Result<crate::models::InlineResponse200, Error<logging_elasticsearch_api::DeleteLogElasticsearchError>> {
+        logging_elasticsearch_api::delete_log_elasticsearch(&self.version.configuration, logging_elasticsearch_api::DeleteLogElasticsearchParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Elasticsearch logging endpoint for a particular service and version.
+    pub async fn get(&self, params: logging_elasticsearch_api::GetLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<logging_elasticsearch_api::GetLogElasticsearchError>> {
+        logging_elasticsearch_api::get_log_elasticsearch(&self.version.configuration, logging_elasticsearch_api::GetLogElasticsearchParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Elasticsearch logging endpoints for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingElasticsearchResponse>, Error<logging_elasticsearch_api::ListLogElasticsearchError>> {
+        logging_elasticsearch_api::list_log_elasticsearch(&self.version.configuration, logging_elasticsearch_api::ListLogElasticsearchParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Elasticsearch logging endpoint for a particular service and version.
+    pub async fn update(&self, params: logging_elasticsearch_api::UpdateLogElasticsearchParams) -> Result<crate::models::LoggingElasticsearchResponse, Error<logging_elasticsearch_api::UpdateLogElasticsearchError>> {
+        logging_elasticsearch_api::update_log_elasticsearch(&self.version.configuration, logging_elasticsearch_api::UpdateLogElasticsearchParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_ftp_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingFtpClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingFtpClient<'_> {
+    /// Create a FTP for a particular service and version.
+    pub async fn create(&self, params: logging_ftp_api::CreateLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<logging_ftp_api::CreateLogFtpError>> {
+        logging_ftp_api::create_log_ftp(&self.version.configuration, logging_ftp_api::CreateLogFtpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the FTP for a particular service and version.
+    pub async fn delete(&self, params: logging_ftp_api::DeleteLogFtpParams) -> Result<crate::models::InlineResponse200, Error<logging_ftp_api::DeleteLogFtpError>> {
+        logging_ftp_api::delete_log_ftp(&self.version.configuration, logging_ftp_api::DeleteLogFtpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the FTP for a particular service and version.
+    pub async fn get(&self, params: logging_ftp_api::GetLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<logging_ftp_api::GetLogFtpError>> {
+        logging_ftp_api::get_log_ftp(&self.version.configuration, logging_ftp_api::GetLogFtpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the FTPs for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingFtpResponse>, Error<logging_ftp_api::ListLogFtpError>> {
+        logging_ftp_api::list_log_ftp(&self.version.configuration, logging_ftp_api::ListLogFtpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the FTP for a particular service and version.
+    pub async fn update(&self, params: logging_ftp_api::UpdateLogFtpParams) -> Result<crate::models::LoggingFtpResponse, Error<logging_ftp_api::UpdateLogFtpError>> {
+        logging_ftp_api::update_log_ftp(&self.version.configuration, logging_ftp_api::UpdateLogFtpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_gcs_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingGcsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingGcsClient<'_> {
+    /// Create GCS logging for a particular service and version.
+    pub async fn create(&self, params: logging_gcs_api::CreateLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<logging_gcs_api::CreateLogGcsError>> {
+        logging_gcs_api::create_log_gcs(&self.version.configuration, logging_gcs_api::CreateLogGcsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the GCS Logging for a particular service and version.
+    pub async fn delete(&self, params: logging_gcs_api::DeleteLogGcsParams) -> Result<crate::models::InlineResponse200, Error<logging_gcs_api::DeleteLogGcsError>> {
+        logging_gcs_api::delete_log_gcs(&self.version.configuration, logging_gcs_api::DeleteLogGcsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the GCS Logging for a particular service and version.
+    pub async fn get(&self, params: logging_gcs_api::GetLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<logging_gcs_api::GetLogGcsError>> {
+        logging_gcs_api::get_log_gcs(&self.version.configuration, logging_gcs_api::GetLogGcsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the GCS log endpoints for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingGcsResponse>, Error<logging_gcs_api::ListLogGcsError>> {
+        logging_gcs_api::list_log_gcs(&self.version.configuration, logging_gcs_api::ListLogGcsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the GCS for a particular service and version.
+    pub async fn update(&self, params: logging_gcs_api::UpdateLogGcsParams) -> Result<crate::models::LoggingGcsResponse, Error<logging_gcs_api::UpdateLogGcsError>> {
+        logging_gcs_api::update_log_gcs(&self.version.configuration, logging_gcs_api::UpdateLogGcsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_heroku_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingHerokuClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingHerokuClient<'_> {
+    /// Create a Heroku for a particular service and version.
+    pub async fn create(&self, params: logging_heroku_api::CreateLogHerokuParams) -> Result<crate::models::LoggingHerokuResponse, Error<logging_heroku_api::CreateLogHerokuError>> {
+        logging_heroku_api::create_log_heroku(&self.version.configuration, logging_heroku_api::CreateLogHerokuParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Heroku for a particular service and version.
+    pub async fn delete(&self, params: logging_heroku_api::DeleteLogHerokuParams) -> Result<crate::models::InlineResponse200, Error<logging_heroku_api::DeleteLogHerokuError>> {
+        logging_heroku_api::delete_log_heroku(&self.version.configuration, logging_heroku_api::DeleteLogHerokuParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Heroku for a particular service and version.
+    pub async fn get(&self, params: logging_heroku_api::GetLogHerokuParams) -> Result<crate::models::LoggingHerokuResponse, Error<logging_heroku_api::GetLogHerokuError>> {
+        logging_heroku_api::get_log_heroku(&self.version.configuration, logging_heroku_api::GetLogHerokuParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Herokus for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingHerokuResponse>, Error<logging_heroku_api::ListLogHerokuError>> {
+        logging_heroku_api::list_log_heroku(&self.version.configuration, logging_heroku_api::ListLogHerokuParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Heroku for a particular service and version.
+    pub async fn update(&self, params: logging_heroku_api::UpdateLogHerokuParams) -> Result<crate::models::LoggingHerokuResponse, Error<logging_heroku_api::UpdateLogHerokuError>> {
+        logging_heroku_api::update_log_heroku(&self.version.configuration, logging_heroku_api::UpdateLogHerokuParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_honeycomb_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingHoneycombClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingHoneycombClient<'_> {
+    /// Create a Honeycomb logging object for a particular service and version.
+    pub async fn create(&self, params: logging_honeycomb_api::CreateLogHoneycombParams) -> Result<crate::models::LoggingHoneycombResponse, Error<logging_honeycomb_api::CreateLogHoneycombError>> {
+        logging_honeycomb_api::create_log_honeycomb(&self.version.configuration, logging_honeycomb_api::CreateLogHoneycombParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Honeycomb logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_honeycomb_api::DeleteLogHoneycombParams) -> Result<crate::models::InlineResponse200, Error<logging_honeycomb_api::DeleteLogHoneycombError>> {
+        logging_honeycomb_api::delete_log_honeycomb(&self.version.configuration, logging_honeycomb_api::DeleteLogHoneycombParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the details of a Honeycomb logging object for a particular service and version.
+    pub async fn get(&self, params: logging_honeycomb_api::GetLogHoneycombParams) -> Result<crate::models::LoggingHoneycombResponse, Error<logging_honeycomb_api::GetLogHoneycombError>> {
+        logging_honeycomb_api::get_log_honeycomb(&self.version.configuration, logging_honeycomb_api::GetLogHoneycombParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Honeycomb logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingHoneycombResponse>, Error<logging_honeycomb_api::ListLogHoneycombError>> {
+        logging_honeycomb_api::list_log_honeycomb(&self.version.configuration, logging_honeycomb_api::ListLogHoneycombParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a Honeycomb logging object for a particular service and version.
+    pub async fn update(&self, params: logging_honeycomb_api::UpdateLogHoneycombParams) -> Result<crate::models::LoggingHoneycombResponse, Error<logging_honeycomb_api::UpdateLogHoneycombError>> {
+        logging_honeycomb_api::update_log_honeycomb(&self.version.configuration, logging_honeycomb_api::UpdateLogHoneycombParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_https_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingHttpsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingHttpsClient<'_> {
+    /// Create an HTTPS object for a particular service and version.
+    pub async fn create(&self, params: logging_https_api::CreateLogHttpsParams) -> Result<crate::models::LoggingHttpsResponse, Error<logging_https_api::CreateLogHttpsError>> {
+        logging_https_api::create_log_https(&self.version.configuration, logging_https_api::CreateLogHttpsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the HTTPS object for a particular service and version.
+    pub async fn delete(&self, params: logging_https_api::DeleteLogHttpsParams) -> Result<crate::models::InlineResponse200, Error<logging_https_api::DeleteLogHttpsError>> {
+        logging_https_api::delete_log_https(&self.version.configuration, logging_https_api::DeleteLogHttpsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the HTTPS object for a particular service and version.
+    pub async fn get(&self, params: logging_https_api::GetLogHttpsParams) -> Result<crate::models::LoggingHttpsResponse, Error<logging_https_api::GetLogHttpsError>> {
+        logging_https_api::get_log_https(&self.version.configuration, logging_https_api::GetLogHttpsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the HTTPS objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingHttpsResponse>, Error<logging_https_api::ListLogHttpsError>> {
+        logging_https_api::list_log_https(&self.version.configuration, logging_https_api::ListLogHttpsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the HTTPS object for a particular service and version.
+    pub async fn update(&self, params: logging_https_api::UpdateLogHttpsParams) -> Result<crate::models::LoggingHttpsResponse, Error<logging_https_api::UpdateLogHttpsError>> {
+        logging_https_api::update_log_https(&self.version.configuration, logging_https_api::UpdateLogHttpsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_kafka_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingKafkaClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingKafkaClient<'_> {
+    /// Create a Kafka logging endpoint for a particular service and version.
+    pub async fn create(&self, params: logging_kafka_api::CreateLogKafkaParams) -> Result<crate::models::LoggingKafkaResponsePost, Error<logging_kafka_api::CreateLogKafkaError>> {
+        logging_kafka_api::create_log_kafka(&self.version.configuration, logging_kafka_api::CreateLogKafkaParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Kafka logging endpoint for a particular service and version.
+    pub async fn delete(&self, params: logging_kafka_api::DeleteLogKafkaParams) -> Result<crate::models::InlineResponse200, Error<logging_kafka_api::DeleteLogKafkaError>> {
+        logging_kafka_api::delete_log_kafka(&self.version.configuration, logging_kafka_api::DeleteLogKafkaParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Kafka logging endpoint for a particular service and version.
+    pub async fn get(&self, params: logging_kafka_api::GetLogKafkaParams) -> Result<crate::models::LoggingKafkaResponse, Error<logging_kafka_api::GetLogKafkaError>> {
+        logging_kafka_api::get_log_kafka(&self.version.configuration, logging_kafka_api::GetLogKafkaParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Kafka logging endpoints for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingKafkaResponse>, Error<logging_kafka_api::ListLogKafkaError>> {
+        logging_kafka_api::list_log_kafka(&self.version.configuration, logging_kafka_api::ListLogKafkaParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Kafka logging endpoint for a particular service and version.
+    pub async fn update(&self, params: logging_kafka_api::UpdateLogKafkaParams) -> Result<crate::models::LoggingKafkaResponse, Error<logging_kafka_api::UpdateLogKafkaError>> {
+        logging_kafka_api::update_log_kafka(&self.version.configuration, logging_kafka_api::UpdateLogKafkaParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_kinesis_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingKinesisClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingKinesisClient<'_> {
+    /// Create an Amazon Kinesis Data Streams logging object for a particular service and version.
+    pub async fn create(&self, params: logging_kinesis_api::CreateLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<logging_kinesis_api::CreateLogKinesisError>> {
+        logging_kinesis_api::create_log_kinesis(&self.version.configuration, logging_kinesis_api::CreateLogKinesisParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete an Amazon Kinesis Data Streams logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_kinesis_api::DeleteLogKinesisParams) -> Result<crate::models::InlineResponse200, Error<logging_kinesis_api::DeleteLogKinesisError>> {
+        logging_kinesis_api::delete_log_kinesis(&self.version.configuration, logging_kinesis_api::DeleteLogKinesisParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the details for an Amazon Kinesis Data Streams logging object for a particular service and version.
+    pub async fn get(&self, params: logging_kinesis_api::GetLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<logging_kinesis_api::GetLogKinesisError>> {
+        logging_kinesis_api::get_log_kinesis(&self.version.configuration, logging_kinesis_api::GetLogKinesisParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Amazon Kinesis Data Streams logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingKinesisResponse>, Error<logging_kinesis_api::ListLogKinesisError>> {
+        logging_kinesis_api::list_log_kinesis(&self.version.configuration, logging_kinesis_api::ListLogKinesisParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update an Amazon Kinesis Data Streams logging object for a particular service and version.
+    pub async fn update(&self, params: logging_kinesis_api::UpdateLogKinesisParams) -> Result<crate::models::LoggingKinesisResponse, Error<logging_kinesis_api::UpdateLogKinesisError>> {
+        logging_kinesis_api::update_log_kinesis(&self.version.configuration, logging_kinesis_api::UpdateLogKinesisParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_logentries_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingLogentriesClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingLogentriesClient<'_> {
+    /// Create a Logentry for a particular service and version.
+    pub async fn create(&self, params: logging_logentries_api::CreateLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<logging_logentries_api::CreateLogLogentriesError>> {
+        logging_logentries_api::create_log_logentries(&self.version.configuration, logging_logentries_api::CreateLogLogentriesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Logentry for a particular service and version.
+    pub async fn delete(&self, params: logging_logentries_api::DeleteLogLogentriesParams) -> Result<crate::models::InlineResponse200, Error<logging_logentries_api::DeleteLogLogentriesError>> {
+        logging_logentries_api::delete_log_logentries(&self.version.configuration, logging_logentries_api::DeleteLogLogentriesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Logentry for a particular service and version.
+    pub async fn get(&self, params: logging_logentries_api::GetLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<logging_logentries_api::GetLogLogentriesError>> {
+        logging_logentries_api::get_log_logentries(&self.version.configuration, logging_logentries_api::GetLogLogentriesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Logentries for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingLogentriesResponse>, Error<logging_logentries_api::ListLogLogentriesError>> {
+        logging_logentries_api::list_log_logentries(&self.version.configuration, logging_logentries_api::ListLogLogentriesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Logentry for a particular service and version.
+    pub async fn update(&self, params: logging_logentries_api::UpdateLogLogentriesParams) -> Result<crate::models::LoggingLogentriesResponse, Error<logging_logentries_api::UpdateLogLogentriesError>> {
+        logging_logentries_api::update_log_logentries(&self.version.configuration, logging_logentries_api::UpdateLogLogentriesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_loggly_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingLogglyClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingLogglyClient<'_> {
+    /// Create a Loggly logging object for a particular service and version.
+    pub async fn create(&self, params: logging_loggly_api::CreateLogLogglyParams) -> Result<crate::models::LoggingLogglyResponse, Error<logging_loggly_api::CreateLogLogglyError>> {
+        logging_loggly_api::create_log_loggly(&self.version.configuration, logging_loggly_api::CreateLogLogglyParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Loggly logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_loggly_api::DeleteLogLogglyParams) -> Result<crate::models::InlineResponse200, Error<logging_loggly_api::DeleteLogLogglyError>> {
+        logging_loggly_api::delete_log_loggly(&self.version.configuration, logging_loggly_api::DeleteLogLogglyParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Loggly logging object for a particular service and version.
+    pub async fn get(&self, params: logging_loggly_api::GetLogLogglyParams) -> Result<crate::models::LoggingLogglyResponse, Error<logging_loggly_api::GetLogLogglyError>> {
+        logging_loggly_api::get_log_loggly(&self.version.configuration, logging_loggly_api::GetLogLogglyParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all Loggly logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingLogglyResponse>, Error<logging_loggly_api::ListLogLogglyError>> {
+        logging_loggly_api::list_log_loggly(&self.version.configuration, logging_loggly_api::ListLogLogglyParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Loggly logging object for a particular service and version.
+    pub async fn update(&self, params: logging_loggly_api::UpdateLogLogglyParams) -> Result<crate::models::LoggingLogglyResponse, Error<logging_loggly_api::UpdateLogLogglyError>> {
+        logging_loggly_api::update_log_loggly(&self.version.configuration, logging_loggly_api::UpdateLogLogglyParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_logshuttle_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingLogshuttleClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingLogshuttleClient<'_> {
+    /// Create a Log Shuttle logging endpoint for a particular service and version.
+    pub async fn create(&self, params: logging_logshuttle_api::CreateLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<logging_logshuttle_api::CreateLogLogshuttleError>> {
+        logging_logshuttle_api::create_log_logshuttle(&self.version.configuration, logging_logshuttle_api::CreateLogLogshuttleParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Log Shuttle logging endpoint for a particular service and version.
+    pub async fn delete(&self, params: logging_logshuttle_api::DeleteLogLogshuttleParams) -> Result<crate::models::InlineResponse200, Error<logging_logshuttle_api::DeleteLogLogshuttleError>> {
+        logging_logshuttle_api::delete_log_logshuttle(&self.version.configuration, logging_logshuttle_api::DeleteLogLogshuttleParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Log Shuttle logging endpoint for a particular service and version.
+    pub async fn get(&self, params: logging_logshuttle_api::GetLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<logging_logshuttle_api::GetLogLogshuttleError>> {
+        logging_logshuttle_api::get_log_logshuttle(&self.version.configuration, logging_logshuttle_api::GetLogLogshuttleParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Log Shuttle logging endpoints for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingLogshuttleResponse>, Error<logging_logshuttle_api::ListLogLogshuttleError>> {
+        logging_logshuttle_api::list_log_logshuttle(&self.version.configuration, logging_logshuttle_api::ListLogLogshuttleParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Log Shuttle logging endpoint for a particular service and version.
+    pub async fn update(&self, params: logging_logshuttle_api::UpdateLogLogshuttleParams) -> Result<crate::models::LoggingLogshuttleResponse, Error<logging_logshuttle_api::UpdateLogLogshuttleError>> {
+        logging_logshuttle_api::update_log_logshuttle(&self.version.configuration, logging_logshuttle_api::UpdateLogLogshuttleParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_newrelic_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingNewrelicClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingNewrelicClient<'_> {
+    /// Create a New Relic Logs logging object for a particular service and version.
+    pub async fn create(&self, params: logging_newrelic_api::CreateLogNewrelicParams) -> Result<crate::models::LoggingNewrelicResponse, Error<logging_newrelic_api::CreateLogNewrelicError>> {
+        logging_newrelic_api::create_log_newrelic(&self.version.configuration, logging_newrelic_api::CreateLogNewrelicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the New Relic Logs logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_newrelic_api::DeleteLogNewrelicParams) -> Result<crate::models::InlineResponse200, Error<logging_newrelic_api::DeleteLogNewrelicError>> {
+        logging_newrelic_api::delete_log_newrelic(&self.version.configuration, logging_newrelic_api::DeleteLogNewrelicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the details of a New Relic Logs logging object for a particular service and version.
+    pub async fn get(&self, params: logging_newrelic_api::GetLogNewrelicParams) -> Result<crate::models::LoggingNewrelicResponse, Error<logging_newrelic_api::GetLogNewrelicError>> {
+        logging_newrelic_api::get_log_newrelic(&self.version.configuration, logging_newrelic_api::GetLogNewrelicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the New Relic Logs logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingNewrelicResponse>, Error<logging_newrelic_api::ListLogNewrelicError>> {
+        logging_newrelic_api::list_log_newrelic(&self.version.configuration, logging_newrelic_api::ListLogNewrelicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a New Relic Logs logging object for a particular service and version.
+    pub async fn update(&self, params: logging_newrelic_api::UpdateLogNewrelicParams) -> Result<crate::models::LoggingNewrelicResponse, Error<logging_newrelic_api::UpdateLogNewrelicError>> {
+        logging_newrelic_api::update_log_newrelic(&self.version.configuration, logging_newrelic_api::UpdateLogNewrelicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_newrelicotlp_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingNewrelicotlpClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingNewrelicotlpClient<'_> {
+    /// Create a New Relic OTLP logging object for a particular service and version.
+    pub async fn create(&self, params: logging_newrelicotlp_api::CreateLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<logging_newrelicotlp_api::CreateLogNewrelicotlpError>> {
+        logging_newrelicotlp_api::create_log_newrelicotlp(&self.version.configuration, logging_newrelicotlp_api::CreateLogNewrelicotlpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the New Relic OTLP logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_newrelicotlp_api::DeleteLogNewrelicotlpParams) -> Result<crate::models::InlineResponse200, Error<logging_newrelicotlp_api::DeleteLogNewrelicotlpError>> {
+        logging_newrelicotlp_api::delete_log_newrelicotlp(&self.version.configuration, logging_newrelicotlp_api::DeleteLogNewrelicotlpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the details of a New Relic OTLP logging object for a particular service and version.
+    pub async fn get(&self, params: logging_newrelicotlp_api::GetLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<logging_newrelicotlp_api::GetLogNewrelicotlpError>> {
+        logging_newrelicotlp_api::get_log_newrelicotlp(&self.version.configuration, logging_newrelicotlp_api::GetLogNewrelicotlpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the New Relic OTLP logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingNewrelicotlpResponse>, Error<logging_newrelicotlp_api::ListLogNewrelicotlpError>> {
+        logging_newrelicotlp_api::list_log_newrelicotlp(&self.version.configuration, logging_newrelicotlp_api::ListLogNewrelicotlpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a New Relic OTLP logging object for a particular service and version.
+    pub async fn update(&self, params: logging_newrelicotlp_api::UpdateLogNewrelicotlpParams) -> Result<crate::models::LoggingNewrelicotlpResponse, Error<logging_newrelicotlp_api::UpdateLogNewrelicotlpError>> {
+        logging_newrelicotlp_api::update_log_newrelicotlp(&self.version.configuration, logging_newrelicotlp_api::UpdateLogNewrelicotlpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_openstack_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingOpenstackClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingOpenstackClient<'_> {
+    /// Create a openstack for a particular service and version.
+    pub async fn create(&self, params: logging_openstack_api::CreateLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<logging_openstack_api::CreateLogOpenstackError>> {
+        logging_openstack_api::create_log_openstack(&self.version.configuration, logging_openstack_api::CreateLogOpenstackParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the openstack for a particular service and version.
+    pub async fn delete(&self, params: logging_openstack_api::DeleteLogOpenstackParams) -> Result<crate::models::InlineResponse200, Error<logging_openstack_api::DeleteLogOpenstackError>> {
+        logging_openstack_api::delete_log_openstack(&self.version.configuration, logging_openstack_api::DeleteLogOpenstackParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the openstack for a particular service and version.
+    pub async fn get(&self, params: logging_openstack_api::GetLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<logging_openstack_api::GetLogOpenstackError>> {
+        logging_openstack_api::get_log_openstack(&self.version.configuration, logging_openstack_api::GetLogOpenstackParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the openstacks for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingOpenstackResponse>, Error<logging_openstack_api::ListLogOpenstackError>> {
+        logging_openstack_api::list_log_openstack(&self.version.configuration, logging_openstack_api::ListLogOpenstackParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the openstack for a particular service and version.
+    pub async fn update(&self, params: logging_openstack_api::UpdateLogOpenstackParams) -> Result<crate::models::LoggingOpenstackResponse, Error<logging_openstack_api::UpdateLogOpenstackError>> {
+        logging_openstack_api::update_log_openstack(&self.version.configuration, logging_openstack_api::UpdateLogOpenstackParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_papertrail_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingPapertrailClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingPapertrailClient<'_> {
+    /// Create a Papertrail for a particular service and version.
+    pub async fn create(&self, params: logging_papertrail_api::CreateLogPapertrailParams) -> Result<crate::models::LoggingPapertrailResponse, Error<logging_papertrail_api::CreateLogPapertrailError>> {
+        logging_papertrail_api::create_log_papertrail(&self.version.configuration, logging_papertrail_api::CreateLogPapertrailParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Papertrail for a particular service and version.
+    pub async fn delete(&self, params: logging_papertrail_api::DeleteLogPapertrailParams) -> Result<crate::models::InlineResponse200, Error<logging_papertrail_api::DeleteLogPapertrailError>> {
+        logging_papertrail_api::delete_log_papertrail(&self.version.configuration, logging_papertrail_api::DeleteLogPapertrailParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Papertrail for a particular service and version.
+    pub async fn get(&self, params: logging_papertrail_api::GetLogPapertrailParams) -> Result<crate::models::LoggingPapertrailResponse, Error<logging_papertrail_api::GetLogPapertrailError>> {
+        logging_papertrail_api::get_log_papertrail(&self.version.configuration, logging_papertrail_api::GetLogPapertrailParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Papertrails for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingPapertrailResponse>, Error<logging_papertrail_api::ListLogPapertrailError>> {
+        logging_papertrail_api::list_log_papertrail(&self.version.configuration, logging_papertrail_api::ListLogPapertrailParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Papertrail for a particular service and version.
+    pub async fn update(&self, params: logging_papertrail_api::UpdateLogPapertrailParams) -> Result<crate::models::LoggingPapertrailResponse, Error<logging_papertrail_api::UpdateLogPapertrailError>> {
+        logging_papertrail_api::update_log_papertrail(&self.version.configuration, logging_papertrail_api::UpdateLogPapertrailParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_pubsub_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingPubsubClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingPubsubClient<'_> {
+    /// Create a Pub/Sub logging object for a particular service and version.
+    pub async fn create(&self, params: logging_pubsub_api::CreateLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<logging_pubsub_api::CreateLogGcpPubsubError>> {
+        logging_pubsub_api::create_log_gcp_pubsub(&self.version.configuration, logging_pubsub_api::CreateLogGcpPubsubParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete a Pub/Sub logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_pubsub_api::DeleteLogGcpPubsubParams) -> Result<crate::models::InlineResponse200, Error<logging_pubsub_api::DeleteLogGcpPubsubError>> {
+        logging_pubsub_api::delete_log_gcp_pubsub(&self.version.configuration, logging_pubsub_api::DeleteLogGcpPubsubParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the details for a Pub/Sub logging object for a particular service and version.
+    pub async fn get(&self, params: logging_pubsub_api::GetLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<logging_pubsub_api::GetLogGcpPubsubError>> {
+        logging_pubsub_api::get_log_gcp_pubsub(&self.version.configuration, logging_pubsub_api::GetLogGcpPubsubParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Pub/Sub logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingGooglePubsubResponse>, Error<logging_pubsub_api::ListLogGcpPubsubError>> {
+        logging_pubsub_api::list_log_gcp_pubsub(&self.version.configuration, logging_pubsub_api::ListLogGcpPubsubParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a Pub/Sub logging object for a particular service and version.
+    pub async fn update(&self, params: logging_pubsub_api::UpdateLogGcpPubsubParams) -> Result<crate::models::LoggingGooglePubsubResponse, Error<logging_pubsub_api::UpdateLogGcpPubsubError>> {
+        logging_pubsub_api::update_log_gcp_pubsub(&self.version.configuration, logging_pubsub_api::UpdateLogGcpPubsubParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_s3_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingS3Client<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingS3Client<'_> {
+    /// Create a S3 for a particular service and version.
+    pub async fn create(&self, params: logging_s3_api::CreateLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<logging_s3_api::CreateLogAwsS3Error>> {
+        logging_s3_api::create_log_aws_s3(&self.version.configuration, logging_s3_api::CreateLogAwsS3Params { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the S3 for a particular service and version.
+    pub async fn delete(&self, params: logging_s3_api::DeleteLogAwsS3Params) -> Result<crate::models::InlineResponse200, Error<logging_s3_api::DeleteLogAwsS3Error>> {
+        logging_s3_api::delete_log_aws_s3(&self.version.configuration, logging_s3_api::DeleteLogAwsS3Params { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the S3 for a particular service and version.
+    pub async fn get(&self, params: logging_s3_api::GetLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<logging_s3_api::GetLogAwsS3Error>> {
+        logging_s3_api::get_log_aws_s3(&self.version.configuration, logging_s3_api::GetLogAwsS3Params { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the S3s for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingS3Response>, Error<logging_s3_api::ListLogAwsS3Error>> {
+        logging_s3_api::list_log_aws_s3(&self.version.configuration, logging_s3_api::ListLogAwsS3Params { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the S3 for a particular service and version.
+    pub async fn update(&self, params: logging_s3_api::UpdateLogAwsS3Params) -> Result<crate::models::LoggingS3Response, Error<logging_s3_api::UpdateLogAwsS3Error>> {
+        logging_s3_api::update_log_aws_s3(&self.version.configuration, logging_s3_api::UpdateLogAwsS3Params { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_scalyr_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingScalyrClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingScalyrClient<'_> {
+    /// Create a Scalyr for a particular service and version.
+    pub async fn create(&self, params: logging_scalyr_api::CreateLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<logging_scalyr_api::CreateLogScalyrError>> {
+        logging_scalyr_api::create_log_scalyr(&self.version.configuration, logging_scalyr_api::CreateLogScalyrParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Scalyr for a particular service and version.
+    pub async fn delete(&self, params: logging_scalyr_api::DeleteLogScalyrParams) -> Result<crate::models::InlineResponse200, Error<logging_scalyr_api::DeleteLogScalyrError>> {
+        logging_scalyr_api::delete_log_scalyr(&self.version.configuration, logging_scalyr_api::DeleteLogScalyrParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Scalyr for a particular service and version.
+    pub async fn get(&self, params: logging_scalyr_api::GetLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<logging_scalyr_api::GetLogScalyrError>> {
+        logging_scalyr_api::get_log_scalyr(&self.version.configuration, logging_scalyr_api::GetLogScalyrParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Scalyrs for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingScalyrResponse>, Error<logging_scalyr_api::ListLogScalyrError>> {
+        logging_scalyr_api::list_log_scalyr(&self.version.configuration, logging_scalyr_api::ListLogScalyrParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Scalyr for a particular service and version.
+    pub async fn update(&self, params: logging_scalyr_api::UpdateLogScalyrParams) -> Result<crate::models::LoggingScalyrResponse, Error<logging_scalyr_api::UpdateLogScalyrError>> {
+        logging_scalyr_api::update_log_scalyr(&self.version.configuration, logging_scalyr_api::UpdateLogScalyrParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_sftp_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingSftpClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingSftpClient<'_> {
+    /// Create a SFTP for a particular service and version.
+    pub async fn create(&self, params: logging_sftp_api::CreateLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<logging_sftp_api::CreateLogSftpError>> {
+        logging_sftp_api::create_log_sftp(&self.version.configuration, logging_sftp_api::CreateLogSftpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the SFTP for a particular service and version.
+    pub async fn delete(&self, params: logging_sftp_api::DeleteLogSftpParams) -> Result<crate::models::InlineResponse200, Error<logging_sftp_api::DeleteLogSftpError>> {
+        logging_sftp_api::delete_log_sftp(&self.version.configuration, logging_sftp_api::DeleteLogSftpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the SFTP for a particular service and version.
+    pub async fn get(&self, params: logging_sftp_api::GetLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<logging_sftp_api::GetLogSftpError>> {
+        logging_sftp_api::get_log_sftp(&self.version.configuration, logging_sftp_api::GetLogSftpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the SFTPs for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingSftpResponse>, Error<logging_sftp_api::ListLogSftpError>> {
+        logging_sftp_api::list_log_sftp(&self.version.configuration, logging_sftp_api::ListLogSftpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the SFTP for a particular service and version.
+    pub async fn update(&self, params: logging_sftp_api::UpdateLogSftpParams) -> Result<crate::models::LoggingSftpResponse, Error<logging_sftp_api::UpdateLogSftpError>> {
+        logging_sftp_api::update_log_sftp(&self.version.configuration, logging_sftp_api::UpdateLogSftpParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_splunk_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingSplunkClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingSplunkClient<'_> {
+    /// Create a Splunk logging object for a particular service and version.
+    pub async fn create(&self, params: logging_splunk_api::CreateLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<logging_splunk_api::CreateLogSplunkError>> {
+        logging_splunk_api::create_log_splunk(&self.version.configuration, logging_splunk_api::CreateLogSplunkParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Splunk logging object for a particular service and version.
+    pub async fn delete(&self, params: logging_splunk_api::DeleteLogSplunkParams) -> Result<crate::models::InlineResponse200, Error<logging_splunk_api::DeleteLogSplunkError>> {
+        logging_splunk_api::delete_log_splunk(&self.version.configuration, logging_splunk_api::DeleteLogSplunkParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the details for a Splunk logging object for a particular service and version.
+    pub async fn get(&self, params: logging_splunk_api::GetLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<logging_splunk_api::GetLogSplunkError>> {
+        logging_splunk_api::get_log_splunk(&self.version.configuration, logging_splunk_api::GetLogSplunkParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Splunk logging objects for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingSplunkResponse>, Error<logging_splunk_api::ListLogSplunkError>> {
+        logging_splunk_api::list_log_splunk(&self.version.configuration, logging_splunk_api::ListLogSplunkParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Splunk logging object for a particular service and version.
+    pub async fn update(&self, params: logging_splunk_api::UpdateLogSplunkParams) -> Result<crate::models::LoggingSplunkResponse, Error<logging_splunk_api::UpdateLogSplunkError>> {
+        logging_splunk_api::update_log_splunk(&self.version.configuration, logging_splunk_api::UpdateLogSplunkParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_sumologic_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingSumologicClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingSumologicClient<'_> {
+    /// Create a Sumologic for a particular service and version.
+    pub async fn create(&self, params: logging_sumologic_api::CreateLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<logging_sumologic_api::CreateLogSumologicError>> {
+        logging_sumologic_api::create_log_sumologic(&self.version.configuration, logging_sumologic_api::CreateLogSumologicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Sumologic for a particular service and version.
+    pub async fn delete(&self, params: logging_sumologic_api::DeleteLogSumologicParams) -> Result<crate::models::InlineResponse200, Error<logging_sumologic_api::DeleteLogSumologicError>> {
+        logging_sumologic_api::delete_log_sumologic(&self.version.configuration, logging_sumologic_api::DeleteLogSumologicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Sumologic for a particular service and version.
+    pub async fn get(&self, params: logging_sumologic_api::GetLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<logging_sumologic_api::GetLogSumologicError>> {
+        logging_sumologic_api::get_log_sumologic(&self.version.configuration, logging_sumologic_api::GetLogSumologicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Sumologics for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingSumologicResponse>, Error<logging_sumologic_api::ListLogSumologicError>> {
+        logging_sumologic_api::list_log_sumologic(&self.version.configuration, logging_sumologic_api::ListLogSumologicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Sumologic for a particular service and version.
+    pub async fn update(&self, params: logging_sumologic_api::UpdateLogSumologicParams) -> Result<crate::models::LoggingSumologicResponse, Error<logging_sumologic_api::UpdateLogSumologicError>> {
+        logging_sumologic_api::update_log_sumologic(&self.version.configuration, logging_sumologic_api::UpdateLogSumologicParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "logging")]
+/// [`logging_syslog_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingSyslogClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "logging")]
+impl LoggingSyslogClient<'_> {
+    /// Create a Syslog for a particular service and version.
+    pub async fn create(&self, params: logging_syslog_api::CreateLogSyslogParams) -> Result<crate::models::LoggingSyslogResponse, Error<logging_syslog_api::CreateLogSyslogError>> {
+        logging_syslog_api::create_log_syslog(&self.version.configuration, logging_syslog_api::CreateLogSyslogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the Syslog for a particular service and version.
+    pub async fn delete(&self, params: logging_syslog_api::DeleteLogSyslogParams) -> Result<crate::models::InlineResponse200, Error<logging_syslog_api::DeleteLogSyslogError>> {
+        logging_syslog_api::delete_log_syslog(&self.version.configuration, logging_syslog_api::DeleteLogSyslogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the Syslog for a particular service and version.
+    pub async fn get(&self, params: logging_syslog_api::GetLogSyslogParams) -> Result<crate::models::LoggingSyslogResponse, Error<logging_syslog_api::GetLogSyslogError>> {
+        logging_syslog_api::get_log_syslog(&self.version.configuration, logging_syslog_api::GetLogSyslogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all of the Syslogs for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::LoggingSyslogResponse>, Error<logging_syslog_api::ListLogSyslogError>> {
+        logging_syslog_api::list_log_syslog(&self.version.configuration, logging_syslog_api::ListLogSyslogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the Syslog for a particular service and version.
+    pub async fn update(&self, params: logging_syslog_api::UpdateLogSyslogParams) -> Result<crate::models::LoggingSyslogResponse, Error<logging_syslog_api::UpdateLogSyslogError>> {
+        logging_syslog_api::update_log_syslog(&self.version.configuration, logging_syslog_api::UpdateLogSyslogParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "compute")]
+/// [`package_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct PackageClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "compute")]
+impl PackageClient<'_> {
+    /// List detailed information about the Compute package for the specified service.
+    pub async fn get(&self) -> Result<crate::models::PackageResponse, Error<package_api::GetPackageError>> {
+        package_api::get_package(&self.version.configuration, package_api::GetPackageParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Upload a Compute package associated with the specified service version.
+    pub async fn put(&self, params: package_api::PutPackageParams) -> Result<crate::models::PackageResponse, Error<package_api::PutPackageError>> {
+        package_api::put_package(&self.version.configuration, package_api::PutPackageParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "backends")]
+/// [`pool_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct PoolsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "backends")]
+impl PoolsClient<'_> {
+    /// Creates a pool for a particular service and version.
+    pub async fn create(&self, params: pool_api::CreateServerPoolParams) -> Result<crate::models::PoolResponsePost, Error<pool_api::CreateServerPoolError>> {
+        pool_api::create_server_pool(&self.version.configuration, pool_api::CreateServerPoolParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Deletes a specific pool for a particular service and version.
+    pub async fn delete(&self, params: pool_api::DeleteServerPoolParams) -> Result<crate::models::InlineResponse200, Error<pool_api::DeleteServerPoolError>> {
+        pool_api::delete_server_pool(&self.version.configuration, pool_api::DeleteServerPoolParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Gets a single pool for a particular service and version.
+    pub async fn get(&self, params: pool_api::GetServerPoolParams) -> Result<crate::models::PoolResponse, Error<pool_api::GetServerPoolError>> {
+        pool_api::get_server_pool(&self.version.configuration, pool_api::GetServerPoolParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Lists all pools for a particular service and pool.
+    pub async fn list(&self) -> Result<Vec<crate::models::PoolResponse>, Error<pool_api::ListServerPoolsError>> {
+        pool_api::list_server_pools(&self.version.configuration, pool_api::ListServerPoolsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Updates a specific pool for a particular service and version.
+    pub async fn update(&self, params: pool_api::UpdateServerPoolParams) -> Result<crate::models::PoolResponse, Error<pool_api::UpdateServerPoolError>> {
+        pool_api::update_server_pool(&self.version.configuration, pool_api::UpdateServerPoolParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`rate_limiter_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitersClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl RateLimitersClient<'_> {
+    /// Create a rate limiter for a particular service and version.
+    pub async fn create(&self, params: rate_limiter_api::CreateRateLimiterParams) -> Result<crate::models::RateLimiterResponse, Error<rate_limiter_api::CreateRateLimiterError>> {
+        rate_limiter_api::create_rate_limiter(&self.version.configuration, rate_limiter_api::CreateRateLimiterParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all rate limiters for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::RateLimiterResponse>, Error<rate_limiter_api::ListRateLimitersError>> {
+        rate_limiter_api::list_rate_limiters(&self.version.configuration, rate_limiter_api::ListRateLimitersParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`request_settings_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestSettingsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl RequestSettingsClient<'_> {
+    /// Creates a new Request Settings object.
+    pub async fn create(&self) -> Result<crate::models::RequestSettingsResponse, Error<request_settings_api::CreateRequestSettingsError>> {
+        request_settings_api::create_request_settings(&self.version.configuration, request_settings_api::CreateRequestSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Removes the specified Request Settings object.
+    pub async fn delete(&self, params: request_settings_api::DeleteRequestSettingsParams) -> Result<crate::models::InlineResponse200, Error<request_settings_api::DeleteRequestSettingsError>> {
+        request_settings_api::delete_request_settings(&self.version.configuration, request_settings_api::DeleteRequestSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Gets the specified Request Settings object.
+    pub async fn get(&self, params: request_settings_api::GetRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<request_settings_api::GetRequestSettingsError>> {
+        request_settings_api::get_request_settings(&self.version.configuration, request_settings_api::GetRequestSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Returns a list of all Request Settings objects for the given service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::RequestSettingsResponse>, Error<request_settings_api::ListRequestSettingsError>> {
+        request_settings_api::list_request_settings(&self.version.configuration, request_settings_api::ListRequestSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Updates the specified Request Settings object.
+    pub async fn update(&self, params: request_settings_api::UpdateRequestSettingsParams) -> Result<crate::models::RequestSettingsResponse, Error<request_settings_api::UpdateRequestSettingsError>> {
+        request_settings_api::update_request_settings(&self.version.configuration, request_settings_api::UpdateRequestSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "compute")]
+/// [`resource_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct ResourcesClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "compute")]
+impl ResourcesClient<'_> {
+    /// Create a link between a resource and a service version.
+    pub async fn create(&self, params: resource_api::CreateResourceParams) -> Result<crate::models::ResourceResponse, Error<resource_api::CreateResourceError>> {
+        resource_api::create_resource(&self.version.configuration, resource_api::CreateResourceParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete a link between a resource and a service version.
+    pub async fn delete(&self, params: resource_api::DeleteResourceParams) -> Result<crate::models::InlineResponse200, Error<resource_api::DeleteResourceError>> {
+        resource_api::delete_resource(&self.version.configuration, resource_api::DeleteResourceParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Display a resource link by its identifier.
+    pub async fn get(&self, params: resource_api::GetResourceParams) -> Result<crate::models::ResourceResponse, Error<resource_api::GetResourceError>> {
+        resource_api::get_resource(&self.version.configuration, resource_api::GetResourceParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List links between resources and services
+    pub async fn list(&self) -> Result<Vec<crate::models::ResourceResponse>, Error<resource_api::ListResourcesError>> {
+        resource_api::list_resources(&self.version.configuration, resource_api::ListResourcesParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a link between a resource and a service version.
+    pub async fn update(&self, params: resource_api::UpdateResourceParams) -> Result<crate::models::ResourceResponse, Error<resource_api::UpdateResourceError>> {
+        resource_api::update_resource(&self.version.configuration, resource_api::UpdateResourceParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`response_object_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseObjectsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl ResponseObjectsClient<'_> {
+    /// Creates a new Response Object.
+    pub async fn create(&self, params: response_object_api::CreateResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<response_object_api::CreateResponseObjectError>> {
+        response_object_api::create_response_object(&self.version.configuration, response_object_api::CreateResponseObjectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Deletes the specified Response Object.
+    pub async fn delete(&self, params: response_object_api::DeleteResponseObjectParams) -> Result<crate::models::InlineResponse200, Error<response_object_api::DeleteResponseObjectError>> {
+        response_object_api::delete_response_object(&self.version.configuration, response_object_api::DeleteResponseObjectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Gets the specified Response Object.
+    pub async fn get(&self, params: response_object_api::GetResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<response_object_api::GetResponseObjectError>> {
+        response_object_api::get_response_object(&self.version.configuration, response_object_api::GetResponseObjectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Returns all Response Objects for the specified service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::ResponseObjectResponse>, Error<response_object_api::ListResponseObjectsError>> {
+        response_object_api::list_response_objects(&self.version.configuration, response_object_api::ListResponseObjectsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Updates the specified Response Object.
+    pub async fn update(&self, params: response_object_api::UpdateResponseObjectParams) -> Result<crate::models::ResponseObjectResponse, Error<response_object_api::UpdateResponseObjectError>> {
+        response_object_api::update_response_object(&self.version.configuration, response_object_api::UpdateResponseObjectParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`settings_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct SettingsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl SettingsClient<'_> {
+    /// Get the settings for a particular service and version.
+    pub async fn get(&self) -> Result<crate::models::SettingsResponse, Error<settings_api::GetServiceSettingsError>> {
+        settings_api::get_service_settings(&self.version.configuration, settings_api::GetServiceSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update the settings for a particular service and version. NOTE: If you override TTLs with custom VCL, any general.default_ttl value will not be honored and the expected behavior may change. 
+    pub async fn update(&self, params: settings_api::UpdateServiceSettingsParams) -> Result<crate::models::SettingsResponse, Error<settings_api::UpdateServiceSettingsError>> {
+        settings_api::update_service_settings(&self.version.configuration, settings_api::UpdateServiceSettingsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`snippet_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct SnippetsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl SnippetsClient<'_> {
+    /// Create a snippet for a particular service and version.
+    pub async fn create(&self, params: snippet_api::CreateSnippetParams) -> Result<crate::models::SnippetResponsePost, Error<snippet_api::CreateSnippetError>> {
+        snippet_api::create_snippet(&self.version.configuration, snippet_api::CreateSnippetParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete a specific snippet for a particular service and version.
+    pub async fn delete(&self, params: snippet_api::DeleteSnippetParams) -> Result<crate::models::InlineResponse200, Error<snippet_api::DeleteSnippetError>> {
+        snippet_api::delete_snippet(&self.version.configuration, snippet_api::DeleteSnippetParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get a single snippet for a particular service and version.
+    pub async fn get(&self, params: snippet_api::GetSnippetParams) -> Result<crate::models::SnippetResponse, Error<snippet_api::GetSnippetError>> {
+        snippet_api::get_snippet(&self.version.configuration, snippet_api::GetSnippetParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List all snippets for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::SnippetResponse>, Error<snippet_api::ListSnippetsError>> {
+        snippet_api::list_snippets(&self.version.configuration, snippet_api::ListSnippetsParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Update a specific snippet for a particular service and version.
+    pub async fn update(&self, params: snippet_api::UpdateSnippetParams) -> Result<crate::models::SnippetResponse, Error<snippet_api::UpdateSnippetError>> {
+        snippet_api::update_snippet(&self.version.configuration, snippet_api::UpdateSnippetParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
+
+#[cfg(feature = "vcl")]
+/// [`vcl_api`] endpoints, scoped to a service version.
+#[derive(Debug, Clone, Copy)]
+pub struct VclsClient<'a> {
+    version: &'a VersionClient,
+}
+
+#[cfg(feature = "vcl")]
+impl VclsClient<'_> {
+    /// Upload a VCL for a particular service and version.
+    pub async fn create(&self, params: vcl_api::CreateCustomVclParams) -> Result<crate::models::VclResponse, Error<vcl_api::CreateCustomVclError>> {
+        vcl_api::create_custom_vcl(&self.version.configuration, vcl_api::CreateCustomVclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Delete the uploaded VCL for a particular service and version.
+    pub async fn delete(&self, params: vcl_api::DeleteCustomVclParams) -> Result<crate::models::InlineResponse200, Error<vcl_api::DeleteCustomVclError>> {
+        vcl_api::delete_custom_vcl(&self.version.configuration, vcl_api::DeleteCustomVclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Get the uploaded VCL for a particular service and version.
+    pub async fn get(&self, params: vcl_api::GetCustomVclParams) -> Result<crate::models::VclResponse, Error<vcl_api::GetCustomVclError>> {
+        vcl_api::get_custom_vcl(&self.version.configuration, vcl_api::GetCustomVclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Return boilerplate VCL with the service's TTL from the [settings](https://www.fastly.com/documentation/reference/api/vcl-services/settings/).
+    pub async fn get_boilerplate(&self) -> Result<String, Error<vcl_api::GetCustomVclBoilerplateError>> {
+        vcl_api::get_custom_vcl_boilerplate(&self.version.configuration, vcl_api::GetCustomVclBoilerplateParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Display the generated VCL for a particular service and version.
+    pub async fn get_generated(&self) -> Result<crate::models::VclResponse, Error<vcl_api::GetCustomVclGeneratedError>> {
+        vcl_api::get_custom_vcl_generated(&self.version.configuration, vcl_api::GetCustomVclGeneratedParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Display the content of generated VCL with HTML syntax highlighting. Include line numbers by sending `lineno=true` as a request parameter.
+    pub async fn get_generated_highlighted(&self) -> Result<crate::models::VclSyntaxHighlightingResponse, Error<vcl_api::GetCustomVclGeneratedHighlightedError>> {
+        vcl_api::get_custom_vcl_generated_highlighted(&self.version.configuration, vcl_api::GetCustomVclGeneratedHighlightedParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Get the uploaded VCL for a particular service and version with HTML syntax highlighting. Include line numbers by sending `lineno=true` as a request parameter.
+    pub async fn get_highlighted(&self, params: vcl_api::GetCustomVclHighlightedParams) -> Result<crate::models::VclSyntaxHighlightingResponse, Error<vcl_api::GetCustomVclHighlightedError>> {
+        vcl_api::get_custom_vcl_highlighted(&self.version.configuration, vcl_api::GetCustomVclHighlightedParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Download the specified VCL.
+    pub async fn get_raw(&self, params: vcl_api::GetCustomVclRawParams) -> Result<String, Error<vcl_api::GetCustomVclRawError>> {
+        vcl_api::get_custom_vcl_raw(&self.version.configuration, vcl_api::GetCustomVclRawParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// List the uploaded VCLs for a particular service and version.
+    pub async fn list(&self) -> Result<Vec<crate::models::VclResponse>, Error<vcl_api::ListCustomVclError>> {
+        vcl_api::list_custom_vcl(&self.version.configuration, vcl_api::ListCustomVclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id }).await
+    }
+
+    /// Set the specified VCL as the main.
+    pub async fn set_main(&self, params: vcl_api::SetCustomVclMainParams) -> Result<crate::models::VclResponse, Error<vcl_api::SetCustomVclMainError>> {
+        vcl_api::set_custom_vcl_main(&self.version.configuration, vcl_api::SetCustomVclMainParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+
+    /// Update the uploaded VCL for a particular service and version.
+    pub async fn update(&self, params: vcl_api::UpdateCustomVclParams) -> Result<crate::models::VclResponse, Error<vcl_api::UpdateCustomVclError>> {
+        vcl_api::update_custom_vcl(&self.version.configuration, vcl_api::UpdateCustomVclParams { service_id: self.version.service_id.clone(), version_id: self.version.version_id, ..params }).await
+    }
+}
@@ -7,4 +7,9 @@ extern crate url;
 extern crate reqwest;
 
 pub mod apis;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod client;
 pub mod models;
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -22,7 +22,7 @@ pub struct AutomationTokenCreateResponseAllOf {
     #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     #[serde(rename = "access_token", skip_serializing_if = "Option::is_none")]
-    pub access_token: Option<String>,
+    pub access_token: Option<crate::apis::redact::Secret>,
     /// A UTC time-stamp of when the token was last used.
     #[serde(rename = "last_used_at", skip_serializing_if = "Option::is_none")]
     pub last_used_at: Option<String>,
@@ -90,7 +90,7 @@ pub struct BackendResponse {
     pub ssl_client_cert: Option<String>,
     /// Client key attached to origin.
     #[serde(rename = "ssl_client_key", skip_serializing_if = "Option::is_none")]
-    pub ssl_client_key: Option<String>,
+    pub ssl_client_key: Option<crate::apis::redact::Secret>,
     /// Use `ssl_cert_hostname` and `ssl_sni_hostname` to configure certificate validation.
     #[serde(rename = "ssl_hostname", skip_serializing_if = "Option::is_none")]
     pub ssl_hostname: Option<String>,
@@ -19,7 +19,7 @@ pub struct HistoricalFieldResponse {
     #[serde(rename = "msg", skip_serializing_if = "Option::is_none")]
     pub msg: Option<String>,
     #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
-    pub data: Option<::std::collections::HashMap<String, crate::models::Array>>,
+    pub data: Option<::std::collections::HashMap<String, Vec<crate::models::HistoricalFieldResultsAttributes>>>,
 }
 
 impl HistoricalFieldResponse {
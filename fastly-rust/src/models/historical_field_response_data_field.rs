@@ -11,7 +11,7 @@
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub struct HistoricalFieldResponseDataField {
     #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
-    pub data: Option<::std::collections::HashMap<String, crate::models::Array>>,
+    pub data: Option<::std::collections::HashMap<String, Vec<crate::models::HistoricalFieldResultsAttributes>>>,
 }
 
 impl HistoricalFieldResponseDataField {
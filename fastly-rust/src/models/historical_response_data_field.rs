@@ -12,7 +12,7 @@
 pub struct HistoricalResponseDataField {
     /// Contains the results of the query, organized by *service ID*, into arrays where each element describes one service over a *time span*.
     #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
-    pub data: Option<::std::collections::HashMap<String, crate::models::Array>>,
+    pub data: Option<::std::collections::HashMap<String, Vec<crate::models::Results>>>,
 }
 
 impl HistoricalResponseDataField {
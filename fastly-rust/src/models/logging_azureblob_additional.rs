@@ -21,7 +21,7 @@ pub struct LoggingAzureblobAdditional {
     pub container: Option<String>,
     /// The Azure shared access signature providing write access to the blob service objects. Be sure to update your token before it expires or the logging functionality will not work. Required.
     #[serde(rename = "sas_token", skip_serializing_if = "Option::is_none")]
-    pub sas_token: Option<String>,
+    pub sas_token: Option<crate::apis::redact::Secret>,
     /// A PGP public key that Fastly will use to encrypt your log files before writing them to disk.
     #[serde(rename = "public_key", skip_serializing_if = "Option::is_none")]
     pub public_key: Option<String>,
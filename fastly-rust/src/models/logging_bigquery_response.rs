@@ -30,7 +30,7 @@ pub struct LoggingBigqueryResponse {
     pub user: Option<String>,
     /// Your Google Cloud Platform account secret key. The `private_key` field in your service account authentication JSON. Not required if `account_name` is specified.
     #[serde(rename = "secret_key", skip_serializing_if = "Option::is_none")]
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The name of the Google Cloud Platform service account associated with the target log collection service. Not required if `user` and `secret_key` are provided.
     #[serde(rename = "account_name", skip_serializing_if = "Option::is_none")]
     pub account_name: Option<String>,
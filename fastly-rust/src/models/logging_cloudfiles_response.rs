@@ -55,7 +55,7 @@ pub struct LoggingCloudfilesResponse {
     pub version: Option<Box<String>>,
     /// Your Cloud Files account access key.
     #[serde(rename = "access_key", skip_serializing_if = "Option::is_none")]
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The name of your Cloud Files container.
     #[serde(rename = "bucket_name", skip_serializing_if = "Option::is_none")]
     pub bucket_name: Option<String>,
@@ -30,7 +30,7 @@ pub struct LoggingDatadog {
     pub region: Option<Region>,
     /// The API key from your Datadog account. Required.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
 }
 
 impl LoggingDatadog {
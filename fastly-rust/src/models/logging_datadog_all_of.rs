@@ -18,7 +18,7 @@ pub struct LoggingDatadogAllOf {
     pub format: Option<String>,
     /// The API key from your Datadog account. Required.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
 }
 
 impl LoggingDatadogAllOf {
@@ -30,7 +30,7 @@ pub struct LoggingDatadogResponse {
     pub region: Option<Region>,
     /// The API key from your Datadog account. Required.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// Date and time in ISO 8601 format.
     #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
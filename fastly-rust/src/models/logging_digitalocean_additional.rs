@@ -15,10 +15,10 @@ pub struct LoggingDigitaloceanAdditional {
     pub bucket_name: Option<String>,
     /// Your DigitalOcean Spaces account access key.
     #[serde(rename = "access_key", skip_serializing_if = "Option::is_none")]
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// Your DigitalOcean Spaces account secret key.
     #[serde(rename = "secret_key", skip_serializing_if = "Option::is_none")]
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The domain of the DigitalOcean Spaces endpoint.
     #[serde(rename = "domain", skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
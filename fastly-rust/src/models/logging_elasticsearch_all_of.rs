@@ -24,7 +24,7 @@ pub struct LoggingElasticsearchAllOf {
     pub user: Option<String>,
     /// Basic Auth password.
     #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
+    pub password: Option<crate::apis::redact::Secret>,
     /// A Fastly [log format string](https://docs.fastly.com/en/guides/custom-log-formats). Must produce valid JSON that Elasticsearch can ingest.
     #[serde(rename = "format", skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
@@ -51,7 +51,7 @@ pub struct LoggingFtp {
     pub ipv4: Option<String>,
     /// The password for the server. For anonymous use an email address.
     #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
+    pub password: Option<crate::apis::redact::Secret>,
     /// The path to upload log files to. If the path ends in `/` then it is treated as a directory.
     #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
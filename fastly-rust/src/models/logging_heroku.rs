@@ -27,7 +27,7 @@ pub struct LoggingHeroku {
     pub format: Option<String>,
     /// The token to use for authentication ([https://devcenter.heroku.com/articles/add-on-partner-log-integration](https://devcenter.heroku.com/articles/add-on-partner-log-integration)).
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The URL to stream logs to.
     #[serde(rename = "url", skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
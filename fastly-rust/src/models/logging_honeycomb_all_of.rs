@@ -18,7 +18,7 @@ pub struct LoggingHoneycombAllOf {
     pub dataset: Option<String>,
     /// The Write Key from the Account page of your Honeycomb account.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
 }
 
 impl LoggingHoneycombAllOf {
@@ -30,7 +30,7 @@ pub struct LoggingHoneycombResponse {
     pub dataset: Option<String>,
     /// The Write Key from the Account page of your Honeycomb account.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// Date and time in ISO 8601 format.
     #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
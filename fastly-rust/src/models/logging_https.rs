@@ -33,7 +33,7 @@ pub struct LoggingHttps {
     pub tls_client_cert: Option<String>,
     /// The client private key used to make authenticated requests. Must be in PEM format.
     #[serde(rename = "tls_client_key", skip_serializing_if = "Option::is_none")]
-    pub tls_client_key: Option<String>,
+    pub tls_client_key: Option<crate::apis::redact::Secret>,
     /// The hostname to verify the server's certificate. This should be one of the Subject Alternative Name (SAN) fields for the certificate. Common Names (CN) are not supported.
     #[serde(rename = "tls_hostname", skip_serializing_if = "Option::is_none")]
     pub tls_hostname: Option<String>,
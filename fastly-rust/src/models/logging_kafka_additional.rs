@@ -36,7 +36,7 @@ pub struct LoggingKafkaAdditional {
     pub user: Option<String>,
     /// SASL password.
     #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
+    pub password: Option<crate::apis::redact::Secret>,
     #[serde(rename = "use_tls", skip_serializing_if = "Option::is_none")]
     pub use_tls: Option<crate::models::LoggingUseTlsString>,
 }
@@ -27,10 +27,10 @@ pub struct LoggingKinesis {
     pub region: Option<crate::models::AwsRegion>,
     /// The secret key associated with the target Amazon Kinesis stream. Not required if `iam_role` is specified.
     #[serde(rename = "secret_key", skip_serializing_if = "Option::is_none")]
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// The access key associated with the target Amazon Kinesis stream. Not required if `iam_role` is specified.
     #[serde(rename = "access_key", skip_serializing_if = "Option::is_none")]
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The ARN for an IAM role granting Fastly access to the target Amazon Kinesis stream. Not required if `access_key` and `secret_key` are provided.
     #[serde(rename = "iam_role", skip_serializing_if = "Option::is_none")]
     pub iam_role: Option<String>,
@@ -30,7 +30,7 @@ pub struct LoggingLogentries {
     pub port: Option<i32>,
     /// Use token based authentication ([https://logentries.com/doc/input-token/](https://logentries.com/doc/input-token/)).
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     #[serde(rename = "use_tls", skip_serializing_if = "Option::is_none")]
     pub use_tls: Option<crate::models::LoggingUseTls>,
     /// The region to which to stream logs.
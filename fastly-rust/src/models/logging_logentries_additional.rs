@@ -15,7 +15,7 @@ pub struct LoggingLogentriesAdditional {
     pub port: Option<i32>,
     /// Use token based authentication.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     #[serde(rename = "use_tls", skip_serializing_if = "Option::is_none")]
     pub use_tls: Option<crate::models::LoggingUseTlsString>,
     /// The region to which to stream logs.
@@ -12,7 +12,7 @@
 pub struct LoggingLogshuttleAdditional {
     /// The data authentication token associated with this endpoint.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The URL to stream logs to.
     #[serde(rename = "url", skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
@@ -27,7 +27,7 @@ pub struct LoggingNewrelicResponse {
     pub format_version: Option<FormatVersion>,
     /// The Insert API key from the Account page of your New Relic account. Required.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The region to which to stream logs.
     #[serde(rename = "region", skip_serializing_if = "Option::is_none")]
     pub region: Option<Region>,
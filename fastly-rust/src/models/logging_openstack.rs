@@ -42,7 +42,7 @@ pub struct LoggingOpenstack {
     pub compression_codec: Option<CompressionCodec>,
     /// Your OpenStack account access key.
     #[serde(rename = "access_key", skip_serializing_if = "Option::is_none")]
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The name of your OpenStack container.
     #[serde(rename = "bucket_name", skip_serializing_if = "Option::is_none")]
     pub bucket_name: Option<String>,
@@ -55,7 +55,7 @@ pub struct LoggingS3Response {
     pub version: Option<Box<String>>,
     /// The access key for your S3 account. Not required if `iam_role` is provided.
     #[serde(rename = "access_key", skip_serializing_if = "Option::is_none")]
-    pub access_key: Option<String>,
+    pub access_key: Option<crate::apis::redact::Secret>,
     /// The access control list (ACL) specific request header. See the AWS documentation for [Access Control List (ACL) Specific Request Headers](https://docs.aws.amazon.com/AmazonS3/latest/API/mpUploadInitiate.html#initiate-mpu-acl-specific-request-headers) for more information.
     #[serde(rename = "acl", skip_serializing_if = "Option::is_none")]
     pub acl: Option<String>,
@@ -79,7 +79,7 @@ pub struct LoggingS3Response {
     pub redundancy: Option<String>,
     /// The secret key for your S3 account. Not required if `iam_role` is provided.
     #[serde(rename = "secret_key", skip_serializing_if = "Option::is_none")]
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// Optional server-side KMS Key Id. Must be set if `server_side_encryption` is set to `aws:kms` or `AES256`.
     #[serde(rename = "server_side_encryption_kms_key_id", skip_serializing_if = "Option::is_none")]
     pub server_side_encryption_kms_key_id: Option<String>,
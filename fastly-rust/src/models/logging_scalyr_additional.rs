@@ -15,7 +15,7 @@ pub struct LoggingScalyrAdditional {
     pub region: Option<Region>,
     /// The token to use for authentication.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     /// The name of the logfile within Scalyr.
     #[serde(rename = "project_id", skip_serializing_if = "Option::is_none")]
     pub project_id: Option<String>,
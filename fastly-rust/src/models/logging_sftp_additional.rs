@@ -12,7 +12,7 @@
 pub struct LoggingSftpAdditional {
     /// The password for the server. If both `password` and `secret_key` are passed, `secret_key` will be used in preference.
     #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
+    pub password: Option<crate::apis::redact::Secret>,
     /// The path to upload logs to.
     #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
@@ -21,7 +21,7 @@ pub struct LoggingSftpAdditional {
     pub public_key: Option<String>,
     /// The SSH private key for the server. If both `password` and `secret_key` are passed, `secret_key` will be used in preference.
     #[serde(rename = "secret_key", skip_serializing_if = "Option::is_none")]
-    pub secret_key: Option<String>,
+    pub secret_key: Option<crate::apis::redact::Secret>,
     /// A list of host keys for all hosts we can connect to over SFTP.
     #[serde(rename = "ssh_known_hosts", skip_serializing_if = "Option::is_none")]
     pub ssh_known_hosts: Option<String>,
@@ -15,7 +15,7 @@ pub struct LoggingSplunkAllOf {
     pub url: Option<String>,
     /// A Splunk token for use in posting logs over HTTP to your collector.
     #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
-    pub token: Option<String>,
+    pub token: Option<crate::apis::redact::Secret>,
     #[serde(rename = "use_tls", skip_serializing_if = "Option::is_none")]
     pub use_tls: Option<crate::models::LoggingUseTls>,
 }
@@ -1,1414 +1,2868 @@
+#[cfg(feature = "full")]
 pub mod acl;
+#[cfg(feature = "full")]
 pub use self::acl::Acl;
+#[cfg(feature = "acl")]
 pub mod acl_entry;
+#[cfg(feature = "acl")]
 pub use self::acl_entry::AclEntry;
+#[cfg(feature = "acl")]
 pub mod acl_entry_response;
+#[cfg(feature = "acl")]
 pub use self::acl_entry_response::AclEntryResponse;
+#[cfg(feature = "full")]
 pub mod acl_entry_response_all_of;
+#[cfg(feature = "full")]
 pub use self::acl_entry_response_all_of::AclEntryResponseAllOf;
+#[cfg(feature = "acl")]
 pub mod acl_response;
+#[cfg(feature = "acl")]
 pub use self::acl_response::AclResponse;
+#[cfg(feature = "full")]
 pub mod acl_response_all_of;
+#[cfg(feature = "full")]
 pub use self::acl_response_all_of::AclResponseAllOf;
+#[cfg(feature = "domains")]
 pub mod apex_redirect;
+#[cfg(feature = "domains")]
 pub use self::apex_redirect::ApexRedirect;
+#[cfg(feature = "full")]
 pub mod apex_redirect_all_of;
+#[cfg(feature = "full")]
 pub use self::apex_redirect_all_of::ApexRedirectAllOf;
+#[cfg(feature = "full")]
 pub mod async_response;
+#[cfg(feature = "full")]
 pub use self::async_response::AsyncResponse;
+#[cfg(feature = "full")]
 pub mod automation_token;
+#[cfg(feature = "full")]
 pub use self::automation_token::AutomationToken;
+#[cfg(feature = "accounts")]
 pub mod automation_token_create_request;
+#[cfg(feature = "accounts")]
 pub use self::automation_token_create_request::AutomationTokenCreateRequest;
+#[cfg(feature = "accounts")]
 pub mod automation_token_create_request_attributes;
+#[cfg(feature = "accounts")]
 pub use self::automation_token_create_request_attributes::AutomationTokenCreateRequestAttributes;
+#[cfg(feature = "accounts")]
 pub mod automation_token_create_response;
+#[cfg(feature = "accounts")]
 pub use self::automation_token_create_response::AutomationTokenCreateResponse;
+#[cfg(feature = "full")]
 pub mod automation_token_create_response_all_of;
+#[cfg(feature = "full")]
 pub use self::automation_token_create_response_all_of::AutomationTokenCreateResponseAllOf;
+#[cfg(feature = "accounts")]
 pub mod automation_token_error_response;
+#[cfg(feature = "accounts")]
 pub use self::automation_token_error_response::AutomationTokenErrorResponse;
+#[cfg(feature = "accounts")]
 pub mod automation_token_response;
+#[cfg(feature = "accounts")]
 pub use self::automation_token_response::AutomationTokenResponse;
+#[cfg(feature = "full")]
 pub mod automation_token_response_all_of;
+#[cfg(feature = "full")]
 pub use self::automation_token_response_all_of::AutomationTokenResponseAllOf;
+#[cfg(feature = "logging")]
 pub mod aws_region;
+#[cfg(feature = "logging")]
 pub use self::aws_region::AwsRegion;
 pub mod backend;
 pub use self::backend::Backend;
 pub mod backend_response;
 pub use self::backend_response::BackendResponse;
+#[cfg(feature = "full")]
 pub mod backend_response_all_of;
+#[cfg(feature = "full")]
 pub use self::backend_response_all_of::BackendResponseAllOf;
+#[cfg(feature = "full")]
 pub mod batch;
+#[cfg(feature = "full")]
 pub use self::batch::Batch;
+#[cfg(feature = "full")]
 pub mod batch_errors;
+#[cfg(feature = "full")]
 pub use self::batch_errors::BatchErrors;
+#[cfg(feature = "full")]
 pub mod billing;
+#[cfg(feature = "full")]
 pub use self::billing::Billing;
+#[cfg(feature = "billing")]
 pub mod billing_address_attributes;
+#[cfg(feature = "billing")]
 pub use self::billing_address_attributes::BillingAddressAttributes;
+#[cfg(feature = "billing")]
 pub mod billing_address_request;
+#[cfg(feature = "billing")]
 pub use self::billing_address_request::BillingAddressRequest;
+#[cfg(feature = "billing")]
 pub mod billing_address_request_data;
+#[cfg(feature = "billing")]
 pub use self::billing_address_request_data::BillingAddressRequestData;
+#[cfg(feature = "billing")]
 pub mod billing_address_response;
+#[cfg(feature = "billing")]
 pub use self::billing_address_response::BillingAddressResponse;
+#[cfg(feature = "billing")]
 pub mod billing_address_response_data;
+#[cfg(feature = "billing")]
 pub use self::billing_address_response_data::BillingAddressResponseData;
+#[cfg(feature = "billing")]
 pub mod billing_address_verification_error_response;
+#[cfg(feature = "billing")]
 pub use self::billing_address_verification_error_response::BillingAddressVerificationErrorResponse;
+#[cfg(feature = "billing")]
 pub mod billing_address_verification_error_response_errors;
+#[cfg(feature = "billing")]
 pub use self::billing_address_verification_error_response_errors::BillingAddressVerificationErrorResponseErrors;
+#[cfg(feature = "billing")]
 pub mod billing_bandwidth;
+#[cfg(feature = "billing")]
 pub use self::billing_bandwidth::BillingBandwidth;
+#[cfg(feature = "billing")]
 pub mod billing_bandwidth_tiers;
+#[cfg(feature = "billing")]
 pub use self::billing_bandwidth_tiers::BillingBandwidthTiers;
+#[cfg(feature = "full")]
 pub mod billing_estimate_invoice_id;
+#[cfg(feature = "full")]
 pub use self::billing_estimate_invoice_id::BillingEstimateInvoiceId;
+#[cfg(feature = "full")]
 pub mod billing_estimate_lines;
+#[cfg(feature = "full")]
 pub use self::billing_estimate_lines::BillingEstimateLines;
+#[cfg(feature = "billing")]
 pub mod billing_estimate_lines_line_items;
+#[cfg(feature = "billing")]
 pub use self::billing_estimate_lines_line_items::BillingEstimateLinesLineItems;
+#[cfg(feature = "billing")]
 pub mod billing_estimate_response;
+#[cfg(feature = "billing")]
 pub use self::billing_estimate_response::BillingEstimateResponse;
+#[cfg(feature = "full")]
 pub mod billing_invoice_id;
+#[cfg(feature = "full")]
 pub use self::billing_invoice_id::BillingInvoiceId;
+#[cfg(feature = "billing")]
 pub mod billing_regions;
+#[cfg(feature = "billing")]
 pub use self::billing_regions::BillingRegions;
+#[cfg(feature = "billing")]
 pub mod billing_response;
+#[cfg(feature = "billing")]
 pub use self::billing_response::BillingResponse;
+#[cfg(feature = "full")]
 pub mod billing_response_item_items_data;
+#[cfg(feature = "full")]
 pub use self::billing_response_item_items_data::BillingResponseItemItemsData;
+#[cfg(feature = "billing")]
 pub mod billing_response_line_item;
+#[cfg(feature = "billing")]
 pub use self::billing_response_line_item::BillingResponseLineItem;
+#[cfg(feature = "billing")]
 pub mod billing_status;
+#[cfg(feature = "billing")]
 pub use self::billing_status::BillingStatus;
+#[cfg(feature = "billing")]
 pub mod billing_total;
+#[cfg(feature = "billing")]
 pub use self::billing_total::BillingTotal;
+#[cfg(feature = "billing")]
 pub mod billing_total_extras;
+#[cfg(feature = "billing")]
 pub use self::billing_total_extras::BillingTotalExtras;
+#[cfg(feature = "acl")]
 pub mod bulk_update_acl_entries_request;
+#[cfg(feature = "acl")]
 pub use self::bulk_update_acl_entries_request::BulkUpdateAclEntriesRequest;
+#[cfg(feature = "acl")]
 pub mod bulk_update_acl_entry;
+#[cfg(feature = "acl")]
 pub use self::bulk_update_acl_entry::BulkUpdateAclEntry;
+#[cfg(feature = "full")]
 pub mod bulk_update_acl_entry_all_of;
+#[cfg(feature = "full")]
 pub use self::bulk_update_acl_entry_all_of::BulkUpdateAclEntryAllOf;
+#[cfg(feature = "stores")]
 pub mod bulk_update_config_store_item;
+#[cfg(feature = "stores")]
 pub use self::bulk_update_config_store_item::BulkUpdateConfigStoreItem;
+#[cfg(feature = "full")]
 pub mod bulk_update_config_store_item_all_of;
+#[cfg(feature = "full")]
 pub use self::bulk_update_config_store_item_all_of::BulkUpdateConfigStoreItemAllOf;
+#[cfg(feature = "stores")]
 pub mod bulk_update_config_store_list_request;
+#[cfg(feature = "stores")]
 pub use self::bulk_update_config_store_list_request::BulkUpdateConfigStoreListRequest;
+#[cfg(feature = "dictionary")]
 pub mod bulk_update_dictionary_item;
+#[cfg(feature = "dictionary")]
 pub use self::bulk_update_dictionary_item::BulkUpdateDictionaryItem;
+#[cfg(feature = "dictionary")]
 pub mod bulk_update_dictionary_list_request;
+#[cfg(feature = "dictionary")]
 pub use self::bulk_update_dictionary_list_request::BulkUpdateDictionaryListRequest;
+#[cfg(feature = "full")]
 pub mod bulk_waf_active_rules;
+#[cfg(feature = "full")]
 pub use self::bulk_waf_active_rules::BulkWafActiveRules;
+#[cfg(feature = "full")]
 pub mod cache_setting;
+#[cfg(feature = "full")]
 pub use self::cache_setting::CacheSetting;
 pub mod cache_setting_response;
 pub use self::cache_setting_response::CacheSettingResponse;
+#[cfg(feature = "stores")]
 pub mod client_key;
+#[cfg(feature = "stores")]
 pub use self::client_key::ClientKey;
+#[cfg(feature = "full")]
 pub mod condition;
+#[cfg(feature = "full")]
 pub use self::condition::Condition;
 pub mod condition_response;
 pub use self::condition_response::ConditionResponse;
+#[cfg(feature = "full")]
 pub mod config_store;
+#[cfg(feature = "full")]
 pub use self::config_store::ConfigStore;
+#[cfg(feature = "stores")]
 pub mod config_store_info_response;
+#[cfg(feature = "stores")]
 pub use self::config_store_info_response::ConfigStoreInfoResponse;
+#[cfg(feature = "full")]
 pub mod config_store_item;
+#[cfg(feature = "full")]
 pub use self::config_store_item::ConfigStoreItem;
+#[cfg(feature = "stores")]
 pub mod config_store_item_response;
+#[cfg(feature = "stores")]
 pub use self::config_store_item_response::ConfigStoreItemResponse;
+#[cfg(feature = "full")]
 pub mod config_store_item_response_all_of;
+#[cfg(feature = "full")]
 pub use self::config_store_item_response_all_of::ConfigStoreItemResponseAllOf;
+#[cfg(feature = "stores")]
 pub mod config_store_response;
+#[cfg(feature = "stores")]
 pub use self::config_store_response::ConfigStoreResponse;
+#[cfg(feature = "full")]
 pub mod config_store_response_all_of;
+#[cfg(feature = "full")]
 pub use self::config_store_response_all_of::ConfigStoreResponseAllOf;
+#[cfg(feature = "full")]
 pub mod contact;
+#[cfg(feature = "full")]
 pub use self::contact::Contact;
+#[cfg(feature = "accounts")]
 pub mod contact_response;
+#[cfg(feature = "accounts")]
 pub use self::contact_response::ContactResponse;
+#[cfg(feature = "full")]
 pub mod contact_response_all_of;
+#[cfg(feature = "full")]
 pub use self::contact_response_all_of::ContactResponseAllOf;
+#[cfg(feature = "platform")]
 pub mod content;
+#[cfg(feature = "platform")]
 pub use self::content::Content;
+#[cfg(feature = "stats")]
 pub mod create_dashboard_request;
+#[cfg(feature = "stats")]
 pub use self::create_dashboard_request::CreateDashboardRequest;
+#[cfg(feature = "vcl")]
 pub mod create_response_object_request;
+#[cfg(feature = "vcl")]
 pub use self::create_response_object_request::CreateResponseObjectRequest;
+#[cfg(feature = "full")]
 pub mod customer;
+#[cfg(feature = "full")]
 pub use self::customer::Customer;
+#[cfg(feature = "accounts")]
 pub mod customer_response;
+#[cfg(feature = "accounts")]
 pub use self::customer_response::CustomerResponse;
+#[cfg(feature = "full")]
 pub mod customer_response_all_of;
+#[cfg(feature = "full")]
 pub use self::customer_response_all_of::CustomerResponseAllOf;
+#[cfg(feature = "stats")]
 pub mod dashboard;
+#[cfg(feature = "stats")]
 pub use self::dashboard::Dashboard;
+#[cfg(feature = "stats")]
 pub mod dashboard_item;
+#[cfg(feature = "stats")]
 pub use self::dashboard_item::DashboardItem;
+#[cfg(feature = "stats")]
 pub mod dashboard_item_property_data_source;
+#[cfg(feature = "stats")]
 pub use self::dashboard_item_property_data_source::DashboardItemPropertyDataSource;
+#[cfg(feature = "stats")]
 pub mod dashboard_item_property_data_source_property_config;
+#[cfg(feature = "stats")]
 pub use self::dashboard_item_property_data_source_property_config::DashboardItemPropertyDataSourcePropertyConfig;
+#[cfg(feature = "stats")]
 pub mod dashboard_item_property_visualization;
+#[cfg(feature = "stats")]
 pub use self::dashboard_item_property_visualization::DashboardItemPropertyVisualization;
+#[cfg(feature = "stats")]
 pub mod dashboard_item_property_visualization_property_config;
+#[cfg(feature = "stats")]
 pub use self::dashboard_item_property_visualization_property_config::DashboardItemPropertyVisualizationPropertyConfig;
+#[cfg(feature = "stats")]
 pub mod dashboard_property_created_by;
+#[cfg(feature = "stats")]
 pub use self::dashboard_property_created_by::DashboardPropertyCreatedBy;
+#[cfg(feature = "stats")]
 pub mod dashboard_property_updated_by;
+#[cfg(feature = "stats")]
 pub use self::dashboard_property_updated_by::DashboardPropertyUpdatedBy;
+#[cfg(feature = "vcl")]
 pub mod default_settings;
+#[cfg(feature = "vcl")]
 pub use self::default_settings::DefaultSettings;
+#[cfg(feature = "vcl")]
 pub mod default_settings_error;
+#[cfg(feature = "vcl")]
 pub use self::default_settings_error::DefaultSettingsError;
+#[cfg(feature = "vcl")]
 pub mod default_settings_response;
+#[cfg(feature = "vcl")]
 pub use self::default_settings_response::DefaultSettingsResponse;
+#[cfg(feature = "full")]
 pub mod dictionary;
+#[cfg(feature = "full")]
 pub use self::dictionary::Dictionary;
+#[cfg(feature = "dictionary")]
 pub mod dictionary_info_response;
+#[cfg(feature = "dictionary")]
 pub use self::dictionary_info_response::DictionaryInfoResponse;
+#[cfg(feature = "full")]
 pub mod dictionary_item;
+#[cfg(feature = "full")]
 pub use self::dictionary_item::DictionaryItem;
+#[cfg(feature = "dictionary")]
 pub mod dictionary_item_response;
+#[cfg(feature = "dictionary")]
 pub use self::dictionary_item_response::DictionaryItemResponse;
+#[cfg(feature = "full")]
 pub mod dictionary_item_response_all_of;
+#[cfg(feature = "full")]
 pub use self::dictionary_item_response_all_of::DictionaryItemResponseAllOf;
+#[cfg(feature = "dictionary")]
 pub mod dictionary_response;
+#[cfg(feature = "dictionary")]
 pub use self::dictionary_response::DictionaryResponse;
+#[cfg(feature = "full")]
 pub mod dictionary_response_all_of;
+#[cfg(feature = "full")]
 pub use self::dictionary_response_all_of::DictionaryResponseAllOf;
+#[cfg(feature = "vcl")]
 pub mod diff_response;
+#[cfg(feature = "vcl")]
 pub use self::diff_response::DiffResponse;
 pub mod director;
 pub use self::director::Director;
+#[cfg(feature = "backends")]
 pub mod director_backend;
+#[cfg(feature = "backends")]
 pub use self::director_backend::DirectorBackend;
+#[cfg(feature = "full")]
 pub mod director_backend_all_of;
+#[cfg(feature = "full")]
 pub use self::director_backend_all_of::DirectorBackendAllOf;
+#[cfg(feature = "backends")]
 pub mod director_response;
+#[cfg(feature = "backends")]
 pub use self::director_response::DirectorResponse;
+#[cfg(feature = "full")]
 pub mod domain;
+#[cfg(feature = "full")]
 pub use self::domain::Domain;
+#[cfg(feature = "stats")]
 pub mod domain_inspector;
+#[cfg(feature = "stats")]
 pub use self::domain_inspector::DomainInspector;
+#[cfg(feature = "stats")]
 pub mod domain_inspector_entry;
+#[cfg(feature = "stats")]
 pub use self::domain_inspector_entry::DomainInspectorEntry;
+#[cfg(feature = "stats")]
 pub mod domain_inspector_entry_dimensions;
+#[cfg(feature = "stats")]
 pub use self::domain_inspector_entry_dimensions::DomainInspectorEntryDimensions;
+#[cfg(feature = "stats")]
 pub mod domain_inspector_measurements;
+#[cfg(feature = "stats")]
 pub use self::domain_inspector_measurements::DomainInspectorMeasurements;
+#[cfg(feature = "stats")]
 pub mod domain_inspector_realtime_entry;
+#[cfg(feature = "stats")]
 pub use self::domain_inspector_realtime_entry::DomainInspectorRealtimeEntry;
 pub mod domain_response;
 pub use self::domain_response::DomainResponse;
+#[cfg(feature = "platform")]
 pub mod enabled_product_response;
+#[cfg(feature = "platform")]
 pub use self::enabled_product_response::EnabledProductResponse;
+#[cfg(feature = "platform")]
 pub mod enabled_product_response__links;
+#[cfg(feature = "platform")]
 pub use self::enabled_product_response__links::EnabledProductResponseLinks;
+#[cfg(feature = "platform")]
 pub mod enabled_product_response_product;
+#[cfg(feature = "platform")]
 pub use self::enabled_product_response_product::EnabledProductResponseProduct;
+#[cfg(feature = "platform")]
 pub mod enabled_product_response_service;
+#[cfg(feature = "platform")]
 pub use self::enabled_product_response_service::EnabledProductResponseService;
+#[cfg(feature = "billing")]
 pub mod eom_invoice_response;
+#[cfg(feature = "billing")]
 pub use self::eom_invoice_response::EomInvoiceResponse;
+#[cfg(feature = "billing")]
 pub mod error;
+#[cfg(feature = "billing")]
 pub use self::error::Error;
+#[cfg(feature = "full")]
 pub mod error_response_data;
+#[cfg(feature = "full")]
 pub use self::error_response_data::ErrorResponseData;
+#[cfg(feature = "accounts")]
 pub mod event;
+#[cfg(feature = "accounts")]
 pub use self::event::Event;
+#[cfg(feature = "accounts")]
 pub mod event_attributes;
+#[cfg(feature = "accounts")]
 pub use self::event_attributes::EventAttributes;
+#[cfg(feature = "full")]
 pub mod event_data;
+#[cfg(feature = "full")]
 pub use self::event_data::EventData;
+#[cfg(feature = "accounts")]
 pub mod event_response;
+#[cfg(feature = "accounts")]
 pub use self::event_response::EventResponse;
+#[cfg(feature = "accounts")]
 pub mod events_response;
+#[cfg(feature = "accounts")]
 pub use self::events_response::EventsResponse;
+#[cfg(feature = "accounts")]
 pub mod generic_token_error;
+#[cfg(feature = "accounts")]
 pub use self::generic_token_error::GenericTokenError;
+#[cfg(feature = "full")]
 pub mod get_service_level_usage_response;
+#[cfg(feature = "full")]
 pub use self::get_service_level_usage_response::GetServiceLevelUsageResponse;
+#[cfg(feature = "full")]
 pub mod get_service_level_usage_types_response;
+#[cfg(feature = "full")]
 pub use self::get_service_level_usage_types_response::GetServiceLevelUsageTypesResponse;
+#[cfg(feature = "full")]
 pub mod gzip;
+#[cfg(feature = "full")]
 pub use self::gzip::Gzip;
 pub mod gzip_response;
 pub use self::gzip_response::GzipResponse;
+#[cfg(feature = "full")]
 pub mod header;
+#[cfg(feature = "full")]
 pub use self::header::Header;
 pub mod header_response;
 pub use self::header_response::HeaderResponse;
+#[cfg(feature = "full")]
 pub mod header_response_additional;
+#[cfg(feature = "full")]
 pub use self::header_response_additional::HeaderResponseAdditional;
+#[cfg(feature = "full")]
 pub mod healthcheck;
+#[cfg(feature = "full")]
 pub use self::healthcheck::Healthcheck;
 pub mod healthcheck_response;
 pub use self::healthcheck_response::HealthcheckResponse;
+#[cfg(feature = "full")]
+pub mod historical;
+#[cfg(feature = "full")]
+pub use self::historical::Historical;
+#[cfg(feature = "stats")]
+pub mod historical_aggregate_response;
+#[cfg(feature = "stats")]
+pub use self::historical_aggregate_response::HistoricalAggregateResponse;
+#[cfg(feature = "full")]
+pub mod historical_aggregate_response_all_of;
+#[cfg(feature = "full")]
+pub use self::historical_aggregate_response_all_of::HistoricalAggregateResponseAllOf;
+#[cfg(feature = "full")]
 pub mod historical_ddos;
+#[cfg(feature = "full")]
 pub use self::historical_ddos::HistoricalDdos;
+#[cfg(feature = "stats")]
 pub mod historical_ddos_meta;
+#[cfg(feature = "stats")]
 pub use self::historical_ddos_meta::HistoricalDdosMeta;
+#[cfg(feature = "full")]
 pub mod historical_domains;
+#[cfg(feature = "full")]
 pub use self::historical_domains::HistoricalDomains;
+#[cfg(feature = "full")]
 pub mod historical_domains_data;
+#[cfg(feature = "full")]
 pub use self::historical_domains_data::HistoricalDomainsData;
+#[cfg(feature = "stats")]
 pub mod historical_domains_meta;
+#[cfg(feature = "stats")]
 pub use self::historical_domains_meta::HistoricalDomainsMeta;
+#[cfg(feature = "stats")]
 pub mod historical_domains_meta_filters;
+#[cfg(feature = "stats")]
 pub use self::historical_domains_meta_filters::HistoricalDomainsMetaFilters;
+#[cfg(feature = "stats")]
 pub mod historical_domains_response;
+#[cfg(feature = "stats")]
 pub use self::historical_domains_response::HistoricalDomainsResponse;
+#[cfg(feature = "full")]
 pub mod historical_domains_response_all_of;
+#[cfg(feature = "full")]
 pub use self::historical_domains_response_all_of::HistoricalDomainsResponseAllOf;
+#[cfg(feature = "stats")]
+pub mod historical_field_aggregate_response;
+#[cfg(feature = "stats")]
+pub use self::historical_field_aggregate_response::HistoricalFieldAggregateResponse;
+#[cfg(feature = "full")]
+pub mod historical_field_aggregate_response_all_of;
+#[cfg(feature = "full")]
+pub use self::historical_field_aggregate_response_all_of::HistoricalFieldAggregateResponseAllOf;
+#[cfg(feature = "stats")]
+pub mod historical_field_response;
+#[cfg(feature = "stats")]
+pub use self::historical_field_response::HistoricalFieldResponse;
+#[cfg(feature = "full")]
+pub mod historical_field_response_data_field;
+#[cfg(feature = "full")]
+pub use self::historical_field_response_data_field::HistoricalFieldResponseDataField;
+#[cfg(feature = "stats")]
+pub mod historical_field_results_attributes;
+#[cfg(feature = "stats")]
+pub use self::historical_field_results_attributes::HistoricalFieldResultsAttributes;
+#[cfg(feature = "full")]
+pub mod historical_field_results_attributes_all_of;
+#[cfg(feature = "full")]
+pub use self::historical_field_results_attributes_all_of::HistoricalFieldResultsAttributesAllOf;
+#[cfg(feature = "stats")]
+pub mod historical_meta;
+#[cfg(feature = "stats")]
+pub use self::historical_meta::HistoricalMeta;
+#[cfg(feature = "stats")]
 pub mod historical_origins_response;
+#[cfg(feature = "stats")]
 pub use self::historical_origins_response::HistoricalOriginsResponse;
+#[cfg(feature = "full")]
 pub mod historical_origins_response_all_of;
+#[cfg(feature = "full")]
 pub use self::historical_origins_response_all_of::HistoricalOriginsResponseAllOf;
+#[cfg(feature = "stats")]
+pub mod historical_regions_response;
+#[cfg(feature = "stats")]
+pub use self::historical_regions_response::HistoricalRegionsResponse;
+#[cfg(feature = "full")]
+pub mod historical_regions_response_all_of;
+#[cfg(feature = "full")]
+pub use self::historical_regions_response_all_of::HistoricalRegionsResponseAllOf;
+#[cfg(feature = "stats")]
+pub mod historical_response;
+#[cfg(feature = "stats")]
+pub use self::historical_response::HistoricalResponse;
+#[cfg(feature = "full")]
+pub mod historical_response_data_field;
+#[cfg(feature = "full")]
+pub use self::historical_response_data_field::HistoricalResponseDataField;
+#[cfg(feature = "stats")]
+pub mod historical_service;
+#[cfg(feature = "stats")]
+pub use self::historical_service::HistoricalService;
+#[cfg(feature = "stats")]
+pub mod historical_usage_aggregate_response;
+#[cfg(feature = "stats")]
+pub use self::historical_usage_aggregate_response::HistoricalUsageAggregateResponse;
+#[cfg(feature = "stats")]
+pub mod historical_usage_month_response;
+#[cfg(feature = "stats")]
+pub use self::historical_usage_month_response::HistoricalUsageMonthResponse;
+#[cfg(feature = "full")]
+pub mod historical_usage_month_response_all_of;
+#[cfg(feature = "full")]
+pub use self::historical_usage_month_response_all_of::HistoricalUsageMonthResponseAllOf;
+#[cfg(feature = "stats")]
+pub mod historical_usage_month_response_data;
+#[cfg(feature = "stats")]
+pub use self::historical_usage_month_response_data::HistoricalUsageMonthResponseData;
+#[cfg(feature = "stats")]
+pub mod historical_usage_results;
+#[cfg(feature = "stats")]
+pub use self::historical_usage_results::HistoricalUsageResults;
+#[cfg(feature = "stats")]
+pub mod historical_usage_service_response;
+#[cfg(feature = "stats")]
+pub use self::historical_usage_service_response::HistoricalUsageServiceResponse;
+#[cfg(feature = "full")]
+pub mod historical_usage_service_response_all_of;
+#[cfg(feature = "full")]
+pub use self::historical_usage_service_response_all_of::HistoricalUsageServiceResponseAllOf;
+#[cfg(feature = "vcl")]
 pub mod http3;
+#[cfg(feature = "vcl")]
 pub use self::http3::Http3;
+#[cfg(feature = "full")]
 pub mod http3_all_of;
+#[cfg(feature = "full")]
 pub use self::http3_all_of::Http3AllOf;
+#[cfg(feature = "platform")]
 pub mod http_response_format;
+#[cfg(feature = "platform")]
 pub use self::http_response_format::HttpResponseFormat;
+#[cfg(feature = "platform")]
 pub mod http_stream_format;
+#[cfg(feature = "platform")]
 pub use self::http_stream_format::HttpStreamFormat;
+#[cfg(feature = "full")]
 pub mod iam_permission;
+#[cfg(feature = "full")]
 pub use self::iam_permission::IamPermission;
+#[cfg(feature = "full")]
 pub mod iam_role;
+#[cfg(feature = "full")]
 pub use self::iam_role::IamRole;
+#[cfg(feature = "full")]
 pub mod iam_role_all_of;
+#[cfg(feature = "full")]
 pub use self::iam_role_all_of::IamRoleAllOf;
+#[cfg(feature = "full")]
 pub mod iam_service_group;
+#[cfg(feature = "full")]
 pub use self::iam_service_group::IamServiceGroup;
+#[cfg(feature = "full")]
 pub mod iam_service_group_all_of;
+#[cfg(feature = "full")]
 pub use self::iam_service_group_all_of::IamServiceGroupAllOf;
+#[cfg(feature = "full")]
 pub mod iam_user_group;
+#[cfg(feature = "full")]
 pub use self::iam_user_group::IamUserGroup;
+#[cfg(feature = "full")]
 pub mod iam_user_group_all_of;
+#[cfg(feature = "full")]
 pub use self::iam_user_group_all_of::IamUserGroupAllOf;
+#[cfg(feature = "full")]
 pub mod included_with_tls_configuration;
+#[cfg(feature = "full")]
 pub use self::included_with_tls_configuration::IncludedWithTlsConfiguration;
+#[cfg(feature = "full")]
 pub mod included_with_tls_configuration_item;
+#[cfg(feature = "full")]
 pub use self::included_with_tls_configuration_item::IncludedWithTlsConfigurationItem;
+#[cfg(feature = "waf")]
 pub mod included_with_waf_active_rule_item;
+#[cfg(feature = "waf")]
 pub use self::included_with_waf_active_rule_item::IncludedWithWafActiveRuleItem;
+#[cfg(feature = "waf")]
 pub mod included_with_waf_exclusion_item;
+#[cfg(feature = "waf")]
 pub use self::included_with_waf_exclusion_item::IncludedWithWafExclusionItem;
+#[cfg(feature = "waf")]
 pub mod included_with_waf_firewall_version_item;
+#[cfg(feature = "waf")]
 pub use self::included_with_waf_firewall_version_item::IncludedWithWafFirewallVersionItem;
+#[cfg(feature = "waf")]
 pub mod included_with_waf_rule_item;
+#[cfg(feature = "waf")]
 pub use self::included_with_waf_rule_item::IncludedWithWafRuleItem;
+#[cfg(feature = "vcl")]
 pub mod inline_object;
+#[cfg(feature = "vcl")]
 pub use self::inline_object::InlineObject;
+#[cfg(feature = "vcl")]
 pub mod inline_object_1;
+#[cfg(feature = "vcl")]
 pub use self::inline_object_1::InlineObject1;
 pub mod inline_response_200;
 pub use self::inline_response_200::InlineResponse200;
+#[cfg(feature = "accounts")]
 pub mod inline_response_200_1;
+#[cfg(feature = "accounts")]
 pub use self::inline_response_200_1::InlineResponse2001;
+#[cfg(feature = "domains")]
 pub mod inline_response_200_2;
+#[cfg(feature = "domains")]
 pub use self::inline_response_200_2::InlineResponse2002;
+#[cfg(feature = "stores")]
 pub mod inline_response_200_3;
+#[cfg(feature = "stores")]
 pub use self::inline_response_200_3::InlineResponse2003;
+#[cfg(feature = "stores")]
 pub mod inline_response_200_3_meta;
+#[cfg(feature = "stores")]
 pub use self::inline_response_200_3_meta::InlineResponse2003Meta;
+#[cfg(feature = "stores")]
 pub mod inline_response_200_4;
+#[cfg(feature = "stores")]
 pub use self::inline_response_200_4::InlineResponse2004;
+#[cfg(feature = "stores")]
 pub mod inline_response_200_4_meta;
+#[cfg(feature = "stores")]
 pub use self::inline_response_200_4_meta::InlineResponse2004Meta;
+#[cfg(feature = "stores")]
 pub mod inline_response_200_5;
+#[cfg(feature = "stores")]
 pub use self::inline_response_200_5::InlineResponse2005;
+#[cfg(feature = "stores")]
 pub mod inline_response_200_6;
+#[cfg(feature = "stores")]
 pub use self::inline_response_200_6::InlineResponse2006;
+#[cfg(feature = "iam")]
 pub mod inline_response_200_7;
+#[cfg(feature = "iam")]
 pub use self::inline_response_200_7::InlineResponse2007;
+#[cfg(feature = "accounts")]
 pub mod inline_response_400;
+#[cfg(feature = "accounts")]
 pub use self::inline_response_400::InlineResponse400;
+#[cfg(feature = "accounts")]
 pub mod invitation;
+#[cfg(feature = "accounts")]
 pub use self::invitation::Invitation;
+#[cfg(feature = "accounts")]
 pub mod invitation_data;
+#[cfg(feature = "accounts")]
 pub use self::invitation_data::InvitationData;
+#[cfg(feature = "accounts")]
 pub mod invitation_data_attributes;
+#[cfg(feature = "accounts")]
 pub use self::invitation_data_attributes::InvitationDataAttributes;
+#[cfg(feature = "accounts")]
 pub mod invitation_response;
+#[cfg(feature = "accounts")]
 pub use self::invitation_response::InvitationResponse;
+#[cfg(feature = "full")]
 pub mod invitation_response_all_of;
+#[cfg(feature = "full")]
 pub use self::invitation_response_all_of::InvitationResponseAllOf;
+#[cfg(feature = "accounts")]
 pub mod invitation_response_data;
+#[cfg(feature = "accounts")]
 pub use self::invitation_response_data::InvitationResponseData;
+#[cfg(feature = "full")]
 pub mod invitation_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::invitation_response_data_all_of::InvitationResponseDataAllOf;
+#[cfg(feature = "accounts")]
 pub mod invitations_response;
+#[cfg(feature = "accounts")]
 pub use self::invitations_response::InvitationsResponse;
+#[cfg(feature = "full")]
 pub mod invitations_response_all_of;
+#[cfg(feature = "full")]
 pub use self::invitations_response_all_of::InvitationsResponseAllOf;
+#[cfg(feature = "billing")]
 pub mod invoice;
+#[cfg(feature = "billing")]
 pub use self::invoice::Invoice;
+#[cfg(feature = "billing")]
 pub mod invoicelineitems;
+#[cfg(feature = "billing")]
 pub use self::invoicelineitems::Invoicelineitems;
+#[cfg(feature = "full")]
 pub mod legacy_waf_configuration_set;
+#[cfg(feature = "full")]
 pub use self::legacy_waf_configuration_set::LegacyWafConfigurationSet;
+#[cfg(feature = "full")]
 pub mod legacy_waf_firewall;
+#[cfg(feature = "full")]
 pub use self::legacy_waf_firewall::LegacyWafFirewall;
+#[cfg(feature = "full")]
 pub mod legacy_waf_owasp;
+#[cfg(feature = "full")]
 pub use self::legacy_waf_owasp::LegacyWafOwasp;
+#[cfg(feature = "full")]
 pub mod legacy_waf_rule;
+#[cfg(feature = "full")]
 pub use self::legacy_waf_rule::LegacyWafRule;
+#[cfg(feature = "full")]
 pub mod legacy_waf_rule_status;
+#[cfg(feature = "full")]
 pub use self::legacy_waf_rule_status::LegacyWafRuleStatus;
+#[cfg(feature = "full")]
 pub mod legacy_waf_ruleset;
+#[cfg(feature = "full")]
 pub use self::legacy_waf_ruleset::LegacyWafRuleset;
+#[cfg(feature = "full")]
 pub mod legacy_waf_tag;
+#[cfg(feature = "full")]
 pub use self::legacy_waf_tag::LegacyWafTag;
+#[cfg(feature = "full")]
 pub mod legacy_waf_update_status;
+#[cfg(feature = "full")]
 pub use self::legacy_waf_update_status::LegacyWafUpdateStatus;
+#[cfg(feature = "full")]
 pub mod line_item_data;
+#[cfg(feature = "full")]
 pub use self::line_item_data::LineItemData;
+#[cfg(feature = "stats")]
 pub mod list_dashboards_response;
+#[cfg(feature = "stats")]
 pub use self::list_dashboards_response::ListDashboardsResponse;
+#[cfg(feature = "billing")]
 pub mod list_eom_invoices_response;
+#[cfg(feature = "billing")]
 pub use self::list_eom_invoices_response::ListEomInvoicesResponse;
+#[cfg(feature = "full")]
 pub mod listinvoices;
+#[cfg(feature = "full")]
 pub use self::listinvoices::Listinvoices;
+#[cfg(feature = "full")]
 pub mod logging_address_and_port;
+#[cfg(feature = "full")]
 pub use self::logging_address_and_port::LoggingAddressAndPort;
+#[cfg(feature = "full")]
 pub mod logging_azureblob_additional;
+#[cfg(feature = "full")]
 pub use self::logging_azureblob_additional::LoggingAzureblobAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_azureblob_response;
+#[cfg(feature = "logging")]
 pub use self::logging_azureblob_response::LoggingAzureblobResponse;
+#[cfg(feature = "full")]
 pub mod logging_bigquery_additional;
+#[cfg(feature = "full")]
 pub use self::logging_bigquery_additional::LoggingBigqueryAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_bigquery_response;
+#[cfg(feature = "logging")]
 pub use self::logging_bigquery_response::LoggingBigqueryResponse;
+#[cfg(feature = "full")]
 pub mod logging_cloudfiles_additional;
+#[cfg(feature = "full")]
 pub use self::logging_cloudfiles_additional::LoggingCloudfilesAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_cloudfiles_response;
+#[cfg(feature = "logging")]
 pub use self::logging_cloudfiles_response::LoggingCloudfilesResponse;
+#[cfg(feature = "full")]
 pub mod logging_common;
+#[cfg(feature = "full")]
 pub use self::logging_common::LoggingCommon;
+#[cfg(feature = "full")]
 pub mod logging_common_response;
+#[cfg(feature = "full")]
 pub use self::logging_common_response::LoggingCommonResponse;
+#[cfg(feature = "full")]
 pub mod logging_common_response_all_of;
+#[cfg(feature = "full")]
 pub use self::logging_common_response_all_of::LoggingCommonResponseAllOf;
+#[cfg(feature = "full")]
 pub mod logging_common_response_all_of_1;
+#[cfg(feature = "full")]
 pub use self::logging_common_response_all_of_1::LoggingCommonResponseAllOf1;
+#[cfg(feature = "full")]
 pub mod logging_datadog_additional;
+#[cfg(feature = "full")]
 pub use self::logging_datadog_additional::LoggingDatadogAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_datadog_response;
+#[cfg(feature = "logging")]
 pub use self::logging_datadog_response::LoggingDatadogResponse;
+#[cfg(feature = "full")]
 pub mod logging_digitalocean_additional;
+#[cfg(feature = "full")]
 pub use self::logging_digitalocean_additional::LoggingDigitaloceanAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_digitalocean_response;
+#[cfg(feature = "logging")]
 pub use self::logging_digitalocean_response::LoggingDigitaloceanResponse;
+#[cfg(feature = "full")]
 pub mod logging_elasticsearch_additional;
+#[cfg(feature = "full")]
 pub use self::logging_elasticsearch_additional::LoggingElasticsearchAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_elasticsearch_response;
+#[cfg(feature = "logging")]
 pub use self::logging_elasticsearch_response::LoggingElasticsearchResponse;
+#[cfg(feature = "full")]
 pub mod logging_format_version_integer;
+#[cfg(feature = "full")]
 pub use self::logging_format_version_integer::LoggingFormatVersionInteger;
+#[cfg(feature = "full")]
 pub mod logging_format_version_string;
+#[cfg(feature = "full")]
 pub use self::logging_format_version_string::LoggingFormatVersionString;
+#[cfg(feature = "full")]
 pub mod logging_ftp_additional;
+#[cfg(feature = "full")]
 pub use self::logging_ftp_additional::LoggingFtpAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_ftp_response;
+#[cfg(feature = "logging")]
 pub use self::logging_ftp_response::LoggingFtpResponse;
+#[cfg(feature = "full")]
 pub mod logging_ftp_response_all_of;
+#[cfg(feature = "full")]
 pub use self::logging_ftp_response_all_of::LoggingFtpResponseAllOf;
+#[cfg(feature = "full")]
 pub mod logging_gcs_additional;
+#[cfg(feature = "full")]
 pub use self::logging_gcs_additional::LoggingGcsAdditional;
+#[cfg(feature = "full")]
 pub mod logging_gcs_common;
+#[cfg(feature = "full")]
 pub use self::logging_gcs_common::LoggingGcsCommon;
+#[cfg(feature = "logging")]
 pub mod logging_gcs_response;
+#[cfg(feature = "logging")]
 pub use self::logging_gcs_response::LoggingGcsResponse;
+#[cfg(feature = "full")]
 pub mod logging_generic_common;
+#[cfg(feature = "full")]
 pub use self::logging_generic_common::LoggingGenericCommon;
+#[cfg(feature = "full")]
 pub mod logging_generic_common_response;
+#[cfg(feature = "full")]
 pub use self::logging_generic_common_response::LoggingGenericCommonResponse;
+#[cfg(feature = "full")]
 pub mod logging_generic_common_response_all_of;
+#[cfg(feature = "full")]
 pub use self::logging_generic_common_response_all_of::LoggingGenericCommonResponseAllOf;
+#[cfg(feature = "full")]
 pub mod logging_generic_common_response_all_of_1;
+#[cfg(feature = "full")]
 pub use self::logging_generic_common_response_all_of_1::LoggingGenericCommonResponseAllOf1;
+#[cfg(feature = "full")]
 pub mod logging_google_pubsub_additional;
+#[cfg(feature = "full")]
 pub use self::logging_google_pubsub_additional::LoggingGooglePubsubAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_google_pubsub_response;
+#[cfg(feature = "logging")]
 pub use self::logging_google_pubsub_response::LoggingGooglePubsubResponse;
+#[cfg(feature = "full")]
 pub mod logging_heroku_additional;
+#[cfg(feature = "full")]
 pub use self::logging_heroku_additional::LoggingHerokuAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_heroku_response;
+#[cfg(feature = "logging")]
 pub use self::logging_heroku_response::LoggingHerokuResponse;
+#[cfg(feature = "full")]
 pub mod logging_honeycomb_additional;
+#[cfg(feature = "full")]
 pub use self::logging_honeycomb_additional::LoggingHoneycombAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_honeycomb_response;
+#[cfg(feature = "logging")]
 pub use self::logging_honeycomb_response::LoggingHoneycombResponse;
+#[cfg(feature = "full")]
 pub mod logging_https_additional;
+#[cfg(feature = "full")]
 pub use self::logging_https_additional::LoggingHttpsAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_https_response;
+#[cfg(feature = "logging")]
 pub use self::logging_https_response::LoggingHttpsResponse;
+#[cfg(feature = "full")]
 pub mod logging_kafka_additional;
+#[cfg(feature = "full")]
 pub use self::logging_kafka_additional::LoggingKafkaAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_kafka_response;
+#[cfg(feature = "logging")]
 pub use self::logging_kafka_response::LoggingKafkaResponse;
+#[cfg(feature = "logging")]
 pub mod logging_kafka_response_post;
+#[cfg(feature = "logging")]
 pub use self::logging_kafka_response_post::LoggingKafkaResponsePost;
+#[cfg(feature = "full")]
 pub mod logging_kinesis_additional;
+#[cfg(feature = "full")]
 pub use self::logging_kinesis_additional::LoggingKinesisAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_kinesis_response;
+#[cfg(feature = "logging")]
 pub use self::logging_kinesis_response::LoggingKinesisResponse;
+#[cfg(feature = "full")]
 pub mod logging_logentries_additional;
+#[cfg(feature = "full")]
 pub use self::logging_logentries_additional::LoggingLogentriesAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_logentries_response;
+#[cfg(feature = "logging")]
 pub use self::logging_logentries_response::LoggingLogentriesResponse;
+#[cfg(feature = "full")]
 pub mod logging_loggly_additional;
+#[cfg(feature = "full")]
 pub use self::logging_loggly_additional::LoggingLogglyAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_loggly_response;
+#[cfg(feature = "logging")]
 pub use self::logging_loggly_response::LoggingLogglyResponse;
+#[cfg(feature = "full")]
 pub mod logging_logshuttle_additional;
+#[cfg(feature = "full")]
 pub use self::logging_logshuttle_additional::LoggingLogshuttleAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_logshuttle_response;
+#[cfg(feature = "logging")]
 pub use self::logging_logshuttle_response::LoggingLogshuttleResponse;
+#[cfg(feature = "logging")]
 pub mod logging_message_type;
+#[cfg(feature = "logging")]
 pub use self::logging_message_type::LoggingMessageType;
+#[cfg(feature = "full")]
 pub mod logging_newrelic_additional;
+#[cfg(feature = "full")]
 pub use self::logging_newrelic_additional::LoggingNewrelicAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_newrelic_response;
+#[cfg(feature = "logging")]
 pub use self::logging_newrelic_response::LoggingNewrelicResponse;
+#[cfg(feature = "full")]
 pub mod logging_newrelicotlp_additional;
+#[cfg(feature = "full")]
 pub use self::logging_newrelicotlp_additional::LoggingNewrelicotlpAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_newrelicotlp_response;
+#[cfg(feature = "logging")]
 pub use self::logging_newrelicotlp_response::LoggingNewrelicotlpResponse;
+#[cfg(feature = "full")]
 pub mod logging_openstack_additional;
+#[cfg(feature = "full")]
 pub use self::logging_openstack_additional::LoggingOpenstackAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_openstack_response;
+#[cfg(feature = "logging")]
 pub use self::logging_openstack_response::LoggingOpenstackResponse;
+#[cfg(feature = "logging")]
 pub mod logging_papertrail_response;
+#[cfg(feature = "logging")]
 pub use self::logging_papertrail_response::LoggingPapertrailResponse;
+#[cfg(feature = "logging")]
 pub mod logging_placement;
+#[cfg(feature = "logging")]
 pub use self::logging_placement::LoggingPlacement;
+#[cfg(feature = "full")]
 pub mod logging_request_caps_common;
+#[cfg(feature = "full")]
 pub use self::logging_request_caps_common::LoggingRequestCapsCommon;
+#[cfg(feature = "full")]
 pub mod logging_s3_additional;
+#[cfg(feature = "full")]
 pub use self::logging_s3_additional::LoggingS3Additional;
+#[cfg(feature = "logging")]
 pub mod logging_s3_response;
+#[cfg(feature = "logging")]
 pub use self::logging_s3_response::LoggingS3Response;
+#[cfg(feature = "full")]
 pub mod logging_scalyr_additional;
+#[cfg(feature = "full")]
 pub use self::logging_scalyr_additional::LoggingScalyrAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_scalyr_response;
+#[cfg(feature = "logging")]
 pub use self::logging_scalyr_response::LoggingScalyrResponse;
+#[cfg(feature = "full")]
 pub mod logging_sftp_additional;
+#[cfg(feature = "full")]
 pub use self::logging_sftp_additional::LoggingSftpAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_sftp_response;
+#[cfg(feature = "logging")]
 pub use self::logging_sftp_response::LoggingSftpResponse;
+#[cfg(feature = "full")]
 pub mod logging_sftp_response_all_of;
+#[cfg(feature = "full")]
 pub use self::logging_sftp_response_all_of::LoggingSftpResponseAllOf;
+#[cfg(feature = "full")]
 pub mod logging_splunk_additional;
+#[cfg(feature = "full")]
 pub use self::logging_splunk_additional::LoggingSplunkAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_splunk_response;
+#[cfg(feature = "logging")]
 pub use self::logging_splunk_response::LoggingSplunkResponse;
+#[cfg(feature = "full")]
 pub mod logging_sumologic_additional;
+#[cfg(feature = "full")]
 pub use self::logging_sumologic_additional::LoggingSumologicAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_sumologic_response;
+#[cfg(feature = "logging")]
 pub use self::logging_sumologic_response::LoggingSumologicResponse;
+#[cfg(feature = "full")]
 pub mod logging_syslog_additional;
+#[cfg(feature = "full")]
 pub use self::logging_syslog_additional::LoggingSyslogAdditional;
+#[cfg(feature = "logging")]
 pub mod logging_syslog_response;
+#[cfg(feature = "logging")]
 pub use self::logging_syslog_response::LoggingSyslogResponse;
+#[cfg(feature = "full")]
 pub mod logging_tls_common;
+#[cfg(feature = "full")]
 pub use self::logging_tls_common::LoggingTlsCommon;
+#[cfg(feature = "logging")]
 pub mod logging_use_tls_string;
+#[cfg(feature = "logging")]
 pub use self::logging_use_tls_string::LoggingUseTlsString;
+#[cfg(feature = "billing")]
 pub mod metadata;
+#[cfg(feature = "billing")]
 pub use self::metadata::Metadata;
+#[cfg(feature = "billing")]
 pub mod mtd_invoice_response;
+#[cfg(feature = "billing")]
 pub use self::mtd_invoice_response::MtdInvoiceResponse;
+#[cfg(feature = "full")]
 pub mod mtdinvoice;
+#[cfg(feature = "full")]
 pub use self::mtdinvoice::Mtdinvoice;
+#[cfg(feature = "billing")]
 pub mod mtdlineitems;
+#[cfg(feature = "billing")]
 pub use self::mtdlineitems::Mtdlineitems;
+#[cfg(feature = "tls")]
 pub mod mutual_authentication;
+#[cfg(feature = "tls")]
 pub use self::mutual_authentication::MutualAuthentication;
+#[cfg(feature = "tls")]
 pub mod mutual_authentication_data;
+#[cfg(feature = "tls")]
 pub use self::mutual_authentication_data::MutualAuthenticationData;
+#[cfg(feature = "tls")]
 pub mod mutual_authentication_data_attributes;
+#[cfg(feature = "tls")]
 pub use self::mutual_authentication_data_attributes::MutualAuthenticationDataAttributes;
+#[cfg(feature = "tls")]
 pub mod mutual_authentication_response;
+#[cfg(feature = "tls")]
 pub use self::mutual_authentication_response::MutualAuthenticationResponse;
+#[cfg(feature = "tls")]
 pub mod mutual_authentication_response_attributes;
+#[cfg(feature = "tls")]
 pub use self::mutual_authentication_response_attributes::MutualAuthenticationResponseAttributes;
+#[cfg(feature = "full")]
 pub mod mutual_authentication_response_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::mutual_authentication_response_attributes_all_of::MutualAuthenticationResponseAttributesAllOf;
+#[cfg(feature = "tls")]
 pub mod mutual_authentication_response_data;
+#[cfg(feature = "tls")]
 pub use self::mutual_authentication_response_data::MutualAuthenticationResponseData;
+#[cfg(feature = "full")]
 pub mod mutual_authentication_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::mutual_authentication_response_data_all_of::MutualAuthenticationResponseDataAllOf;
+#[cfg(feature = "tls")]
 pub mod mutual_authentications_response;
+#[cfg(feature = "tls")]
 pub use self::mutual_authentications_response::MutualAuthenticationsResponse;
+#[cfg(feature = "full")]
 pub mod mutual_authentications_response_all_of;
+#[cfg(feature = "full")]
 pub use self::mutual_authentications_response_all_of::MutualAuthenticationsResponseAllOf;
+#[cfg(feature = "stats")]
 pub mod origin_inspector;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector::OriginInspector;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_dimensions;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_dimensions::OriginInspectorDimensions;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_entry;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_entry::OriginInspectorEntry;
+#[cfg(feature = "full")]
 pub mod origin_inspector_historical;
+#[cfg(feature = "full")]
 pub use self::origin_inspector_historical::OriginInspectorHistorical;
+#[cfg(feature = "full")]
 pub mod origin_inspector_historical_data;
+#[cfg(feature = "full")]
 pub use self::origin_inspector_historical_data::OriginInspectorHistoricalData;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_historical_meta;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_historical_meta::OriginInspectorHistoricalMeta;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_historical_meta_filters;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_historical_meta_filters::OriginInspectorHistoricalMetaFilters;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_measurements;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_measurements::OriginInspectorMeasurements;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_realtime_entry;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_realtime_entry::OriginInspectorRealtimeEntry;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_realtime_entry_recorded;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_realtime_entry_recorded::OriginInspectorRealtimeEntryRecorded;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_subsequent_request_timestamp;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_subsequent_request_timestamp::OriginInspectorSubsequentRequestTimestamp;
+#[cfg(feature = "stats")]
 pub mod origin_inspector_values;
+#[cfg(feature = "stats")]
 pub use self::origin_inspector_values::OriginInspectorValues;
+#[cfg(feature = "full")]
 pub mod package;
+#[cfg(feature = "full")]
 pub use self::package::Package;
+#[cfg(feature = "compute")]
 pub mod package_metadata;
+#[cfg(feature = "compute")]
 pub use self::package_metadata::PackageMetadata;
+#[cfg(feature = "compute")]
 pub mod package_response;
+#[cfg(feature = "compute")]
 pub use self::package_response::PackageResponse;
+#[cfg(feature = "full")]
 pub mod package_response_all_of;
+#[cfg(feature = "full")]
 pub use self::package_response_all_of::PackageResponseAllOf;
+#[cfg(feature = "accounts")]
 pub mod pagination;
+#[cfg(feature = "accounts")]
 pub use self::pagination::Pagination;
+#[cfg(feature = "stores")]
 pub mod pagination_cursor_meta;
+#[cfg(feature = "stores")]
 pub use self::pagination_cursor_meta::PaginationCursorMeta;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls", feature = "waf"))]
 pub mod pagination_links;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls", feature = "waf"))]
 pub use self::pagination_links::PaginationLinks;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls", feature = "waf"))]
 pub mod pagination_meta;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls", feature = "waf"))]
 pub use self::pagination_meta::PaginationMeta;
+#[cfg(feature = "iam")]
 pub mod permission;
+#[cfg(feature = "iam")]
 pub use self::permission::Permission;
+#[cfg(feature = "full")]
 pub mod platform_ddos_data_items;
+#[cfg(feature = "full")]
 pub use self::platform_ddos_data_items::PlatformDdosDataItems;
+#[cfg(feature = "stats")]
 pub mod platform_ddos_entry;
+#[cfg(feature = "stats")]
 pub use self::platform_ddos_entry::PlatformDdosEntry;
+#[cfg(feature = "stats")]
 pub mod platform_ddos_response;
+#[cfg(feature = "stats")]
 pub use self::platform_ddos_response::PlatformDdosResponse;
+#[cfg(feature = "full")]
 pub mod platform_ddos_response_all_of;
+#[cfg(feature = "full")]
 pub use self::platform_ddos_response_all_of::PlatformDdosResponseAllOf;
+#[cfg(feature = "full")]
 pub mod pool_additional;
+#[cfg(feature = "full")]
 pub use self::pool_additional::PoolAdditional;
+#[cfg(feature = "backends")]
 pub mod pool_response;
+#[cfg(feature = "backends")]
 pub use self::pool_response::PoolResponse;
+#[cfg(feature = "full")]
 pub mod pool_response_all_of;
+#[cfg(feature = "full")]
 pub use self::pool_response_all_of::PoolResponseAllOf;
+#[cfg(feature = "full")]
 pub mod pool_response_common;
+#[cfg(feature = "full")]
 pub use self::pool_response_common::PoolResponseCommon;
+#[cfg(feature = "backends")]
 pub mod pool_response_post;
+#[cfg(feature = "backends")]
 pub use self::pool_response_post::PoolResponsePost;
+#[cfg(feature = "full")]
 pub mod pool_response_post_all_of;
+#[cfg(feature = "full")]
 pub use self::pool_response_post_all_of::PoolResponsePostAllOf;
+#[cfg(feature = "platform")]
 pub mod pop;
+#[cfg(feature = "platform")]
 pub use self::pop::Pop;
+#[cfg(feature = "platform")]
 pub mod pop_coordinates;
+#[cfg(feature = "platform")]
 pub use self::pop_coordinates::PopCoordinates;
+#[cfg(feature = "platform")]
 pub mod public_ip_list;
+#[cfg(feature = "platform")]
 pub use self::public_ip_list::PublicIpList;
+#[cfg(feature = "platform")]
 pub mod publish_item;
+#[cfg(feature = "platform")]
 pub use self::publish_item::PublishItem;
+#[cfg(feature = "platform")]
 pub mod publish_item_formats;
+#[cfg(feature = "platform")]
 pub use self::publish_item_formats::PublishItemFormats;
+#[cfg(feature = "platform")]
 pub mod publish_request;
+#[cfg(feature = "platform")]
 pub use self::publish_request::PublishRequest;
+#[cfg(feature = "full")]
 pub mod purge_keys;
+#[cfg(feature = "full")]
 pub use self::purge_keys::PurgeKeys;
+#[cfg(feature = "purge")]
 pub mod purge_response;
+#[cfg(feature = "purge")]
 pub use self::purge_response::PurgeResponse;
+#[cfg(feature = "full")]
 pub mod rate_limiter;
+#[cfg(feature = "full")]
 pub use self::rate_limiter::RateLimiter;
+#[cfg(feature = "vcl")]
 pub mod rate_limiter_response;
+#[cfg(feature = "vcl")]
 pub use self::rate_limiter_response::RateLimiterResponse;
+#[cfg(feature = "full")]
 pub mod rate_limiter_response_all_of;
+#[cfg(feature = "full")]
 pub use self::rate_limiter_response_all_of::RateLimiterResponseAllOf;
+#[cfg(feature = "accounts")]
 pub mod read_only_customer_id;
+#[cfg(feature = "accounts")]
 pub use self::read_only_customer_id::ReadOnlyCustomerId;
+#[cfg(feature = "accounts")]
 pub mod read_only_id;
+#[cfg(feature = "accounts")]
 pub use self::read_only_id::ReadOnlyId;
+#[cfg(feature = "full")]
 pub mod read_only_service_id;
+#[cfg(feature = "full")]
 pub use self::read_only_service_id::ReadOnlyServiceId;
+#[cfg(feature = "accounts")]
 pub mod read_only_user_id;
+#[cfg(feature = "accounts")]
 pub use self::read_only_user_id::ReadOnlyUserId;
+#[cfg(feature = "full")]
 pub mod read_only_version;
+#[cfg(feature = "full")]
 pub use self::read_only_version::ReadOnlyVersion;
+#[cfg(feature = "stats")]
 pub mod realtime;
+#[cfg(feature = "stats")]
 pub use self::realtime::Realtime;
+#[cfg(feature = "stats")]
 pub mod realtime_entry;
+#[cfg(feature = "stats")]
 pub use self::realtime_entry::RealtimeEntry;
+#[cfg(feature = "stats")]
 pub mod realtime_entry_aggregated;
+#[cfg(feature = "stats")]
 pub use self::realtime_entry_aggregated::RealtimeEntryAggregated;
+#[cfg(feature = "stats")]
 pub mod realtime_entry_recorded;
+#[cfg(feature = "stats")]
 pub use self::realtime_entry_recorded::RealtimeEntryRecorded;
+#[cfg(feature = "stats")]
 pub mod realtime_measurements;
+#[cfg(feature = "stats")]
 pub use self::realtime_measurements::RealtimeMeasurements;
+#[cfg(feature = "stats")]
 pub mod recorded_timestamp;
+#[cfg(feature = "stats")]
 pub use self::recorded_timestamp::RecordedTimestamp;
+#[cfg(feature = "full")]
 pub mod relationship_common_name;
+#[cfg(feature = "full")]
 pub use self::relationship_common_name::RelationshipCommonName;
+#[cfg(feature = "billing")]
 pub mod relationship_customer;
+#[cfg(feature = "billing")]
 pub use self::relationship_customer::RelationshipCustomer;
+#[cfg(any(feature = "accounts", feature = "billing"))]
 pub mod relationship_customer_customer;
+#[cfg(any(feature = "accounts", feature = "billing"))]
 pub use self::relationship_customer_customer::RelationshipCustomerCustomer;
+#[cfg(feature = "full")]
 pub mod relationship_default_ecdsa_tls_certificate;
+#[cfg(feature = "full")]
 pub use self::relationship_default_ecdsa_tls_certificate::RelationshipDefaultEcdsaTlsCertificate;
+#[cfg(feature = "full")]
 pub mod relationship_default_ecdsa_tls_certificate_default_ecdsa_certificate;
+#[cfg(feature = "full")]
 pub use self::relationship_default_ecdsa_tls_certificate_default_ecdsa_certificate::RelationshipDefaultEcdsaTlsCertificateDefaultEcdsaCertificate;
+#[cfg(feature = "full")]
 pub mod relationship_default_ecdsa_tls_certificate_default_ecdsa_certificate_data;
+#[cfg(feature = "full")]
 pub use self::relationship_default_ecdsa_tls_certificate_default_ecdsa_certificate_data::RelationshipDefaultEcdsaTlsCertificateDefaultEcdsaCertificateData;
+#[cfg(feature = "full")]
 pub mod relationship_default_tls_certificate;
+#[cfg(feature = "full")]
 pub use self::relationship_default_tls_certificate::RelationshipDefaultTlsCertificate;
+#[cfg(feature = "full")]
 pub mod relationship_default_tls_certificate_default_certificate;
+#[cfg(feature = "full")]
 pub use self::relationship_default_tls_certificate_default_certificate::RelationshipDefaultTlsCertificateDefaultCertificate;
+#[cfg(feature = "full")]
 pub mod relationship_default_tls_certificate_default_certificate_data;
+#[cfg(feature = "full")]
 pub use self::relationship_default_tls_certificate_default_certificate_data::RelationshipDefaultTlsCertificateDefaultCertificateData;
+#[cfg(any(feature = "accounts", feature = "billing"))]
 pub mod relationship_member_customer;
+#[cfg(any(feature = "accounts", feature = "billing"))]
 pub use self::relationship_member_customer::RelationshipMemberCustomer;
+#[cfg(feature = "full")]
 pub mod relationship_member_mutual_authentication;
+#[cfg(feature = "full")]
 pub use self::relationship_member_mutual_authentication::RelationshipMemberMutualAuthentication;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls"))]
 pub mod relationship_member_service;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls"))]
 pub use self::relationship_member_service::RelationshipMemberService;
+#[cfg(feature = "accounts")]
 pub mod relationship_member_service_invitation;
+#[cfg(feature = "accounts")]
 pub use self::relationship_member_service_invitation::RelationshipMemberServiceInvitation;
+#[cfg(feature = "tls")]
 pub mod relationship_member_tls_activation;
+#[cfg(feature = "tls")]
 pub use self::relationship_member_tls_activation::RelationshipMemberTlsActivation;
+#[cfg(feature = "full")]
 pub mod relationship_member_tls_bulk_certificate;
+#[cfg(feature = "full")]
 pub use self::relationship_member_tls_bulk_certificate::RelationshipMemberTlsBulkCertificate;
+#[cfg(feature = "tls")]
 pub mod relationship_member_tls_certificate;
+#[cfg(feature = "tls")]
 pub use self::relationship_member_tls_certificate::RelationshipMemberTlsCertificate;
+#[cfg(feature = "tls")]
 pub mod relationship_member_tls_configuration;
+#[cfg(feature = "tls")]
 pub use self::relationship_member_tls_configuration::RelationshipMemberTlsConfiguration;
+#[cfg(feature = "tls")]
 pub mod relationship_member_tls_domain;
+#[cfg(feature = "tls")]
 pub use self::relationship_member_tls_domain::RelationshipMemberTlsDomain;
+#[cfg(feature = "tls")]
 pub mod relationship_member_tls_private_key;
+#[cfg(feature = "tls")]
 pub use self::relationship_member_tls_private_key::RelationshipMemberTlsPrivateKey;
+#[cfg(feature = "tls")]
 pub mod relationship_member_tls_subscription;
+#[cfg(feature = "tls")]
 pub use self::relationship_member_tls_subscription::RelationshipMemberTlsSubscription;
+#[cfg(feature = "waf")]
 pub mod relationship_member_waf_active_rule;
+#[cfg(feature = "waf")]
 pub use self::relationship_member_waf_active_rule::RelationshipMemberWafActiveRule;
+#[cfg(feature = "full")]
 pub mod relationship_member_waf_firewall;
+#[cfg(feature = "full")]
 pub use self::relationship_member_waf_firewall::RelationshipMemberWafFirewall;
+#[cfg(feature = "waf")]
 pub mod relationship_member_waf_firewall_version;
+#[cfg(feature = "waf")]
 pub use self::relationship_member_waf_firewall_version::RelationshipMemberWafFirewallVersion;
+#[cfg(feature = "waf")]
 pub mod relationship_member_waf_rule;
+#[cfg(feature = "waf")]
 pub use self::relationship_member_waf_rule::RelationshipMemberWafRule;
+#[cfg(feature = "waf")]
 pub mod relationship_member_waf_rule_revision;
+#[cfg(feature = "waf")]
 pub use self::relationship_member_waf_rule_revision::RelationshipMemberWafRuleRevision;
+#[cfg(feature = "waf")]
 pub mod relationship_member_waf_tag;
+#[cfg(feature = "waf")]
 pub use self::relationship_member_waf_tag::RelationshipMemberWafTag;
+#[cfg(feature = "full")]
 pub mod relationship_mutual_authentication;
+#[cfg(feature = "full")]
 pub use self::relationship_mutual_authentication::RelationshipMutualAuthentication;
+#[cfg(feature = "full")]
 pub mod relationship_mutual_authentication_mutual_authentication;
+#[cfg(feature = "full")]
 pub use self::relationship_mutual_authentication_mutual_authentication::RelationshipMutualAuthenticationMutualAuthentication;
+#[cfg(feature = "full")]
 pub mod relationship_mutual_authentications;
+#[cfg(feature = "full")]
 pub use self::relationship_mutual_authentications::RelationshipMutualAuthentications;
+#[cfg(feature = "full")]
 pub mod relationship_mutual_authentications_mutual_authentications;
+#[cfg(feature = "full")]
 pub use self::relationship_mutual_authentications_mutual_authentications::RelationshipMutualAuthenticationsMutualAuthentications;
+#[cfg(feature = "full")]
 pub mod relationship_service;
+#[cfg(feature = "full")]
 pub use self::relationship_service::RelationshipService;
+#[cfg(feature = "full")]
 pub mod relationship_service_invitations;
+#[cfg(feature = "full")]
 pub use self::relationship_service_invitations::RelationshipServiceInvitations;
+#[cfg(feature = "accounts")]
 pub mod relationship_service_invitations_create;
+#[cfg(feature = "accounts")]
 pub use self::relationship_service_invitations_create::RelationshipServiceInvitationsCreate;
+#[cfg(feature = "accounts")]
 pub mod relationship_service_invitations_create_service_invitations;
+#[cfg(feature = "accounts")]
 pub use self::relationship_service_invitations_create_service_invitations::RelationshipServiceInvitationsCreateServiceInvitations;
+#[cfg(feature = "accounts")]
 pub mod relationship_service_invitations_service_invitations;
+#[cfg(feature = "accounts")]
 pub use self::relationship_service_invitations_service_invitations::RelationshipServiceInvitationsServiceInvitations;
+#[cfg(feature = "full")]
 pub mod relationship_services;
+#[cfg(feature = "full")]
 pub use self::relationship_services::RelationshipServices;
+#[cfg(feature = "full")]
 pub mod relationship_services_services;
+#[cfg(feature = "full")]
 pub use self::relationship_services_services::RelationshipServicesServices;
+#[cfg(feature = "full")]
 pub mod relationship_tls_activation;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_activation::RelationshipTlsActivation;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_activation_tls_activation;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_activation_tls_activation::RelationshipTlsActivationTlsActivation;
+#[cfg(feature = "full")]
 pub mod relationship_tls_activations;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_activations::RelationshipTlsActivations;
+#[cfg(feature = "full")]
 pub mod relationship_tls_bulk_certificate;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_bulk_certificate::RelationshipTlsBulkCertificate;
+#[cfg(feature = "full")]
 pub mod relationship_tls_bulk_certificate_tls_bulk_certificate;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_bulk_certificate_tls_bulk_certificate::RelationshipTlsBulkCertificateTlsBulkCertificate;
+#[cfg(feature = "full")]
 pub mod relationship_tls_bulk_certificates;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_bulk_certificates::RelationshipTlsBulkCertificates;
+#[cfg(feature = "full")]
 pub mod relationship_tls_certificate;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_certificate::RelationshipTlsCertificate;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_certificate_tls_certificate;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_certificate_tls_certificate::RelationshipTlsCertificateTlsCertificate;
+#[cfg(feature = "full")]
 pub mod relationship_tls_certificates;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_certificates::RelationshipTlsCertificates;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_certificates_tls_certificates;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_certificates_tls_certificates::RelationshipTlsCertificatesTlsCertificates;
+#[cfg(feature = "full")]
 pub mod relationship_tls_configuration;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_configuration::RelationshipTlsConfiguration;
+#[cfg(feature = "full")]
 pub mod relationship_tls_configuration_for_tls_subscription;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_configuration_for_tls_subscription::RelationshipTlsConfigurationForTlsSubscription;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_configuration_tls_configuration;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_configuration_tls_configuration::RelationshipTlsConfigurationTlsConfiguration;
+#[cfg(feature = "full")]
 pub mod relationship_tls_configurations;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_configurations::RelationshipTlsConfigurations;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_configurations_tls_configurations;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_configurations_tls_configurations::RelationshipTlsConfigurationsTlsConfigurations;
+#[cfg(feature = "full")]
 pub mod relationship_tls_dns_records_request;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_dns_records_request::RelationshipTlsDnsRecordsRequest;
+#[cfg(feature = "full")]
 pub mod relationship_tls_dns_records_request_dns_records;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_dns_records_request_dns_records::RelationshipTlsDnsRecordsRequestDnsRecords;
+#[cfg(feature = "full")]
 pub mod relationship_tls_dns_records_request_dns_records_data;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_dns_records_request_dns_records_data::RelationshipTlsDnsRecordsRequestDnsRecordsData;
+#[cfg(feature = "full")]
 pub mod relationship_tls_dns_records_response;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_dns_records_response::RelationshipTlsDnsRecordsResponse;
+#[cfg(feature = "full")]
 pub mod relationship_tls_dns_records_response_dns_records;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_dns_records_response_dns_records::RelationshipTlsDnsRecordsResponseDnsRecords;
+#[cfg(feature = "full")]
 pub mod relationship_tls_dns_records_response_dns_records_data;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_dns_records_response_dns_records_data::RelationshipTlsDnsRecordsResponseDnsRecordsData;
+#[cfg(feature = "full")]
 pub mod relationship_tls_domain;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_domain::RelationshipTlsDomain;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_domain_tls_domain;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_domain_tls_domain::RelationshipTlsDomainTlsDomain;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_domains;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_domains::RelationshipTlsDomains;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_domains_tls_domains;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_domains_tls_domains::RelationshipTlsDomainsTlsDomains;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_private_key;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_private_key::RelationshipTlsPrivateKey;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_private_key_tls_private_key;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_private_key_tls_private_key::RelationshipTlsPrivateKeyTlsPrivateKey;
+#[cfg(feature = "full")]
 pub mod relationship_tls_private_keys;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_private_keys::RelationshipTlsPrivateKeys;
+#[cfg(feature = "full")]
 pub mod relationship_tls_private_keys_tls_private_keys;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_private_keys_tls_private_keys::RelationshipTlsPrivateKeysTlsPrivateKeys;
+#[cfg(feature = "full")]
 pub mod relationship_tls_subscription;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_subscription::RelationshipTlsSubscription;
+#[cfg(feature = "tls")]
 pub mod relationship_tls_subscription_tls_subscription;
+#[cfg(feature = "tls")]
 pub use self::relationship_tls_subscription_tls_subscription::RelationshipTlsSubscriptionTlsSubscription;
+#[cfg(feature = "full")]
 pub mod relationship_tls_subscriptions;
+#[cfg(feature = "full")]
 pub use self::relationship_tls_subscriptions::RelationshipTlsSubscriptions;
+#[cfg(feature = "full")]
 pub mod relationship_user;
+#[cfg(feature = "full")]
 pub use self::relationship_user::RelationshipUser;
+#[cfg(feature = "accounts")]
 pub mod relationship_user_user;
+#[cfg(feature = "accounts")]
 pub use self::relationship_user_user::RelationshipUserUser;
+#[cfg(feature = "full")]
 pub mod relationship_waf_active_rules;
+#[cfg(feature = "full")]
 pub use self::relationship_waf_active_rules::RelationshipWafActiveRules;
+#[cfg(feature = "waf")]
 pub mod relationship_waf_active_rules_waf_active_rules;
+#[cfg(feature = "waf")]
 pub use self::relationship_waf_active_rules_waf_active_rules::RelationshipWafActiveRulesWafActiveRules;
+#[cfg(feature = "full")]
 pub mod relationship_waf_firewall;
+#[cfg(feature = "full")]
 pub use self::relationship_waf_firewall::RelationshipWafFirewall;
+#[cfg(feature = "full")]
 pub mod relationship_waf_firewall_version;
+#[cfg(feature = "full")]
 pub use self::relationship_waf_firewall_version::RelationshipWafFirewallVersion;
+#[cfg(feature = "waf")]
 pub mod relationship_waf_firewall_version_waf_firewall_version;
+#[cfg(feature = "waf")]
 pub use self::relationship_waf_firewall_version_waf_firewall_version::RelationshipWafFirewallVersionWafFirewallVersion;
+#[cfg(feature = "waf")]
 pub mod relationship_waf_firewall_versions;
+#[cfg(feature = "waf")]
 pub use self::relationship_waf_firewall_versions::RelationshipWafFirewallVersions;
+#[cfg(feature = "full")]
 pub mod relationship_waf_firewall_waf_firewall;
+#[cfg(feature = "full")]
 pub use self::relationship_waf_firewall_waf_firewall::RelationshipWafFirewallWafFirewall;
+#[cfg(feature = "waf")]
 pub mod relationship_waf_rule;
+#[cfg(feature = "waf")]
 pub use self::relationship_waf_rule::RelationshipWafRule;
+#[cfg(feature = "full")]
 pub mod relationship_waf_rule_revision;
+#[cfg(feature = "full")]
 pub use self::relationship_waf_rule_revision::RelationshipWafRuleRevision;
+#[cfg(feature = "waf")]
 pub mod relationship_waf_rule_revision_waf_rule_revisions;
+#[cfg(feature = "waf")]
 pub use self::relationship_waf_rule_revision_waf_rule_revisions::RelationshipWafRuleRevisionWafRuleRevisions;
+#[cfg(feature = "full")]
 pub mod relationship_waf_rule_revisions;
+#[cfg(feature = "full")]
 pub use self::relationship_waf_rule_revisions::RelationshipWafRuleRevisions;
+#[cfg(feature = "waf")]
 pub mod relationship_waf_rule_waf_rule;
+#[cfg(feature = "waf")]
 pub use self::relationship_waf_rule_waf_rule::RelationshipWafRuleWafRule;
+#[cfg(feature = "full")]
 pub mod relationship_waf_rules;
+#[cfg(feature = "full")]
 pub use self::relationship_waf_rules::RelationshipWafRules;
+#[cfg(feature = "full")]
 pub mod relationship_waf_tags;
+#[cfg(feature = "full")]
 pub use self::relationship_waf_tags::RelationshipWafTags;
+#[cfg(feature = "waf")]
 pub mod relationship_waf_tags_waf_tags;
+#[cfg(feature = "waf")]
 pub use self::relationship_waf_tags_waf_tags::RelationshipWafTagsWafTags;
+#[cfg(feature = "accounts")]
 pub mod relationships_for_invitation;
+#[cfg(feature = "accounts")]
 pub use self::relationships_for_invitation::RelationshipsForInvitation;
+#[cfg(feature = "tls")]
 pub mod relationships_for_mutual_authentication;
+#[cfg(feature = "tls")]
 pub use self::relationships_for_mutual_authentication::RelationshipsForMutualAuthentication;
+#[cfg(feature = "accounts")]
 pub mod relationships_for_star;
+#[cfg(feature = "accounts")]
 pub use self::relationships_for_star::RelationshipsForStar;
+#[cfg(feature = "tls")]
 pub mod relationships_for_tls_activation;
+#[cfg(feature = "tls")]
 pub use self::relationships_for_tls_activation::RelationshipsForTlsActivation;
+#[cfg(feature = "tls")]
 pub mod relationships_for_tls_bulk_certificate;
+#[cfg(feature = "tls")]
 pub use self::relationships_for_tls_bulk_certificate::RelationshipsForTlsBulkCertificate;
+#[cfg(feature = "tls")]
 pub mod relationships_for_tls_configuration;
+#[cfg(feature = "tls")]
 pub use self::relationships_for_tls_configuration::RelationshipsForTlsConfiguration;
+#[cfg(feature = "tls")]
 pub mod relationships_for_tls_domain;
+#[cfg(feature = "tls")]
 pub use self::relationships_for_tls_domain::RelationshipsForTlsDomain;
+#[cfg(feature = "tls")]
 pub mod relationships_for_tls_private_key;
+#[cfg(feature = "tls")]
 pub use self::relationships_for_tls_private_key::RelationshipsForTlsPrivateKey;
+#[cfg(feature = "tls")]
 pub mod relationships_for_tls_subscription;
+#[cfg(feature = "tls")]
 pub use self::relationships_for_tls_subscription::RelationshipsForTlsSubscription;
+#[cfg(feature = "waf")]
 pub mod relationships_for_waf_active_rule;
+#[cfg(feature = "waf")]
 pub use self::relationships_for_waf_active_rule::RelationshipsForWafActiveRule;
+#[cfg(feature = "waf")]
 pub mod relationships_for_waf_exclusion;
+#[cfg(feature = "waf")]
 pub use self::relationships_for_waf_exclusion::RelationshipsForWafExclusion;
+#[cfg(feature = "waf")]
 pub mod relationships_for_waf_firewall_version;
+#[cfg(feature = "waf")]
 pub use self::relationships_for_waf_firewall_version::RelationshipsForWafFirewallVersion;
+#[cfg(feature = "waf")]
 pub mod relationships_for_waf_rule;
+#[cfg(feature = "waf")]
 pub use self::relationships_for_waf_rule::RelationshipsForWafRule;
+#[cfg(feature = "full")]
 pub mod request_settings_additional;
+#[cfg(feature = "full")]
 pub use self::request_settings_additional::RequestSettingsAdditional;
 pub mod request_settings_response;
 pub use self::request_settings_response::RequestSettingsResponse;
+#[cfg(feature = "full")]
 pub mod request_settings_response_all_of;
+#[cfg(feature = "full")]
 pub use self::request_settings_response_all_of::RequestSettingsResponseAllOf;
+#[cfg(feature = "full")]
 pub mod resource;
+#[cfg(feature = "full")]
 pub use self::resource::Resource;
+#[cfg(feature = "compute")]
 pub mod resource_response;
+#[cfg(feature = "compute")]
 pub use self::resource_response::ResourceResponse;
+#[cfg(feature = "full")]
 pub mod resource_response_all_of;
+#[cfg(feature = "full")]
 pub use self::resource_response_all_of::ResourceResponseAllOf;
+#[cfg(feature = "full")]
 pub mod response_object;
+#[cfg(feature = "full")]
 pub use self::response_object::ResponseObject;
 pub mod response_object_response;
 pub use self::response_object_response::ResponseObjectResponse;
+#[cfg(feature = "stats")]
 pub mod results;
+#[cfg(feature = "stats")]
 pub use self::results::Results;
+#[cfg(feature = "accounts")]
 pub mod role_user;
+#[cfg(feature = "accounts")]
 pub use self::role_user::RoleUser;
+#[cfg(feature = "accounts")]
 pub mod schemas_contact_response;
+#[cfg(feature = "accounts")]
 pub use self::schemas_contact_response::SchemasContactResponse;
 pub mod schemas_snippet_response;
 pub use self::schemas_snippet_response::SchemasSnippetResponse;
+#[cfg(feature = "full")]
 pub mod schemas_snippet_response_common;
+#[cfg(feature = "full")]
 pub use self::schemas_snippet_response_common::SchemasSnippetResponseCommon;
+#[cfg(feature = "accounts")]
 pub mod schemas_user_response;
+#[cfg(feature = "accounts")]
 pub use self::schemas_user_response::SchemasUserResponse;
+#[cfg(feature = "full")]
 pub mod schemas_user_response_read_only;
+#[cfg(feature = "full")]
 pub use self::schemas_user_response_read_only::SchemasUserResponseReadOnly;
 pub mod schemas_vcl_response;
 pub use self::schemas_vcl_response::SchemasVclResponse;
+#[cfg(feature = "full")]
 pub mod schemas_version;
+#[cfg(feature = "full")]
 pub use self::schemas_version::SchemasVersion;
 pub mod schemas_version_response;
 pub use self::schemas_version_response::SchemasVersionResponse;
+#[cfg(feature = "waf")]
 pub mod schemas_waf_firewall_version;
+#[cfg(feature = "waf")]
 pub use self::schemas_waf_firewall_version::SchemasWafFirewallVersion;
+#[cfg(feature = "waf")]
 pub mod schemas_waf_firewall_version_data;
+#[cfg(feature = "waf")]
 pub use self::schemas_waf_firewall_version_data::SchemasWafFirewallVersionData;
+#[cfg(feature = "stores")]
 pub mod secret;
+#[cfg(feature = "stores")]
 pub use self::secret::Secret;
+#[cfg(feature = "stores")]
 pub mod secret_response;
+#[cfg(feature = "stores")]
 pub use self::secret_response::SecretResponse;
+#[cfg(feature = "stores")]
 pub mod secret_store;
+#[cfg(feature = "stores")]
 pub use self::secret_store::SecretStore;
+#[cfg(feature = "stores")]
 pub mod secret_store_response;
+#[cfg(feature = "stores")]
 pub use self::secret_store_response::SecretStoreResponse;
+#[cfg(feature = "full")]
 pub mod server;
+#[cfg(feature = "full")]
 pub use self::server::Server;
+#[cfg(feature = "backends")]
 pub mod server_response;
+#[cfg(feature = "backends")]
 pub use self::server_response::ServerResponse;
+#[cfg(feature = "full")]
 pub mod server_response_all_of;
+#[cfg(feature = "full")]
 pub use self::server_response_all_of::ServerResponseAllOf;
+#[cfg(feature = "full")]
 pub mod service;
+#[cfg(feature = "full")]
 pub use self::service::Service;
+#[cfg(feature = "iam")]
 pub mod service_authorization;
+#[cfg(feature = "iam")]
 pub use self::service_authorization::ServiceAuthorization;
+#[cfg(feature = "iam")]
 pub mod service_authorization_data;
+#[cfg(feature = "iam")]
 pub use self::service_authorization_data::ServiceAuthorizationData;
+#[cfg(feature = "iam")]
 pub mod service_authorization_data_attributes;
+#[cfg(feature = "iam")]
 pub use self::service_authorization_data_attributes::ServiceAuthorizationDataAttributes;
+#[cfg(feature = "iam")]
 pub mod service_authorization_data_relationships;
+#[cfg(feature = "iam")]
 pub use self::service_authorization_data_relationships::ServiceAuthorizationDataRelationships;
+#[cfg(feature = "iam")]
 pub mod service_authorization_data_relationships_user;
+#[cfg(feature = "iam")]
 pub use self::service_authorization_data_relationships_user::ServiceAuthorizationDataRelationshipsUser;
+#[cfg(any(feature = "accounts", feature = "iam"))]
 pub mod service_authorization_data_relationships_user_data;
+#[cfg(any(feature = "accounts", feature = "iam"))]
 pub use self::service_authorization_data_relationships_user_data::ServiceAuthorizationDataRelationshipsUserData;
+#[cfg(feature = "iam")]
 pub mod service_authorization_response;
+#[cfg(feature = "iam")]
 pub use self::service_authorization_response::ServiceAuthorizationResponse;
+#[cfg(feature = "iam")]
 pub mod service_authorization_response_data;
+#[cfg(feature = "iam")]
 pub use self::service_authorization_response_data::ServiceAuthorizationResponseData;
+#[cfg(feature = "full")]
 pub mod service_authorization_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::service_authorization_response_data_all_of::ServiceAuthorizationResponseDataAllOf;
+#[cfg(feature = "iam")]
 pub mod service_authorizations_response;
+#[cfg(feature = "iam")]
 pub use self::service_authorizations_response::ServiceAuthorizationsResponse;
+#[cfg(feature = "full")]
 pub mod service_authorizations_response_all_of;
+#[cfg(feature = "full")]
 pub use self::service_authorizations_response_all_of::ServiceAuthorizationsResponseAllOf;
+#[cfg(feature = "full")]
 pub mod service_create;
+#[cfg(feature = "full")]
 pub use self::service_create::ServiceCreate;
+#[cfg(feature = "full")]
 pub mod service_create_all_of;
+#[cfg(feature = "full")]
 pub use self::service_create_all_of::ServiceCreateAllOf;
 pub mod service_detail;
 pub use self::service_detail::ServiceDetail;
+#[cfg(feature = "full")]
 pub mod service_detail_all_of;
+#[cfg(feature = "full")]
 pub use self::service_detail_all_of::ServiceDetailAllOf;
+#[cfg(feature = "full")]
 pub mod service_id_and_version;
+#[cfg(feature = "full")]
 pub use self::service_id_and_version::ServiceIdAndVersion;
+#[cfg(feature = "full")]
 pub mod service_id_and_version_string;
+#[cfg(feature = "full")]
 pub use self::service_id_and_version_string::ServiceIdAndVersionString;
+#[cfg(feature = "accounts")]
 pub mod service_invitation;
+#[cfg(feature = "accounts")]
 pub use self::service_invitation::ServiceInvitation;
+#[cfg(feature = "accounts")]
 pub mod service_invitation_data;
+#[cfg(feature = "accounts")]
 pub use self::service_invitation_data::ServiceInvitationData;
+#[cfg(feature = "accounts")]
 pub mod service_invitation_data_attributes;
+#[cfg(feature = "accounts")]
 pub use self::service_invitation_data_attributes::ServiceInvitationDataAttributes;
+#[cfg(feature = "accounts")]
 pub mod service_invitation_data_relationships;
+#[cfg(feature = "accounts")]
 pub use self::service_invitation_data_relationships::ServiceInvitationDataRelationships;
+#[cfg(feature = "full")]
 pub mod service_invitation_response;
+#[cfg(feature = "full")]
 pub use self::service_invitation_response::ServiceInvitationResponse;
+#[cfg(feature = "full")]
 pub mod service_invitation_response_all_of;
+#[cfg(feature = "full")]
 pub use self::service_invitation_response_all_of::ServiceInvitationResponseAllOf;
+#[cfg(feature = "full")]
 pub mod service_invitation_response_all_of_data;
+#[cfg(feature = "full")]
 pub use self::service_invitation_response_all_of_data::ServiceInvitationResponseAllOfData;
 pub mod service_list_response;
 pub use self::service_list_response::ServiceListResponse;
+#[cfg(feature = "full")]
 pub mod service_list_response_all_of;
+#[cfg(feature = "full")]
 pub use self::service_list_response_all_of::ServiceListResponseAllOf;
 pub mod service_response;
 pub use self::service_response::ServiceResponse;
+#[cfg(feature = "full")]
 pub mod service_response_all_of;
+#[cfg(feature = "full")]
 pub use self::service_response_all_of::ServiceResponseAllOf;
 pub mod service_version_detail;
 pub use self::service_version_detail::ServiceVersionDetail;
 pub mod service_version_detail_or_null;
 pub use self::service_version_detail_or_null::ServiceVersionDetailOrNull;
+#[cfg(feature = "billing")]
 pub mod serviceusagemetric;
+#[cfg(feature = "billing")]
 pub use self::serviceusagemetric::Serviceusagemetric;
+#[cfg(feature = "billing")]
 pub mod serviceusagemetrics;
+#[cfg(feature = "billing")]
 pub use self::serviceusagemetrics::Serviceusagemetrics;
+#[cfg(feature = "billing")]
 pub mod serviceusagemetrics_data;
+#[cfg(feature = "billing")]
 pub use self::serviceusagemetrics_data::ServiceusagemetricsData;
+#[cfg(feature = "billing")]
 pub mod serviceusagetype;
+#[cfg(feature = "billing")]
 pub use self::serviceusagetype::Serviceusagetype;
+#[cfg(feature = "billing")]
 pub mod serviceusagetypes;
+#[cfg(feature = "billing")]
 pub use self::serviceusagetypes::Serviceusagetypes;
+#[cfg(feature = "full")]
 pub mod settings;
+#[cfg(feature = "full")]
 pub use self::settings::Settings;
+#[cfg(feature = "vcl")]
 pub mod settings_response;
+#[cfg(feature = "vcl")]
 pub use self::settings_response::SettingsResponse;
+#[cfg(feature = "stores")]
 pub mod signing_key;
+#[cfg(feature = "stores")]
 pub use self::signing_key::SigningKey;
+#[cfg(feature = "full")]
 pub mod snippet;
+#[cfg(feature = "full")]
 pub use self::snippet::Snippet;
+#[cfg(feature = "full")]
 pub mod snippet_all_of;
+#[cfg(feature = "full")]
 pub use self::snippet_all_of::SnippetAllOf;
+#[cfg(feature = "full")]
 pub mod snippet_common;
+#[cfg(feature = "full")]
 pub use self::snippet_common::SnippetCommon;
+#[cfg(feature = "vcl")]
 pub mod snippet_response;
+#[cfg(feature = "vcl")]
 pub use self::snippet_response::SnippetResponse;
+#[cfg(feature = "full")]
 pub mod snippet_response_common;
+#[cfg(feature = "full")]
 pub use self::snippet_response_common::SnippetResponseCommon;
+#[cfg(feature = "full")]
 pub mod snippet_response_common_all_of;
+#[cfg(feature = "full")]
 pub use self::snippet_response_common_all_of::SnippetResponseCommonAllOf;
+#[cfg(feature = "vcl")]
 pub mod snippet_response_post;
+#[cfg(feature = "vcl")]
 pub use self::snippet_response_post::SnippetResponsePost;
+#[cfg(feature = "full")]
 pub mod snippet_with_dynamic_number;
+#[cfg(feature = "full")]
 pub use self::snippet_with_dynamic_number::SnippetWithDynamicNumber;
+#[cfg(feature = "full")]
 pub mod snippet_with_dynamic_number_all_of;
+#[cfg(feature = "full")]
 pub use self::snippet_with_dynamic_number_all_of::SnippetWithDynamicNumberAllOf;
+#[cfg(feature = "accounts")]
 pub mod star;
+#[cfg(feature = "accounts")]
 pub use self::star::Star;
+#[cfg(feature = "accounts")]
 pub mod star_data;
+#[cfg(feature = "accounts")]
 pub use self::star_data::StarData;
+#[cfg(feature = "accounts")]
 pub mod star_response;
+#[cfg(feature = "accounts")]
 pub use self::star_response::StarResponse;
+#[cfg(feature = "full")]
 pub mod star_response_all_of;
+#[cfg(feature = "full")]
 pub use self::star_response_all_of::StarResponseAllOf;
+#[cfg(feature = "stats")]
 pub mod stats;
+#[cfg(feature = "stats")]
 pub use self::stats::Stats;
+#[cfg(feature = "stores")]
 pub mod store;
+#[cfg(feature = "stores")]
 pub use self::store::Store;
+#[cfg(feature = "stores")]
 pub mod store_response;
+#[cfg(feature = "stores")]
 pub use self::store_response::StoreResponse;
+#[cfg(feature = "stats")]
 pub mod subsequent_request_timestamp;
+#[cfg(feature = "stats")]
 pub use self::subsequent_request_timestamp::SubsequentRequestTimestamp;
+#[cfg(feature = "accounts")]
 pub mod sudo_generic_token_error;
+#[cfg(feature = "accounts")]
 pub use self::sudo_generic_token_error::SudoGenericTokenError;
+#[cfg(feature = "accounts")]
 pub mod sudo_request;
+#[cfg(feature = "accounts")]
 pub use self::sudo_request::SudoRequest;
+#[cfg(feature = "accounts")]
 pub mod sudo_response;
+#[cfg(feature = "accounts")]
 pub use self::sudo_response::SudoResponse;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls"))]
 pub mod timestamps;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls"))]
 pub use self::timestamps::Timestamps;
+#[cfg(feature = "full")]
 pub mod timestamps_no_delete;
+#[cfg(feature = "full")]
 pub use self::timestamps_no_delete::TimestampsNoDelete;
+#[cfg(feature = "tls")]
 pub mod tls_activation;
+#[cfg(feature = "tls")]
 pub use self::tls_activation::TlsActivation;
+#[cfg(feature = "tls")]
 pub mod tls_activation_data;
+#[cfg(feature = "tls")]
 pub use self::tls_activation_data::TlsActivationData;
+#[cfg(feature = "tls")]
 pub mod tls_activation_response;
+#[cfg(feature = "tls")]
 pub use self::tls_activation_response::TlsActivationResponse;
+#[cfg(feature = "tls")]
 pub mod tls_activation_response_data;
+#[cfg(feature = "tls")]
 pub use self::tls_activation_response_data::TlsActivationResponseData;
+#[cfg(feature = "full")]
 pub mod tls_activation_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_activation_response_data_all_of::TlsActivationResponseDataAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_activations_response;
+#[cfg(feature = "tls")]
 pub use self::tls_activations_response::TlsActivationsResponse;
+#[cfg(feature = "full")]
 pub mod tls_activations_response_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_activations_response_all_of::TlsActivationsResponseAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_bulk_certificate;
+#[cfg(feature = "tls")]
 pub use self::tls_bulk_certificate::TlsBulkCertificate;
+#[cfg(feature = "tls")]
 pub mod tls_bulk_certificate_data;
+#[cfg(feature = "tls")]
 pub use self::tls_bulk_certificate_data::TlsBulkCertificateData;
+#[cfg(feature = "tls")]
 pub mod tls_bulk_certificate_data_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_bulk_certificate_data_attributes::TlsBulkCertificateDataAttributes;
+#[cfg(feature = "tls")]
 pub mod tls_bulk_certificate_response;
+#[cfg(feature = "tls")]
 pub use self::tls_bulk_certificate_response::TlsBulkCertificateResponse;
+#[cfg(feature = "tls")]
 pub mod tls_bulk_certificate_response_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_bulk_certificate_response_attributes::TlsBulkCertificateResponseAttributes;
+#[cfg(feature = "full")]
 pub mod tls_bulk_certificate_response_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_bulk_certificate_response_attributes_all_of::TlsBulkCertificateResponseAttributesAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_bulk_certificate_response_data;
+#[cfg(feature = "tls")]
 pub use self::tls_bulk_certificate_response_data::TlsBulkCertificateResponseData;
+#[cfg(feature = "full")]
 pub mod tls_bulk_certificate_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_bulk_certificate_response_data_all_of::TlsBulkCertificateResponseDataAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_bulk_certificates_response;
+#[cfg(feature = "tls")]
 pub use self::tls_bulk_certificates_response::TlsBulkCertificatesResponse;
+#[cfg(feature = "full")]
 pub mod tls_bulk_certificates_response_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_bulk_certificates_response_all_of::TlsBulkCertificatesResponseAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_certificate;
+#[cfg(feature = "tls")]
 pub use self::tls_certificate::TlsCertificate;
+#[cfg(feature = "tls")]
 pub mod tls_certificate_blob_response;
+#[cfg(feature = "tls")]
 pub use self::tls_certificate_blob_response::TlsCertificateBlobResponse;
+#[cfg(feature = "tls")]
 pub mod tls_certificate_data;
+#[cfg(feature = "tls")]
 pub use self::tls_certificate_data::TlsCertificateData;
+#[cfg(feature = "tls")]
 pub mod tls_certificate_data_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_certificate_data_attributes::TlsCertificateDataAttributes;
+#[cfg(feature = "tls")]
 pub mod tls_certificate_response;
+#[cfg(feature = "tls")]
 pub use self::tls_certificate_response::TlsCertificateResponse;
+#[cfg(feature = "tls")]
 pub mod tls_certificate_response_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_certificate_response_attributes::TlsCertificateResponseAttributes;
+#[cfg(feature = "full")]
 pub mod tls_certificate_response_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_certificate_response_attributes_all_of::TlsCertificateResponseAttributesAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_certificate_response_data;
+#[cfg(feature = "tls")]
 pub use self::tls_certificate_response_data::TlsCertificateResponseData;
+#[cfg(feature = "full")]
 pub mod tls_certificate_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_certificate_response_data_all_of::TlsCertificateResponseDataAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_certificates_response;
+#[cfg(feature = "tls")]
 pub use self::tls_certificates_response::TlsCertificatesResponse;
+#[cfg(feature = "full")]
 pub mod tls_certificates_response_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_certificates_response_all_of::TlsCertificatesResponseAllOf;
+#[cfg(feature = "full")]
 pub mod tls_common_response;
+#[cfg(feature = "full")]
 pub use self::tls_common_response::TlsCommonResponse;
+#[cfg(feature = "full")]
 pub mod tls_common_response_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_common_response_all_of::TlsCommonResponseAllOf;
+#[cfg(feature = "full")]
 pub mod tls_common_response_all_of_1;
+#[cfg(feature = "full")]
 pub use self::tls_common_response_all_of_1::TlsCommonResponseAllOf1;
+#[cfg(feature = "tls")]
 pub mod tls_configuration;
+#[cfg(feature = "tls")]
 pub use self::tls_configuration::TlsConfiguration;
+#[cfg(feature = "tls")]
 pub mod tls_configuration_data;
+#[cfg(feature = "tls")]
 pub use self::tls_configuration_data::TlsConfigurationData;
+#[cfg(feature = "tls")]
 pub mod tls_configuration_data_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_configuration_data_attributes::TlsConfigurationDataAttributes;
+#[cfg(feature = "tls")]
 pub mod tls_configuration_response;
+#[cfg(feature = "tls")]
 pub use self::tls_configuration_response::TlsConfigurationResponse;
+#[cfg(feature = "tls")]
 pub mod tls_configuration_response_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_configuration_response_attributes::TlsConfigurationResponseAttributes;
+#[cfg(feature = "full")]
 pub mod tls_configuration_response_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_configuration_response_attributes_all_of::TlsConfigurationResponseAttributesAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_configuration_response_data;
+#[cfg(feature = "tls")]
 pub use self::tls_configuration_response_data::TlsConfigurationResponseData;
+#[cfg(feature = "full")]
 pub mod tls_configuration_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_configuration_response_data_all_of::TlsConfigurationResponseDataAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_configurations_response;
+#[cfg(feature = "tls")]
 pub use self::tls_configurations_response::TlsConfigurationsResponse;
+#[cfg(feature = "full")]
 pub mod tls_configurations_response_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_configurations_response_all_of::TlsConfigurationsResponseAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_csr;
+#[cfg(feature = "tls")]
 pub use self::tls_csr::TlsCsr;
+#[cfg(feature = "tls")]
 pub mod tls_csr_data;
+#[cfg(feature = "tls")]
 pub use self::tls_csr_data::TlsCsrData;
+#[cfg(feature = "tls")]
 pub mod tls_csr_data_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_csr_data_attributes::TlsCsrDataAttributes;
+#[cfg(feature = "full")]
 pub mod tls_csr_error_response;
+#[cfg(feature = "full")]
 pub use self::tls_csr_error_response::TlsCsrErrorResponse;
+#[cfg(feature = "tls")]
 pub mod tls_csr_response;
+#[cfg(feature = "tls")]
 pub use self::tls_csr_response::TlsCsrResponse;
+#[cfg(feature = "tls")]
 pub mod tls_csr_response_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_csr_response_attributes::TlsCsrResponseAttributes;
+#[cfg(feature = "tls")]
 pub mod tls_csr_response_data;
+#[cfg(feature = "tls")]
 pub use self::tls_csr_response_data::TlsCsrResponseData;
+#[cfg(feature = "full")]
 pub mod tls_dns_record;
+#[cfg(feature = "full")]
 pub use self::tls_dns_record::TlsDnsRecord;
+#[cfg(feature = "tls")]
 pub mod tls_domain_data;
+#[cfg(feature = "tls")]
 pub use self::tls_domain_data::TlsDomainData;
+#[cfg(feature = "tls")]
 pub mod tls_domains_response;
+#[cfg(feature = "tls")]
 pub use self::tls_domains_response::TlsDomainsResponse;
+#[cfg(feature = "full")]
 pub mod tls_domains_response_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_domains_response_all_of::TlsDomainsResponseAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_private_key;
+#[cfg(feature = "tls")]
 pub use self::tls_private_key::TlsPrivateKey;
+#[cfg(feature = "tls")]
 pub mod tls_private_key_data;
+#[cfg(feature = "tls")]
 pub use self::tls_private_key_data::TlsPrivateKeyData;
+#[cfg(feature = "tls")]
 pub mod tls_private_key_data_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_private_key_data_attributes::TlsPrivateKeyDataAttributes;
+#[cfg(feature = "tls")]
 pub mod tls_private_key_response;
+#[cfg(feature = "tls")]
 pub use self::tls_private_key_response::TlsPrivateKeyResponse;
+#[cfg(feature = "tls")]
 pub mod tls_private_key_response_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_private_key_response_attributes::TlsPrivateKeyResponseAttributes;
+#[cfg(feature = "full")]
 pub mod tls_private_key_response_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_private_key_response_attributes_all_of::TlsPrivateKeyResponseAttributesAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_private_key_response_data;
+#[cfg(feature = "tls")]
 pub use self::tls_private_key_response_data::TlsPrivateKeyResponseData;
+#[cfg(feature = "tls")]
 pub mod tls_private_keys_response;
+#[cfg(feature = "tls")]
 pub use self::tls_private_keys_response::TlsPrivateKeysResponse;
+#[cfg(feature = "full")]
 pub mod tls_private_keys_response_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_private_keys_response_all_of::TlsPrivateKeysResponseAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_subscription;
+#[cfg(feature = "tls")]
 pub use self::tls_subscription::TlsSubscription;
+#[cfg(feature = "tls")]
 pub mod tls_subscription_data;
+#[cfg(feature = "tls")]
 pub use self::tls_subscription_data::TlsSubscriptionData;
+#[cfg(feature = "tls")]
 pub mod tls_subscription_data_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_subscription_data_attributes::TlsSubscriptionDataAttributes;
+#[cfg(feature = "tls")]
 pub mod tls_subscription_response;
+#[cfg(feature = "tls")]
 pub use self::tls_subscription_response::TlsSubscriptionResponse;
+#[cfg(feature = "tls")]
 pub mod tls_subscription_response_attributes;
+#[cfg(feature = "tls")]
 pub use self::tls_subscription_response_attributes::TlsSubscriptionResponseAttributes;
+#[cfg(feature = "full")]
 pub mod tls_subscription_response_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_subscription_response_attributes_all_of::TlsSubscriptionResponseAttributesAllOf;
+#[cfg(feature = "tls")]
 pub mod tls_subscription_response_data;
+#[cfg(feature = "tls")]
 pub use self::tls_subscription_response_data::TlsSubscriptionResponseData;
+#[cfg(feature = "tls")]
 pub mod tls_subscriptions_response;
+#[cfg(feature = "tls")]
 pub use self::tls_subscriptions_response::TlsSubscriptionsResponse;
+#[cfg(feature = "full")]
 pub mod tls_subscriptions_response_all_of;
+#[cfg(feature = "full")]
 pub use self::tls_subscriptions_response_all_of::TlsSubscriptionsResponseAllOf;
+#[cfg(feature = "full")]
 pub mod token;
+#[cfg(feature = "full")]
 pub use self::token::Token;
+#[cfg(feature = "accounts")]
 pub mod token_created_response;
+#[cfg(feature = "accounts")]
 pub use self::token_created_response::TokenCreatedResponse;
+#[cfg(feature = "full")]
 pub mod token_created_response_all_of;
+#[cfg(feature = "full")]
 pub use self::token_created_response_all_of::TokenCreatedResponseAllOf;
+#[cfg(feature = "accounts")]
 pub mod token_response;
+#[cfg(feature = "accounts")]
 pub use self::token_response::TokenResponse;
+#[cfg(feature = "full")]
 pub mod token_response_all_of;
+#[cfg(feature = "full")]
 pub use self::token_response_all_of::TokenResponseAllOf;
+#[cfg(feature = "vcl")]
 pub mod tokens_additional_props;
+#[cfg(feature = "vcl")]
 pub use self::tokens_additional_props::TokensAdditionalProps;
+#[cfg(feature = "billing")]
 pub mod type_billing_address;
+#[cfg(feature = "billing")]
 pub use self::type_billing_address::TypeBillingAddress;
+#[cfg(feature = "full")]
 pub mod type_contact;
+#[cfg(feature = "full")]
 pub use self::type_contact::TypeContact;
+#[cfg(any(feature = "accounts", feature = "billing"))]
 pub mod type_customer;
+#[cfg(any(feature = "accounts", feature = "billing"))]
 pub use self::type_customer::TypeCustomer;
+#[cfg(feature = "accounts")]
 pub mod type_event;
+#[cfg(feature = "accounts")]
 pub use self::type_event::TypeEvent;
+#[cfg(feature = "accounts")]
 pub mod type_invitation;
+#[cfg(feature = "accounts")]
 pub use self::type_invitation::TypeInvitation;
+#[cfg(feature = "tls")]
 pub mod type_mutual_authentication;
+#[cfg(feature = "tls")]
 pub use self::type_mutual_authentication::TypeMutualAuthentication;
+#[cfg(feature = "compute")]
 pub mod type_resource;
+#[cfg(feature = "compute")]
 pub use self::type_resource::TypeResource;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls"))]
 pub mod type_service;
+#[cfg(any(feature = "accounts", feature = "iam", feature = "tls"))]
 pub use self::type_service::TypeService;
+#[cfg(feature = "iam")]
 pub mod type_service_authorization;
+#[cfg(feature = "iam")]
 pub use self::type_service_authorization::TypeServiceAuthorization;
+#[cfg(feature = "accounts")]
 pub mod type_service_invitation;
+#[cfg(feature = "accounts")]
 pub use self::type_service_invitation::TypeServiceInvitation;
+#[cfg(feature = "accounts")]
 pub mod type_star;
+#[cfg(feature = "accounts")]
 pub use self::type_star::TypeStar;
+#[cfg(feature = "tls")]
 pub mod type_tls_activation;
+#[cfg(feature = "tls")]
 pub use self::type_tls_activation::TypeTlsActivation;
+#[cfg(feature = "tls")]
 pub mod type_tls_bulk_certificate;
+#[cfg(feature = "tls")]
 pub use self::type_tls_bulk_certificate::TypeTlsBulkCertificate;
+#[cfg(feature = "tls")]
 pub mod type_tls_certificate;
+#[cfg(feature = "tls")]
 pub use self::type_tls_certificate::TypeTlsCertificate;
+#[cfg(feature = "tls")]
 pub mod type_tls_configuration;
+#[cfg(feature = "tls")]
 pub use self::type_tls_configuration::TypeTlsConfiguration;
+#[cfg(feature = "tls")]
 pub mod type_tls_csr;
+#[cfg(feature = "tls")]
 pub use self::type_tls_csr::TypeTlsCsr;
+#[cfg(feature = "full")]
 pub mod type_tls_dns_record;
+#[cfg(feature = "full")]
 pub use self::type_tls_dns_record::TypeTlsDnsRecord;
+#[cfg(feature = "tls")]
 pub mod type_tls_domain;
+#[cfg(feature = "tls")]
 pub use self::type_tls_domain::TypeTlsDomain;
+#[cfg(feature = "tls")]
 pub mod type_tls_private_key;
+#[cfg(feature = "tls")]
 pub use self::type_tls_private_key::TypeTlsPrivateKey;
+#[cfg(feature = "tls")]
 pub mod type_tls_subscription;
+#[cfg(feature = "tls")]
 pub use self::type_tls_subscription::TypeTlsSubscription;
+#[cfg(any(feature = "accounts", feature = "iam"))]
 pub mod type_user;
+#[cfg(any(feature = "accounts", feature = "iam"))]
 pub use self::type_user::TypeUser;
+#[cfg(feature = "waf")]
 pub mod type_waf_active_rule;
+#[cfg(feature = "waf")]
 pub use self::type_waf_active_rule::TypeWafActiveRule;
+#[cfg(feature = "waf")]
 pub mod type_waf_exclusion;
+#[cfg(feature = "waf")]
 pub use self::type_waf_exclusion::TypeWafExclusion;
+#[cfg(feature = "waf")]
 pub mod type_waf_firewall;
+#[cfg(feature = "waf")]
 pub use self::type_waf_firewall::TypeWafFirewall;
+#[cfg(feature = "waf")]
 pub mod type_waf_firewall_version;
+#[cfg(feature = "waf")]
 pub use self::type_waf_firewall_version::TypeWafFirewallVersion;
+#[cfg(feature = "waf")]
 pub mod type_waf_rule;
+#[cfg(feature = "waf")]
 pub use self::type_waf_rule::TypeWafRule;
+#[cfg(feature = "waf")]
 pub mod type_waf_rule_revision;
+#[cfg(feature = "waf")]
 pub use self::type_waf_rule_revision::TypeWafRuleRevision;
+#[cfg(feature = "waf")]
 pub mod type_waf_tag;
+#[cfg(feature = "waf")]
 pub use self::type_waf_tag::TypeWafTag;
+#[cfg(feature = "billing")]
 pub mod update_billing_address_request;
+#[cfg(feature = "billing")]
 pub use self::update_billing_address_request::UpdateBillingAddressRequest;
+#[cfg(feature = "billing")]
 pub mod update_billing_address_request_data;
+#[cfg(feature = "billing")]
 pub use self::update_billing_address_request_data::UpdateBillingAddressRequestData;
+#[cfg(feature = "stats")]
 pub mod update_dashboard_request;
+#[cfg(feature = "stats")]
 pub use self::update_dashboard_request::UpdateDashboardRequest;
+#[cfg(feature = "full")]
 pub mod user;
+#[cfg(feature = "full")]
 pub use self::user::User;
+#[cfg(feature = "accounts")]
 pub mod user_response;
+#[cfg(feature = "accounts")]
 pub use self::user_response::UserResponse;
+#[cfg(feature = "full")]
 pub mod user_response_read_only;
+#[cfg(feature = "full")]
 pub use self::user_response_read_only::UserResponseReadOnly;
+#[cfg(feature = "vcl")]
 pub mod validator_result;
+#[cfg(feature = "vcl")]
 pub use self::validator_result::ValidatorResult;
+#[cfg(feature = "vcl")]
 pub mod validator_result_data;
+#[cfg(feature = "vcl")]
 pub use self::validator_result_data::ValidatorResultData;
+#[cfg(feature = "vcl")]
 pub mod validator_result_data_attributes;
+#[cfg(feature = "vcl")]
 pub use self::validator_result_data_attributes::ValidatorResultDataAttributes;
+#[cfg(feature = "vcl")]
 pub mod validator_result_data_attributes_messages;
+#[cfg(feature = "vcl")]
 pub use self::validator_result_data_attributes_messages::ValidatorResultDataAttributesMessages;
+#[cfg(feature = "stats")]
 pub mod values;
+#[cfg(feature = "stats")]
 pub use self::values::Values;
+#[cfg(feature = "stats")]
 pub mod values_ddos;
+#[cfg(feature = "stats")]
 pub use self::values_ddos::ValuesDdos;
+#[cfg(feature = "full")]
 pub mod vcl;
+#[cfg(feature = "full")]
 pub use self::vcl::Vcl;
+#[cfg(feature = "vcl")]
 pub mod vcl_diff;
+#[cfg(feature = "vcl")]
 pub use self::vcl_diff::VclDiff;
+#[cfg(feature = "vcl")]
 pub mod vcl_response;
+#[cfg(feature = "vcl")]
 pub use self::vcl_response::VclResponse;
+#[cfg(feature = "vcl")]
 pub mod vcl_syntax_highlighting_response;
+#[cfg(feature = "vcl")]
 pub use self::vcl_syntax_highlighting_response::VclSyntaxHighlightingResponse;
 pub mod version;
 pub use self::version::Version;
 pub mod version_create_response;
 pub use self::version_create_response::VersionCreateResponse;
+#[cfg(feature = "full")]
 pub mod version_detail;
+#[cfg(feature = "full")]
 pub use self::version_detail::VersionDetail;
 pub mod version_detail_settings;
 pub use self::version_detail_settings::VersionDetailSettings;
 pub mod version_response;
 pub use self::version_response::VersionResponse;
+#[cfg(feature = "full")]
 pub mod version_response_all_of;
+#[cfg(feature = "full")]
 pub use self::version_response_all_of::VersionResponseAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_active_rule;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rule::WafActiveRule;
+#[cfg(feature = "waf")]
 pub mod waf_active_rule_creation_response;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rule_creation_response::WafActiveRuleCreationResponse;
+#[cfg(feature = "waf")]
 pub mod waf_active_rule_data;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rule_data::WafActiveRuleData;
+#[cfg(feature = "waf")]
 pub mod waf_active_rule_data_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rule_data_attributes::WafActiveRuleDataAttributes;
+#[cfg(feature = "waf")]
 pub mod waf_active_rule_response;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rule_response::WafActiveRuleResponse;
+#[cfg(feature = "waf")]
 pub mod waf_active_rule_response_data;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rule_response_data::WafActiveRuleResponseData;
+#[cfg(feature = "full")]
 pub mod waf_active_rule_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_active_rule_response_data_all_of::WafActiveRuleResponseDataAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_active_rule_response_data_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rule_response_data_attributes::WafActiveRuleResponseDataAttributes;
+#[cfg(feature = "full")]
 pub mod waf_active_rule_response_data_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_active_rule_response_data_attributes_all_of::WafActiveRuleResponseDataAttributesAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_active_rule_response_data_relationships;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rule_response_data_relationships::WafActiveRuleResponseDataRelationships;
+#[cfg(feature = "waf")]
 pub mod waf_active_rules_response;
+#[cfg(feature = "waf")]
 pub use self::waf_active_rules_response::WafActiveRulesResponse;
+#[cfg(feature = "full")]
 pub mod waf_active_rules_response_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_active_rules_response_all_of::WafActiveRulesResponseAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_exclusion;
+#[cfg(feature = "waf")]
 pub use self::waf_exclusion::WafExclusion;
+#[cfg(feature = "waf")]
 pub mod waf_exclusion_data;
+#[cfg(feature = "waf")]
 pub use self::waf_exclusion_data::WafExclusionData;
+#[cfg(feature = "waf")]
 pub mod waf_exclusion_data_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_exclusion_data_attributes::WafExclusionDataAttributes;
+#[cfg(feature = "waf")]
 pub mod waf_exclusion_response;
+#[cfg(feature = "waf")]
 pub use self::waf_exclusion_response::WafExclusionResponse;
+#[cfg(feature = "waf")]
 pub mod waf_exclusion_response_data;
+#[cfg(feature = "waf")]
 pub use self::waf_exclusion_response_data::WafExclusionResponseData;
+#[cfg(feature = "full")]
 pub mod waf_exclusion_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_exclusion_response_data_all_of::WafExclusionResponseDataAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_exclusion_response_data_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_exclusion_response_data_attributes::WafExclusionResponseDataAttributes;
+#[cfg(feature = "full")]
 pub mod waf_exclusion_response_data_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_exclusion_response_data_attributes_all_of::WafExclusionResponseDataAttributesAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_exclusion_response_data_relationships;
+#[cfg(feature = "waf")]
 pub use self::waf_exclusion_response_data_relationships::WafExclusionResponseDataRelationships;
+#[cfg(feature = "waf")]
 pub mod waf_exclusions_response;
+#[cfg(feature = "waf")]
 pub use self::waf_exclusions_response::WafExclusionsResponse;
+#[cfg(feature = "full")]
 pub mod waf_exclusions_response_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_exclusions_response_all_of::WafExclusionsResponseAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_firewall;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall::WafFirewall;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_data;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_data::WafFirewallData;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_data_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_data_attributes::WafFirewallDataAttributes;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_response;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_response::WafFirewallResponse;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_response_data;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_response_data::WafFirewallResponseData;
+#[cfg(feature = "full")]
 pub mod waf_firewall_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_firewall_response_data_all_of::WafFirewallResponseDataAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_response_data_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_response_data_attributes::WafFirewallResponseDataAttributes;
+#[cfg(feature = "full")]
 pub mod waf_firewall_response_data_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_firewall_response_data_attributes_all_of::WafFirewallResponseDataAttributesAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_version;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_version::WafFirewallVersion;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_version_data;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_version_data::WafFirewallVersionData;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_version_data_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_version_data_attributes::WafFirewallVersionDataAttributes;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_version_response;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_version_response::WafFirewallVersionResponse;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_version_response_data;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_version_response_data::WafFirewallVersionResponseData;
+#[cfg(feature = "full")]
 pub mod waf_firewall_version_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_firewall_version_response_data_all_of::WafFirewallVersionResponseDataAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_version_response_data_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_version_response_data_attributes::WafFirewallVersionResponseDataAttributes;
+#[cfg(feature = "full")]
 pub mod waf_firewall_version_response_data_attributes_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_firewall_version_response_data_attributes_all_of::WafFirewallVersionResponseDataAttributesAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_firewall_versions_response;
+#[cfg(feature = "waf")]
 pub use self::waf_firewall_versions_response::WafFirewallVersionsResponse;
+#[cfg(feature = "full")]
 pub mod waf_firewall_versions_response_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_firewall_versions_response_all_of::WafFirewallVersionsResponseAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_firewalls_response;
+#[cfg(feature = "waf")]
 pub use self::waf_firewalls_response::WafFirewallsResponse;
+#[cfg(feature = "full")]
 pub mod waf_firewalls_response_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_firewalls_response_all_of::WafFirewallsResponseAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_rule;
+#[cfg(feature = "waf")]
 pub use self::waf_rule::WafRule;
+#[cfg(feature = "waf")]
 pub mod waf_rule_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_rule_attributes::WafRuleAttributes;
+#[cfg(feature = "waf")]
 pub mod waf_rule_response;
+#[cfg(feature = "waf")]
 pub use self::waf_rule_response::WafRuleResponse;
+#[cfg(feature = "waf")]
 pub mod waf_rule_response_data;
+#[cfg(feature = "waf")]
 pub use self::waf_rule_response_data::WafRuleResponseData;
+#[cfg(feature = "full")]
 pub mod waf_rule_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_rule_response_data_all_of::WafRuleResponseDataAllOf;
+#[cfg(feature = "full")]
 pub mod waf_rule_revision;
+#[cfg(feature = "full")]
 pub use self::waf_rule_revision::WafRuleRevision;
+#[cfg(feature = "waf")]
 pub mod waf_rule_revision_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_rule_revision_attributes::WafRuleRevisionAttributes;
+#[cfg(feature = "waf")]
 pub mod waf_rule_revision_or_latest;
+#[cfg(feature = "waf")]
 pub use self::waf_rule_revision_or_latest::WafRuleRevisionOrLatest;
+#[cfg(feature = "waf")]
 pub mod waf_rule_revision_response;
+#[cfg(feature = "waf")]
 pub use self::waf_rule_revision_response::WafRuleRevisionResponse;
+#[cfg(feature = "waf")]
 pub mod waf_rule_revision_response_data;
+#[cfg(feature = "waf")]
 pub use self::waf_rule_revision_response_data::WafRuleRevisionResponseData;
+#[cfg(feature = "full")]
 pub mod waf_rule_revision_response_data_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_rule_revision_response_data_all_of::WafRuleRevisionResponseDataAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_rule_revisions_response;
+#[cfg(feature = "waf")]
 pub use self::waf_rule_revisions_response::WafRuleRevisionsResponse;
+#[cfg(feature = "full")]
 pub mod waf_rule_revisions_response_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_rule_revisions_response_all_of::WafRuleRevisionsResponseAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_rules_response;
+#[cfg(feature = "waf")]
 pub use self::waf_rules_response::WafRulesResponse;
+#[cfg(feature = "full")]
 pub mod waf_rules_response_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_rules_response_all_of::WafRulesResponseAllOf;
+#[cfg(feature = "full")]
 pub mod waf_tag;
+#[cfg(feature = "full")]
 pub use self::waf_tag::WafTag;
+#[cfg(feature = "waf")]
 pub mod waf_tag_attributes;
+#[cfg(feature = "waf")]
 pub use self::waf_tag_attributes::WafTagAttributes;
+#[cfg(feature = "waf")]
 pub mod waf_tags_response;
+#[cfg(feature = "waf")]
 pub use self::waf_tags_response::WafTagsResponse;
+#[cfg(feature = "full")]
 pub mod waf_tags_response_all_of;
+#[cfg(feature = "full")]
 pub use self::waf_tags_response_all_of::WafTagsResponseAllOf;
+#[cfg(feature = "waf")]
 pub mod waf_tags_response_data_item;
+#[cfg(feature = "waf")]
 pub use self::waf_tags_response_data_item::WafTagsResponseDataItem;
+#[cfg(feature = "platform")]
 pub mod ws_message_format;
+#[cfg(feature = "platform")]
 pub use self::ws_message_format::WsMessageFormat;
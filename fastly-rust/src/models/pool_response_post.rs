@@ -18,7 +18,7 @@ pub struct PoolResponsePost {
     pub tls_client_cert: Option<String>,
     /// The client private key used to make authenticated requests. Must be in PEM format.
     #[serde(rename = "tls_client_key", skip_serializing_if = "Option::is_none")]
-    pub tls_client_key: Option<String>,
+    pub tls_client_key: Option<crate::apis::redact::Secret>,
     /// The hostname used to verify a server's certificate. It can either be the Common Name (CN) or a Subject Alternative Name (SAN).
     #[serde(rename = "tls_cert_hostname", skip_serializing_if = "Option::is_none")]
     pub tls_cert_hostname: Option<String>,
@@ -15,7 +15,7 @@ pub struct Secret {
     pub name: Option<String>,
     /// A Base64-encoded string containing either the secret or the encrypted secret (when using client_key). The maximum secret size (before Base64 encoding and optional local encryption) is 64KB.
     #[serde(rename = "secret", skip_serializing_if = "Option::is_none")]
-    pub secret: Option<String>,
+    pub secret: Option<crate::apis::redact::Secret>,
     /// The Base64-encoded string containing the client key used to encrypt the secret, if applicable.
     #[serde(rename = "client_key", skip_serializing_if = "Option::is_none")]
     pub client_key: Option<String>,
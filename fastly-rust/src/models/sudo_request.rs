@@ -13,7 +13,7 @@ pub struct SudoRequest {
     #[serde(rename = "username")]
     pub username: String,
     #[serde(rename = "password")]
-    pub password: String,
+    pub password: crate::apis::redact::Secret,
     #[serde(rename = "expiry_time", skip_serializing_if = "Option::is_none")]
     pub expiry_time: Option<String>,
 }
@@ -22,7 +22,7 @@ impl SudoRequest {
     pub fn new(username: String, password: String) -> SudoRequest {
         SudoRequest {
             username,
-            password,
+            password: password.into(),
             expiry_time: None,
         }
     }
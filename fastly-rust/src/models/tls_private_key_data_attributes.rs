@@ -15,7 +15,7 @@ pub struct TlsPrivateKeyDataAttributes {
     pub name: Option<String>,
     /// The contents of the private key. Must be a PEM-formatted key. Not returned in response body. Required.
     #[serde(rename = "key", skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+    pub key: Option<crate::apis::redact::Secret>,
 }
 
 impl TlsPrivateKeyDataAttributes {
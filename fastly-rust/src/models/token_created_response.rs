@@ -46,7 +46,7 @@ pub struct TokenCreatedResponse {
     pub user_agent: Option<String>,
     /// The alphanumeric string for accessing the API (only available on token creation).
     #[serde(rename = "access_token", skip_serializing_if = "Option::is_none")]
-    pub access_token: Option<String>,
+    pub access_token: Option<crate::apis::redact::Secret>,
 }
 
 impl TokenCreatedResponse {
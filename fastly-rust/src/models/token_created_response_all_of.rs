@@ -12,7 +12,7 @@
 pub struct TokenCreatedResponseAllOf {
     /// The alphanumeric string for accessing the API (only available on token creation).
     #[serde(rename = "access_token", skip_serializing_if = "Option::is_none")]
-    pub access_token: Option<String>,
+    pub access_token: Option<crate::apis::redact::Secret>,
 }
 
 impl TokenCreatedResponseAllOf {
@@ -0,0 +1,256 @@
+//! An in-memory fake of the Fastly API, for tests that should not touch the
+//! network. Enabled by the `testing` feature.
+//!
+//! [`FakeFastly`] listens on a local port and keeps state between calls, so
+//! a test can create a service, clone and activate versions, and add
+//! healthchecks, backends, domains, dictionaries, ACLs and KV store items
+//! through the regular [`apis`](crate::apis) functions, then assert on the
+//! resulting state and on the requests the server received:
+//!
+//! ```no_run
+//! # use fastly_api::apis::healthcheck_api::{create_healthcheck, CreateHealthcheckParams};
+//! # use fastly_api::testing::FakeFastly;
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let fake = FakeFastly::start().await?;
+//! let service_id = fake.create_service("example");
+//!
+//! let params = CreateHealthcheckParams {
+//!     service_id: service_id.clone(),
+//!     version_id: 1,
+//!     name: Some("origin-health".to_owned()),
+//!     ..Default::default()
+//! };
+//! create_healthcheck(&fake.configuration(), params).await?;
+//!
+//! assert_eq!(fake.healthchecks(&service_id, 1).len(), 1);
+//! assert_eq!(fake.requests()[0].form_value("name").as_deref(), Some("origin-health"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Like the API, the fake rejects requests without credentials, changes to
+//! locked or active versions, and duplicate names; the public IP list needs
+//! no credentials. Endpoints it does not model answer `404 Not found`.
+
+use std::convert::Infallible;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+
+use hyper::service::{make_service_fn, service_fn};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::sync::oneshot;
+
+use crate::apis::configuration::{ApiKey, Configuration};
+use crate::models;
+
+mod state;
+
+use state::State;
+
+/// The API token [`FakeFastly::configuration`] authenticates with.
+pub const FAKE_TOKEN: &str = "fake-fastly-token";
+
+/// A request received by [`FakeFastly`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedRequest {
+    pub method: String,
+    /// Still percent-encoded, as sent.
+    pub path: String,
+    pub query: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl ReceivedRequest {
+    /// The value of header `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+
+    pub fn query_param(&self, name: &str) -> Option<String> {
+        url::form_urlencoded::parse(self.query.as_bytes()).find(|(k, _)| k == name).map(|(_, v)| v.into_owned())
+    }
+
+    /// The first value of `name` in a form-encoded body.
+    pub fn form_value(&self, name: &str) -> Option<String> {
+        url::form_urlencoded::parse(self.body.as_bytes()).find(|(k, _)| k == name).map(|(_, v)| v.into_owned())
+    }
+}
+
+/// A stateful stand-in for `api.fastly.com`. Stops when dropped.
+#[derive(Debug)]
+pub struct FakeFastly {
+    base_path: String,
+    state: Arc<Mutex<State>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl FakeFastly {
+    /// Start serving on a free port of `127.0.0.1`. Must be called from
+    /// within a Tokio runtime.
+    pub async fn start() -> std::io::Result<FakeFastly> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))?;
+        listener.set_nonblocking(true)?;
+        let base_path = format!("http://{}", listener.local_addr()?);
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let shared = state.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = shared.clone();
+            async move { Ok::<_, Infallible>(service_fn(move |request| serve(state.clone(), request))) }
+        });
+        let server = hyper::Server::from_tcp(listener)
+            .map_err(std::io::Error::other)?
+            .serve(make_service);
+        let (shutdown, stopped) = oneshot::channel::<()>();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            let _ = stopped.await;
+        }));
+
+        Ok(FakeFastly { base_path, state, shutdown: Some(shutdown) })
+    }
+
+    /// `http://127.0.0.1:<port>`, for [`Configuration::base_path`].
+    pub fn base_path(&self) -> &str {
+        &self.base_path
+    }
+
+    /// A configuration that talks to this fake with [`FAKE_TOKEN`]. Realtime
+    /// endpoints are routed here too, though the fake does not model them.
+    pub fn configuration(&self) -> Configuration {
+        let mut configuration = Configuration {
+            base_path: self.base_path.clone(),
+            api_key: Some(ApiKey { prefix: None, key: FAKE_TOKEN.into() }),
+            ..Default::default()
+        };
+        for group in [
+            crate::apis::configuration::EndpointGroup::Realtime,
+            crate::apis::configuration::EndpointGroup::OriginInspectorRealtime,
+            crate::apis::configuration::EndpointGroup::DomainInspectorRealtime,
+        ] {
+            configuration.endpoint_base_paths.insert(group, self.base_path.clone());
+        }
+        configuration
+    }
+
+    /// Every request received so far, oldest first.
+    pub fn requests(&self) -> Vec<ReceivedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Add a service named `name`, with an empty version 1, and return its ID.
+    pub fn create_service(&self, name: &str) -> String {
+        let mut fields = serde_json::Map::new();
+        fields.insert("name".to_owned(), Value::String(name.to_owned()));
+        self.state.lock().unwrap().create_service(fields)
+    }
+
+    pub fn service(&self, service_id: &str) -> Option<models::ServiceResponse> {
+        let state = self.state.lock().unwrap();
+        state.service(service_id).ok().map(|s| convert(s.render()))
+    }
+
+    pub fn version(&self, service_id: &str, version_id: i32) -> Option<models::VersionResponse> {
+        let state = self.state.lock().unwrap();
+        let service = state.service(service_id).ok()?;
+        service.version(version_id).ok().map(|v| convert(service.render_version(v)))
+    }
+
+    pub fn healthchecks(&self, service_id: &str, version_id: i32) -> Vec<models::HealthcheckResponse> {
+        self.resources(service_id, version_id, "healthcheck")
+    }
+
+    pub fn backends(&self, service_id: &str, version_id: i32) -> Vec<models::BackendResponse> {
+        self.resources(service_id, version_id, "backend")
+    }
+
+    pub fn domains(&self, service_id: &str, version_id: i32) -> Vec<models::DomainResponse> {
+        self.resources(service_id, version_id, "domain")
+    }
+
+    pub fn dictionaries(&self, service_id: &str, version_id: i32) -> Vec<models::DictionaryResponse> {
+        self.resources(service_id, version_id, "dictionary")
+    }
+
+    pub fn acls(&self, service_id: &str, version_id: i32) -> Vec<models::AclResponse> {
+        self.resources(service_id, version_id, "acl")
+    }
+
+    /// The items of dictionary `dictionary_id`, by key.
+    pub fn dictionary_items(&self, service_id: &str, dictionary_id: &str) -> Vec<(String, String)> {
+        let state = self.state.lock().unwrap();
+        let items = state.service(service_id).ok().and_then(|s| s.dictionary_items.get(dictionary_id));
+        items.into_iter().flatten().map(|(k, v)| (k.clone(), v.clone())).collect()
+    }
+
+    pub fn acl_entries(&self, service_id: &str, acl_id: &str) -> Vec<models::AclEntryResponse> {
+        let state = self.state.lock().unwrap();
+        let entries = state.service(service_id).ok().and_then(|s| s.acl_entries.get(acl_id));
+        entries
+            .into_iter()
+            .flatten()
+            .map(|(id, entry)| {
+                let mut entry = entry.clone();
+                entry.insert("id".to_owned(), Value::String(id.clone()));
+                entry.insert("acl_id".to_owned(), Value::String(acl_id.to_owned()));
+                entry.insert("service_id".to_owned(), Value::String(service_id.to_owned()));
+                convert(Value::Object(entry))
+            })
+            .collect()
+    }
+
+    pub fn kv_value(&self, store_id: &str, key: &str) -> Option<String> {
+        let state = self.state.lock().unwrap();
+        state.kv_store(store_id).ok()?.items.get(key).cloned()
+    }
+
+    fn resources<T: DeserializeOwned>(&self, service_id: &str, version_id: i32, kind: &str) -> Vec<T> {
+        let state = self.state.lock().unwrap();
+        let Ok(service) = state.service(service_id) else { return Vec::new() };
+        let Ok(version) = service.version(version_id) else { return Vec::new() };
+        service.render_resources(version, kind).into_iter().map(convert).collect()
+    }
+}
+
+impl Drop for FakeFastly {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// The fake renders the shapes the models expect, so this only fails on a bug in the fake.
+fn convert<T: DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).expect("fake rendered a value the model does not accept")
+}
+
+async fn serve(state: Arc<Mutex<State>>, request: hyper::Request<hyper::Body>) -> Result<hyper::Response<hyper::Body>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+    let received = ReceivedRequest {
+        method: parts.method.to_string(),
+        path: parts.uri.path().to_owned(),
+        query: parts.uri.query().unwrap_or_default().to_owned(),
+        headers: parts
+            .headers
+            .iter()
+            .map(|(k, v)| (k.to_string(), String::from_utf8_lossy(v.as_bytes()).into_owned()))
+            .collect(),
+        body: String::from_utf8_lossy(&body).into_owned(),
+    };
+
+    let (reply, request_id) = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(received.clone());
+        (state.handle(&received), format!("fake-request-{}", state.requests.len()))
+    };
+
+    let response = hyper::Response::builder().status(reply.status).header("Fastly-Request-ID", request_id);
+    let response = match reply.body {
+        Some(body) => response.header(hyper::header::CONTENT_TYPE, "application/json").body(hyper::Body::from(body.to_string())),
+        None => response.body(hyper::Body::empty()),
+    };
+    Ok(response.expect("status and headers are valid"))
+}
@@ -0,0 +1,675 @@
+//! What the fake remembers, and how it answers each endpoint.
+//!
+//! Resources are kept as the JSON objects the API would return, built from
+//! the submitted form fields, so new fields need no changes here.
+
+use std::collections::BTreeMap;
+
+use serde_json::{json, Map, Value};
+
+use super::ReceivedRequest;
+
+/// Form fields the API returns as numbers rather than strings.
+const INTEGER_FIELDS: &[&str] = &[
+    "between_bytes_timeout",
+    "check_interval",
+    "connect_timeout",
+    "expected_response",
+    "first_byte_timeout",
+    "initial",
+    "keepalive_time",
+    "max_conn",
+    "port",
+    "subnet",
+    "tcp_keepalive_interval",
+    "tcp_keepalive_probes",
+    "tcp_keepalive_time",
+    "threshold",
+    "timeout",
+    "weight",
+    "window",
+];
+
+/// Form fields the API returns as booleans rather than strings.
+const BOOLEAN_FIELDS: &[&str] = &["auto_loadbalance", "ssl_check_cert", "tcp_keepalive_enable", "use_ssl", "write_only"];
+
+/// Version-scoped collections, by the path segment that names them.
+pub(super) const KINDS: &[&str] = &["acl", "backend", "dictionary", "domain", "healthcheck"];
+
+type Fields = Map<String, Value>;
+
+pub(super) struct Reply {
+    pub status: u16,
+    /// `None` for an empty body.
+    pub body: Option<Value>,
+}
+
+impl Reply {
+    fn ok(body: Value) -> Reply {
+        Reply { status: 200, body: Some(body) }
+    }
+
+    fn status_ok() -> Reply {
+        Reply::ok(json!({ "status": "ok" }))
+    }
+
+    fn no_content() -> Reply {
+        Reply { status: 204, body: None }
+    }
+
+    fn error(status: u16, msg: &str, detail: impl Into<String>) -> Reply {
+        Reply { status, body: Some(json!({ "msg": msg, "detail": detail.into() })) }
+    }
+
+    fn not_found(detail: impl Into<String>) -> Reply {
+        Reply::error(404, "Record not found", detail)
+    }
+
+    fn bad_request(detail: impl Into<String>) -> Reply {
+        Reply::error(400, "Bad request", detail)
+    }
+
+    fn duplicate(detail: impl Into<String>) -> Reply {
+        Reply::error(409, "Duplicate record", detail)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct State {
+    ids: Ids,
+    pub services: BTreeMap<String, Service>,
+    pub kv_stores: BTreeMap<String, KvStore>,
+    pub requests: Vec<ReceivedRequest>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Service {
+    pub id: String,
+    /// `name`, `comment`, `customer_id` and `type`.
+    pub fields: Fields,
+    pub versions: Vec<Version>,
+    /// Items by dictionary ID, then key. Shared by every version, as in the API.
+    pub dictionary_items: BTreeMap<String, BTreeMap<String, String>>,
+    /// Entries by ACL ID, then entry ID. Shared by every version, as in the API.
+    pub acl_entries: BTreeMap<String, BTreeMap<String, Fields>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Version {
+    pub number: i32,
+    pub active: bool,
+    pub locked: bool,
+    pub comment: String,
+    /// Resources by kind (one of [`KINDS`]), then name.
+    pub resources: BTreeMap<String, BTreeMap<String, Fields>>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct KvStore {
+    pub id: String,
+    pub name: String,
+    pub items: BTreeMap<String, String>,
+}
+
+/// Source of the IDs the fake hands out, unique across all resources.
+#[derive(Debug, Default)]
+struct Ids(u64);
+
+impl Ids {
+    fn next(&mut self, prefix: &str) -> String {
+        self.0 += 1;
+        format!("{}{:0>width$}", prefix, self.0, width = 22 - prefix.len())
+    }
+}
+
+impl State {
+    /// Add a service with an empty, inactive version 1 and return its ID.
+    pub fn create_service(&mut self, mut fields: Fields) -> String {
+        let id = self.ids.next("fakesvc");
+        fields.entry("customer_id").or_insert_with(|| json!("fakecustomer0000000001"));
+        fields.entry("type").or_insert_with(|| json!("vcl"));
+        fields.entry("comment").or_insert_with(|| json!(""));
+        let service = Service {
+            id: id.clone(),
+            fields,
+            versions: vec![Version { number: 1, ..Default::default() }],
+            dictionary_items: BTreeMap::new(),
+            acl_entries: BTreeMap::new(),
+        };
+        self.services.insert(id.clone(), service);
+        id
+    }
+
+    pub fn handle(&mut self, request: &ReceivedRequest) -> Reply {
+        let segments: Vec<String> = request.path.trim_matches('/').split('/').map(decode).collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+        // The one endpoint the API serves without credentials.
+        if let ("GET", ["public-ip-list"]) = (request.method.as_str(), segments.as_slice()) {
+            return Reply::ok(json!({ "addresses": ["23.235.32.0/20"], "ipv6_addresses": ["2a04:4e40::/32"] }));
+        }
+        if request.header("fastly-key").is_none() && request.header("authorization").is_none() {
+            return Reply::error(401, "Provided credentials are missing or invalid", "");
+        }
+        let result = match segments.as_slice() {
+            ["service", rest @ ..] => self.service_route(request, rest),
+            ["resources", "stores", "kv", rest @ ..] => self.kv_route(request, rest),
+            _ => Err(not_routed(request)),
+        };
+        result.unwrap_or_else(|reply| reply)
+    }
+
+    fn service_route(&mut self, request: &ReceivedRequest, path: &[&str]) -> Result<Reply, Reply> {
+        let method = request.method.as_str();
+        match (method, path) {
+            ("GET", []) => Ok(self.list_services(request)),
+            ("POST", []) => {
+                let fields = submitted(request);
+                if !fields.contains_key("name") {
+                    return Err(Reply::bad_request("Required parameter 'name' is missing"));
+                }
+                let id = self.create_service(fields);
+                Ok(Reply::ok(self.services[&id].render()))
+            }
+            ("GET", ["search"]) => {
+                let name = request.query_param("name").unwrap_or_default();
+                self.services
+                    .values()
+                    .find(|s| s.fields.get("name").and_then(Value::as_str) == Some(name.as_str()))
+                    .map(|s| Reply::ok(s.render()))
+                    .ok_or_else(|| Reply::not_found(format!("Couldn't find Service '{}'", name)))
+            }
+            ("GET", [id]) => Ok(Reply::ok(self.service(id)?.render())),
+            ("PUT", [id]) => {
+                let service = self.service_mut(id)?;
+                service.fields.extend(submitted(request));
+                Ok(Reply::ok(service.render()))
+            }
+            ("DELETE", [id]) => {
+                self.services.remove(*id).ok_or_else(|| missing_service(id))?;
+                Ok(Reply::status_ok())
+            }
+            ("GET", [id, "details"]) => Ok(Reply::ok(self.service(id)?.render_detail())),
+            ("GET", [id, "domain"]) => {
+                let service = self.service(id)?;
+                let latest = service.versions.last().expect("services always have a version");
+                Ok(Reply::ok(Value::Array(service.render_resources(latest, "domain"))))
+            }
+            ("GET", [id, "version"]) => {
+                let service = self.service(id)?;
+                Ok(Reply::ok(service.versions.iter().map(|v| service.render_version(v)).collect()))
+            }
+            ("POST", [id, "version"]) => {
+                let service = self.service_mut(id)?;
+                let number = service.versions.len() as i32 + 1;
+                service.versions.push(Version { number, ..Default::default() });
+                Ok(Reply::ok(json!({ "number": number, "service_id": service.id })))
+            }
+            (_, [id, "version", number, rest @ ..]) => {
+                let number: i32 = number.parse().map_err(|_| Reply::bad_request(format!("Invalid version '{}'", number)))?;
+                let service = self.services.get_mut(*id).ok_or_else(|| missing_service(id))?;
+                service.version(number)?;
+                version_route(request, service, &mut self.ids, number, rest)
+            }
+            (_, [id, "dictionary", dictionary_id, rest @ ..]) => {
+                let service = self.service_mut(id)?;
+                dictionary_route(request, service, dictionary_id, rest)
+            }
+            (_, [id, "acl", acl_id, rest @ ..]) => {
+                let service = self.services.get_mut(*id).ok_or_else(|| missing_service(id))?;
+                acl_route(request, service, &mut self.ids, acl_id, rest)
+            }
+            _ => Err(not_routed(request)),
+        }
+    }
+
+    fn list_services(&self, request: &ReceivedRequest) -> Reply {
+        let services: Vec<Value> = self.services.values().map(Service::render_list_item).collect();
+        // Without paging parameters every service is returned at once.
+        let per_page = request.query_param("per_page").and_then(|v| v.parse::<usize>().ok());
+        let page = request.query_param("page").and_then(|v| v.parse::<usize>().ok());
+        if per_page.is_none() && page.is_none() {
+            return Reply::ok(Value::Array(services));
+        }
+        let per_page = per_page.unwrap_or(20).max(1);
+        let skip = page.unwrap_or(1).saturating_sub(1) * per_page;
+        Reply::ok(services.into_iter().skip(skip).take(per_page).collect())
+    }
+
+    fn kv_route(&mut self, request: &ReceivedRequest, path: &[&str]) -> Result<Reply, Reply> {
+        match (request.method.as_str(), path) {
+            ("POST", []) => {
+                let body: Value = serde_json::from_str(&request.body).unwrap_or_default();
+                let name = body.get("name").and_then(Value::as_str).ok_or_else(|| Reply::bad_request("Required parameter 'name' is missing"))?;
+                if self.kv_stores.values().any(|s| s.name == name) {
+                    return Err(Reply::duplicate(format!("Duplicate store: '{}'", name)));
+                }
+                let id = self.ids.next("fakekv");
+                let store = KvStore { id: id.clone(), name: name.to_owned(), items: BTreeMap::new() };
+                let reply = Reply::ok(store.render());
+                self.kv_stores.insert(id, store);
+                Ok(reply)
+            }
+            ("GET", []) => {
+                let ids: Vec<String> = self.kv_stores.keys().cloned().collect();
+                let (page, next_cursor, limit) = cursor_page(request, ids);
+                let data: Vec<Value> = page.iter().map(|id| self.kv_stores[id].render()).collect();
+                Ok(Reply::ok(json!({ "data": data, "meta": { "next_cursor": next_cursor, "limit": limit } })))
+            }
+            ("GET", [id]) => Ok(Reply::ok(self.kv_store(id)?.render())),
+            ("DELETE", [id]) => {
+                self.kv_stores.remove(*id).ok_or_else(|| missing_store(id))?;
+                Ok(Reply::no_content())
+            }
+            ("GET", [id, "keys"]) => {
+                let prefix = request.query_param("prefix").unwrap_or_default();
+                let keys: Vec<String> = self.kv_store(id)?.items.keys().filter(|k| k.starts_with(&prefix)).cloned().collect();
+                let (page, next_cursor, limit) = cursor_page(request, keys);
+                Ok(Reply::ok(json!({ "data": page, "meta": { "next_cursor": next_cursor, "limit": limit } })))
+            }
+            ("GET", [id, "keys", key]) => {
+                let value = self.kv_store(id)?.items.get(*key).ok_or_else(|| Reply::not_found(format!("Couldn't find key '{}'", key)))?;
+                Ok(Reply::ok(json!(value)))
+            }
+            ("PUT", [id, "keys", key]) => {
+                // The generated client sends the value as a JSON string.
+                let value = match serde_json::from_str(&request.body) {
+                    Ok(Value::String(value)) => value,
+                    _ => request.body.clone(),
+                };
+                let store = self.kv_stores.get_mut(*id).ok_or_else(|| missing_store(id))?;
+                store.items.insert((*key).to_owned(), value.clone());
+                Ok(Reply::ok(json!(value)))
+            }
+            ("DELETE", [id, "keys", key]) => {
+                let store = self.kv_stores.get_mut(*id).ok_or_else(|| missing_store(id))?;
+                store.items.remove(*key).ok_or_else(|| Reply::not_found(format!("Couldn't find key '{}'", key)))?;
+                Ok(Reply::no_content())
+            }
+            _ => Err(not_routed(request)),
+        }
+    }
+
+    pub fn service(&self, id: &str) -> Result<&Service, Reply> {
+        self.services.get(id).ok_or_else(|| missing_service(id))
+    }
+
+    fn service_mut(&mut self, id: &str) -> Result<&mut Service, Reply> {
+        self.services.get_mut(id).ok_or_else(|| missing_service(id))
+    }
+
+    pub fn kv_store(&self, id: &str) -> Result<&KvStore, Reply> {
+        self.kv_stores.get(id).ok_or_else(|| missing_store(id))
+    }
+}
+
+fn version_route(request: &ReceivedRequest, service: &mut Service, ids: &mut Ids, number: i32, path: &[&str]) -> Result<Reply, Reply> {
+    match (request.method.as_str(), path) {
+        ("GET", []) => Ok(Reply::ok(service.render_version(service.version(number)?))),
+        ("PUT", []) => {
+            let comment = submitted(request).get("comment").and_then(Value::as_str).unwrap_or_default().to_owned();
+            service.version_mut(number)?.comment = comment;
+            Ok(Reply::ok(service.render_version(service.version(number)?)))
+        }
+        ("PUT", ["clone"]) => {
+            let next = service.versions.len() as i32 + 1;
+            let source = service.version(number)?;
+            let clone = Version {
+                number: next,
+                active: false,
+                locked: false,
+                comment: source.comment.clone(),
+                resources: source.resources.clone(),
+            };
+            service.versions.push(clone);
+            Ok(Reply::ok(service.render_version(service.version(next)?)))
+        }
+        ("PUT", ["activate"]) => {
+            for version in &mut service.versions {
+                version.active = version.number == number;
+            }
+            let version = service.version_mut(number)?;
+            version.locked = true;
+            Ok(Reply::ok(service.render_version(service.version(number)?)))
+        }
+        ("PUT", ["deactivate"]) => {
+            service.version_mut(number)?.active = false;
+            Ok(Reply::ok(service.render_version(service.version(number)?)))
+        }
+        ("PUT", ["lock"]) => {
+            service.version_mut(number)?.locked = true;
+            Ok(Reply::ok(service.render_version(service.version(number)?)))
+        }
+        ("GET", ["validate"]) => Ok(Reply::status_ok()),
+        (method, [kind]) if KINDS.contains(kind) => match method {
+            "GET" => Ok(Reply::ok(Value::Array(service.render_resources(service.version(number)?, kind)))),
+            "POST" => {
+                let mut fields = submitted(request);
+                let name = fields.get("name").and_then(Value::as_str).map(str::to_owned);
+                let name = name.ok_or_else(|| Reply::bad_request("Required parameter 'name' is missing"))?;
+                // Dictionaries and ACLs get an ID that outlives the version.
+                let prefix = match *kind {
+                    "dictionary" => Some("fakedict"),
+                    "acl" => Some("fakeacl"),
+                    _ => None,
+                };
+                if let Some(prefix) = prefix {
+                    fields.insert("id".to_owned(), json!(ids.next(prefix)));
+                }
+                let collection = service.editable(number)?.resources.entry((*kind).to_owned()).or_default();
+                if collection.contains_key(&name) {
+                    return Err(Reply::duplicate(format!("Duplicate {}: '{}'", kind, name)));
+                }
+                collection.insert(name.clone(), fields);
+                Ok(Reply::ok(service.render_resource(number, kind, &name)?))
+            }
+            _ => Err(not_routed(request)),
+        },
+        (method, [kind, name]) if KINDS.contains(kind) => {
+            service.resource(number, kind, name)?;
+            match method {
+                "GET" => Ok(Reply::ok(service.render_resource(number, kind, name)?)),
+                "PUT" => {
+                    let update = submitted(request);
+                    let collection = service.editable(number)?.resources.get_mut(*kind).expect("resource was found above");
+                    let mut fields = collection.remove(*name).expect("resource was found above");
+                    fields.extend(update);
+                    let renamed = fields.get("name").and_then(Value::as_str).unwrap_or(name).to_owned();
+                    if renamed != *name && collection.contains_key(&renamed) {
+                        collection.insert((*name).to_owned(), fields);
+                        return Err(Reply::duplicate(format!("Duplicate {}: '{}'", kind, renamed)));
+                    }
+                    collection.insert(renamed.clone(), fields);
+                    Ok(Reply::ok(service.render_resource(number, kind, &renamed)?))
+                }
+                "DELETE" => {
+                    service.editable(number)?.resources.get_mut(*kind).map(|c| c.remove(*name));
+                    Ok(Reply::status_ok())
+                }
+                _ => Err(not_routed(request)),
+            }
+        }
+        _ => Err(not_routed(request)),
+    }
+}
+
+fn dictionary_route(request: &ReceivedRequest, service: &mut Service, dictionary_id: &str, path: &[&str]) -> Result<Reply, Reply> {
+    if !service.has_resource_id("dictionary", dictionary_id) {
+        return Err(Reply::not_found(format!("Couldn't find Dictionary '{}'", dictionary_id)));
+    }
+    let service_id = service.id.clone();
+    let render = |key: &str, value: &str| json!({ "dictionary_id": dictionary_id, "service_id": service_id, "item_key": key, "item_value": value });
+    let items = service.dictionary_items.entry(dictionary_id.to_owned()).or_default();
+    let missing = |key: &str| Reply::not_found(format!("Couldn't find Dictionary item '{}'", key));
+
+    match (request.method.as_str(), path) {
+        ("GET", ["item"]) => Ok(Reply::ok(items.iter().map(|(k, v)| render(k, v)).collect())),
+        ("POST", ["item"]) => {
+            let fields = submitted(request);
+            let key = fields.get("item_key").and_then(Value::as_str).ok_or_else(|| Reply::bad_request("Required parameter 'item_key' is missing"))?;
+            let value = fields.get("item_value").and_then(Value::as_str).unwrap_or_default();
+            if items.contains_key(key) {
+                return Err(Reply::duplicate(format!("Duplicate Dictionary item: '{}'", key)));
+            }
+            items.insert(key.to_owned(), value.to_owned());
+            Ok(Reply::ok(render(key, value)))
+        }
+        ("GET", ["item", key]) => items.get(*key).map(|v| Reply::ok(render(key, v))).ok_or_else(|| missing(key)),
+        ("PATCH", ["item", key]) | ("PUT", ["item", key]) => {
+            if request.method == "PATCH" && !items.contains_key(*key) {
+                return Err(missing(key));
+            }
+            let value = submitted(request).get("item_value").and_then(Value::as_str).unwrap_or_default().to_owned();
+            items.insert((*key).to_owned(), value.clone());
+            Ok(Reply::ok(render(key, &value)))
+        }
+        ("DELETE", ["item", key]) => {
+            items.remove(*key).ok_or_else(|| missing(key))?;
+            Ok(Reply::status_ok())
+        }
+        ("PATCH", ["items"]) => {
+            let body: Value = serde_json::from_str(&request.body).unwrap_or_default();
+            for item in body.get("items").and_then(Value::as_array).into_iter().flatten() {
+                let key = item.get("item_key").and_then(Value::as_str).unwrap_or_default();
+                let value = item.get("item_value").and_then(Value::as_str).unwrap_or_default();
+                match item.get("op").and_then(Value::as_str) {
+                    Some("create") if items.contains_key(key) => return Err(Reply::duplicate(format!("Duplicate Dictionary item: '{}'", key))),
+                    Some("update") if !items.contains_key(key) => return Err(missing(key)),
+                    Some("create" | "update" | "upsert") => {
+                        items.insert(key.to_owned(), value.to_owned());
+                    }
+                    Some("delete") => {
+                        items.remove(key);
+                    }
+                    op => return Err(Reply::bad_request(format!("Invalid op {:?}", op))),
+                }
+            }
+            Ok(Reply::status_ok())
+        }
+        _ => Err(not_routed(request)),
+    }
+}
+
+fn acl_route(request: &ReceivedRequest, service: &mut Service, ids: &mut Ids, acl_id: &str, path: &[&str]) -> Result<Reply, Reply> {
+    if !service.has_resource_id("acl", acl_id) {
+        return Err(Reply::not_found(format!("Couldn't find ACL '{}'", acl_id)));
+    }
+    let service_id = service.id.clone();
+    let render = |id: &str, fields: &Fields| {
+        let mut entry = fields.clone();
+        entry.insert("id".to_owned(), json!(id));
+        entry.insert("acl_id".to_owned(), json!(acl_id));
+        entry.insert("service_id".to_owned(), json!(service_id));
+        Value::Object(entry)
+    };
+    let entries = service.acl_entries.entry(acl_id.to_owned()).or_default();
+    let missing = |id: &str| Reply::not_found(format!("Couldn't find ACL entry '{}'", id));
+
+    match (request.method.as_str(), path) {
+        ("GET", ["entries"]) => Ok(Reply::ok(entries.iter().map(|(id, e)| render(id, e)).collect())),
+        ("POST", ["entry"]) => {
+            let fields = submitted(request);
+            if !fields.contains_key("ip") {
+                return Err(Reply::bad_request("Required parameter 'ip' is missing"));
+            }
+            let id = ids.next("fakeaclentry");
+            let reply = Reply::ok(render(&id, &fields));
+            entries.insert(id, fields);
+            Ok(reply)
+        }
+        ("GET", ["entry", id]) => entries.get(*id).map(|e| Reply::ok(render(id, e))).ok_or_else(|| missing(id)),
+        ("PATCH", ["entry", id]) => {
+            let entry = entries.get_mut(*id).ok_or_else(|| missing(id))?;
+            entry.extend(submitted(request));
+            Ok(Reply::ok(render(id, entry)))
+        }
+        ("DELETE", ["entry", id]) => {
+            entries.remove(*id).ok_or_else(|| missing(id))?;
+            Ok(Reply::status_ok())
+        }
+        ("PATCH", ["entries"]) => {
+            let body: Value = serde_json::from_str(&request.body).unwrap_or_default();
+            for entry in body.get("entries").and_then(Value::as_array).into_iter().flatten() {
+                let Value::Object(mut fields) = entry.clone() else { continue };
+                let op = fields.remove("op");
+                let id = fields.remove("id").and_then(|v| v.as_str().map(str::to_owned));
+                match (op.as_ref().and_then(Value::as_str), id) {
+                    (Some("create"), _) => {
+                        entries.insert(ids.next("fakeaclentry"), fields);
+                    }
+                    (Some("update"), Some(id)) => entries.get_mut(&id).ok_or_else(|| missing(&id))?.extend(fields),
+                    (Some("delete"), Some(id)) => {
+                        entries.remove(&id).ok_or_else(|| missing(&id))?;
+                    }
+                    (op, _) => return Err(Reply::bad_request(format!("Invalid op {:?} or missing id", op))),
+                }
+            }
+            Ok(Reply::status_ok())
+        }
+        _ => Err(not_routed(request)),
+    }
+}
+
+impl Service {
+    pub fn version(&self, number: i32) -> Result<&Version, Reply> {
+        self.versions
+            .iter()
+            .find(|v| v.number == number)
+            .ok_or_else(|| Reply::not_found(format!("Couldn't find Version '{}' of Service '{}'", number, self.id)))
+    }
+
+    fn version_mut(&mut self, number: i32) -> Result<&mut Version, Reply> {
+        let id = self.id.clone();
+        self.versions
+            .iter_mut()
+            .find(|v| v.number == number)
+            .ok_or_else(|| Reply::not_found(format!("Couldn't find Version '{}' of Service '{}'", number, id)))
+    }
+
+    /// The version, if it may still be changed.
+    fn editable(&mut self, number: i32) -> Result<&mut Version, Reply> {
+        let version = self.version_mut(number)?;
+        if version.locked || version.active {
+            return Err(Reply::bad_request(format!("Version {} is locked", number)));
+        }
+        Ok(version)
+    }
+
+    fn resource(&self, number: i32, kind: &str, name: &str) -> Result<&Fields, Reply> {
+        self.version(number)?
+            .resources
+            .get(kind)
+            .and_then(|c| c.get(name))
+            .ok_or_else(|| Reply::not_found(format!("Couldn't find {} '{}'", kind, name)))
+    }
+
+    fn has_resource_id(&self, kind: &str, id: &str) -> bool {
+        self.versions
+            .iter()
+            .filter_map(|v| v.resources.get(kind))
+            .flat_map(|c| c.values())
+            .any(|r| r.get("id").and_then(Value::as_str) == Some(id))
+    }
+
+    pub fn render(&self) -> Value {
+        let mut service = self.fields.clone();
+        service.insert("id".to_owned(), json!(self.id));
+        service.insert("paused".to_owned(), json!(false));
+        service.insert("versions".to_owned(), self.versions.iter().map(|v| self.render_version(v)).collect());
+        Value::Object(service)
+    }
+
+    fn render_list_item(&self) -> Value {
+        let mut service = self.render();
+        let current = self.versions.iter().find(|v| v.active).or(self.versions.last()).map(|v| v.number);
+        service["version"] = json!(current);
+        service
+    }
+
+    fn render_detail(&self) -> Value {
+        let detail = |version: &Version| {
+            let mut detail = self.render_version(version);
+            for (kind, field) in [("backend", "backends"), ("domain", "domains"), ("healthcheck", "healthchecks")] {
+                detail[field] = Value::Array(self.render_resources(version, kind));
+            }
+            detail
+        };
+        let mut service = self.render();
+        service["active_version"] = self.versions.iter().find(|v| v.active).map(detail).unwrap_or(Value::Null);
+        service["version"] = detail(self.versions.last().expect("services always have a version"));
+        service
+    }
+
+    pub fn render_version(&self, version: &Version) -> Value {
+        json!({
+            "number": version.number,
+            "active": version.active,
+            "locked": version.locked,
+            "comment": version.comment,
+            "deployed": false,
+            "staging": false,
+            "testing": false,
+            "service_id": self.id,
+        })
+    }
+
+    pub fn render_resources(&self, version: &Version, kind: &str) -> Vec<Value> {
+        let names: Vec<String> = version.resources.get(kind).map(|c| c.keys().cloned().collect()).unwrap_or_default();
+        names.iter().filter_map(|name| self.render_resource(version.number, kind, name).ok()).collect()
+    }
+
+    fn render_resource(&self, number: i32, kind: &str, name: &str) -> Result<Value, Reply> {
+        let mut resource = self.resource(number, kind, name)?.clone();
+        resource.insert("service_id".to_owned(), json!(self.id));
+        // ACLs report their version as a string.
+        let version = if kind == "acl" { json!(number.to_string()) } else { json!(number) };
+        resource.insert("version".to_owned(), version);
+        Ok(Value::Object(resource))
+    }
+}
+
+impl KvStore {
+    fn render(&self) -> Value {
+        json!({ "id": self.id, "name": self.name })
+    }
+}
+
+/// One page of `keys`, starting after the `cursor` query parameter, with the
+/// cursor for the next page and the page size.
+fn cursor_page(request: &ReceivedRequest, keys: Vec<String>) -> (Vec<String>, Option<String>, usize) {
+    let limit = request.query_param("limit").and_then(|v| v.parse().ok()).unwrap_or(100).max(1);
+    let cursor = request.query_param("cursor").unwrap_or_default();
+    let mut rest: Vec<String> = keys.into_iter().filter(|k| cursor.is_empty() || *k > cursor).collect();
+    let more = rest.len() > limit;
+    rest.truncate(limit);
+    let next_cursor = if more { rest.last().cloned() } else { None };
+    (rest, next_cursor, limit)
+}
+
+/// The fields submitted in `request`, from a JSON object body or a form
+/// body. Form `key[]` entries are collected into arrays and numeric and
+/// boolean form fields converted.
+fn submitted(request: &ReceivedRequest) -> Fields {
+    if let Ok(Value::Object(mut fields)) = serde_json::from_str(&request.body) {
+        fields.retain(|_, v| !v.is_null());
+        return fields;
+    }
+    let mut fields = Fields::new();
+    for (key, value) in url::form_urlencoded::parse(request.body.as_bytes()) {
+        match key.strip_suffix("[]") {
+            Some(key) => {
+                let array = fields.entry(key.to_owned()).or_insert_with(|| Value::Array(Vec::new()));
+                if let Value::Array(ref mut values) = array {
+                    values.push(Value::String(value.into_owned()));
+                }
+            }
+            None => {
+                let value = if INTEGER_FIELDS.contains(&key.as_ref()) {
+                    value.parse::<i64>().map(Value::from).unwrap_or_else(|_| Value::String(value.into_owned()))
+                } else if BOOLEAN_FIELDS.contains(&key.as_ref()) {
+                    Value::Bool(matches!(value.as_ref(), "true" | "1"))
+                } else {
+                    Value::String(value.into_owned())
+                };
+                fields.insert(key.into_owned(), value);
+            }
+        }
+    }
+    fields
+}
+
+/// Path parameters are encoded with [`crate::apis::urlencode`], which is form encoding.
+fn decode(segment: &str) -> String {
+    url::form_urlencoded::parse(segment.as_bytes()).next().map(|(k, _)| k.into_owned()).unwrap_or_default()
+}
+
+fn missing_service(id: &str) -> Reply {
+    Reply::not_found(format!("Couldn't find Service '{}'", id))
+}
+
+fn missing_store(id: &str) -> Reply {
+    Reply::not_found(format!("Couldn't find store '{}'", id))
+}
+
+fn not_routed(request: &ReceivedRequest) -> Reply {
+    Reply::error(404, "Not found", format!("the fake does not model {} {}", request.method, request.path))
+}
@@ -0,0 +1,94 @@
+//! Which credential is attached to a request.
+#![cfg(feature = "accounts")]
+
+mod common;
+
+use common::{get_service_params, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::{ApiKey, Configuration};
+use fastly_api::apis::service_api::get_service;
+use fastly_api::apis::user_api::{update_user_password, UpdateUserPasswordParams};
+use fastly_api::apis::Error;
+
+fn unauthenticated(server: &StandIn) -> Configuration {
+    Configuration {
+        base_path: server.base_path.clone(),
+        api_key: None,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn api_key_takes_precedence_over_bearer_tokens() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let cfg = Configuration {
+        api_key: Some(ApiKey { prefix: None, key: "api-token".into() }),
+        bearer_access_token: Some("bearer-token".into()),
+        basic_auth: Some(("user".to_owned(), Some("password".into()))),
+        ..unauthenticated(&server)
+    };
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    let request = server.last_request();
+    assert_eq!(request.header("fastly-key"), Some("api-token"));
+    assert_eq!(request.header("authorization"), None);
+}
+
+#[tokio::test]
+async fn bearer_and_oauth_tokens_are_sent_as_authorization() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let mut cfg = Configuration { oauth_access_token: Some("oauth-token".into()), ..unauthenticated(&server) };
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+    cfg.bearer_access_token = Some("bearer-token".into());
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("authorization"), Some("Bearer oauth-token"));
+    assert_eq!(requests[1].header("authorization"), Some("Bearer bearer-token"));
+    assert_eq!(requests[1].header("fastly-key"), None);
+}
+
+#[tokio::test]
+async fn basic_auth_is_used_when_no_token_is_configured() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let cfg = Configuration { basic_auth: Some(("user".to_owned(), Some("password".into()))), ..unauthenticated(&server) };
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    assert_eq!(server.last_request().header("authorization"), Some("Basic dXNlcjpwYXNzd29yZA=="));
+}
+
+#[tokio::test]
+async fn endpoint_declared_scheme_comes_first() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let cfg = Configuration {
+        api_key: Some(ApiKey { prefix: None, key: "api-token".into() }),
+        basic_auth: Some(("user".to_owned(), Some("password".into()))),
+        ..unauthenticated(&server)
+    };
+
+    update_user_password(&cfg, UpdateUserPasswordParams::default()).await.unwrap();
+
+    let request = server.last_request();
+    assert_eq!(request.header("authorization"), Some("Basic dXNlcjpwYXNzd29yZA=="));
+    assert_eq!(request.header("fastly-key"), None);
+}
+
+#[tokio::test]
+async fn missing_credentials_fail_before_sending() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let cfg = Configuration {
+        api_key: Some(ApiKey { prefix: None, key: "".into() }),
+        ..unauthenticated(&server)
+    };
+
+    let err = get_service(&cfg, get_service_params()).await.unwrap_err();
+
+    assert!(matches!(err, Error::Auth(_)));
+    assert_eq!(
+        err.to_string(),
+        "error in authentication: no credential for get_service: token_source, api_key, bearer_access_token, oauth_access_token and basic_auth are all unset or empty",
+    );
+    assert!(server.requests().is_empty());
+}
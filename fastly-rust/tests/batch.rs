@@ -0,0 +1,139 @@
+//! Running one endpoint for many params with `batch::Batch`.
+#![cfg(feature = "healthcheck")]
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use common::{configuration, get_service_params, ScriptedResponse, StandIn};
+use fastly_api::apis::batch::Batch;
+use fastly_api::apis::configuration::{Configuration, RetryPolicy};
+use fastly_api::apis::dry_run::Plan;
+use fastly_api::apis::healthcheck_api::{update_healthcheck, UpdateHealthcheckParams};
+use fastly_api::apis::service_api::get_service;
+use fastly_api::apis::Error;
+
+fn params(service_ids: &[&str]) -> Vec<UpdateHealthcheckParams> {
+    service_ids
+        .iter()
+        .map(|service_id| UpdateHealthcheckParams {
+            service_id: (*service_id).to_owned(),
+            version_id: 2,
+            healthcheck_name: "origin".to_owned(),
+            headers: Some(vec!["Host: example.com".to_owned()]),
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn report_lists_every_outcome_in_order() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"name":"origin","service_id":"s1"}"#),
+        ScriptedResponse::json(400, r#"{"msg":"Bad request","detail":"Version 2 is locked"}"#),
+        ScriptedResponse::json(200, r#"{"name":"origin","service_id":"s3"}"#),
+    ])
+    .await;
+    let cfg = Configuration { retry_policy: RetryPolicy::never(), ..configuration(&server) };
+
+    let report = Batch::new(&cfg).concurrency(1).run(params(&["s1", "s2", "s3"]), update_healthcheck).await;
+
+    assert_eq!((report.succeeded(), report.failed()), (2, 1));
+    assert!(!report.is_success());
+    assert_eq!(report.results[2].as_ref().unwrap().service_id.as_deref().map(String::as_str), Some("s3"));
+    let failures: Vec<_> = report.failures().map(|(index, _)| index).collect();
+    assert_eq!(failures, [1]);
+    assert!(matches!(&report.results[1], Err(Error::ResponseError(r)) if r.status == 400));
+    assert_eq!(report.to_string(), "2 of 3 calls succeeded\n  #1: error in response: status code 400 Bad Request: Bad request: Version 2 is locked");
+    let targets: Vec<_> = server.requests().iter().map(|r| r.path().to_owned()).collect();
+    assert_eq!(targets, ["/service/s1/version/2/healthcheck/origin", "/service/s2/version/2/healthcheck/origin", "/service/s3/version/2/healthcheck/origin"]);
+}
+
+#[tokio::test]
+async fn concurrency_bounds_calls_in_flight() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"name":"origin"}"#)]).await;
+    let cfg = Configuration { retry_policy: RetryPolicy::never(), ..configuration(&server) };
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let most = Arc::new(AtomicUsize::new(0));
+
+    let report = Batch::new(&cfg)
+        .concurrency(3)
+        .run(params(&["s1", "s2", "s3", "s4", "s5", "s6", "s7"]), |cfg, params| {
+            let (in_flight, most) = (in_flight.clone(), most.clone());
+            async move {
+                most.fetch_max(in_flight.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                let result = update_healthcheck(cfg, params).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                result
+            }
+        })
+        .await;
+
+    assert!(report.is_success(), "{report}");
+    assert_eq!(report.results.len(), 7);
+    assert_eq!(most.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn transient_failures_are_retried_and_the_budget_is_shared() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(503, "{}"),
+        ScriptedResponse::json(200, r#"{"name":"origin"}"#)
+            .with_header("Fastly-RateLimit-Remaining", "640")
+            .with_header("Fastly-RateLimit-Reset", "1700000000"),
+    ])
+    .await;
+    let cfg = Configuration { retry_policy: RetryPolicy::never(), ..configuration(&server) };
+    let batch = Batch::new(&cfg).retry_policy(RetryPolicy { initial_backoff: Duration::from_millis(1), ..Default::default() });
+
+    let report = batch.run(params(&["s1"]), update_healthcheck).await;
+
+    assert!(report.is_success(), "{report}");
+    assert_eq!(server.requests().len(), 2);
+    // The batch's throttle and the caller's configuration saw the same budget.
+    assert_eq!(batch.configuration().throttle.as_ref().unwrap().available(), 640);
+    assert_eq!(cfg.rate_limit_state().remaining, 640);
+    assert_eq!(cfg.retry_policy, RetryPolicy::never());
+}
+
+#[tokio::test]
+async fn batch_starts_from_the_budget_already_spent() {
+    let reset = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 2;
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"id":"SU1Z0isxPaozGVKXdv0eY"}"#)
+            .with_header("Fastly-RateLimit-Remaining", "1")
+            .with_header("Fastly-RateLimit-Reset", &reset.to_string()),
+        ScriptedResponse::json(200, r#"{"name":"origin"}"#),
+    ])
+    .await;
+    let cfg = Configuration { retry_policy: RetryPolicy::never(), ..configuration(&server) };
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    let batch = Batch::new(&cfg).concurrency(2);
+    assert_eq!(batch.configuration().throttle.as_ref().unwrap().available(), 1);
+    let started = Instant::now();
+    let report = batch.run(params(&["s1", "s2"]), update_healthcheck).await;
+
+    assert!(report.is_success(), "{report}");
+    // One call fit in what was left of the window; the other waited for it to reset.
+    assert!(started.elapsed() > Duration::from_millis(500), "second call was not throttled");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn dry_run_calls_count_as_planned() {
+    let server = StandIn::start(vec![]).await;
+    let plan = Plan::new();
+    let cfg = Configuration { dry_run: Some(plan.clone()), ..configuration(&server) };
+
+    let report = Batch::new(&cfg).run(params(&["s1", "s2", "s3"]), update_healthcheck).await;
+
+    assert_eq!((report.succeeded(), report.planned(), report.failed()), (0, 3, 0));
+    assert!(report.is_success(), "{report}");
+    assert_eq!(report.failures().count(), 0);
+    assert_eq!(report.to_string(), "0 of 3 calls succeeded, 3 planned");
+    assert_eq!(plan.len(), 3);
+    assert!(server.requests().is_empty());
+}
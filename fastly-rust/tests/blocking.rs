@@ -0,0 +1,79 @@
+//! The synchronous API behind the `blocking` feature.
+#![cfg(all(feature = "blocking", feature = "healthcheck"))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::{Configuration, RetryPolicy};
+use fastly_api::apis::Error;
+use fastly_api::blocking::healthcheck_api::{create_healthcheck, CreateHealthcheckParams};
+use fastly_api::blocking::pagination::paginate;
+use fastly_api::blocking::service_api::ListServicesParams;
+use fastly_api::blocking::version_api::{get_service_version_with_info, GetServiceVersionParams};
+use tokio::runtime::Runtime;
+
+/// Serves the scripted responses from a runtime of its own; the calls under
+/// test are made from the plain test thread.
+fn start(responses: Vec<ScriptedResponse>) -> (Runtime, StandIn) {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(StandIn::start(responses));
+    (runtime, server)
+}
+
+#[test]
+fn calls_return_the_same_entities_and_headers() {
+    let (_runtime, server) = start(vec![ScriptedResponse::json(200, r#"{"number":3,"locked":true}"#)
+        .with_header("Fastly-Request-ID", "r1")
+        .with_header("Fastly-RateLimit-Remaining", "950")]);
+    let cfg = Configuration { retry_policy: RetryPolicy { initial_backoff: Duration::from_millis(1), ..Default::default() }, ..configuration(&server) };
+
+    let params = GetServiceVersionParams { service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(), version_id: 3 };
+    let response = get_service_version_with_info(&cfg, params).unwrap();
+
+    assert_eq!(response.entity.number, Some(3));
+    assert_eq!(response.request_id(), Some("r1"));
+    assert_eq!(cfg.rate_limit_state().remaining, 950);
+    let request = server.last_request();
+    assert_eq!(request.target, "/service/SU1Z0isxPaozGVKXdv0eY/version/3");
+    assert_eq!(request.header("fastly-key"), Some("test-token"));
+}
+
+#[test]
+fn errors_and_retries_match_the_async_functions() {
+    let (_runtime, server) = start(vec![
+        ScriptedResponse::json(503, "{}"),
+        ScriptedResponse::json(400, r#"{"msg":"Bad request","detail":"Name is required"}"#),
+    ]);
+    let cfg = Configuration { retry_policy: RetryPolicy { initial_backoff: Duration::from_millis(1), ..Default::default() }, ..configuration(&server) };
+
+    // POST is not retried, so only the 503 is seen.
+    let params = CreateHealthcheckParams { service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(), version_id: 1, ..Default::default() };
+    let err = create_healthcheck(&cfg, params).unwrap_err();
+    assert!(matches!(&err, Error::ResponseError(r) if r.status == 503), "{err}");
+    assert_eq!(server.requests().len(), 1);
+
+    let params = GetServiceVersionParams { service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(), version_id: 1 };
+    let err = get_service_version_with_info(&cfg, params).unwrap_err();
+    let Error::ResponseError(content) = err else { panic!("expected a response error, got {err:?}") };
+    assert_eq!(content.status, 400);
+    assert_eq!(content.api_error.detail.as_deref(), Some("Name is required"));
+}
+
+#[test]
+fn paginate_is_an_iterator() {
+    let (_runtime, server) = start(vec![
+        ScriptedResponse::json(200, r#"[{"name":"a"},{"name":"b"}]"#),
+        ScriptedResponse::json(200, r#"[{"name":"c"}]"#),
+    ]);
+    let cfg = Configuration { retry_policy: RetryPolicy { initial_backoff: Duration::from_millis(1), ..Default::default() }, ..configuration(&server) };
+
+    let names: Vec<_> = paginate(&cfg, ListServicesParams::default())
+        .page_size(2)
+        .map(|service| service.unwrap().name.unwrap())
+        .collect();
+
+    assert_eq!(names, ["a", "b", "c"]);
+    assert_eq!(server.requests().len(), 2);
+}
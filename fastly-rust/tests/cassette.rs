@@ -0,0 +1,132 @@
+//! Recording exchanges to a cassette file and replaying them offline.
+#![cfg(feature = "healthcheck")]
+
+mod common;
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use common::{configuration_at, ScriptedResponse, StandIn};
+use fastly_api::apis::cassette::{Cassette, Mode};
+use fastly_api::apis::configuration::{ApiKey, Configuration, RetryPolicy};
+use fastly_api::apis::healthcheck_api::{create_healthcheck, CreateHealthcheckParams};
+use fastly_api::apis::Error;
+
+const HEALTHCHECK: &str = r#"{"check_interval":5000,"comment":"","expected_response":200,"headers":["Ricky: Test","Another-Header: Test"],"host":"","http_version":"1.1","initial":1,"method":"HEAD","name":"hc1","path":"/","threshold":1,"timeout":5000,"window":2,"service_id":"SU1Z0isxPaozGVKXdv0eY","version":1,"created_at":"2024-05-02T09:14:03Z","deleted_at":null,"updated_at":"2024-05-02T09:14:03Z"}"#;
+
+fn cassette_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("fastly-api-{}-{}.json", name, std::process::id()))
+}
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/cassettes").join(name)
+}
+
+fn configuration(base_path: &str, token: &str, cassette: Cassette) -> Configuration {
+    Configuration {
+        api_key: Some(ApiKey { prefix: None, key: token.into() }),
+        retry_policy: RetryPolicy::never(),
+        cassette: Some(Arc::new(cassette)),
+        ..configuration_at(base_path)
+    }
+}
+
+/// The service the fixture cassettes were recorded against: the first one
+/// a fresh [`FakeFastly`](fastly_api::testing::FakeFastly) creates.
+const FIXTURE_SERVICE_ID: &str = "fakesvc000000000000001";
+
+fn healthcheck_params() -> CreateHealthcheckParams {
+    CreateHealthcheckParams {
+        service_id: FIXTURE_SERVICE_ID.to_owned(),
+        version_id: 1,
+        headers: Some(vec!["Ricky: Test".to_owned(), "Another-Header: Test".to_owned()]),
+        name: Some("hc1".to_owned()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn recorded_exchange_replays_without_network() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, HEALTHCHECK).with_header("Fastly-Request-ID", "r1")]).await;
+    let path = cassette_path("record-replay");
+
+    let recording = configuration(&server.base_path, "real-token", Cassette::record(&path));
+    let live = create_healthcheck(&recording, healthcheck_params()).await.unwrap();
+    assert_eq!(server.requests().len(), 1);
+
+    // Nothing listens here; a request that reached the network would fail.
+    let replaying = configuration("http://127.0.0.1:9", "other-token", Cassette::replay(&path).unwrap());
+    let replayed = create_healthcheck(&replaying, healthcheck_params()).await.unwrap();
+    assert_eq!(format!("{replayed:?}"), format!("{live:?}"));
+
+    // Each interaction is served once.
+    let err = create_healthcheck(&replaying, healthcheck_params()).await.unwrap_err();
+    assert!(matches!(&err, Error::Io(e) if e.kind() == std::io::ErrorKind::NotFound), "{err}");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn credentials_are_scrubbed_from_the_file() {
+    let token_response = r#"{"id":"t1","access_token":"minted-secret","name":"ci"}"#;
+    let server = StandIn::start(vec![ScriptedResponse::json(200, token_response)]).await;
+    let path = cassette_path("scrubbed");
+
+    let cfg = configuration(&server.base_path, "real-token", Cassette::record(&path));
+    let _ = create_healthcheck(&cfg, healthcheck_params()).await;
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(!written.contains("real-token"), "{written}");
+    assert!(!written.contains("minted-secret"), "{written}");
+    let interaction = &cfg.cassette.as_ref().unwrap().interactions()[0];
+    assert!(interaction.request.headers.contains(&("fastly-key".to_owned(), "***".to_owned())));
+    assert!(interaction.response.body.contains(r#""access_token":"***""#));
+    // Fields that are not secret are kept, so the body still matches on replay.
+    assert!(interaction.request.body.as_deref().unwrap().contains("name=hc1"));
+}
+
+#[tokio::test]
+async fn replay_matches_on_method_path_query_and_body() {
+    let cassette = Cassette::replay(fixture("create_healthcheck_with_headers.json")).unwrap();
+    assert_eq!(cassette.mode(), Mode::Replay);
+    let cfg = configuration("http://127.0.0.1:9", "any-token", cassette);
+
+    let other_name = CreateHealthcheckParams { name: Some("hc2".to_owned()), ..healthcheck_params() };
+    let err = create_healthcheck(&cfg, other_name).await.unwrap_err();
+    assert!(matches!(&err, Error::Io(e) if e.kind() == std::io::ErrorKind::NotFound), "{err}");
+
+    let other_version = CreateHealthcheckParams { version_id: 2, ..healthcheck_params() };
+    assert!(create_healthcheck(&cfg, other_version).await.is_err());
+}
+
+/// Rewrite `create_healthcheck_with_headers.json`. Run with
+/// `cargo test --features testing --test cassette -- --ignored`.
+#[cfg(feature = "testing")]
+#[tokio::test]
+#[ignore = "rewrites a fixture"]
+async fn record_healthcheck_headers_cassette() {
+    let fake = fastly_api::testing::FakeFastly::start().await.unwrap();
+    assert_eq!(fake.create_service("example"), FIXTURE_SERVICE_ID);
+    let cassette = Cassette::record(fixture("create_healthcheck_with_headers.json"));
+    let cfg = Configuration { cassette: Some(Arc::new(cassette)), ..fake.configuration() };
+
+    create_healthcheck(&cfg, healthcheck_params()).await.unwrap();
+}
+
+#[tokio::test]
+async fn healthcheck_headers_cassette() {
+    // The request of the `src/main.rs` reproduction. The cassette was
+    // recorded against `FakeFastly`, not the live API, so this guards the
+    // `headers[]` form encoding, not the exact response Fastly sends.
+    let cassette = Cassette::replay(fixture("create_healthcheck_with_headers.json")).unwrap();
+    let cfg = configuration("http://127.0.0.1:9", "any-token", cassette);
+
+    let created = create_healthcheck(&cfg, healthcheck_params()).await.unwrap();
+
+    assert_eq!(created.headers, Some(vec!["Ricky: Test".to_owned(), "Another-Header: Test".to_owned()]));
+    assert_eq!(created.name.as_deref(), Some("hc1"));
+    let interactions = cfg.cassette.as_ref().unwrap().interactions();
+    assert_eq!(interactions[0].request.body.as_deref(), Some("headers%5B%5D=Ricky%3A+Test&headers%5B%5D=Another-Header%3A+Test&name=hc1"));
+}
+
@@ -0,0 +1,59 @@
+//! The `FastlyClient` facade fills in service and version IDs.
+#![cfg(all(feature = "dictionary", feature = "healthcheck"))]
+
+mod common;
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::dictionary_item_api::GetDictionaryItemParams;
+use fastly_api::apis::healthcheck_api::CreateHealthcheckParams;
+use fastly_api::client::FastlyClient;
+
+fn client(server: &StandIn) -> FastlyClient {
+    FastlyClient::new(configuration(server))
+}
+
+#[tokio::test]
+async fn version_scoped_calls_carry_service_and_version() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"name":"hc"}"#), ScriptedResponse::json(200, "[]")]).await;
+    let client = client(&server);
+    let version = client.service("SU1Z0isxPaozGVKXdv0eY").version(3);
+
+    let params = CreateHealthcheckParams { name: Some("hc".to_owned()), ..Default::default() };
+    let created = version.healthchecks().create(params).await.unwrap();
+    let listed = version.healthchecks().list().await.unwrap();
+
+    assert_eq!(created.name.as_deref(), Some("hc"));
+    assert!(listed.is_empty());
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path(), "/service/SU1Z0isxPaozGVKXdv0eY/version/3/healthcheck");
+    assert_eq!(requests[0].body, "name=hc");
+    assert_eq!(requests[1].method, "GET");
+    assert_eq!(requests[1].path(), "/service/SU1Z0isxPaozGVKXdv0eY/version/3/healthcheck");
+}
+
+#[tokio::test]
+async fn service_scoped_calls_carry_the_service() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"item_key":"k","item_value":"v"}"#),
+        ScriptedResponse::json(200, r#"{"number":7,"active":true}"#),
+    ])
+    .await;
+    let client = client(&server);
+    let service = client.service("SU1Z0isxPaozGVKXdv0eY");
+
+    let params = GetDictionaryItemParams {
+        dictionary_id: "dict".to_owned(),
+        dictionary_item_key: "k".to_owned(),
+        ..Default::default()
+    };
+    let item = service.dictionary_items().get(params).await.unwrap();
+    let activated = service.version(7).activate().await.unwrap();
+
+    assert_eq!(item.item_value.as_deref(), Some("v"));
+    assert_eq!(activated.active, Some(true));
+    let requests = server.requests();
+    assert_eq!(requests[0].path(), "/service/SU1Z0isxPaozGVKXdv0eY/dictionary/dict/item/k");
+    assert_eq!(requests[1].method, "PUT");
+    assert_eq!(requests[1].path(), "/service/SU1Z0isxPaozGVKXdv0eY/version/7/activate");
+}
@@ -0,0 +1,235 @@
+//! A minimal local HTTP stand-in for the Fastly API.
+//!
+//! The server answers each connection with the next scripted response and
+//! records what it received, so tests can assert on the exact wire format.
+//! It can also serve HTTPS with the certificates in `tests/fixtures/tls`.
+
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use fastly_api::apis::configuration::{ApiKey, Configuration};
+#[cfg(feature = "healthcheck")]
+use fastly_api::apis::healthcheck_api::CreateHealthcheckParams;
+use fastly_api::apis::service_api::GetServiceParams;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// Path including the query string, e.g. `/service/abc?page=2`.
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn path(&self) -> &str {
+        self.target.split('?').next().unwrap_or_default()
+    }
+
+    pub fn query(&self) -> &str {
+        self.target.split_once('?').map(|(_, q)| q).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ScriptedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl ScriptedResponse {
+    pub fn json(status: u16, body: &str) -> ScriptedResponse {
+        ScriptedResponse {
+            status,
+            headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+            body: body.to_owned(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> ScriptedResponse {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+}
+
+pub struct StandIn {
+    pub base_path: String,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl StandIn {
+    /// Start a server that answers with `responses` in order. Once the script
+    /// runs out, the last response is repeated.
+    pub async fn start(responses: Vec<ScriptedResponse>) -> StandIn {
+        StandIn::serve(responses, None).await
+    }
+
+    /// Like [`StandIn::start`], but over TLS; `base_path` is `https://localhost:<port>`.
+    pub async fn start_tls(responses: Vec<ScriptedResponse>, acceptor: TlsAcceptor) -> StandIn {
+        StandIn::serve(responses, Some(acceptor)).await
+    }
+
+    async fn serve(responses: Vec<ScriptedResponse>, acceptor: Option<TlsAcceptor>) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let base_path = match acceptor {
+            Some(_) => format!("https://localhost:{}", port),
+            None => format!("http://127.0.0.1:{}", port),
+        };
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let mut script: VecDeque<ScriptedResponse> = responses.into();
+
+        tokio::spawn(async move {
+            let mut last = None;
+            loop {
+                let (stream, _) = match listener.accept().await {
+                    Ok(conn) => conn,
+                    Err(_) => return,
+                };
+                let mut stream: Box<dyn Stream> = match acceptor {
+                    Some(ref acceptor) => match acceptor.accept(stream).await {
+                        Ok(stream) => Box::new(stream),
+                        Err(_) => continue,
+                    },
+                    None => Box::new(stream),
+                };
+                let request = match read_request(&mut stream).await {
+                    Some(request) => request,
+                    None => continue,
+                };
+                recorded.lock().unwrap().push(request);
+
+                let response = script.pop_front().or_else(|| last.clone()).unwrap_or_else(|| ScriptedResponse::json(200, "{}"));
+                last = Some(response.clone());
+                let _ = stream.write_all(render(&response).as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+
+        StandIn { base_path, requests }
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn last_request(&self) -> RecordedRequest {
+        self.requests().pop().expect("no request was received")
+    }
+}
+
+/// A configuration that sends requests to `server` with a static API token.
+pub fn configuration(server: &StandIn) -> Configuration {
+    configuration_at(&server.base_path)
+}
+
+/// Like [`configuration`], for a server other than a [`StandIn`].
+pub fn configuration_at(base_path: &str) -> Configuration {
+    Configuration {
+        base_path: base_path.to_owned(),
+        api_key: Some(ApiKey { prefix: None, key: "test-token".into() }),
+        ..Default::default()
+    }
+}
+
+pub fn get_service_params() -> GetServiceParams {
+    GetServiceParams { service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned() }
+}
+
+#[cfg(feature = "healthcheck")]
+pub fn create_healthcheck_params() -> CreateHealthcheckParams {
+    CreateHealthcheckParams {
+        service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(),
+        version_id: 1,
+        name: Some("hc".to_owned()),
+        ..Default::default()
+    }
+}
+
+trait Stream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> Stream for S {}
+
+async fn read_request(stream: &mut Box<dyn Stream>) -> Option<RecordedRequest> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let head_end = loop {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_owned();
+    let target = request_line.next()?.to_owned();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_owned(), v.trim().to_owned()))
+        .collect();
+
+    let content_length = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buf[head_end + 4..].to_vec();
+    while body.len() < content_length {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+
+    Some(RecordedRequest {
+        method,
+        target,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn render(response: &ScriptedResponse) -> String {
+    let mut out = format!("HTTP/1.1 {} Scripted\r\n", response.status);
+    for (name, value) in &response.headers {
+        out.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    out.push_str(&format!("Content-Length: {}\r\nConnection: close\r\n\r\n", response.body.len()));
+    out.push_str(&response.body);
+    out
+}
+
+/// A TLS acceptor for `localhost`, signed by the test CA in `tests/fixtures/tls/ca.pem`.
+pub fn localhost_tls_acceptor() -> TlsAcceptor {
+    use tokio_rustls::rustls;
+
+    let certs = rustls_pemfile::certs(&mut &include_bytes!("../fixtures/tls/server.pem")[..]).unwrap();
+    let mut keys = rustls_pemfile::pkcs8_private_keys(&mut &include_bytes!("../fixtures/tls/server.key")[..]).unwrap();
+    let config = rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(certs.into_iter().map(rustls::Certificate).collect(), rustls::PrivateKey(keys.remove(0)))
+        .unwrap();
+    TlsAcceptor::from(Arc::new(config))
+}
@@ -0,0 +1,47 @@
+//! Sharing one `Configuration` between concurrent calls.
+#![cfg(feature = "healthcheck")]
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::Configuration;
+use fastly_api::apis::healthcheck_api::{get_healthcheck, GetHealthcheckParams};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn configuration_is_send_and_sync() {
+    assert_send_sync::<Configuration>();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn one_configuration_serves_many_tasks() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"name":"hc"}"#)
+        .with_header("Fastly-RateLimit-Remaining", "900")
+        .with_header("Fastly-RateLimit-Reset", "1700000000")])
+    .await;
+    let cfg = Arc::new(configuration(&server));
+    let observer = (*cfg).clone();
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for i in 0..20 {
+        let cfg = cfg.clone();
+        tasks.spawn(async move {
+            let params = GetHealthcheckParams {
+                service_id: format!("service-{i}"),
+                version_id: 1,
+                healthcheck_name: "hc".to_owned(),
+            };
+            get_healthcheck(&cfg, params).await
+        });
+    }
+    while let Some(result) = tasks.join_next().await {
+        assert_eq!(result.unwrap().unwrap().name.as_deref(), Some("hc"));
+    }
+
+    assert_eq!(server.requests().len(), 20);
+    // Clones share the rate-limit state with the original.
+    assert_eq!(observer.rate_limit_state().remaining, 900);
+}
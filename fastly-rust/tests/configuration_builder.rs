@@ -0,0 +1,113 @@
+//! HTTP client settings applied by `ConfigurationBuilder`.
+//!
+//! The TLS fixtures were generated with `openssl`: a self-signed CA and a
+//! `localhost`/`127.0.0.1` server certificate it signed, both valid for 100 years.
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{get_service_params, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::{Configuration, RetryPolicy};
+use fastly_api::apis::service_api::get_service;
+use fastly_api::apis::Error;
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn test_ca() -> reqwest::Certificate {
+    reqwest::Certificate::from_pem(include_bytes!("fixtures/tls/ca.pem")).unwrap()
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+#[tokio::test]
+async fn custom_root_ca_is_trusted() {
+    let server = StandIn::start_tls(vec![ScriptedResponse::json(200, "{}")], common::localhost_tls_acceptor()).await;
+    let cfg = Configuration::builder()
+        .base_path(&server.base_path)
+        .api_key("test-token")
+        .add_root_certificate(test_ca())
+        .build()
+        .unwrap();
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    assert_eq!(server.last_request().path(), "/service/SU1Z0isxPaozGVKXdv0eY");
+}
+
+#[cfg(feature = "rustls")]
+#[tokio::test]
+async fn rustls_backend_trusts_only_the_custom_ca() {
+    let server = StandIn::start_tls(vec![ScriptedResponse::json(200, "{}")], common::localhost_tls_acceptor()).await;
+    let cfg = Configuration::builder()
+        .base_path(&server.base_path)
+        .api_key("test-token")
+        .use_rustls_tls()
+        .tls_built_in_root_certs(false)
+        .add_root_certificate(test_ca())
+        .build()
+        .unwrap();
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+#[tokio::test]
+async fn unknown_ca_is_rejected() {
+    let server = StandIn::start_tls(vec![ScriptedResponse::json(200, "{}")], common::localhost_tls_acceptor()).await;
+    let cfg = Configuration::builder()
+        .base_path(&server.base_path)
+        .api_key("test-token")
+        .retry_policy(RetryPolicy::never())
+        .build()
+        .unwrap();
+
+    let err = get_service(&cfg, get_service_params()).await.unwrap_err();
+
+    assert!(matches!(err, Error::Reqwest(ref e) if e.is_connect()));
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn timeout_bounds_each_attempt() {
+    // Accept connections but never answer.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((stream, _)) = listener.accept().await {
+            held.push(stream);
+        }
+    });
+    let cfg = Configuration::builder()
+        .base_path(format!("http://{addr}"))
+        .api_key("test-token")
+        .timeout(Duration::from_millis(200))
+        .retry_policy(RetryPolicy::never())
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    let err = get_service(&cfg, get_service_params()).await.unwrap_err();
+
+    assert!(matches!(err, Error::Reqwest(ref e) if e.is_timeout()));
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[tokio::test]
+async fn requests_go_through_the_proxy_with_the_user_agent() {
+    let proxy = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let cfg = Configuration::builder()
+        .base_path("http://api.fastly.test")
+        .api_key("test-token")
+        .user_agent("deploy-bot/1.0")
+        .proxy(reqwest::Proxy::http(&proxy.base_path).unwrap())
+        .build()
+        .unwrap();
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    let request = proxy.last_request();
+    assert_eq!(request.target, "http://api.fastly.test/service/SU1Z0isxPaozGVKXdv0eY");
+    assert_eq!(request.header("user-agent"), Some("deploy-bot/1.0"));
+}
@@ -0,0 +1,126 @@
+//! Resolving the API token from explicit values, env vars and CLI profiles.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use fastly_api::apis::credential_chain::{CredentialChain, PROFILE_ENV, TOKEN_ENV};
+
+const CLI_CONFIG: &str = r#"
+[fastly]
+api_endpoint = "https://api.fastly.com"
+
+[profile.staging]
+default = true
+email = "dev@example.com"
+token = "staging-token"
+
+[profile.prod]
+default = false
+email = "dev@example.com"
+token = "prod-token"
+
+[profile.empty]
+email = "dev@example.com"
+"#;
+
+// The chain reads process-wide environment variables, so tests take turns.
+static ENV: Mutex<()> = Mutex::new(());
+
+fn clean_env() -> MutexGuard<'static, ()> {
+    let guard = ENV.lock().unwrap_or_else(|e| e.into_inner());
+    env::remove_var(TOKEN_ENV);
+    env::remove_var(PROFILE_ENV);
+    guard
+}
+
+fn cli_config(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("fastly-api-{}-{}.toml", name, std::process::id()));
+    fs::write(&path, CLI_CONFIG).unwrap();
+    path
+}
+
+#[test]
+fn sources_are_checked_in_order() {
+    let _env = clean_env();
+    let path = cli_config("order");
+    let chain = CredentialChain::new().config_path(&path);
+
+    assert_eq!(chain.resolve().unwrap().expose(), "staging-token");
+
+    env::set_var(TOKEN_ENV, "env-token");
+    assert_eq!(chain.resolve().unwrap().expose(), "env-token");
+
+    let explicit = chain.clone().token("explicit-token");
+    assert_eq!(explicit.resolve().unwrap().expose(), "explicit-token");
+
+    env::remove_var(TOKEN_ENV);
+}
+
+#[test]
+fn named_profile_is_selected() {
+    let _env = clean_env();
+    let path = cli_config("named");
+
+    let chain = CredentialChain::new().config_path(&path).profile("prod");
+    assert_eq!(chain.resolve().unwrap().expose(), "prod-token");
+
+    env::set_var(PROFILE_ENV, "prod");
+    let cfg = CredentialChain::new().config_path(&path).configuration().unwrap();
+    assert_eq!(cfg.api_key.unwrap().key.expose(), "prod-token");
+
+    env::remove_var(PROFILE_ENV);
+}
+
+#[test]
+fn error_lists_every_source_checked() {
+    let _env = clean_env();
+    let path = cli_config("missing");
+
+    let missing = env::temp_dir().join("fastly-api-does-not-exist.toml");
+    let err = CredentialChain::new().config_path(&missing).resolve().unwrap_err();
+    assert_eq!(err.checked.len(), 3);
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "no Fastly API token found; checked explicit token (not set), FASTLY_API_TOKEN (not set), default Fastly CLI profile in {} (file not found)",
+            missing.display(),
+        ),
+    );
+
+    env::set_var(PROFILE_ENV, "qa");
+    let err = CredentialChain::new().config_path(&path).resolve().unwrap_err();
+    assert_eq!(err.checked[2].reason, "no such profile");
+    env::remove_var(PROFILE_ENV);
+
+    let err = CredentialChain::new().config_path(&path).profile("empty").resolve().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "no Fastly API token found; checked explicit token (not set), Fastly CLI profile \"empty\" in {} (profile has no token)",
+            path.display(),
+        ),
+    );
+}
+
+#[test]
+fn explicit_profile_wins_over_the_env_token() {
+    let _env = clean_env();
+    let path = cli_config("precedence");
+    env::set_var(TOKEN_ENV, "env-token");
+
+    let chain = CredentialChain::new().config_path(&path).profile("prod");
+    assert_eq!(chain.resolve().unwrap().expose(), "prod-token");
+
+    // An explicit profile that cannot be used does not fall back to the env token.
+    let err = CredentialChain::new().config_path(&path).profile("qa").resolve().unwrap_err();
+    assert_eq!(err.checked[1].reason, "no such profile");
+
+    // `FASTLY_PROFILE` is part of the environment, and comes after its token.
+    env::set_var(PROFILE_ENV, "prod");
+    assert_eq!(CredentialChain::new().config_path(&path).resolve().unwrap().expose(), "env-token");
+
+    env::remove_var(TOKEN_ENV);
+    env::remove_var(PROFILE_ENV);
+}
@@ -0,0 +1,145 @@
+//! Planning mutating requests with `Configuration::dry_run`.
+#![cfg(feature = "healthcheck")]
+
+mod common;
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::Configuration;
+use fastly_api::apis::dry_run::{Plan, PLANNED};
+use fastly_api::apis::healthcheck_api::{create_healthcheck, update_healthcheck_with_info, CreateHealthcheckParams, UpdateHealthcheckParams};
+use fastly_api::apis::raw::{self, Body};
+use fastly_api::apis::service_api::{get_service, GetServiceError, GetServiceParams};
+use fastly_api::apis::version_api::{activate_service_version, clone_service_version, ActivateServiceVersionParams, CloneServiceVersionParams};
+use fastly_api::apis::{Error, FormParams};
+use serde_json::json;
+
+#[tokio::test]
+async fn mutating_calls_are_planned_and_reads_still_run() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"id":"SU1Z0isxPaozGVKXdv0eY","name":"www"}"#)]).await;
+    let plan = Plan::new();
+    let cfg = Configuration { dry_run: Some(plan.clone()), ..configuration(&server) };
+
+    let service = get_service(&cfg, GetServiceParams { service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned() }).await.unwrap();
+    assert_eq!(service.name.as_deref(), Some("www"));
+
+    let params = UpdateHealthcheckParams {
+        service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(),
+        version_id: 2,
+        healthcheck_name: "origin".to_owned(),
+        headers: Some(vec!["Host: example.com".to_owned()]),
+        ..Default::default()
+    };
+    let response = update_healthcheck_with_info(&cfg, params).await.unwrap();
+    assert_eq!(response.status, PLANNED);
+    assert!(response.headers.is_empty());
+    assert_eq!(response.entity, Default::default());
+
+    let planned = &plan.requests()[0];
+    assert_eq!(planned.operation, "update_healthcheck");
+    assert_eq!(planned.method, reqwest::Method::PUT);
+    assert_eq!(planned.url, format!("{}/service/SU1Z0isxPaozGVKXdv0eY/version/2/healthcheck/origin", server.base_path));
+    assert_eq!((planned.service_id.as_deref(), planned.version), (Some("SU1Z0isxPaozGVKXdv0eY"), Some(2)));
+    let Body::Form(form) = &planned.body else { panic!("expected a form body, got {:?}", planned.body) };
+    assert_eq!(form.iter().collect::<Vec<_>>(), [("headers[]", "Host: example.com")]);
+
+    // Only the read reached the server.
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(plan.len(), 1);
+    assert_eq!(
+        plan.to_string(),
+        format!(
+            "1. PUT {}/service/SU1Z0isxPaozGVKXdv0eY/version/2/healthcheck/origin\n    service SU1Z0isxPaozGVKXdv0eY version 2\n    headers%5B%5D=Host%3A+example.com\n",
+            server.base_path
+        )
+    );
+}
+
+#[tokio::test]
+async fn planned_requests_are_redacted_and_can_be_taken() {
+    let server = StandIn::start(vec![]).await;
+    let plan = Plan::new();
+    let cfg = Configuration { dry_run: Some(plan.clone()), ..configuration(&server) };
+
+    let body = Body::Json(json!({"name": "ci", "access_token": "minted-secret", "scope": {"client_secret": "also-secret"}}));
+    let response = raw::request(&cfg, reqwest::Method::POST, "/tokens", &[("api_key", "query-secret"), ("page", "2")], body).await.unwrap();
+    assert_eq!((response.status, response.entity), (PLANNED, serde_json::Value::Null));
+
+    let planned = &plan.requests()[0];
+    assert_eq!(planned.url, format!("{}/tokens?api_key=***&page=2", server.base_path));
+    let Body::Json(json) = &planned.body else { panic!("expected a JSON body, got {:?}", planned.body) };
+    assert_eq!(json, &json!({"name": "ci", "access_token": "***", "scope": {"client_secret": "***"}}));
+    assert_eq!(planned.service_id, None);
+
+    raw::request(&cfg, reqwest::Method::DELETE, "/service/SU1Z0isxPaozGVKXdv0eY", &[], Body::Empty).await.unwrap();
+    let taken = plan.take();
+    assert_eq!(taken.iter().map(|r| r.method.as_str()).collect::<Vec<_>>(), ["POST", "DELETE"]);
+    assert!(plan.is_empty());
+    assert!(server.requests().is_empty());
+}
+
+/// The body of the request that `raw::request` added to `plan`.
+async fn planned_body(cfg: &Configuration, plan: &Plan, method: reqwest::Method, path: &str, body: Body) -> Body {
+    raw::request(cfg, method, path, &[], body).await.unwrap();
+    plan.take().remove(0).body
+}
+
+#[tokio::test]
+async fn generic_field_names_are_only_redacted_where_they_hold_credentials() {
+    let server = StandIn::start(vec![]).await;
+    let plan = Plan::new();
+    let cfg = Configuration { dry_run: Some(plan.clone()), ..configuration(&server) };
+
+    let item = Body::Json(json!({"key": "feature-flag", "item_value": "on"}));
+    let path = "/service/SU1Z0isxPaozGVKXdv0eY/dictionary/dict/item/feature-flag";
+    let Body::Json(json) = planned_body(&cfg, &plan, reqwest::Method::PUT, path, item).await else { panic!("expected a JSON body") };
+    assert_eq!(json, json!({"key": "feature-flag", "item_value": "on"}));
+
+    let logging = |token: &str| {
+        let mut form = FormParams::new();
+        form.insert("name", "logs".to_owned());
+        form.insert("token", token.to_owned());
+        Body::Form(form)
+    };
+
+    let path = "/service/SU1Z0isxPaozGVKXdv0eY/version/2/logging/datadog";
+    let Body::Form(form) = planned_body(&cfg, &plan, reqwest::Method::POST, path, logging("dd-api-key")).await else { panic!("expected a form body") };
+    assert_eq!(form.encode(), "name=logs&token=***");
+
+    let path = "/service/SU1Z0isxPaozGVKXdv0eY/version/2/logging/syslog";
+    let Body::Form(form) = planned_body(&cfg, &plan, reqwest::Method::POST, path, logging("app-prefix")).await else { panic!("expected a form body") };
+    assert_eq!(form.encode(), "name=logs&token=app-prefix");
+}
+
+#[tokio::test]
+async fn a_sequence_of_changes_is_planned_in_order() {
+    let server = StandIn::start(vec![ScriptedResponse::json(404, r#"{"msg":"Record not found"}"#)]).await;
+    let plan = Plan::new();
+    let cfg = Configuration { dry_run: Some(plan.clone()), ..configuration(&server) };
+    let service_id = "SU1Z0isxPaozGVKXdv0eY".to_owned();
+
+    let script = async {
+        let cloned = clone_service_version(&cfg, CloneServiceVersionParams { service_id: service_id.clone(), version_id: 2 }).await?;
+        assert_eq!(cloned.number, None);
+        let version_id = cloned.number.unwrap_or(3);
+        let params = CreateHealthcheckParams { service_id: service_id.clone(), version_id, name: Some("origin".to_owned()), ..Default::default() };
+        create_healthcheck(&cfg, params).await?;
+        activate_service_version(&cfg, ActivateServiceVersionParams { service_id: service_id.clone(), version_id }).await?;
+        // Reads still reach the server, and their errors still stop the script.
+        get_service(&cfg, GetServiceParams { service_id: service_id.clone() }).await?;
+        Ok::<_, Box<dyn std::error::Error>>(())
+    };
+    let err = script.await.unwrap_err();
+
+    let err = err.downcast_ref::<Error<GetServiceError>>().unwrap();
+    assert!(matches!(err, Error::ResponseError(r) if r.status == 404), "{err}");
+    let planned: Vec<_> = plan.requests().iter().map(|r| (r.operation, r.method.to_string(), r.version)).collect();
+    assert_eq!(
+        planned,
+        [
+            ("clone_service_version", "PUT".to_owned(), Some(2)),
+            ("create_healthcheck", "POST".to_owned(), Some(3)),
+            ("activate_service_version", "PUT".to_owned(), Some(3)),
+        ]
+    );
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,43 @@
+//! Endpoint groups served from hosts other than `base_path`.
+#![cfg(feature = "stats")]
+
+mod common;
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::{Configuration, EndpointGroup};
+use fastly_api::apis::origin_inspector_realtime_api::{get_origin_inspector_last120_seconds, GetOriginInspectorLast120SecondsParams};
+use fastly_api::apis::realtime_api::{get_stats_last120_seconds, GetStatsLast120SecondsParams};
+use fastly_api::apis::service_api::{get_service, GetServiceParams};
+
+const SERVICE_ID: &str = "SU1Z0isxPaozGVKXdv0eY";
+
+#[test]
+fn realtime_groups_default_to_the_realtime_host() {
+    let cfg = Configuration::default();
+
+    assert_eq!(cfg.base_path_for(None), "https://api.fastly.com");
+    for group in [EndpointGroup::Realtime, EndpointGroup::OriginInspectorRealtime, EndpointGroup::DomainInspectorRealtime] {
+        assert_eq!(cfg.base_path_for(Some(group)), "https://rt.fastly.com");
+    }
+}
+
+#[tokio::test]
+async fn each_group_can_be_pointed_at_its_own_server() {
+    let api = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let realtime = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let origins = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let mut cfg = configuration(&api);
+    cfg.endpoint_base_paths.insert(EndpointGroup::Realtime, realtime.base_path.clone());
+    cfg.endpoint_base_paths.insert(EndpointGroup::OriginInspectorRealtime, origins.base_path.clone());
+
+    get_service(&cfg, GetServiceParams { service_id: SERVICE_ID.to_owned() }).await.unwrap();
+    get_stats_last120_seconds(&cfg, GetStatsLast120SecondsParams { service_id: SERVICE_ID.to_owned() }).await.unwrap();
+    get_origin_inspector_last120_seconds(&cfg, GetOriginInspectorLast120SecondsParams { service_id: SERVICE_ID.to_owned() })
+        .await
+        .unwrap();
+
+    assert_eq!(api.last_request().path(), "/service/SU1Z0isxPaozGVKXdv0eY");
+    assert_eq!(realtime.last_request().path(), "/v1/channel/SU1Z0isxPaozGVKXdv0eY/ts/h");
+    assert_eq!(origins.last_request().path(), "/v1/origins/SU1Z0isxPaozGVKXdv0eY/ts/h");
+    assert_eq!(api.requests().len(), 1);
+}
@@ -0,0 +1,60 @@
+//! Parsing of Fastly API error bodies.
+#![cfg(all(feature = "healthcheck", feature = "tls"))]
+
+mod common;
+
+use common::{configuration, create_healthcheck_params, ScriptedResponse, StandIn};
+use fastly_api::apis::healthcheck_api::create_healthcheck;
+use fastly_api::apis::tls_subscriptions_api::{get_tls_sub, GetTlsSubParams};
+
+#[tokio::test]
+async fn classic_error_body_is_parsed() {
+    let server = StandIn::start(vec![ScriptedResponse::json(400, r#"{"msg":"Bad request","detail":"Name can't be blank"}"#)
+        .with_header("Fastly-Request-ID", "req-123")])
+    .await;
+    let cfg = configuration(&server);
+
+    let err = create_healthcheck(&cfg, create_healthcheck_params()).await.unwrap_err();
+
+    let api_error = err.api_error().unwrap();
+    assert_eq!(api_error.status.as_u16(), 400);
+    assert_eq!(api_error.message.as_deref(), Some("Bad request"));
+    assert_eq!(api_error.detail.as_deref(), Some("Name can't be blank"));
+    assert_eq!(api_error.request_id.as_deref(), Some("req-123"));
+    assert_eq!(
+        err.to_string(),
+        "error in response: status code 400 Bad Request: Bad request: Name can't be blank (request ID req-123)",
+    );
+}
+
+#[tokio::test]
+async fn json_api_error_body_is_parsed() {
+    let body = r#"{"errors":[{"title":"Invalid value","detail":"must be a valid domain","source":{"pointer":"/data/attributes/domain"}}]}"#;
+    let server = StandIn::start(vec![ScriptedResponse::json(422, body)]).await;
+    let cfg = configuration(&server);
+
+    let err = get_tls_sub(&cfg, GetTlsSubParams { tls_subscription_id: "sub".to_owned(), ..Default::default() })
+        .await
+        .unwrap_err();
+
+    let api_error = err.api_error().unwrap();
+    assert_eq!(api_error.message.as_deref(), Some("Invalid value"));
+    assert_eq!(api_error.errors.len(), 1);
+    assert_eq!(api_error.errors[0].field.as_deref(), Some("/data/attributes/domain"));
+    assert!(err.to_string().contains("/data/attributes/domain: must be a valid domain"));
+}
+
+#[tokio::test]
+async fn non_json_error_body_still_reports_status() {
+    let server = StandIn::start(vec![ScriptedResponse {
+        status: 404,
+        headers: Vec::new(),
+        body: "Not Found".to_owned(),
+    }])
+    .await;
+    let cfg = configuration(&server);
+
+    let err = create_healthcheck(&cfg, create_healthcheck_params()).await.unwrap_err();
+
+    assert_eq!(err.to_string(), "error in response: status code 404 Not Found");
+}
@@ -0,0 +1,98 @@
+//! Wire-format tests for form-encoded request bodies.
+#![cfg(all(feature = "domains", feature = "healthcheck", feature = "vcl"))]
+
+mod common;
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::apex_redirect_api::{create_apex_redirect, CreateApexRedirectParams};
+use fastly_api::apis::healthcheck_api::{create_healthcheck, update_healthcheck, CreateHealthcheckParams, UpdateHealthcheckParams};
+use fastly_api::apis::rate_limiter_api::{create_rate_limiter, CreateRateLimiterParams};
+use fastly_api::apis::FormParams;
+
+#[test]
+fn arrays_are_encoded_as_repeated_bracketed_keys() {
+    let mut form = FormParams::new();
+    form.insert("name", "check".to_owned());
+    form.insert_array("headers", vec!["Host: example.com", "X-Test: a&b"]);
+
+    assert_eq!(form.encode(), "name=check&headers%5B%5D=Host%3A+example.com&headers%5B%5D=X-Test%3A+a%26b");
+}
+
+#[tokio::test]
+async fn create_healthcheck_sends_headers_in_form_body() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"name":"hc"}"#)]).await;
+    let cfg = configuration(&server);
+
+    let params = CreateHealthcheckParams {
+        service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(),
+        version_id: 1,
+        name: Some("hc".to_owned()),
+        headers: Some(vec!["Ricky: Test".to_owned(), "Another-Header: Test".to_owned()]),
+        ..Default::default()
+    };
+    create_healthcheck(&cfg, params).await.unwrap();
+
+    let request = server.last_request();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path(), "/service/SU1Z0isxPaozGVKXdv0eY/version/1/healthcheck");
+    assert_eq!(request.query(), "");
+    assert_eq!(request.header("content-type"), Some("application/x-www-form-urlencoded"));
+    assert_eq!(request.body, "headers%5B%5D=Ricky%3A+Test&headers%5B%5D=Another-Header%3A+Test&name=hc");
+}
+
+#[tokio::test]
+async fn update_healthcheck_sends_headers_in_form_body() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"name":"hc"}"#)]).await;
+    let cfg = configuration(&server);
+
+    let params = UpdateHealthcheckParams {
+        service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(),
+        version_id: 2,
+        healthcheck_name: "hc".to_owned(),
+        headers: Some(vec!["Ricky: Test".to_owned(), "Another-Header: Test".to_owned()]),
+        ..Default::default()
+    };
+    update_healthcheck(&cfg, params).await.unwrap();
+
+    let request = server.last_request();
+    assert_eq!(request.method, "PUT");
+    assert_eq!(request.path(), "/service/SU1Z0isxPaozGVKXdv0eY/version/2/healthcheck/hc");
+    assert_eq!(request.query(), "");
+    assert_eq!(request.body, "headers%5B%5D=Ricky%3A+Test&headers%5B%5D=Another-Header%3A+Test");
+}
+
+#[tokio::test]
+async fn create_rate_limiter_sends_each_array_element() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let cfg = configuration(&server);
+
+    let params = CreateRateLimiterParams {
+        service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(),
+        version_id: 1,
+        http_methods: Some(vec!["GET".to_owned(), "POST".to_owned()]),
+        client_key: Some(vec!["req.http.Fastly-Client-IP".to_owned()]),
+        ..Default::default()
+    };
+    create_rate_limiter(&cfg, params).await.unwrap();
+
+    assert_eq!(
+        server.last_request().body,
+        "http_methods%5B%5D=GET&http_methods%5B%5D=POST&client_key%5B%5D=req.http.Fastly-Client-IP",
+    );
+}
+
+#[tokio::test]
+async fn create_apex_redirect_sends_each_domain() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let cfg = configuration(&server);
+
+    let params = CreateApexRedirectParams {
+        service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(),
+        version_id: 1,
+        domains: Some(vec!["example.com".to_owned(), "example.org".to_owned()]),
+        ..Default::default()
+    };
+    create_apex_redirect(&cfg, params).await.unwrap();
+
+    assert_eq!(server.last_request().body, "domains%5B%5D=example.com&domains%5B%5D=example.org");
+}
@@ -0,0 +1,157 @@
+//! Walking list endpoints with `paginate`.
+#![cfg(all(feature = "accounts", feature = "billing", feature = "iam", feature = "stores", feature = "waf-legacy"))]
+
+mod common;
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::billing_usage_metrics_api::GetServiceLevelUsageParams;
+use fastly_api::apis::events_api::ListEventsParams;
+use fastly_api::apis::iam_roles_api::ListRolesParams;
+use fastly_api::apis::kv_store_item_api::GetKeysParams;
+use fastly_api::apis::legacy_waf_tag_api::ListLegacyWafTagsParams;
+use fastly_api::apis::pagination::paginate;
+use fastly_api::apis::service_api::ListServicesParams;
+use fastly_api::apis::Error;
+use futures::{StreamExt, TryStreamExt};
+
+#[tokio::test]
+async fn page_and_per_page_stop_at_a_short_page() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"[{"name":"a"},{"name":"b"}]"#),
+        ScriptedResponse::json(200, r#"[{"name":"c"}]"#),
+    ])
+    .await;
+    let cfg = configuration(&server);
+
+    let services: Vec<_> = paginate(&cfg, ListServicesParams::default()).page_size(2).try_collect().await.unwrap();
+
+    let names: Vec<_> = services.iter().map(|s| s.name.clone().unwrap()).collect();
+    assert_eq!(names, ["a", "b", "c"]);
+    let queries: Vec<_> = server.requests().iter().map(|r| r.query().to_owned()).collect();
+    assert_eq!(queries, ["per_page=2", "page=2&per_page=2"]);
+}
+
+#[tokio::test]
+async fn page_number_follows_links_next() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(
+            200,
+            r#"{"data":[{"id":"e1"}],"links":{"next":"https://api.fastly.com/events?page[number]=2"},"meta":{"current_page":1,"total_pages":2}}"#,
+        ),
+        ScriptedResponse::json(200, r#"{"data":[{"id":"e2"}],"links":{"next":null},"meta":{"current_page":2,"total_pages":2}}"#),
+    ])
+    .await;
+    let cfg = configuration(&server);
+
+    let events: Vec<_> = paginate(&cfg, ListEventsParams::default()).try_collect().await.unwrap();
+
+    let ids: Vec<_> = events.iter().map(|e| e.id.as_deref().map(String::as_str).unwrap()).collect();
+    assert_eq!(ids, ["e1", "e2"]);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].query().contains("page%5Bnumber%5D=2"), "{}", requests[1].query());
+}
+
+#[tokio::test]
+async fn untyped_json_api_documents_follow_meta() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"data":[{"id":"t1"},{"id":"t2"}],"meta":{"current_page":1,"total_pages":2}}"#),
+        ScriptedResponse::json(200, r#"{"data":[{"id":"t3"}],"meta":{"current_page":2,"total_pages":2}}"#),
+    ])
+    .await;
+    let cfg = configuration(&server);
+
+    let tags: Vec<_> = paginate(&cfg, ListLegacyWafTagsParams::default()).page_size(2).try_collect().await.unwrap();
+
+    let ids: Vec<_> = tags.iter().map(|t| t["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, ["t1", "t2", "t3"]);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].query().contains("page%5Bnumber%5D=2"), "{}", requests[1].query());
+}
+
+#[tokio::test]
+async fn iam_lists_yield_the_items_under_data() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"data":[{"id":"r1"},{"id":"r2"}],"meta":{"per_page":2}}"#),
+        ScriptedResponse::json(200, r#"{"data":[],"meta":{"per_page":2}}"#),
+    ])
+    .await;
+    let cfg = configuration(&server);
+
+    let roles: Vec<_> = paginate(&cfg, ListRolesParams::default()).page_size(2).try_collect().await.unwrap();
+
+    let ids: Vec<_> = roles.iter().map(|r| r["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, ["r1", "r2"]);
+    let queries: Vec<_> = server.requests().iter().map(|r| r.query().to_owned()).collect();
+    assert_eq!(queries, ["per_page=2", "per_page=2&page=2"]);
+}
+
+#[tokio::test]
+async fn service_usage_details_are_walked_by_cursor() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"data":{"details":[{"service_id":"s1"}],"meta":{"next_cursor":"c2"}}}"#),
+        ScriptedResponse::json(200, r#"{"data":{"details":[{"service_id":"s2"}],"meta":{}}}"#),
+    ])
+    .await;
+    let cfg = configuration(&server);
+
+    let params = GetServiceLevelUsageParams { customer_id: "x9KzsrACXZv8tPwlEDsKb6".to_owned(), ..Default::default() };
+    let usage: Vec<_> = paginate(&cfg, params).try_collect().await.unwrap();
+
+    let services: Vec<_> = usage.iter().map(|u| u.service_id.as_deref().unwrap()).collect();
+    assert_eq!(services, ["s1", "s2"]);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].query().contains("cursor=c2"), "{}", requests[1].query());
+}
+
+#[tokio::test]
+async fn cursor_is_passed_until_exhausted() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"data":["k1","k2"],"meta":{"next_cursor":"abc","limit":2}}"#),
+        ScriptedResponse::json(200, r#"{"data":["k3"],"meta":{"limit":2}}"#),
+    ])
+    .await;
+    let cfg = configuration(&server);
+
+    let params = GetKeysParams { store_id: "store".to_owned(), ..Default::default() };
+    let keys: Vec<String> = paginate(&cfg, params).page_size(2).try_collect().await.unwrap();
+
+    assert_eq!(keys, ["k1", "k2", "k3"]);
+    let queries: Vec<_> = server.requests().iter().map(|r| r.query().to_owned()).collect();
+    assert_eq!(queries, ["limit=2", "cursor=abc&limit=2"]);
+}
+
+#[tokio::test]
+async fn unconsumed_pages_are_not_fetched() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"data":["k1","k2"],"meta":{"next_cursor":"p2"}}"#),
+        ScriptedResponse::json(200, r#"{"data":["k3","k4"],"meta":{"next_cursor":"p3"}}"#),
+        ScriptedResponse::json(200, r#"{"data":["k5","k6"],"meta":{"next_cursor":"p4"}}"#),
+    ])
+    .await;
+    let cfg = configuration(&server);
+
+    let params = GetKeysParams { store_id: "store".to_owned(), ..Default::default() };
+    let keys: Vec<String> = paginate(&cfg, params).take(3).map(Result::unwrap).collect().await;
+
+    assert_eq!(keys, ["k1", "k2", "k3"]);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn stream_ends_after_an_error() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"[{"name":"a"}]"#),
+        ScriptedResponse::json(404, r#"{"msg":"not found"}"#),
+    ])
+    .await;
+    let cfg = configuration(&server);
+
+    let results: Vec<_> = paginate(&cfg, ListServicesParams::default()).collect().await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(matches!(&results[1], Err(Error::ResponseError(r)) if r.status == 404));
+}
@@ -0,0 +1,126 @@
+//! Rate-limit tracking from `Fastly-RateLimit-*` response headers.
+#![cfg(feature = "healthcheck")]
+
+mod common;
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use common::{configuration, create_healthcheck_params, get_service_params, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::{Configuration, RateLimitState, Throttle, DEFAULT_RATELIMIT};
+use fastly_api::apis::healthcheck_api::create_healthcheck;
+use fastly_api::apis::service_api::get_service;
+
+#[tokio::test]
+async fn get_requests_update_rate_limit_state() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")
+        .with_header("Fastly-RateLimit-Remaining", "998")
+        .with_header("Fastly-RateLimit-Reset", "1700000000")])
+    .await;
+    let cfg = configuration(&server);
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    let state = cfg.rate_limit_state();
+    assert_eq!(state.remaining, 998);
+    assert_eq!(state.reset, Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)));
+    assert!(state.last_updated.is_some());
+    assert_eq!(state.last_error, None);
+}
+
+#[tokio::test]
+async fn malformed_headers_are_reported_instead_of_panicking() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")
+        .with_header("Fastly-RateLimit-Remaining", "lots")
+        .with_header("Fastly-RateLimit-Reset", "1700000000")])
+    .await;
+    let cfg = configuration(&server);
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    let state = cfg.rate_limit_state();
+    assert_eq!(state.remaining, DEFAULT_RATELIMIT);
+    assert_eq!(state.reset, None);
+    let error = state.last_error.as_ref().unwrap();
+    assert_eq!(error.header, "Fastly-RateLimit-Remaining");
+    assert_eq!(error.value, "lots");
+}
+
+#[tokio::test]
+async fn throttle_holds_mutating_calls_until_reset() {
+    let reset = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 2;
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"name":"hc"}"#)
+        .with_header("Fastly-RateLimit-Remaining", "5")
+        .with_header("Fastly-RateLimit-Reset", &reset.to_string())])
+    .await;
+    let throttle = Throttle::new(5);
+    let cfg = Configuration { throttle: Some(throttle.clone()), ..configuration(&server) };
+    let clone = cfg.clone();
+
+    // The first call learns that only the reserve is left.
+    create_healthcheck(&cfg, create_healthcheck_params()).await.unwrap();
+    assert_eq!(throttle.available(), 5);
+
+    // A clone shares the bucket, so its call has to wait for the reset.
+    let started = Instant::now();
+    create_healthcheck(&clone, create_healthcheck_params()).await.unwrap();
+    assert!(started.elapsed() > Duration::from_millis(500), "mutating call was not throttled");
+
+    // Reads are never throttled.
+    let started = Instant::now();
+    get_service(&cfg, get_service_params()).await.unwrap();
+    assert!(started.elapsed() < Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn resync_leaves_out_calls_still_in_flight() {
+    let throttle = Throttle::new(5);
+    let first = throttle.acquire().await;
+    let second = throttle.acquire().await;
+    assert_eq!(throttle.available(), DEFAULT_RATELIMIT - 2);
+
+    // The first call completes; the server has not seen the second yet.
+    drop(first);
+    throttle.observe(&RateLimitState { remaining: 7, ..Default::default() });
+    assert_eq!(throttle.available(), 6);
+
+    drop(second);
+    throttle.observe(&RateLimitState { remaining: 6, ..Default::default() });
+    assert_eq!(throttle.available(), 6);
+}
+
+#[tokio::test]
+async fn calls_let_through_without_a_reset_count_as_in_flight() {
+    let throttle = Throttle::new(5);
+    throttle.observe(&RateLimitState { remaining: 5, reset: None, ..Default::default() });
+
+    // Only the reserve is left, but with no reset time both calls go ahead.
+    let first = throttle.acquire().await;
+    let second = throttle.acquire().await;
+    throttle.observe(&RateLimitState { remaining: 4, reset: None, ..Default::default() });
+    assert_eq!(throttle.available(), 2);
+
+    drop(first);
+    drop(second);
+    throttle.observe(&RateLimitState { remaining: 3, reset: None, ..Default::default() });
+    assert_eq!(throttle.available(), 3);
+}
+
+#[tokio::test]
+async fn seeded_throttle_starts_from_the_known_budget() {
+    let reset = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 2;
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"name":"hc"}"#)
+        .with_header("Fastly-RateLimit-Remaining", "5")
+        .with_header("Fastly-RateLimit-Reset", &reset.to_string())])
+    .await;
+    let cfg = configuration(&server);
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    let throttle = Throttle::seeded(5, &cfg.rate_limit_state());
+    assert_eq!(throttle.available(), 5);
+
+    // Only the reserve is left, so the first mutating call already waits.
+    let cfg = Configuration { throttle: Some(throttle), ..cfg };
+    let started = Instant::now();
+    create_healthcheck(&cfg, create_healthcheck_params()).await.unwrap();
+    assert!(started.elapsed() > Duration::from_millis(500), "mutating call was not throttled");
+}
@@ -0,0 +1,74 @@
+//! Calling arbitrary endpoints with `apis::raw::request`.
+
+mod common;
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::raw::{self, Body};
+use fastly_api::apis::{Error, FormParams, MultipartParams};
+use reqwest::Method;
+use serde_json::json;
+
+#[tokio::test]
+async fn json_request_returns_json_entity() {
+    let server = StandIn::start(vec![ScriptedResponse::json(201, r#"{"id":"n1"}"#).with_header("Fastly-Request-ID", "r1")]).await;
+    let cfg = configuration(&server);
+
+    let response = raw::request(&cfg, Method::POST, "/resources/new-thing", &[("dry run", "true")], Body::from(json!({"name": "n"})))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, 201);
+    assert_eq!(response.entity, json!({"id": "n1"}));
+    assert_eq!(response.request_id(), Some("r1"));
+    let request = server.last_request();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.target, "/resources/new-thing?dry+run=true");
+    assert_eq!(request.header("fastly-key"), Some("test-token"));
+    assert_eq!(request.header("content-type"), Some("application/json"));
+    assert_eq!(request.body, r#"{"name":"n"}"#);
+}
+
+#[tokio::test]
+async fn form_and_multipart_bodies_are_encoded() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "")]).await;
+    let cfg = configuration(&server);
+
+    let mut form = FormParams::new();
+    form.insert("name", "a b".to_owned());
+    let response = raw::request(&cfg, Method::PUT, "/thing", &[], form.into()).await.unwrap();
+    assert_eq!(response.entity, serde_json::Value::Null);
+
+    let mut parts = MultipartParams::new();
+    parts.file("package", "pkg.tar.gz", b"bytes".to_vec());
+    raw::request(&cfg, Method::PUT, "/thing", &[], parts.into()).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("content-type"), Some("application/x-www-form-urlencoded"));
+    assert_eq!(requests[0].body, "name=a+b");
+    assert!(requests[1].header("content-type").unwrap().starts_with("multipart/form-data; boundary="));
+    assert!(requests[1].body.contains(r#"name="package"; filename="pkg.tar.gz""#));
+}
+
+#[tokio::test]
+async fn error_status_is_a_response_error() {
+    let server = StandIn::start(vec![ScriptedResponse::json(404, r#"{"msg":"Record not found"}"#)]).await;
+    let cfg = configuration(&server);
+
+    let err = raw::request(&cfg, Method::GET, "/missing", &[], Body::Empty).await.unwrap_err();
+
+    let Error::ResponseError(content) = err else { panic!("expected a response error, got {err:?}") };
+    assert_eq!(content.status, 404);
+    assert_eq!(content.entity, Some(json!({"msg": "Record not found"})));
+    assert_eq!(content.api_error.message.as_deref(), Some("Record not found"));
+}
+
+#[tokio::test]
+async fn non_json_body_is_kept_as_a_string() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "sub vcl_recv { }").with_header("ETag", "\"v1\"")]).await;
+    let cfg = configuration(&server);
+
+    let response = raw::request(&cfg, Method::GET, "/thing.vcl", &[], Body::Empty).await.unwrap();
+
+    assert_eq!(response.entity, json!("sub vcl_recv { }"));
+    assert_eq!(response.etag(), Some("\"v1\""));
+}
@@ -0,0 +1,49 @@
+//! Status and headers of successful responses via the `*_with_info` variants.
+#![cfg(all(feature = "healthcheck", feature = "stores"))]
+
+mod common;
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use common::{configuration, ScriptedResponse, StandIn};
+use fastly_api::apis::healthcheck_api::{update_healthcheck_with_info, UpdateHealthcheckParams};
+use fastly_api::apis::kv_store_item_api::{delete_key_from_store_with_info, DeleteKeyFromStoreParams};
+
+#[tokio::test]
+async fn entity_comes_with_status_and_headers() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"{"name":"hc"}"#)
+        .with_header("Fastly-Request-ID", "b6a8c2f0")
+        .with_header("ETag", "\"abc123\"")
+        .with_header("Fastly-RateLimit-Remaining", "998")
+        .with_header("Fastly-RateLimit-Reset", "1700000000")])
+    .await;
+    let cfg = configuration(&server);
+
+    let params = UpdateHealthcheckParams {
+        service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(),
+        version_id: 1,
+        healthcheck_name: "hc".to_owned(),
+        ..Default::default()
+    };
+    let response = update_healthcheck_with_info(&cfg, params).await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.request_id(), Some("b6a8c2f0"));
+    assert_eq!(response.etag(), Some("\"abc123\""));
+    assert_eq!(response.link(), None);
+    assert_eq!(response.rate_limit_remaining(), Some(998));
+    assert_eq!(response.rate_limit_reset(), Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)));
+    assert_eq!(response.into_entity().name.as_deref(), Some("hc"));
+}
+
+#[tokio::test]
+async fn endpoints_without_a_body_still_report_status() {
+    let server = StandIn::start(vec![ScriptedResponse::json(204, "").with_header("Fastly-Request-ID", "77f1")]).await;
+    let cfg = configuration(&server);
+
+    let params = DeleteKeyFromStoreParams { store_id: "store".to_owned(), key_name: "k".to_owned() };
+    let response = delete_key_from_store_with_info(&cfg, params).await.unwrap();
+
+    assert_eq!(response.status, 204);
+    assert_eq!(response.request_id(), Some("77f1"));
+}
@@ -0,0 +1,154 @@
+//! Retries against scripted transient failures.
+#![cfg(feature = "healthcheck")]
+
+mod common;
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use common::{configuration, configuration_at, create_healthcheck_params, get_service_params, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::{Configuration, RetryPolicy};
+use fastly_api::apis::healthcheck_api::create_healthcheck;
+use fastly_api::apis::service_api::get_service;
+use fastly_api::apis::Error;
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn transient_statuses_are_retried_until_success() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(503, r#"{"msg":"unavailable"}"#),
+        ScriptedResponse::json(502, r#"{"msg":"bad gateway"}"#),
+        ScriptedResponse::json(200, r#"{"id":"SU1Z0isxPaozGVKXdv0eY"}"#),
+    ])
+    .await;
+    let cfg = Configuration { retry_policy: fast_policy(), ..configuration(&server) };
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn gives_up_after_max_attempts() {
+    let server = StandIn::start(vec![ScriptedResponse::json(504, "{}")]).await;
+    let cfg = Configuration { retry_policy: fast_policy(), ..configuration(&server) };
+
+    let err = get_service(&cfg, get_service_params()).await.unwrap_err();
+
+    match err {
+        Error::ResponseError(content) => assert_eq!(content.status.as_u16(), 504),
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let server = StandIn::start(vec![ScriptedResponse::json(404, r#"{"msg":"not found"}"#)]).await;
+    let cfg = Configuration { retry_policy: fast_policy(), ..configuration(&server) };
+
+    assert!(get_service(&cfg, get_service_params()).await.is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn post_is_not_retried_by_default() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(503, "{}"),
+        ScriptedResponse::json(200, r#"{"name":"hc"}"#),
+    ])
+    .await;
+    let cfg = Configuration { retry_policy: fast_policy(), ..configuration(&server) };
+
+    assert!(create_healthcheck(&cfg, create_healthcheck_params()).await.is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn post_is_retried_when_opted_in() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(503, "{}"),
+        ScriptedResponse::json(200, r#"{"name":"hc"}"#),
+    ])
+    .await;
+    let policy = RetryPolicy { retry_non_idempotent: true, ..fast_policy() };
+    let cfg = Configuration { retry_policy: policy, ..configuration(&server) };
+
+    create_healthcheck(&cfg, create_healthcheck_params()).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].body, requests[1].body);
+}
+
+#[tokio::test]
+async fn rate_limited_requests_wait_for_the_reset_time() {
+    let reset = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 2;
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(429, "{}")
+            .with_header("Fastly-RateLimit-Remaining", "0")
+            .with_header("Fastly-RateLimit-Reset", &reset.to_string()),
+        ScriptedResponse::json(200, "{}"),
+    ])
+    .await;
+    let cfg = Configuration { retry_policy: fast_policy(), ..configuration(&server) };
+
+    let started = Instant::now();
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    assert_eq!(server.requests().len(), 2);
+    assert!(started.elapsed() > Duration::from_millis(500), "retried without waiting for the reset");
+}
+
+#[tokio::test]
+async fn unbounded_max_wait_waits_for_a_distant_reset() {
+    let reset = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 3600;
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(429, "{}")
+            .with_header("Fastly-RateLimit-Remaining", "0")
+            .with_header("Fastly-RateLimit-Reset", &reset.to_string()),
+        ScriptedResponse::json(200, "{}"),
+    ])
+    .await;
+    let policy = RetryPolicy { max_wait: None, ..fast_policy() };
+    let cfg = Configuration { retry_policy: policy, ..configuration(&server) };
+
+    let call = get_service(&cfg, get_service_params());
+    let waited = tokio::time::timeout(Duration::from_millis(500), call).await;
+
+    assert!(waited.is_err(), "gave up instead of waiting for the reset: {waited:?}");
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn retry_after_beyond_max_wait_fails_immediately() {
+    let server = StandIn::start(vec![
+        ScriptedResponse::json(429, "{}").with_header("Retry-After", "3600"),
+        ScriptedResponse::json(200, "{}"),
+    ])
+    .await;
+    let cfg = Configuration { retry_policy: fast_policy(), ..configuration(&server) };
+
+    let err = get_service(&cfg, get_service_params()).await.unwrap_err();
+
+    assert!(matches!(err, Error::ResponseError(ref content) if content.status.as_u16() == 429));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn connection_failures_surface_after_retries() {
+    // Bind and drop a listener to get a port that refuses connections.
+    let addr = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+    let cfg = Configuration { retry_policy: fast_policy(), ..configuration_at(&format!("http://{addr}")) };
+
+    let err = get_service(&cfg, get_service_params()).await.unwrap_err();
+
+    assert!(matches!(err, Error::Reqwest(ref e) if e.is_connect()));
+}
@@ -0,0 +1,163 @@
+//! Reading list responses item by item with `streaming::stream`.
+#![cfg(all(feature = "stats", feature = "stores"))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{configuration, configuration_at, ScriptedResponse, StandIn};
+use fastly_api::apis::historical_api::GetHistStatsParams;
+use fastly_api::apis::kv_store_item_api::GetKeysParams;
+use fastly_api::apis::service_api::ListServicesParams;
+use fastly_api::apis::stats_api::GetServiceStatsParams;
+use fastly_api::apis::streaming::stream;
+use fastly_api::apis::Error;
+use futures::{StreamExt, TryStreamExt};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Answer one request with a chunked 200 response, writing each of `parts`
+/// as a chunk of its own with a pause in between.
+async fn serve_in_parts(parts: &'static [&'static str]) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_path = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (mut conn, _) = listener.accept().await.unwrap();
+        let mut head = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = conn.read(&mut buf).await.unwrap();
+            head.extend_from_slice(&buf[..n]);
+        }
+        let head = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n";
+        conn.write_all(head.as_bytes()).await.unwrap();
+        for part in parts {
+            conn.write_all(format!("{:x}\r\n{}\r\n", part.len(), part).as_bytes()).await.unwrap();
+            conn.flush().await.unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        conn.write_all(b"0\r\n\r\n").await.unwrap();
+    });
+    base_path
+}
+
+#[tokio::test]
+async fn bare_array_yields_each_element() {
+    let server = StandIn::start(vec![ScriptedResponse::json(200, r#"[{"name":"a"}, {"name":"b","versions":[{"number":1}]}]"#)]).await;
+    let cfg = configuration(&server);
+
+    let services: Vec<_> = stream(&cfg, ListServicesParams::default()).try_collect().await.unwrap();
+
+    let names: Vec<_> = services.iter().map(|s| s.name.clone().unwrap()).collect();
+    assert_eq!(names, ["a", "b"]);
+    assert_eq!(server.last_request().path(), "/service");
+}
+
+#[tokio::test]
+async fn items_under_data_skip_the_other_fields() {
+    let body = r#"{"meta":{"limit":2,"data":["not","these"]},"data":["k1","k2"],"links":{"next":"x"}}"#;
+    let server = StandIn::start(vec![ScriptedResponse::json(200, body)]).await;
+    let cfg = configuration(&server);
+
+    let params = GetKeysParams { store_id: "store".to_owned(), ..Default::default() };
+    let keys: Vec<String> = stream(&cfg, params).try_collect().await.unwrap();
+
+    assert_eq!(keys, ["k1", "k2"]);
+}
+
+#[tokio::test]
+async fn elements_split_across_chunks() {
+    let base_path = serve_in_parts(&[
+        r#"{"data":["k1","a \"quoted\" ]"#,
+        r#", key","k\"#,
+        r#"\3", "{[,]}"]}"#,
+    ])
+    .await;
+    let cfg = configuration_at(&base_path);
+
+    let params = GetKeysParams { store_id: "store".to_owned(), ..Default::default() };
+    let mut keys = stream(&cfg, params);
+
+    // The first key is yielded before the rest of the body has been sent.
+    assert_eq!(keys.next().await.unwrap().unwrap(), "k1");
+    let rest: Vec<String> = keys.try_collect().await.unwrap();
+    assert_eq!(rest, [r#"a "quoted" ], key"#, r"k\3", "{[,]}"]);
+}
+
+#[tokio::test]
+async fn historical_stats_yield_each_entry_with_its_service() {
+    // A day of per-minute entries for each of three services.
+    let service_ids = ["SU1Z0isxPaozGVKXdv0eY", "2ZXybUfnZdPUz6F1KXxGjt", "7i6HN3TK9wS159v2gPAZ8A"];
+    let minutes = 24 * 60;
+    let lists: Vec<_> = service_ids
+        .iter()
+        .map(|service_id| {
+            let entries: Vec<_> = (0..minutes)
+                .map(|minute| format!(r#"{{"service_id":"{service_id}","start_time":{},"requests":{minute},"hits":{}}}"#, 1_700_000_000 + 60 * minute, minute / 2))
+                .collect();
+            format!(r#""{service_id}":[{}]"#, entries.join(","))
+        })
+        .collect();
+    let body = format!(r#"{{"status":"success","meta":{{"by":"minute"}},"msg":null,"data":{{{}}}}}"#, lists.join(","));
+    let server = StandIn::start(vec![ScriptedResponse::json(200, &body)]).await;
+    let cfg = configuration(&server);
+
+    let params = GetHistStatsParams { by: Some("minute".to_owned()), ..Default::default() };
+    let entries: Vec<_> = stream(&cfg, params).try_collect().await.unwrap();
+
+    assert_eq!(entries.len(), service_ids.len() * minutes as usize);
+    for (index, (service_id, entry)) in entries.iter().enumerate() {
+        let minute = index as i32 % minutes;
+        assert_eq!(service_id, service_ids[index / minutes as usize]);
+        assert_eq!(entry.service_id.as_deref().map(String::as_str), Some(service_id.as_str()));
+        assert_eq!((entry.start_time, entry.requests, entry.hits), (Some(1_700_000_000 + 60 * minute), Some(minute), Some(minute / 2)));
+    }
+    assert_eq!(server.last_request().target, "/stats?by=minute");
+}
+
+#[tokio::test]
+async fn keyed_items_split_across_chunks() {
+    let base_path = serve_in_parts(&[
+        r#"{"stats":{"AMS":{"requests":1},"a \"quoted\" "#,
+        r#"pop":{"requests":2,"service_id":"}"},"#,
+        r#""LHR":{"requests":3}}}"#,
+    ])
+    .await;
+    let cfg = configuration_at(&base_path);
+
+    let params = GetServiceStatsParams { service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(), ..Default::default() };
+    let mut pops = stream(&cfg, params);
+
+    // The first PoP is yielded before the rest of the body has been sent.
+    let (pop, stats) = pops.next().await.unwrap().unwrap();
+    assert_eq!((pop.as_str(), stats.requests), ("AMS", Some(1)));
+    let rest: Vec<_> = pops.map_ok(|(pop, stats)| (pop, stats.requests)).try_collect().await.unwrap();
+    assert_eq!(rest, [(r#"a "quoted" pop"#.to_owned(), Some(2)), ("LHR".to_owned(), Some(3))]);
+}
+
+#[tokio::test]
+async fn truncated_body_ends_with_a_serde_error() {
+    let base_path = serve_in_parts(&[r#"[{"name":"a"},{"na"#]).await;
+    let cfg = configuration_at(&base_path);
+
+    let mut services = stream(&cfg, ListServicesParams::default());
+
+    assert_eq!(services.next().await.unwrap().unwrap().name.as_deref(), Some("a"));
+    let err = services.next().await.unwrap().unwrap_err();
+    assert!(matches!(err, Error::Serde(_)), "{err}");
+    assert!(services.next().await.is_none());
+}
+
+#[tokio::test]
+async fn error_status_is_a_response_error() {
+    let server = StandIn::start(vec![ScriptedResponse::json(404, r#"{"msg":"Record not found","detail":"Couldn't find Store"}"#)]).await;
+    let cfg = configuration(&server);
+
+    let params = GetKeysParams { store_id: "missing".to_owned(), ..Default::default() };
+    let results: Vec<_> = stream(&cfg, params).collect().await;
+
+    assert_eq!(results.len(), 1);
+    let Err(Error::ResponseError(content)) = &results[0] else { panic!("expected a response error, got {:?}", results[0]) };
+    assert_eq!(content.status, 404);
+    assert_eq!(content.api_error.detail.as_deref(), Some("Couldn't find Store"));
+}
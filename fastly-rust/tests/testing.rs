@@ -0,0 +1,173 @@
+//! The in-memory `FakeFastly` server behind the `testing` feature.
+#![cfg(feature = "testing")]
+
+use fastly_api::apis::acl_api::{create_acl, CreateAclParams};
+use fastly_api::apis::acl_entry_api::{create_acl_entry, list_acl_entries, CreateAclEntryParams, ListAclEntriesParams};
+use fastly_api::apis::backend_api::{create_backend, CreateBackendParams};
+use fastly_api::apis::dictionary_api::{create_dictionary, CreateDictionaryParams};
+use fastly_api::apis::dictionary_item_api::{bulk_update_dictionary_item, BulkUpdateDictionaryItemParams};
+use fastly_api::apis::domain_api::{create_domain, CreateDomainParams};
+use fastly_api::apis::healthcheck_api::{create_healthcheck, CreateHealthcheckParams};
+use fastly_api::apis::kv_store_api::{create_store, CreateStoreParams};
+use fastly_api::apis::kv_store_item_api::{get_value_for_key, set_value_for_key, GetKeysParams, GetValueForKeyParams, SetValueForKeyParams};
+use fastly_api::apis::pagination::paginate;
+use fastly_api::apis::public_ip_list_api::list_fastly_ips;
+use fastly_api::apis::service_api::{get_service_detail, GetServiceDetailParams};
+use fastly_api::apis::version_api::{activate_service_version, clone_service_version, ActivateServiceVersionParams, CloneServiceVersionParams};
+use fastly_api::apis::configuration::Configuration;
+use fastly_api::apis::Error;
+use fastly_api::models::{AclEntry, BulkUpdateDictionaryItem, BulkUpdateDictionaryListRequest, Store};
+use fastly_api::models::bulk_update_dictionary_item::Op;
+use fastly_api::testing::{FakeFastly, FAKE_TOKEN};
+use futures::TryStreamExt;
+
+#[tokio::test]
+async fn healthcheck_with_headers_is_stored() {
+    // The `src/main.rs` reproduction, without a network.
+    let fake = FakeFastly::start().await.unwrap();
+    let service_id = fake.create_service("health-check-test");
+
+    let params = CreateHealthcheckParams {
+        service_id: service_id.clone(),
+        version_id: 1,
+        headers: Some(vec!["Ricky: Test".to_owned(), "Another-Header: Test".to_owned()]),
+        name: Some("hc1".to_owned()),
+        threshold: Some(3),
+        ..Default::default()
+    };
+    let created = create_healthcheck(&fake.configuration(), params).await.unwrap();
+
+    assert_eq!(created.headers, Some(vec!["Ricky: Test".to_owned(), "Another-Header: Test".to_owned()]));
+    assert_eq!(created.threshold, Some(3));
+    let stored = fake.healthchecks(&service_id, 1);
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].name.as_deref(), Some("hc1"));
+
+    let request = &fake.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, format!("/service/{}/version/1/healthcheck", service_id));
+    assert_eq!(request.header("fastly-key"), Some(FAKE_TOKEN));
+    assert_eq!(request.body, "headers%5B%5D=Ricky%3A+Test&headers%5B%5D=Another-Header%3A+Test&name=hc1&threshold=3");
+}
+
+#[tokio::test]
+async fn versions_clone_activate_and_lock() {
+    let fake = FakeFastly::start().await.unwrap();
+    let cfg = fake.configuration();
+    let service_id = fake.create_service("lifecycle");
+
+    let backend = CreateBackendParams {
+        service_id: service_id.clone(),
+        version_id: 1,
+        name: Some("origin".to_owned()),
+        address: Some("example.com".to_owned()),
+        port: Some(443),
+        use_ssl: Some(true),
+        ..Default::default()
+    };
+    create_backend(&cfg, backend.clone()).await.unwrap();
+    activate_service_version(&cfg, ActivateServiceVersionParams { service_id: service_id.clone(), version_id: 1 }).await.unwrap();
+
+    // Active versions are locked.
+    let err = create_backend(&cfg, CreateBackendParams { name: Some("other".to_owned()), ..backend }).await.unwrap_err();
+    assert!(matches!(&err, Error::ResponseError(r) if r.status == 400), "{err}");
+
+    let clone = clone_service_version(&cfg, CloneServiceVersionParams { service_id: service_id.clone(), version_id: 1 }).await.unwrap();
+    assert_eq!(clone.number, Some(2));
+    let domain = CreateDomainParams { service_id: service_id.clone(), version_id: 2, name: Some("www.example.com".to_owned()), ..Default::default() };
+    create_domain(&cfg, domain.clone()).await.unwrap();
+    let err = create_domain(&cfg, domain).await.unwrap_err();
+    assert!(matches!(&err, Error::ResponseError(r) if r.status == 409), "{err}");
+    activate_service_version(&cfg, ActivateServiceVersionParams { service_id: service_id.clone(), version_id: 2 }).await.unwrap();
+
+    assert_eq!(fake.backends(&service_id, 2)[0].port, Some(443));
+    assert_eq!(fake.domains(&service_id, 1).len(), 0);
+    assert_eq!(fake.version(&service_id, 1).unwrap().active, Some(false));
+    let detail = get_service_detail(&cfg, GetServiceDetailParams { service_id: service_id.clone(), ..Default::default() }).await.unwrap();
+    let active = detail.active_version.unwrap();
+    assert_eq!(active.number, Some(2));
+    assert_eq!(active.locked, Some(true));
+    assert_eq!(active.domains.unwrap()[0].name.as_deref(), Some("www.example.com"));
+}
+
+#[tokio::test]
+async fn dictionary_items_and_acl_entries() {
+    let fake = FakeFastly::start().await.unwrap();
+    let cfg = fake.configuration();
+    let service_id = fake.create_service("edge-data");
+
+    let dictionary = CreateDictionaryParams { service_id: service_id.clone(), version_id: 1, name: Some("flags".to_owned()), ..Default::default() };
+    let dictionary_id = create_dictionary(&cfg, dictionary).await.unwrap().id.unwrap().to_string();
+    let item = |key: &str, op| BulkUpdateDictionaryItem { item_key: Some(key.to_owned()), item_value: Some("on".to_owned()), op: Some(op) };
+    let bulk = BulkUpdateDictionaryItemParams {
+        service_id: service_id.clone(),
+        dictionary_id: dictionary_id.clone(),
+        bulk_update_dictionary_list_request: Some(BulkUpdateDictionaryListRequest { items: Some(vec![item("a", Op::Create), item("b", Op::Upsert)]) }),
+    };
+    bulk_update_dictionary_item(&cfg, bulk).await.unwrap();
+    assert_eq!(fake.dictionary_items(&service_id, &dictionary_id), [("a".to_owned(), "on".to_owned()), ("b".to_owned(), "on".to_owned())]);
+
+    let acl_id = create_acl(&cfg, CreateAclParams { service_id: service_id.clone(), version_id: 1, name: Some("blocked".to_owned()) })
+        .await
+        .unwrap()
+        .id
+        .unwrap()
+        .to_string();
+    let entry = AclEntry { ip: Some("192.0.2.0".to_owned()), subnet: Some(24), ..Default::default() };
+    let params = CreateAclEntryParams { service_id: service_id.clone(), acl_id: acl_id.clone(), acl_entry: Some(entry) };
+    create_acl_entry(&cfg, params).await.unwrap();
+
+    let listed = list_acl_entries(&cfg, ListAclEntriesParams { service_id: service_id.clone(), acl_id: acl_id.clone(), ..Default::default() }).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].subnet, Some(24));
+    assert_eq!(fake.acl_entries(&service_id, &acl_id)[0].ip.as_deref(), Some("192.0.2.0"));
+}
+
+#[tokio::test]
+async fn kv_store_items_page_with_a_cursor() {
+    let fake = FakeFastly::start().await.unwrap();
+    let cfg = fake.configuration();
+
+    let store = create_store(&cfg, CreateStoreParams { store: Some(Store { name: Some("sessions".to_owned()) }), ..Default::default() }).await.unwrap();
+    let store_id = store.id.unwrap();
+    for key in ["k1", "k2", "k3"] {
+        let params = SetValueForKeyParams { store_id: store_id.clone(), key_name: key.to_owned(), body: Some(format!("value of {key}")), ..Default::default() };
+        set_value_for_key(&cfg, params).await.unwrap();
+    }
+
+    let value = get_value_for_key(&cfg, GetValueForKeyParams { store_id: store_id.clone(), key_name: "k2".to_owned() }).await.unwrap();
+    assert_eq!(value, "value of k2");
+    assert_eq!(fake.kv_value(&store_id, "k3").as_deref(), Some("value of k3"));
+
+    let keys: Vec<String> = paginate(&cfg, GetKeysParams { store_id, ..Default::default() }).page_size(2).try_collect().await.unwrap();
+    assert_eq!(keys, ["k1", "k2", "k3"]);
+}
+
+#[tokio::test]
+async fn unauthenticated_and_unknown_requests_fail() {
+    let fake = FakeFastly::start().await.unwrap();
+
+    let anonymous = reqwest::get(format!("{}/service", fake.base_path())).await.unwrap();
+    assert_eq!(anonymous.status(), 401);
+
+    let params = GetServiceDetailParams { service_id: "nope".to_owned(), ..Default::default() };
+    let err = get_service_detail(&fake.configuration(), params).await.unwrap_err();
+    let Error::ResponseError(content) = err else { panic!("expected a response error, got {err:?}") };
+    assert_eq!(content.status, 404);
+    assert_eq!(content.api_error.detail.as_deref(), Some("Couldn't find Service 'nope'"));
+    assert!(content.api_error.request_id.is_some());
+}
+
+#[tokio::test]
+async fn public_ip_list_needs_no_credential() {
+    let fake = FakeFastly::start().await.unwrap();
+    let cfg = Configuration { base_path: fake.base_path().to_owned(), api_key: None, ..Default::default() };
+
+    let ips = list_fastly_ips(&cfg).await.unwrap();
+
+    assert_eq!(ips.addresses.as_deref(), Some(&["23.235.32.0/20".to_owned()][..]));
+    let requests = fake.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].header("fastly-key"), None);
+    assert_eq!(requests[0].header("authorization"), None);
+}
@@ -0,0 +1,75 @@
+//! Fetching and refreshing credentials through a `TokenSource`.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::{get_service_params, ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::Configuration;
+use fastly_api::apis::service_api::get_service;
+use fastly_api::apis::token_source::{CachingTokenSource, ClientCredentialsTokenSource, TokenSource};
+use fastly_api::apis::Error;
+
+fn configuration(api: &StandIn, source: impl TokenSource + 'static) -> Configuration {
+    Configuration { api_key: None, token_source: Some(Arc::new(source)), ..common::configuration(api) }
+}
+
+#[tokio::test]
+async fn token_is_fetched_from_endpoint_and_cached() {
+    let tokens = StandIn::start(vec![ScriptedResponse::json(200, r#"{"access_token":"t1","token_type":"bearer","expires_in":3600}"#)]).await;
+    let api = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let source = ClientCredentialsTokenSource::new(format!("{}/token", tokens.base_path), "client", "hunter2");
+    let cfg = configuration(&api, CachingTokenSource::new(source));
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    assert_eq!(tokens.requests().len(), 1);
+    let token_request = tokens.last_request();
+    assert_eq!(token_request.method, "POST");
+    assert_eq!(token_request.body, "grant_type=client_credentials");
+    assert_eq!(token_request.header("authorization"), Some("Basic Y2xpZW50Omh1bnRlcjI="));
+
+    let requests = api.requests();
+    assert_eq!(requests.len(), 2);
+    for request in requests {
+        assert_eq!(request.header("authorization"), Some("Bearer t1"));
+        assert_eq!(request.header("fastly-key"), None);
+    }
+}
+
+#[tokio::test]
+async fn expired_token_is_refreshed() {
+    let tokens = StandIn::start(vec![
+        ScriptedResponse::json(200, r#"{"access_token":"t1","expires_in":1}"#),
+        ScriptedResponse::json(200, r#"{"access_token":"t2","expires_in":3600}"#),
+    ])
+    .await;
+    let api = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let source = ClientCredentialsTokenSource::new(format!("{}/token", tokens.base_path), "client", "hunter2");
+    let cfg = configuration(&api, CachingTokenSource::with_leeway(source, Duration::ZERO));
+
+    get_service(&cfg, get_service_params()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    get_service(&cfg, get_service_params()).await.unwrap();
+    get_service(&cfg, get_service_params()).await.unwrap();
+
+    assert_eq!(tokens.requests().len(), 2);
+    let keys: Vec<_> = api.requests().iter().map(|r| r.header("fastly-key").unwrap_or_default().to_owned()).collect();
+    assert_eq!(keys, ["t1", "t2", "t2"]);
+}
+
+#[tokio::test]
+async fn token_endpoint_failure_is_an_auth_error() {
+    let tokens = StandIn::start(vec![ScriptedResponse::json(401, r#"{"error":"invalid_client"}"#)]).await;
+    let api = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let source = ClientCredentialsTokenSource::new(format!("{}/token", tokens.base_path), "client", "wrong");
+    let cfg = configuration(&api, source);
+
+    let err = get_service(&cfg, get_service_params()).await.unwrap_err();
+
+    assert!(matches!(err, Error::Auth(_)));
+    assert_eq!(err.to_string(), "error in authentication: token endpoint returned status code 401 Unauthorized");
+    assert!(api.requests().is_empty());
+}
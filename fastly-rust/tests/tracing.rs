@@ -0,0 +1,63 @@
+//! Request instrumentation, with credentials kept out of the logs.
+
+#![cfg(all(feature = "tracing", feature = "logging"))]
+
+mod common;
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use common::{ScriptedResponse, StandIn};
+use fastly_api::apis::configuration::{ApiKey, Configuration};
+use fastly_api::apis::logging_ftp_api::{create_log_ftp, CreateLogFtpParams};
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn requests_are_traced_with_secrets_redacted() {
+    let capture = Capture::default();
+    let writer = capture.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let server = StandIn::start(vec![ScriptedResponse::json(200, "{}")]).await;
+    let cfg = Configuration {
+        base_path: server.base_path.clone(),
+        api_key: Some(ApiKey { prefix: None, key: "super-secret-token".into() }),
+        ..Default::default()
+    };
+    let params = CreateLogFtpParams {
+        service_id: "SU1Z0isxPaozGVKXdv0eY".to_owned(),
+        version_id: 3,
+        name: Some("ftp".to_owned()),
+        password: Some("hunter2".into()),
+        ..Default::default()
+    };
+    create_log_ftp(&cfg, params).await.unwrap();
+
+    let logs = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("endpoint=\"create_log_ftp\""), "{logs}");
+    assert!(logs.contains("service_id=\"SU1Z0isxPaozGVKXdv0eY\""), "{logs}");
+    assert!(logs.contains("version=\"3\""), "{logs}");
+    assert!(logs.contains(r#"("password", "***")"#), "{logs}");
+    assert!(!logs.contains("hunter2"), "{logs}");
+    assert!(!logs.contains("super-secret-token"), "{logs}");
+    // The request was sent with the real values.
+    assert!(server.last_request().body.contains("password=hunter2"));
+}
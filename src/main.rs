@@ -3,7 +3,7 @@ use rand::{thread_rng, Rng};
 use rand::distributions::{Alphanumeric, Uniform, Standard};
 
 #[cfg(feature = "fix")]
-use patch_fastly_api::apis::{healthcheck_api::{create_healthcheck, CreateHealthcheckParams}, configuration::{ApiKey, Configuration}};
+use patch_fastly_api::apis::{healthcheck_api::{create_healthcheck, CreateHealthcheckParams}, configuration::Configuration};
 #[cfg(not(feature = "fix"))]
 use fastly_api::apis::{healthcheck_api::{create_healthcheck, CreateHealthcheckParams}, configuration::{ApiKey, Configuration}};
 
@@ -20,6 +20,7 @@ fn random_health_check_name() -> String {
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    #[cfg(not(feature = "fix"))]
     let mut cfg = Configuration {
         api_key: Some(ApiKey {
             prefix: None,
@@ -27,6 +28,8 @@ async fn main() -> Result<()> {
         }),
         ..Default::default()
     };
+    #[cfg(feature = "fix")]
+    let cfg = Configuration::from_env_chain()?;
 
     let params = CreateHealthcheckParams {
         service_id: env::var("FASTLY_SERVICE_ID")?,
@@ -36,7 +39,10 @@ async fn main() -> Result<()> {
         ..Default::default()
     };
 
+    #[cfg(not(feature = "fix"))]
     let res = create_healthcheck(&mut cfg, params).await;
+    #[cfg(feature = "fix")]
+    let res = create_healthcheck(&cfg, params).await;
     println!("Response:\n{res:?}\n");
 
     Ok(())